@@ -8,3 +8,4 @@ export { getContainerMethodNames } from "./container-methods.js";
 export { getHiddenPropNames } from "./hidden-props.js";
 export { APPLICATION_PARAM_NAME } from "./native-callbacks.js";
 export { getRenderableSlotNames } from "./renderable-slots.js";
+export { getTrampolineKind } from "./trampoline-kinds.js";
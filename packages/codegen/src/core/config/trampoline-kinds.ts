@@ -0,0 +1,56 @@
+import type { TrampolineType } from "@gtkx/native";
+
+type TrampolineKind = NonNullable<TrampolineType["kind"]>;
+
+/**
+ * Callbacks whose JS result needs converting before it is returned to C.
+ *
+ * - `"compare"`: comparators returning a negative, zero or positive `int`.
+ *   The sign of a JS comparator's result is kept even when it is
+ *   fractional, as with `a.size - b.size`, instead of being truncated to zero.
+ * - `"filter"`: predicates, and iteration callbacks returning whether to
+ *   stop, with a `gboolean` result. Any JS value, such as
+ *   `(row) => row.getChild()`, is converted by its truthiness instead of
+ *   counting only `true`.
+ * - `"source"`: `GSourceFunc`s and callbacks repeated on the same terms,
+ *   such as `GtkTickCallback`. The callback is kept only when it returns
+ *   `true` (`G_SOURCE_CONTINUE`), so one that returns nothing or throws
+ *   runs once.
+ */
+const TRAMPOLINE_KINDS: ReadonlyMap<string, TrampolineKind> = new Map([
+    ["GLib.CompareFunc", "compare"],
+    ["GLib.CompareDataFunc", "compare"],
+    ["Gtk.FlowBoxSortFunc", "compare"],
+    ["Gtk.ListBoxSortFunc", "compare"],
+    ["Gtk.TreeIterCompareFunc", "compare"],
+    ["Gtk.CellAllocCallback", "filter"],
+    ["Gtk.CellCallback", "filter"],
+    ["Gtk.CustomFilterFunc", "filter"],
+    ["Gtk.EntryCompletionMatchFunc", "filter"],
+    ["Gtk.FlowBoxFilterFunc", "filter"],
+    ["Gtk.FontFilterFunc", "filter"],
+    ["Gtk.ListBoxFilterFunc", "filter"],
+    ["Gtk.PrinterFunc", "filter"],
+    ["Gtk.ShortcutFunc", "filter"],
+    ["Gtk.TextCharPredicate", "filter"],
+    ["Gtk.TreeModelFilterVisibleFunc", "filter"],
+    ["Gtk.TreeModelForeachFunc", "filter"],
+    ["Gtk.TreeSelectionFunc", "filter"],
+    ["Gtk.TreeViewColumnDropFunc", "filter"],
+    ["Gtk.TreeViewRowSeparatorFunc", "filter"],
+    ["Gtk.TreeViewSearchEqualFunc", "filter"],
+    ["Pango.AttrFilterFunc", "filter"],
+    ["Pango.FontsetForeachFunc", "filter"],
+    ["GLib.SourceFunc", "source"],
+    ["Gtk.TickCallback", "source"],
+    ["GtkSource.SchedulerCallback", "source"],
+]);
+
+/**
+ * Returns the trampoline kind of the callback identified by `qualifiedName`,
+ * or undefined when its result is written as declared.
+ *
+ * @param qualifiedName - The qualified GIR callback name (e.g. "GLib.CompareDataFunc")
+ */
+export const getTrampolineKind = (qualifiedName: string): TrampolineKind | undefined =>
+    TRAMPOLINE_KINDS.get(qualifiedName);
@@ -8,6 +8,7 @@
 
 import type { GirCallback, GirField, GirNamespace, GirParameter, GirRepository, GirType } from "@gtkx/gir";
 import { isIntrinsicType, isStringType } from "@gtkx/gir";
+import { getTrampolineKind } from "../config/index.js";
 import { normalizeClassName, toCamelCase, toPascalCase, toValidIdentifier } from "../utils/naming.js";
 import { splitQualifiedName } from "../utils/qualified-name.js";
 import {
@@ -24,8 +25,10 @@ import {
     fundamentalType,
     gArrayType,
     getFfiTypeByteSize,
+    gbytesType,
     getPrimitiveTypeSize,
     gobjectType,
+    gstringType,
     hashTableType,
     type ImportType,
     isPrimitiveFieldType,
@@ -171,10 +174,11 @@ export class FfiMapper {
         const elementResult = this.mapType(type.elementType, isReturn, elementTransferOwnership, sizeParamOffset);
         imports.push(...elementResult.imports);
         const elementSize = this.resolveInlineElementSize(type.elementType);
+        const isByteBuffer = (listType === "sized" || listType === "fixed") && elementResult.ffi.type === "uint8";
 
         return withInheritedUnsafe(
             {
-                ts: `${elementResult.ts}[]`,
+                ts: isByteBuffer ? "Uint8Array" : `${elementResult.ts}[]`,
                 ffi: arrayType(
                     elementResult.ffi,
                     listType,
@@ -214,6 +218,28 @@ export class FfiMapper {
             };
         }
 
+        // GString is a boxed record, but outside GLib itself it is only ever
+        // used to carry text, so it is exposed as a plain string.
+        if (type.name === "GLib.String" && this.currentNamespace !== "GLib") {
+            const effectiveTransferOwnership = type.transferOwnership ?? parentTransferOwnership;
+            return {
+                ts: "string",
+                ffi: gstringType(effectiveTransferOwnership !== "none"),
+                imports,
+            };
+        }
+
+        // Likewise, GBytes outside GLib only carries binary data, so it is
+        // exposed as a byte buffer.
+        if (type.name === "GLib.Bytes" && this.currentNamespace !== "GLib") {
+            const effectiveTransferOwnership = type.transferOwnership ?? parentTransferOwnership;
+            return {
+                ts: "Uint8Array",
+                ffi: gbytesType(effectiveTransferOwnership !== "none"),
+                imports,
+            };
+        }
+
         const primitive = PRIMITIVE_TYPE_MAP.get(type.name);
         if (primitive) {
             const base: MappedType = { ...primitive, imports };
@@ -819,7 +845,8 @@ export class FfiMapper {
             ? this.mapType(callback.returnType, true, callback.returnType.transferOwnership).ffi
             : FFI_VOID;
 
-        return trampolineType(argTypes, returnType, undefined, userDataIndex);
+        const kind = getTrampolineKind(callback.qualifiedName);
+        return trampolineType(argTypes, returnType, undefined, userDataIndex, kind);
     }
 
     private mapGLibArrayContainer(
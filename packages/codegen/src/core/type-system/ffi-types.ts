@@ -6,7 +6,7 @@
  */
 
 import type { TypeKind } from "@gtkx/gir";
-import type { CallbackType, Type } from "@gtkx/native";
+import type { CallbackType, TrampolineType, Type } from "@gtkx/native";
 
 export type ImportType = TypeKind;
 
@@ -42,7 +42,8 @@ export type FfiTypeDescriptor = {
         | "gbytearray"
         | "sized"
         | "fixed"
-        | CallbackType["kind"];
+        | CallbackType["kind"]
+        | NonNullable<TrampolineType["kind"]>;
 
     sizeParamIndex?: number;
 
@@ -75,6 +76,8 @@ export type FfiTypeDescriptor = {
     typeName?: string;
 
     size?: number;
+
+    gstring?: boolean;
 };
 
 /**
@@ -285,6 +288,25 @@ export const stringType = (transferFull: boolean): FfiTypeDescriptor => ({
     ownership: toOwnership(transferFull),
 });
 
+/**
+ * Creates an FFI type descriptor for a `GBytes*` converted to and from a JS `Buffer`.
+ * @param transferFull - true for transfer full, false for transfer none
+ */
+export const gbytesType = (transferFull: boolean): FfiTypeDescriptor => ({
+    type: "gbytes",
+    ownership: toOwnership(transferFull),
+});
+
+/**
+ * Creates an FFI type descriptor for a `GString*` converted to and from a JS string.
+ * @param transferFull - true for transfer full, false for transfer none
+ */
+export const gstringType = (transferFull: boolean): FfiTypeDescriptor => ({
+    type: "string",
+    ownership: toOwnership(transferFull),
+    gstring: true,
+});
+
 /**
  * Creates a GObject FFI type descriptor.
  * @param transferFull - true for transfer full, false for transfer none
@@ -461,6 +483,7 @@ export const trampolineType = (
     returnType: FfiTypeDescriptor,
     hasDestroy?: boolean,
     userDataIndex?: number,
+    kind?: TrampolineType["kind"],
 ): FfiTypeDescriptor => {
     const result: FfiTypeDescriptor = {
         type: "trampoline",
@@ -473,6 +496,9 @@ export const trampolineType = (
     if (userDataIndex !== undefined) {
         result.userDataIndex = userDataIndex;
     }
+    if (kind !== undefined) {
+        result.kind = kind;
+    }
     return result;
 };
 
@@ -0,0 +1,76 @@
+/**
+ * Type Registrations
+ *
+ * Digests a GIR namespace into the metadata accepted by `registerTypes`
+ * from `@gtkx/native`, so the runtime can resolve boxed, fundamental, enum,
+ * flags and callback types by name instead of every descriptor carrying
+ * their library and function names.
+ */
+
+import type { GirCallback, GirNamespace, GirRepository } from "@gtkx/gir";
+import type { Type, TypeRegistration } from "@gtkx/native";
+import { FfiMapper } from "./ffi-mapper.js";
+
+const isUserData = (name: string): boolean => name === "user_data" || name === "data";
+
+function callbackRegistration(mapper: FfiMapper, callback: GirCallback): TypeRegistration {
+    const argTypes = callback.parameters
+        .filter((param) => !isUserData(param.name))
+        .map((param) => mapper.mapType(param.type, false, param.transferOwnership).ffi as Type);
+    const returnType = mapper.mapType(callback.returnType, true, callback.returnType.transferOwnership).ffi as Type;
+    return { kind: "callback", typeName: callback.cType, argTypes, returnType };
+}
+
+/**
+ * Builds the type registrations of a namespace.
+ *
+ * Covers records registered as boxed types, fundamental classes with ref
+ * and unref functions, enumerations and bitfields with a `get_type`
+ * function, and callbacks, whose signatures exclude their user data in the
+ * same way generated trampolines do. The result is plain JSON, suitable for
+ * writing out at build time and passing to `registerTypes` at runtime.
+ *
+ * @param repo - Resolved repository the namespace belongs to
+ * @param namespace - Namespace to digest
+ * @returns Registrations keyed by `GType` name, or C type name for callbacks
+ */
+export function buildTypeRegistrations(repo: GirRepository, namespace: GirNamespace): TypeRegistration[] {
+    const library = namespace.sharedLibrary;
+    const registrations: TypeRegistration[] = [];
+
+    for (const record of namespace.records.values()) {
+        if (record.glibTypeName && record.glibGetType) {
+            registrations.push({ kind: "boxed", typeName: record.glibTypeName, library, getTypeFn: record.glibGetType });
+        }
+    }
+
+    for (const cls of namespace.classes.values()) {
+        if (cls.fundamental && cls.glibTypeName && cls.refFunc && cls.unrefFunc) {
+            registrations.push({
+                kind: "fundamental",
+                typeName: cls.glibTypeName,
+                library,
+                refFn: cls.refFunc,
+                unrefFn: cls.unrefFunc,
+            });
+        }
+    }
+
+    for (const [kind, enumerations] of [
+        ["enum", namespace.enumerations],
+        ["flags", namespace.bitfields],
+    ] as const) {
+        for (const enumeration of enumerations.values()) {
+            if (enumeration.glibGetType) {
+                registrations.push({ kind, typeName: enumeration.cType, library, getTypeFn: enumeration.glibGetType });
+            }
+        }
+    }
+
+    const mapper = new FfiMapper(repo, namespace.name);
+    for (const callback of namespace.callbacks.values()) {
+        registrations.push(callbackRegistration(mapper, callback));
+    }
+
+    return registrations;
+}
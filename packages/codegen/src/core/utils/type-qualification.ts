@@ -8,7 +8,7 @@
 /**
  * TypeScript primitive types that don't need namespace qualification.
  */
-const TS_PRIMITIVES = new Set(["boolean", "number", "string", "void", "unknown", "null", "undefined"]);
+const TS_PRIMITIVES = new Set(["boolean", "number", "string", "void", "unknown", "null", "undefined", "Uint8Array"]);
 
 /**
  * Qualifies a TypeScript type with a namespace prefix if needed.
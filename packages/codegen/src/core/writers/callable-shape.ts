@@ -551,6 +551,9 @@ const initialValueFor = (tsType: string): string => {
     if (tsType.endsWith("[]")) {
         return "[]";
     }
+    if (tsType === "Uint8Array") {
+        return "new Uint8Array()";
+    }
     if (tsType.includes("|")) {
         return "null";
     }
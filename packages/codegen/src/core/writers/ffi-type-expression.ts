@@ -48,13 +48,17 @@ export function writeFfiTypeExpression(writer: Writer, descriptor: FfiTypeDescri
 
     switch (descriptor.type) {
         case "string":
-            writer.write(`t.string(${ownership(descriptor)})`);
+            writer.write(`t.${descriptor.gstring ? "gstring" : "string"}(${ownership(descriptor)})`);
             return;
 
         case "gobject":
             writer.write(`t.object(${ownership(descriptor)})`);
             return;
 
+        case "gbytes":
+            writer.write(`t.gbytes(${ownership(descriptor)})`);
+            return;
+
         case "boxed":
             writeBoxedExpression(writer, descriptor);
             return;
@@ -236,6 +240,7 @@ function writeTrampolineOptions(writer: Writer, descriptor: FfiTypeDescriptor):
     if (descriptor.hasDestroy === true) opts.push("hasDestroy: true");
     if (descriptor.userDataIndex !== undefined) opts.push(`userDataIndex: ${descriptor.userDataIndex}`);
     if (descriptor.scope !== undefined) opts.push(`scope: ${stringify(descriptor.scope)}`);
+    if (descriptor.kind !== undefined) opts.push(`kind: ${stringify(descriptor.kind)}`);
     if (opts.length === 0) return;
     writer.write(`, { ${opts.join(", ")} }`);
 }
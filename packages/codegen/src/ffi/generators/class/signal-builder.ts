@@ -73,7 +73,7 @@ export class SignalBuilder {
         }
 
         this.imports.addTypeImport("../../object.js", ["NativeHandle"]);
-        this.imports.addImport("../../registry.js", ["getNativeObject"]);
+        this.imports.addImport("../../registry.js", ["getNativeObject", "trackSignalHandler"]);
         if (this.options.namespace === "GObject") {
             this.imports.addImport("./param-spec.js", ["ParamSpec"]);
         } else {
@@ -511,26 +511,17 @@ export class SignalBuilder {
             ],
             returnType: { type: "uint64" },
         });
-        writer.write("return ");
+        writer.write("return trackSignalHandler(this, ");
         callWriter(writer);
-        writer.writeLine(" as number;");
+        writer.writeLine(" as number);");
     }
 
     private writeClosureSignalConnectCall(writer: Writer, callbackType: FfiTypeDescriptor): void {
-        const callWriter = this.callExpression.toWriter({
-            sharedLibrary: this.options.sharedLibrary,
-            cIdentifier: "g_signal_connect_closure",
-            args: [
-                { type: { type: "gobject", ownership: "borrowed" }, value: "this.handle" },
-                { type: { type: "string", ownership: "borrowed" }, value: "signal" },
-                { type: callbackType, value: "wrappedHandler" },
-                { type: { type: "boolean" }, value: "after ?? false" },
-            ],
-            returnType: { type: "uint64" },
-        });
-        writer.write("return ");
-        callWriter(writer);
-        writer.writeLine(" as number;");
+        this.imports.addImport("@gtkx/native", ["connect"]);
+        this.imports.addImport("../../native.js", ["t"]);
+        writer.write("return trackSignalHandler(this, connect(this.handle, signal, { type: ");
+        writeFfiTypeExpression(writer, callbackType);
+        writer.writeLine(", value: wrappedHandler }, { after }));");
     }
 
     private buildHandlerParams(signal: GirSignal): string {
@@ -18,14 +18,19 @@ import { writeFfiTypeExpression } from "../../../core/writers/ffi-type-expressio
 import { FfiTypeWriter } from "../../../core/writers/ffi-type-writer.js";
 import { addTypeImports, type ImportCollector } from "../../../core/writers/index.js";
 
+/** FFI types a bitfield accessor can read and write. */
+const BITFIELD_FFI_TYPES = new Set(["int8", "uint8", "int16", "uint16", "int32", "uint32", "boolean"]);
+
 /**
  * Field layout information.
  */
-type FieldLayout = {
+export type FieldLayout = {
     field: GirField;
     offset: number;
     size: number;
     alignment: number;
+    /** For bitfields, position of the lowest bit within the byte at `offset`. */
+    bitOffset?: number;
 };
 
 /**
@@ -53,10 +58,15 @@ export class FieldBuilder {
      * Calculates struct memory layout for fields.
      * By default excludes private fields (for accessors).
      * Use includePrivate=true for allocation size calculation.
+     *
+     * Bitfields are packed as C compilers do on the SysV ABI: each starts at
+     * the next free bit unless it would straddle a storage unit of its
+     * declared type, in which case it moves to the next unit. Their `size`
+     * covers only the bytes the field's bits occupy.
      */
     calculateLayout(fields: readonly GirField[], includePrivate = false): FieldLayout[] {
         const layout: FieldLayout[] = [];
-        let currentOffset = 0;
+        let currentBit = 0;
 
         for (const field of fields) {
             if (field.private && !includePrivate) continue;
@@ -64,16 +74,26 @@ export class FieldBuilder {
             const size = this.getFieldSize(field.type);
             const alignment = this.getFieldAlignment(field.type);
 
-            currentOffset = Math.ceil(currentOffset / alignment) * alignment;
-
-            layout.push({
-                field,
-                offset: currentOffset,
-                size,
-                alignment,
-            });
+            if (field.bits !== undefined) {
+                const unitBits = size * 8;
+                if (Math.floor(currentBit / unitBits) !== Math.floor((currentBit + field.bits - 1) / unitBits)) {
+                    currentBit = Math.ceil(currentBit / unitBits) * unitBits;
+                }
+                const bitOffset = currentBit % 8;
+                layout.push({
+                    field,
+                    offset: Math.floor(currentBit / 8),
+                    size: Math.ceil((bitOffset + field.bits) / 8),
+                    alignment,
+                    bitOffset,
+                });
+                currentBit += field.bits;
+                continue;
+            }
 
-            currentOffset += size;
+            const offset = Math.ceil(Math.ceil(currentBit / 8) / alignment) * alignment;
+            layout.push({ field, offset, size, alignment });
+            currentBit = (offset + size) * 8;
         }
 
         return layout;
@@ -108,11 +128,20 @@ export class FieldBuilder {
         );
 
         return (writer) => {
-            for (const { field, offset } of initializableFields) {
+            for (const item of initializableFields) {
+                const { field, offset } = item;
                 let fieldName = toValidMemberName(toCamelCase(field.name));
                 if (fieldName === "id") fieldName = "id_";
 
-                if (this.isInlineNestedStruct(field)) {
+                if (item.bitOffset !== undefined) {
+                    const descriptor = this.getBitfieldDescriptor(item);
+                    if (!descriptor) continue;
+                    this.imports.addImport("../../native.js", ["writeBitfield"]);
+                    const value = this.isBooleanType(field.type) ? `init.${fieldName} ? 1 : 0` : `init.${fieldName}`;
+                    writer.writeLine(
+                        `if (init.${fieldName} !== undefined) writeBitfield(this.handle, ${descriptor}, ${value});`,
+                    );
+                } else if (this.isInlineNestedStruct(field)) {
                     const typeName = String(field.type.name);
                     const nestedLayout = this.getNestedStructLayout(typeName);
                     if (!nestedLayout) continue;
@@ -125,6 +154,7 @@ export class FieldBuilder {
                     writer.withIndent(() => {
                         for (const nestedItem of nestedLayout) {
                             if (!this.isWritableType(nestedItem.field.type)) continue;
+                            if (nestedItem.bitOffset !== undefined) continue;
                             const nestedFieldName = toValidMemberName(toCamelCase(nestedItem.field.name));
                             const nestedOffset = offset + nestedItem.offset;
                             const nestedTypeMapping = this.ffiMapper.mapType(
@@ -169,10 +199,39 @@ export class FieldBuilder {
                 !f.private &&
                 f.writable !== false &&
                 this.isGeneratableFieldType(String(f.type.name)) &&
+                (f.bits === undefined || this.isBitfieldType(f.type)) &&
                 (this.isWritableType(f.type) || this.isInlineNestedStruct(f)),
         );
     }
 
+    /**
+     * Checks if a bitfield of this type can be read and written as an integer.
+     */
+    isBitfieldType(type: GirField["type"]): boolean {
+        return BITFIELD_FFI_TYPES.has(this.ffiMapper.mapType(type, false, type.transferOwnership).ffi.type);
+    }
+
+    /**
+     * Checks if a field type maps to a boolean.
+     */
+    isBooleanType(type: GirField["type"]): boolean {
+        return this.ffiMapper.mapType(type, false, type.transferOwnership).ffi.type === "boolean";
+    }
+
+    /**
+     * Builds the `Bitfield` descriptor literal passed to `readBitfield` and
+     * `writeBitfield` for a bitfield layout entry. Returns null for entries
+     * that are not bitfields or whose type cannot be read as an integer.
+     */
+    getBitfieldDescriptor(item: FieldLayout): string | null {
+        const { field, offset, bitOffset } = item;
+        if (bitOffset === undefined || field.bits === undefined || !this.isBitfieldType(field.type)) return null;
+
+        const ffiType = this.ffiMapper.mapType(field.type, false, field.type.transferOwnership).ffi.type;
+        const signed = ffiType.startsWith("int") ? ", signed: true" : "";
+        return `{ offset: ${offset}, bitOffset: ${bitOffset}, width: ${field.bits}${signed} }`;
+    }
+
     /**
      * Checks if a type can be written to memory.
      */
@@ -41,7 +41,7 @@ import {
     type MethodBodyWriter,
     type MethodStructure,
 } from "../../../core/writers/index.js";
-import { FieldBuilder } from "./field-builder.js";
+import { FieldBuilder, type FieldLayout } from "./field-builder.js";
 
 type RecordTypeMeta = {
     glibTypeName?: string;
@@ -692,10 +692,56 @@ export class RecordGenerator {
 
         for (const layoutItem of layout) {
             if (!layoutItem) continue;
+            if (layoutItem.bitOffset !== undefined) {
+                this.generateBitfieldAccessor(layoutItem, cls, methodNames);
+                continue;
+            }
             this.generateField(layoutItem.field, layoutItem.offset, fields, cls, methodNames);
         }
     }
 
+    private generateBitfieldAccessor(
+        layoutItem: FieldLayout,
+        cls: ClassDeclarationBuilder,
+        methodNames: Set<string>,
+    ): void {
+        const { field } = layoutItem;
+        const fieldName = this.resolveFieldName(field);
+        const descriptor = this.fieldBuilder.getBitfieldDescriptor(layoutItem);
+        if (!descriptor) return;
+
+        const isReadable = field.readable !== false && !methodNames.has(fieldName);
+        const isWritable = field.writable !== false && !methodNames.has(fieldName);
+        if (!isReadable) return;
+
+        const typeMapping = this.ffiMapper.mapType(field.type, false, field.type.transferOwnership);
+        const isBoolean = this.fieldBuilder.isBooleanType(field.type);
+        const doc = buildJsDocStructure(field.doc, this.options.namespace);
+
+        this.file.addImport("../../native.js", ["readBitfield"]);
+        const getBody = (writer: Writer) => {
+            writer.writeLine(`return readBitfield(this.handle, ${descriptor})${isBoolean ? " !== 0" : ""};`);
+        };
+
+        let setBody: ((writer: Writer) => void) | undefined;
+        if (isWritable) {
+            this.file.addImport("../../native.js", ["writeBitfield"]);
+            const value = isBoolean ? "value ? 1 : 0" : "value";
+            setBody = (writer) => {
+                writer.writeLine(`writeBitfield(this.handle, ${descriptor}, ${value});`);
+            };
+        }
+
+        cls.addAccessor(
+            accessor(fieldName, {
+                type: typeMapping.ts,
+                getBody,
+                setBody,
+                doc: doc?.[0]?.description,
+            }),
+        );
+    }
+
     private generateNestedStructAccessors(
         field: GirField,
         fieldName: string,
@@ -718,6 +764,7 @@ export class RecordGenerator {
         const writableFields = nestedLayout
             .filter(
                 (item) =>
+                    item.bitOffset === undefined &&
                     this.fieldBuilder.isGeneratableFieldType(String(item.field.type.name)) &&
                     this.fieldBuilder.isWritableType(item.field.type),
             )
@@ -809,6 +856,7 @@ export class RecordGenerator {
 
         const writableNestedFields = nestedLayout.filter(
             (item) =>
+                item.bitOffset === undefined &&
                 this.fieldBuilder.isGeneratableFieldType(String(item.field.type.name)) &&
                 this.fieldBuilder.isWritableType(item.field.type),
         );
@@ -1,5 +1,6 @@
 export * from "./commands/index.js";
 export { CodegenOrchestrator } from "./core/codegen-orchestrator.js";
+export { buildTypeRegistrations } from "./core/type-system/type-registrations.js";
 export * from "./core/utils/progress.js";
 export { writeGeneratedDir } from "./core/utils/writer.js";
 export * from "./ffi/index.js";
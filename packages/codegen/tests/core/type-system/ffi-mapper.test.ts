@@ -151,6 +151,24 @@ describe("FfiMapper", () => {
 
                 expect(result.ffi).toEqual({ type: "string", ownership: "borrowed" });
             });
+
+            it("maps GLib.String to a gstring-backed string", () => {
+                const { mapper } = createTestSetup();
+                const type = createNormalizedType({ name: "GLib.String", transferOwnership: "full" });
+                const result = mapper.mapType(type);
+
+                expect(result.ts).toBe("string");
+                expect(result.ffi).toEqual({ type: "string", ownership: "full", gstring: true });
+            });
+
+            it("maps GLib.Bytes to a gbytes-backed byte buffer", () => {
+                const { mapper } = createTestSetup();
+                const type = createNormalizedType({ name: "GLib.Bytes", transferOwnership: "none" });
+                const result = mapper.mapType(type);
+
+                expect(result.ts).toBe("Uint8Array");
+                expect(result.ffi).toEqual({ type: "gbytes", ownership: "borrowed" });
+            });
         });
 
         describe("arrays", () => {
@@ -752,6 +770,278 @@ describe("FfiMapper", () => {
                 }
             });
 
+            it("marks comparator callbacks as compare trampolines", () => {
+                const compareCallback = createNormalizedCallback({
+                    name: "CompareDataFunc",
+                    qualifiedName: qualifiedName("GLib", "CompareDataFunc"),
+                    parameters: [
+                        createNormalizedParameter({ name: "a", type: createNormalizedType({ name: "gpointer" }) }),
+                        createNormalizedParameter({ name: "b", type: createNormalizedType({ name: "gpointer" }) }),
+                        createNormalizedParameter({
+                            name: "user_data",
+                            type: createNormalizedType({ name: "gpointer" }),
+                        }),
+                    ],
+                    returnType: createNormalizedType({ name: "gint" }),
+                });
+                const glibNs = createNormalizedNamespace({
+                    name: "GLib",
+                    callbacks: new Map([["CompareDataFunc", compareCallback]]),
+                });
+                const { mapper } = createTestSetup(new Map([["GLib", glibNs]]));
+
+                const param = createNormalizedParameter({
+                    name: "compare_func",
+                    type: createNormalizedType({ name: "GLib.CompareDataFunc" }),
+                });
+                const result = mapper.mapParameter(param);
+
+                expect(result.ffi.type).toBe("trampoline");
+                expect(result.ffi.kind).toBe("compare");
+                expect(result.ffi.returnType).toEqual({ type: "int32" });
+                expect(result.ffi.userDataIndex).toBe(2);
+            });
+
+            it("marks predicate callbacks as filter trampolines", () => {
+                const filterCallback = createNormalizedCallback({
+                    name: "FlowBoxFilterFunc",
+                    qualifiedName: qualifiedName("Gtk", "FlowBoxFilterFunc"),
+                    parameters: [
+                        createNormalizedParameter({
+                            name: "child",
+                            type: createNormalizedType({ name: "Gtk.FlowBoxChild" }),
+                        }),
+                        createNormalizedParameter({
+                            name: "user_data",
+                            type: createNormalizedType({ name: "gpointer" }),
+                        }),
+                    ],
+                    returnType: createNormalizedType({ name: "gboolean" }),
+                });
+                const gtkNs = createNormalizedNamespace({
+                    name: "Gtk",
+                    classes: new Map([["FlowBoxChild", createNormalizedClass({ name: "FlowBoxChild" })]]),
+                    callbacks: new Map([["FlowBoxFilterFunc", filterCallback]]),
+                });
+                const { mapper } = createTestSetup(new Map([["Gtk", gtkNs]]));
+
+                const param = createNormalizedParameter({
+                    name: "filter_func",
+                    type: createNormalizedType({ name: "Gtk.FlowBoxFilterFunc" }),
+                });
+                const result = mapper.mapParameter(param);
+
+                expect(result.ffi.type).toBe("trampoline");
+                expect(result.ffi.kind).toBe("filter");
+                expect(result.ffi.returnType).toEqual({ type: "boolean" });
+                expect(result.ffi.userDataIndex).toBe(1);
+            });
+
+            it("marks text match callbacks as filter trampolines", () => {
+                const treeIter = createNormalizedRecord({
+                    name: "TreeIter",
+                    qualifiedName: qualifiedName("Gtk", "TreeIter"),
+                    glibTypeName: "GtkTreeIter",
+                    glibGetType: "gtk_tree_iter_get_type",
+                });
+                const matchFunc = createNormalizedCallback({
+                    name: "EntryCompletionMatchFunc",
+                    qualifiedName: qualifiedName("Gtk", "EntryCompletionMatchFunc"),
+                    parameters: [
+                        createNormalizedParameter({
+                            name: "completion",
+                            type: createNormalizedType({ name: "Gtk.EntryCompletion" }),
+                        }),
+                        createNormalizedParameter({ name: "key", type: createNormalizedType({ name: "utf8" }) }),
+                        createNormalizedParameter({
+                            name: "iter",
+                            type: createNormalizedType({ name: "Gtk.TreeIter" }),
+                        }),
+                        createNormalizedParameter({
+                            name: "user_data",
+                            type: createNormalizedType({ name: "gpointer" }),
+                        }),
+                    ],
+                    returnType: createNormalizedType({ name: "gboolean" }),
+                });
+                const gtkNs = createNormalizedNamespace({
+                    name: "Gtk",
+                    sharedLibrary: "libgtk-4.so.1",
+                    classes: new Map([["EntryCompletion", createNormalizedClass({ name: "EntryCompletion" })]]),
+                    records: new Map([["TreeIter", treeIter]]),
+                    callbacks: new Map([["EntryCompletionMatchFunc", matchFunc]]),
+                });
+                const { mapper } = createTestSetup(new Map([["Gtk", gtkNs]]));
+
+                const param = createNormalizedParameter({
+                    name: "func",
+                    type: createNormalizedType({ name: "Gtk.EntryCompletionMatchFunc" }),
+                    destroy: 2,
+                });
+                const result = mapper.mapParameter(param);
+
+                expect(result.unsafe).toBeFalsy();
+                expect(result.ts).toContain("key: string");
+                expect(result.ffi.kind).toBe("filter");
+                expect(result.ffi.hasDestroy).toBe(true);
+                expect(result.ffi.userDataIndex).toBe(3);
+            });
+
+            it("marks Pango.AttrFilterFunc as a filter trampoline with a boxed attribute", () => {
+                const attribute = createNormalizedRecord({
+                    name: "Attribute",
+                    qualifiedName: qualifiedName("Pango", "Attribute"),
+                    glibTypeName: "PangoAttribute",
+                    glibGetType: "pango_attribute_get_type",
+                });
+                const attrFilterFunc = createNormalizedCallback({
+                    name: "AttrFilterFunc",
+                    qualifiedName: qualifiedName("Pango", "AttrFilterFunc"),
+                    parameters: [
+                        createNormalizedParameter({
+                            name: "attribute",
+                            type: createNormalizedType({ name: "Pango.Attribute" }),
+                        }),
+                        createNormalizedParameter({
+                            name: "user_data",
+                            type: createNormalizedType({ name: "gpointer" }),
+                        }),
+                    ],
+                    returnType: createNormalizedType({ name: "gboolean" }),
+                });
+                const pangoNs = createNormalizedNamespace({
+                    name: "Pango",
+                    sharedLibrary: "libpango-1.0.so.0",
+                    records: new Map([["Attribute", attribute]]),
+                    callbacks: new Map([["AttrFilterFunc", attrFilterFunc]]),
+                });
+                const { mapper } = createTestSetup(new Map([["Pango", pangoNs]]));
+
+                const param = createNormalizedParameter({
+                    name: "func",
+                    type: createNormalizedType({ name: "Pango.AttrFilterFunc" }),
+                    scope: "call",
+                });
+                const result = mapper.mapParameter(param);
+
+                expect(result.unsafe).toBeFalsy();
+                expect(result.ffi.kind).toBe("filter");
+                expect(result.ffi.scope).toBe("call");
+                expect(result.ffi.argTypes?.[0]?.type).toBe("boxed");
+                expect(result.ffi.userDataIndex).toBe(1);
+            });
+
+            it("marks GLib.SourceFunc as a source trampoline", () => {
+                const sourceFunc = createNormalizedCallback({
+                    name: "SourceFunc",
+                    qualifiedName: qualifiedName("GLib", "SourceFunc"),
+                    parameters: [
+                        createNormalizedParameter({
+                            name: "user_data",
+                            type: createNormalizedType({ name: "gpointer" }),
+                        }),
+                    ],
+                    returnType: createNormalizedType({ name: "gboolean" }),
+                });
+                const glibNs = createNormalizedNamespace({
+                    name: "GLib",
+                    callbacks: new Map([["SourceFunc", sourceFunc]]),
+                });
+                const { mapper } = createTestSetup(new Map([["GLib", glibNs]]));
+
+                const param = createNormalizedParameter({
+                    name: "function",
+                    type: createNormalizedType({ name: "GLib.SourceFunc" }),
+                });
+                const result = mapper.mapParameter(param);
+
+                expect(result.ffi.kind).toBe("source");
+                expect(result.ffi.userDataIndex).toBe(0);
+            });
+
+            it("marks Gtk.TickCallback as a source trampoline", () => {
+                const widget = createNormalizedClass({ name: "Widget" });
+                const frameClock = createNormalizedClass({
+                    name: "FrameClock",
+                    qualifiedName: qualifiedName("Gdk", "FrameClock"),
+                });
+                const tickCallback = createNormalizedCallback({
+                    name: "TickCallback",
+                    qualifiedName: qualifiedName("Gtk", "TickCallback"),
+                    parameters: [
+                        createNormalizedParameter({
+                            name: "widget",
+                            type: createNormalizedType({ name: "Gtk.Widget" }),
+                        }),
+                        createNormalizedParameter({
+                            name: "frame_clock",
+                            type: createNormalizedType({ name: "Gdk.FrameClock" }),
+                        }),
+                        createNormalizedParameter({
+                            name: "user_data",
+                            type: createNormalizedType({ name: "gpointer" }),
+                        }),
+                    ],
+                    returnType: createNormalizedType({ name: "gboolean" }),
+                });
+                const gtkNs = createNormalizedNamespace({
+                    name: "Gtk",
+                    classes: new Map([["Widget", widget]]),
+                    callbacks: new Map([["TickCallback", tickCallback]]),
+                });
+                const gdkNs = createNormalizedNamespace({
+                    name: "Gdk",
+                    classes: new Map([["FrameClock", frameClock]]),
+                });
+                const { mapper } = createTestSetup(
+                    new Map([
+                        ["Gtk", gtkNs],
+                        ["Gdk", gdkNs],
+                    ]),
+                );
+
+                const param = createNormalizedParameter({
+                    name: "callback",
+                    type: createNormalizedType({ name: "Gtk.TickCallback" }),
+                    scope: "notified",
+                });
+                const result = mapper.mapParameter(param);
+
+                expect(result.ffi.kind).toBe("source");
+                expect(result.ffi.scope).toBe("notified");
+                expect(result.ffi.argTypes?.map((arg) => arg.type)).toEqual(["gobject", "gobject"]);
+                expect(result.ffi.userDataIndex).toBe(2);
+            });
+
+            it("marks Gtk.TreeModelForeachFunc as a filter trampoline", () => {
+                const foreachFunc = createNormalizedCallback({
+                    name: "TreeModelForeachFunc",
+                    qualifiedName: qualifiedName("Gtk", "TreeModelForeachFunc"),
+                    parameters: [
+                        createNormalizedParameter({
+                            name: "data",
+                            type: createNormalizedType({ name: "gpointer" }),
+                        }),
+                    ],
+                    returnType: createNormalizedType({ name: "gboolean" }),
+                });
+                const gtkNs = createNormalizedNamespace({
+                    name: "Gtk",
+                    callbacks: new Map([["TreeModelForeachFunc", foreachFunc]]),
+                });
+                const { mapper } = createTestSetup(new Map([["Gtk", gtkNs]]));
+
+                const param = createNormalizedParameter({
+                    name: "func",
+                    type: createNormalizedType({ name: "Gtk.TreeModelForeachFunc" }),
+                    scope: "call",
+                });
+                const result = mapper.mapParameter(param);
+
+                expect(result.ffi.kind).toBe("filter");
+                expect(result.ffi.userDataIndex).toBe(0);
+            });
+
             it("maps GLib.Closure to generic callback", () => {
                 const { mapper } = createTestSetup();
                 const param = createNormalizedParameter({
@@ -1367,6 +1657,26 @@ describe("FfiMapper - Extended Coverage", () => {
             expect(result.ffi.sizeParamIndex).toBe(1);
         });
 
+        it("maps sized and fixed-size byte arrays to Uint8Array", () => {
+            const { mapper } = createTestSetup();
+            const sized = createNormalizedType({
+                name: "guint8",
+                isArray: true,
+                elementType: createNormalizedType({ name: "guint8" }),
+                sizeParamIndex: 1,
+                zeroTerminated: false,
+            });
+            const fixed = createNormalizedType({
+                name: "guint8",
+                isArray: true,
+                elementType: createNormalizedType({ name: "guint8" }),
+                fixedSize: 16,
+            });
+
+            expect(mapper.mapType(sized).ts).toBe("Uint8Array");
+            expect(mapper.mapType(fixed).ts).toBe("Uint8Array");
+        });
+
         it("adjusts size param index with offset for instance methods", () => {
             const { mapper } = createTestSetup();
             const type = createNormalizedType({
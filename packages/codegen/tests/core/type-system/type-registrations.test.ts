@@ -0,0 +1,112 @@
+import { describe, expect, it } from "vitest";
+import { FfiMapper } from "../../../src/core/type-system/ffi-mapper.js";
+import { buildTypeRegistrations } from "../../../src/core/type-system/type-registrations.js";
+import {
+    createNormalizedCallback,
+    createNormalizedClass,
+    createNormalizedEnumeration,
+    createNormalizedNamespace,
+    createNormalizedParameter,
+    createNormalizedRecord,
+    createNormalizedType,
+} from "../../fixtures/gir-fixtures.js";
+import { createMockRepository } from "../../fixtures/mock-repository.js";
+
+type Repository = Parameters<typeof buildTypeRegistrations>[0];
+
+function build(ns: ReturnType<typeof createNormalizedNamespace>) {
+    const repo = createMockRepository(new Map([[ns.name, ns]])) as Repository;
+    return buildTypeRegistrations(repo, ns);
+}
+
+describe("buildTypeRegistrations", () => {
+    it("registers records with a GType as boxed types", () => {
+        const rgba = createNormalizedRecord({
+            name: "RGBA",
+            qualifiedName: "Gdk.RGBA",
+            glibTypeName: "GdkRGBA",
+            glibGetType: "gdk_rgba_get_type",
+        });
+        const plain = createNormalizedRecord({ name: "Plain", qualifiedName: "Gdk.Plain" });
+        const ns = createNormalizedNamespace({
+            name: "Gdk",
+            records: new Map([
+                ["RGBA", rgba],
+                ["Plain", plain],
+            ]),
+        });
+
+        expect(build(ns)).toEqual([
+            { kind: "boxed", typeName: "GdkRGBA", library: "libgdk-4.so.1", getTypeFn: "gdk_rgba_get_type" },
+        ]);
+    });
+
+    it("registers fundamental classes with their ref and unref functions", () => {
+        const node = createNormalizedClass({
+            name: "RenderNode",
+            qualifiedName: "Gsk.RenderNode",
+            fundamental: true,
+            refFunc: "gsk_render_node_ref",
+            unrefFunc: "gsk_render_node_unref",
+        });
+        const widget = createNormalizedClass({ name: "Widget", qualifiedName: "Gsk.Widget" });
+        const ns = createNormalizedNamespace({
+            name: "Gsk",
+            classes: new Map([
+                ["RenderNode", node],
+                ["Widget", widget],
+            ]),
+        });
+
+        expect(build(ns)).toEqual([
+            {
+                kind: "fundamental",
+                typeName: "GskRenderNode",
+                library: "libgsk-4.so.1",
+                refFn: "gsk_render_node_ref",
+                unrefFn: "gsk_render_node_unref",
+            },
+        ]);
+    });
+
+    it("registers enumerations and bitfields with a get_type function", () => {
+        const align = createNormalizedEnumeration({ name: "Align", glibGetType: "gtk_align_get_type" });
+        const untyped = createNormalizedEnumeration({ name: "Untyped" });
+        const flags = createNormalizedEnumeration({ name: "StateFlags", glibGetType: "gtk_state_flags_get_type" });
+        const ns = createNormalizedNamespace({
+            enumerations: new Map([
+                ["Align", align],
+                ["Untyped", untyped],
+            ]),
+            bitfields: new Map([["StateFlags", flags]]),
+        });
+
+        expect(build(ns)).toEqual([
+            { kind: "enum", typeName: "GtkAlign", library: "libgtk-4.so.1", getTypeFn: "gtk_align_get_type" },
+            { kind: "flags", typeName: "GtkStateFlags", library: "libgtk-4.so.1", getTypeFn: "gtk_state_flags_get_type" },
+        ]);
+    });
+
+    it("registers callback signatures without their user data", () => {
+        const callback = createNormalizedCallback({
+            name: "TickCallback",
+            qualifiedName: "Gtk.TickCallback",
+            returnType: createNormalizedType({ name: "gboolean" }),
+            parameters: [
+                createNormalizedParameter({ name: "count", type: createNormalizedType({ name: "gint" }) }),
+                createNormalizedParameter({ name: "user_data", type: createNormalizedType({ name: "gpointer" }) }),
+            ],
+        });
+        const ns = createNormalizedNamespace({ callbacks: new Map([["TickCallback", callback]]) });
+        const mapper = new FfiMapper(createMockRepository(new Map([["Gtk", ns]])) as Repository, "Gtk");
+
+        expect(build(ns)).toEqual([
+            {
+                kind: "callback",
+                typeName: "GtkTickCallback",
+                argTypes: [mapper.mapType(createNormalizedType({ name: "gint" })).ffi],
+                returnType: mapper.mapType(createNormalizedType({ name: "gboolean" }), true).ffi,
+            },
+        ]);
+    });
+});
@@ -34,6 +34,14 @@ describe("writeFfiTypeExpression — strings and gobjects", () => {
         expect(render({ type: "string", ownership: "full" })).toBe('t.string("full")');
     });
 
+    it("renders GString-backed strings using t.gstring", () => {
+        expect(render({ type: "string", ownership: "full", gstring: true })).toBe('t.gstring("full")');
+    });
+
+    it("renders GBytes using t.gbytes", () => {
+        expect(render({ type: "gbytes", ownership: "full" })).toBe('t.gbytes("full")');
+    });
+
     it("renders gobject types using t.object with the supplied ownership", () => {
         expect(render({ type: "gobject", ownership: "full" })).toBe('t.object("full")');
     });
@@ -283,6 +291,18 @@ describe("writeFfiTypeExpression — callback and trampoline", () => {
         ).toBe('t.trampoline([t.int32], t.void, { hasDestroy: true, userDataIndex: 1, scope: "async" })');
     });
 
+    it("renders the kind of a compare trampoline", () => {
+        expect(
+            render({
+                type: "trampoline",
+                argTypes: [{ type: "uint64" }, { type: "uint64" }, { type: "uint64" }],
+                returnType: { type: "int32" },
+                userDataIndex: 2,
+                kind: "compare",
+            }),
+        ).toBe('t.trampoline([t.uint64, t.uint64, t.uint64], t.int32, { userDataIndex: 2, kind: "compare" })');
+    });
+
     it("omits the options object when the trampoline carries no options", () => {
         expect(render({ type: "trampoline", argTypes: [], returnType: { type: "void" } })).toBe(
             "t.trampoline([], t.void)",
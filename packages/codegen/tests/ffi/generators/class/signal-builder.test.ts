@@ -1,5 +1,6 @@
 import { describe, expect, it } from "vitest";
 import { fileBuilder } from "../../../../src/builders/file-builder.js";
+import { Writer } from "../../../../src/builders/writer.js";
 import { FfiMapper } from "../../../../src/core/type-system/ffi-mapper.js";
 import { SignalBuilder } from "../../../../src/ffi/generators/class/signal-builder.js";
 import {
@@ -99,6 +100,44 @@ describe("SignalBuilder", () => {
 
             expect(structures[0].returnType).toBe("number");
         });
+
+        it("records the handler ID with trackSignalHandler", () => {
+            const { builder } = createTestSetup({
+                signals: [createNormalizedSignal({ name: "clicked" })],
+            });
+
+            const structures = builder.buildConnectMethodStructures();
+            const writer = new Writer();
+            (structures[0].statements as (w: Writer) => void)(writer);
+
+            expect(writer.toString()).toContain("return trackSignalHandler(this, ");
+        });
+
+        it("connects signals GObject.Object does not declare through the native connect", () => {
+            const namespaces = new Map([["GObject", createNormalizedNamespace({ name: "GObject" })]]);
+            const repo = createMockRepository(namespaces);
+            const ffiMapper = new FfiMapper(repo as Parameters<typeof FfiMapper>[0], "GObject");
+            const cls = createNormalizedClass({
+                name: "Object",
+                qualifiedName: qualifiedName("GObject", "Object"),
+                parent: null,
+                signals: [createNormalizedSignal({ name: "notify" })],
+            });
+            const builder = new SignalBuilder(cls, ffiMapper, fileBuilder(), repo as Parameters<typeof FfiMapper>[0], {
+                namespace: "GObject",
+                sharedLibrary: "libgobject-2.0.so.0",
+                glibLibrary: "libglib-2.0.so.0",
+                gobjectLibrary: "libgobject-2.0.so.0",
+            });
+
+            const structures = builder.buildConnectMethodStructures();
+            const writer = new Writer();
+            (structures[0].statements as (w: Writer) => void)(writer);
+
+            const output = writer.toString();
+            expect(output).toContain('connect(this.handle, signal, { type: t.callback([t.object("borrowed")], t.void)');
+            expect(output).toContain("value: wrappedHandler }, { after })");
+        });
     });
 
     describe("collectAllSignals", () => {
@@ -444,4 +444,62 @@ describe("FieldBuilder", () => {
             expect(layout[1].offset).toBe(8);
         });
     });
+
+    describe("bitfields", () => {
+        it("packs consecutive bitfields into one storage unit", () => {
+            const { builder } = createTestSetup();
+            const fields = [
+                createNormalizedField({ name: "a", type: createNormalizedType({ name: "guint" }), bits: 1 }),
+                createNormalizedField({ name: "b", type: createNormalizedType({ name: "guint" }), bits: 1 }),
+                createNormalizedField({ name: "c", type: createNormalizedType({ name: "guint" }), bits: 30 }),
+                createNormalizedField({ name: "d", type: createNormalizedType({ name: "gint" }) }),
+            ];
+
+            const layout = builder.calculateLayout(fields);
+
+            expect(layout.map((l) => [l.offset, l.bitOffset])).toEqual([
+                [0, 0],
+                [0, 1],
+                [0, 2],
+                [4, undefined],
+            ]);
+            expect(builder.calculateStructSize(fields)).toBe(8);
+        });
+
+        it("moves a bitfield that would straddle a storage unit to the next one", () => {
+            const { builder } = createTestSetup();
+            const fields = [
+                createNormalizedField({ name: "a", type: createNormalizedType({ name: "guint" }), bits: 20 }),
+                createNormalizedField({ name: "b", type: createNormalizedType({ name: "guint" }), bits: 20 }),
+            ];
+
+            const layout = builder.calculateLayout(fields);
+
+            expect(layout[1].offset).toBe(4);
+            expect(layout[1].bitOffset).toBe(0);
+            expect(builder.calculateStructSize(fields)).toBe(8);
+        });
+
+        it("builds descriptors with the bit position and signedness", () => {
+            const { builder } = createTestSetup();
+            const fields = [
+                createNormalizedField({ name: "flag", type: createNormalizedType({ name: "guint" }), bits: 3 }),
+                createNormalizedField({ name: "delta", type: createNormalizedType({ name: "gint" }), bits: 7 }),
+            ];
+
+            const [flag, delta] = builder.calculateLayout(fields);
+
+            expect(builder.getBitfieldDescriptor(flag)).toBe("{ offset: 0, bitOffset: 0, width: 3 }");
+            expect(builder.getBitfieldDescriptor(delta)).toBe("{ offset: 0, bitOffset: 3, width: 7, signed: true }");
+        });
+
+        it("returns no descriptor for regular fields", () => {
+            const { builder } = createTestSetup();
+            const fields = [createNormalizedField({ name: "value", type: createNormalizedType({ name: "gint" }) })];
+
+            const [value] = builder.calculateLayout(fields);
+
+            expect(builder.getBitfieldDescriptor(value)).toBeNull();
+        });
+    });
 });
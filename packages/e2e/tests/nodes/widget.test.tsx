@@ -262,6 +262,26 @@ describe("widget - props", () => {
 
             expect(ref.current?.getLabel()).toBe("");
         });
+
+        it("holds back notifications until every changed prop is applied", async () => {
+            const ref = createRef<Gtk.Label>();
+
+            function App({ text, selectable }: { text: string; selectable: boolean }) {
+                return <GtkLabel ref={ref} label={text} selectable={selectable} />;
+            }
+
+            const { rerender } = await render(<App text="Initial" selectable={false} />);
+            const label = ref.current as Gtk.Label;
+            const seen: Array<[string, boolean]> = [];
+            const handlerId = label.connect("notify::label", () => {
+                seen.push([label.getLabel(), label.getSelectable()]);
+            });
+
+            await rerender(<App text="Updated" selectable={true} />);
+            label.disconnect(handlerId);
+
+            expect(seen).toEqual([["Updated", true]]);
+        });
     });
 
     describe("consumed props", () => {
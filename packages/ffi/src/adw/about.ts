@@ -0,0 +1,119 @@
+import { AboutWindow } from "../generated/adw/about-window.js";
+import type { Application } from "../generated/gtk/application.js";
+import type { License } from "../generated/gtk/enums.js";
+
+/** A named group of people credited in the about window. */
+export type AboutCreditSection = {
+    name: string;
+    /** People, as `"Name"`, `"Name <email>"` or `"Name https://url"`. */
+    people: readonly string[];
+};
+
+/** An additional license shown in the legal page, e.g. for a bundled library. */
+export type AboutLegalSection = {
+    title: string;
+    copyright?: string;
+    licenseType: License;
+    /** License text, used when `licenseType` is `CUSTOM`. */
+    license?: string;
+};
+
+/** A link shown in the details page. */
+export type AboutLink = {
+    title: string;
+    url: string;
+};
+
+/** Contents of the window built by {@link showAboutWindow}. */
+export type AboutInfo = {
+    applicationName: string;
+    applicationIcon?: string;
+    version?: string;
+    developerName?: string;
+    comments?: string;
+    website?: string;
+    issueUrl?: string;
+    supportUrl?: string;
+    releaseNotes?: string;
+    releaseNotesVersion?: string;
+    copyright?: string;
+    licenseType?: License;
+    /** License text, used when `licenseType` is `CUSTOM` or omitted. */
+    license?: string;
+    developers?: readonly string[];
+    designers?: readonly string[];
+    artists?: readonly string[];
+    documenters?: readonly string[];
+    /** Translator credits, conventionally the translated `"translator-credits"` string. */
+    translatorCredits?: string;
+    credits?: readonly AboutCreditSection[];
+    acknowledgements?: readonly AboutCreditSection[];
+    legal?: readonly AboutLegalSection[];
+    links?: readonly AboutLink[];
+    /** Troubleshooting text users can copy into bug reports. */
+    debugInfo?: string;
+    debugInfoFilename?: string;
+};
+
+/**
+ * Builds an `AdwAboutWindow` from `info`, adds it to `application` and
+ * presents it over the application's active window.
+ *
+ * @param application - The application the window belongs to
+ * @param info - Contents of the window
+ * @returns The presented window
+ *
+ * @example
+ * ```tsx
+ * showAboutWindow(app, {
+ *     applicationName: "Notes",
+ *     version: "1.2.0",
+ *     developers: ["Ada Lovelace <ada@example.com>"],
+ *     licenseType: Gtk.License.GPL_3_0,
+ *     links: [{ title: "Source", url: "https://example.com/notes" }],
+ * });
+ * ```
+ */
+export const showAboutWindow = (application: Application, info: AboutInfo): AboutWindow => {
+    const window = new AboutWindow();
+    window.setApplicationName(info.applicationName);
+    if (info.applicationIcon !== undefined) window.setApplicationIcon(info.applicationIcon);
+    if (info.version !== undefined) window.setVersion(info.version);
+    if (info.developerName !== undefined) window.setDeveloperName(info.developerName);
+    if (info.comments !== undefined) window.setComments(info.comments);
+    if (info.website !== undefined) window.setWebsite(info.website);
+    if (info.issueUrl !== undefined) window.setIssueUrl(info.issueUrl);
+    if (info.supportUrl !== undefined) window.setSupportUrl(info.supportUrl);
+    if (info.releaseNotes !== undefined) window.setReleaseNotes(info.releaseNotes);
+    if (info.releaseNotesVersion !== undefined) window.setReleaseNotesVersion(info.releaseNotesVersion);
+    if (info.copyright !== undefined) window.setCopyright(info.copyright);
+    if (info.license !== undefined) window.setLicense(info.license);
+    if (info.licenseType !== undefined) window.setLicenseType(info.licenseType);
+    if (info.translatorCredits !== undefined) window.setTranslatorCredits(info.translatorCredits);
+    if (info.debugInfo !== undefined) window.setDebugInfo(info.debugInfo);
+    if (info.debugInfoFilename !== undefined) window.setDebugInfoFilename(info.debugInfoFilename);
+
+    if (info.developers) window.setDevelopers([...info.developers]);
+    if (info.designers) window.setDesigners([...info.designers]);
+    if (info.artists) window.setArtists([...info.artists]);
+    if (info.documenters) window.setDocumenters([...info.documenters]);
+
+    for (const section of info.credits ?? []) {
+        window.addCreditSection(section.name, [...section.people]);
+    }
+    for (const section of info.acknowledgements ?? []) {
+        window.addAcknowledgementSection(section.name, [...section.people]);
+    }
+    for (const section of info.legal ?? []) {
+        window.addLegalSection(section.title, section.copyright ?? null, section.licenseType, section.license ?? null);
+    }
+    for (const link of info.links ?? []) {
+        window.addLink(link.title, link.url);
+    }
+
+    application.addWindow(window);
+    const parent = application.getActiveWindow();
+    if (parent && parent !== window) window.setTransientFor(parent);
+    window.present();
+    return window;
+};
@@ -0,0 +1,86 @@
+import { AlertDialog } from "../generated/adw/alert-dialog.js";
+import type { ResponseAppearance } from "../generated/adw/enums.js";
+import type { Widget } from "../generated/gtk/widget.js";
+
+/** A button offered by {@link showAlertDialog}. */
+export type AlertResponse = {
+    /** Response ID the promise resolves with when the button is clicked. */
+    id: string;
+    label: string;
+    /** Styling of the button, such as suggested or destructive. */
+    appearance?: ResponseAppearance;
+    /** Whether the button can be clicked. Defaults to true. */
+    enabled?: boolean;
+};
+
+/** Options for {@link showAlertDialog}. */
+export type AlertDialogOptions = {
+    heading?: string;
+    body?: string;
+    /** Interpret `heading` and `body` as Pango markup. */
+    useMarkup?: boolean;
+    /** Buttons, in display order. */
+    responses: readonly AlertResponse[];
+    /** Response activated by pressing Enter. */
+    defaultResponse?: string;
+    /**
+     * Response the promise resolves with when the dialog is dismissed
+     * without clicking a button (Escape, or closing the window). Defaults
+     * to `"close"`.
+     */
+    closeResponse?: string;
+};
+
+/**
+ * Dialogs presented and not yet answered. Holding them here keeps each
+ * wrapper, and the response handler connected through it, alive until the
+ * user responds.
+ */
+const pendingDialogs = new Set<AlertDialog>();
+
+/**
+ * Presents an `AdwAlertDialog` and resolves with the ID of the response
+ * the user chose.
+ *
+ * @param parent - Widget the dialog is presented over, or null for none
+ * @param options - Content and buttons of the dialog
+ * @returns The chosen response ID, or `closeResponse` if the dialog was dismissed
+ *
+ * @example
+ * ```tsx
+ * const response = await showAlertDialog(window, {
+ *     heading: "Discard changes?",
+ *     responses: [
+ *         { id: "cancel", label: "Cancel" },
+ *         { id: "discard", label: "Discard", appearance: Adw.ResponseAppearance.DESTRUCTIVE },
+ *     ],
+ *     defaultResponse: "cancel",
+ *     closeResponse: "cancel",
+ * });
+ * ```
+ */
+export const showAlertDialog = (parent: Widget | null, options: AlertDialogOptions): Promise<string> => {
+    const dialog = new AlertDialog(options.heading ?? null, options.body ?? null);
+    if (options.useMarkup) {
+        dialog.setHeadingUseMarkup(true);
+        dialog.setBodyUseMarkup(true);
+    }
+
+    for (const response of options.responses) {
+        dialog.addResponse(response.id, response.label);
+        if (response.appearance !== undefined) dialog.setResponseAppearance(response.id, response.appearance);
+        if (response.enabled === false) dialog.setResponseEnabled(response.id, false);
+    }
+    if (options.defaultResponse !== undefined) dialog.setDefaultResponse(options.defaultResponse);
+    dialog.setCloseResponse(options.closeResponse ?? "close");
+
+    return new Promise((resolve) => {
+        const handlerId = dialog.connect("response", (_dialog: AlertDialog, response: string) => {
+            dialog.disconnect(handlerId);
+            pendingDialogs.delete(dialog);
+            resolve(response);
+        });
+        pendingDialogs.add(dialog);
+        dialog.present(parent);
+    });
+};
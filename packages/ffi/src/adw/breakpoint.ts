@@ -0,0 +1,74 @@
+import type { NativeHandle } from "@gtkx/native";
+import { Breakpoint } from "../generated/adw/breakpoint.js";
+import { BreakpointCondition } from "../generated/adw/breakpoint-condition.js";
+import { t } from "../native.js";
+import { getNativeObject } from "../registry.js";
+
+const LIB = "libadwaita-1.so.0";
+
+const CONDITION = t.boxed("AdwBreakpointCondition", "full", LIB, "adw_breakpoint_condition_get_type");
+
+const parseCondition = t.fn(LIB, "adw_breakpoint_condition_parse", [{ type: t.string("borrowed") }], CONDITION);
+
+/** Handlers for {@link connectBreakpoint}. */
+export type BreakpointHandlers = {
+    /** Called when the breakpoint's condition starts to match. */
+    apply?: () => void;
+    /** Called when the breakpoint's condition stops matching. */
+    unapply?: () => void;
+};
+
+/**
+ * Parses a breakpoint condition string.
+ *
+ * Accepts the syntax of `adw_breakpoint_condition_parse`: length and
+ * aspect-ratio comparisons such as `"max-width: 400sp"` or
+ * `"min-aspect-ratio: 4/3"`, combined with `and`, `or` and parentheses.
+ *
+ * @param condition - The condition string
+ * @returns The parsed condition
+ * @throws if the string is not a valid condition
+ */
+export const parseBreakpointCondition = (condition: string): BreakpointCondition => {
+    const handle = parseCondition(condition) as NativeHandle | null;
+    if (!handle) {
+        throw new Error(`Invalid breakpoint condition '${condition}'`);
+    }
+    return getNativeObject(handle, BreakpointCondition);
+};
+
+/**
+ * Creates a breakpoint from a condition string.
+ *
+ * @param condition - The condition string, as accepted by {@link parseBreakpointCondition}
+ * @returns A breakpoint ready to be added to an `AdwBreakpointBin` or window
+ * @throws if the string is not a valid condition
+ */
+export const createBreakpoint = (condition: string): Breakpoint => new Breakpoint(parseBreakpointCondition(condition));
+
+/**
+ * Replaces a breakpoint's condition with one parsed from a string.
+ *
+ * @param breakpoint - The breakpoint to update
+ * @param condition - The condition string
+ * @throws if the string is not a valid condition
+ */
+export const setBreakpointCondition = (breakpoint: Breakpoint, condition: string): void => {
+    breakpoint.setCondition(parseBreakpointCondition(condition));
+};
+
+/**
+ * Connects handlers to a breakpoint's `apply` and `unapply` signals.
+ *
+ * @param breakpoint - The breakpoint to observe
+ * @param handlers - Handlers to connect; omitted signals are left alone
+ * @returns The signal handler IDs, for use with `disconnect`
+ */
+export const connectBreakpoint = (breakpoint: Breakpoint, handlers: BreakpointHandlers): number[] => {
+    const ids: number[] = [];
+    for (const signal of ["apply", "unapply"] as const) {
+        const handler = handlers[signal];
+        if (handler) ids.push(breakpoint.connect(signal, () => handler()));
+    }
+    return ids;
+};
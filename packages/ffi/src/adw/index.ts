@@ -0,0 +1,7 @@
+export * from "../generated/adw/index.js";
+export * from "./navigation-view.js";
+export * from "./alert-dialog.js";
+export * from "./breakpoint.js";
+export * from "./toast.js";
+export * from "./style-manager.js";
+export * from "./about.js";
@@ -0,0 +1,139 @@
+import { NavigationPage } from "../generated/adw/navigation-page.js";
+import type { NavigationView } from "../generated/adw/navigation-view.js";
+
+/** Transition used when a navigation change is applied. */
+export type NavigationTransition = {
+    /** Whether to animate. Defaults to the view's `animate-transitions`. */
+    animate?: boolean;
+};
+
+/** How the navigation stack changed. */
+export type NavigationAction = "push" | "pop" | "replace";
+
+/** Payload delivered by {@link connectVisiblePageChanged}. */
+export type VisiblePageChangedEvent = {
+    action: NavigationAction;
+    /** The page now visible, or `null` if the stack is empty. */
+    page: NavigationPage | null;
+    /** Tags of the pages on the navigation stack, bottom first. */
+    history: string[];
+};
+
+const withTransition = <T>(view: NavigationView, transition: NavigationTransition | undefined, fn: () => T): T => {
+    if (transition?.animate === undefined) return fn();
+
+    const previous = view.getAnimateTransitions();
+    view.setAnimateTransitions(transition.animate);
+    try {
+        return fn();
+    } finally {
+        view.setAnimateTransitions(previous);
+    }
+};
+
+/**
+ * Returns the tags of the pages on the navigation stack, bottom first.
+ * Pages without a tag are skipped.
+ *
+ * @param view - The navigation view
+ */
+export const getNavigationHistory = (view: NavigationView): string[] => {
+    const stack = view.getNavigationStack();
+    const history: string[] = [];
+    const nItems = stack.getNItems();
+
+    for (let i = 0; i < nItems; i++) {
+        const page = stack.getObject(i);
+        if (!(page instanceof NavigationPage)) continue;
+        const tag = page.getTag();
+        if (tag) history.push(tag);
+    }
+
+    return history;
+};
+
+/**
+ * Pushes a page, or the page registered under a tag, onto the navigation stack.
+ *
+ * @param view - The navigation view
+ * @param page - The page, or the tag of a page added with `add`
+ * @param transition - Transition to use
+ */
+export const pushNavigationPage = (
+    view: NavigationView,
+    page: NavigationPage | string,
+    transition?: NavigationTransition,
+): void => {
+    withTransition(view, transition, () => {
+        if (typeof page === "string") {
+            view.pushByTag(page);
+        } else {
+            view.push(page);
+        }
+    });
+};
+
+/**
+ * Pops the visible page, or every page above the one with `toTag`.
+ *
+ * @param view - The navigation view
+ * @param options - Target tag and transition
+ * @returns Whether any page was popped
+ */
+export const popNavigationPage = (
+    view: NavigationView,
+    options: NavigationTransition & { toTag?: string } = {},
+): boolean =>
+    withTransition(view, options, () => (options.toTag === undefined ? view.pop() : view.popToTag(options.toTag)));
+
+/**
+ * Replaces the whole navigation stack; the last page becomes visible.
+ *
+ * @param view - The navigation view
+ * @param pages - The new stack, bottom first, as pages or tags
+ * @param transition - Transition to use
+ */
+export const replaceNavigationPages = (
+    view: NavigationView,
+    pages: readonly (NavigationPage | string)[],
+    transition?: NavigationTransition,
+): void => {
+    withTransition(view, transition, () => {
+        if (pages.every((page) => typeof page === "string")) {
+            view.replaceWithTags(pages as string[]);
+            return;
+        }
+        view.replace(
+            pages.map((page) => {
+                if (typeof page !== "string") return page;
+                const found = view.findPage(page);
+                if (!found) throw new Error(`NavigationView has no page tagged '${page}'`);
+                return found;
+            }),
+        );
+    });
+};
+
+/**
+ * Connects a handler that runs once per navigation change.
+ *
+ * Collapses the `pushed`, `popped` and `replaced` signals into one typed
+ * event carrying the visible page and the resulting history.
+ *
+ * @param view - The navigation view to observe
+ * @param handler - Called after each change
+ * @returns The signal handler IDs, for use with `disconnect`
+ */
+export const connectVisiblePageChanged = (
+    view: NavigationView,
+    handler: (event: VisiblePageChangedEvent) => void,
+): number[] => {
+    const emit = (action: NavigationAction) => () =>
+        handler({ action, page: view.getVisiblePage(), history: getNavigationHistory(view) });
+
+    return [
+        view.connect("pushed", emit("push")),
+        view.connect("popped", emit("pop")),
+        view.connect("replaced", emit("replace")),
+    ];
+};
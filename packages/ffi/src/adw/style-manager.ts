@@ -0,0 +1,63 @@
+import type { AccentColor, ColorScheme } from "../generated/adw/enums.js";
+import { StyleManager } from "../generated/adw/style-manager.js";
+import { type Rgba, toRgba } from "../gdk/rgba.js";
+
+export type { Rgba };
+
+/** Snapshot of the application's appearance delivered by {@link connectStyleChanged}. */
+export type StyleState = {
+    /** Color scheme requested by the application. */
+    colorScheme: ColorScheme;
+    /** Whether the application currently uses a dark appearance. */
+    dark: boolean;
+    highContrast: boolean;
+    /** System accent color. */
+    accentColor: AccentColor;
+    /** System accent color, resolved for the current appearance. */
+    accentRgba: Rgba;
+};
+
+/** Properties whose changes {@link connectStyleChanged} reports. */
+const STYLE_PROPERTIES = ["color-scheme", "dark", "high-contrast", "accent-color"] as const;
+
+const styleManager = (): StyleManager => StyleManager.getDefault();
+
+/**
+ * Sets the color scheme the application prefers.
+ *
+ * @param scheme - The color scheme, such as `Adw.ColorScheme.PREFER_DARK`
+ */
+export const setColorScheme = (scheme: ColorScheme): void => {
+    styleManager().setColorScheme(scheme);
+};
+
+/** Returns the color scheme the application prefers. */
+export const getColorScheme = (): ColorScheme => styleManager().getColorScheme();
+
+/** Returns the system accent color, resolved to an RGBA value for the current appearance. */
+export const getAccentRgba = (): Rgba => toRgba(styleManager().getAccentColorRgba());
+
+/** Returns the application's current appearance. */
+export const getStyleState = (): StyleState => {
+    const manager = styleManager();
+    return {
+        colorScheme: manager.getColorScheme(),
+        dark: manager.getDark(),
+        highContrast: manager.getHighContrast(),
+        accentColor: manager.getAccentColor(),
+        accentRgba: getAccentRgba(),
+    };
+};
+
+/**
+ * Connects a handler that runs whenever the application's appearance
+ * changes: its color scheme, dark or high-contrast state, or the system
+ * accent color.
+ *
+ * @param handler - Called with the new appearance
+ * @returns The signal handler IDs, for use with `disconnect` on `Adw.StyleManager.getDefault()`
+ */
+export const connectStyleChanged = (handler: (state: StyleState) => void): number[] => {
+    const manager = styleManager();
+    return STYLE_PROPERTIES.map((property) => manager.connect(`notify::${property}`, () => handler(getStyleState())));
+};
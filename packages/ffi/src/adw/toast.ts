@@ -0,0 +1,59 @@
+import type { ToastPriority } from "../generated/adw/enums.js";
+import { Toast } from "../generated/adw/toast.js";
+import type { ToastOverlay } from "../generated/adw/toast-overlay.js";
+
+/** Options for {@link showToast}. */
+export type ShowToastOptions = {
+    title: string;
+    /** Interpret `title` as Pango markup. Defaults to true, as in `AdwToast`. */
+    useMarkup?: boolean;
+    /** Label of the toast's button. No button is shown when omitted. */
+    button?: string;
+    /** Seconds until the toast is dismissed; 0 keeps it until dismissed explicitly. Defaults to 5. */
+    timeout?: number;
+    priority?: ToastPriority;
+    /** Called when the toast's button is clicked. */
+    actionCallback?: () => void;
+    /** Called once the toast has been dismissed, for any reason. */
+    onDismissed?: () => void;
+};
+
+/**
+ * Toasts shown and not yet dismissed. Holding them here keeps each wrapper,
+ * and the handlers connected through it, alive while the toast is visible.
+ */
+const visibleToasts = new Set<Toast>();
+
+/**
+ * Shows a toast on an overlay.
+ *
+ * Builds the `AdwToast`, connects `button-clicked` and `dismissed`, and
+ * keeps the toast alive until it is dismissed.
+ *
+ * @param overlay - The overlay to show the toast on
+ * @param options - Content, timing and handlers of the toast
+ * @returns The toast, which can be dismissed early with `dismiss()`
+ *
+ * @example
+ * ```tsx
+ * showToast(overlay, { title: "File deleted", button: "Undo", actionCallback: restore });
+ * ```
+ */
+export const showToast = (overlay: ToastOverlay, options: ShowToastOptions): Toast => {
+    const toast = new Toast(options.title);
+    if (options.useMarkup !== undefined) toast.setUseMarkup(options.useMarkup);
+    if (options.button !== undefined) toast.setButtonLabel(options.button);
+    if (options.timeout !== undefined) toast.setTimeout(options.timeout);
+    if (options.priority !== undefined) toast.setPriority(options.priority);
+
+    const { actionCallback, onDismissed } = options;
+    if (actionCallback) toast.connect("button-clicked", () => actionCallback());
+    toast.connect("dismissed", () => {
+        visibleToasts.delete(toast);
+        onDismissed?.();
+    });
+
+    visibleToasts.add(toast);
+    overlay.addToast(toast);
+    return toast;
+};
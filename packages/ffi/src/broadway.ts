@@ -0,0 +1,115 @@
+import { type ChildProcess, spawn } from "node:child_process";
+import { existsSync } from "node:fs";
+import { homedir } from "node:os";
+import { join } from "node:path";
+
+const BASE_PORT = 8080;
+const MAX_DISPLAY = 64;
+const POLL_INTERVAL = 50;
+
+/** Options for {@link startBroadway}. */
+export type BroadwayOptions = {
+    /** Broadway display number. Defaults to the first one not in use. */
+    display?: number;
+    /** HTTP port to serve on. Defaults to 8080 plus the display number. */
+    port?: number;
+    /** Address to listen on. Defaults to all interfaces. */
+    address?: string;
+    /** How long to wait for the daemon to start, in milliseconds. Defaults to 5000. */
+    timeout?: number;
+    /** Path to the daemon executable. Defaults to `gtk4-broadwayd` on `PATH`. */
+    executable?: string;
+};
+
+/** A running Broadway daemon, from {@link startBroadway}. */
+export type BroadwaySession = {
+    display: number;
+    port: number;
+    /** URL to open in a browser to see the app. */
+    url: string;
+    /** Stops the daemon. Windows still open in it stop being served. */
+    stop: () => void;
+};
+
+const runtimeDir = (): string => process.env.XDG_RUNTIME_DIR ?? join(homedir(), ".cache");
+
+const socketPath = (display: number): string => join(runtimeDir(), `broadway${display + 1}.socket`);
+
+const findFreeDisplay = (): number => {
+    for (let display = 0; display < MAX_DISPLAY; display++) {
+        if (!existsSync(socketPath(display))) return display;
+    }
+    throw new Error("No free Broadway display");
+};
+
+const waitForSocket = (daemon: ChildProcess, path: string, timeout: number): Promise<void> =>
+    new Promise((resolve, reject) => {
+        const deadline = Date.now() + timeout;
+        let timer: ReturnType<typeof setTimeout> | undefined;
+
+        const handleExit = (code: number | null) => {
+            clearTimeout(timer);
+            reject(new Error(`gtk4-broadwayd exited with code ${code} before it was ready`));
+        };
+
+        const check = () => {
+            if (existsSync(path)) {
+                daemon.off("exit", handleExit);
+                resolve();
+            } else if (Date.now() >= deadline) {
+                daemon.off("exit", handleExit);
+                daemon.kill();
+                reject(new Error(`gtk4-broadwayd did not start within ${timeout}ms`));
+            } else {
+                timer = setTimeout(check, POLL_INTERVAL);
+            }
+        };
+
+        daemon.once("exit", handleExit);
+        check();
+    });
+
+/**
+ * Starts a Broadway daemon and points GTK at it, so the app renders to a
+ * web page instead of a local display, for demos and remote testing.
+ *
+ * Must be called before `initRuntime`, which should then select the
+ * Broadway backend. The daemon is stopped when the process exits.
+ *
+ * @param options - Display, port and address to serve on
+ * @returns The running session
+ * @throws If the daemon cannot be started or does not become ready in time
+ *
+ * @example
+ * ```tsx
+ * const session = await startBroadway({ port: 8085 });
+ * initRuntime({ backend: "broadway" });
+ * console.log(`Open ${session.url} in a browser`);
+ * ```
+ */
+export const startBroadway = async (options: BroadwayOptions = {}): Promise<BroadwaySession> => {
+    const display = options.display ?? findFreeDisplay();
+    const port = options.port ?? BASE_PORT + display;
+    const args = ["--port", String(port)];
+    if (options.address) args.push("--address", options.address);
+    args.push(`:${display}`);
+
+    const daemon = spawn(options.executable ?? "gtk4-broadwayd", args, { stdio: "ignore" });
+    await new Promise<void>((resolve, reject) => {
+        daemon.once("spawn", resolve);
+        daemon.once("error", reject);
+    });
+    await waitForSocket(daemon, socketPath(display), options.timeout ?? 5000);
+
+    const stop = () => {
+        process.off("exit", stop);
+        daemon.kill();
+    };
+    process.on("exit", stop);
+    daemon.unref();
+
+    process.env.BROADWAY_DISPLAY = `:${display}`;
+
+    const host = !options.address || options.address === "0.0.0.0" ? "localhost" : options.address;
+    return { display, port, url: `http://${host}:${port}/`, stop };
+};
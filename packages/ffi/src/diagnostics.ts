@@ -0,0 +1,157 @@
+import {
+    getMajorVersion as getAdwMajorVersion,
+    getMicroVersion as getAdwMicroVersion,
+    getMinorVersion as getAdwMinorVersion,
+} from "./generated/adw/functions.js";
+import { Display } from "./generated/gdk/display.js";
+import type { Monitor } from "./generated/gdk/monitor.js";
+import { checkVersion } from "./generated/glib/functions.js";
+import { typeNameFromInstance } from "./generated/gobject/functions.js";
+import { TypeInstance } from "./generated/gobject/type-instance.js";
+import { getMajorVersion, getMicroVersion, getMinorVersion } from "./generated/gtk/functions.js";
+import { Settings } from "./generated/gtk/settings.js";
+import { Window } from "./generated/gtk/window.js";
+import { type Backend, getDisplayBackend, isStarted } from "./lifecycle.js";
+
+/** Environment variables that change how GTK renders or behaves. */
+const DIAGNOSTIC_ENV = [
+    "GDK_BACKEND",
+    "GDK_DEBUG",
+    "GDK_DISABLE",
+    "GDK_SCALE",
+    "GDK_DPI_SCALE",
+    "GSK_RENDERER",
+    "GSK_DEBUG",
+    "GTK_DEBUG",
+    "GTK_THEME",
+    "GTK_IM_MODULE",
+    "GTK_A11Y",
+    "XDG_SESSION_TYPE",
+    "XDG_CURRENT_DESKTOP",
+    "WAYLAND_DISPLAY",
+    "DISPLAY",
+    "LANG",
+    "LC_ALL",
+];
+
+/** A snapshot of the GTK environment, from {@link diagnostics}. */
+export type Diagnostics = {
+    /** Running GTK version, such as `"4.16.3"`. */
+    gtkVersion: string;
+    /** Running GLib version. */
+    glibVersion: string;
+    /** Running libadwaita version, or null if it is not available. */
+    adwaitaVersion: string | null;
+    /** GDK backend of the default display. */
+    backend: Backend | null;
+    /** Type name of the GSK renderer windows use, such as `"GskNglRenderer"`. */
+    renderer: string | null;
+    /** GTK theme name. */
+    themeName: string | null;
+    /** Icon theme name. */
+    iconThemeName: string | null;
+    /** Input method module, or null when GTK picks one automatically. */
+    imModule: string | null;
+    /** Scale factor of each connected monitor. */
+    scaleFactors: number[];
+    /** Set environment variables that affect GTK, such as `GSK_RENDERER`. */
+    env: Record<string, string>;
+};
+
+const formatVersion = (major: number, minor: number, micro: number): string => `${major}.${minor}.${micro}`;
+
+/** Returns the highest `n` from 0 to 1023 that `satisfied` accepts, which must hold for all lower values. */
+const highestSatisfied = (satisfied: (n: number) => boolean): number => {
+    let low = 0;
+    let high = 1023;
+    while (low < high) {
+        const mid = Math.ceil((low + high) / 2);
+        if (satisfied(mid)) {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    return low;
+};
+
+// GLib only exposes its runtime version as exported variables, so it is
+// found by asking glib_check_version which versions are satisfied.
+const getGlibVersion = (): string => {
+    const minor = highestSatisfied((n) => checkVersion(2, n, 0) === null);
+    const micro = highestSatisfied((n) => checkVersion(2, minor, n) === null);
+    return formatVersion(2, minor, micro);
+};
+
+const getAdwaitaVersion = (): string | null => {
+    try {
+        return formatVersion(getAdwMajorVersion(), getAdwMinorVersion(), getAdwMicroVersion());
+    } catch {
+        return null;
+    }
+};
+
+// The renderer is only chosen once a surface exists, so a window is
+// realized, without being shown, to ask for it.
+const getRendererName = (): string | null => {
+    const window = new Window();
+    try {
+        window.realize();
+        const renderer = window.getRenderer();
+        return renderer ? typeNameFromInstance(new TypeInstance(renderer.handle)) : null;
+    } catch {
+        return null;
+    } finally {
+        window.destroy();
+    }
+};
+
+const getScaleFactors = (display: Display): number[] => {
+    const monitors = display.getMonitors();
+    const scales: number[] = [];
+    for (let i = 0; i < monitors.getNItems(); i++) {
+        const monitor = monitors.getItem(i) as Monitor | null;
+        if (monitor) scales.push(monitor.getScale());
+    }
+    return scales;
+};
+
+/**
+ * Gathers versions, the display backend and renderer, theme, input method,
+ * monitor scales and relevant environment variables into one object.
+ *
+ * Useful to attach to bug reports, and to adapt behavior to the
+ * environment. Display related fields are null or empty before
+ * `initRuntime`.
+ *
+ * @returns The environment snapshot
+ *
+ * @example
+ * ```tsx
+ * const { renderer, scaleFactors } = diagnostics();
+ * console.log(JSON.stringify(diagnostics(), null, 2));
+ * ```
+ */
+export const diagnostics = (): Diagnostics => {
+    const env: Record<string, string> = {};
+    for (const name of DIAGNOSTIC_ENV) {
+        const value = process.env[name];
+        if (value !== undefined) env[name] = value;
+    }
+
+    const display = isStarted() ? Display.getDefault() : null;
+    const settings = display ? Settings.getDefault() : null;
+
+    return {
+        gtkVersion: formatVersion(getMajorVersion(), getMinorVersion(), getMicroVersion()),
+        glibVersion: getGlibVersion(),
+        adwaitaVersion: getAdwaitaVersion(),
+        backend: display ? getDisplayBackend() : null,
+        renderer: display ? getRendererName() : null,
+        themeName: settings?.gtkThemeName ?? null,
+        iconThemeName: settings?.gtkIconThemeName ?? null,
+        imModule: settings?.gtkImModule || null,
+        scaleFactors: display ? getScaleFactors(display) : [],
+        env,
+    };
+};
@@ -0,0 +1,61 @@
+import { decodeEvent as nativeDecodeEvent } from "@gtkx/native";
+import { Device } from "../generated/gdk/device.js";
+import type { EventType, ModifierType } from "../generated/gdk/enums.js";
+import type { Event } from "../generated/gdk/event.js";
+import { getNativeObject } from "../registry.js";
+
+/**
+ * The common fields of a `GdkEvent`, from {@link decodeEvent}. Fields the
+ * event's type does not have are null.
+ */
+export type EventDetails = {
+    /** What kind of event this is. */
+    type: EventType;
+    /** Timestamp in milliseconds, for APIs such as `presentWithTime`. */
+    time: number;
+    /** Modifier keys and mouse buttons held during the event. */
+    modifiers: ModifierType;
+    /** Horizontal position in surface coordinates, if the event has one. */
+    x: number | null;
+    /** Vertical position in surface coordinates, if the event has one. */
+    y: number | null;
+    /** The device that caused the event. */
+    device: Device | null;
+    /** Button number of a button press or release, 1 being the primary button. */
+    button: number | null;
+    /** Keyval of a key press or release, to compare with `Gdk.KEY_*`. */
+    keyval: number | null;
+    /** Hardware keycode of a key press or release. */
+    keycode: number | null;
+    /** Horizontal delta of a smooth scroll event; 0 for a discrete one. */
+    deltaX: number | null;
+    /** Vertical delta of a smooth scroll event; 0 for a discrete one. */
+    deltaY: number | null;
+};
+
+/**
+ * Reads the type, position, device, button or key, modifiers and timestamp
+ * of an event at once.
+ *
+ * Each getter of `Gdk.Event` is a separate call to the `GLib` thread; this
+ * reads them all in one, which matters in handlers for frequent events
+ * such as motion or key presses.
+ *
+ * @param event - An event, such as from `controller.getCurrentEvent()`
+ * @returns The event's fields
+ *
+ * @example
+ * ```tsx
+ * const { keyval, modifiers } = decodeEvent(controller.getCurrentEvent()!);
+ * if (keyval === Gdk.KEY_s && modifiers & Gdk.ModifierType.CONTROL_MASK) save();
+ * ```
+ */
+export const decodeEvent = (event: Event): EventDetails => {
+    const decoded = nativeDecodeEvent(event.handle);
+    return {
+        ...decoded,
+        type: decoded.type as EventType,
+        modifiers: decoded.modifiers as ModifierType,
+        device: getNativeObject(decoded.device, Device),
+    };
+};
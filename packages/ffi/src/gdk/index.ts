@@ -0,0 +1,4 @@
+export * from "../generated/gdk/index.js";
+export * from "./event.js";
+export * from "./keys.js";
+export * from "./rgba.js";
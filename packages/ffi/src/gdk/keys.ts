@@ -0,0 +1,125 @@
+import { createRef } from "@gtkx/native";
+import { KEY_VoidSymbol } from "../generated/gdk/constants.js";
+import { Display } from "../generated/gdk/display.js";
+import type { ModifierType } from "../generated/gdk/enums.js";
+import {
+    keyvalFromName,
+    keyvalName,
+    keyvalToLower,
+    keyvalToUnicode,
+    keyvalToUpper,
+    unicodeToKeyval,
+} from "../generated/gdk/functions.js";
+import { t } from "../native.js";
+
+const LIB = "libgtk-4.so.1";
+
+const translateKey = t.fn(
+    LIB,
+    "gdk_display_translate_key",
+    [
+        { type: t.object("borrowed") },
+        { type: t.uint32 },
+        { type: t.uint32 },
+        { type: t.int32 },
+        { type: t.ref(t.uint32) },
+        { type: t.ref(t.int32) },
+        { type: t.ref(t.int32) },
+        { type: t.ref(t.uint32) },
+    ],
+    t.boolean,
+);
+
+/** The result of translating a hardware keycode, from {@link keys}. */
+export type KeyTranslation = {
+    /** Keyval the key produces, to compare with `Gdk.KEY_*`. */
+    keyval: number;
+    /** Keyboard group, or layout, used for the translation. */
+    group: number;
+    /** Shift level the modifiers selected. */
+    level: number;
+    /**
+     * Modifiers that went into choosing the keyval, such as Shift for `plus`
+     * on a US layout. Mask them out of the event's modifiers before
+     * comparing them with a shortcut's.
+     */
+    consumed: ModifierType;
+};
+
+/**
+ * Conversions between keyvals, key names, characters and hardware
+ * keycodes, matching how GTK itself interprets keys.
+ *
+ * @example
+ * ```tsx
+ * const { keyval, consumed } = keys.translate(keycode, modifiers, group)!;
+ * const shortcut = `${modifiers & ~consumed}+${keys.name(keys.toLower(keyval))}`;
+ * ```
+ */
+export const keys = {
+    /**
+     * Returns the name of a keyval, as used in accelerators, such as
+     * `"Return"` or `"a"`, or null if it has none.
+     */
+    name: (keyval: number): string | null => keyvalName(keyval),
+
+    /**
+     * Returns the keyval of a key name such as `"Return"`, or null if the
+     * name is not known.
+     */
+    fromName: (name: string): number | null => {
+        const keyval = keyvalFromName(name);
+        return keyval === KEY_VoidSymbol ? null : keyval;
+    },
+
+    /** Returns the character a keyval types, or null if it types none. */
+    toUnicode: (keyval: number): string | null => {
+        const codePoint = keyvalToUnicode(keyval);
+        return codePoint === 0 ? null : String.fromCodePoint(codePoint);
+    },
+
+    /**
+     * Returns the keyval that types the first character of `text`. Characters
+     * without a dedicated keyval map to a Unicode keyval.
+     */
+    fromUnicode: (text: string): number => unicodeToKeyval(text.codePointAt(0) ?? 0),
+
+    /** Returns the lowercase form of a keyval, or the keyval if it has none. */
+    toLower: (keyval: number): number => keyvalToLower(keyval),
+
+    /** Returns the uppercase form of a keyval, or the keyval if it has none. */
+    toUpper: (keyval: number): number => keyvalToUpper(keyval),
+
+    /**
+     * Translates a hardware keycode with modifiers and a keyboard group into
+     * a keyval through the keymap of a display.
+     *
+     * @param keycode - Hardware keycode, such as from a key event
+     * @param state - Modifiers held, which select the shift level
+     * @param group - Active keyboard group
+     * @param display - Display whose keymap to use. Defaults to the default display.
+     * @returns The translation, or null if the keycode has no keyval
+     */
+    translate: (
+        keycode: number,
+        state: ModifierType,
+        group = 0,
+        display: Display | null = Display.getDefault(),
+    ): KeyTranslation | null => {
+        if (!display) return null;
+
+        const keyval = createRef(0);
+        const effectiveGroup = createRef(0);
+        const level = createRef(0);
+        const consumed = createRef(0);
+        const found = translateKey(display.handle, keycode, state, group, keyval, effectiveGroup, level, consumed);
+        if (!found) return null;
+
+        return {
+            keyval: keyval.value,
+            group: effectiveGroup.value,
+            level: level.value,
+            consumed: consumed.value as ModifierType,
+        };
+    },
+};
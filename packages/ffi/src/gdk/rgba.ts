@@ -0,0 +1,41 @@
+import { RGBA } from "../generated/gdk/rgba.js";
+
+/** An RGBA color with channels in the 0–1 range. */
+export type Rgba = {
+    red: number;
+    green: number;
+    blue: number;
+    alpha: number;
+};
+
+/**
+ * Copies a `GdkRGBA` into a plain object, which stays valid after the
+ * native value is freed and can be compared, serialized or stored in state.
+ *
+ * @param rgba - The color to copy
+ */
+export const toRgba = (rgba: RGBA): Rgba => ({
+    red: rgba.red,
+    green: rgba.green,
+    blue: rgba.blue,
+    alpha: rgba.alpha,
+});
+
+/**
+ * Creates a `GdkRGBA` from a plain color or a CSS color string, such as
+ * `"#3584e4"` or `"rgba(0, 0, 0, 0.5)"`.
+ *
+ * @param color - Channels, or a string accepted by `gdk_rgba_parse`
+ * @throws Error if the string is not a valid color
+ */
+export const fromRgba = (color: Rgba | string): RGBA => {
+    if (typeof color !== "string") {
+        return new RGBA({ red: color.red, green: color.green, blue: color.blue, alpha: color.alpha });
+    }
+
+    const rgba = new RGBA();
+    if (!rgba.parse(color)) {
+        throw new Error(`Invalid color '${color}'`);
+    }
+    return rgba;
+};
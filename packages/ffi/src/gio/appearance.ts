@@ -0,0 +1,113 @@
+import type { DBusConnection } from "../generated/gio/dbus-connection.js";
+import { packVariant } from "../glib/variant.js";
+import { getBus } from "./dbus.js";
+import { callPortalMethod, subscribePortalSignal } from "./portal.js";
+
+const INTERFACE = "org.freedesktop.portal.Settings";
+const APPEARANCE_NAMESPACE = "org.freedesktop.appearance";
+
+/**
+ * Color scheme the user prefers. `"default"` means no preference, which
+ * applications usually treat as light.
+ */
+export type ColorScheme = "default" | "dark" | "light";
+
+/** Contrast the user prefers. */
+export type Contrast = "default" | "high";
+
+/** Cross-desktop appearance settings, from {@link readAppearance}. */
+export type Appearance = {
+    colorScheme: ColorScheme;
+    contrast: Contrast;
+};
+
+const COLOR_SCHEMES: readonly ColorScheme[] = ["default", "dark", "light"];
+
+const toColorScheme = (value: unknown): ColorScheme => COLOR_SCHEMES[value as number] ?? "default";
+
+const toContrast = (value: unknown): Contrast => (value === 1 ? "high" : "default");
+
+const toAppearance = (settings: Record<string, unknown>): Appearance => ({
+    colorScheme: toColorScheme(settings["color-scheme"]),
+    contrast: toContrast(settings.contrast),
+});
+
+/**
+ * Reads the `org.freedesktop.appearance` settings through the Settings XDG
+ * desktop portal, which every desktop with a portal backend provides, not
+ * only GNOME.
+ *
+ * Settings the backend does not know are reported as `"default"`.
+ *
+ * @param connection - Session bus connection. Defaults to the shared session bus.
+ * @returns The color scheme and contrast preferences
+ * @throws NativeError if the portal is not available
+ *
+ * @example
+ * ```tsx
+ * const { colorScheme } = await readAppearance();
+ * const dark = colorScheme === "dark";
+ * ```
+ */
+export const readAppearance = async (connection: DBusConnection = getBus()): Promise<Appearance> => {
+    const [namespaces] = await callPortalMethod(
+        connection,
+        INTERFACE,
+        "ReadAll",
+        packVariant("(as)", [[APPEARANCE_NAMESPACE]]),
+    );
+    const settings = (namespaces as Record<string, Record<string, unknown>>)[APPEARANCE_NAMESPACE];
+    return toAppearance(settings ?? {});
+};
+
+/**
+ * Calls `handler` with the appearance settings once they are read, and
+ * again whenever the color scheme or contrast preference changes.
+ *
+ * Changes arrive through the portal's `SettingChanged` signal. When the
+ * portal is not available, `handler` is never called.
+ *
+ * @param handler - Called with the current settings
+ * @param connection - Session bus connection. Defaults to the shared session bus.
+ * @returns A function that stops watching
+ *
+ * @example
+ * ```tsx
+ * const stop = watchAppearance(({ colorScheme }) => {
+ *     styleManager.setColorScheme(colorScheme === "dark" ? Adw.ColorScheme.FORCE_DARK : Adw.ColorScheme.DEFAULT);
+ * });
+ * ```
+ */
+export const watchAppearance = (
+    handler: (appearance: Appearance) => void,
+    connection: DBusConnection = getBus(),
+): (() => void) => {
+    let current: Appearance | null = null;
+    let stopped = false;
+
+    const update = (next: Appearance) => {
+        if (stopped) return;
+        if (current?.colorScheme === next.colorScheme && current.contrast === next.contrast) return;
+        current = next;
+        handler(next);
+    };
+
+    const unsubscribe = subscribePortalSignal(connection, INTERFACE, "SettingChanged", ([namespace, key, value]) => {
+        if (namespace !== APPEARANCE_NAMESPACE) return;
+        const base = current ?? toAppearance({});
+        if (key === "color-scheme") update({ ...base, colorScheme: toColorScheme(value) });
+        if (key === "contrast") update({ ...base, contrast: toContrast(value) });
+    });
+
+    readAppearance(connection).then(
+        (appearance) => {
+            if (current === null) update(appearance);
+        },
+        () => {},
+    );
+
+    return () => {
+        stopped = true;
+        unsubscribe();
+    };
+};
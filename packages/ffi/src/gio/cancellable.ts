@@ -0,0 +1,26 @@
+import { Cancellable } from "../generated/gio/cancellable.js";
+
+/**
+ * Creates a `GCancellable` that is cancelled when an `AbortSignal` aborts,
+ * for passing web-style cancellation to GIO async methods.
+ *
+ * @param signal - The signal to follow, if any
+ * @returns A cancellable bound to the signal, or undefined when no signal is given
+ *
+ * @example
+ * ```tsx
+ * const controller = new AbortController();
+ * const file = await dialog.openAsync(window, cancellableFromSignal(controller.signal));
+ * ```
+ */
+export const cancellableFromSignal = (signal?: AbortSignal): Cancellable | undefined => {
+    if (!signal) return undefined;
+
+    const cancellable = new Cancellable();
+    if (signal.aborted) {
+        cancellable.cancel();
+    } else {
+        signal.addEventListener("abort", () => cancellable.cancel(), { once: true });
+    }
+    return cancellable;
+};
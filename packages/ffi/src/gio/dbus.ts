@@ -0,0 +1,436 @@
+import { createRef, type NativeHandle } from "@gtkx/native";
+import type { DBusConnection } from "../generated/gio/dbus-connection.js";
+import { BusType, DBusCallFlags } from "../generated/gio/enums.js";
+import { busGetSync } from "../generated/gio/functions.js";
+import { GError } from "../generated/glib/error.js";
+import { Variant } from "../generated/glib/variant.js";
+import { packVariant, unpackVariant } from "../glib/variant.js";
+import { NativeError, t } from "../native.js";
+import { getNativeObject } from "../registry.js";
+
+const LIB = "libgio-2.0.so.0";
+const LIB_GLIB = "libglib-2.0.so.0";
+const LIB_GOBJECT = "libgobject-2.0.so.0";
+
+const CONNECTION = t.object("borrowed");
+const INVOCATION = t.object("borrowed");
+/** The `g_dbus_method_invocation_return_*` functions take ownership of the invocation. */
+const OWNED_INVOCATION = t.object("full");
+const STRING = t.string("borrowed");
+const VARIANT = t.fundamental(LIB_GLIB, "g_variant_ref_sink", "g_variant_unref", "borrowed", "GVariant");
+const NODE_INFO = t.boxed("GDBusNodeInfo", "full", LIB, "g_dbus_node_info_get_type");
+const INTERFACE_INFO = t.boxed("GDBusInterfaceInfo", "borrowed", LIB, "g_dbus_interface_info_get_type");
+const GERROR_REF = t.ref(t.boxed("GError", "full", LIB_GLIB, "g_error_get_type"));
+
+const METHOD_CALL_CLOSURE = t.callback([CONNECTION, STRING, STRING, STRING, STRING, VARIANT, INVOCATION], t.void);
+const GET_PROPERTY_CLOSURE = t.callback([CONNECTION, STRING, STRING, STRING, STRING], VARIANT);
+const SET_PROPERTY_CLOSURE = t.callback([CONNECTION, STRING, STRING, STRING, STRING, VARIANT], t.boolean);
+
+const newNodeInfoForXml = t.fn(
+    LIB,
+    "g_dbus_node_info_new_for_xml",
+    [{ type: STRING }, { type: GERROR_REF }],
+    NODE_INFO,
+);
+const lookupInterface = t.fn(
+    LIB,
+    "g_dbus_node_info_lookup_interface",
+    [{ type: t.boxed("GDBusNodeInfo", "borrowed", LIB, "g_dbus_node_info_get_type") }, { type: STRING }],
+    INTERFACE_INFO,
+);
+const registerObject = t.fn(
+    LIB,
+    "g_dbus_connection_register_object_with_closures",
+    [
+        { type: CONNECTION },
+        { type: STRING },
+        { type: INTERFACE_INFO },
+        { type: METHOD_CALL_CLOSURE, optional: true },
+        { type: GET_PROPERTY_CLOSURE, optional: true },
+        { type: SET_PROPERTY_CLOSURE, optional: true },
+        { type: GERROR_REF },
+    ],
+    t.uint32,
+);
+const unregisterObject = t.fn(
+    LIB,
+    "g_dbus_connection_unregister_object",
+    [{ type: CONNECTION }, { type: t.uint32 }],
+    t.boolean,
+);
+const emitSignal = t.fn(
+    LIB,
+    "g_dbus_connection_emit_signal",
+    [
+        { type: CONNECTION },
+        { type: STRING, optional: true },
+        { type: STRING },
+        { type: STRING },
+        { type: STRING },
+        { type: VARIANT, optional: true },
+        { type: GERROR_REF },
+    ],
+    t.boolean,
+);
+const SIGNAL_CALLBACK = t.trampoline([CONNECTION, STRING, STRING, STRING, STRING, VARIANT, t.void], t.void, {
+    hasDestroy: true,
+    userDataIndex: 6,
+    scope: "notified",
+});
+
+const signalSubscribe = t.fn(
+    LIB,
+    "g_dbus_connection_signal_subscribe",
+    [
+        { type: CONNECTION },
+        { type: STRING, optional: true },
+        { type: STRING, optional: true },
+        { type: STRING, optional: true },
+        { type: STRING, optional: true },
+        { type: STRING, optional: true },
+        { type: t.uint32 },
+        { type: SIGNAL_CALLBACK },
+    ],
+    t.uint32,
+);
+const signalUnsubscribe = t.fn(
+    LIB,
+    "g_dbus_connection_signal_unsubscribe",
+    [{ type: CONNECTION }, { type: t.uint32 }],
+    t.void,
+);
+const returnValue = t.fn(
+    LIB,
+    "g_dbus_method_invocation_return_value",
+    [{ type: OWNED_INVOCATION }, { type: VARIANT, optional: true }],
+    t.void,
+);
+const returnDBusError = t.fn(
+    LIB,
+    "g_dbus_method_invocation_return_dbus_error",
+    [{ type: OWNED_INVOCATION }, { type: STRING }, { type: STRING }],
+    t.void,
+);
+
+/**
+ * Drops the reference to the invocation that the method call handler is
+ * given on top of the one held by its closure argument. Replies take a
+ * reference of their own, so this one is released once the call is answered.
+ */
+const releaseInvocation = t.fn(LIB_GOBJECT, "g_object_unref", [{ type: INVOCATION }], t.void);
+
+/** D-Bus error name used when a method handler throws. */
+export const DBUS_ERROR_FAILED = "org.freedesktop.DBus.Error.Failed";
+
+/** Details of an incoming method call or property access. */
+export type DBusCall = {
+    /** Unique bus name of the caller. */
+    sender: string;
+    objectPath: string;
+    interfaceName: string;
+    /** Name of the method or property. */
+    member: string;
+};
+
+/**
+ * Handles a method call. Receives the unpacked input arguments and returns
+ * the reply as a tuple variant (see {@link packVariant}), or nothing for an
+ * empty reply. Throwing, or rejecting, replies with a D-Bus error.
+ */
+export type DBusMethodHandler = (args: unknown[], call: DBusCall) => Variant | undefined | Promise<Variant | undefined>;
+
+/** Error thrown from a {@link DBusMethodHandler} to reply with a specific D-Bus error name. */
+export class DBusError extends Error {
+    readonly errorName: string;
+
+    /**
+     * @param errorName - D-Bus error name, such as `"org.freedesktop.DBus.Error.InvalidArgs"`
+     * @param message - Human-readable error message
+     */
+    constructor(errorName: string, message: string) {
+        super(message);
+        this.errorName = errorName;
+        this.name = "DBusError";
+    }
+}
+
+/** An interface to export with {@link exportDBusInterface}. */
+export type DBusInterfaceDefinition = {
+    /** Introspection XML containing the interface. */
+    xml: string;
+    /** Name of the interface within `xml`. */
+    name: string;
+    methods?: Record<string, DBusMethodHandler>;
+    /** Getters for readable properties, returning a variant of the property's type. */
+    properties?: Record<string, (call: DBusCall) => Variant>;
+    /** Setters for writable properties, receiving the unpacked value. */
+    setProperties?: Record<string, (value: unknown, call: DBusCall) => void>;
+};
+
+/** An interface exported with {@link exportDBusInterface}. */
+export type DBusExportedInterface = {
+    /** Registration ID returned by `g_dbus_connection_register_object`. */
+    readonly id: number;
+    /**
+     * Emits a signal of the interface.
+     *
+     * @param name - Signal name
+     * @param args - Signal arguments as a tuple variant, or null for none
+     */
+    emitSignal(name: string, args?: Variant | null): void;
+    /**
+     * Emits `org.freedesktop.DBus.Properties.PropertiesChanged` with the
+     * current values of the named properties.
+     */
+    emitPropertiesChanged(names: readonly string[]): void;
+    /** Removes the interface from the bus. */
+    unexport(): void;
+};
+
+const throwIfError = (error: { value: NativeHandle | null }): void => {
+    if (error.value !== null) {
+        throw new NativeError(getNativeObject(error.value, GError));
+    }
+};
+
+const replyWithError = (invocation: NativeHandle, error: unknown): void => {
+    if (error instanceof DBusError) {
+        returnDBusError(invocation, error.errorName, error.message);
+    } else {
+        returnDBusError(invocation, DBUS_ERROR_FAILED, error instanceof Error ? error.message : String(error));
+    }
+};
+
+/**
+ * Returns the shared connection to a message bus, connecting on first use.
+ *
+ * @param busType - Bus to connect to. Defaults to the session bus.
+ */
+export const getBus = (busType: BusType = BusType.SESSION): DBusConnection => busGetSync(busType, null);
+
+/**
+ * Exports a D-Bus interface implemented in JavaScript at `objectPath`.
+ *
+ * Method calls are dispatched to `definition.methods` by name. A handler
+ * may reply asynchronously by returning a promise; the caller waits for
+ * it. Property reads and writes run synchronously.
+ *
+ * @param connection - The bus connection, e.g. from {@link getBus}
+ * @param objectPath - Object path to export at
+ * @param definition - Introspection data and handlers
+ * @returns The exported interface
+ * @throws NativeError if the XML is invalid or the path is already taken for this interface
+ *
+ * @example
+ * ```tsx
+ * const greeter = exportDBusInterface(getBus(), "/org/example/Greeter", {
+ *     xml: `<node><interface name="org.example.Greeter">
+ *         <method name="Greet"><arg type="s" direction="in"/><arg type="s" direction="out"/></method>
+ *     </interface></node>`,
+ *     name: "org.example.Greeter",
+ *     methods: { Greet: ([name]) => packVariant("(s)", [`Hello, ${name}`]) },
+ * });
+ * ```
+ */
+export const exportDBusInterface = (
+    connection: DBusConnection,
+    objectPath: string,
+    definition: DBusInterfaceDefinition,
+): DBusExportedInterface => {
+    const error = createRef<NativeHandle | null>(null);
+    const nodeInfo = newNodeInfoForXml(definition.xml, error) as NativeHandle;
+    throwIfError(error);
+
+    const interfaceInfo = lookupInterface(nodeInfo, definition.name) as NativeHandle | null;
+    if (!interfaceInfo) {
+        throw new Error(`Introspection XML has no interface named '${definition.name}'`);
+    }
+
+    const toCall = (sender: string, path: string, interfaceName: string, member: string): DBusCall => ({
+        sender,
+        objectPath: path,
+        interfaceName,
+        member,
+    });
+
+    const onMethodCall = (
+        _connection: NativeHandle,
+        sender: string,
+        path: string,
+        interfaceName: string,
+        method: string,
+        parameters: NativeHandle,
+        invocation: NativeHandle,
+    ) => {
+        const handler = definition.methods?.[method];
+        if (!handler) {
+            returnDBusError(invocation, "org.freedesktop.DBus.Error.UnknownMethod", `Unknown method '${method}'`);
+            releaseInvocation(invocation);
+            return;
+        }
+        const args = unpackVariant(getNativeObject(parameters, Variant)) as unknown[];
+        Promise.resolve()
+            .then(() => handler(args, toCall(sender, path, interfaceName, method)))
+            .then(
+                (reply) => returnValue(invocation, reply?.handle ?? null),
+                (err: unknown) => replyWithError(invocation, err),
+            )
+            .finally(() => releaseInvocation(invocation));
+    };
+
+    const onGetProperty = (
+        _connection: NativeHandle,
+        sender: string,
+        path: string,
+        interfaceName: string,
+        property: string,
+    ) => definition.properties?.[property]?.(toCall(sender, path, interfaceName, property))?.handle ?? null;
+
+    const onSetProperty = (
+        _connection: NativeHandle,
+        sender: string,
+        path: string,
+        interfaceName: string,
+        property: string,
+        value: NativeHandle,
+    ) => {
+        const setter = definition.setProperties?.[property];
+        if (!setter) return false;
+        try {
+            setter(unpackVariant(getNativeObject(value, Variant)), toCall(sender, path, interfaceName, property));
+            return true;
+        } catch {
+            return false;
+        }
+    };
+
+    const id = registerObject(
+        connection.handle,
+        objectPath,
+        interfaceInfo,
+        onMethodCall,
+        definition.properties ? onGetProperty : null,
+        definition.setProperties ? onSetProperty : null,
+        error,
+    ) as number;
+    throwIfError(error);
+
+    const emit = (interfaceName: string, name: string, args: Variant | null) => {
+        const emitError = createRef<NativeHandle | null>(null);
+        emitSignal(connection.handle, null, objectPath, interfaceName, name, args?.handle ?? null, emitError);
+        throwIfError(emitError);
+    };
+
+    return {
+        id,
+        emitSignal: (name, args = null) => emit(definition.name, name, args),
+        emitPropertiesChanged: (names) => {
+            const call = toCall(connection.getUniqueName() ?? "", objectPath, definition.name, "");
+            const changed: Record<string, Variant> = {};
+            for (const name of names) {
+                const getter = definition.properties?.[name];
+                if (getter) changed[name] = getter({ ...call, member: name });
+            }
+            emit(
+                "org.freedesktop.DBus.Properties",
+                "PropertiesChanged",
+                packVariant("(sa{sv}as)", [definition.name, changed, []]),
+            );
+        },
+        unexport: () => {
+            unregisterObject(connection.handle, id);
+        },
+    };
+};
+
+/** A method call made with {@link callDBusMethod}. */
+export type DBusMethodCall = {
+    /** Bus name of the peer, such as `"org.freedesktop.portal.Desktop"`. */
+    busName: string;
+    objectPath: string;
+    interfaceName: string;
+    method: string;
+    /** Input arguments as a tuple variant, or null for none. */
+    args?: Variant | null;
+    /** Timeout in milliseconds; -1 uses the D-Bus default. */
+    timeout?: number;
+};
+
+/**
+ * Calls a D-Bus method and resolves with the unpacked reply arguments.
+ *
+ * @param connection - The bus connection, e.g. from {@link getBus}
+ * @param call - Destination, method and arguments
+ * @returns The reply's output arguments
+ * @throws NativeError if the call fails or the peer replies with an error
+ */
+export const callDBusMethod = async (connection: DBusConnection, call: DBusMethodCall): Promise<unknown[]> => {
+    const reply = await connection.callAsync(
+        call.busName,
+        call.objectPath,
+        call.interfaceName,
+        call.method,
+        call.args ?? null,
+        null,
+        DBusCallFlags.NONE,
+        call.timeout ?? -1,
+        null,
+    );
+    return unpackVariant(reply) as unknown[];
+};
+
+/** Signals matched by {@link subscribeDBusSignal}. Omitted fields match anything. */
+export type DBusSignalMatch = {
+    /** Bus name of the sender. */
+    sender?: string;
+    interfaceName?: string;
+    /** Signal name. */
+    member?: string;
+    objectPath?: string;
+};
+
+/** A signal delivered to a {@link subscribeDBusSignal} handler. */
+export type DBusSignal = {
+    /** Unique bus name of the sender. */
+    sender: string;
+    objectPath: string;
+    interfaceName: string;
+    member: string;
+};
+
+/**
+ * Calls `handler` with the unpacked arguments of every matching signal.
+ *
+ * @param connection - The bus connection, e.g. from {@link getBus}
+ * @param match - Which signals to receive
+ * @param handler - Called with the signal's arguments and origin
+ * @returns A function that unsubscribes
+ */
+export const subscribeDBusSignal = (
+    connection: DBusConnection,
+    match: DBusSignalMatch,
+    handler: (args: unknown[], signal: DBusSignal) => void,
+): (() => void) => {
+    const callback = (
+        _connection: NativeHandle,
+        sender: string,
+        objectPath: string,
+        interfaceName: string,
+        member: string,
+        parameters: NativeHandle,
+    ) => {
+        const args = unpackVariant(getNativeObject(parameters, Variant)) as unknown[];
+        handler(args, { sender, objectPath, interfaceName, member });
+    };
+    const id = signalSubscribe(
+        connection.handle,
+        match.sender ?? null,
+        match.interfaceName ?? null,
+        match.member ?? null,
+        match.objectPath ?? null,
+        null,
+        0,
+        callback,
+    ) as number;
+    return () => signalUnsubscribe(connection.handle, id);
+};
@@ -0,0 +1,169 @@
+import type { DBusConnection } from "../generated/gio/dbus-connection.js";
+import { boxVariant, packVariant } from "../glib/variant.js";
+import { getBus } from "./dbus.js";
+import { callPortal, closePortalSession, createPortalToken, subscribePortalSignal } from "./portal.js";
+
+const INTERFACE = "org.freedesktop.portal.GlobalShortcuts";
+
+/** Options for {@link registerGlobalShortcut}. */
+export type GlobalShortcutOptions = {
+    /** Stable identifier of the shortcut. Defaults to the accelerator. */
+    id?: string;
+    /** Shown to the user when the desktop asks to confirm or change the binding. Defaults to the ID. */
+    description?: string;
+};
+
+type Registration = {
+    trigger: string;
+    description: string;
+    callback: () => void;
+};
+
+const MODIFIERS: Record<string, string> = {
+    control: "CTRL",
+    ctrl: "CTRL",
+    primary: "CTRL",
+    alt: "ALT",
+    shift: "SHIFT",
+    super: "LOGO",
+    meta: "LOGO",
+};
+
+/**
+ * Converts a GTK accelerator such as `"<Control><Shift>k"` into the trigger
+ * syntax of the XDG shortcuts specification (`"CTRL+SHIFT+k"`). Triggers
+ * already in that syntax are returned unchanged.
+ */
+const toTrigger = (accel: string): string => {
+    if (!accel.startsWith("<")) return accel;
+
+    const parts: string[] = [];
+    let rest = accel;
+    for (let match = /^<([^>]+)>/.exec(rest); match; match = /^<([^>]+)>/.exec(rest)) {
+        const name = (match[1] as string).toLowerCase();
+        const modifier = MODIFIERS[name];
+        if (!modifier) throw new Error(`Unsupported modifier '<${match[1]}>' in accelerator '${accel}'`);
+        parts.push(modifier);
+        rest = rest.slice(match[0].length);
+    }
+    if (!rest) throw new Error(`Accelerator '${accel}' has no key`);
+    parts.push(rest);
+    return parts.join("+");
+};
+
+const registrations = new Map<string, Registration>();
+
+let connection: DBusConnection | null = null;
+let sessionHandle: string | null = null;
+let unsubscribeActivated: (() => void) | null = null;
+
+/** Serializes session rebuilds so overlapping registrations apply in order. */
+let pending: Promise<void> = Promise.resolve();
+
+const bus = (): DBusConnection => {
+    connection ??= getBus();
+    return connection;
+};
+
+const closeSession = async (): Promise<void> => {
+    unsubscribeActivated?.();
+    unsubscribeActivated = null;
+    if (sessionHandle) {
+        const handle = sessionHandle;
+        sessionHandle = null;
+        await closePortalSession(bus(), handle).catch(() => {});
+    }
+};
+
+/**
+ * Replaces the portal session with one binding every registered shortcut.
+ * Portals let a session bind its shortcuts only once, so changes start a
+ * new session.
+ */
+const rebind = async (): Promise<void> => {
+    await closeSession();
+    if (registrations.size === 0) return;
+
+    const created = await callPortal(bus(), {
+        interfaceName: INTERFACE,
+        method: "CreateSession",
+        args: (handleToken) =>
+            packVariant("(a{sv})", [
+                {
+                    handle_token: boxVariant("s", handleToken),
+                    session_handle_token: boxVariant("s", createPortalToken("gtkx_shortcuts")),
+                },
+            ]),
+    });
+    const session = created.session_handle as string;
+    sessionHandle = session;
+
+    unsubscribeActivated = subscribePortalSignal(bus(), INTERFACE, "Activated", ([activatedSession, shortcutId]) => {
+        if (activatedSession !== session) return;
+        registrations.get(shortcutId as string)?.callback();
+    });
+
+    const shortcuts = [...registrations].map(([id, registration]) => [
+        id,
+        {
+            description: boxVariant("s", registration.description),
+            preferred_trigger: boxVariant("s", registration.trigger),
+        },
+    ]);
+    await callPortal(bus(), {
+        interfaceName: INTERFACE,
+        method: "BindShortcuts",
+        args: (handleToken) =>
+            packVariant("(oa(sa{sv})sa{sv})", [session, shortcuts, "", { handle_token: boxVariant("s", handleToken) }]),
+    });
+};
+
+const schedule = (): Promise<void> => {
+    pending = pending.catch(() => {}).then(rebind);
+    return pending;
+};
+
+/**
+ * Registers a system-wide keyboard shortcut through the GlobalShortcuts
+ * XDG desktop portal, so `callback` runs even while the application is
+ * not focused.
+ *
+ * All shortcuts of the process share one portal session. The desktop may
+ * ask the user to confirm the shortcut or pick a different trigger; the
+ * accelerator is only a preference.
+ *
+ * @param accel - GTK accelerator, such as `"<Control><Alt>n"`
+ * @param callback - Called on each activation
+ * @param options - Identifier and description of the shortcut
+ * @returns A function that unregisters the shortcut
+ * @throws PortalError if the user declined, NativeError if the portal is unavailable
+ *
+ * @example
+ * ```tsx
+ * const unregister = await registerGlobalShortcut("<Control><Alt>n", () => window.present(), {
+ *     description: "Show Notes",
+ * });
+ * ```
+ */
+export const registerGlobalShortcut = async (
+    accel: string,
+    callback: () => void,
+    options: GlobalShortcutOptions = {},
+): Promise<() => Promise<void>> => {
+    const id = options.id ?? accel;
+    if (registrations.has(id)) {
+        throw new Error(`A global shortcut with ID '${id}' is already registered`);
+    }
+
+    registrations.set(id, { trigger: toTrigger(accel), description: options.description ?? id, callback });
+    try {
+        await schedule();
+    } catch (error) {
+        registrations.delete(id);
+        throw error;
+    }
+
+    return async () => {
+        if (registrations.delete(id)) await schedule();
+    };
+};
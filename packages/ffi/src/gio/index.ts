@@ -0,0 +1,10 @@
+export * from "../generated/gio/index.js";
+export * from "./appearance.js";
+export * from "./cancellable.js";
+export * from "./dbus.js";
+export * from "./global-shortcuts.js";
+export * from "./list-store.js";
+export * from "./portal.js";
+export * from "./screen-capture.js";
+export * from "./single-instance.js";
+export * from "./status-notifier.js";
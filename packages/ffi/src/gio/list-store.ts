@@ -0,0 +1,57 @@
+import type { NativeHandle } from "@gtkx/native";
+import type { ListStore } from "../generated/gio/list-store.js";
+import type { Object as GObject } from "../generated/gobject/object.js";
+import { t } from "../native.js";
+import { getNativeObject } from "../registry.js";
+
+const LIB = "libgio-2.0.so.0";
+
+const STORE = t.object("borrowed");
+const ITEM = t.object("borrowed");
+const COMPARE_FUNC = t.trampoline([ITEM, ITEM, t.void], t.int32, { userDataIndex: 2, kind: "compare" });
+
+const listStoreSort = t.fn(LIB, "g_list_store_sort", [{ type: STORE }, { type: COMPARE_FUNC }], t.void);
+const listStoreInsertSorted = t.fn(
+    LIB,
+    "g_list_store_insert_sorted",
+    [{ type: STORE }, { type: ITEM }, { type: COMPARE_FUNC }],
+    t.uint32,
+);
+
+const toCompareFunc =
+    <T extends GObject>(compare: (a: T, b: T) => number) =>
+    (a: NativeHandle, b: NativeHandle): number =>
+        compare(getNativeObject(a) as unknown as T, getNativeObject(b) as unknown as T);
+
+/**
+ * Sorts the items of a `GListStore` in place with a JS comparator.
+ *
+ * The comparator follows `Array.prototype.sort` conventions: only the sign
+ * of its result matters, so fractional differences order correctly.
+ *
+ * @param store - The store to sort
+ * @param compare - Comparator for two items of the store
+ *
+ * @example
+ * ```tsx
+ * sortListStore<Gtk.StringObject>(store, (a, b) => a.getString().localeCompare(b.getString()));
+ * ```
+ */
+export const sortListStore = <T extends GObject>(store: ListStore, compare: (a: T, b: T) => number): void => {
+    listStoreSort(store.handle, toCompareFunc(compare));
+};
+
+/**
+ * Inserts an item into a `GListStore` that is already sorted by `compare`,
+ * keeping it sorted.
+ *
+ * @param store - A store sorted by `compare`
+ * @param item - The item to insert
+ * @param compare - Comparator the store is sorted by
+ * @returns The position the item was inserted at
+ */
+export const insertSortedInListStore = <T extends GObject>(
+    store: ListStore,
+    item: T,
+    compare: (a: T, b: T) => number,
+): number => listStoreInsertSorted(store.handle, item.handle, toCompareFunc(compare)) as number;
@@ -0,0 +1,151 @@
+import type { DBusConnection } from "../generated/gio/dbus-connection.js";
+import type { Variant } from "../generated/glib/variant.js";
+import { callDBusMethod, subscribeDBusSignal } from "./dbus.js";
+
+const PORTAL_BUS_NAME = "org.freedesktop.portal.Desktop";
+const PORTAL_OBJECT_PATH = "/org/freedesktop/portal/desktop";
+
+/** Error thrown when a portal request ends without success. */
+export class PortalError extends Error {
+    /** `1` if the user cancelled the interaction, `2` if it failed otherwise. */
+    readonly response: number;
+
+    /**
+     * @param method - The portal method that was called
+     * @param response - The non-zero response code of the request
+     */
+    constructor(method: string, response: number) {
+        super(response === 1 ? `${method} was cancelled` : `${method} failed`);
+        this.response = response;
+        this.name = "PortalError";
+    }
+}
+
+let nextToken = 1;
+
+/** Returns a token unique to this process, valid as an object path element. */
+export const createPortalToken = (prefix = "gtkx"): string => `${prefix}_${process.pid}_${nextToken++}`;
+
+const senderPathElement = (connection: DBusConnection): string =>
+    (connection.getUniqueName() ?? "").replace(/^:/, "").replace(/\./g, "_");
+
+/** A request made with {@link callPortal}. */
+export type PortalRequest = {
+    /** Portal interface, such as `"org.freedesktop.portal.Screenshot"`. */
+    interfaceName: string;
+    method: string;
+    /**
+     * Builds the method arguments as a tuple variant. Receives the request's
+     * `handle_token`, which must be included in the method's options dictionary.
+     */
+    args: (handleToken: string) => Variant;
+};
+
+/**
+ * Calls an XDG desktop portal method that answers through a `Request`
+ * object, and resolves with the results of its `Response` signal.
+ *
+ * Subscribes to the response before making the call, as the portal
+ * documentation requires, so a fast response cannot be missed.
+ *
+ * @param connection - Session bus connection
+ * @param request - Interface, method and arguments
+ * @returns The `results` dictionary of the response
+ * @throws PortalError if the user cancelled or the request failed
+ * @throws NativeError if the portal is not available
+ */
+export const callPortal = (connection: DBusConnection, request: PortalRequest): Promise<Record<string, unknown>> => {
+    const handleToken = createPortalToken();
+    const requestPath = `${PORTAL_OBJECT_PATH}/request/${senderPathElement(connection)}/${handleToken}`;
+
+    return new Promise((resolve, reject) => {
+        const unsubscribe = subscribeDBusSignal(
+            connection,
+            {
+                sender: PORTAL_BUS_NAME,
+                interfaceName: "org.freedesktop.portal.Request",
+                member: "Response",
+                objectPath: requestPath,
+            },
+            ([response, results]) => {
+                unsubscribe();
+                if (response === 0) {
+                    resolve(results as Record<string, unknown>);
+                } else {
+                    reject(new PortalError(`${request.interfaceName}.${request.method}`, response as number));
+                }
+            },
+        );
+
+        callDBusMethod(connection, {
+            busName: PORTAL_BUS_NAME,
+            objectPath: PORTAL_OBJECT_PATH,
+            interfaceName: request.interfaceName,
+            method: request.method,
+            args: request.args(handleToken),
+        }).catch((error: unknown) => {
+            unsubscribe();
+            reject(error);
+        });
+    });
+};
+
+/**
+ * Calls a portal method that replies directly, without a `Request` object.
+ *
+ * @param connection - Session bus connection
+ * @param interfaceName - Portal interface
+ * @param method - Method name
+ * @param args - Arguments as a tuple variant, or null for none
+ * @returns The reply's output arguments
+ */
+export const callPortalMethod = (
+    connection: DBusConnection,
+    interfaceName: string,
+    method: string,
+    args: Variant | null = null,
+): Promise<unknown[]> =>
+    callDBusMethod(connection, {
+        busName: PORTAL_BUS_NAME,
+        objectPath: PORTAL_OBJECT_PATH,
+        interfaceName,
+        method,
+        args,
+    });
+
+/**
+ * Subscribes to a signal emitted by the portal on its main object.
+ *
+ * @param connection - Session bus connection
+ * @param interfaceName - Portal interface
+ * @param member - Signal name
+ * @param handler - Called with the signal's unpacked arguments
+ * @returns A function that unsubscribes
+ */
+export const subscribePortalSignal = (
+    connection: DBusConnection,
+    interfaceName: string,
+    member: string,
+    handler: (args: unknown[]) => void,
+): (() => void) =>
+    subscribeDBusSignal(
+        connection,
+        { sender: PORTAL_BUS_NAME, interfaceName, member, objectPath: PORTAL_OBJECT_PATH },
+        handler,
+    );
+
+/**
+ * Closes a portal session, such as one created by the GlobalShortcuts or
+ * ScreenCast portals.
+ *
+ * @param connection - Session bus connection
+ * @param sessionHandle - Object path of the session
+ */
+export const closePortalSession = async (connection: DBusConnection, sessionHandle: string): Promise<void> => {
+    await callDBusMethod(connection, {
+        busName: PORTAL_BUS_NAME,
+        objectPath: sessionHandle,
+        interfaceName: "org.freedesktop.portal.Session",
+        method: "Close",
+    });
+};
@@ -0,0 +1,220 @@
+import type { DBusConnection } from "../generated/gio/dbus-connection.js";
+import { boxVariant, packVariant, type VariantBox } from "../glib/variant.js";
+import { getBus } from "./dbus.js";
+import { callPortal, closePortalSession, createPortalToken } from "./portal.js";
+
+const SCREENSHOT_INTERFACE = "org.freedesktop.portal.Screenshot";
+const SCREENCAST_INTERFACE = "org.freedesktop.portal.ScreenCast";
+
+/** Kinds of content a screen cast can capture. */
+export enum ScreenCastSourceType {
+    MONITOR = 1,
+    WINDOW = 2,
+    VIRTUAL = 4,
+}
+
+/** How the cursor appears in a screen cast. */
+export enum ScreenCastCursorMode {
+    HIDDEN = 1,
+    EMBEDDED = 2,
+    METADATA = 4,
+}
+
+/** Whether the portal remembers the user's source selection. */
+export enum ScreenCastPersistMode {
+    NONE = 0,
+    TRANSIENT = 1,
+    PERSISTENT = 2,
+}
+
+/** Options for {@link takeScreenshot}. */
+export type ScreenshotOptions = {
+    /** Let the user choose the area or window to capture. Defaults to false. */
+    interactive?: boolean;
+    /** Make the portal dialog modal to the parent window. Defaults to true. */
+    modal?: boolean;
+    /** Parent window identifier, such as `"wayland:<handle>"` or `"x11:<xid>"`. */
+    parentWindow?: string;
+};
+
+/** Options for {@link startScreenCast}. */
+export type ScreenCastOptions = {
+    /** Bitwise combination of the sources the user may pick. Defaults to monitors. */
+    sources?: number;
+    /** Allow selecting more than one source. Defaults to false. */
+    multiple?: boolean;
+    cursorMode?: ScreenCastCursorMode;
+    persistMode?: ScreenCastPersistMode;
+    /** Token from an earlier {@link ScreenCastSession.restoreToken} to skip the selection dialog. */
+    restoreToken?: string;
+    /** Parent window identifier for the selection dialog. */
+    parentWindow?: string;
+};
+
+/** A PipeWire stream of a running screen cast. */
+export type ScreenCastStream = {
+    /** PipeWire node to connect to for the stream's video. */
+    nodeId: number;
+    /** Position of the source in compositor coordinates, if known. */
+    position?: [number, number];
+    /** Size of the source in compositor coordinates, if known. */
+    size?: [number, number];
+    sourceType?: ScreenCastSourceType;
+    /** Identifier that stays the same for a source across sessions, if provided. */
+    id?: string;
+};
+
+/** A screen cast started with {@link startScreenCast}. */
+export type ScreenCastSession = {
+    /** Object path of the portal session. */
+    sessionHandle: string;
+    streams: ScreenCastStream[];
+    /** Token to restore the same selection later, when a persist mode was requested. */
+    restoreToken?: string;
+    /** Ends the screen cast and closes its PipeWire streams. */
+    close(): Promise<void>;
+};
+
+/** A color picked with {@link pickColor}, with channels from 0 to 1. */
+export type PickedColor = {
+    red: number;
+    green: number;
+    blue: number;
+};
+
+/**
+ * Takes a screenshot through the Screenshot XDG desktop portal.
+ *
+ * @param options - Interaction and parent window options
+ * @param connection - Session bus connection, defaults to {@link getBus}
+ * @returns URI of the saved screenshot
+ * @throws PortalError if the user cancelled or the request failed
+ *
+ * @example
+ * ```tsx
+ * const uri = await takeScreenshot({ interactive: true });
+ * ```
+ */
+export const takeScreenshot = async (
+    options: ScreenshotOptions = {},
+    connection: DBusConnection = getBus(),
+): Promise<string> => {
+    const results = await callPortal(connection, {
+        interfaceName: SCREENSHOT_INTERFACE,
+        method: "Screenshot",
+        args: (handleToken) =>
+            packVariant("(sa{sv})", [
+                options.parentWindow ?? "",
+                {
+                    handle_token: boxVariant("s", handleToken),
+                    interactive: boxVariant("b", options.interactive ?? false),
+                    modal: boxVariant("b", options.modal ?? true),
+                },
+            ]),
+    });
+    return results.uri as string;
+};
+
+/**
+ * Lets the user pick a color from anywhere on the screen through the
+ * Screenshot XDG desktop portal.
+ *
+ * @param parentWindow - Parent window identifier
+ * @param connection - Session bus connection, defaults to {@link getBus}
+ * @throws PortalError if the user cancelled or the request failed
+ */
+export const pickColor = async (parentWindow = "", connection: DBusConnection = getBus()): Promise<PickedColor> => {
+    const results = await callPortal(connection, {
+        interfaceName: SCREENSHOT_INTERFACE,
+        method: "PickColor",
+        args: (handleToken) => packVariant("(sa{sv})", [parentWindow, { handle_token: boxVariant("s", handleToken) }]),
+    });
+    const [red, green, blue] = results.color as [number, number, number];
+    return { red, green, blue };
+};
+
+const toStream = ([nodeId, properties]: [number, Record<string, unknown>]): ScreenCastStream => {
+    const stream: ScreenCastStream = { nodeId };
+    if (properties.position) stream.position = properties.position as [number, number];
+    if (properties.size) stream.size = properties.size as [number, number];
+    if (properties.source_type !== undefined) stream.sourceType = properties.source_type as ScreenCastSourceType;
+    if (properties.id !== undefined) stream.id = properties.id as string;
+    return stream;
+};
+
+/**
+ * Starts a screen cast through the ScreenCast XDG desktop portal.
+ *
+ * Creates a session, asks the user which sources to share and starts the
+ * cast. Each selected source becomes a PipeWire stream, identified by its
+ * node ID, that a media pipeline such as GStreamer's `pipewiresrc` can read.
+ *
+ * @param options - Source selection, cursor and persistence options
+ * @param connection - Session bus connection, defaults to {@link getBus}
+ * @returns The running session and its streams
+ * @throws PortalError if the user cancelled or a request failed
+ *
+ * @example
+ * ```tsx
+ * const cast = await startScreenCast({ sources: ScreenCastSourceType.MONITOR | ScreenCastSourceType.WINDOW });
+ * console.log(cast.streams[0]?.nodeId);
+ * await cast.close();
+ * ```
+ */
+export const startScreenCast = async (
+    options: ScreenCastOptions = {},
+    connection: DBusConnection = getBus(),
+): Promise<ScreenCastSession> => {
+    const created = await callPortal(connection, {
+        interfaceName: SCREENCAST_INTERFACE,
+        method: "CreateSession",
+        args: (handleToken) =>
+            packVariant("(a{sv})", [
+                {
+                    handle_token: boxVariant("s", handleToken),
+                    session_handle_token: boxVariant("s", createPortalToken("gtkx_screencast")),
+                },
+            ]),
+    });
+    const sessionHandle = created.session_handle as string;
+
+    try {
+        await callPortal(connection, {
+            interfaceName: SCREENCAST_INTERFACE,
+            method: "SelectSources",
+            args: (handleToken) => {
+                const selection: Record<string, VariantBox> = {
+                    handle_token: boxVariant("s", handleToken),
+                    types: boxVariant("u", options.sources ?? ScreenCastSourceType.MONITOR),
+                    multiple: boxVariant("b", options.multiple ?? false),
+                };
+                if (options.cursorMode !== undefined) selection.cursor_mode = boxVariant("u", options.cursorMode);
+                if (options.persistMode !== undefined) selection.persist_mode = boxVariant("u", options.persistMode);
+                if (options.restoreToken) selection.restore_token = boxVariant("s", options.restoreToken);
+                return packVariant("(oa{sv})", [sessionHandle, selection]);
+            },
+        });
+
+        const started = await callPortal(connection, {
+            interfaceName: SCREENCAST_INTERFACE,
+            method: "Start",
+            args: (handleToken) =>
+                packVariant("(osa{sv})", [
+                    sessionHandle,
+                    options.parentWindow ?? "",
+                    { handle_token: boxVariant("s", handleToken) },
+                ]),
+        });
+
+        const streams = (started.streams ?? []) as [number, Record<string, unknown>][];
+        return {
+            sessionHandle,
+            streams: streams.map(toStream),
+            restoreToken: started.restore_token as string | undefined,
+            close: () => closePortalSession(connection, sessionHandle),
+        };
+    } catch (error) {
+        await closePortalSession(connection, sessionHandle).catch(() => {});
+        throw error;
+    }
+};
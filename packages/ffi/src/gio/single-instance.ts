@@ -0,0 +1,79 @@
+import type { Application } from "../generated/gio/application.js";
+import { SimpleAction } from "../generated/gio/simple-action.js";
+import type { Variant } from "../generated/glib/variant.js";
+import { VariantType } from "../generated/glib/variant-type.js";
+import { packVariant, unpackVariant } from "../glib/variant.js";
+import { events } from "../lifecycle.js";
+
+const ACTIVATE_ACTION = "gtkx-activate";
+const ACTIVATION_SIGNATURE = "(assms)";
+
+// GDK consumes the launch token from the environment when it opens the
+// display, so it is read before the runtime starts.
+const launchToken = process.env.XDG_ACTIVATION_TOKEN ?? process.env.DESKTOP_STARTUP_ID ?? null;
+
+/**
+ * A launch of the application forwarded from a second instance.
+ *
+ * Pass `activationToken` to `presentWindow` from `@gtkx/ffi/gtk`, so the
+ * compositor lets the primary instance take focus.
+ */
+export type Activation = {
+    /** Command line arguments of the second launch, without the program name. */
+    args: string[];
+    /** Working directory of the second launch, for resolving relative paths. */
+    cwd: string;
+    /** `XDG_ACTIVATION_TOKEN` or `DESKTOP_STARTUP_ID` of the second launch, if any. */
+    activationToken: string | null;
+};
+
+/**
+ * Forwards this launch to the primary instance when another process
+ * already owns the application id.
+ *
+ * Applies to applications registered without `NON_UNIQUE`. The primary
+ * instance receives the launch through {@link watchActivations}.
+ *
+ * @param application - A registered application
+ * @param activation - Launch details. Defaults to this process's arguments,
+ *   working directory and activation token.
+ * @returns Whether the launch was forwarded, in which case this instance
+ *   should exit
+ *
+ * @example
+ * ```tsx
+ * app.register(null);
+ * if (forwardActivation(app)) stop();
+ * ```
+ */
+export const forwardActivation = (application: Application, activation: Partial<Activation> = {}): boolean => {
+    if (!application.getIsRemote()) return false;
+
+    const parameter = packVariant(ACTIVATION_SIGNATURE, [
+        activation.args ?? process.argv.slice(2),
+        activation.cwd ?? process.cwd(),
+        activation.activationToken ?? launchToken,
+    ]);
+    application.activateAction(ACTIVATE_ACTION, parameter);
+    application.getDbusConnection()?.flushSync(null);
+    return true;
+};
+
+/**
+ * Emits an `"activated"` event on {@link events} for each launch forwarded
+ * to this instance by {@link forwardActivation}.
+ *
+ * @param application - The primary application
+ * @returns A function that stops watching
+ */
+export const watchActivations = (application: Application): (() => void) => {
+    const action = new SimpleAction(ACTIVATE_ACTION, new VariantType(ACTIVATION_SIGNATURE));
+    action.connect("activate", (_action: SimpleAction, parameter: Variant | null) => {
+        if (!parameter) return;
+        const [args, cwd, activationToken] = unpackVariant(parameter) as [string[], string, string | null];
+        events.emit("activated", { args, cwd, activationToken });
+    });
+    application.addAction(action);
+
+    return () => application.removeAction(ACTIVATE_ACTION);
+};
@@ -0,0 +1,373 @@
+import type { DBusConnection } from "../generated/gio/dbus-connection.js";
+import { boxVariant, packVariant, type VariantBox } from "../glib/variant.js";
+import { callDBusMethod, DBusError, type DBusExportedInterface, exportDBusInterface, getBus } from "./dbus.js";
+
+const ITEM_INTERFACE = "org.kde.StatusNotifierItem";
+const MENU_INTERFACE = "com.canonical.dbusmenu";
+const INVALID_ARGS = "org.freedesktop.DBus.Error.InvalidArgs";
+
+const ITEM_XML = `<node>
+  <interface name="${ITEM_INTERFACE}">
+    <property name="Category" type="s" access="read"/>
+    <property name="Id" type="s" access="read"/>
+    <property name="Title" type="s" access="read"/>
+    <property name="Status" type="s" access="read"/>
+    <property name="WindowId" type="i" access="read"/>
+    <property name="IconName" type="s" access="read"/>
+    <property name="IconThemePath" type="s" access="read"/>
+    <property name="OverlayIconName" type="s" access="read"/>
+    <property name="AttentionIconName" type="s" access="read"/>
+    <property name="ToolTip" type="(sa(iiay)ss)" access="read"/>
+    <property name="ItemIsMenu" type="b" access="read"/>
+    <property name="Menu" type="o" access="read"/>
+    <method name="ContextMenu"><arg name="x" type="i" direction="in"/><arg name="y" type="i" direction="in"/></method>
+    <method name="Activate"><arg name="x" type="i" direction="in"/><arg name="y" type="i" direction="in"/></method>
+    <method name="SecondaryActivate">
+      <arg name="x" type="i" direction="in"/><arg name="y" type="i" direction="in"/>
+    </method>
+    <method name="Scroll">
+      <arg name="delta" type="i" direction="in"/><arg name="orientation" type="s" direction="in"/>
+    </method>
+    <signal name="NewTitle"/>
+    <signal name="NewIcon"/>
+    <signal name="NewAttentionIcon"/>
+    <signal name="NewOverlayIcon"/>
+    <signal name="NewToolTip"/>
+    <signal name="NewStatus"><arg name="status" type="s"/></signal>
+  </interface>
+</node>`;
+
+const MENU_XML = `<node>
+  <interface name="${MENU_INTERFACE}">
+    <property name="Version" type="u" access="read"/>
+    <property name="TextDirection" type="s" access="read"/>
+    <property name="Status" type="s" access="read"/>
+    <property name="IconThemePath" type="as" access="read"/>
+    <method name="GetLayout">
+      <arg name="parentId" type="i" direction="in"/>
+      <arg name="recursionDepth" type="i" direction="in"/>
+      <arg name="propertyNames" type="as" direction="in"/>
+      <arg name="revision" type="u" direction="out"/>
+      <arg name="layout" type="(ia{sv}av)" direction="out"/>
+    </method>
+    <method name="GetGroupProperties">
+      <arg name="ids" type="ai" direction="in"/>
+      <arg name="propertyNames" type="as" direction="in"/>
+      <arg name="properties" type="a(ia{sv})" direction="out"/>
+    </method>
+    <method name="GetProperty">
+      <arg name="id" type="i" direction="in"/>
+      <arg name="name" type="s" direction="in"/>
+      <arg name="value" type="v" direction="out"/>
+    </method>
+    <method name="Event">
+      <arg name="id" type="i" direction="in"/>
+      <arg name="eventId" type="s" direction="in"/>
+      <arg name="data" type="v" direction="in"/>
+      <arg name="timestamp" type="u" direction="in"/>
+    </method>
+    <method name="EventGroup">
+      <arg name="events" type="a(isvu)" direction="in"/>
+      <arg name="idErrors" type="ai" direction="out"/>
+    </method>
+    <method name="AboutToShow">
+      <arg name="id" type="i" direction="in"/>
+      <arg name="needUpdate" type="b" direction="out"/>
+    </method>
+    <method name="AboutToShowGroup">
+      <arg name="ids" type="ai" direction="in"/>
+      <arg name="updatesNeeded" type="ai" direction="out"/>
+      <arg name="idErrors" type="ai" direction="out"/>
+    </method>
+    <signal name="ItemsPropertiesUpdated">
+      <arg name="updatedProps" type="a(ia{sv})"/><arg name="removedProps" type="a(ias)"/>
+    </signal>
+    <signal name="LayoutUpdated"><arg name="revision" type="u"/><arg name="parent" type="i"/></signal>
+    <signal name="ItemActivationRequested"><arg name="id" type="i"/><arg name="timestamp" type="u"/></signal>
+  </interface>
+</node>`;
+
+/** Status of a tray icon, which hosts may use to hide passive icons. */
+export type TrayIconStatus = "Passive" | "Active" | "NeedsAttention";
+
+/** Category of a tray icon. */
+export type TrayIconCategory = "ApplicationStatus" | "Communications" | "SystemServices" | "Hardware";
+
+/** An entry of a tray icon's menu. */
+export type TrayMenuItem = {
+    label?: string;
+    /** Themed icon shown next to the label. */
+    iconName?: string;
+    /** Defaults to true. */
+    enabled?: boolean;
+    /** Defaults to true. */
+    visible?: boolean;
+    /** Render as a separator; every other field is ignored. */
+    separator?: boolean;
+    /** Show a check mark (or a radio indicator with `radio`) in this state. */
+    checked?: boolean;
+    radio?: boolean;
+    /** Child entries, shown as a submenu. */
+    submenu?: readonly TrayMenuItem[];
+    /** Called when the entry is clicked. */
+    onActivate?: () => void;
+};
+
+/** Appearance of a tray icon; all fields can be changed with {@link TrayIcon.update}. */
+export type TrayIconProperties = {
+    title?: string;
+    /** Themed icon name. */
+    iconName?: string;
+    /** Directory searched for `iconName` in addition to the icon theme. */
+    iconThemePath?: string;
+    attentionIconName?: string;
+    overlayIconName?: string;
+    /** Tooltip title, or title and body. */
+    tooltip?: string | { title: string; description?: string };
+    status?: TrayIconStatus;
+};
+
+/** Options for {@link createTrayIcon}. */
+export type TrayIconOptions = TrayIconProperties & {
+    /** Application-unique identifier of the icon, e.g. the application ID. */
+    id: string;
+    category?: TrayIconCategory;
+    menu?: readonly TrayMenuItem[];
+    /** Called on primary activation (usually a left click) with screen coordinates. */
+    onActivate?: (x: number, y: number) => void;
+    /** Called on secondary activation (usually a middle click). */
+    onSecondaryActivate?: (x: number, y: number) => void;
+    /** Called when the host asks the application to show its own context menu. */
+    onContextMenu?: (x: number, y: number) => void;
+    /** Called on scroll with the delta and `"vertical"` or `"horizontal"`. */
+    onScroll?: (delta: number, orientation: string) => void;
+};
+
+/** A tray icon created with {@link createTrayIcon}. */
+export type TrayIcon = {
+    /** Changes the icon's appearance and notifies the host. */
+    update(properties: TrayIconProperties): void;
+    /** Replaces the icon's menu. */
+    setMenu(items: readonly TrayMenuItem[]): void;
+    /** Removes the icon from the tray. */
+    remove(): void;
+};
+
+type MenuNode = {
+    id: number;
+    item: TrayMenuItem | null;
+    children: MenuNode[];
+};
+
+type LayoutValue = [number, Record<string, VariantBox>, VariantBox[]];
+
+const buildMenu = (items: readonly TrayMenuItem[]): Map<number, MenuNode> => {
+    const nodes = new Map<number, MenuNode>();
+    const build = (item: TrayMenuItem | null, entries: readonly TrayMenuItem[]): MenuNode => {
+        const node: MenuNode = { id: nodes.size, item, children: [] };
+        nodes.set(node.id, node);
+        node.children = entries.map((entry) => build(entry, entry.submenu ?? []));
+        return node;
+    };
+    build(null, items);
+    return nodes;
+};
+
+const menuItemProperties = (node: MenuNode): Record<string, VariantBox> => {
+    const { item } = node;
+    const properties: Record<string, VariantBox> = {};
+    if (item?.separator) {
+        properties.type = boxVariant("s", "separator");
+        return properties;
+    }
+    if (item?.label !== undefined) properties.label = boxVariant("s", item.label);
+    if (item?.iconName !== undefined) properties["icon-name"] = boxVariant("s", item.iconName);
+    if (item?.enabled === false) properties.enabled = boxVariant("b", false);
+    if (item?.visible === false) properties.visible = boxVariant("b", false);
+    if (item?.checked !== undefined) {
+        properties["toggle-type"] = boxVariant("s", item.radio ? "radio" : "checkmark");
+        properties["toggle-state"] = boxVariant("i", item.checked ? 1 : 0);
+    }
+    if (node.children.length > 0) properties["children-display"] = boxVariant("s", "submenu");
+    return properties;
+};
+
+const pickProperties = (properties: Record<string, VariantBox>, names: readonly string[]) =>
+    names.length === 0
+        ? properties
+        : Object.fromEntries(Object.entries(properties).filter(([name]) => names.includes(name)));
+
+const layoutOf = (node: MenuNode, depth: number, names: readonly string[]): LayoutValue => [
+    node.id,
+    pickProperties(menuItemProperties(node), names),
+    depth === 0 ? [] : node.children.map((child) => boxVariant("(ia{sv}av)", layoutOf(child, depth - 1, names))),
+];
+
+let nextTrayIconId = 1;
+
+/**
+ * Shows an icon in the desktop's system tray.
+ *
+ * Implements the `org.kde.StatusNotifierItem` protocol, which GNOME (with
+ * the AppIndicator extension), KDE and most other desktops and status bars
+ * host, with the icon's menu exported over `com.canonical.dbusmenu`.
+ *
+ * @param options - Identity, appearance, menu and handlers of the icon
+ * @param connection - Session bus connection. Defaults to {@link getBus}.
+ * @returns The tray icon
+ * @throws NativeError if no tray host is running (no `org.kde.StatusNotifierWatcher` on the bus)
+ *
+ * @example
+ * ```tsx
+ * const tray = await createTrayIcon({
+ *     id: "org.example.Notes",
+ *     iconName: "accessories-text-editor",
+ *     tooltip: "Notes",
+ *     onActivate: () => window.present(),
+ *     menu: [{ label: "Quit", onActivate: () => app.quit() }],
+ * });
+ * ```
+ */
+export const createTrayIcon = async (
+    options: TrayIconOptions,
+    connection: DBusConnection = getBus(),
+): Promise<TrayIcon> => {
+    const itemPath = `/org/gtkx/StatusNotifierItem/${nextTrayIconId++}`;
+    const menuPath = `${itemPath}/Menu`;
+    const state: TrayIconProperties = { ...options };
+
+    let menu = buildMenu(options.menu ?? []);
+    let revision = 1;
+
+    const findNode = (id: number): MenuNode => {
+        const node = menu.get(id);
+        if (!node) throw new DBusError(INVALID_ARGS, `No menu item with ID ${id}`);
+        return node;
+    };
+
+    const tooltip = () => {
+        const { tooltip: value } = state;
+        const title = typeof value === "string" ? value : (value?.title ?? "");
+        const description = typeof value === "string" ? "" : (value?.description ?? "");
+        return packVariant("(sa(iiay)ss)", [state.iconName ?? "", [], title, description]);
+    };
+
+    const item: DBusExportedInterface = exportDBusInterface(connection, itemPath, {
+        xml: ITEM_XML,
+        name: ITEM_INTERFACE,
+        properties: {
+            Category: () => packVariant("s", options.category ?? "ApplicationStatus"),
+            Id: () => packVariant("s", options.id),
+            Title: () => packVariant("s", state.title ?? ""),
+            Status: () => packVariant("s", state.status ?? "Active"),
+            WindowId: () => packVariant("i", 0),
+            IconName: () => packVariant("s", state.iconName ?? ""),
+            IconThemePath: () => packVariant("s", state.iconThemePath ?? ""),
+            OverlayIconName: () => packVariant("s", state.overlayIconName ?? ""),
+            AttentionIconName: () => packVariant("s", state.attentionIconName ?? ""),
+            ToolTip: tooltip,
+            ItemIsMenu: () => packVariant("b", !options.onActivate),
+            Menu: () => packVariant("o", menuPath),
+        },
+        methods: {
+            Activate: ([x, y]) => {
+                options.onActivate?.(x as number, y as number);
+            },
+            SecondaryActivate: ([x, y]) => {
+                options.onSecondaryActivate?.(x as number, y as number);
+            },
+            ContextMenu: ([x, y]) => {
+                options.onContextMenu?.(x as number, y as number);
+            },
+            Scroll: ([delta, orientation]) => {
+                options.onScroll?.(delta as number, orientation as string);
+            },
+        },
+    });
+
+    const activate = (id: number, eventId: string): boolean => {
+        const node = menu.get(id);
+        if (!node) return false;
+        if (eventId === "clicked") node.item?.onActivate?.();
+        return true;
+    };
+
+    const menuInterface = exportDBusInterface(connection, menuPath, {
+        xml: MENU_XML,
+        name: MENU_INTERFACE,
+        properties: {
+            Version: () => packVariant("u", 3),
+            TextDirection: () => packVariant("s", "ltr"),
+            Status: () => packVariant("s", "normal"),
+            IconThemePath: () => packVariant("as", state.iconThemePath ? [state.iconThemePath] : []),
+        },
+        methods: {
+            GetLayout: ([parentId, depth, names]) =>
+                packVariant("(u(ia{sv}av))", [
+                    revision,
+                    layoutOf(findNode(parentId as number), depth as number, names as string[]),
+                ]),
+            GetGroupProperties: ([ids, names]) => {
+                const requested = (ids as number[]).length > 0 ? (ids as number[]) : [...menu.keys()];
+                const found = requested.flatMap((id) => {
+                    const node = menu.get(id);
+                    return node ? [[id, pickProperties(menuItemProperties(node), names as string[])]] : [];
+                });
+                return packVariant("(a(ia{sv}))", [found]);
+            },
+            GetProperty: ([id, name]) => {
+                const value = menuItemProperties(findNode(id as number))[name as string];
+                if (!value) throw new DBusError(INVALID_ARGS, `Menu item ${id} has no property '${name}'`);
+                return packVariant("(v)", [value]);
+            },
+            Event: ([id, eventId]) => {
+                if (!activate(id as number, eventId as string)) {
+                    throw new DBusError(INVALID_ARGS, `No menu item with ID ${id}`);
+                }
+            },
+            EventGroup: ([events]) => {
+                const errors = (events as [number, string][])
+                    .filter(([id, eventId]) => !activate(id, eventId))
+                    .map(([id]) => id);
+                return packVariant("(ai)", [errors]);
+            },
+            AboutToShow: () => packVariant("(b)", [false]),
+            AboutToShowGroup: () => packVariant("(aiai)", [[], []]),
+        },
+    });
+
+    try {
+        await callDBusMethod(connection, {
+            busName: "org.kde.StatusNotifierWatcher",
+            objectPath: "/StatusNotifierWatcher",
+            interfaceName: "org.kde.StatusNotifierWatcher",
+            method: "RegisterStatusNotifierItem",
+            args: packVariant("(s)", [itemPath]),
+        });
+    } catch (error) {
+        item.unexport();
+        menuInterface.unexport();
+        throw error;
+    }
+
+    return {
+        update: (properties) => {
+            Object.assign(state, properties);
+            if ("title" in properties) item.emitSignal("NewTitle");
+            if ("iconName" in properties || "iconThemePath" in properties) item.emitSignal("NewIcon");
+            if ("attentionIconName" in properties) item.emitSignal("NewAttentionIcon");
+            if ("overlayIconName" in properties) item.emitSignal("NewOverlayIcon");
+            if ("tooltip" in properties) item.emitSignal("NewToolTip");
+            if ("status" in properties) item.emitSignal("NewStatus", packVariant("(s)", [state.status ?? "Active"]));
+        },
+        setMenu: (items) => {
+            menu = buildMenu(items);
+            revision++;
+            menuInterface.emitSignal("LayoutUpdated", packVariant("(ui)", [revision, 0]));
+        },
+        remove: () => {
+            item.unexport();
+            menuInterface.unexport();
+        },
+    };
+};
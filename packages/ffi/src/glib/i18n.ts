@@ -0,0 +1,83 @@
+import { t } from "../native.js";
+
+const LIB = "libglib-2.0.so.0";
+const LIBC = "libc.so.6";
+
+const STRING = t.string("borrowed");
+
+const bindTextDomainDir = t.fn(LIBC, "bindtextdomain", [{ type: STRING }, { type: STRING, optional: true }], STRING);
+const bindTextDomainCodeset = t.fn(LIBC, "bind_textdomain_codeset", [{ type: STRING }, { type: STRING }], STRING);
+const textDomain = t.fn(LIBC, "textdomain", [{ type: STRING, optional: true }], STRING);
+
+const dgettext = t.fn(LIB, "g_dgettext", [{ type: STRING, optional: true }, { type: STRING }], STRING);
+const dpgettext2 = t.fn(
+    LIB,
+    "g_dpgettext2",
+    [{ type: STRING, optional: true }, { type: STRING }, { type: STRING }],
+    STRING,
+);
+
+/**
+ * Tells gettext where the `.mo` catalogs of a text domain are installed,
+ * such as `/usr/share/locale`, and to return its translations as UTF-8, as
+ * GTK expects.
+ *
+ * Catalogs are looked up as `<localeDir>/<locale>/LC_MESSAGES/<domain>.mo`,
+ * for the locale GTK selects from the environment when it initializes.
+ *
+ * @param domain - The text domain, usually the application ID or package name
+ * @param localeDir - Directory holding the catalogs. Omit it to keep the current binding.
+ * @returns The directory the domain's catalogs are read from
+ *
+ * @example
+ * ```tsx
+ * bindTextDomain("org.example.App", join(prefix, "share/locale"));
+ * setTextDomain("org.example.App");
+ * ```
+ */
+export const bindTextDomain = (domain: string, localeDir?: string): string => {
+    const dir = bindTextDomainDir(domain, localeDir ?? null) as string;
+    bindTextDomainCodeset(domain, "UTF-8");
+    return dir;
+};
+
+/**
+ * Sets the default text domain, used by {@link translate} without a domain
+ * and by `Gtk.Builder` files that set no `translation-domain`, so
+ * application strings and UI files read the same catalogs.
+ *
+ * @param domain - The text domain, bound with {@link bindTextDomain}
+ */
+export const setTextDomain = (domain: string): void => {
+    textDomain(domain);
+};
+
+/**
+ * Returns the default text domain, `"messages"` unless set with
+ * {@link setTextDomain}.
+ */
+export const getTextDomain = (): string => textDomain(null) as string;
+
+/**
+ * Translates a message with gettext, in the same locale as GTK's own
+ * strings.
+ *
+ * Messages without a translation are returned unchanged, as are all
+ * messages until GTK has initialized and set the locale. With a context,
+ * the message is looked up as `pgettext` would, so the same text can be
+ * translated differently in different places.
+ *
+ * @param msgid - The untranslated message
+ * @param domain - The text domain. Defaults to the one set with {@link setTextDomain}.
+ * @param context - Disambiguating context, as `msgctxt` in the catalog
+ * @returns The translated message, or `msgid` if there is none
+ *
+ * @example
+ * ```tsx
+ * const _ = (msgid: string) => translate(msgid);
+ * button.setLabel(_("Open"));
+ * menu.append(translate("Open", null, "menu"), "app.open-recent");
+ * ```
+ */
+export const translate = (msgid: string, domain: string | null = null, context: string | null = null): string =>
+    (context === null ? dgettext(domain, msgid) : dpgettext2(domain, context, msgid)) as string;
@@ -0,0 +1,5 @@
+export * from "../generated/glib/index.js";
+export * from "./i18n.js";
+export * from "./mapped-file.js";
+export * from "./source.js";
+export * from "./variant.js";
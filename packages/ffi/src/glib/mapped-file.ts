@@ -0,0 +1,51 @@
+import { type MapFileOptions, mapFile as nativeMapFile, mappedFileBuffer, type NativeHandle } from "@gtkx/native";
+import { Bytes } from "../generated/glib/bytes.js";
+import { MappedFile } from "../generated/glib/mapped-file.js";
+import { t } from "../native.js";
+import { getNativeObject } from "../registry.js";
+
+const LIB = "libglib-2.0.so.0";
+
+const getBytes = t.fn(
+    LIB,
+    "g_mapped_file_get_bytes",
+    [{ type: t.boxed("GMappedFile", "borrowed", LIB, "g_mapped_file_get_type") }],
+    t.boxed("GBytes", "full", LIB, "g_bytes_get_type"),
+);
+
+/** A file mapped into memory, from {@link mapFile}. */
+export type MappedFileView = {
+    /** The underlying `GMappedFile`. */
+    file: MappedFile;
+    /** Contents of the file, backed by the mapping rather than a copy. */
+    buffer: Buffer;
+    /** Wraps the mapping in `GBytes` without copying, for APIs that load fonts, images or resources from bytes. */
+    toBytes: () => Bytes;
+};
+
+/**
+ * Maps a file into memory, so large resources and fonts can be read and
+ * handed to GTK without copying them into the JS heap.
+ *
+ * The mapping is released once the file, the buffer and every `GBytes`
+ * from `toBytes` have been garbage collected.
+ *
+ * @param path - Path of the file to map
+ * @param options - Whether the mapping can be modified, privately to the process
+ * @throws If the file cannot be opened or mapped
+ *
+ * @example
+ * ```tsx
+ * const { buffer, toBytes } = mapFile("/usr/share/backgrounds/default.png");
+ * console.log(buffer.subarray(1, 4).toString());
+ * const texture = Gdk.Texture.newFromBytes(toBytes());
+ * ```
+ */
+export const mapFile = (path: string, options: MapFileOptions = {}): MappedFileView => {
+    const handle = nativeMapFile(path, options);
+    return {
+        file: getNativeObject(handle, MappedFile),
+        buffer: mappedFileBuffer(handle),
+        toBytes: () => getNativeObject(getBytes(handle) as NativeHandle, Bytes),
+    };
+};
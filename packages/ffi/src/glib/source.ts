@@ -0,0 +1,70 @@
+import { t } from "../native.js";
+
+const LIB = "libglib-2.0.so.0";
+
+const PRIORITY_DEFAULT = 0;
+const PRIORITY_DEFAULT_IDLE = 200;
+
+const SOURCE_FUNC = t.trampoline([t.void], t.boolean, {
+    hasDestroy: true,
+    userDataIndex: 0,
+    scope: "notified",
+    kind: "source",
+});
+
+const timeoutAddFull = t.fn(
+    LIB,
+    "g_timeout_add_full",
+    [{ type: t.int32 }, { type: t.uint32 }, { type: SOURCE_FUNC }],
+    t.uint32,
+);
+const idleAddFull = t.fn(LIB, "g_idle_add_full", [{ type: t.int32 }, { type: SOURCE_FUNC }], t.uint32);
+
+/**
+ * A callback run by a `GLib` main loop source. Returning `true`
+ * (`GLib.SOURCE_CONTINUE`) runs it again; returning nothing, `false`
+ * (`GLib.SOURCE_REMOVE`) or throwing removes the source.
+ */
+export type SourceCallback = () => boolean | undefined | void;
+
+/** Options for {@link addTimeout} and {@link addIdle}. */
+export type SourceOptions = {
+    /** Priority of the source, such as `GLib.PRIORITY_HIGH`. */
+    priority?: number;
+};
+
+/**
+ * Runs a callback on the `GLib` main loop after `interval` milliseconds,
+ * and again every `interval` milliseconds while it returns `true`.
+ *
+ * Unlike `setTimeout`, the callback is dispatched among GTK's own sources,
+ * so it is ordered against frame clock updates and other main loop work.
+ * The callback is released once the source is removed.
+ *
+ * @param interval - Delay in milliseconds
+ * @param callback - Called when the timeout expires
+ * @param options - Source priority, `GLib.PRIORITY_DEFAULT` by default
+ * @returns The source id, for `GLib.sourceRemove`
+ *
+ * @example
+ * ```tsx
+ * const id = addTimeout(1000, () => {
+ *     tick();
+ *     return GLib.SOURCE_CONTINUE;
+ * });
+ * GLib.sourceRemove(id);
+ * ```
+ */
+export const addTimeout = (interval: number, callback: SourceCallback, options: SourceOptions = {}): number =>
+    timeoutAddFull(options.priority ?? PRIORITY_DEFAULT, interval, callback) as number;
+
+/**
+ * Runs a callback on the `GLib` main loop when no higher priority events
+ * are pending, and again while it returns `true`.
+ *
+ * @param callback - Called when the main loop is idle
+ * @param options - Source priority, `GLib.PRIORITY_DEFAULT_IDLE` by default
+ * @returns The source id, for `GLib.sourceRemove`
+ */
+export const addIdle = (callback: SourceCallback, options: SourceOptions = {}): number =>
+    idleAddFull(options.priority ?? PRIORITY_DEFAULT_IDLE, callback) as number;
@@ -0,0 +1,254 @@
+import { createRef, type NativeHandle, type Type } from "@gtkx/native";
+import { GError } from "../generated/glib/error.js";
+import { Variant } from "../generated/glib/variant.js";
+import { NativeError, t } from "../native.js";
+import { getNativeObject } from "../registry.js";
+
+const LIB = "libglib-2.0.so.0";
+
+const VARIANT = t.fundamental(LIB, "g_variant_ref_sink", "g_variant_unref", "borrowed", "GVariant");
+const VARIANT_FULL = t.fundamental(LIB, "g_variant_ref_sink", "g_variant_unref", "full", "GVariant");
+const VARIANT_TYPE = t.boxed("GVariantType", "full", LIB, "g_variant_type_get_gtype");
+const GERROR_REF = t.ref(t.boxed("GError", "full", LIB, "g_error_get_type"));
+const STRING = t.string("borrowed");
+
+const typeStringIsValid = t.fn(LIB, "g_variant_type_string_is_valid", [{ type: STRING }], t.boolean);
+const newType = t.fn(LIB, "g_variant_type_new", [{ type: STRING }], VARIANT_TYPE);
+const parse = t.fn(
+    LIB,
+    "g_variant_parse",
+    [
+        { type: t.boxed("GVariantType", "borrowed", LIB, "g_variant_type_get_gtype") },
+        { type: STRING },
+        { type: t.uint64 },
+        { type: t.uint64 },
+        { type: GERROR_REF },
+    ],
+    VARIANT_FULL,
+);
+const print = t.fn(LIB, "g_variant_print", [{ type: VARIANT }, { type: t.boolean }], t.string("full"));
+
+const getTypeString = t.fn(LIB, "g_variant_get_type_string", [{ type: VARIANT }], STRING);
+const nChildren = t.fn(LIB, "g_variant_n_children", [{ type: VARIANT }], t.uint64);
+const getChildValue = t.fn(LIB, "g_variant_get_child_value", [{ type: VARIANT }, { type: t.uint64 }], VARIANT_FULL);
+const getVariant = t.fn(LIB, "g_variant_get_variant", [{ type: VARIANT }], VARIANT_FULL);
+const getMaybe = t.fn(LIB, "g_variant_get_maybe", [{ type: VARIANT }], VARIANT_FULL);
+const getString = t.fn(LIB, "g_variant_get_string", [{ type: VARIANT }, { type: t.uint64 }], STRING);
+
+const scalarGetter = (symbol: string, returnType: Type) => t.fn(LIB, symbol, [{ type: VARIANT }], returnType);
+
+const getByte = scalarGetter("g_variant_get_byte", t.uint8);
+
+const SCALAR_GETTERS: Record<string, (handle: NativeHandle) => unknown> = {
+    b: scalarGetter("g_variant_get_boolean", t.boolean),
+    y: getByte,
+    n: scalarGetter("g_variant_get_int16", t.int16),
+    q: scalarGetter("g_variant_get_uint16", t.uint16),
+    i: scalarGetter("g_variant_get_int32", t.int32),
+    u: scalarGetter("g_variant_get_uint32", t.uint32),
+    x: scalarGetter("g_variant_get_int64", t.int64),
+    t: scalarGetter("g_variant_get_uint64", t.uint64),
+    h: scalarGetter("g_variant_get_handle", t.int32),
+    d: scalarGetter("g_variant_get_double", t.float64),
+};
+
+/**
+ * A value paired with its `GVariant` type signature, used where the
+ * signature cannot be inferred: inside a `v` (variant) slot.
+ */
+export type VariantBox = {
+    signature: string;
+    value: unknown;
+};
+
+/**
+ * Pairs a value with a type signature for a `v` slot of {@link packVariant}.
+ *
+ * @param signature - `GVariant` type string of the value, such as `"s"` or `"a{sv}"`
+ * @param value - The value
+ */
+export const boxVariant = (signature: string, value: unknown): VariantBox => ({ signature, value });
+
+/** Returns the index just past the complete type starting at `start`. */
+const typeEnd = (signature: string, start: number): number => {
+    const head = signature[start];
+    if (head === "a" || head === "m") return typeEnd(signature, start + 1);
+    if (head === "(" || head === "{") {
+        const close = head === "(" ? ")" : "}";
+        let i = start + 1;
+        while (signature[i] !== close) {
+            if (i >= signature.length) throw new Error(`Invalid GVariant type '${signature}'`);
+            i = typeEnd(signature, i);
+        }
+        return i + 1;
+    }
+    if (head === undefined) throw new Error(`Invalid GVariant type '${signature}'`);
+    return start + 1;
+};
+
+/** Splits the member types of a tuple or dict entry type. */
+const memberTypes = (signature: string): string[] => {
+    const members: string[] = [];
+    let i = 1;
+    while (i < signature.length - 1) {
+        const end = typeEnd(signature, i);
+        members.push(signature.slice(i, end));
+        i = end;
+    }
+    return members;
+};
+
+// The GVariant text format accepts double-quoted strings with the same
+// escapes JSON uses.
+const quote = (text: string): string => JSON.stringify(text);
+
+const formatNumber = (signature: string, value: unknown): string => {
+    if (typeof value === "bigint") return String(value);
+    const number = Number(value);
+    if (!Number.isFinite(number)) {
+        throw new Error(`Cannot encode ${String(value)} as GVariant type '${signature}'`);
+    }
+    return String(signature === "d" ? number : Math.trunc(number));
+};
+
+const formatBoxed = (value: unknown): string => {
+    if (value instanceof Variant) return `@${getTypeString(value.handle) as string} ${print(value.handle, true)}`;
+    const box = value as VariantBox;
+    if (typeof box?.signature !== "string") {
+        throw new Error("Expected a Variant or boxVariant() value for GVariant type 'v'");
+    }
+    return `@${box.signature} ${formatValue(box.signature, box.value)}`;
+};
+
+const formatEntries = (signature: string, value: unknown): string => {
+    const [keyType, valueType] = memberTypes(signature.slice(1)) as [string, string];
+    const entries = value instanceof Map ? [...value.entries()] : Object.entries(value as object);
+    const formatted = entries.map(([key, item]) => `${formatValue(keyType, key)}: ${formatValue(valueType, item)}`);
+    return `{${formatted.join(", ")}}`;
+};
+
+const formatValue = (signature: string, value: unknown): string => {
+    switch (signature[0]) {
+        case "b":
+            return value ? "true" : "false";
+        case "y":
+        case "n":
+        case "q":
+        case "i":
+        case "u":
+        case "x":
+        case "t":
+        case "h":
+        case "d":
+            return formatNumber(signature, value);
+        case "s":
+        case "o":
+        case "g":
+            return quote(String(value));
+        case "v":
+            return `<${formatBoxed(value)}>`;
+        case "m":
+            return value === null || value === undefined ? "nothing" : `just ${formatValue(signature.slice(1), value)}`;
+        case "a": {
+            const itemType = signature.slice(1);
+            if (itemType.startsWith("{")) return formatEntries(itemType, value);
+            const items = Array.from(value as Iterable<unknown>);
+            return `[${items.map((item) => formatValue(itemType, item)).join(", ")}]`;
+        }
+        case "(": {
+            const members = memberTypes(signature);
+            const items = value as readonly unknown[];
+            if (items.length !== members.length) {
+                const expected = `Expected ${members.length} items for GVariant type '${signature}'`;
+                throw new Error(`${expected}, got ${items.length}`);
+            }
+            const formatted = members.map((member, i) => formatValue(member, items[i]));
+            return members.length === 1 ? `(${formatted[0]},)` : `(${formatted.join(", ")})`;
+        }
+        default:
+            throw new Error(`Unsupported GVariant type '${signature}'`);
+    }
+};
+
+/**
+ * Builds a `GVariant` of type `signature` from a JavaScript value.
+ *
+ * Booleans, numbers and strings map to the basic types; arrays map to
+ * arrays and tuples (`ay` also accepts a `Uint8Array`); objects and `Map`s
+ * map to dictionaries; `null` maps to an empty maybe. A `v` slot takes a
+ * `Variant` or a {@link boxVariant} value.
+ *
+ * @param signature - `GVariant` type string, such as `"(sa{sv})"`
+ * @param value - The value to encode
+ * @returns A new variant
+ * @throws if the signature is invalid or the value does not match it
+ *
+ * @example
+ * ```tsx
+ * const reply = packVariant("(a{sv})", [{ title: boxVariant("s", "Notes") }]);
+ * ```
+ */
+export const packVariant = (signature: string, value: unknown): Variant => {
+    if (!typeStringIsValid(signature)) {
+        throw new Error(`Invalid GVariant type '${signature}'`);
+    }
+    const error = createRef<NativeHandle | null>(null);
+    const handle = parse(newType(signature), formatValue(signature, value), 0, 0, error) as NativeHandle;
+    if (error.value !== null) {
+        throw new NativeError(getNativeObject(error.value, GError));
+    }
+    return getNativeObject(handle, Variant);
+};
+
+const unpackHandle = (handle: NativeHandle): unknown => {
+    const signature = getTypeString(handle) as string;
+    const head = signature[0] as string;
+
+    const scalar = SCALAR_GETTERS[head];
+    if (scalar) return scalar(handle);
+
+    switch (head) {
+        case "s":
+        case "o":
+        case "g":
+            return getString(handle, 0);
+        case "v":
+            return unpackHandle(getVariant(handle) as NativeHandle);
+        case "m": {
+            const inner = getMaybe(handle) as NativeHandle | null;
+            return inner ? unpackHandle(inner) : null;
+        }
+        default:
+            break;
+    }
+
+    const count = Number(nChildren(handle));
+    const children: NativeHandle[] = [];
+    for (let i = 0; i < count; i++) {
+        children.push(getChildValue(handle, i) as NativeHandle);
+    }
+
+    if (signature.startsWith("a{")) {
+        const dict: Record<string, unknown> = {};
+        for (const entry of children) {
+            const key = unpackHandle(getChildValue(entry, 0) as NativeHandle);
+            dict[String(key)] = unpackHandle(getChildValue(entry, 1) as NativeHandle);
+        }
+        return dict;
+    }
+    if (signature === "ay") {
+        return Uint8Array.from(children, (child) => getByte(child) as number);
+    }
+    return children.map(unpackHandle);
+};
+
+/**
+ * Converts a `GVariant` into plain JavaScript values, recursively.
+ *
+ * The inverse of {@link packVariant}, except that `v` slots unpack to the
+ * value they hold, dictionaries unpack to objects with string keys, and
+ * tuples unpack to arrays.
+ *
+ * @param variant - The variant to convert
+ */
+export const unpackVariant = (variant: Variant): unknown => unpackHandle(variant.handle);
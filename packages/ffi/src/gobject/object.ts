@@ -1,11 +1,11 @@
-import type { NativeHandle } from "@gtkx/native";
+import { type CallbackPriority, connect, disconnect as disconnectHandler, type NativeHandle } from "@gtkx/native";
 import { typeClassRef, typeFromName, typeNameFromInstance } from "../generated/gobject/functions.js";
 import { Object as GObject } from "../generated/gobject/object.js";
 import { ObjectClass } from "../generated/gobject/object-class.js";
 import { TypeInstance } from "../generated/gobject/type-instance.js";
 import { Value } from "../generated/gobject/value.js";
-import { call, t } from "../native.js";
-import { getNativeObject } from "../registry.js";
+import { call, t, withCallbackPriority } from "../native.js";
+import { getNativeObject, untrackSignalHandler } from "../registry.js";
 
 declare module "../generated/gobject/object.js" {
     namespace Object {
@@ -92,6 +92,21 @@ declare module "../generated/gobject/object.js" {
          *   be marshalled to the property's GType
          */
         setProperty(propertyName: string, value: unknown): void;
+
+        /**
+         * Sets several properties by name, coalescing their change
+         * notifications.
+         *
+         * Each entry is applied as by {@link Object.setProperty} inside a
+         * {@link freezeNotify} / {@link thawNotify} pair, so `notify` handlers
+         * run once per changed property after all values are set rather than
+         * interleaved with the assignments.
+         *
+         * @param properties - Property names (kebab-case GIR names) mapped to values
+         * @throws if any property does not exist or cannot be marshalled; the
+         *   notification queue is still thawed
+         */
+        setProperties(properties: Record<string, unknown>): void;
     }
 }
 
@@ -157,15 +172,8 @@ const untrackListener = (instance: GObject, signal: string, handler: Listener):
 };
 
 GObject.prototype.disconnect = function disconnect(handlerId: number): void {
-    call(
-        LIB,
-        "g_signal_handler_disconnect",
-        [
-            { type: GOBJECT_BORROWED, value: this.handle },
-            { type: t.uint64, value: handlerId },
-        ],
-        t.void,
-    );
+    disconnectHandler(this.handle, handlerId);
+    untrackSignalHandler(this, handlerId);
 };
 
 GObject.prototype.on = function on<T extends GObject>(this: T, signal: string, handler: Listener, after?: boolean): T {
@@ -221,15 +229,14 @@ const resolvePropertyValueType = (obj: GObject, propertyName: string): number =>
     return pspec.getDefaultValue().getType();
 };
 
-GObject.prototype.getProperty = function getProperty(propertyName: string): unknown {
-    const valueType = resolvePropertyValueType(this, propertyName);
+const readProperty = (obj: GObject, propertyName: string, valueType: number): unknown => {
     const gvalue = new Value();
     gvalue.init(valueType);
     call(
         LIB,
         "g_object_get_property",
         [
-            { type: GOBJECT_BORROWED, value: this.handle },
+            { type: GOBJECT_BORROWED, value: obj.handle },
             { type: t.string("borrowed"), value: propertyName },
             { type: GVALUE_BORROWED_TYPE, value: gvalue.handle },
         ],
@@ -238,6 +245,116 @@ GObject.prototype.getProperty = function getProperty(propertyName: string): unkn
     return gvalue.toJS();
 };
 
+GObject.prototype.getProperty = function getProperty(propertyName: string): unknown {
+    return readProperty(this, propertyName, resolvePropertyValueType(this, propertyName));
+};
+
+/**
+ * Increases the notify freeze count of an object, queueing `notify`
+ * emissions until a matching {@link thawNotify}.
+ *
+ * @param handle - The object's native handle
+ */
+export const freezeNotify = (handle: NativeHandle): void => {
+    call(LIB, "g_object_freeze_notify", [{ type: GOBJECT_BORROWED, value: handle }], t.void);
+};
+
+/**
+ * Decreases the notify freeze count of an object, emitting queued `notify`
+ * signals (one per distinct property) once it reaches zero.
+ *
+ * @param handle - The object's native handle
+ */
+export const thawNotify = (handle: NativeHandle): void => {
+    call(LIB, "g_object_thaw_notify", [{ type: GOBJECT_BORROWED, value: handle }], t.void);
+};
+
+/** Options for {@link connectSignal}. */
+export type ConnectSignalOptions = {
+    /** Run after the default handler. */
+    after?: boolean;
+    /**
+     * Lane the handler's calls are queued in on the JS thread. Use `"high"`
+     * for input handlers that must not wait behind slow callbacks.
+     */
+    priority?: CallbackPriority;
+};
+
+/**
+ * Connects a handler to a signal with delivery options.
+ *
+ * @param obj - The object to connect to
+ * @param signal - The signal name
+ * @param handler - The callback function
+ * @param options - Delivery options
+ * @returns The signal handler ID, for use with `disconnect`
+ */
+export const connectSignal = (
+    obj: GObject,
+    signal: string,
+    handler: Listener,
+    options: ConnectSignalOptions = {},
+): number => withCallbackPriority(options.priority ?? "default", () => obj.connect(signal, handler, options.after));
+
+const PARAM_SPEC_BORROWED = t.fundamental(LIB, "g_param_spec_ref_sink", "g_param_spec_unref");
+const NOTIFY_CLOSURE_COALESCED = t.callback([GOBJECT_BORROWED, PARAM_SPEC_BORROWED], t.void, { coalesce: true });
+
+/**
+ * Observes several properties of an object, delivering their new values in
+ * batches.
+ *
+ * Each property gets a coalesced `notify::<name>` closure, so the emitting
+ * thread never waits for JavaScript and bursts of changes to one property
+ * collapse into a single read. Properties that change together are reported
+ * in one call, mapped to their current values. Property types are resolved
+ * once up front rather than on every change.
+ *
+ * @param obj - The object to observe
+ * @param propertyNames - Property names (kebab-case GIR names)
+ * @param handler - Called with the properties that changed since the last call
+ * @returns A function that stops observing
+ * @throws if any property does not exist on the object
+ *
+ * @example
+ * ```tsx
+ * const stop = observeProperties(window, ["title", "maximized"], (changes) => {
+ *     if ("title" in changes) setTitle(changes.title as string);
+ * });
+ * ```
+ */
+export const observeProperties = (
+    obj: GObject,
+    propertyNames: readonly string[],
+    handler: (changes: Record<string, unknown>) => void,
+): (() => void) => {
+    const valueTypes = new Map(propertyNames.map((name) => [name, resolvePropertyValueType(obj, name)]));
+    const changed = new Set<string>();
+    let flush: ReturnType<typeof setImmediate> | null = null;
+
+    const deliver = () => {
+        flush = null;
+        const changes: Record<string, unknown> = {};
+        for (const name of changed) {
+            changes[name] = readProperty(obj, name, valueTypes.get(name) as number);
+        }
+        changed.clear();
+        handler(changes);
+    };
+
+    const handlerIds = [...valueTypes.keys()].map((name) => {
+        const callback = () => {
+            changed.add(name);
+            flush ??= setImmediate(deliver);
+        };
+        return connect(obj.handle, `notify::${name}`, { type: NOTIFY_CLOSURE_COALESCED, value: callback });
+    });
+
+    return () => {
+        if (flush) clearImmediate(flush);
+        for (const handlerId of handlerIds) obj.disconnect(handlerId);
+    };
+};
+
 GObject.prototype.setProperty = function setProperty(propertyName: string, value: unknown): void {
     const valueType = resolvePropertyValueType(this, propertyName);
     const gvalue = Value.fromJS(valueType, value);
@@ -252,3 +369,14 @@ GObject.prototype.setProperty = function setProperty(propertyName: string, value
         t.void,
     );
 };
+
+GObject.prototype.setProperties = function setProperties(properties: Record<string, unknown>): void {
+    freezeNotify(this.handle);
+    try {
+        for (const [propertyName, value] of Object.entries(properties)) {
+            this.setProperty(propertyName, value);
+        }
+    } finally {
+        thawNotify(this.handle);
+    }
+};
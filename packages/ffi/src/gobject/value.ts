@@ -4,7 +4,7 @@ import type { Object as GObject } from "../generated/gobject/object.js";
 import type { ParamSpec } from "../generated/gobject/param-spec.js";
 import { Value } from "../generated/gobject/value.js";
 import type { NativeClass, NativeObject } from "../native.js";
-import { call, read, t } from "../native.js";
+import { call, getTypeRegistration, read, t } from "../native.js";
 import { findNativeClass, getNativeObject } from "../registry.js";
 import { Type } from "./types.js";
 
@@ -332,6 +332,18 @@ type ValueStatic = {
 
 const ValueWithStatics = Value as typeof Value & ValueStatic;
 
+type TypeLocation = { library?: string; getTypeFn?: string; typeName?: string };
+
+/** Calls the `get_type` function of an enum or flags type, or of the type registered under its name. */
+const taggedGType = (ffiType: TypeLocation): number => {
+    const registered = ffiType.typeName === undefined ? undefined : getTypeRegistration(ffiType.typeName);
+    const location = ffiType.library === undefined && registered && "getTypeFn" in registered ? registered : ffiType;
+    if (location.library === undefined || location.getTypeFn === undefined) {
+        throw new Error(`Cannot resolve the GType of ${ffiType.typeName ?? "an unnamed enum or flags type"}`);
+    }
+    return call(location.library, location.getTypeFn, [], t.uint64) as number;
+};
+
 ValueWithStatics.newFromBoolean = (value) => initValue(Type.BOOLEAN, (v) => v.setBoolean(value));
 ValueWithStatics.newFromInt = (value) => initValue(Type.INT, (v) => v.setInt(value));
 ValueWithStatics.newFromUint = (value) => initValue(Type.UINT, (v) => v.setUint(value));
@@ -393,7 +405,7 @@ ValueWithStatics.newFrom = (ffiType: FfiType, value: unknown): Value => {
             return Value.newFromString(value as string | null);
 
         case "enum": {
-            const gtype = call(ffiType.library, ffiType.getTypeFn, [], t.uint64) as number;
+            const gtype = taggedGType(ffiType);
             const fundamental = typeFundamental(gtype);
             if (fundamental === Type.FLAGS) {
                 return Value.newFromFlags(gtype, value as number);
@@ -402,7 +414,7 @@ ValueWithStatics.newFrom = (ffiType: FfiType, value: unknown): Value => {
         }
 
         case "flags": {
-            const gtype = call(ffiType.library, ffiType.getTypeFn, [], t.uint64) as number;
+            const gtype = taggedGType(ffiType);
             return Value.newFromFlags(gtype, value as number);
         }
 
@@ -443,11 +455,14 @@ ValueWithStatics.newFrom = (ffiType: FfiType, value: unknown): Value => {
             );
         }
 
-        case "fundamental":
-            if (ffiType.refFn === "g_variant_ref_sink") {
+        case "fundamental": {
+            const registered = ffiType.typeName === undefined ? undefined : getTypeRegistration(ffiType.typeName);
+            const refFn = ffiType.refFn ?? (registered?.kind === "fundamental" ? registered.refFn : undefined);
+            if (refFn === "g_variant_ref_sink") {
                 return Value.newFromVariant(value as NativeObject);
             }
             return Value.newFromBoxed(value as NativeObject);
+        }
 
         default:
             throw new Error(`Unsupported FFI type for GValue conversion: ${(ffiType as { type: string }).type}`);
@@ -0,0 +1,91 @@
+import { Display } from "../generated/gdk/display.js";
+import type { AppInfo } from "../generated/gio/app-info.js";
+import type { Window } from "../generated/gtk/window.js";
+
+/** Options for {@link requestActivationToken}. */
+export type ActivationTokenOptions = {
+    /** Application being launched, for the startup notification. */
+    appInfo?: AppInfo | null;
+    /** Timestamp of the user event that caused the launch. */
+    timestamp?: number;
+};
+
+/** Options for {@link presentWindow}. */
+export type PresentOptions = {
+    /** Activation token or startup id received with the launch or activation. */
+    activationToken?: string | null;
+    /** Timestamp of the user event that caused the window to be presented. */
+    timestamp?: number;
+};
+
+/**
+ * Requests an activation token to pass to an application launched by this
+ * one, so the compositor lets it take focus rather than treating it as
+ * focus stealing.
+ *
+ * On Wayland this is an XDG activation token, on X11 a startup
+ * notification id. Hand it to the launched process with
+ * {@link activationEnvironment}, or as the `activation-token` platform data
+ * of a D-Bus activation.
+ *
+ * @param options - Launched application and triggering event time
+ * @returns The token, or null if the display does not support activation
+ *
+ * @example
+ * ```tsx
+ * const token = requestActivationToken();
+ * spawn("gnome-text-editor", [path], { env: { ...process.env, ...activationEnvironment(token) } });
+ * ```
+ */
+export const requestActivationToken = (options: ActivationTokenOptions = {}): string | null => {
+    const display = Display.getDefault();
+    if (!display) return null;
+
+    const context = display.getAppLaunchContext();
+    if (options.timestamp !== undefined) context.setTimestamp(options.timestamp);
+    return context.getStartupNotifyId(options.appInfo ?? null, null);
+};
+
+/**
+ * Returns the environment variables that pass an activation token to a
+ * child process, covering both Wayland and X11.
+ *
+ * @param token - Token from {@link requestActivationToken}; null gives no variables
+ */
+export const activationEnvironment = (token: string | null): Record<string, string> =>
+    token ? { XDG_ACTIVATION_TOKEN: token, DESKTOP_STARTUP_ID: token } : {};
+
+/**
+ * Reads the activation token from the platform data of a D-Bus activation,
+ * such as the `org.freedesktop.Application` `Activate`, `Open` and
+ * `ActivateAction` methods.
+ *
+ * @param platformData - The unpacked `a{sv}` platform data
+ * @returns The `activation-token`, else the `desktop-startup-id`, or null
+ */
+export const activationTokenFromPlatformData = (platformData: Record<string, unknown>): string | null => {
+    const token = platformData["activation-token"] ?? platformData["desktop-startup-id"];
+    return typeof token === "string" && token !== "" ? token : null;
+};
+
+/**
+ * Presents a window, consuming the activation token of the launch or
+ * activation that asked for it, so the window is raised and focused
+ * instead of only marked as demanding attention.
+ *
+ * @param window - The window to present
+ * @param options - Activation token and triggering event time
+ *
+ * @example
+ * ```tsx
+ * events.on("activated", ({ activationToken }) => presentWindow(window, { activationToken }));
+ * ```
+ */
+export const presentWindow = (window: Window, options: PresentOptions = {}): void => {
+    if (options.activationToken) window.setStartupId(options.activationToken);
+    if (options.timestamp !== undefined) {
+        window.presentWithTime(options.timestamp);
+    } else {
+        window.present();
+    }
+};
@@ -0,0 +1,99 @@
+import { connect, type NativeHandle } from "@gtkx/native";
+import type { Adjustment } from "../generated/gtk/adjustment.js";
+import { t } from "../native.js";
+
+const LIB = "libgtk-4.so.1";
+
+const ADJUSTMENT = t.object("borrowed");
+const F64 = t.float64;
+
+const getValue = t.fn(LIB, "gtk_adjustment_get_value", [{ type: ADJUSTMENT }], F64);
+const getLower = t.fn(LIB, "gtk_adjustment_get_lower", [{ type: ADJUSTMENT }], F64);
+const getUpper = t.fn(LIB, "gtk_adjustment_get_upper", [{ type: ADJUSTMENT }], F64);
+const getStepIncrement = t.fn(LIB, "gtk_adjustment_get_step_increment", [{ type: ADJUSTMENT }], F64);
+const getPageIncrement = t.fn(LIB, "gtk_adjustment_get_page_increment", [{ type: ADJUSTMENT }], F64);
+const getPageSize = t.fn(LIB, "gtk_adjustment_get_page_size", [{ type: ADJUSTMENT }], F64);
+
+const configure = t.fn(
+    LIB,
+    "gtk_adjustment_configure",
+    [
+        { type: ADJUSTMENT },
+        { type: F64 },
+        { type: F64 },
+        { type: F64 },
+        { type: F64 },
+        { type: F64 },
+        { type: F64 },
+    ],
+    t.void,
+);
+
+const VALUE_CHANGED_CLOSURE = t.callback([ADJUSTMENT], t.void);
+const VALUE_CHANGED_CLOSURE_COALESCED = t.callback([ADJUSTMENT], t.void, { coalesce: true });
+
+/** Fields accepted by {@link configureAdjustment}. Omitted fields keep their current value. */
+export type AdjustmentConfig = {
+    value?: number;
+    lower?: number;
+    upper?: number;
+    stepIncrement?: number;
+    pageIncrement?: number;
+    pageSize?: number;
+};
+
+/** Options for {@link connectValueChanged}. */
+export type ValueChangedOptions = {
+    /**
+     * Collapse bursts of `value-changed` emissions into a single handler call
+     * per poll cycle. The handler receives the adjustment's value at delivery time.
+     */
+    coalesce?: boolean;
+    /** Run after the default handler. */
+    after?: boolean;
+};
+
+/**
+ * Sets all properties of an adjustment in a single native call.
+ *
+ * Wraps `gtk_adjustment_configure`, which emits `changed` once and
+ * `value-changed` at most once, instead of once per property set. Fields
+ * omitted from `config` are read from the adjustment first.
+ *
+ * @param adjustment - The adjustment to configure
+ * @param config - The fields to update
+ */
+export const configureAdjustment = (adjustment: Adjustment, config: AdjustmentConfig): void => {
+    const handle = adjustment.handle;
+    configure(
+        handle,
+        config.value ?? getValue(handle),
+        config.lower ?? getLower(handle),
+        config.upper ?? getUpper(handle),
+        config.stepIncrement ?? getStepIncrement(handle),
+        config.pageIncrement ?? getPageIncrement(handle),
+        config.pageSize ?? getPageSize(handle),
+    );
+};
+
+/**
+ * Connects a handler to an adjustment's `value-changed` signal.
+ *
+ * With `coalesce: true` the emitting thread never waits for JavaScript, and
+ * rapid emissions (e.g. during a scroll or drag) are delivered as one call
+ * carrying the latest value.
+ *
+ * @param adjustment - The adjustment to observe
+ * @param handler - Called with the current value
+ * @param options - Delivery options
+ * @returns The signal handler ID, for use with `disconnect`
+ */
+export const connectValueChanged = (
+    adjustment: Adjustment,
+    handler: (value: number) => void,
+    options: ValueChangedOptions = {},
+): number => {
+    const type = options.coalesce ? VALUE_CHANGED_CLOSURE_COALESCED : VALUE_CHANGED_CLOSURE;
+    const callback = (instance: NativeHandle) => handler(getValue(instance) as number);
+    return connect(adjustment.handle, "value-changed", { type, value: callback }, { after: options.after });
+};
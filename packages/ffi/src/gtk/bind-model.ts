@@ -0,0 +1,71 @@
+import type { NativeHandle } from "@gtkx/native";
+import type { ListModel } from "../generated/gio/list-model.js";
+import type { Object as GObject } from "../generated/gobject/object.js";
+import type { FlowBox } from "../generated/gtk/flow-box.js";
+import type { ListBox } from "../generated/gtk/list-box.js";
+import type { Widget } from "../generated/gtk/widget.js";
+import { t } from "../native.js";
+import { getNativeObject } from "../registry.js";
+
+const LIB = "libgtk-4.so.1";
+
+const BOX = t.object("borrowed");
+const MODEL = t.object("borrowed");
+const ITEM = t.object("borrowed");
+const CREATE_WIDGET_FUNC = t.trampoline([ITEM, t.void], t.object("full"), {
+    hasDestroy: true,
+    userDataIndex: 1,
+    scope: "notified",
+});
+
+const bindArgs = [{ type: BOX }, { type: MODEL, optional: true }, { type: CREATE_WIDGET_FUNC, optional: true }];
+const listBoxBindModel = t.fn(LIB, "gtk_list_box_bind_model", bindArgs, t.void);
+const flowBoxBindModel = t.fn(LIB, "gtk_flow_box_bind_model", bindArgs, t.void);
+
+/** Creates the widget that shows an item of a bound model. */
+export type CreateWidgetFunc<T extends GObject> = (item: T) => Widget;
+
+const toCreateWidgetFunc =
+    <T extends GObject>(createWidget: CreateWidgetFunc<T>) =>
+    (item: NativeHandle): NativeHandle =>
+        createWidget(getNativeObject(item) as unknown as T).handle;
+
+/**
+ * Fills a `GtkListBox` from a list model, creating a row for each item and
+ * keeping the rows in sync as the model changes.
+ *
+ * Widgets that are not `GtkListBoxRow`s are wrapped in one. Rows cannot be
+ * added or removed by hand while a model is bound.
+ *
+ * @param box - The list box
+ * @param model - The items to show, or null to unbind the current model
+ * @param createWidget - Creates the widget for an item
+ *
+ * @example
+ * ```tsx
+ * bindListBoxModel(listBox, store, (item: Gtk.StringObject) => new Gtk.Label(item.getString()));
+ * ```
+ */
+export const bindListBoxModel = <T extends GObject>(
+    box: ListBox,
+    model: ListModel | null,
+    createWidget: CreateWidgetFunc<T> | null,
+): void => {
+    listBoxBindModel(box.handle, model?.handle ?? null, model && createWidget && toCreateWidgetFunc(createWidget));
+};
+
+/**
+ * Fills a `GtkFlowBox` from a list model, creating a child for each item
+ * and keeping the children in sync as the model changes.
+ *
+ * @param box - The flow box
+ * @param model - The items to show, or null to unbind the current model
+ * @param createWidget - Creates the widget for an item
+ */
+export const bindFlowBoxModel = <T extends GObject>(
+    box: FlowBox,
+    model: ListModel | null,
+    createWidget: CreateWidgetFunc<T> | null,
+): void => {
+    flowBoxBindModel(box.handle, model?.handle ?? null, model && createWidget && toCreateWidgetFunc(createWidget));
+};
@@ -0,0 +1,48 @@
+import { fromRgba, type Rgba, toRgba } from "../gdk/rgba.js";
+import { ColorDialog } from "../generated/gtk/color-dialog.js";
+import type { Window } from "../generated/gtk/window.js";
+import { cancellableFromSignal } from "../gio/cancellable.js";
+import { isDialogDismissed } from "./dialog-error.js";
+
+/** Options for {@link chooseColor}. */
+export type ColorDialogOptions = {
+    title?: string;
+    /** Whether the dialog blocks its parent window. Defaults to true. */
+    modal?: boolean;
+    /** Let the user pick a translucent color. Defaults to true. */
+    withAlpha?: boolean;
+    /** Color selected initially, as channels or a CSS color string. */
+    initial?: Rgba | string;
+    /** Aborts the dialog; the promise then resolves with null. */
+    signal?: AbortSignal;
+};
+
+/**
+ * Presents a `GtkColorDialog` and resolves with the color the user chose.
+ *
+ * @param parent - Window the dialog belongs to, or null
+ * @param options - Title, alpha support, initial color and abort signal
+ * @returns The chosen color with channels in the 0–1 range, or null if the dialog was dismissed
+ *
+ * @example
+ * ```tsx
+ * const color = await chooseColor(window, { withAlpha: false, initial: "#3584e4" });
+ * if (color) setBackground(color);
+ * ```
+ */
+export const chooseColor = async (parent: Window | null, options: ColorDialogOptions = {}): Promise<Rgba | null> => {
+    const dialog = new ColorDialog();
+    if (options.title !== undefined) dialog.setTitle(options.title);
+    if (options.modal !== undefined) dialog.setModal(options.modal);
+    if (options.withAlpha !== undefined) dialog.setWithAlpha(options.withAlpha);
+
+    const initial = options.initial === undefined ? undefined : fromRgba(options.initial);
+
+    try {
+        const chosen = await dialog.chooseRgbaAsync(parent, initial, cancellableFromSignal(options.signal));
+        return toRgba(chosen);
+    } catch (error) {
+        if (isDialogDismissed(error)) return null;
+        throw error;
+    }
+};
@@ -0,0 +1,122 @@
+import type { ListModel } from "../generated/gio/list-model.js";
+import type { Object as GObject } from "../generated/gobject/object.js";
+import type { ColumnView } from "../generated/gtk/column-view.js";
+import { ColumnViewColumn } from "../generated/gtk/column-view-column.js";
+import type { ListItem } from "../generated/gtk/list-item.js";
+import type { SelectionModel } from "../generated/gtk/selection-model.js";
+import { SignalListItemFactory } from "../generated/gtk/signal-list-item-factory.js";
+import { SingleSelection } from "../generated/gtk/single-selection.js";
+import { SortListModel } from "../generated/gtk/sort-list-model.js";
+import { freezeNotify, thawNotify } from "../gobject/object.js";
+import { createCustomSorter } from "./custom-sorter.js";
+import { connectListItemFactory } from "./list-item-factory.js";
+
+/** Describes one column for {@link setupColumnView}. */
+export type ColumnDescriptor<T extends GObject = GObject> = {
+    /** Column ID, as used by `gtk_column_view_column_set_id`. */
+    id?: string;
+    title: string;
+    expand?: boolean;
+    resizable?: boolean;
+    fixedWidth?: number;
+    /** Creates the cell widget for a list item. */
+    setup?: (listItem: ListItem) => void;
+    /** Fills the cell for the list item's current model item. */
+    bind?: (listItem: ListItem, item: T | null) => void;
+    /** Clears the cell before the list item is reused. */
+    unbind?: (listItem: ListItem, item: T | null) => void;
+    /** Releases resources created in `setup`. */
+    teardown?: (listItem: ListItem) => void;
+    /**
+     * Compares two model items; makes the column sortable from its header.
+     * Returns a negative number, zero, or a positive number.
+     */
+    sort?: (a: T, b: T) => number;
+};
+
+/** Options for {@link setupColumnView}. */
+export type ColumnViewSetupOptions = {
+    /**
+     * Wraps the sorted model in a selection model.
+     * Defaults to a `GtkSingleSelection`.
+     */
+    selection?: (model: ListModel) => SelectionModel;
+};
+
+/** Objects created by {@link setupColumnView}. */
+export type ColumnViewSetup = {
+    columns: ColumnViewColumn[];
+    /** The model sorted by the column view's sorter. */
+    sortModel: SortListModel;
+    /** The selection model installed on the column view. */
+    selection: SelectionModel;
+};
+
+const modelItem = <T extends GObject>(listItem: ListItem): T | null => listItem.getItem() as T | null;
+
+const createFactory = <T extends GObject>(descriptor: ColumnDescriptor<T>): SignalListItemFactory => {
+    const factory = new SignalListItemFactory();
+    const { setup, bind, unbind, teardown } = descriptor;
+
+    connectListItemFactory(factory, {
+        setup,
+        bind: bind && ((listItem) => bind(listItem, modelItem<T>(listItem))),
+        unbind: unbind && ((listItem) => unbind(listItem, modelItem<T>(listItem))),
+        teardown,
+    });
+
+    return factory;
+};
+
+const createColumn = <T extends GObject>(descriptor: ColumnDescriptor<T>): ColumnViewColumn => {
+    const column = new ColumnViewColumn(descriptor.title, createFactory(descriptor));
+
+    if (descriptor.id !== undefined) column.setId(descriptor.id);
+    if (descriptor.expand !== undefined) column.setExpand(descriptor.expand);
+    if (descriptor.resizable !== undefined) column.setResizable(descriptor.resizable);
+    if (descriptor.fixedWidth !== undefined) column.setFixedWidth(descriptor.fixedWidth);
+
+    if (descriptor.sort) column.setSorter(createCustomSorter(descriptor.sort));
+
+    return column;
+};
+
+/**
+ * Populates a column view from column descriptors.
+ *
+ * Creates a `GtkColumnViewColumn` with a `GtkSignalListItemFactory` per
+ * descriptor, wires the factory callbacks, and installs `model` behind a
+ * `GtkSortListModel` driven by the column view's own sorter, so clicking a
+ * sortable header re-sorts the rows. Property notifications on the column
+ * view are held until setup completes.
+ *
+ * @param columnView - The column view to populate
+ * @param model - The unsorted item model
+ * @param columns - One descriptor per column, in display order
+ * @param options - Setup options
+ * @returns The created columns and models
+ */
+export const setupColumnView = <T extends GObject = GObject>(
+    columnView: ColumnView,
+    model: ListModel,
+    columns: readonly ColumnDescriptor<T>[],
+    options: ColumnViewSetupOptions = {},
+): ColumnViewSetup => {
+    freezeNotify(columnView.handle);
+    try {
+        const created = columns.map((descriptor) => {
+            const column = createColumn(descriptor);
+            columnView.appendColumn(column);
+            return column;
+        });
+
+        const sortModel = new SortListModel(model, columnView.getSorter());
+        const sorted = sortModel as unknown as ListModel;
+        const selection = options.selection?.(sorted) ?? (new SingleSelection(sorted) as unknown as SelectionModel);
+        columnView.setModel(selection);
+
+        return { columns: created, sortModel, selection };
+    } finally {
+        thawNotify(columnView.handle);
+    }
+};
@@ -0,0 +1,184 @@
+import { Constraint } from "../generated/gtk/constraint.js";
+import { ConstraintLayout } from "../generated/gtk/constraint-layout.js";
+import type { ConstraintTarget } from "../generated/gtk/constraint-target.js";
+import { ConstraintAttribute, ConstraintRelation, ConstraintStrength } from "../generated/gtk/enums.js";
+import type { Widget } from "../generated/gtk/widget.js";
+
+/** Attribute names accepted in place of `Gtk.ConstraintAttribute` values. */
+export type ConstraintAttributeName =
+    | "left"
+    | "right"
+    | "top"
+    | "bottom"
+    | "start"
+    | "end"
+    | "width"
+    | "height"
+    | "centerX"
+    | "centerY"
+    | "baseline";
+
+/** Relation operators accepted in place of `Gtk.ConstraintRelation` values. */
+export type ConstraintRelationName = "<=" | "==" | ">=";
+
+/** Strength names accepted in place of `Gtk.ConstraintStrength` values. */
+export type ConstraintStrengthName = "required" | "strong" | "medium" | "weak";
+
+/**
+ * A constraint of the form
+ * `target.attribute relation source.sourceAttribute × multiplier + constant`.
+ *
+ * Without `source` and `sourceAttribute`, the constraint compares the
+ * target attribute with `constant` alone.
+ */
+export type ConstraintDescriptor = {
+    /** Widget or guide to constrain. Null or omitted means the widget using the layout. */
+    target?: ConstraintTarget | null;
+    attribute: ConstraintAttribute | ConstraintAttributeName;
+    /** Defaults to `"=="`. */
+    relation?: ConstraintRelation | ConstraintRelationName;
+    /** Widget or guide to compare with. Null means the widget using the layout. */
+    source?: ConstraintTarget | null;
+    /** Attribute of the source. Defaults to `attribute`. */
+    sourceAttribute?: ConstraintAttribute | ConstraintAttributeName;
+    /** Defaults to 1. */
+    multiplier?: number;
+    /** Defaults to 0. */
+    constant?: number;
+    /** Defaults to `"required"`. Numeric strengths between weak and strong are allowed. */
+    strength?: ConstraintStrength | ConstraintStrengthName | number;
+};
+
+/** Options for {@link addVflConstraints}. */
+export type VflOptions = {
+    /** Spacing used where the description gives none. Defaults to GTK's fallback. */
+    hspacing?: number;
+    vspacing?: number;
+};
+
+const ATTRIBUTES: Record<ConstraintAttributeName, ConstraintAttribute> = {
+    left: ConstraintAttribute.LEFT,
+    right: ConstraintAttribute.RIGHT,
+    top: ConstraintAttribute.TOP,
+    bottom: ConstraintAttribute.BOTTOM,
+    start: ConstraintAttribute.START,
+    end: ConstraintAttribute.END,
+    width: ConstraintAttribute.WIDTH,
+    height: ConstraintAttribute.HEIGHT,
+    centerX: ConstraintAttribute.CENTER_X,
+    centerY: ConstraintAttribute.CENTER_Y,
+    baseline: ConstraintAttribute.BASELINE,
+};
+
+const RELATIONS: Record<ConstraintRelationName, ConstraintRelation> = {
+    "<=": ConstraintRelation.LE,
+    "==": ConstraintRelation.EQ,
+    ">=": ConstraintRelation.GE,
+};
+
+const STRENGTHS: Record<ConstraintStrengthName, ConstraintStrength> = {
+    required: ConstraintStrength.REQUIRED,
+    strong: ConstraintStrength.STRONG,
+    medium: ConstraintStrength.MEDIUM,
+    weak: ConstraintStrength.WEAK,
+};
+
+const toAttribute = (attribute: ConstraintAttribute | ConstraintAttributeName): ConstraintAttribute =>
+    typeof attribute === "string" ? ATTRIBUTES[attribute] : attribute;
+
+const toRelation = (relation: ConstraintRelation | ConstraintRelationName = "=="): ConstraintRelation =>
+    typeof relation === "string" ? RELATIONS[relation] : relation;
+
+const toStrength = (strength: ConstraintStrength | ConstraintStrengthName | number = "required"): number =>
+    typeof strength === "string" ? STRENGTHS[strength] : strength;
+
+/**
+ * Creates a `GtkConstraint` from a descriptor.
+ *
+ * @param descriptor - Target, attributes, relation and coefficients of the constraint
+ */
+export const createConstraint = (descriptor: ConstraintDescriptor): Constraint => {
+    const target = descriptor.target ?? null;
+    const attribute = toAttribute(descriptor.attribute);
+    const relation = toRelation(descriptor.relation);
+    const strength = toStrength(descriptor.strength);
+
+    if (descriptor.source === undefined && descriptor.sourceAttribute === undefined) {
+        return Constraint.newConstant(target, attribute, relation, descriptor.constant ?? 0, strength);
+    }
+    return new Constraint(
+        target,
+        attribute,
+        relation,
+        descriptor.source ?? null,
+        toAttribute(descriptor.sourceAttribute ?? descriptor.attribute),
+        descriptor.multiplier ?? 1,
+        descriptor.constant ?? 0,
+        strength,
+    );
+};
+
+/**
+ * Installs a new `GtkConstraintLayout` as the layout manager of a widget.
+ *
+ * @param widget - The widget whose children the layout positions
+ * @returns The installed layout
+ */
+export const setConstraintLayout = (widget: Widget): ConstraintLayout => {
+    const layout = new ConstraintLayout();
+    widget.setLayoutManager(layout);
+    return layout;
+};
+
+/**
+ * Adds constraints built from descriptors to a constraint layout.
+ *
+ * @param layout - The layout to add to
+ * @param descriptors - The constraints to add
+ * @returns The added constraints, for later removal
+ *
+ * @example
+ * ```tsx
+ * const layout = setConstraintLayout(container);
+ * addConstraints(layout, [
+ *     { target: button, attribute: "start", source: null, constant: 12 },
+ *     { target: button, attribute: "width", relation: ">=", constant: 120 },
+ *     { target: button, attribute: "centerY", source: null, strength: "strong" },
+ * ]);
+ * ```
+ */
+export const addConstraints = (layout: ConstraintLayout, descriptors: readonly ConstraintDescriptor[]): Constraint[] =>
+    descriptors.map((descriptor) => {
+        const constraint = createConstraint(descriptor);
+        layout.addConstraint(constraint);
+        return constraint;
+    });
+
+/**
+ * Adds constraints described in GTK's variant of the Visual Format
+ * Language to a constraint layout.
+ *
+ * @param layout - The layout to add to
+ * @param lines - One or more VFL lines, such as `"H:|-[name]-[email(==name)]-|"`
+ * @param views - Widgets and guides referenced by name in the lines; `|` stands for the widget using the layout
+ * @param options - Default spacings
+ * @returns The added constraints, for later removal
+ * @throws NativeError if a line cannot be parsed
+ *
+ * @example
+ * ```tsx
+ * addVflConstraints(layout, ["H:|-[cancel]-[ok(==cancel)]-|", "V:[cancel]-|", "V:[ok]-|"], { cancel, ok });
+ * ```
+ */
+export const addVflConstraints = (
+    layout: ConstraintLayout,
+    lines: string | readonly string[],
+    views: Record<string, ConstraintTarget>,
+    options: VflOptions = {},
+): Constraint[] =>
+    layout.addConstraintsFromDescriptionv(
+        typeof lines === "string" ? [lines] : [...lines],
+        options.hspacing ?? -1,
+        options.vspacing ?? -1,
+        new Map(Object.entries(views)),
+    );
@@ -0,0 +1,56 @@
+import { Cursor } from "../generated/gdk/cursor.js";
+import type { Texture } from "../generated/gdk/texture.js";
+import type { Widget } from "../generated/gtk/widget.js";
+
+/** A point of a cursor image, in pixels from its top left corner. */
+export type CursorHotspot = {
+    x: number;
+    y: number;
+};
+
+/**
+ * What {@link setCursor} shows: a cursor name such as `"pointer"` or
+ * `"text"`, an image, a ready-made cursor, or null for the parent's cursor.
+ */
+export type CursorSource = string | Texture | Cursor | null;
+
+const DEFAULT_CURSOR = "default";
+
+/**
+ * Creates a cursor from a CSS cursor name or an image, falling back to the
+ * default cursor where the name or image is not supported.
+ *
+ * @param source - A CSS cursor name, or the cursor image
+ * @param hotspot - The point of an image that clicks; ignored for names
+ * @returns The cursor
+ */
+export const createCursor = (source: string | Texture, hotspot: CursorHotspot = { x: 0, y: 0 }): Cursor => {
+    const fallback = Cursor.newFromName(DEFAULT_CURSOR, null);
+    const cursor =
+        typeof source === "string"
+            ? Cursor.newFromName(source, fallback)
+            : Cursor.newFromTexture(source, hotspot.x, hotspot.y, fallback);
+    return cursor ?? (fallback as Cursor);
+};
+
+/**
+ * Sets the cursor shown while the pointer is over a widget.
+ *
+ * @param widget - The widget
+ * @param source - A CSS cursor name such as `"pointer"`, an image, a
+ *   cursor, or null to show the parent's cursor
+ * @param hotspot - The point of an image that clicks, `{ x: 0, y: 0 }` by default
+ *
+ * @example
+ * ```tsx
+ * setCursor(link, "pointer");
+ * setCursor(canvas, lookupIconTexture("color-select-symbolic", { size: 24 }), { x: 2, y: 21 });
+ * ```
+ */
+export const setCursor = (widget: Widget, source: CursorSource, hotspot?: CursorHotspot): void => {
+    if (source === null || source instanceof Cursor) {
+        widget.setCursor(source);
+        return;
+    }
+    widget.setCursor(createCursor(source, hotspot));
+};
@@ -0,0 +1,64 @@
+import type { NativeHandle } from "@gtkx/native";
+import type { Object as GObject } from "../generated/gobject/object.js";
+import type { CustomFilter } from "../generated/gtk/custom-filter.js";
+import { t } from "../native.js";
+import { getNativeObject } from "../registry.js";
+
+const LIB = "libgtk-4.so.1";
+
+const FILTER = t.object("borrowed");
+const ITEM = t.object("borrowed");
+const MATCH_FUNC = t.trampoline([ITEM, t.void], t.boolean, {
+    hasDestroy: true,
+    userDataIndex: 1,
+    scope: "notified",
+    kind: "filter",
+});
+
+const customFilterNew = t.fn(LIB, "gtk_custom_filter_new", [{ type: MATCH_FUNC, optional: true }], t.object("full"));
+const customFilterSetFilterFunc = t.fn(
+    LIB,
+    "gtk_custom_filter_set_filter_func",
+    [{ type: FILTER }, { type: MATCH_FUNC, optional: true }],
+    t.void,
+);
+
+const toMatchFunc =
+    <T extends GObject>(predicate: (item: T) => unknown) =>
+    (item: NativeHandle): unknown =>
+        predicate(getNativeObject(item) as unknown as T);
+
+/**
+ * Creates a `GtkCustomFilter` that keeps the items a JS predicate accepts.
+ *
+ * The predicate receives the item itself, and its result counts by its
+ * truthiness, as in `Array.prototype.filter`.
+ *
+ * @param predicate - Decides whether an item of the filtered model is kept
+ * @returns The filter
+ *
+ * @example
+ * ```tsx
+ * const filter = createCustomFilter<Gtk.StringObject>((item) => item.getString().includes(query));
+ * const model = new Gtk.FilterListModel(store, filter);
+ * ```
+ */
+export const createCustomFilter = <T extends GObject>(predicate: (item: T) => unknown): CustomFilter =>
+    getNativeObject(customFilterNew(toMatchFunc(predicate)) as NativeHandle) as unknown as CustomFilter;
+
+/**
+ * Replaces the predicate of a `GtkCustomFilter`.
+ *
+ * Filtered models are not refreshed until the filter emits `changed`, so
+ * call `filter.changed(Gtk.FilterChange.DIFFERENT)` afterwards when the new
+ * predicate keeps different items.
+ *
+ * @param filter - The filter to update
+ * @param predicate - Decides whether an item is kept, or null to keep every item
+ */
+export const setCustomFilterFunc = <T extends GObject>(
+    filter: CustomFilter,
+    predicate: ((item: T) => unknown) | null,
+): void => {
+    customFilterSetFilterFunc(filter.handle, predicate && toMatchFunc(predicate));
+};
@@ -0,0 +1,62 @@
+import type { NativeHandle } from "@gtkx/native";
+import type { Object as GObject } from "../generated/gobject/object.js";
+import type { CustomSorter } from "../generated/gtk/custom-sorter.js";
+import { t } from "../native.js";
+import { getNativeObject } from "../registry.js";
+
+const LIB = "libgtk-4.so.1";
+
+const SORTER = t.object("borrowed");
+const ITEM = t.object("borrowed");
+const SORT_FUNC = t.trampoline([ITEM, ITEM, t.void], t.int32, {
+    hasDestroy: true,
+    userDataIndex: 2,
+    scope: "notified",
+    kind: "compare",
+});
+
+const customSorterNew = t.fn(LIB, "gtk_custom_sorter_new", [{ type: SORT_FUNC, optional: true }], t.object("full"));
+const customSorterSetSortFunc = t.fn(
+    LIB,
+    "gtk_custom_sorter_set_sort_func",
+    [{ type: SORTER }, { type: SORT_FUNC, optional: true }],
+    t.void,
+);
+
+const toSortFunc =
+    <T extends GObject>(compare: (a: T, b: T) => number) =>
+    (a: NativeHandle, b: NativeHandle): number =>
+        compare(getNativeObject(a) as unknown as T, getNativeObject(b) as unknown as T);
+
+/**
+ * Creates a `GtkCustomSorter` that orders items with a JS comparator.
+ *
+ * The comparator receives the items themselves and follows
+ * `Array.prototype.sort` conventions: only the sign of its result matters,
+ * so `a.size - b.size` works even when the difference is fractional.
+ *
+ * @param compare - Comparator for two items of the sorted model
+ * @returns The sorter
+ *
+ * @example
+ * ```tsx
+ * const sorter = createCustomSorter<Gtk.StringObject>((a, b) => a.getString().localeCompare(b.getString()));
+ * const model = new Gtk.SortListModel(store, sorter);
+ * ```
+ */
+export const createCustomSorter = <T extends GObject>(compare: (a: T, b: T) => number): CustomSorter =>
+    getNativeObject(customSorterNew(toSortFunc(compare)) as NativeHandle) as unknown as CustomSorter;
+
+/**
+ * Replaces the comparator of a `GtkCustomSorter`, which then emits
+ * `changed` so sorted models re-sort.
+ *
+ * @param sorter - The sorter to update
+ * @param compare - Comparator for two items, or null to leave items unsorted
+ */
+export const setCustomSorterFunc = <T extends GObject>(
+    sorter: CustomSorter,
+    compare: ((a: T, b: T) => number) | null,
+): void => {
+    customSorterSetSortFunc(sorter.handle, compare && toSortFunc(compare));
+};
@@ -0,0 +1,31 @@
+import { IOErrorEnum } from "../generated/gio/enums.js";
+import { ioErrorQuark } from "../generated/gio/functions.js";
+import { DialogError } from "../generated/gtk/enums.js";
+import { dialogErrorQuark } from "../generated/gtk/functions.js";
+import { NativeError } from "../native.js";
+
+/**
+ * Checks whether an error from an async GTK dialog or launcher means the
+ * user closed it without choosing, or that it was cancelled, rather than
+ * that it failed.
+ *
+ * @param error - The error a dialog promise rejected with
+ *
+ * @example
+ * ```tsx
+ * try {
+ *     await new Gtk.FileDialog().openAsync(window);
+ * } catch (error) {
+ *     if (!isDialogDismissed(error)) throw error;
+ * }
+ * ```
+ */
+export const isDialogDismissed = (error: unknown): boolean => {
+    if (!(error instanceof NativeError)) return false;
+
+    const code = error.getCode();
+    if (error.getDomain() === dialogErrorQuark()) {
+        return code === DialogError.DISMISSED || code === DialogError.CANCELLED;
+    }
+    return error.getDomain() === ioErrorQuark() && code === IOErrorEnum.CANCELLED;
+};
@@ -0,0 +1,56 @@
+import { EmojiChooser } from "../generated/gtk/emoji-chooser.js";
+import type { Widget } from "../generated/gtk/widget.js";
+
+/** Options for {@link chooseEmoji}. */
+export type EmojiChooserOptions = {
+    /** Closes the chooser; the promise then resolves with null. */
+    signal?: AbortSignal;
+};
+
+/**
+ * Choosers currently shown. Holding them here keeps each wrapper, and the
+ * handlers connected through it, alive until the chooser closes.
+ */
+const openChoosers = new Set<EmojiChooser>();
+
+/**
+ * Shows a `GtkEmojiChooser` popover pointing at a widget and resolves with
+ * the emoji the user picks.
+ *
+ * @param parent - Widget the popover is attached to, such as the button that opened it
+ * @param options - Abort signal
+ * @returns The picked emoji as a string, or null if the chooser was closed without picking
+ *
+ * @example
+ * ```tsx
+ * const emoji = await chooseEmoji(button);
+ * if (emoji) entry.insertText(emoji, -1);
+ * ```
+ */
+export const chooseEmoji = (parent: Widget, options: EmojiChooserOptions = {}): Promise<string | null> => {
+    const chooser = new EmojiChooser();
+    chooser.setParent(parent);
+    openChoosers.add(chooser);
+
+    return new Promise((resolve) => {
+        let picked: string | null = null;
+        const onAbort = () => chooser.popdown();
+
+        chooser.connect("emoji-picked", (_chooser: EmojiChooser, text: string) => {
+            picked = text;
+        });
+        chooser.connect("closed", () => {
+            options.signal?.removeEventListener("abort", onAbort);
+            chooser.unparent();
+            openChoosers.delete(chooser);
+            resolve(picked);
+        });
+
+        if (options.signal?.aborted) {
+            queueMicrotask(onAbort);
+        } else {
+            options.signal?.addEventListener("abort", onAbort, { once: true });
+        }
+        chooser.popup();
+    });
+};
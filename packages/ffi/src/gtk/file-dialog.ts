@@ -0,0 +1,172 @@
+import { File } from "../generated/gio/file.js";
+import { fileNewForPath } from "../generated/gio/functions.js";
+import type { ListModel } from "../generated/gio/list-model.js";
+import { ListStore } from "../generated/gio/list-store.js";
+import { typeFromName } from "../generated/gobject/functions.js";
+import { FileDialog } from "../generated/gtk/file-dialog.js";
+import { FileFilter } from "../generated/gtk/file-filter.js";
+import type { Window } from "../generated/gtk/window.js";
+import { cancellableFromSignal } from "../gio/cancellable.js";
+import { getNativeInterface } from "../native.js";
+import { isDialogDismissed } from "./dialog-error.js";
+
+/** Describes a `GtkFileFilter` built by {@link createFileFilter}. */
+export type FileFilterSpec = {
+    /** Name shown in the dialog's filter selector. */
+    name: string;
+    /** Glob patterns, such as `"*.txt"`. */
+    patterns?: readonly string[];
+    /** MIME types, such as `"text/plain"` or `"image/*"`. */
+    mimeTypes?: readonly string[];
+    /** File name suffixes without the dot, matched case-insensitively. */
+    suffixes?: readonly string[];
+};
+
+/** Options shared by the file dialog helpers. */
+export type FileDialogOptions = {
+    title?: string;
+    /** Label of the button that confirms the selection. */
+    acceptLabel?: string;
+    /** Whether the dialog blocks its parent window. Defaults to true. */
+    modal?: boolean;
+    /** Filters offered to the user, in order. */
+    filters?: readonly (FileFilterSpec | FileFilter)[];
+    /** Name of the filter selected initially. Defaults to the first filter. */
+    defaultFilter?: string;
+    /** Folder shown initially, as a path or file. */
+    initialFolder?: string | File;
+    /** Aborts the dialog; the promise then resolves as if it was dismissed. */
+    signal?: AbortSignal;
+};
+
+/** Options for {@link saveFile}. */
+export type SaveFileOptions = FileDialogOptions & {
+    /** File name suggested to the user. */
+    initialName?: string;
+};
+
+/**
+ * Builds a `GtkFileFilter` matching any of the given patterns, MIME types
+ * and suffixes.
+ *
+ * @param spec - Name and match rules of the filter
+ *
+ * @example
+ * ```tsx
+ * const images = createFileFilter({ name: "Images", mimeTypes: ["image/*"] });
+ * ```
+ */
+export const createFileFilter = (spec: FileFilterSpec): FileFilter => {
+    const filter = new FileFilter();
+    filter.setName(spec.name);
+    for (const pattern of spec.patterns ?? []) filter.addPattern(pattern);
+    for (const mimeType of spec.mimeTypes ?? []) filter.addMimeType(mimeType);
+    for (const suffix of spec.suffixes ?? []) filter.addSuffix(suffix);
+    return filter;
+};
+
+const toFile = (file: string | File): File => (typeof file === "string" ? fileNewForPath(file) : file);
+
+const createDialog = (options: FileDialogOptions): FileDialog => {
+    const dialog = new FileDialog();
+    if (options.title !== undefined) dialog.setTitle(options.title);
+    if (options.acceptLabel !== undefined) dialog.setAcceptLabel(options.acceptLabel);
+    if (options.modal !== undefined) dialog.setModal(options.modal);
+    if (options.initialFolder !== undefined) dialog.setInitialFolder(toFile(options.initialFolder));
+
+    if (options.filters && options.filters.length > 0) {
+        const filters = options.filters.map((filter) =>
+            filter instanceof FileFilter ? filter : createFileFilter(filter),
+        );
+        const store = new ListStore(typeFromName("GtkFileFilter"));
+        for (const filter of filters) store.append(filter);
+        dialog.setFilters(store);
+
+        const defaultFilter = options.defaultFilter
+            ? filters.find((filter) => filter.getName() === options.defaultFilter)
+            : filters[0];
+        if (defaultFilter) dialog.setDefaultFilter(defaultFilter);
+    }
+    return dialog;
+};
+
+/**
+ * Resolves to null when the user dismissed the dialog or it was aborted,
+ * and rethrows any other failure.
+ */
+const orDismissed = async <T>(pending: Promise<T>): Promise<T | null> => {
+    try {
+        return await pending;
+    } catch (error) {
+        if (isDialogDismissed(error)) return null;
+        throw error;
+    }
+};
+
+const listFiles = (model: ListModel | null): File[] => {
+    const files: File[] = [];
+    const count = model?.getNItems() ?? 0;
+    for (let i = 0; i < count; i++) {
+        const item = model?.getItem(i);
+        const file = item ? getNativeInterface(item, File) : null;
+        if (file) files.push(file);
+    }
+    return files;
+};
+
+/**
+ * Asks the user to pick a file to open.
+ *
+ * Uses `GtkFileDialog`, which goes through the FileChooser portal when
+ * the application is sandboxed.
+ *
+ * @param parent - Window the dialog belongs to, or null
+ * @param options - Title, filters and initial folder
+ * @returns The chosen file, or null if the dialog was dismissed
+ *
+ * @example
+ * ```tsx
+ * const file = await openFile(window, {
+ *     title: "Open Image",
+ *     filters: [{ name: "Images", mimeTypes: ["image/*"] }, { name: "All Files", patterns: ["*"] }],
+ *     initialFolder: GLib.getUserSpecialDir(GLib.UserDirectory.DIRECTORY_PICTURES),
+ * });
+ * ```
+ */
+export const openFile = (parent: Window | null, options: FileDialogOptions = {}): Promise<File | null> =>
+    orDismissed(createDialog(options).openAsync(parent, cancellableFromSignal(options.signal)));
+
+/**
+ * Asks the user to pick one or more files to open.
+ *
+ * @param parent - Window the dialog belongs to, or null
+ * @param options - Title, filters and initial folder
+ * @returns The chosen files, or an empty array if the dialog was dismissed
+ */
+export const openFiles = async (parent: Window | null, options: FileDialogOptions = {}): Promise<File[]> => {
+    const dialog = createDialog(options);
+    return listFiles(await orDismissed(dialog.openMultipleAsync(parent, cancellableFromSignal(options.signal))));
+};
+
+/**
+ * Asks the user where to save a file.
+ *
+ * @param parent - Window the dialog belongs to, or null
+ * @param options - Title, filters, initial folder and suggested name
+ * @returns The chosen file, or null if the dialog was dismissed
+ */
+export const saveFile = (parent: Window | null, options: SaveFileOptions = {}): Promise<File | null> => {
+    const dialog = createDialog(options);
+    if (options.initialName !== undefined) dialog.setInitialName(options.initialName);
+    return orDismissed(dialog.saveAsync(parent, cancellableFromSignal(options.signal)));
+};
+
+/**
+ * Asks the user to pick a folder.
+ *
+ * @param parent - Window the dialog belongs to, or null
+ * @param options - Title and initial folder
+ * @returns The chosen folder, or null if the dialog was dismissed
+ */
+export const selectFolder = (parent: Window | null, options: FileDialogOptions = {}): Promise<File | null> =>
+    orDismissed(createDialog(options).selectFolderAsync(parent, cancellableFromSignal(options.signal)));
@@ -0,0 +1,87 @@
+import { FontDialog } from "../generated/gtk/font-dialog.js";
+import type { Window } from "../generated/gtk/window.js";
+import { SCALE } from "../generated/pango/constants.js";
+import type { Stretch, Style, Variant, Weight } from "../generated/pango/enums.js";
+import { FontDescription } from "../generated/pango/font-description.js";
+import { cancellableFromSignal } from "../gio/cancellable.js";
+import { isDialogDismissed } from "./dialog-error.js";
+
+/** A font chosen with {@link chooseFont}, decoded from its `PangoFontDescription`. */
+export type FontChoice = {
+    /** Family name, such as `"Cantarell"`, or null if unset. */
+    family: string | null;
+    /** Size in points, or in device units when `sizeIsAbsolute` is true. 0 if unset. */
+    size: number;
+    sizeIsAbsolute: boolean;
+    style: Style;
+    weight: Weight;
+    stretch: Stretch;
+    variant: Variant;
+    /** OpenType font variations, such as `"wght=500"`, or null if none. */
+    variations: string | null;
+    /** String form, such as `"Cantarell Bold 12"`, accepted by `Pango.FontDescription.fromString`. */
+    description: string;
+    /** The underlying description, for APIs that take one. */
+    fontDescription: FontDescription;
+};
+
+/** Options for {@link chooseFont}. */
+export type FontDialogOptions = {
+    title?: string;
+    /** Whether the dialog blocks its parent window. Defaults to true. */
+    modal?: boolean;
+    /** Font selected initially, as a description or a string such as `"Sans 12"`. */
+    initial?: string | FontDescription;
+    /** Aborts the dialog; the promise then resolves with null. */
+    signal?: AbortSignal;
+};
+
+/**
+ * Decodes a `PangoFontDescription` into plain fields.
+ *
+ * @param fontDescription - The description to decode
+ */
+export const describeFont = (fontDescription: FontDescription): FontChoice => ({
+    family: fontDescription.getFamily(),
+    size: fontDescription.getSize() / SCALE,
+    sizeIsAbsolute: fontDescription.getSizeIsAbsolute(),
+    style: fontDescription.getStyle(),
+    weight: fontDescription.getWeight(),
+    stretch: fontDescription.getStretch(),
+    variant: fontDescription.getVariant(),
+    variations: fontDescription.getVariations(),
+    description: fontDescription.toString(),
+    fontDescription,
+});
+
+/**
+ * Presents a `GtkFontDialog` and resolves with the font the user chose.
+ *
+ * @param parent - Window the dialog belongs to, or null
+ * @param options - Title, initial font and abort signal
+ * @returns The chosen font, or null if the dialog was dismissed
+ *
+ * @example
+ * ```tsx
+ * const font = await chooseFont(window, { initial: "Monospace 11" });
+ * if (font) setEditorFont(font.description);
+ * ```
+ */
+export const chooseFont = async (
+    parent: Window | null,
+    options: FontDialogOptions = {},
+): Promise<FontChoice | null> => {
+    const dialog = new FontDialog();
+    if (options.title !== undefined) dialog.setTitle(options.title);
+    if (options.modal !== undefined) dialog.setModal(options.modal);
+
+    const initial = typeof options.initial === "string" ? FontDescription.fromString(options.initial) : options.initial;
+
+    try {
+        const chosen = await dialog.chooseFontAsync(parent, initial, cancellableFromSignal(options.signal));
+        return describeFont(chosen);
+    } catch (error) {
+        if (isDialogDismissed(error)) return null;
+        throw error;
+    }
+};
@@ -1,2 +1,33 @@
 export * from "../generated/gtk/index.js";
 export * from "./constants.js";
+export * from "./activation.js";
+export * from "./adjustment.js";
+export * from "./bind-model.js";
+export * from "./render-hints.js";
+export * from "./render-pause.js";
+export * from "./tree-model.js";
+export * from "./list-item-factory.js";
+export * from "./color-dialog.js";
+export * from "./column-view.js";
+export * from "./constraint-layout.js";
+export * from "./cursor.js";
+export * from "./custom-filter.js";
+export * from "./custom-sorter.js";
+export * from "./dialog-error.js";
+export * from "./emoji-chooser.js";
+export * from "./file-dialog.js";
+export * from "./font-dialog.js";
+export * from "./icon-theme.js";
+export * from "./inspect-tree.js";
+export * from "./inspector.js";
+export * from "./launcher.js";
+export * from "./locale.js";
+export * from "./measure.js";
+export * from "./media.js";
+export * from "./scrolled-window.js";
+export * from "./session-events.js";
+export * from "./stack.js";
+export * from "./style.js";
+export * from "./text-input.js";
+export * from "./tooltip.js";
+export * from "./widget-class.js";
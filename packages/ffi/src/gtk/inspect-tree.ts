@@ -0,0 +1,67 @@
+import type { Widget } from "../generated/gtk/widget.js";
+import { freeze, unfreeze } from "../native.js";
+
+/** A widget and its descendants, as plain JSON-serializable data. */
+export type WidgetTreeNode = {
+    /** GType name of the widget, such as `"GtkButton"`. */
+    type: string;
+    /** Widget name set with `setName()`, or null if it has none. */
+    name: string | null;
+    /** CSS node name, such as `"button"`. */
+    cssName: string;
+    cssClasses: string[];
+    visible: boolean;
+    sensitive: boolean;
+    mapped: boolean;
+    /** Allocated width and height, 0 until the widget is laid out. */
+    width: number;
+    height: number;
+    children: WidgetTreeNode[];
+};
+
+const inspectWidget = (widget: Widget): WidgetTreeNode => {
+    const type = widget.constructor.glibTypeName;
+    const name = widget.getName();
+    const children: WidgetTreeNode[] = [];
+
+    for (let child = widget.getFirstChild(); child; child = child.getNextSibling()) {
+        children.push(inspectWidget(child));
+    }
+
+    return {
+        type,
+        name: name && name !== type ? name : null,
+        cssName: widget.getCssName(),
+        cssClasses: widget.getCssClasses() ?? [],
+        visible: widget.getVisible(),
+        sensitive: widget.getSensitive(),
+        mapped: widget.getMapped(),
+        width: widget.getWidth(),
+        height: widget.getHeight(),
+        children,
+    };
+};
+
+/**
+ * Captures a widget and all its descendants as a plain tree, for devtools
+ * panels and for debugging what the reconciler actually built.
+ *
+ * The whole tree is read in one batch on the GTK thread, so it reflects a
+ * single consistent state and no frame is drawn while it is collected.
+ *
+ * @param root - The widget to start from, usually a window
+ *
+ * @example
+ * ```tsx
+ * const tree = inspectTree(window);
+ * console.log(JSON.stringify(tree, null, 2));
+ * ```
+ */
+export const inspectTree = (root: Widget): WidgetTreeNode => {
+    freeze();
+    try {
+        return inspectWidget(root);
+    } finally {
+        unfreeze();
+    }
+};
@@ -0,0 +1,70 @@
+import { SimpleAction } from "../generated/gio/simple-action.js";
+import { Variant } from "../generated/glib/variant.js";
+import { typeNameFromInstance } from "../generated/gobject/functions.js";
+import { TypeInstance } from "../generated/gobject/type-instance.js";
+import { Window } from "../generated/gtk/window.js";
+
+const INSPECTOR_TYPE_NAME = "GtkInspectorWindow";
+
+const findInspectorWindows = (): Window[] =>
+    Window.listToplevels().filter(
+        (window) => typeNameFromInstance(new TypeInstance(window.handle)) === INSPECTOR_TYPE_NAME,
+    ) as Window[];
+
+/**
+ * Whether a GTK Inspector window is currently shown.
+ */
+export const isInspectorVisible = (): boolean => findInspectorWindows().some((window) => window.getVisible());
+
+/**
+ * Shows or hides the GTK Inspector.
+ *
+ * Works regardless of `GTK_DEBUG` and of the `enable-inspector-keybinding`
+ * setting, which only govern the Ctrl+Shift+I shortcut.
+ *
+ * @param visible - Whether the inspector should be shown
+ */
+export const setInspectorVisible = (visible: boolean): void => {
+    Window.setInteractiveDebugging(visible);
+};
+
+/**
+ * Shows the GTK Inspector if it is hidden, and hides it otherwise.
+ *
+ * @returns Whether the inspector is now shown
+ */
+export const toggleInspector = (): boolean => {
+    const visible = !isInspectorVisible();
+    setInspectorVisible(visible);
+    return visible;
+};
+
+/**
+ * Creates a stateful action that shows and hides the GTK Inspector, for a
+ * "Debug UI" menu item or shortcut.
+ *
+ * The action's boolean state follows the inspector each time it is
+ * activated, including when the inspector was closed from its own window.
+ *
+ * @param name - Action name, without the `app.` or `win.` prefix
+ *
+ * @example
+ * ```tsx
+ * app.addAction(createInspectorAction());
+ * app.setAccelsForAction("app.inspector", ["<Control><Shift>d"]);
+ * // In a menu: <item label="Debug UI" action="app.inspector" />
+ * ```
+ */
+export const createInspectorAction = (name = "inspector"): SimpleAction => {
+    const action = SimpleAction.newStateful(name, null, Variant.newBoolean(isInspectorVisible()));
+    action.connect("activate", () => {
+        action.setState(Variant.newBoolean(toggleInspector()));
+    });
+    action.connect("change-state", (_action: SimpleAction, value: Variant | null) => {
+        if (!value) return;
+        const visible = value.getBoolean();
+        setInspectorVisible(visible);
+        action.setState(Variant.newBoolean(visible));
+    });
+    return action;
+};
@@ -0,0 +1,82 @@
+import type { File } from "../generated/gio/file.js";
+import { fileNewForPath } from "../generated/gio/functions.js";
+import { FileLauncher } from "../generated/gtk/file-launcher.js";
+import { UriLauncher } from "../generated/gtk/uri-launcher.js";
+import type { Window } from "../generated/gtk/window.js";
+import { cancellableFromSignal } from "../gio/cancellable.js";
+import { isDialogDismissed } from "./dialog-error.js";
+
+/** Options for {@link launchUri}. */
+export type LaunchOptions = {
+    /** Window the launch belongs to, used to place any app chooser. */
+    window?: Window | null;
+    /** Aborts the launch; the promise then resolves with false. */
+    signal?: AbortSignal;
+};
+
+/** Options for {@link launchFile}. */
+export type LaunchFileOptions = LaunchOptions & {
+    /** Let the user pick the application even if a default exists. */
+    alwaysAsk?: boolean;
+    /** Request that the application may write to the file. */
+    writable?: boolean;
+};
+
+const orDismissed = async (pending: Promise<boolean>): Promise<boolean> => {
+    try {
+        return await pending;
+    } catch (error) {
+        if (isDialogDismissed(error)) return false;
+        throw error;
+    }
+};
+
+const toFile = (file: string | File): File => (typeof file === "string" ? fileNewForPath(file) : file);
+
+/**
+ * Opens a URI with the user's default handler, such as a web browser for
+ * `https:` or a mail client for `mailto:`.
+ *
+ * Uses `GtkUriLauncher`, which goes through the OpenURI portal when the
+ * application is sandboxed.
+ *
+ * @param uri - The URI to open
+ * @param options - Parent window and abort signal
+ * @returns True if a handler was launched, false if the user dismissed the app chooser
+ * @throws NativeError if no handler could be launched
+ *
+ * @example
+ * ```tsx
+ * await launchUri("https://gtkx.dev", { window });
+ * ```
+ */
+export const launchUri = (uri: string, options: LaunchOptions = {}): Promise<boolean> =>
+    orDismissed(new UriLauncher(uri).launchAsync(options.window, cancellableFromSignal(options.signal)));
+
+/**
+ * Opens a file with the default application for its type.
+ *
+ * @param file - Path or file to open
+ * @param options - Parent window, app chooser and abort signal
+ * @returns True if an application was launched, false if the user dismissed the app chooser
+ * @throws NativeError if no application could be launched
+ */
+export const launchFile = (file: string | File, options: LaunchFileOptions = {}): Promise<boolean> => {
+    const launcher = new FileLauncher(toFile(file));
+    if (options.alwaysAsk !== undefined) launcher.setAlwaysAsk(options.alwaysAsk);
+    if (options.writable !== undefined) launcher.setWritable(options.writable);
+    return orDismissed(launcher.launchAsync(options.window, cancellableFromSignal(options.signal)));
+};
+
+/**
+ * Opens the file manager on the folder containing a file, with the file
+ * selected.
+ *
+ * @param file - Path or file to reveal
+ * @param options - Parent window and abort signal
+ * @returns True if the file manager was opened, false if it was dismissed
+ */
+export const openContainingFolder = (file: string | File, options: LaunchOptions = {}): Promise<boolean> => {
+    const launcher = new FileLauncher(toFile(file));
+    return orDismissed(launcher.openContainingFolderAsync(options.window, cancellableFromSignal(options.signal)));
+};
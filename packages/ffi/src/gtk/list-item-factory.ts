@@ -0,0 +1,44 @@
+import { connect, type NativeHandle } from "@gtkx/native";
+import type { ListItem } from "../generated/gtk/list-item.js";
+import type { SignalListItemFactory } from "../generated/gtk/signal-list-item-factory.js";
+import { t } from "../native.js";
+import { getNativeObject } from "../registry.js";
+
+const FACTORY = t.object("borrowed");
+const LIST_ITEM = t.object("borrowed");
+
+const FACTORY_CLOSURE = t.callback([FACTORY, LIST_ITEM], t.void, { fastArgs: true });
+
+/** Handlers for the signals of a `GtkSignalListItemFactory`. */
+export type ListItemFactoryHandlers = {
+    setup?: (listItem: ListItem) => void;
+    bind?: (listItem: ListItem) => void;
+    unbind?: (listItem: ListItem) => void;
+    teardown?: (listItem: ListItem) => void;
+};
+
+/**
+ * Connects list item factory handlers through a low-overhead path.
+ *
+ * `bind` and `unbind` fire for every row scrolled into view, so the
+ * connections skip generic argument conversion and reuse their argument
+ * storage between emissions.
+ *
+ * @param factory - The factory to connect to
+ * @param handlers - Handlers to connect; omitted signals are left alone
+ * @returns The signal handler IDs, for use with `disconnect`
+ */
+export const connectListItemFactory = (factory: SignalListItemFactory, handlers: ListItemFactoryHandlers): number[] => {
+    const ids: number[] = [];
+
+    for (const signal of ["setup", "bind", "unbind", "teardown"] as const) {
+        const handler = handlers[signal];
+        if (!handler) continue;
+
+        const callback = (_factory: NativeHandle, listItem: NativeHandle) =>
+            handler(getNativeObject(listItem) as unknown as ListItem);
+        ids.push(connect(factory.handle, signal, { type: FACTORY_CLOSURE, value: callback }));
+    }
+
+    return ids;
+};
@@ -0,0 +1,76 @@
+import { getLanguageNames } from "../generated/glib/functions.js";
+import { TextDirection } from "../generated/gtk/enums.js";
+import { getDefaultLanguage, getLocaleDirection } from "../generated/gtk/functions.js";
+import { Widget } from "../generated/gtk/widget.js";
+
+/** Locale and text direction settings, as GTK sees them. */
+export type LocaleInfo = {
+    /** Default direction of widgets, which follows the locale unless overridden. */
+    textDirection: TextDirection;
+    /** Whether widgets lay out right-to-left by default. */
+    rtl: boolean;
+    /** Direction of the locale's script, regardless of overrides. */
+    localeDirection: TextDirection;
+    /** Pango language of the locale, such as `"de-de"`. */
+    language: string;
+    /** BCP 47 tag of the locale, for `Intl` APIs, such as `"de-DE"`. */
+    locale: string;
+    /** Languages to look up translations in, most preferred first, such as `["de_DE.UTF-8", "de_DE", "de", "C"]`. */
+    languageNames: string[];
+    /** BCP 47 tag of the locale used to format numbers (`LC_NUMERIC`). */
+    numericLocale: string;
+    /** Decimal separator of the numeric locale, such as `","`. */
+    decimalSeparator: string;
+    /** Digit grouping separator of the numeric locale, or an empty string if it has none. */
+    groupingSeparator: string;
+};
+
+/**
+ * Converts a POSIX locale name such as `"pt_BR.UTF-8@euro"` or a Pango
+ * language such as `"pt-br"` into a BCP 47 tag (`"pt-BR"`).
+ */
+const toLanguageTag = (name: string): string => {
+    const [language = "", region] = name.split(/[.@]/)[0]?.split(/[_-]/) ?? [];
+    if (!language || language === "C" || language === "POSIX") return "en-US";
+    return region ? `${language.toLowerCase()}-${region.toUpperCase()}` : language.toLowerCase();
+};
+
+const numericLocaleName = (): string => process.env.LC_ALL || process.env.LC_NUMERIC || process.env.LANG || "C";
+
+const separators = (tag: string): { decimal: string; group: string } => {
+    const parts = new Intl.NumberFormat(tag).formatToParts(1234567.5);
+    return {
+        decimal: parts.find((part) => part.type === "decimal")?.value ?? ".",
+        group: parts.find((part) => part.type === "group")?.value ?? "",
+    };
+};
+
+/**
+ * Reports the locale settings GTK uses for text direction and language,
+ * and the separators of the numeric locale, so JavaScript code can format
+ * and lay out content consistently with the widgets around it.
+ *
+ * @example
+ * ```tsx
+ * const { rtl, locale } = localeInfo();
+ * const price = new Intl.NumberFormat(locale, { style: "currency", currency: "EUR" }).format(total);
+ * ```
+ */
+export const localeInfo = (): LocaleInfo => {
+    const textDirection = Widget.getDefaultDirection();
+    const language = getDefaultLanguage().toString();
+    const numericLocale = toLanguageTag(numericLocaleName());
+    const { decimal, group } = separators(numericLocale);
+
+    return {
+        textDirection,
+        rtl: textDirection === TextDirection.RTL,
+        localeDirection: getLocaleDirection(),
+        language,
+        locale: toLanguageTag(language),
+        languageNames: getLanguageNames(),
+        numericLocale,
+        decimalSeparator: decimal,
+        groupingSeparator: group,
+    };
+};
@@ -0,0 +1,73 @@
+import type { Orientation } from "../generated/gtk/enums.js";
+import type { Widget } from "../generated/gtk/widget.js";
+import { Point } from "../generated/graphene/point.js";
+
+/** Size request of a widget along one orientation, from {@link measureWidget}. */
+export type WidgetMeasurement = {
+    minimum: number;
+    natural: number;
+    /** Baseline of the minimum size, or -1 if the widget has none. */
+    minimumBaseline: number;
+    /** Baseline of the natural size, or -1 if the widget has none. */
+    naturalBaseline: number;
+};
+
+/** A rectangle in the coordinate system of some widget. */
+export type WidgetBounds = {
+    x: number;
+    y: number;
+    width: number;
+    height: number;
+};
+
+/**
+ * Measures the size a widget requests along one orientation.
+ *
+ * @param widget - The widget to measure
+ * @param orientation - Whether to measure width or height
+ * @param forSize - Size in the opposite orientation to measure for, or -1 if unknown
+ *
+ * @example
+ * ```tsx
+ * const { natural } = measureWidget(label, Gtk.Orientation.HORIZONTAL);
+ * const height = measureWidget(label, Gtk.Orientation.VERTICAL, natural).natural;
+ * ```
+ */
+export const measureWidget = (widget: Widget, orientation: Orientation, forSize = -1): WidgetMeasurement => {
+    const [minimum, natural, minimumBaseline, naturalBaseline] = widget.measure(orientation, forSize);
+    return { minimum, natural, minimumBaseline, naturalBaseline };
+};
+
+/**
+ * Computes the bounds of a widget in the coordinate system of another.
+ *
+ * @param widget - The widget whose bounds to compute
+ * @param target - The widget whose coordinates to use, such as an ancestor or the window
+ * @returns The bounds, or null if the widgets share no common ancestor or are not laid out
+ */
+export const computeBounds = (widget: Widget, target: Widget): WidgetBounds | null => {
+    const [ok, rect] = widget.computeBounds(target);
+    if (!ok) return null;
+    return { x: rect.getX(), y: rect.getY(), width: rect.getWidth(), height: rect.getHeight() };
+};
+
+/**
+ * Translates a point from the coordinate system of one widget to another.
+ *
+ * @param widget - The widget the point is relative to
+ * @param target - The widget to translate the point into
+ * @param x - Horizontal position relative to `widget`
+ * @param y - Vertical position relative to `widget`
+ * @returns The translated point, or null if the widgets share no common ancestor
+ *
+ * @example
+ * ```tsx
+ * const point = computePoint(handle, window, event.x, event.y);
+ * if (point) showPopoverAt(point.x, point.y);
+ * ```
+ */
+export const computePoint = (widget: Widget, target: Widget, x: number, y: number): { x: number; y: number } | null => {
+    const [ok, point] = widget.computePoint(target, new Point({ x, y }));
+    if (!ok) return null;
+    return { x: point.x, y: point.y };
+};
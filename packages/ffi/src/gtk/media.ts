@@ -0,0 +1,140 @@
+import type { File } from "../generated/gio/file.js";
+import { InputStream } from "../generated/gio/input-stream.js";
+import { MediaFile } from "../generated/gtk/media-file.js";
+import type { MediaStream } from "../generated/gtk/media-stream.js";
+
+/** Playback settings applied by {@link createMediaFile}. */
+export type MediaFileOptions = {
+    /** Restart from the beginning when the end is reached. */
+    loop?: boolean;
+    muted?: boolean;
+    /** Volume from 0 to 1. */
+    volume?: number;
+    /** Start playing as soon as the media is ready. */
+    playing?: boolean;
+};
+
+/** Playback state delivered by {@link watchMediaProgress}. */
+export type MediaProgress = {
+    /** Current position, in seconds. */
+    position: number;
+    /** Length of the media in seconds, or 0 if unknown. */
+    duration: number;
+    playing: boolean;
+    ended: boolean;
+};
+
+/** Options for {@link watchMediaProgress}. */
+export type MediaProgressOptions = {
+    /** Minimum time between two updates, in milliseconds. Defaults to 250. */
+    interval?: number;
+};
+
+const MICROSECONDS_PER_SECOND = 1_000_000;
+
+const PROGRESS_PROPERTIES = ["timestamp", "duration", "playing", "ended"] as const;
+
+/**
+ * Creates a `GtkMediaFile` from a path, a `GFile` or an input stream, and
+ * applies the given playback settings.
+ *
+ * @param source - Path, file or stream to play
+ * @param options - Loop, volume and autoplay settings
+ *
+ * @example
+ * ```tsx
+ * const media = createMediaFile("/path/to/video.webm", { loop: true, playing: true });
+ * <GtkVideo mediaStream={media} />
+ * ```
+ */
+export const createMediaFile = (source: string | File | InputStream, options: MediaFileOptions = {}): MediaFile => {
+    const media =
+        typeof source === "string"
+            ? MediaFile.newForFilename(source)
+            : source instanceof InputStream
+              ? MediaFile.newForInputStream(source)
+              : MediaFile.newForFile(source);
+
+    if (options.loop !== undefined) media.setLoop(options.loop);
+    if (options.muted !== undefined) media.setMuted(options.muted);
+    if (options.volume !== undefined) media.setVolume(options.volume);
+    if (options.playing !== undefined) media.setPlaying(options.playing);
+    return media;
+};
+
+/**
+ * Reads the playback state of a media stream.
+ *
+ * @param stream - The stream to read
+ */
+export const getMediaProgress = (stream: MediaStream): MediaProgress => ({
+    position: stream.getTimestamp() / MICROSECONDS_PER_SECOND,
+    duration: stream.getDuration() / MICROSECONDS_PER_SECOND,
+    playing: stream.getPlaying(),
+    ended: stream.getEnded(),
+});
+
+/**
+ * Subscribes to position, duration, playing and ended changes of a media
+ * stream.
+ *
+ * The stream updates its position on every frame; changes are coalesced so
+ * the handler runs at most once per `interval` with the latest state.
+ *
+ * @param stream - The stream to watch
+ * @param handler - Called with the current playback state
+ * @param options - Update interval
+ * @returns A function that stops watching
+ *
+ * @example
+ * ```tsx
+ * const stop = watchMediaProgress(media, ({ position, duration }) => {
+ *     setProgress(duration > 0 ? position / duration : 0);
+ * });
+ * ```
+ */
+export const watchMediaProgress = (
+    stream: MediaStream,
+    handler: (progress: MediaProgress) => void,
+    options: MediaProgressOptions = {},
+): (() => void) => {
+    const interval = options.interval ?? 250;
+    let lastUpdate = 0;
+    let timer: ReturnType<typeof setTimeout> | null = null;
+
+    const update = () => {
+        timer = null;
+        lastUpdate = Date.now();
+        handler(getMediaProgress(stream));
+    };
+
+    const schedule = () => {
+        if (timer !== null) return;
+        timer = setTimeout(update, Math.max(0, lastUpdate + interval - Date.now()));
+    };
+
+    const handlerIds = PROGRESS_PROPERTIES.map((property) => stream.connect(`notify::${property}`, schedule));
+
+    return () => {
+        if (timer !== null) clearTimeout(timer);
+        timer = null;
+        for (const handlerId of handlerIds) stream.disconnect(handlerId);
+    };
+};
+
+/**
+ * Seeks a media stream to a position in seconds, clamped to its duration.
+ *
+ * @param stream - The stream to seek
+ * @param seconds - Target position
+ * @returns False if the stream does not support seeking
+ */
+export const seekMedia = (stream: MediaStream, seconds: number): boolean => {
+    if (!stream.isSeekable()) return false;
+
+    const duration = stream.getDuration();
+    let timestamp = Math.max(0, Math.round(seconds * MICROSECONDS_PER_SECOND));
+    if (duration > 0) timestamp = Math.min(timestamp, duration);
+    stream.seek(timestamp);
+    return true;
+};
@@ -0,0 +1,55 @@
+import { callHint } from "@gtkx/native";
+import type { Display } from "../generated/gdk/display.js";
+import type { Widget } from "../generated/gtk/widget.js";
+
+const LIB = "libgtk-4.so.1";
+
+/**
+ * Schedules a redraw of `widget` without waiting for the `GLib` thread.
+ *
+ * Equivalent to `widget.queueDraw()` but skips argument marshaling, for
+ * layers that issue render hints on every update.
+ *
+ * @param widget - The widget to redraw
+ */
+export const queueDraw = (widget: Widget): void => {
+    callHint(LIB, "gtk_widget_queue_draw", widget.handle);
+};
+
+/**
+ * Schedules a size renegotiation of `widget` without waiting for the `GLib` thread.
+ *
+ * @param widget - The widget whose size changed
+ */
+export const queueResize = (widget: Widget): void => {
+    callHint(LIB, "gtk_widget_queue_resize", widget.handle);
+};
+
+/**
+ * Schedules a reallocation of `widget` without waiting for the `GLib` thread.
+ *
+ * @param widget - The widget to reallocate
+ */
+export const queueAllocate = (widget: Widget): void => {
+    callHint(LIB, "gtk_widget_queue_allocate", widget.handle);
+};
+
+/**
+ * Flushes pending requests to the windowing system without waiting for the
+ * `GLib` thread.
+ *
+ * @param display - The display to flush
+ */
+export const flushDisplay = (display: Display): void => {
+    callHint(LIB, "gdk_display_flush", display.handle);
+};
+
+/**
+ * Queues a round trip to the windowing system. The `GLib` thread blocks until
+ * the server has processed all pending requests; the JS thread does not.
+ *
+ * @param display - The display to synchronize
+ */
+export const syncDisplay = (display: Display): void => {
+    callHint(LIB, "gdk_display_sync", display.handle);
+};
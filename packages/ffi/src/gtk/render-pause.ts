@@ -0,0 +1,153 @@
+import { blockHandler, unblockHandler } from "@gtkx/native";
+import type { FrameClock } from "../generated/gdk/frame-clock.js";
+import { signalLookup, typeFromName } from "../generated/gobject/functions.js";
+import type { Window } from "../generated/gtk/window.js";
+import { t } from "../native.js";
+
+const LIB = "libgobject-2.0.so.0";
+
+const SIGNAL_MATCH_ID = 1 << 0;
+const SIGNAL_MATCH_UNBLOCKED = 1 << 5;
+
+/**
+ * Frame clock phases whose handlers run tick callbacks, layout and drawing.
+ * Event delivery and the paint bookkeeping of the windowing system are left
+ * running, so a paused window still reacts to input.
+ */
+const PAUSED_PHASES = ["update", "layout", "paint"] as const;
+
+const INSTANCE = t.object("borrowed");
+
+const findUnblockedHandler = t.fn(
+    LIB,
+    "g_signal_handler_find",
+    [
+        { type: INSTANCE },
+        { type: t.uint32 },
+        { type: t.uint32 },
+        { type: t.uint32 },
+        { type: t.uintptr },
+        { type: t.uintptr },
+        { type: t.uintptr },
+    ],
+    t.uint64,
+);
+
+type PausedClock = {
+    clock: FrameClock;
+    /** Handlers blocked by the pause, which are the only ones it unblocks. */
+    handlerIds: number[];
+};
+
+/** Paused frame clocks by handle ID. */
+const paused = new Map<number, PausedClock>();
+
+const getFrameClock = (window: Window): FrameClock | null => window.getSurface()?.getFrameClock() ?? null;
+
+const blockPhase = (clock: FrameClock, phase: string): number[] => {
+    const signalId = signalLookup(phase, typeFromName("GdkFrameClock"));
+    const mask = SIGNAL_MATCH_ID | SIGNAL_MATCH_UNBLOCKED;
+    const handlerIds: number[] = [];
+    for (
+        let id = findUnblockedHandler(clock.handle, mask, signalId, 0, 0, 0, 0) as number;
+        id !== 0;
+        id = findUnblockedHandler(clock.handle, mask, signalId, 0, 0, 0, 0) as number
+    ) {
+        blockHandler(clock.handle, id);
+        handlerIds.push(id);
+    }
+    return handlerIds;
+};
+
+/**
+ * Stops a window from running tick callbacks, allocating and drawing,
+ * leaving its last frame on screen, to cut the idle CPU use of windows the
+ * user is not looking at.
+ *
+ * The window keeps receiving input. Widgets whose tick callbacks or frame
+ * clock handlers are added while the window is paused are not paused.
+ * Popovers and other surfaces sharing the window's frame clock are paused
+ * with it.
+ *
+ * @param window - A realized window
+ * @returns False if the window is not realized, and so has no frame clock
+ *
+ * @example
+ * ```tsx
+ * pauseRendering(previewWindow);
+ * // ...
+ * resumeRendering(previewWindow);
+ * ```
+ */
+export const pauseRendering = (window: Window): boolean => {
+    const clock = getFrameClock(window);
+    if (!clock) return false;
+    if (paused.has(clock.handle.id)) return true;
+
+    const handlerIds = PAUSED_PHASES.flatMap((phase) => blockPhase(clock, phase));
+    paused.set(clock.handle.id, { clock, handlerIds });
+    return true;
+};
+
+/**
+ * Undoes {@link pauseRendering}, then lays out and redraws the window to
+ * catch up on the changes made while it was paused.
+ *
+ * @param window - A paused window; others are left unchanged
+ */
+export const resumeRendering = (window: Window): void => {
+    const clock = getFrameClock(window);
+    const entry = clock ? paused.get(clock.handle.id) : undefined;
+    if (!clock || !entry) return;
+
+    paused.delete(clock.handle.id);
+    for (const id of entry.handlerIds) {
+        unblockHandler(clock.handle, id);
+    }
+    window.queueResize();
+    window.queueDraw();
+};
+
+/**
+ * Returns whether a window is paused by {@link pauseRendering}.
+ *
+ * @param window - The window
+ */
+export const isRenderingPaused = (window: Window): boolean => {
+    const clock = getFrameClock(window);
+    return clock !== null && paused.has(clock.handle.id);
+};
+
+/**
+ * Pauses a window's rendering while it is not the active window, and
+ * resumes it when the window gains focus.
+ *
+ * Suited to secondary windows of multi-window applications, whose content
+ * only needs to be current while the user works in them. A window that is
+ * not realized yet is paused the next time it loses focus.
+ *
+ * @param window - The window to throttle
+ * @returns A function that stops throttling and resumes the window
+ *
+ * @example
+ * ```tsx
+ * const stop = pauseRenderingWhenInactive(inspectorWindow);
+ * ```
+ */
+export const pauseRenderingWhenInactive = (window: Window): (() => void) => {
+    const sync = () => {
+        if (window.isActive()) {
+            resumeRendering(window);
+        } else {
+            pauseRendering(window);
+        }
+    };
+
+    const handlerId = window.connect("notify::is-active", sync);
+    sync();
+
+    return () => {
+        window.disconnect(handlerId);
+        resumeRendering(window);
+    };
+};
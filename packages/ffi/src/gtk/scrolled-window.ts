@@ -0,0 +1,157 @@
+import type { Adjustment } from "../generated/gtk/adjustment.js";
+import type { ScrolledWindow } from "../generated/gtk/scrolled-window.js";
+import { freeze, unfreeze } from "../native.js";
+import { connectValueChanged } from "./adjustment.js";
+
+/** Scroll position of a scrolled window, in pixels from the start of its content. */
+export type ScrollState = {
+    x: number;
+    y: number;
+};
+
+/** An edge of the scrollable content. */
+export type ScrollEdge = "top" | "bottom" | "left" | "right";
+
+/** Options for {@link watchScrollEdges}. */
+export type ScrollEdgeOptions = {
+    /** Report an edge once the visible area is within this many pixels of it. Defaults to 0. */
+    threshold?: number;
+};
+
+/**
+ * Reads the scroll position of a scrolled window.
+ *
+ * @param scrolledWindow - The scrolled window to read
+ */
+export const getScrollState = (scrolledWindow: ScrolledWindow): ScrollState => ({
+    x: scrolledWindow.getHadjustment().getValue(),
+    y: scrolledWindow.getVadjustment().getValue(),
+});
+
+/**
+ * Scrolls a scrolled window to a position, setting both axes in one batch
+ * so no frame is drawn with only one of them applied.
+ *
+ * Stops any kinetic scrolling in progress first, which would otherwise
+ * carry on from the old position. Values are clamped to the content size
+ * known at the time of the call.
+ *
+ * @param scrolledWindow - The scrolled window to scroll
+ * @param state - Position to restore; omitted axes are left unchanged
+ *
+ * @example
+ * ```tsx
+ * const saved = getScrollState(scrolledWindow);
+ * // ...rebuild the content...
+ * setScrollState(scrolledWindow, saved);
+ * ```
+ */
+export const setScrollState = (scrolledWindow: ScrolledWindow, state: Partial<ScrollState>): void => {
+    freeze();
+    try {
+        if (scrolledWindow.getKineticScrolling()) {
+            scrolledWindow.setKineticScrolling(false);
+            scrolledWindow.setKineticScrolling(true);
+        }
+        if (state.x !== undefined) scrolledWindow.getHadjustment().setValue(state.x);
+        if (state.y !== undefined) scrolledWindow.getVadjustment().setValue(state.y);
+    } finally {
+        unfreeze();
+    }
+};
+
+type EdgeWatch = {
+    edges: readonly [ScrollEdge, ScrollEdge];
+    adjustment: Adjustment;
+    /** Edges currently reported and not yet left. */
+    reached: Set<ScrollEdge>;
+};
+
+/**
+ * Reports when scrolling reaches an edge of the content, for loading more
+ * items into infinitely scrolling lists.
+ *
+ * Each edge is reported once when the visible area comes within
+ * `threshold` pixels of it, and again only after it has moved away. Scroll
+ * updates are coalesced, and while a returned promise is pending the edge
+ * is not reported again. Once it settles, the edge is rechecked if the
+ * content size changed, so content that is still too short keeps loading.
+ *
+ * @param scrolledWindow - The scrolled window to watch
+ * @param handler - Called with the reached edge. May return a promise.
+ * @param options - Distance from the edge that counts as reaching it
+ * @returns A function that stops watching
+ *
+ * @example
+ * ```tsx
+ * const stop = watchScrollEdges(scrolledWindow, async (edge) => {
+ *     if (edge === "bottom") await loadNextPage();
+ * }, { threshold: 200 });
+ * ```
+ */
+export const watchScrollEdges = (
+    scrolledWindow: ScrolledWindow,
+    handler: (edge: ScrollEdge) => void | Promise<void>,
+    options: ScrollEdgeOptions = {},
+): (() => void) => {
+    const threshold = options.threshold ?? 0;
+    const pending = new Set<ScrollEdge>();
+    let stopped = false;
+
+    const watches: EdgeWatch[] = [
+        { edges: ["top", "bottom"], adjustment: scrolledWindow.getVadjustment(), reached: new Set() },
+        { edges: ["left", "right"], adjustment: scrolledWindow.getHadjustment(), reached: new Set() },
+    ];
+
+    const check = (watch: EdgeWatch) => {
+        const { adjustment, reached } = watch;
+        const [startEdge, endEdge] = watch.edges;
+        const value = adjustment.getValue();
+        const lower = adjustment.getLower();
+        const upper = adjustment.getUpper();
+        if (upper - lower <= 0) return;
+
+        const atEdge = {
+            [startEdge]: value - lower <= threshold,
+            [endEdge]: upper - (value + adjustment.getPageSize()) <= threshold,
+        };
+        for (const edge of watch.edges) {
+            if (!atEdge[edge]) {
+                reached.delete(edge);
+            } else if (!reached.has(edge) && !pending.has(edge)) {
+                reached.add(edge);
+                report(watch, edge);
+            }
+        }
+    };
+
+    const report = (watch: EdgeWatch, edge: ScrollEdge) => {
+        const upper = watch.adjustment.getUpper();
+        const result = handler(edge);
+        if (!(result instanceof Promise)) return;
+
+        pending.add(edge);
+        result
+            .catch(() => {})
+            .finally(() => {
+                pending.delete(edge);
+                if (stopped || watch.adjustment.getUpper() === upper) return;
+                watch.reached.delete(edge);
+                check(watch);
+            });
+    };
+
+    const handlerIds = watches.map((watch) => ({
+        adjustment: watch.adjustment,
+        valueChanged: connectValueChanged(watch.adjustment, () => check(watch), { coalesce: true }),
+        changed: watch.adjustment.connect("changed", () => check(watch)),
+    }));
+
+    return () => {
+        stopped = true;
+        for (const { adjustment, valueChanged, changed } of handlerIds) {
+            adjustment.disconnect(valueChanged);
+            adjustment.disconnect(changed);
+        }
+    };
+};
@@ -0,0 +1,118 @@
+import type { DBusConnection } from "../generated/gio/dbus-connection.js";
+import { BusType } from "../generated/gio/enums.js";
+import type { Application } from "../generated/gtk/application.js";
+import { callDBusMethod, getBus, subscribeDBusSignal } from "../gio/dbus.js";
+import { packVariant } from "../glib/variant.js";
+
+const LOGIND_BUS_NAME = "org.freedesktop.login1";
+const LOGIND_PATH = "/org/freedesktop/login1";
+const UPOWER_BUS_NAME = "org.freedesktop.UPower";
+
+/**
+ * A change in the power or session state of the machine.
+ *
+ * - `suspend` / `resume`: the system is about to sleep, or woke up
+ * - `lid-close` / `lid-open`: the laptop lid changed state
+ * - `lock` / `unlock`: the user session was asked to lock, or unlocked
+ * - `session-end`: the user is logging out or shutting down
+ */
+export type SessionEvent = "suspend" | "resume" | "lid-close" | "lid-open" | "lock" | "unlock" | "session-end";
+
+/** Options for {@link watchSessionEvents}. */
+export type SessionEventOptions = {
+    /** Application whose `query-end` signal reports `session-end`. */
+    application?: Application;
+    /** System bus connection. Defaults to the shared system bus. */
+    systemBus?: DBusConnection;
+};
+
+const getSystemBus = (): DBusConnection | null => {
+    try {
+        return getBus(BusType.SYSTEM);
+    } catch {
+        return null;
+    }
+};
+
+/**
+ * Subscribes to power and session state changes from a single handler, so
+ * applications can pause timers and save state at the right moments.
+ *
+ * Sleep and session lock events come from systemd-logind, lid events from
+ * UPower and session end from the application. Sources that are not
+ * available, such as logind inside some containers, are skipped.
+ *
+ * @param handler - Called with each event
+ * @param options - Application and system bus to use
+ * @returns A function that stops watching
+ *
+ * @example
+ * ```tsx
+ * const stop = watchSessionEvents((event) => {
+ *     if (event === "suspend" || event === "session-end") saveState();
+ * }, { application: app });
+ * ```
+ */
+export const watchSessionEvents = (
+    handler: (event: SessionEvent) => void,
+    options: SessionEventOptions = {},
+): (() => void) => {
+    const cleanups: (() => void)[] = [];
+    let stopped = false;
+
+    const application = options.application;
+    if (application) {
+        const handlerId = application.connect("query-end", () => handler("session-end"));
+        cleanups.push(() => application.disconnect(handlerId));
+    }
+
+    const bus = options.systemBus ?? getSystemBus();
+    if (bus) {
+        cleanups.push(
+            subscribeDBusSignal(
+                bus,
+                { sender: LOGIND_BUS_NAME, interfaceName: "org.freedesktop.login1.Manager", member: "PrepareForSleep" },
+                ([start]) => handler(start ? "suspend" : "resume"),
+            ),
+        );
+
+        cleanups.push(
+            subscribeDBusSignal(
+                bus,
+                {
+                    sender: UPOWER_BUS_NAME,
+                    interfaceName: "org.freedesktop.DBus.Properties",
+                    member: "PropertiesChanged",
+                    objectPath: "/org/freedesktop/UPower",
+                },
+                ([, changed]) => {
+                    const lidIsClosed = (changed as Record<string, unknown>).LidIsClosed;
+                    if (lidIsClosed !== undefined) handler(lidIsClosed ? "lid-close" : "lid-open");
+                },
+            ),
+        );
+
+        callDBusMethod(bus, {
+            busName: LOGIND_BUS_NAME,
+            objectPath: LOGIND_PATH,
+            interfaceName: "org.freedesktop.login1.Manager",
+            method: "GetSessionByPID",
+            args: packVariant("(u)", [process.pid]),
+        })
+            .then(([sessionPath]) => {
+                if (stopped) return;
+                const match = { sender: LOGIND_BUS_NAME, interfaceName: "org.freedesktop.login1.Session" };
+                const objectPath = sessionPath as string;
+                cleanups.push(
+                    subscribeDBusSignal(bus, { ...match, member: "Lock", objectPath }, () => handler("lock")),
+                    subscribeDBusSignal(bus, { ...match, member: "Unlock", objectPath }, () => handler("unlock")),
+                );
+            })
+            .catch(() => {});
+    }
+
+    return () => {
+        stopped = true;
+        for (const cleanup of cleanups.splice(0)) cleanup();
+    };
+};
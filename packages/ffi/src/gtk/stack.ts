@@ -0,0 +1,161 @@
+import type { StackTransitionType } from "../generated/gtk/enums.js";
+import type { Stack } from "../generated/gtk/stack.js";
+import type { StackPage } from "../generated/gtk/stack-page.js";
+import type { Widget } from "../generated/gtk/widget.js";
+
+/** Transition used when a page change is applied. */
+export type StackTransition = {
+    /** Animation type. Defaults to the stack's `transition-type`. */
+    type?: StackTransitionType;
+    /** Animation duration in milliseconds. Defaults to the stack's `transition-duration`. */
+    duration?: number;
+};
+
+/** Options for {@link pushStackPage}. */
+export type PushStackPageOptions = {
+    /** Title shown by stack switchers. */
+    title?: string;
+    transition?: StackTransition;
+};
+
+/** Payload delivered by {@link connectVisibleChildChanged}. */
+export type VisibleChildChangedEvent = {
+    /** Name of the new visible child, if it has one. */
+    name: string | null;
+    child: Widget | null;
+    /** Name of the previously visible child, if it had one. */
+    previousName: string | null;
+};
+
+const showChild = (stack: Stack, child: Widget, transition: StackTransition | undefined): void => {
+    const previousType = stack.getTransitionType();
+    const previousDuration = stack.getTransitionDuration();
+    if (transition?.type !== undefined) stack.setTransitionType(transition.type);
+    if (transition?.duration !== undefined) stack.setTransitionDuration(transition.duration);
+    try {
+        stack.setVisibleChild(child);
+    } finally {
+        if (transition?.type !== undefined) stack.setTransitionType(previousType);
+        if (transition?.duration !== undefined) stack.setTransitionDuration(previousDuration);
+    }
+};
+
+const requireChild = (stack: Stack, name: string): Widget => {
+    const child = stack.getChildByName(name);
+    if (!child) {
+        throw new Error(`Stack has no child named '${name}'`);
+    }
+    return child;
+};
+
+/**
+ * Makes the named child visible using the given transition.
+ *
+ * The transition, if given, applies to this change only; the stack's
+ * `transition-type` and `transition-duration` are restored afterwards.
+ *
+ * @param stack - The stack
+ * @param name - Name of an existing child
+ * @param transition - Transition to use
+ * @throws if the stack has no child named `name`
+ */
+export const showStackPage = (stack: Stack, name: string, transition?: StackTransition): void => {
+    showChild(stack, requireChild(stack, name), transition);
+};
+
+/**
+ * Adds a named child and makes it visible.
+ *
+ * @param stack - The stack
+ * @param child - The widget to add
+ * @param name - Name for the new page; must be unique in the stack
+ * @param options - Title and transition
+ * @returns The new page
+ */
+export const pushStackPage = (
+    stack: Stack,
+    child: Widget,
+    name: string,
+    options: PushStackPageOptions = {},
+): StackPage => {
+    const page =
+        options.title === undefined ? stack.addNamed(child, name) : stack.addTitled(child, name, options.title);
+    showChild(stack, child, options.transition);
+    return page;
+};
+
+/**
+ * Swaps the child registered under `name` for a new widget, keeping the
+ * name and title, and makes it visible.
+ *
+ * The old child is removed once the new one is shown, so the transition
+ * animates from the old content.
+ *
+ * @param stack - The stack
+ * @param name - Name of the page to replace
+ * @param child - The replacement widget
+ * @param transition - Transition to use
+ * @returns The new page
+ * @throws if the stack has no child named `name`
+ */
+export const replaceStackPage = (
+    stack: Stack,
+    name: string,
+    child: Widget,
+    transition?: StackTransition,
+): StackPage => {
+    const previous = requireChild(stack, name);
+    const title = stack.getPage(previous).getTitle();
+
+    const page = stack.addChild(child);
+    showChild(stack, child, transition);
+    stack.remove(previous);
+
+    page.setName(name);
+    if (title !== null) page.setTitle(title);
+    return page;
+};
+
+/**
+ * Removes the named child, first showing `fallback` if the child is
+ * currently visible.
+ *
+ * @param stack - The stack
+ * @param name - Name of the page to remove
+ * @param fallback - Name of the page to show instead
+ * @param transition - Transition to use when switching to `fallback`
+ * @returns Whether a child was removed
+ */
+export const popStackPage = (stack: Stack, name: string, fallback?: string, transition?: StackTransition): boolean => {
+    const child = stack.getChildByName(name);
+    if (!child) return false;
+
+    if (fallback !== undefined && stack.getVisibleChildName() === name) {
+        showStackPage(stack, fallback, transition);
+    }
+    stack.remove(child);
+    return true;
+};
+
+/**
+ * Connects a handler that runs once per visible-child change.
+ *
+ * Collapses `notify::visible-child` and `notify::visible-child-name` into
+ * one typed event.
+ *
+ * @param stack - The stack to observe
+ * @param handler - Called with the new and previous child
+ * @returns The signal handler ID, for use with `disconnect`
+ */
+export const connectVisibleChildChanged = (
+    stack: Stack,
+    handler: (event: VisibleChildChangedEvent) => void,
+): number => {
+    let previousName = stack.getVisibleChildName();
+    return stack.connect("notify::visible-child", () => {
+        const name = stack.getVisibleChildName();
+        const event = { name, child: stack.getVisibleChild(), previousName };
+        previousName = name;
+        handler(event);
+    });
+};
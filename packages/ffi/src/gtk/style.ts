@@ -0,0 +1,79 @@
+import { type Rgba, toRgba } from "../gdk/rgba.js";
+import type { Widget } from "../generated/gtk/widget.js";
+import { freeze, unfreeze } from "../native.js";
+import { describeFont, type FontChoice } from "./font-dialog.js";
+
+/** Effective style of a widget, as resolved from the current theme and CSS. */
+export type ComputedStyle = {
+    /** Foreground color, the CSS `color` property. */
+    color: Rgba;
+    /** Font the widget renders text with. */
+    font: FontChoice;
+    /** Named colors requested with `colors`, or null for names the theme does not define. */
+    colors: Record<string, Rgba | null>;
+};
+
+/** Options for {@link getComputedStyle}. */
+export type ComputedStyleOptions = {
+    /** Named theme colors to look up, such as `"accent_color"` or `"window_bg_color"`. */
+    colors?: readonly string[];
+};
+
+/**
+ * Adds and removes CSS classes on a widget in a single update, so style
+ * is recomputed once instead of once per class.
+ *
+ * Classes in both lists end up removed.
+ *
+ * @param widget - The widget to restyle
+ * @param add - Classes to add
+ * @param remove - Classes to remove
+ *
+ * @example
+ * ```tsx
+ * updateCssClasses(row, ["error"], ["warning", "success"]);
+ * ```
+ */
+export const updateCssClasses = (widget: Widget, add: readonly string[], remove: readonly string[] = []): void => {
+    const classes = new Set(widget.getCssClasses() ?? []);
+    for (const name of add) classes.add(name);
+    for (const name of remove) classes.delete(name);
+    widget.setCssClasses([...classes]);
+};
+
+/**
+ * Reads the effective color, font and named theme colors of a widget in
+ * one batch, for custom drawing that should match the theme.
+ *
+ * Named colors are resolved through the widget's style context, which
+ * GTK deprecates for new code but still offers as the only way to look
+ * them up.
+ *
+ * @param widget - The widget whose style to read
+ * @param options - Named colors to resolve
+ *
+ * @example
+ * ```tsx
+ * const { color, font, colors } = getComputedStyle(area, { colors: ["accent_color"] });
+ * cr.setSourceRgba(color.red, color.green, color.blue, color.alpha);
+ * ```
+ */
+export const getComputedStyle = (widget: Widget, options: ComputedStyleOptions = {}): ComputedStyle => {
+    freeze();
+    try {
+        const styleContext = widget.getStyleContext();
+        const colors: Record<string, Rgba | null> = {};
+        for (const name of options.colors ?? []) {
+            const [found, rgba] = styleContext.lookupColor(name);
+            colors[name] = found ? toRgba(rgba) : null;
+        }
+
+        return {
+            color: toRgba(widget.getColor()),
+            font: describeFont(widget.getPangoContext().getFontDescription()),
+            colors,
+        };
+    } finally {
+        unfreeze();
+    }
+};
@@ -0,0 +1,85 @@
+import { Entry } from "../generated/gtk/entry.js";
+import { InputHints, type InputPurpose } from "../generated/gtk/enums.js";
+import type { Text } from "../generated/gtk/text.js";
+import { TextView } from "../generated/gtk/text-view.js";
+import { getNativeObject } from "../registry.js";
+
+/** Text widgets {@link configureTextInput} applies to. */
+export type TextInputWidget = Entry | Text | TextView;
+
+/** Input method settings applied by {@link configureTextInput}. */
+export type TextInputOptions = {
+    /** Kind of content, used by on-screen keyboards to pick a layout. */
+    purpose?: InputPurpose;
+    /** Hints to start from. The options below add to or remove from them. */
+    hints?: InputHints;
+    /** Ask the input method to check spelling, or not to. */
+    spellCheck?: boolean;
+    /** Ask the input method to suggest word completions. */
+    wordCompletion?: boolean;
+    /**
+     * Offer emoji in the input method and complete `:name` sequences with
+     * emoji where the widget supports it, or suppress both.
+     */
+    emoji?: boolean;
+    /** Called with the uncommitted text while the user composes input, and with `""` once it is committed. */
+    onPreeditChanged?: (preedit: string) => void;
+};
+
+const withFlag = (hints: number, enabled: boolean | undefined, on: number, off: number): number => {
+    if (enabled === undefined) return hints;
+    return enabled ? (hints | on) & ~off : (hints | off) & ~on;
+};
+
+const computeHints = (options: TextInputOptions): InputHints => {
+    let hints: number = options.hints ?? InputHints.NONE;
+    hints = withFlag(hints, options.spellCheck, InputHints.SPELLCHECK, InputHints.NO_SPELLCHECK);
+    hints = withFlag(hints, options.emoji, InputHints.EMOJI, InputHints.NO_EMOJI);
+    if (options.wordCompletion !== undefined) {
+        hints = options.wordCompletion ? hints | InputHints.WORD_COMPLETION : hints & ~InputHints.WORD_COMPLETION;
+    }
+    return hints as InputHints;
+};
+
+/**
+ * Configures the input method behavior of a text widget in one call:
+ * purpose, hints, spell checking, completion and emoji support, and
+ * tracking of text being composed.
+ *
+ * @param widget - An entry, text or text view
+ * @param options - Settings to apply. Input hints are always replaced; other omitted settings are left unchanged.
+ * @returns A function that disconnects `onPreeditChanged`
+ *
+ * @example
+ * ```tsx
+ * configureTextInput(messageView, {
+ *     purpose: Gtk.InputPurpose.FREE_FORM,
+ *     spellCheck: true,
+ *     emoji: true,
+ *     onPreeditChanged: (preedit) => setComposing(preedit !== ""),
+ * });
+ * ```
+ */
+export const configureTextInput = (widget: TextInputWidget, options: TextInputOptions): (() => void) => {
+    const properties: Record<string, unknown> = { "input-hints": computeHints(options) };
+    if (options.purpose !== undefined) properties["input-purpose"] = options.purpose;
+    if (options.emoji !== undefined && !(widget instanceof TextView)) {
+        properties["enable-emoji-completion"] = options.emoji;
+    }
+    widget.setProperties(properties);
+
+    const onPreeditChanged = options.onPreeditChanged;
+    if (!onPreeditChanged) return () => {};
+
+    if (widget instanceof TextView) {
+        const handlerId = widget.connect("preedit-changed", (_view: TextView, preedit: string) =>
+            onPreeditChanged(preedit),
+        );
+        return () => widget.disconnect(handlerId);
+    }
+
+    const delegate = widget instanceof Entry ? widget.getDelegate() : null;
+    const text = delegate ? (getNativeObject(delegate.handle) as Text) : (widget as Text);
+    const handlerId = text.connect("preedit-changed", (_text: Text, preedit: string) => onPreeditChanged(preedit));
+    return () => text.disconnect(handlerId);
+};
@@ -0,0 +1,84 @@
+import type { Tooltip } from "../generated/gtk/tooltip.js";
+import { Widget } from "../generated/gtk/widget.js";
+
+/** The position and tooltip passed to a {@link setTooltipContent} provider. */
+export type TooltipQuery = {
+    /** Pointer position relative to the widget. */
+    x: number;
+    y: number;
+    /** Whether the tooltip was requested with the keyboard rather than by hovering. */
+    keyboardMode: boolean;
+    /** The tooltip about to be shown, for direct customization. */
+    tooltip: Tooltip;
+};
+
+/**
+ * What a {@link setTooltipContent} provider shows: plain text, a widget, or
+ * a combination of markup, text, icon and widget. `null` or `false` shows
+ * no tooltip at this position.
+ */
+export type TooltipContent =
+    | string
+    | Widget
+    | {
+          text?: string;
+          /** Pango markup, used instead of `text`. */
+          markup?: string;
+          iconName?: string;
+          /** Custom content, replacing text and icon. */
+          widget?: Widget;
+      }
+    | boolean
+    | null;
+
+const applyContent = (tooltip: Tooltip, content: TooltipContent): boolean => {
+    if (content === null || content === false) return false;
+    if (content === true) return true;
+
+    if (typeof content === "string") {
+        tooltip.setText(content);
+    } else if (content instanceof Widget) {
+        tooltip.setCustom(content);
+    } else {
+        if (content.markup !== undefined) tooltip.setMarkup(content.markup);
+        else if (content.text !== undefined) tooltip.setText(content.text);
+        if (content.iconName !== undefined) tooltip.setIconFromIconName(content.iconName);
+        if (content.widget !== undefined) tooltip.setCustom(content.widget);
+    }
+    return true;
+};
+
+/**
+ * Builds a widget's tooltip on demand from its `query-tooltip` signal, so
+ * the tooltip can depend on the pointer position or contain custom widgets.
+ *
+ * Enables `has-tooltip` on the widget. The provider runs each time the
+ * tooltip is about to be shown; it can fill in the tooltip itself through
+ * `query.tooltip` and return true, or return the content to show.
+ *
+ * @param widget - The widget to attach the tooltip to
+ * @param provider - Returns the content for a query
+ * @returns A function that removes the provider and restores `has-tooltip`
+ *
+ * @example
+ * ```tsx
+ * setTooltipContent(drawingArea, ({ x, y }) => {
+ *     const point = findPoint(x, y);
+ *     return point ? { markup: `<b>${point.label}</b>`, iconName: "mark-location-symbolic" } : null;
+ * });
+ * ```
+ */
+export const setTooltipContent = (widget: Widget, provider: (query: TooltipQuery) => TooltipContent): (() => void) => {
+    const hadTooltip = widget.getHasTooltip();
+    const handlerId = widget.connect(
+        "query-tooltip",
+        (_widget: Widget, x: number, y: number, keyboardMode: boolean, tooltip: Tooltip) =>
+            applyContent(tooltip, provider({ x, y, keyboardMode, tooltip })),
+    );
+    widget.setHasTooltip(true);
+
+    return () => {
+        widget.disconnect(handlerId);
+        widget.setHasTooltip(hadTooltip);
+    };
+};
@@ -0,0 +1,175 @@
+import { copy, type NativeHandle } from "@gtkx/native";
+import { Value } from "../generated/gobject/value.js";
+import type { ListStore } from "../generated/gtk/list-store.js";
+import { TreeIter } from "../generated/gtk/tree-iter.js";
+import type { TreeModel } from "../generated/gtk/tree-model.js";
+import { TreePath } from "../generated/gtk/tree-path.js";
+import type { TreeStore } from "../generated/gtk/tree-store.js";
+import { t } from "../native.js";
+import { getNativeObject } from "../registry.js";
+
+const LIB = "libgtk-4.so.1";
+
+const MODEL = t.object("borrowed");
+const ITER = t.boxed("GtkTreeIter", "borrowed", LIB, "gtk_tree_iter_get_type");
+const PATH = t.boxed("GtkTreePath", "borrowed", LIB, "gtk_tree_path_get_type");
+const GVALUE = t.boxed("GValue", "borrowed", "libgobject-2.0.so.0", "g_value_get_type");
+
+const getColumnType = t.fn(LIB, "gtk_tree_model_get_column_type", [{ type: MODEL }, { type: t.int32 }], t.uint64);
+const getNColumns = t.fn(LIB, "gtk_tree_model_get_n_columns", [{ type: MODEL }], t.int32);
+const getIterFirst = t.fn(LIB, "gtk_tree_model_get_iter_first", [{ type: MODEL }, { type: ITER }], t.boolean);
+const iterNext = t.fn(LIB, "gtk_tree_model_iter_next", [{ type: MODEL }, { type: ITER }], t.boolean);
+
+const getValue = t.fn(
+    LIB,
+    "gtk_tree_model_get_value",
+    [{ type: MODEL }, { type: ITER }, { type: t.int32 }, { type: GVALUE }],
+    t.void,
+);
+
+const listStoreSetValue = t.fn(
+    LIB,
+    "gtk_list_store_set_value",
+    [{ type: MODEL }, { type: ITER }, { type: t.int32 }, { type: GVALUE }],
+    t.void,
+);
+
+const treeStoreSetValue = t.fn(
+    LIB,
+    "gtk_tree_store_set_value",
+    [{ type: MODEL }, { type: ITER }, { type: t.int32 }, { type: GVALUE }],
+    t.void,
+);
+
+const FOREACH_FUNC = t.trampoline([MODEL, PATH, ITER, t.uint64], t.boolean, { userDataIndex: 3, scope: "call" });
+
+const listStoreAppend = t.fn(LIB, "gtk_list_store_append", [{ type: MODEL }, { type: ITER }], t.void);
+
+const foreach = t.fn(LIB, "gtk_tree_model_foreach", [{ type: MODEL }, { type: FOREACH_FUNC }], t.void);
+
+/** A `GtkTreeModel` implementation accepted by the tree model helpers. */
+export type TreeModelLike = TreeModel | ListStore | TreeStore;
+
+/** Size of `GtkTreeIter`: an `int` stamp followed by three pointers. */
+const TREE_ITER_SIZE = 32;
+
+/**
+ * Copies a `GtkTreeIter` into a JS-owned allocation.
+ *
+ * Iterators handed to callbacks point into the caller's stack frame and
+ * become invalid as soon as the callback returns. The copy is safe to keep,
+ * subject to the model's own iterator validity rules.
+ *
+ * @param iter - The iterator to copy
+ * @returns An independent iterator
+ */
+export const copyTreeIter = (iter: TreeIter): TreeIter =>
+    getNativeObject(copy(iter.handle, "GtkTreeIter", TREE_ITER_SIZE), TreeIter);
+
+/**
+ * Reads a cell of a tree model as a plain JavaScript value.
+ *
+ * @param model - The model to read from
+ * @param iter - The row
+ * @param column - The column index
+ * @returns The cell value, unmarshalled with {@link Value.toJS}
+ */
+export const getTreeModelValue = (model: TreeModelLike, iter: TreeIter, column: number): unknown => {
+    const gvalue = new Value();
+    getValue(model.handle, iter.handle, column, gvalue.handle);
+    return gvalue.toJS();
+};
+
+/**
+ * Reads every column of a row.
+ *
+ * @param model - The model to read from
+ * @param iter - The row
+ * @returns The cell values, indexed by column
+ */
+export const getTreeModelRow = (model: TreeModelLike, iter: TreeIter): unknown[] => {
+    const nColumns = getNColumns(model.handle) as number;
+    return Array.from({ length: nColumns }, (_, column) => getTreeModelValue(model, iter, column));
+};
+
+const toColumnValue = (model: ListStore | TreeStore, column: number, value: unknown): Value =>
+    Value.fromJS(getColumnType(model.handle, column) as number, value);
+
+/**
+ * Sets a cell of a `GtkListStore` from a plain JavaScript value.
+ *
+ * The value is marshalled to the column's declared GType.
+ *
+ * @param store - The store to modify
+ * @param iter - The row
+ * @param column - The column index
+ * @param value - The new value
+ */
+export const setListStoreValue = (store: ListStore, iter: TreeIter, column: number, value: unknown): void => {
+    listStoreSetValue(store.handle, iter.handle, column, toColumnValue(store, column, value).handle);
+};
+
+/**
+ * Appends a row to a `GtkListStore` and fills its leading columns.
+ *
+ * @param store - The store to modify
+ * @param values - Cell values, indexed by column; missing columns stay unset
+ * @returns An iterator pointing at the new row
+ */
+export const appendListStoreRow = (store: ListStore, values: readonly unknown[]): TreeIter => {
+    const iter = new TreeIter();
+    listStoreAppend(store.handle, iter.handle);
+    values.forEach((value, column) => {
+        setListStoreValue(store, iter, column, value);
+    });
+    return iter;
+};
+
+/**
+ * Sets a cell of a `GtkTreeStore` from a plain JavaScript value.
+ *
+ * The value is marshalled to the column's declared GType.
+ *
+ * @param store - The store to modify
+ * @param iter - The row
+ * @param column - The column index
+ * @param value - The new value
+ */
+export const setTreeStoreValue = (store: TreeStore, iter: TreeIter, column: number, value: unknown): void => {
+    treeStoreSetValue(store.handle, iter.handle, column, toColumnValue(store, column, value).handle);
+};
+
+/**
+ * Calls `callback` for each row of a tree model, depth first.
+ *
+ * Wraps `gtk_tree_model_foreach`. The path and iterator passed to `callback`
+ * are copies, so they remain usable after it returns.
+ *
+ * @param model - The model to walk
+ * @param callback - Called per row; return `true` to stop the walk
+ */
+export const forEachTreeModelRow = (
+    model: TreeModelLike,
+    callback: (path: TreePath, iter: TreeIter) => unknown,
+): void => {
+    foreach(model.handle, (_model: NativeHandle, path: NativeHandle, iter: NativeHandle) => {
+        const ownedPath = getNativeObject(copy(path, "GtkTreePath"), TreePath);
+        const ownedIter = getNativeObject(copy(iter, "GtkTreeIter", TREE_ITER_SIZE), TreeIter);
+        return callback(ownedPath, ownedIter) === true;
+    });
+};
+
+/**
+ * Iterates over the top-level rows of a tree model.
+ *
+ * Uses a single caller-allocated iterator; each yielded value is a copy.
+ *
+ * @param model - The model to iterate
+ */
+export function* treeModelRows(model: TreeModelLike): Generator<TreeIter> {
+    const iter = new TreeIter();
+    if (!getIterFirst(model.handle, iter.handle)) return;
+    do {
+        yield copyTreeIter(iter);
+    } while (iterNext(model.handle, iter.handle));
+}
@@ -0,0 +1,115 @@
+import type { NativeHandle } from "@gtkx/native";
+import type { ModifierType } from "../generated/gdk/enums.js";
+import { Variant } from "../generated/glib/variant.js";
+import { typeClassRef, typeFromName, typeIsA } from "../generated/gobject/functions.js";
+import type { Widget } from "../generated/gtk/widget.js";
+import { t } from "../native.js";
+import type { NativeClass } from "../object.js";
+import { getNativeObject } from "../registry.js";
+
+const LIB = "libgtk-4.so.1";
+const LIB_GLIB = "libglib-2.0.so.0";
+
+const WIDGET_CLASS = t.struct("GtkWidgetClass");
+const VARIANT = t.fundamental(LIB_GLIB, "g_variant_ref_sink", "g_variant_unref", "borrowed", "GVariant");
+
+// `GtkWidgetActionActivateFunc` takes no user data; the data pointer the
+// trampoline passes after it is an extra trailing argument the callee ignores.
+const ACTIVATE = t.trampoline([t.object("borrowed"), t.string("borrowed"), VARIANT], t.void, { scope: "forever" });
+
+const installAction = t.fn(
+    LIB,
+    "gtk_widget_class_install_action",
+    [
+        { type: WIDGET_CLASS },
+        { type: t.string("borrowed") },
+        { type: t.string("borrowed"), optional: true },
+        { type: ACTIVATE },
+    ],
+    t.void,
+);
+
+// Variadic in C; called with a NULL format string and no trailing arguments.
+const addBindingAction = t.fn(
+    LIB,
+    "gtk_widget_class_add_binding_action",
+    [
+        { type: WIDGET_CLASS },
+        { type: t.uint32 },
+        { type: t.flags(LIB, "gdk_modifier_type_get_type", false) },
+        { type: t.string("borrowed") },
+        { type: t.string("borrowed"), optional: true },
+    ],
+    t.void,
+);
+
+/** Handler for an action installed with {@link installWidgetAction}. */
+export type WidgetActionActivate<T extends Widget = Widget> = (
+    widget: T,
+    actionName: string,
+    parameter: Variant | null,
+) => void;
+
+const resolveWidgetClass = (cls: NativeClass<Widget>): NativeHandle => {
+    const gtype = typeFromName(cls.glibTypeName);
+    if (gtype === 0) {
+        throw new Error(`Unknown GLib type '${cls.glibTypeName}'`);
+    }
+    if (!typeIsA(gtype, typeFromName("GtkWidget"))) {
+        throw new Error(`'${cls.glibTypeName}' is not a GtkWidget subclass`);
+    }
+    return typeClassRef(gtype).handle;
+};
+
+/**
+ * Installs an action on a widget class, making it available as
+ * `actionName` to every instance of the class and its subclasses.
+ *
+ * Wraps `gtk_widget_class_install_action`. Install actions before creating
+ * instances: a widget reads its class actions the first time its action
+ * group is needed. The handler lives as long as the class.
+ *
+ * @param cls - A registered widget subclass
+ * @param actionName - Name of the action, conventionally `"group.name"`
+ * @param parameterType - `GVariant` type string of the parameter, or null for none
+ * @param activate - Called with the widget the action was activated on
+ * @throws if `cls` is not registered with GLib or is not a widget type
+ *
+ * @example
+ * ```tsx
+ * installWidgetAction(MyEditor, "editor.save", null, (editor) => editor.save());
+ * addWidgetBindingAction(MyEditor, Gdk.KEY_s, Gdk.ModifierType.CONTROL_MASK, "editor.save");
+ * ```
+ */
+export const installWidgetAction = <T extends Widget>(
+    cls: NativeClass<T>,
+    actionName: string,
+    parameterType: string | null,
+    activate: WidgetActionActivate<T>,
+): void => {
+    const callback = (widget: NativeHandle, name: string, parameter: NativeHandle | null) =>
+        activate(getNativeObject(widget) as unknown as T, name, getNativeObject(parameter, Variant));
+    installAction(resolveWidgetClass(cls), actionName, parameterType, callback);
+};
+
+/**
+ * Binds a key combination to an action on a widget class.
+ *
+ * Wraps `gtk_widget_class_add_binding_action`. The shortcut triggers while
+ * an instance of the class has focus, activating `actionName` without a
+ * parameter.
+ *
+ * @param cls - A registered widget subclass
+ * @param keyval - Key value, such as `Gdk.KEY_s`
+ * @param mods - Modifiers that must be held
+ * @param actionName - Name of the action to activate
+ * @throws if `cls` is not registered with GLib or is not a widget type
+ */
+export const addWidgetBindingAction = (
+    cls: NativeClass<Widget>,
+    keyval: number,
+    mods: ModifierType,
+    actionName: string,
+): void => {
+    addBindingAction(resolveWidgetClass(cls), keyval, mods, actionName, null);
+};
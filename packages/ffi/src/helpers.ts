@@ -1,12 +1,52 @@
-import { type Arg, call as nativeCall, type Type } from "@gtkx/native";
+import {
+    type Arg,
+    type CallbackOverflow,
+    type CallbackPriority,
+    type CallbackType,
+    call as nativeCall,
+    type StructField,
+    type Type,
+} from "@gtkx/native";
 
-export { alloc, call, freeze, read, unfreeze, write } from "@gtkx/native";
+export {
+    alloc,
+    type ArrayLayout,
+    type Bitfield,
+    type ByteOrder,
+    call,
+    type EnumValue,
+    flagsFromNicks,
+    flagsToNicks,
+    freeze,
+    getTypeRegistration,
+    indexHandle,
+    listEnumValues,
+    type MemoryAccessOptions,
+    read,
+    readBitfield,
+    registerTypes,
+    type StructField,
+    type TypeRegistration,
+    unfreeze,
+    withCallbackPriority,
+    write,
+    writeBitfield,
+} from "@gtkx/native";
 
 /** Whether the caller takes ownership of a returned native value (`"full"`) or only borrows it (`"borrowed"`). */
 export type Ownership = "full" | "borrowed";
 
 /** Container shape for array-like FFI types. */
-export type ArrayKind = "array" | "glist" | "gslist" | "gptrarray" | "garray" | "gbytearray" | "sized" | "fixed";
+export type ArrayKind =
+    | "array"
+    | "glist"
+    | "gslist"
+    | "gptrarray"
+    | "garray"
+    | "gbytearray"
+    | "gvaluearray"
+    | "sized"
+    | "fixed";
 
 /** Lifetime of a callback trampoline. */
 export type TrampolineScope = "call" | "notified" | "async" | "forever";
@@ -60,15 +100,30 @@ const int32: Type = Object.freeze({ type: "int32" });
 const uint32: Type = Object.freeze({ type: "uint32" });
 const int64: Type = Object.freeze({ type: "int64" });
 const uint64: Type = Object.freeze({ type: "uint64" });
+const intptr: Type = Object.freeze({ type: "intptr" });
+const uintptr: Type = Object.freeze({ type: "uintptr" });
 const float32: Type = Object.freeze({ type: "float32" });
 const float64: Type = Object.freeze({ type: "float64" });
 const booleanT: Type = Object.freeze({ type: "boolean" });
 const voidT: Type = Object.freeze({ type: "void" });
 const unicharT: Type = Object.freeze({ type: "unichar" });
+const gerrorT: Type = Object.freeze({ type: "gerror" });
 
 const stringT = (ownership: Ownership = "borrowed", length?: number): Type =>
     length === undefined ? { type: "string", ownership } : { type: "string", ownership, length };
 
+const gstringT = (ownership: Ownership = "borrowed"): Type => ({ type: "string", ownership, gstring: true });
+
+const gbytesT = (ownership: Ownership = "borrowed", view?: boolean): Type =>
+    view === undefined ? { type: "gbytes", ownership } : { type: "gbytes", ownership, view };
+
+const gvalueT = (ownership: Ownership = "borrowed", valueType?: Type, typeName?: string): Type => {
+    const result: Type = { type: "gvalue", ownership };
+    if (valueType !== undefined) result.valueType = valueType;
+    if (typeName !== undefined) result.typeName = typeName;
+    return result;
+};
+
 const objectT = (ownership: Ownership = "borrowed"): Type => ({ type: "gobject", ownership });
 
 const boxedT = (innerType: string, ownership: Ownership = "borrowed", library?: string, getTypeFn?: string): Type => {
@@ -78,9 +133,15 @@ const boxedT = (innerType: string, ownership: Ownership = "borrowed", library?:
     return result;
 };
 
-const structT = (innerType: string, ownership: Ownership = "borrowed", size?: number): Type => {
+const structT = (
+    innerType: string,
+    ownership: Ownership = "borrowed",
+    size?: number,
+    fields?: StructField[],
+): Type => {
     const result: Type = { type: "struct", ownership, innerType };
     if (size !== undefined) result.size = size;
+    if (fields !== undefined) result.fields = fields;
     return result;
 };
 
@@ -105,19 +166,29 @@ const hashTableT = (keyType: Type, valueType: Type, ownership: Ownership = "borr
     ownership,
 });
 
-const enumT = (library: string, getTypeFn: string, signed: boolean): Type => ({
-    type: "enum",
-    library,
-    getTypeFn,
-    signed,
-});
+/** Optional configuration for an enum FFI descriptor. */
+export type EnumOptions = {
+    /** Reject values not defined by the registered enum type. */
+    strict?: boolean;
+};
 
-const flagsT = (library: string, getTypeFn: string, signed: boolean): Type => ({
-    type: "flags",
-    library,
-    getTypeFn,
-    signed,
-});
+const enumT = (library: string, getTypeFn: string, signed: boolean, options?: EnumOptions): Type => {
+    const result: Type = { type: "enum", library, getTypeFn, signed };
+    if (options?.strict !== undefined) result.strict = options.strict;
+    return result;
+};
+
+/** Optional configuration for a flags FFI descriptor. */
+export type FlagsOptions = {
+    /** Reject masks with bits not defined by the registered flags type. */
+    strict?: boolean;
+};
+
+const flagsT = (library: string, getTypeFn: string, signed: boolean, options?: FlagsOptions): Type => {
+    const result: Type = { type: "flags", library, getTypeFn, signed };
+    if (options?.strict !== undefined) result.strict = options.strict;
+    return result;
+};
 
 /** Optional sizing metadata for array-like FFI descriptors. */
 export type ArrayOptions = {
@@ -153,6 +224,9 @@ const gArray = (itemType: Type, ownership: Ownership = "borrowed", elementSize?:
 
 const byteArray = (ownership: Ownership = "borrowed"): Type => arrayT(uint8, "gbytearray", ownership);
 
+const valueArray = (itemType: Type = voidT, ownership: Ownership = "borrowed"): Type =>
+    arrayT(itemType, "gvaluearray", ownership);
+
 const sizedArray = (
     itemType: Type,
     sizeParamIndex: number,
@@ -163,12 +237,32 @@ const sizedArray = (
 const fixedArray = (itemType: Type, fixedSize: number, ownership: Ownership = "borrowed", elementSize?: number): Type =>
     arrayT(itemType, "fixed", ownership, { fixedSize, elementSize });
 
-const callbackT = (argTypes: Type[], returnType: Type): Type => ({
-    type: "callback",
-    kind: "closure",
-    argTypes,
-    returnType,
-});
+/** Optional configuration for a closure callback FFI descriptor. */
+export type CallbackOptions = {
+    /** Collapse bursts of emissions into one call with the latest arguments. Requires a void return type. */
+    coalesce?: boolean;
+    /** Pass the emitting instance as the last argument instead of the first. */
+    swapped?: boolean;
+    /** Index of the `GObject` argument whose finalization invalidates the closure. */
+    watchArg?: number;
+    /** Read arguments directly and reuse their storage. Void callbacks with plain arguments only. */
+    fastArgs?: boolean;
+    /** Lane the closure's calls are queued in on the JS thread. */
+    priority?: CallbackPriority;
+    /** Drop or coalesce emissions while the JS thread is behind. Void callbacks only. */
+    overflow?: CallbackOverflow;
+};
+
+const callbackT = (argTypes: Type[], returnType: Type, options?: CallbackOptions): CallbackType => {
+    const result: CallbackType = { type: "callback", kind: "closure", argTypes, returnType };
+    if (options?.coalesce !== undefined) result.coalesce = options.coalesce;
+    if (options?.swapped !== undefined) result.swapped = options.swapped;
+    if (options?.watchArg !== undefined) result.watchArg = options.watchArg;
+    if (options?.fastArgs !== undefined) result.fastArgs = options.fastArgs;
+    if (options?.priority !== undefined) result.priority = options.priority;
+    if (options?.overflow !== undefined) result.overflow = options.overflow;
+    return result;
+};
 
 /** Optional configuration for a trampoline FFI descriptor. */
 export type TrampolineOptions = {
@@ -178,6 +272,12 @@ export type TrampolineOptions = {
     userDataIndex?: number;
     /** Lifetime of the callback. */
     scope?: TrampolineScope;
+    /**
+     * `"compare"` reduces a comparator's number result to its sign;
+     * `"filter"` converts a predicate's result to a boolean by its truthiness;
+     * `"source"` keeps a `GSourceFunc`'s source only when it returns `true`.
+     */
+    kind?: "default" | "compare" | "filter" | "source";
 };
 
 const trampolineT = (argTypes: Type[], returnType: Type, options?: TrampolineOptions): Type => {
@@ -185,6 +285,7 @@ const trampolineT = (argTypes: Type[], returnType: Type, options?: TrampolineOpt
     if (options?.hasDestroy !== undefined) result.hasDestroy = options.hasDestroy;
     if (options?.userDataIndex !== undefined) result.userDataIndex = options.userDataIndex;
     if (options?.scope !== undefined) result.scope = options.scope;
+    if (options?.kind !== undefined) result.kind = options.kind;
     return result;
 };
 
@@ -215,12 +316,18 @@ export const t = {
     uint32,
     int64,
     uint64,
+    intptr,
+    uintptr,
     float32,
     float64,
     boolean: booleanT,
     void: voidT,
     unichar: unicharT,
+    gerror: gerrorT,
     string: stringT,
+    gstring: gstringT,
+    gbytes: gbytesT,
+    gvalue: gvalueT,
     object: objectT,
     boxed: boxedT,
     struct: structT,
@@ -235,6 +342,7 @@ export const t = {
     ptrArray,
     gArray,
     byteArray,
+    valueArray,
     sizedArray,
     fixedArray,
     callback: callbackT,
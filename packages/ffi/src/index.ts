@@ -1,4 +1,6 @@
 export { Arg, type FfiValue, NativeHandle, Type } from "@gtkx/native";
+export * from "./broadway.js";
+export * from "./diagnostics.js";
 export * from "./lifecycle.js";
 export * from "./native.js";
 export * from "./registry.js";
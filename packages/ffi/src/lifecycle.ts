@@ -1,7 +1,21 @@
 import EventEmitter from "node:events";
-import { type NativeHandle, init as nativeInit, stop as nativeStop } from "@gtkx/native";
+import { dirname, resolve } from "node:path";
+import {
+    type BundleOptions,
+    configureBundle,
+    type FatalError,
+    type NativeHandle,
+    init as nativeInit,
+    onFatalError,
+    stop as nativeStop,
+} from "@gtkx/native";
+import type { Activation } from "./gio/single-instance.js";
 import { init as initAdwaita } from "./generated/adw/functions.js";
-import { init as initGtk } from "./generated/gtk/functions.js";
+import { Display } from "./generated/gdk/display.js";
+import { setenv } from "./generated/glib/functions.js";
+import { typeNameFromInstance } from "./generated/gobject/functions.js";
+import { TypeInstance } from "./generated/gobject/type-instance.js";
+import { initCheck as initGtk } from "./generated/gtk/functions.js";
 import { finalize as finalizeGtkSource, init as initGtkSource } from "./generated/gtksource/functions.js";
 
 const KEEP_ALIVE_INTERVAL = 2147483647;
@@ -25,13 +39,20 @@ type NativeEventMap = {
     start: [];
     /** Emitted when the GTK runtime is shutting down */
     stop: [];
+    /** Emitted when a second launch of the application is forwarded to this instance */
+    activated: [Activation];
+    /** Emitted when a fatal GLib error is about to abort the process */
+    crash: [FatalError];
 };
 
 /**
  * Event emitter for GTK runtime lifecycle events.
  *
  * Emits "start" the first time {@link initRuntime} is called and "stop"
- * when {@link stop} is invoked.
+ * when {@link stop} is invoked. Emits "activated" for launches forwarded
+ * by a second instance while `watchActivations` from `@gtkx/ffi/gio` is
+ * watching, and "crash" before a fatal error aborts the process when
+ * {@link initRuntime} was given `fatalErrors: "stop"`.
  *
  * @example
  * ```tsx
@@ -56,19 +77,97 @@ export const events = new EventEmitter<NativeEventMap>();
  */
 export const isStarted = (): boolean => runtimeReady;
 
+/** A GDK windowing backend. */
+export type Backend = "wayland" | "x11" | "broadway";
+
+const BACKEND_DISPLAY_TYPES: Record<string, Backend> = {
+    GdkWaylandDisplay: "wayland",
+    GdkX11Display: "x11",
+    GdkBroadwayDisplay: "broadway",
+};
+
+/** Options for {@link initRuntime}. */
+export type RuntimeOptions = {
+    /**
+     * GDK backend to open the display with, or several to try in order.
+     * Overrides `GDK_BACKEND` from the environment; when omitted, GDK picks
+     * the backend as usual.
+     *
+     * Forcing `"x11"` runs the app under XWayland on a Wayland session, for
+     * example so screen sharing tools that only capture X11 windows can see it.
+     */
+    backend?: Backend | readonly Backend[];
+    /**
+     * Relocatable bundle to load GTK from, for apps packaged with their own
+     * copies of the libraries. A relative `root` resolves against the
+     * directory of the Node.js executable, so a packaged app keeps working
+     * wherever it is installed.
+     *
+     * Must be given on the first call into GTK, since libraries loaded
+     * earlier are not reloaded from the bundle.
+     */
+    bundle?: BundleOptions;
+    /**
+     * What happens when `g_error` or a fatal assertion failure aborts the
+     * process. `"abort"`, the default, leaves GLib to abort right away.
+     * `"stop"` first emits a `"crash"` event on {@link events}, whose
+     * listeners can still call into GTK to save state, and then runs
+     * {@link stop}. The process aborts either way.
+     */
+    fatalErrors?: "abort" | "stop";
+};
+
+export type { BundleOptions, FatalError };
+
 /**
- * Initializes optional GTK extension libraries (Adwaita, GtkSource).
+ * Initializes GTK and the optional extension libraries (Adwaita, GtkSource).
  *
  * The `GLib` main loop is spawned automatically when `@gtkx/ffi` is
  * imported, so most callers should rely on `render` from `@gtkx/react`
  * to trigger initialization. Call this directly only when bootstrapping
- * GTK without the React reconciler.
+ * GTK without the React reconciler, to choose the GDK backend, or to load
+ * GTK from a bundle. Options are ignored once the runtime has started.
+ *
+ * @param options - Runtime options
+ * @throws If `backend` is an empty list, or no display could be opened
+ *
+ * @example
+ * ```tsx
+ * initRuntime({ backend: ["x11", "wayland"] });
+ * console.log(getDisplayBackend()); // "x11", unless only Wayland was available
+ * ```
  */
-export const initRuntime = (): void => {
+export const initRuntime = (options: RuntimeOptions = {}): void => {
+    const backends = options.backend === undefined ? [] : [options.backend].flat();
+    if (options.backend !== undefined && backends.length === 0) {
+        throw new Error("Expected at least one GDK backend");
+    }
+
     if (runtimeReady) return;
-    runtimeReady = true;
 
-    initGtk();
+    if (options.bundle) {
+        configureBundle({ ...options.bundle, root: resolve(dirname(process.execPath), options.bundle.root) });
+    }
+
+    if (options.fatalErrors === "stop") {
+        onFatalError((error) => {
+            try {
+                events.emit("crash", error);
+            } finally {
+                stop();
+            }
+        });
+    }
+
+    if (backends.length > 0) {
+        setenv("GDK_BACKEND", backends.join(","), true);
+    }
+
+    if (!initGtk()) {
+        const tried = backends.length > 0 ? backends.join(", ") : "the default backend";
+        throw new Error(`Failed to open a display with ${tried}`);
+    }
+    runtimeReady = true;
 
     try {
         initAdwaita();
@@ -78,6 +177,18 @@ export const initRuntime = (): void => {
     events.emit("start");
 };
 
+/**
+ * Returns the GDK backend the default display was opened with.
+ *
+ * @returns The backend, or null before {@link initRuntime} or for backends
+ *   other than Wayland, X11 and Broadway
+ */
+export const getDisplayBackend = (): Backend | null => {
+    const display = Display.getDefault();
+    if (!display) return null;
+    return BACKEND_DISPLAY_TYPES[typeNameFromInstance(new TypeInstance(display.handle))] ?? null;
+};
+
 /**
  * Shuts down the GTK runtime.
  *
@@ -5,8 +5,41 @@
  * evaluated after the generated modules in our import-graph cycle.
  */
 
-export type { ArrayKind, ArrayOptions, Ownership, TrampolineOptions, TrampolineScope } from "./helpers.js";
-export { alloc, call, freeze, read, t, unfreeze, write } from "./helpers.js";
+export type {
+    ArrayKind,
+    ArrayOptions,
+    CallbackOptions,
+    EnumOptions,
+    FlagsOptions,
+    Ownership,
+    TrampolineOptions,
+    TrampolineScope,
+} from "./helpers.js";
+export {
+    alloc,
+    type ArrayLayout,
+    type Bitfield,
+    type ByteOrder,
+    call,
+    type EnumValue,
+    flagsFromNicks,
+    flagsToNicks,
+    freeze,
+    getTypeRegistration,
+    indexHandle,
+    listEnumValues,
+    type MemoryAccessOptions,
+    read,
+    readBitfield,
+    registerTypes,
+    type StructField,
+    t,
+    type TypeRegistration,
+    unfreeze,
+    withCallbackPriority,
+    write,
+    writeBitfield,
+} from "./helpers.js";
 
 import type { GError } from "./generated/glib/error.js";
 import { typeCheckInstanceIsA, typeFromName } from "./generated/gobject/functions.js";
@@ -0,0 +1,2 @@
+export * from "../generated/pango/index.js";
+export * from "./markup.js";
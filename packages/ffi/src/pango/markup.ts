@@ -0,0 +1,77 @@
+import { createRef, type NativeHandle } from "@gtkx/native";
+import { GError } from "../generated/glib/error.js";
+import { NativeError, t } from "../native.js";
+import { getNativeObject } from "../registry.js";
+
+const LIB = "libpango-1.0.so.0";
+
+const parseMarkup = t.fn(
+    LIB,
+    "pango_parse_markup",
+    [
+        { type: t.string("borrowed") },
+        { type: t.int32 },
+        { type: t.unichar },
+        { type: t.uintptr },
+        { type: t.uintptr },
+        { type: t.uintptr },
+        { type: t.ref(t.boxed("GError", "full", "libglib-2.0.so.0", "g_error_get_type")) },
+    ],
+    t.boolean,
+);
+
+const ENTITIES: Record<string, string> = {
+    "&": "&amp;",
+    "<": "&lt;",
+    ">": "&gt;",
+    "'": "&apos;",
+    '"': "&quot;",
+};
+
+// Matches the characters g_markup_escape_text replaces: the markup
+// delimiters, and the control characters that are not allowed unescaped
+// in XML 1.1.
+// biome-ignore lint/suspicious/noControlCharactersInRegex: control characters are what is being escaped
+const ESCAPED = /[&<>'"\u0001-\u0008\u000b\u000c\u000e-\u001f\u007f-\u0084\u0086-\u009f]/g;
+
+/**
+ * Checks that a string is valid Pango markup, as accepted by
+ * `Gtk.Label.setMarkup`, in a single call.
+ *
+ * @param markup - The markup to check
+ * @returns Null if the markup is valid, else the parse error
+ *
+ * @example
+ * ```tsx
+ * const error = validateMarkup(userMarkup);
+ * if (error) label.setText(userMarkup);
+ * else label.setMarkup(userMarkup);
+ * ```
+ */
+export const validateMarkup = (markup: string): NativeError | null => {
+    const error = createRef<NativeHandle | null>(null);
+    parseMarkup(markup, -1, 0, 0, 0, 0, error);
+    return error.value === null ? null : new NativeError(getNativeObject(error.value, GError));
+};
+
+/**
+ * Escapes text for inclusion in Pango markup, like `g_markup_escape_text`,
+ * without a call to the native side.
+ *
+ * @param text - Text that should appear literally, such as user input
+ * @returns The text with markup delimiters and control characters escaped
+ */
+export const escapeMarkup = (text: string): string =>
+    text.replace(ESCAPED, (char) => ENTITIES[char] ?? `&#x${char.charCodeAt(0).toString(16)};`);
+
+/**
+ * Template tag that builds Pango markup, escaping every interpolated value
+ * with {@link escapeMarkup} so user content cannot inject tags.
+ *
+ * @example
+ * ```tsx
+ * label.setMarkup(markup`<b>${user.name}</b> joined ${room.title}`);
+ * ```
+ */
+export const markup = (strings: TemplateStringsArray, ...values: unknown[]): string =>
+    strings.reduce((result, string, i) => result + escapeMarkup(String(values[i - 1])) + string);
@@ -119,6 +119,52 @@ export function findNativeObject(handle: NativeHandle): NativeObject | null {
     return obj;
 }
 
+const signalHandlerTable = new WeakMap<NativeObject, Set<number>>();
+
+/**
+ * Records a signal handler ID connected on an object.
+ *
+ * Called automatically by generated `connect` methods. Entries live as long
+ * as the wrapper; the handler's JS function itself is released by the native
+ * module when the handler is disconnected or the object is finalized.
+ *
+ * @param obj - The object the handler was connected on
+ * @param handlerId - The ID returned by `g_signal_connect_*`
+ * @returns `handlerId`, for use as a return value
+ */
+export function trackSignalHandler(obj: NativeObject, handlerId: number): number {
+    let ids = signalHandlerTable.get(obj);
+    if (!ids) {
+        ids = new Set();
+        signalHandlerTable.set(obj, ids);
+    }
+    ids.add(handlerId);
+    return handlerId;
+}
+
+/**
+ * Forgets a signal handler ID previously recorded with {@link trackSignalHandler}.
+ *
+ * @param obj - The object the handler was connected on
+ * @param handlerId - The handler ID
+ */
+export function untrackSignalHandler(obj: NativeObject, handlerId: number): void {
+    const ids = signalHandlerTable.get(obj);
+    ids?.delete(handlerId);
+    if (ids?.size === 0) signalHandlerTable.delete(obj);
+}
+
+/**
+ * Lists the signal handler IDs connected on an object through generated
+ * `connect` methods and not yet disconnected.
+ *
+ * @param obj - The object to inspect
+ * @returns The tracked handler IDs, in connection order
+ */
+export function getSignalHandlerIds(obj: NativeObject): number[] {
+    return [...(signalHandlerTable.get(obj) ?? [])];
+}
+
 /** @internal */
 type GetNativeObjectResult<
     T extends NativeHandle | null | undefined,
@@ -0,0 +1,62 @@
+import { describe, expect, it } from "vitest";
+import { showAboutWindow } from "../../src/adw/about.js";
+import * as Gio from "../../src/generated/gio/index.js";
+import * as Gtk from "../../src/generated/gtk/index.js";
+
+const createApplication = (id: string): Gtk.Application => {
+    const application = new Gtk.Application(Gio.ApplicationFlags.NON_UNIQUE, id);
+    application.register(null);
+    return application;
+};
+
+describe("showAboutWindow", () => {
+    it("builds the window from the info", () => {
+        const application = createApplication("org.gtkx.Test.AboutInfo");
+
+        const window = showAboutWindow(application, {
+            applicationName: "Notes",
+            version: "1.2.0",
+            developerName: "Ada Lovelace",
+            website: "https://example.com/notes",
+            licenseType: Gtk.License.GPL_3_0,
+            developers: ["Ada Lovelace <ada@example.com>"],
+            designers: ["Charles Babbage"],
+            debugInfo: "backend: x11",
+        });
+
+        expect(window.getApplicationName()).toBe("Notes");
+        expect(window.getVersion()).toBe("1.2.0");
+        expect(window.getDeveloperName()).toBe("Ada Lovelace");
+        expect(window.getWebsite()).toBe("https://example.com/notes");
+        expect(window.getLicenseType()).toBe(Gtk.License.GPL_3_0);
+        expect(window.getDevelopers()).toEqual(["Ada Lovelace <ada@example.com>"]);
+        expect(window.getDesigners()).toEqual(["Charles Babbage"]);
+        expect(window.getDebugInfo()).toBe("backend: x11");
+        window.destroy();
+    });
+
+    it("keeps the defaults of fields it is not given", () => {
+        const application = createApplication("org.gtkx.Test.AboutDefaults");
+
+        const window = showAboutWindow(application, { applicationName: "Notes" });
+
+        expect(window.getVersion()).toBe("");
+        expect(window.getLicenseType()).toBe(Gtk.License.UNKNOWN);
+        expect(window.getDevelopers()).toBeNull();
+        window.destroy();
+    });
+
+    it("adds the window to the application and presents it over the active window", () => {
+        const application = createApplication("org.gtkx.Test.AboutParent");
+        const parent = new Gtk.ApplicationWindow(application);
+        parent.present();
+
+        const window = showAboutWindow(application, { applicationName: "Notes" });
+
+        expect(window.getApplication()).toBe(application);
+        expect(window.getTransientFor()).toBe(parent);
+        expect(window.getVisible()).toBe(true);
+        window.destroy();
+        parent.destroy();
+    });
+});
@@ -0,0 +1,49 @@
+import { afterEach, describe, expect, it, vi } from "vitest";
+import { showAlertDialog } from "../../src/adw/alert-dialog.js";
+import * as Adw from "../../src/generated/adw/index.js";
+
+const responses = [
+    { id: "cancel", label: "Cancel" },
+    { id: "discard", label: "Discard", appearance: Adw.ResponseAppearance.DESTRUCTIVE },
+];
+
+const presentedDialog = (present: { mock: { contexts: unknown[] } }): Adw.AlertDialog =>
+    present.mock.contexts.at(-1) as Adw.AlertDialog;
+
+describe("showAlertDialog", () => {
+    afterEach(() => {
+        vi.restoreAllMocks();
+    });
+
+    it("builds the dialog from the options", () => {
+        const present = vi.spyOn(Adw.AlertDialog.prototype, "present");
+
+        void showAlertDialog(null, { heading: "Discard changes?", responses, defaultResponse: "cancel" });
+        const dialog = presentedDialog(present);
+
+        expect(dialog.getHeading()).toBe("Discard changes?");
+        expect(dialog.hasResponse("discard")).toBe(true);
+        expect(dialog.getResponseAppearance("discard")).toBe(Adw.ResponseAppearance.DESTRUCTIVE);
+        expect(dialog.getDefaultResponse()).toBe("cancel");
+        expect(dialog.getCloseResponse()).toBe("close");
+        dialog.forceClose();
+    });
+
+    it("resolves with the ID of the chosen response", async () => {
+        const present = vi.spyOn(Adw.AlertDialog.prototype, "present");
+
+        const response = showAlertDialog(null, { responses });
+        presentedDialog(present).emit("response", "discard");
+
+        await expect(response).resolves.toBe("discard");
+    });
+
+    it("resolves with the close response when dismissed", async () => {
+        const present = vi.spyOn(Adw.AlertDialog.prototype, "present");
+
+        const response = showAlertDialog(null, { responses, closeResponse: "cancel" });
+        presentedDialog(present).close();
+
+        await expect(response).resolves.toBe("cancel");
+    });
+});
@@ -0,0 +1,77 @@
+import { describe, expect, it, vi } from "vitest";
+import {
+    connectBreakpoint,
+    createBreakpoint,
+    parseBreakpointCondition,
+    setBreakpointCondition,
+} from "../../src/adw/breakpoint.js";
+
+describe("parseBreakpointCondition", () => {
+    it("parses length and aspect-ratio conditions", () => {
+        expect(parseBreakpointCondition("max-width: 400sp").toString()).toBe("max-width: 400sp");
+        expect(parseBreakpointCondition("min-aspect-ratio: 4/3").toString()).toBe("min-aspect-ratio: 4/3");
+    });
+
+    it("parses combined conditions", () => {
+        const condition = parseBreakpointCondition("max-width: 400px and max-height: 300px");
+
+        expect(condition.toString()).toBe("max-width: 400px and max-height: 300px");
+    });
+
+    it("throws on invalid conditions", () => {
+        expect(() => parseBreakpointCondition("widest: 4")).toThrow("Invalid breakpoint condition 'widest: 4'");
+    });
+});
+
+describe("createBreakpoint", () => {
+    it("creates a breakpoint with the parsed condition", () => {
+        const breakpoint = createBreakpoint("max-width: 600sp");
+
+        expect(breakpoint.getCondition()?.toString()).toBe("max-width: 600sp");
+    });
+});
+
+describe("setBreakpointCondition", () => {
+    it("replaces the condition", () => {
+        const breakpoint = createBreakpoint("max-width: 600sp");
+
+        setBreakpointCondition(breakpoint, "min-width: 200px");
+
+        expect(breakpoint.getCondition()?.toString()).toBe("min-width: 200px");
+    });
+
+    it("keeps the condition when the new one is invalid", () => {
+        const breakpoint = createBreakpoint("max-width: 600sp");
+
+        expect(() => setBreakpointCondition(breakpoint, "max-width")).toThrow();
+        expect(breakpoint.getCondition()?.toString()).toBe("max-width: 600sp");
+    });
+});
+
+describe("connectBreakpoint", () => {
+    it("calls the handler of each signal", () => {
+        const breakpoint = createBreakpoint("max-width: 600sp");
+        const calls: string[] = [];
+
+        const ids = connectBreakpoint(breakpoint, {
+            apply: () => calls.push("apply"),
+            unapply: () => calls.push("unapply"),
+        });
+        breakpoint.emit("apply");
+        breakpoint.emit("unapply");
+
+        expect(ids).toHaveLength(2);
+        expect(calls).toEqual(["apply", "unapply"]);
+    });
+
+    it("connects only the handlers it is given", () => {
+        const breakpoint = createBreakpoint("max-width: 600sp");
+        const unapply = vi.fn();
+
+        const ids = connectBreakpoint(breakpoint, { unapply });
+        breakpoint.emit("apply");
+
+        expect(ids).toHaveLength(1);
+        expect(unapply).not.toHaveBeenCalled();
+    });
+});
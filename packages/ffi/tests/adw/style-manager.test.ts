@@ -0,0 +1,49 @@
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import {
+    connectStyleChanged,
+    getColorScheme,
+    getStyleState,
+    type StyleState,
+    setColorScheme,
+} from "../../src/adw/style-manager.js";
+import * as Adw from "../../src/generated/adw/index.js";
+
+describe("color scheme", () => {
+    let previous: Adw.ColorScheme;
+
+    beforeEach(() => {
+        previous = getColorScheme();
+    });
+
+    afterEach(() => {
+        setColorScheme(previous);
+    });
+
+    it("sets the color scheme of the default style manager", () => {
+        setColorScheme(Adw.ColorScheme.FORCE_DARK);
+
+        expect(getColorScheme()).toBe(Adw.ColorScheme.FORCE_DARK);
+        expect(Adw.StyleManager.getDefault().getColorScheme()).toBe(Adw.ColorScheme.FORCE_DARK);
+    });
+
+    it("reports the forced appearance in the style state", () => {
+        setColorScheme(Adw.ColorScheme.FORCE_DARK);
+        expect(getStyleState()).toMatchObject({ colorScheme: Adw.ColorScheme.FORCE_DARK, dark: true });
+
+        setColorScheme(Adw.ColorScheme.FORCE_LIGHT);
+        expect(getStyleState()).toMatchObject({ colorScheme: Adw.ColorScheme.FORCE_LIGHT, dark: false });
+    });
+
+    it("notifies handlers of the new appearance", () => {
+        setColorScheme(Adw.ColorScheme.FORCE_LIGHT);
+        const states: StyleState[] = [];
+        const ids = connectStyleChanged((state) => states.push(state));
+
+        setColorScheme(Adw.ColorScheme.FORCE_DARK);
+        for (const id of ids) Adw.StyleManager.getDefault().disconnect(id);
+
+        expect(states.length).toBeGreaterThan(0);
+        expect(states.at(-1)).toMatchObject({ colorScheme: Adw.ColorScheme.FORCE_DARK, dark: true });
+        expect(states.at(-1)?.accentRgba).toEqual(getStyleState().accentRgba);
+    });
+});
@@ -0,0 +1,44 @@
+import { describe, expect, it, vi } from "vitest";
+import { showToast } from "../../src/adw/toast.js";
+import * as Adw from "../../src/generated/adw/index.js";
+
+describe("showToast", () => {
+    it("builds the toast from the options", () => {
+        const overlay = new Adw.ToastOverlay();
+
+        const toast = showToast(overlay, {
+            title: "File deleted",
+            button: "Undo",
+            timeout: 0,
+            priority: Adw.ToastPriority.HIGH,
+        });
+
+        expect(toast.getTitle()).toBe("File deleted");
+        expect(toast.getButtonLabel()).toBe("Undo");
+        expect(toast.getTimeout()).toBe(0);
+        expect(toast.getPriority()).toBe(Adw.ToastPriority.HIGH);
+        toast.dismiss();
+    });
+
+    it("calls the action callback when the button is clicked", () => {
+        const overlay = new Adw.ToastOverlay();
+        const actionCallback = vi.fn();
+
+        const toast = showToast(overlay, { title: "File deleted", button: "Undo", timeout: 0, actionCallback });
+        toast.emit("button-clicked");
+
+        expect(actionCallback).toHaveBeenCalledTimes(1);
+        toast.dismiss();
+    });
+
+    it("calls onDismissed once the toast is dismissed", () => {
+        const overlay = new Adw.ToastOverlay();
+        const onDismissed = vi.fn();
+
+        const toast = showToast(overlay, { title: "Saved", timeout: 0, onDismissed });
+        expect(onDismissed).not.toHaveBeenCalled();
+        toast.dismiss();
+
+        expect(onDismissed).toHaveBeenCalledTimes(1);
+    });
+});
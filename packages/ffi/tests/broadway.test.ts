@@ -0,0 +1,12 @@
+import { describe, expect, it } from "vitest";
+import { startBroadway } from "../src/index.js";
+
+describe("startBroadway", () => {
+    it("rejects when the daemon cannot be spawned", async () => {
+        await expect(startBroadway({ executable: "gtkx-missing-broadwayd" })).rejects.toThrow("ENOENT");
+    });
+
+    it("rejects when the daemon exits before it is ready", async () => {
+        await expect(startBroadway({ executable: "false" })).rejects.toThrow("exited with code 1");
+    });
+});
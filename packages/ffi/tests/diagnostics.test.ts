@@ -0,0 +1,30 @@
+import { describe, expect, it } from "vitest";
+import { diagnostics } from "../src/index.js";
+
+describe("diagnostics", () => {
+    it("reports the running library versions", () => {
+        const { gtkVersion, glibVersion } = diagnostics();
+
+        expect(gtkVersion).toMatch(/^4\.\d+\.\d+$/);
+        expect(glibVersion).toMatch(/^2\.\d+\.\d+$/);
+        expect(Number(glibVersion.split(".")[1])).toBeGreaterThanOrEqual(66);
+    });
+
+    it("reports the display backend and renderer", () => {
+        const result = diagnostics();
+
+        expect(["wayland", "x11", "broadway"]).toContain(result.backend);
+        expect(result.renderer).toMatch(/^Gsk\w+Renderer$/);
+        expect(result.scaleFactors.every((scale) => scale > 0)).toBe(true);
+    });
+
+    it("includes only environment variables that are set", () => {
+        process.env.GSK_DEBUG = "renderer";
+
+        const { env } = diagnostics();
+        delete process.env.GSK_DEBUG;
+
+        expect(env.GSK_DEBUG).toBe("renderer");
+        expect(Object.values(env).every((value) => typeof value === "string")).toBe(true);
+    });
+});
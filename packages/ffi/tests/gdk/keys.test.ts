@@ -0,0 +1,32 @@
+import { describe, expect, it } from "vitest";
+import { keys } from "../../src/gdk/keys.js";
+import { KEY_A, KEY_a, KEY_Return } from "../../src/generated/gdk/constants.js";
+import { ModifierType } from "../../src/generated/gdk/enums.js";
+
+describe("keys", () => {
+    it("converts between keyvals and names", () => {
+        expect(keys.name(KEY_Return)).toBe("Return");
+        expect(keys.fromName("Return")).toBe(KEY_Return);
+        expect(keys.fromName("NotAKey")).toBeNull();
+    });
+
+    it("converts between keyvals and characters", () => {
+        expect(keys.toUnicode(KEY_a)).toBe("a");
+        expect(keys.toUnicode(KEY_Return)).toBe("\r");
+        expect(keys.fromUnicode("a")).toBe(KEY_a);
+    });
+
+    it("changes the case of keyvals", () => {
+        expect(keys.toUpper(KEY_a)).toBe(KEY_A);
+        expect(keys.toLower(KEY_A)).toBe(KEY_a);
+    });
+
+    it("translates keycodes or reports that they have no keyval", () => {
+        const translation = keys.translate(38, ModifierType.NO_MODIFIER_MASK);
+
+        if (translation !== null) {
+            expect(keys.name(translation.keyval)).toEqual(expect.any(String));
+        }
+        expect(keys.translate(0, ModifierType.NO_MODIFIER_MASK)).toBeNull();
+    });
+});
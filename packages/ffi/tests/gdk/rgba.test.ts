@@ -0,0 +1,18 @@
+import { describe, expect, it } from "vitest";
+import { fromRgba, toRgba } from "../../src/gdk/rgba.js";
+
+describe("RGBA mapping", () => {
+    it("round-trips plain colors", () => {
+        const color = { red: 0.25, green: 0.5, blue: 0.75, alpha: 1 };
+
+        expect(toRgba(fromRgba(color))).toEqual(color);
+    });
+
+    it("parses CSS color strings", () => {
+        expect(toRgba(fromRgba("#ff0000"))).toEqual({ red: 1, green: 0, blue: 0, alpha: 1 });
+    });
+
+    it("rejects invalid color strings", () => {
+        expect(() => fromRgba("not a color")).toThrow("Invalid color 'not a color'");
+    });
+});
@@ -0,0 +1,20 @@
+import { describe, expect, it } from "vitest";
+import { readAppearance, watchAppearance } from "../../src/gio/appearance.js";
+
+describe("readAppearance", () => {
+    it("rejects when no portal is running", async () => {
+        await expect(readAppearance()).rejects.toThrow();
+    });
+});
+
+describe("watchAppearance", () => {
+    it("never calls the handler when no portal is running", async () => {
+        const settings: unknown[] = [];
+
+        const stop = watchAppearance((appearance) => settings.push(appearance));
+        await readAppearance().catch(() => {});
+        stop();
+
+        expect(settings).toEqual([]);
+    });
+});
@@ -0,0 +1,21 @@
+import { describe, expect, it } from "vitest";
+import { cancellableFromSignal } from "../../src/gio/cancellable.js";
+
+describe("cancellableFromSignal", () => {
+    it("returns undefined without a signal", () => {
+        expect(cancellableFromSignal()).toBeUndefined();
+    });
+
+    it("cancels when the signal aborts", () => {
+        const controller = new AbortController();
+        const cancellable = cancellableFromSignal(controller.signal);
+
+        expect(cancellable?.isCancelled()).toBe(false);
+        controller.abort();
+        expect(cancellable?.isCancelled()).toBe(true);
+    });
+
+    it("is cancelled immediately for an aborted signal", () => {
+        expect(cancellableFromSignal(AbortSignal.abort())?.isCancelled()).toBe(true);
+    });
+});
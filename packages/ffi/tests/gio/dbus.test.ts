@@ -0,0 +1,120 @@
+import { type NativeHandle, read } from "@gtkx/native";
+import { describe, expect, it, vi } from "vitest";
+import { callDBusMethod, DBusError, exportDBusInterface, getBus } from "../../src/gio/dbus.js";
+import { packVariant } from "../../src/glib/variant.js";
+
+const INTERFACE = "org.gtkx.Test.Calculator";
+
+const XML = `<node>
+  <interface name="${INTERFACE}">
+    <method name="Add">
+      <arg type="i" direction="in"/><arg type="i" direction="in"/><arg type="i" direction="out"/>
+    </method>
+    <method name="Fail"/>
+    <property name="Name" type="s" access="read"/>
+  </interface>
+</node>`;
+
+const GOBJECT_REF_COUNT_OFFSET = 8;
+
+let nextPath = 1;
+
+const refCount = (object: { handle: NativeHandle }) =>
+    read(object.handle, { type: "uint32" }, GOBJECT_REF_COUNT_OFFSET) as number;
+
+const settle = () =>
+    new Promise((resolve) => {
+        global.gc?.();
+        setTimeout(resolve, 50);
+    });
+
+const exportCalculator = () => {
+    const connection = getBus();
+    const objectPath = `/org/gtkx/Test/Calculator${nextPath++}`;
+    const exported = exportDBusInterface(connection, objectPath, {
+        xml: XML,
+        name: INTERFACE,
+        methods: {
+            Add: ([a, b]) => packVariant("(i)", [(a as number) + (b as number)]),
+            Fail: async () => {
+                throw new DBusError("org.gtkx.Test.Error.Refused", "refused");
+            },
+        },
+        properties: {
+            Name: () => packVariant("s", "calculator"),
+        },
+    });
+    const call = (method: string, args: unknown = null) =>
+        callDBusMethod(connection, {
+            busName: connection.getUniqueName() as string,
+            objectPath,
+            interfaceName: INTERFACE,
+            method,
+            args: args === null ? null : packVariant("(ii)", args),
+        });
+    return { connection, objectPath, exported, call };
+};
+
+describe("exportDBusInterface", () => {
+    it("dispatches method calls to JavaScript handlers", async () => {
+        const { exported, call } = exportCalculator();
+
+        await expect(call("Add", [2, 3])).resolves.toEqual([5]);
+
+        exported.unexport();
+    });
+
+    it("replies with the D-Bus error thrown by a handler", async () => {
+        const { exported, call } = exportCalculator();
+
+        await expect(call("Fail")).rejects.toThrow("refused");
+
+        exported.unexport();
+    });
+
+    it("serves property reads", async () => {
+        const { connection, objectPath, exported } = exportCalculator();
+
+        const reply = await callDBusMethod(connection, {
+            busName: connection.getUniqueName() as string,
+            objectPath,
+            interfaceName: "org.freedesktop.DBus.Properties",
+            method: "Get",
+            args: packVariant("(ss)", [INTERFACE, "Name"]),
+        });
+
+        expect(reply).toEqual(["calculator"]);
+        exported.unexport();
+    });
+
+    it("releases each invocation once it is answered", async () => {
+        const { connection, exported, call } = exportCalculator();
+        await call("Add", [0, 0]);
+        await settle();
+        const baseline = refCount(connection);
+
+        for (let i = 0; i < 5; i++) {
+            await call("Add", [i, i]);
+            await call("Fail").catch(() => {});
+        }
+
+        await vi.waitFor(() => {
+            global.gc?.();
+            expect(refCount(connection)).toBe(baseline);
+        });
+        exported.unexport();
+    });
+
+    it("rejects XML without the named interface", () => {
+        expect(() =>
+            exportDBusInterface(getBus(), "/org/gtkx/Test/Missing", { xml: XML, name: "org.gtkx.Test.Missing" }),
+        ).toThrow("Introspection XML has no interface named 'org.gtkx.Test.Missing'");
+    });
+
+    it("stops serving calls once unexported", async () => {
+        const { exported, call } = exportCalculator();
+        exported.unexport();
+
+        await expect(call("Add", [1, 1])).rejects.toThrow();
+    });
+});
@@ -0,0 +1,17 @@
+import { describe, expect, it } from "vitest";
+import { registerGlobalShortcut } from "../../src/gio/global-shortcuts.js";
+
+describe("registerGlobalShortcut", () => {
+    it("rejects unsupported accelerator modifiers", async () => {
+        await expect(registerGlobalShortcut("<Hyper>k", () => {})).rejects.toThrow(
+            "Unsupported modifier '<Hyper>' in accelerator '<Hyper>k'",
+        );
+    });
+
+    it("rejects and forgets the shortcut when no portal is running", async () => {
+        const register = () => registerGlobalShortcut("<Control><Alt>n", () => {}, { id: "test-shortcut" });
+
+        await expect(register()).rejects.toThrow();
+        await expect(register()).rejects.not.toThrow("already registered");
+    });
+});
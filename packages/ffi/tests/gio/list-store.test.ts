@@ -0,0 +1,37 @@
+import { describe, expect, it } from "vitest";
+import * as Gio from "../../src/generated/gio/index.js";
+import { typeFromName } from "../../src/generated/gobject/functions.js";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import { insertSortedInListStore, sortListStore } from "../../src/gio/list-store.js";
+
+const byNumber = (a: Gtk.StringObject, b: Gtk.StringObject): number => Number(a.getString()) - Number(b.getString());
+
+const createStore = (values: string[]): Gio.ListStore => {
+    const store = new Gio.ListStore(typeFromName("GtkStringObject"));
+    for (const value of values) store.append(new Gtk.StringObject(value));
+    return store;
+};
+
+const valuesOf = (store: Gio.ListStore): string[] =>
+    Array.from({ length: store.getNItems() }, (_, i) => (store.getItem(i) as Gtk.StringObject).getString());
+
+describe("sortListStore", () => {
+    it("orders items by the comparator", () => {
+        const store = createStore(["0.5", "0.25", "0.75"]);
+
+        sortListStore(store, byNumber);
+
+        expect(valuesOf(store)).toEqual(["0.25", "0.5", "0.75"]);
+    });
+});
+
+describe("insertSortedInListStore", () => {
+    it("inserts at the sorted position", () => {
+        const store = createStore(["1", "3"]);
+
+        const position = insertSortedInListStore(store, new Gtk.StringObject("2"), byNumber);
+
+        expect(position).toBe(1);
+        expect(valuesOf(store)).toEqual(["1", "2", "3"]);
+    });
+});
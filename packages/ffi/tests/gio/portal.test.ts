@@ -0,0 +1,26 @@
+import { describe, expect, it } from "vitest";
+import { getBus } from "../../src/gio/dbus.js";
+import { callPortal, createPortalToken } from "../../src/gio/portal.js";
+import { boxVariant, packVariant } from "../../src/glib/variant.js";
+
+describe("createPortalToken", () => {
+    it("returns distinct tokens usable as object path elements", () => {
+        const first = createPortalToken("test");
+        const second = createPortalToken("test");
+
+        expect(first).not.toBe(second);
+        expect(first).toMatch(/^test_[A-Za-z0-9_]+$/);
+    });
+});
+
+describe("callPortal", () => {
+    it("rejects when no portal is running", async () => {
+        const request = callPortal(getBus(), {
+            interfaceName: "org.freedesktop.portal.Screenshot",
+            method: "Screenshot",
+            args: (handleToken) => packVariant("(sa{sv})", ["", { handle_token: boxVariant("s", handleToken) }]),
+        });
+
+        await expect(request).rejects.toThrow();
+    });
+});
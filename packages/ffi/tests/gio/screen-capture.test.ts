@@ -0,0 +1,14 @@
+import { describe, expect, it } from "vitest";
+import { startScreenCast, takeScreenshot } from "../../src/gio/screen-capture.js";
+
+describe("takeScreenshot", () => {
+    it("rejects when no portal is running", async () => {
+        await expect(takeScreenshot()).rejects.toThrow();
+    });
+});
+
+describe("startScreenCast", () => {
+    it("rejects when no portal is running", async () => {
+        await expect(startScreenCast()).rejects.toThrow();
+    });
+});
@@ -0,0 +1,42 @@
+import { describe, expect, it, vi } from "vitest";
+import * as Gio from "../../src/generated/gio/index.js";
+import { forwardActivation, watchActivations } from "../../src/gio/single-instance.js";
+import { packVariant } from "../../src/glib/variant.js";
+import { events } from "../../src/lifecycle.js";
+
+const createApplication = (id: string): Gio.Application => {
+    const application = new Gio.Application(id, Gio.ApplicationFlags.NON_UNIQUE);
+    application.register(null);
+    return application;
+};
+
+describe("forwardActivation", () => {
+    it("keeps the launch in the primary instance", () => {
+        const application = createApplication("org.gtkx.Test.ForwardActivation");
+
+        expect(forwardActivation(application, { args: ["notes.txt"] })).toBe(false);
+    });
+});
+
+describe("watchActivations", () => {
+    it("emits forwarded launches as activated events", () => {
+        const application = createApplication("org.gtkx.Test.WatchActivations");
+        const handler = vi.fn();
+        events.on("activated", handler);
+        const unwatch = watchActivations(application);
+
+        application.activateAction("gtkx-activate", packVariant("(assms)", [["--new", "a.txt"], "/tmp", "token-1"]));
+        application.activateAction("gtkx-activate", packVariant("(assms)", [[], "/home", null]));
+        unwatch();
+        events.removeListener("activated", handler);
+
+        expect(handler).toHaveBeenCalledTimes(2);
+        expect(handler).toHaveBeenNthCalledWith(1, {
+            args: ["--new", "a.txt"],
+            cwd: "/tmp",
+            activationToken: "token-1",
+        });
+        expect(handler).toHaveBeenNthCalledWith(2, { args: [], cwd: "/home", activationToken: null });
+        expect(application.hasAction("gtkx-activate")).toBe(false);
+    });
+});
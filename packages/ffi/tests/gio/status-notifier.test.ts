@@ -0,0 +1,11 @@
+import { describe, expect, it } from "vitest";
+import { createTrayIcon } from "../../src/gio/status-notifier.js";
+
+describe("createTrayIcon", () => {
+    it("rejects and unexports its objects when no tray host is running", async () => {
+        const options = { id: "org.gtkx.Test", iconName: "dialog-information" };
+
+        await expect(createTrayIcon(options)).rejects.toThrow();
+        await expect(createTrayIcon(options)).rejects.toThrow();
+    });
+});
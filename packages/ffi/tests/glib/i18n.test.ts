@@ -0,0 +1,38 @@
+import { describe, expect, it } from "vitest";
+import { bindTextDomain, getTextDomain, setTextDomain, translate } from "../../src/glib/i18n.js";
+
+describe("bindTextDomain", () => {
+    it("binds a domain to a locale directory", () => {
+        expect(bindTextDomain("gtkx-test", "/tmp/gtkx-locale")).toBe("/tmp/gtkx-locale");
+    });
+
+    it("keeps the current binding when no directory is given", () => {
+        bindTextDomain("gtkx-test-query", "/tmp/gtkx-query");
+
+        expect(bindTextDomain("gtkx-test-query")).toBe("/tmp/gtkx-query");
+    });
+});
+
+describe("setTextDomain", () => {
+    it("sets the default text domain", () => {
+        const previous = getTextDomain();
+        try {
+            setTextDomain("gtkx-test");
+
+            expect(getTextDomain()).toBe("gtkx-test");
+        } finally {
+            setTextDomain(previous);
+        }
+    });
+});
+
+describe("translate", () => {
+    it("returns messages without a translation unchanged", () => {
+        expect(translate("Untranslated message")).toBe("Untranslated message");
+        expect(translate("Untranslated message", "gtkx-test")).toBe("Untranslated message");
+    });
+
+    it("returns the message without its context when there is no translation", () => {
+        expect(translate("Open", "gtkx-test", "menu")).toBe("Open");
+    });
+});
@@ -0,0 +1,39 @@
+import { mkdtempSync, rmSync, writeFileSync } from "node:fs";
+import { tmpdir } from "node:os";
+import { join } from "node:path";
+import { afterAll, describe, expect, it } from "vitest";
+import { Bytes } from "../../src/generated/glib/bytes.js";
+import { MappedFile } from "../../src/generated/glib/mapped-file.js";
+import { mapFile } from "../../src/glib/mapped-file.js";
+
+const dir = mkdtempSync(join(tmpdir(), "gtkx-mapped-"));
+
+describe("mapFile", () => {
+    afterAll(() => {
+        rmSync(dir, { recursive: true, force: true });
+    });
+
+    it("maps a file and exposes its contents", () => {
+        const path = join(dir, "contents.txt");
+        writeFileSync(path, "mapped contents");
+
+        const { file, buffer } = mapFile(path);
+
+        expect(file).toBeInstanceOf(MappedFile);
+        expect(buffer.toString()).toBe("mapped contents");
+    });
+
+    it("passes the mapping as GBytes", () => {
+        const path = join(dir, "bytes.bin");
+        writeFileSync(path, Buffer.alloc(4096, 7));
+
+        const bytes = mapFile(path).toBytes();
+
+        expect(bytes).toBeInstanceOf(Bytes);
+        expect(bytes.getSize()).toBe(4096);
+    });
+
+    it("throws for a missing file", () => {
+        expect(() => mapFile(join(dir, "missing"))).toThrow("Cannot map");
+    });
+});
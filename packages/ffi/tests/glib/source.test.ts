@@ -0,0 +1,37 @@
+import { describe, expect, it, vi } from "vitest";
+import { sourceRemove } from "../../src/generated/glib/functions.js";
+import { addIdle, addTimeout } from "../../src/glib/source.js";
+
+const wait = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+describe("addTimeout", () => {
+    it("repeats the callback while it returns true", async () => {
+        let runs = 0;
+        addTimeout(5, () => ++runs < 3);
+
+        await wait(100);
+
+        expect(runs).toBe(3);
+    });
+
+    it("does not run a removed source", async () => {
+        const callback = vi.fn();
+        const id = addTimeout(20, callback);
+
+        sourceRemove(id);
+        await wait(60);
+
+        expect(callback).not.toHaveBeenCalled();
+    });
+});
+
+describe("addIdle", () => {
+    it("runs the callback once when it returns nothing", async () => {
+        const callback = vi.fn();
+        addIdle(callback);
+
+        await wait(50);
+
+        expect(callback).toHaveBeenCalledTimes(1);
+    });
+});
@@ -0,0 +1,63 @@
+import { describe, expect, it } from "vitest";
+import { Variant } from "../../src/generated/glib/variant.js";
+import { boxVariant, packVariant, unpackVariant } from "../../src/glib/variant.js";
+
+const roundTrip = (signature: string, value: unknown) => unpackVariant(packVariant(signature, value));
+
+describe("packVariant", () => {
+    it("packs basic types", () => {
+        expect(roundTrip("b", true)).toBe(true);
+        expect(roundTrip("i", -42)).toBe(-42);
+        expect(roundTrip("u", 42)).toBe(42);
+        expect(roundTrip("d", 1.5)).toBe(1.5);
+        expect(roundTrip("s", "hello")).toBe("hello");
+        expect(roundTrip("o", "/org/example")).toBe("/org/example");
+    });
+
+    it("escapes quotes and control characters in strings", () => {
+        const text = `it's "quoted"\n\\ done`;
+        expect(roundTrip("s", text)).toBe(text);
+    });
+
+    it("packs tuples, including single-element tuples", () => {
+        expect(roundTrip("(si)", ["a", 1])).toEqual(["a", 1]);
+        expect(roundTrip("(s)", ["only"])).toEqual(["only"]);
+    });
+
+    it("packs arrays and dictionaries", () => {
+        expect(roundTrip("as", ["a", "b"])).toEqual(["a", "b"]);
+        expect(roundTrip("as", [])).toEqual([]);
+        expect(roundTrip("a{si}", { one: 1, two: 2 })).toEqual({ one: 1, two: 2 });
+        expect(roundTrip("a{si}", new Map([["one", 1]]))).toEqual({ one: 1 });
+    });
+
+    it("packs byte arrays from a Uint8Array", () => {
+        expect(roundTrip("ay", new Uint8Array([1, 2, 255]))).toEqual(new Uint8Array([1, 2, 255]));
+    });
+
+    it("packs variants from boxed values and existing variants", () => {
+        const inner = packVariant("u", 7);
+        expect(roundTrip("a{sv}", { name: boxVariant("s", "x"), count: inner })).toEqual({ name: "x", count: 7 });
+    });
+
+    it("packs maybe types", () => {
+        expect(roundTrip("ms", null)).toBeNull();
+        expect(roundTrip("ms", "set")).toBe("set");
+    });
+
+    it("returns a Variant wrapper", () => {
+        expect(packVariant("s", "x")).toBeInstanceOf(Variant);
+    });
+
+    it("rejects invalid signatures", () => {
+        expect(() => packVariant("(s", ["x"])).toThrow("Invalid GVariant type '(s'");
+    });
+
+    it("rejects tuples of the wrong length", () => {
+        expect(() => packVariant("(ss)", ["x"])).toThrow("Expected 2 items for GVariant type '(ss)', got 1");
+    });
+
+    it("requires a type for variant slots", () => {
+        expect(() => packVariant("v", "x")).toThrow("Expected a Variant or boxVariant() value for GVariant type 'v'");
+    });
+});
@@ -1,7 +1,7 @@
 import { describe, expect, it, vi } from "vitest";
 import * as Gdk from "../../src/generated/gdk/index.js";
 import * as Gtk from "../../src/generated/gtk/index.js";
-import "../../src/gobject/object.js";
+import { connectSignal, observeProperties } from "../../src/gobject/object.js";
 import "../../src/gobject/value.js";
 
 describe("Object.setProperty / getProperty auto-marshalling", () => {
@@ -131,3 +131,55 @@ describe("Object.emit() typed signal emission", () => {
         );
     });
 });
+
+describe("connectSignal", () => {
+    it("connects a handler with a priority", () => {
+        const button = new Gtk.Button();
+        const handler = vi.fn();
+
+        const handlerId = connectSignal(button, "clicked", handler, { priority: "high" });
+        button.emit("clicked");
+
+        expect(handlerId).toBeGreaterThan(0);
+        expect(handler).toHaveBeenCalledOnce();
+        button.disconnect(handlerId);
+    });
+});
+
+describe("observeProperties", () => {
+    const settle = () => new Promise((resolve) => setTimeout(resolve, 20));
+
+    it("delivers changed properties with their new values in one batch", async () => {
+        const label = new Gtk.Label("");
+        const handler = vi.fn();
+        const stop = observeProperties(label, ["label", "selectable", "visible"], handler);
+
+        label.setLabel("first");
+        label.setLabel("second");
+        label.setSelectable(true);
+        await settle();
+        stop();
+
+        expect(handler).toHaveBeenCalledOnce();
+        expect(handler).toHaveBeenCalledWith({ label: "second", selectable: true });
+    });
+
+    it("stops delivering once stopped", async () => {
+        const label = new Gtk.Label("");
+        const handler = vi.fn();
+        observeProperties(label, ["label"], handler)();
+
+        label.setLabel("changed");
+        await settle();
+
+        expect(handler).not.toHaveBeenCalled();
+    });
+
+    it("throws for unknown properties", () => {
+        const label = new Gtk.Label("");
+
+        expect(() => observeProperties(label, ["no-such-property"], () => {})).toThrow(
+            "No property 'no-such-property' on GtkLabel",
+        );
+    });
+});
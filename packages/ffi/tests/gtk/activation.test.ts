@@ -0,0 +1,56 @@
+import { describe, expect, it } from "vitest";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import {
+    activationEnvironment,
+    activationTokenFromPlatformData,
+    presentWindow,
+    requestActivationToken,
+} from "../../src/gtk/activation.js";
+
+describe("requestActivationToken", () => {
+    it("returns a token or null when activation is unsupported", () => {
+        const token = requestActivationToken({ timestamp: 0 });
+
+        expect(token === null || typeof token === "string").toBe(true);
+    });
+});
+
+describe("activationEnvironment", () => {
+    it("passes the token for both Wayland and X11", () => {
+        expect(activationEnvironment("abc")).toEqual({ XDG_ACTIVATION_TOKEN: "abc", DESKTOP_STARTUP_ID: "abc" });
+        expect(activationEnvironment(null)).toEqual({});
+    });
+});
+
+describe("activationTokenFromPlatformData", () => {
+    it("prefers the activation token over the startup id", () => {
+        expect(activationTokenFromPlatformData({ "activation-token": "a", "desktop-startup-id": "b" })).toBe("a");
+        expect(activationTokenFromPlatformData({ "desktop-startup-id": "b" })).toBe("b");
+    });
+
+    it("returns null without a usable token", () => {
+        expect(activationTokenFromPlatformData({})).toBeNull();
+        expect(activationTokenFromPlatformData({ "activation-token": "" })).toBeNull();
+        expect(activationTokenFromPlatformData({ "activation-token": 3 })).toBeNull();
+    });
+});
+
+describe("presentWindow", () => {
+    it("shows the window with an activation token", () => {
+        const window = new Gtk.Window();
+
+        presentWindow(window, { activationToken: "test-token" });
+
+        expect(window.getVisible()).toBe(true);
+        window.destroy();
+    });
+
+    it("shows the window at an event time", () => {
+        const window = new Gtk.Window();
+
+        presentWindow(window, { timestamp: 0 });
+
+        expect(window.getVisible()).toBe(true);
+        window.destroy();
+    });
+});
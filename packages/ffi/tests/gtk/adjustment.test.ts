@@ -0,0 +1,57 @@
+import { describe, expect, it, vi } from "vitest";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import { configureAdjustment, connectValueChanged } from "../../src/gtk/adjustment.js";
+
+const flush = () => new Promise((resolve) => setTimeout(resolve, 20));
+
+describe("configureAdjustment", () => {
+    it("keeps the fields it is not given", () => {
+        const adjustment = new Gtk.Adjustment(0, 0, 100, 1, 10, 10);
+
+        configureAdjustment(adjustment, { upper: 200, value: 50 });
+
+        expect(adjustment.getUpper()).toBe(200);
+        expect(adjustment.getValue()).toBe(50);
+        expect(adjustment.getPageSize()).toBe(10);
+    });
+});
+
+describe("connectValueChanged", () => {
+    it("calls the handler with the value of each change", () => {
+        const adjustment = new Gtk.Adjustment(0, 0, 100, 1, 10, 0);
+        const handler = vi.fn();
+        connectValueChanged(adjustment, handler);
+
+        adjustment.setValue(10);
+        adjustment.setValue(20);
+
+        expect(handler.mock.calls).toEqual([[10], [20]]);
+    });
+
+    it("delivers changes made in one turn once, with the latest value, when coalesced", async () => {
+        const adjustment = new Gtk.Adjustment(0, 0, 100, 1, 10, 0);
+        const handler = vi.fn();
+        connectValueChanged(adjustment, handler, { coalesce: true });
+
+        adjustment.setValue(10);
+        adjustment.setValue(20);
+        adjustment.setValue(30);
+        expect(handler).not.toHaveBeenCalled();
+        await flush();
+
+        expect(handler).toHaveBeenCalledTimes(1);
+        expect(handler).toHaveBeenCalledWith(30);
+    });
+
+    it("stops delivering coalesced changes once disconnected", async () => {
+        const adjustment = new Gtk.Adjustment(0, 0, 100, 1, 10, 0);
+        const handler = vi.fn();
+        const handlerId = connectValueChanged(adjustment, handler, { coalesce: true });
+
+        adjustment.disconnect(handlerId);
+        adjustment.setValue(40);
+        await flush();
+
+        expect(handler).not.toHaveBeenCalled();
+    });
+});
@@ -0,0 +1,40 @@
+import { describe, expect, it } from "vitest";
+import type * as Gio from "../../src/generated/gio/index.js";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import { bindFlowBoxModel, bindListBoxModel } from "../../src/gtk/bind-model.js";
+
+const createLabel = (item: Gtk.StringObject): Gtk.Widget => new Gtk.Label(item.getString());
+
+const labelOf = (child: Gtk.Widget | null): string | null => (child as Gtk.Label | null)?.getLabel() ?? null;
+
+describe("bindListBoxModel", () => {
+    it("creates a row for each item and follows model changes", () => {
+        const box = new Gtk.ListBox();
+        const list = new Gtk.StringList(["one", "two"]);
+
+        bindListBoxModel(box, list as unknown as Gio.ListModel, createLabel);
+        list.append("three");
+
+        expect(labelOf(box.getRowAtIndex(0)?.getChild() ?? null)).toBe("one");
+        expect(labelOf(box.getRowAtIndex(2)?.getChild() ?? null)).toBe("three");
+    });
+
+    it("removes the rows when the model is unbound", () => {
+        const box = new Gtk.ListBox();
+        bindListBoxModel(box, new Gtk.StringList(["one"]) as unknown as Gio.ListModel, createLabel);
+
+        bindListBoxModel(box, null, null);
+
+        expect(box.getRowAtIndex(0)).toBeNull();
+    });
+});
+
+describe("bindFlowBoxModel", () => {
+    it("creates a child for each item", () => {
+        const box = new Gtk.FlowBox();
+
+        bindFlowBoxModel(box, new Gtk.StringList(["a", "b"]) as unknown as Gio.ListModel, createLabel);
+
+        expect(labelOf(box.getChildAtIndex(1)?.getChild() ?? null)).toBe("b");
+    });
+});
@@ -0,0 +1,8 @@
+import { describe, expect, it } from "vitest";
+import { chooseColor } from "../../src/gtk/color-dialog.js";
+
+describe("chooseColor", () => {
+    it("resolves with null when aborted", async () => {
+        await expect(chooseColor(null, { initial: "#3584e4", signal: AbortSignal.abort() })).resolves.toBeNull();
+    });
+});
@@ -0,0 +1,46 @@
+import { describe, expect, it } from "vitest";
+import type * as Gio from "../../src/generated/gio/index.js";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import { setupColumnView } from "../../src/gtk/column-view.js";
+
+const createModel = (): Gio.ListModel => new Gtk.StringList(["b", "c", "a"]) as unknown as Gio.ListModel;
+
+describe("setupColumnView", () => {
+    it("appends one column per descriptor", () => {
+        const columnView = new Gtk.ColumnView(null);
+
+        const { columns } = setupColumnView(columnView, createModel(), [
+            { id: "name", title: "Name", expand: true },
+            { id: "size", title: "Size", fixedWidth: 80 },
+        ]);
+
+        expect(columns).toHaveLength(2);
+        expect(columnView.getColumns().getNItems()).toBe(2);
+        expect(columns[0]?.getId()).toBe("name");
+        expect(columns[0]?.getExpand()).toBe(true);
+        expect(columns[1]?.getFixedWidth()).toBe(80);
+    });
+
+    it("installs the sorted model behind a selection", () => {
+        const columnView = new Gtk.ColumnView(null);
+
+        const { sortModel, selection } = setupColumnView(columnView, createModel(), [{ title: "Name" }]);
+
+        expect((sortModel as unknown as Gio.ListModel).getNItems()).toBe(3);
+        expect(columnView.getModel()?.handle.id).toBe(selection.handle.id);
+    });
+
+    it("sorts rows by a column's comparator", () => {
+        const columnView = new Gtk.ColumnView(null);
+        const text = (obj: unknown): string => (obj as Gtk.StringObject).getString();
+
+        const { columns, sortModel } = setupColumnView(columnView, createModel(), [
+            { title: "Name", sort: (a, b) => text(a).localeCompare(text(b)) },
+        ]);
+        columnView.sortByColumn(Gtk.SortType.ASCENDING, columns[0] ?? null);
+
+        const model = sortModel as unknown as Gio.ListModel;
+        const values = Array.from({ length: model.getNItems() }, (_, i) => text(model.getObject(i)));
+        expect(values).toEqual(["a", "b", "c"]);
+    });
+});
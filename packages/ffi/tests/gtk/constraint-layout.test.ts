@@ -0,0 +1,52 @@
+import { describe, expect, it } from "vitest";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import { addConstraints, addVflConstraints, setConstraintLayout } from "../../src/gtk/constraint-layout.js";
+
+const createContainer = () => {
+    const container = new Gtk.Box();
+    const button = new Gtk.Button();
+    button.setParent(container);
+    return { container, button, layout: setConstraintLayout(container) };
+};
+
+describe("setConstraintLayout", () => {
+    it("installs a constraint layout on the widget", () => {
+        const { container, layout } = createContainer();
+
+        expect(container.getLayoutManager()?.handle).toEqual(layout.handle);
+    });
+});
+
+describe("addConstraints", () => {
+    it("builds constraints from descriptors", () => {
+        const { button, layout } = createContainer();
+
+        const [start, width] = addConstraints(layout, [
+            { target: button, attribute: "start", source: null, constant: 12 },
+            { target: button, attribute: "width", relation: ">=", constant: 120, strength: "strong" },
+        ]);
+
+        expect(start?.getTargetAttribute()).toBe(Gtk.ConstraintAttribute.START);
+        expect(start?.getSourceAttribute()).toBe(Gtk.ConstraintAttribute.START);
+        expect(start?.getConstant()).toBe(12);
+        expect(width?.getRelation()).toBe(Gtk.ConstraintRelation.GE);
+        expect(width?.isConstant()).toBe(true);
+        expect(width?.getStrength()).toBe(Gtk.ConstraintStrength.STRONG);
+    });
+});
+
+describe("addVflConstraints", () => {
+    it("adds constraints from visual format lines", () => {
+        const { button, layout } = createContainer();
+
+        const constraints = addVflConstraints(layout, ["H:|-[button]-|", "V:|[button]|"], { button });
+
+        expect(constraints.length).toBeGreaterThan(0);
+    });
+
+    it("throws for invalid lines", () => {
+        const { button, layout } = createContainer();
+
+        expect(() => addVflConstraints(layout, "H:|-[missing]-|", { button })).toThrow();
+    });
+});
@@ -0,0 +1,42 @@
+import { describe, expect, it } from "vitest";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import { createCursor, setCursor } from "../../src/gtk/cursor.js";
+import { lookupIconTexture } from "../../src/gtk/icon-theme.js";
+
+describe("setCursor", () => {
+    it("sets a cursor by name", () => {
+        const widget = new Gtk.Label("Link");
+
+        setCursor(widget, "pointer");
+
+        expect(widget.getCursor()?.getName()).toBe("pointer");
+    });
+
+    it("sets a cursor from an image with a hotspot", () => {
+        const widget = new Gtk.Label("Canvas");
+
+        setCursor(widget, lookupIconTexture("edit-select-symbolic", { size: 24 }), { x: 2, y: 21 });
+
+        const cursor = widget.getCursor();
+        expect(cursor?.getTexture()).not.toBeNull();
+        expect(cursor?.getHotspotX()).toBe(2);
+        expect(cursor?.getHotspotY()).toBe(21);
+    });
+
+    it("reuses a cursor and clears it with null", () => {
+        const widget = new Gtk.Label("Text");
+        const cursor = createCursor("text");
+
+        setCursor(widget, cursor);
+        expect(widget.getCursor()?.getName()).toBe("text");
+
+        setCursor(widget, null);
+        expect(widget.getCursor()).toBeNull();
+    });
+});
+
+describe("createCursor", () => {
+    it("falls back to the default cursor", () => {
+        expect(createCursor("pointer").getFallback()?.getName()).toBe("default");
+    });
+});
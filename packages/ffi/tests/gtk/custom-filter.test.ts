@@ -0,0 +1,49 @@
+import { describe, expect, it } from "vitest";
+import type * as Gio from "../../src/generated/gio/index.js";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import { createCustomFilter, setCustomFilterFunc } from "../../src/gtk/custom-filter.js";
+
+const valuesOf = (model: Gtk.FilterListModel): string[] => {
+    const list = model as unknown as Gio.ListModel;
+    return Array.from({ length: list.getNItems() }, (_, i) => (list.getObject(i) as Gtk.StringObject).getString());
+};
+
+const createModel = (filter: Gtk.Filter): Gtk.FilterListModel =>
+    new Gtk.FilterListModel(new Gtk.StringList(["apple", "", "avocado", "banana"]) as unknown as Gio.ListModel, filter);
+
+describe("createCustomFilter", () => {
+    it("filters a model with a JS predicate", () => {
+        const filter = createCustomFilter((item: Gtk.StringObject) => item.getString().startsWith("a"));
+
+        expect(filter).toBeInstanceOf(Gtk.CustomFilter);
+        expect(valuesOf(createModel(filter))).toEqual(["apple", "avocado"]);
+    });
+
+    it("keeps items by the truthiness of the predicate result", () => {
+        const filter = createCustomFilter((item: Gtk.StringObject) => item.getString());
+
+        expect(valuesOf(createModel(filter))).toEqual(["apple", "avocado", "banana"]);
+    });
+});
+
+describe("setCustomFilterFunc", () => {
+    it("filters models with the new predicate once the filter changes", () => {
+        const filter = createCustomFilter((item: Gtk.StringObject) => item.getString().startsWith("a"));
+        const model = createModel(filter);
+
+        setCustomFilterFunc(filter, (item: Gtk.StringObject) => item.getString().startsWith("b"));
+        filter.changed(Gtk.FilterChange.DIFFERENT);
+
+        expect(valuesOf(model)).toEqual(["banana"]);
+    });
+
+    it("keeps every item without a predicate", () => {
+        const filter = createCustomFilter((item: Gtk.StringObject) => item.getString().startsWith("a"));
+        const model = createModel(filter);
+
+        setCustomFilterFunc(filter, null);
+        filter.changed(Gtk.FilterChange.LESS_STRICT);
+
+        expect(valuesOf(model)).toHaveLength(4);
+    });
+});
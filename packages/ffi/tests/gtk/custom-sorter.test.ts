@@ -0,0 +1,32 @@
+import { describe, expect, it } from "vitest";
+import type * as Gio from "../../src/generated/gio/index.js";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import { createCustomSorter, setCustomSorterFunc } from "../../src/gtk/custom-sorter.js";
+
+const byString = (a: Gtk.StringObject, b: Gtk.StringObject): number => a.getString().localeCompare(b.getString());
+
+const valuesOf = (model: Gtk.SortListModel): string[] => {
+    const list = model as unknown as Gio.ListModel;
+    return Array.from({ length: list.getNItems() }, (_, i) => (list.getObject(i) as Gtk.StringObject).getString());
+};
+
+describe("createCustomSorter", () => {
+    it("sorts a model with a JS comparator", () => {
+        const sorter = createCustomSorter(byString);
+        const model = new Gtk.SortListModel(new Gtk.StringList(["b", "c", "a"]) as unknown as Gio.ListModel, sorter);
+
+        expect(sorter).toBeInstanceOf(Gtk.CustomSorter);
+        expect(valuesOf(model)).toEqual(["a", "b", "c"]);
+    });
+});
+
+describe("setCustomSorterFunc", () => {
+    it("re-sorts models with the new comparator", () => {
+        const sorter = createCustomSorter(byString);
+        const model = new Gtk.SortListModel(new Gtk.StringList(["b", "c", "a"]) as unknown as Gio.ListModel, sorter);
+
+        setCustomSorterFunc(sorter, (a: Gtk.StringObject, b: Gtk.StringObject) => byString(b, a));
+
+        expect(valuesOf(model)).toEqual(["c", "b", "a"]);
+    });
+});
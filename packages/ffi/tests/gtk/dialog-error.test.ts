@@ -0,0 +1,9 @@
+import { describe, expect, it } from "vitest";
+import { isDialogDismissed } from "../../src/gtk/dialog-error.js";
+
+describe("isDialogDismissed", () => {
+    it("is false for errors not raised by GLib", () => {
+        expect(isDialogDismissed(new Error("failed"))).toBe(false);
+        expect(isDialogDismissed(undefined)).toBe(false);
+    });
+});
@@ -0,0 +1,19 @@
+import { describe, expect, it } from "vitest";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import { chooseEmoji } from "../../src/gtk/emoji-chooser.js";
+
+describe("chooseEmoji", () => {
+    it("resolves with null when closed without picking", async () => {
+        const window = new Gtk.Window();
+        const button = new Gtk.Button();
+        window.setChild(button);
+        window.present();
+        const controller = new AbortController();
+
+        const emoji = chooseEmoji(button, { signal: controller.signal });
+        controller.abort();
+
+        await expect(emoji).resolves.toBeNull();
+        window.destroy();
+    });
+});
@@ -0,0 +1,47 @@
+import { describe, expect, it, vi } from "vitest";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import { Type } from "../../src/gobject/types.js";
+import { appendListStoreRow, getTreeModelValue } from "../../src/gtk/tree-model.js";
+import "../../src/gobject/value.js";
+
+const createCompletion = (): { entry: Gtk.Entry; completion: Gtk.EntryCompletion; store: Gtk.ListStore } => {
+    const store = new Gtk.ListStore([Type.STRING]);
+    for (const fruit of ["apple", "apricot", "banana"]) appendListStoreRow(store, [fruit]);
+
+    const completion = new Gtk.EntryCompletion();
+    completion.setModel(store);
+    completion.setTextColumn(0);
+    const entry = new Gtk.Entry();
+    entry.setCompletion(completion);
+    return { entry, completion, store };
+};
+
+describe("EntryCompletion.setMatchFunc", () => {
+    it("offers only the rows the match function accepts", () => {
+        const { entry, completion, store } = createCompletion();
+        const keys = new Set<string>();
+        completion.setMatchFunc((_completion, key, iter) => {
+            keys.add(key);
+            const text = getTreeModelValue(store, iter, 0) as string;
+            return text.startsWith(key) && text.endsWith("cot");
+        });
+
+        entry.setText("AP");
+        completion.complete();
+
+        expect(keys).toEqual(new Set(["ap"]));
+        expect(completion.computePrefix("ap")).toBe("apricot");
+    });
+
+    it("offers no rows when the match function returns nothing", () => {
+        const { entry, completion } = createCompletion();
+        const match = vi.fn();
+        completion.setMatchFunc(match);
+
+        entry.setText("a");
+        completion.complete();
+
+        expect(match).toHaveBeenCalled();
+        expect(completion.computePrefix("a")).toBeNull();
+    });
+});
@@ -0,0 +1,24 @@
+import { describe, expect, it } from "vitest";
+import { createFileFilter, openFile } from "../../src/gtk/file-dialog.js";
+
+describe("createFileFilter", () => {
+    it("builds a named filter", () => {
+        const filter = createFileFilter({ name: "Images", mimeTypes: ["image/*"], suffixes: ["png"] });
+
+        expect(filter.getName()).toBe("Images");
+    });
+});
+
+describe("openFile", () => {
+    it("resolves with null when aborted", async () => {
+        const controller = new AbortController();
+        controller.abort();
+
+        const file = await openFile(null, {
+            filters: [{ name: "Text", patterns: ["*.txt"] }],
+            signal: controller.signal,
+        });
+
+        expect(file).toBeNull();
+    });
+});
@@ -0,0 +1,23 @@
+import { describe, expect, it } from "vitest";
+import { Style, Weight } from "../../src/generated/pango/enums.js";
+import { FontDescription } from "../../src/generated/pango/font-description.js";
+import { chooseFont, describeFont } from "../../src/gtk/font-dialog.js";
+
+describe("describeFont", () => {
+    it("decodes a font description", () => {
+        const font = describeFont(FontDescription.fromString("Sans Bold Italic 12"));
+
+        expect(font.family).toBe("Sans");
+        expect(font.size).toBe(12);
+        expect(font.sizeIsAbsolute).toBe(false);
+        expect(font.weight).toBe(Weight.BOLD);
+        expect(font.style).toBe(Style.ITALIC);
+        expect(font.description).toBe("Sans Bold Italic 12");
+    });
+});
+
+describe("chooseFont", () => {
+    it("resolves with null when aborted", async () => {
+        await expect(chooseFont(null, { initial: "Sans 12", signal: AbortSignal.abort() })).resolves.toBeNull();
+    });
+});
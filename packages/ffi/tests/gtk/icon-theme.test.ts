@@ -0,0 +1,25 @@
+import { describe, expect, it } from "vitest";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import { lookupIcon, lookupIconTexture } from "../../src/gtk/icon-theme.js";
+
+describe("lookupIcon", () => {
+    it("returns a paintable of the requested size", () => {
+        const icon = lookupIcon("document-save-symbolic", { size: 32 });
+
+        expect(icon).toBeInstanceOf(Gtk.IconPaintable);
+        expect(icon.getIntrinsicWidth()).toBe(32);
+    });
+
+    it("resolves a missing icon instead of failing", () => {
+        expect(lookupIcon("gtkx-no-such-icon")).toBeInstanceOf(Gtk.IconPaintable);
+    });
+});
+
+describe("lookupIconTexture", () => {
+    it("renders the icon at its size times the scale", () => {
+        const texture = lookupIconTexture("document-save-symbolic", { size: 16, scale: 2 });
+
+        expect(texture.getWidth()).toBe(32);
+        expect(texture.getHeight()).toBe(32);
+    });
+});
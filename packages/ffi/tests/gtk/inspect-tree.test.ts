@@ -0,0 +1,37 @@
+import { describe, expect, it } from "vitest";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import { inspectTree } from "../../src/gtk/inspect-tree.js";
+
+describe("inspectTree", () => {
+    it("captures widget types, properties and children", () => {
+        const box = new Gtk.Box(Gtk.Orientation.VERTICAL, 0);
+        const button = new Gtk.Button();
+        button.setName("save");
+        button.addCssClass("suggested-action");
+        button.setSensitive(false);
+        box.append(button);
+        box.append(new Gtk.Label("Status"));
+
+        const tree = inspectTree(box);
+
+        expect(tree.type).toBe("GtkBox");
+        expect(tree.name).toBeNull();
+        expect(tree.children.map((child) => child.type)).toEqual(["GtkButton", "GtkLabel"]);
+        expect(tree.children[0]).toMatchObject({
+            name: "save",
+            cssName: "button",
+            cssClasses: expect.arrayContaining(["suggested-action"]),
+            sensitive: false,
+            visible: true,
+        });
+    });
+
+    it("produces JSON-serializable output", () => {
+        const box = new Gtk.Box(Gtk.Orientation.HORIZONTAL, 0);
+        box.append(new Gtk.Label("Child"));
+
+        const tree = inspectTree(box);
+
+        expect(JSON.parse(JSON.stringify(tree))).toEqual(tree);
+    });
+});
@@ -0,0 +1,43 @@
+import { afterEach, describe, expect, it } from "vitest";
+import {
+    createInspectorAction,
+    isInspectorVisible,
+    setInspectorVisible,
+    toggleInspector,
+} from "../../src/gtk/inspector.js";
+
+afterEach(() => {
+    setInspectorVisible(false);
+});
+
+describe("setInspectorVisible", () => {
+    it("shows and hides the inspector", () => {
+        setInspectorVisible(true);
+        expect(isInspectorVisible()).toBe(true);
+
+        setInspectorVisible(false);
+        expect(isInspectorVisible()).toBe(false);
+    });
+});
+
+describe("toggleInspector", () => {
+    it("flips the inspector visibility", () => {
+        expect(toggleInspector()).toBe(true);
+        expect(isInspectorVisible()).toBe(true);
+
+        expect(toggleInspector()).toBe(false);
+        expect(isInspectorVisible()).toBe(false);
+    });
+});
+
+describe("createInspectorAction", () => {
+    it("toggles the inspector and tracks its state", () => {
+        const action = createInspectorAction();
+
+        action.activate(null);
+
+        expect(action.getName()).toBe("inspector");
+        expect(isInspectorVisible()).toBe(true);
+        expect(action.getState()?.getBoolean()).toBe(true);
+    });
+});
@@ -0,0 +1,8 @@
+import { describe, expect, it } from "vitest";
+import { launchUri } from "../../src/gtk/launcher.js";
+
+describe("launchUri", () => {
+    it("resolves with false when aborted", async () => {
+        await expect(launchUri("https://gtkx.dev", { signal: AbortSignal.abort() })).resolves.toBe(false);
+    });
+});
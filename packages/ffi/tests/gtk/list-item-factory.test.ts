@@ -0,0 +1,23 @@
+import { describe, expect, it } from "vitest";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import { connectListItemFactory } from "../../src/gtk/list-item-factory.js";
+
+describe("connectListItemFactory", () => {
+    it("connects only the handlers provided", () => {
+        const factory = new Gtk.SignalListItemFactory();
+
+        const ids = connectListItemFactory(factory, { setup: () => {}, bind: () => {} });
+
+        expect(ids).toHaveLength(2);
+        expect(ids.every((id) => id > 0)).toBe(true);
+    });
+
+    it("returns handler IDs that can be disconnected", () => {
+        const factory = new Gtk.SignalListItemFactory();
+
+        const [id] = connectListItemFactory(factory, { teardown: () => {} });
+
+        expect(id).toBeDefined();
+        expect(() => factory.disconnect(id as number)).not.toThrow();
+    });
+});
@@ -0,0 +1,33 @@
+import { afterEach, describe, expect, it, vi } from "vitest";
+import { TextDirection } from "../../src/generated/gtk/enums.js";
+import { localeInfo } from "../../src/gtk/locale.js";
+
+describe("localeInfo", () => {
+    afterEach(() => {
+        vi.unstubAllEnvs();
+    });
+
+    it("reports the default text direction", () => {
+        const info = localeInfo();
+
+        expect([TextDirection.LTR, TextDirection.RTL]).toContain(info.textDirection);
+        expect(info.rtl).toBe(info.textDirection === TextDirection.RTL);
+        expect(info.languageNames).toContain("C");
+    });
+
+    it("derives separators from the numeric locale", () => {
+        vi.stubEnv("LC_ALL", "de_DE.UTF-8");
+
+        const info = localeInfo();
+
+        expect(info.numericLocale).toBe("de-DE");
+        expect(info.decimalSeparator).toBe(",");
+        expect(info.groupingSeparator).toBe(".");
+    });
+
+    it("treats the C locale as US English", () => {
+        vi.stubEnv("LC_ALL", "C");
+
+        expect(localeInfo().decimalSeparator).toBe(".");
+    });
+});
@@ -0,0 +1,48 @@
+import { describe, expect, it } from "vitest";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import { computeBounds, computePoint, measureWidget } from "../../src/gtk/measure.js";
+
+describe("measureWidget", () => {
+    it("returns the size request as an object", () => {
+        const box = new Gtk.Box(Gtk.Orientation.HORIZONTAL, 0);
+        box.setSizeRequest(120, 40);
+
+        const measurement = measureWidget(box, Gtk.Orientation.HORIZONTAL);
+
+        expect(measurement.minimum).toBe(120);
+        expect(measurement.natural).toBeGreaterThanOrEqual(120);
+        expect(measurement.minimumBaseline).toBe(-1);
+        expect(measurement.naturalBaseline).toBe(-1);
+    });
+});
+
+describe("computeBounds / computePoint", () => {
+    const layout = () => {
+        const fixed = new Gtk.Fixed();
+        const child = new Gtk.Box(Gtk.Orientation.HORIZONTAL, 0);
+        child.setSizeRequest(30, 20);
+        fixed.put(child, 10, 15);
+        fixed.measure(Gtk.Orientation.HORIZONTAL, -1);
+        fixed.measure(Gtk.Orientation.VERTICAL, -1);
+        fixed.allocate(100, 100, -1, null);
+        return { fixed, child };
+    };
+
+    it("computes bounds relative to an ancestor", () => {
+        const { fixed, child } = layout();
+
+        expect(computeBounds(child, fixed)).toEqual({ x: 10, y: 15, width: 30, height: 20 });
+    });
+
+    it("translates points between widgets", () => {
+        const { fixed, child } = layout();
+
+        expect(computePoint(child, fixed, 5, 5)).toEqual({ x: 15, y: 20 });
+    });
+
+    it("returns null for unrelated widgets", () => {
+        const { child } = layout();
+
+        expect(computePoint(child, new Gtk.Box(Gtk.Orientation.HORIZONTAL, 0), 0, 0)).toBeNull();
+    });
+});
@@ -0,0 +1,40 @@
+import { describe, expect, it, vi } from "vitest";
+import { createMediaFile, getMediaProgress, watchMediaProgress } from "../../src/gtk/media.js";
+
+describe("createMediaFile", () => {
+    it("applies playback settings", () => {
+        const media = createMediaFile("/nonexistent/video.webm", { loop: true, muted: true, volume: 0.5 });
+
+        expect(media.getLoop()).toBe(true);
+        expect(media.getMuted()).toBe(true);
+        expect(media.getVolume()).toBeCloseTo(0.5);
+    });
+});
+
+describe("watchMediaProgress", () => {
+    it("coalesces changes into one update per interval", async () => {
+        const media = createMediaFile("/nonexistent/video.webm");
+        const handler = vi.fn();
+        const stop = watchMediaProgress(media, handler, { interval: 20 });
+
+        media.notify("timestamp");
+        media.notify("duration");
+        media.notify("playing");
+        await new Promise((resolve) => setTimeout(resolve, 50));
+        stop();
+
+        expect(handler).toHaveBeenCalledTimes(1);
+        expect(handler).toHaveBeenCalledWith(getMediaProgress(media));
+    });
+
+    it("stops updating once stopped", async () => {
+        const media = createMediaFile("/nonexistent/video.webm");
+        const handler = vi.fn();
+        watchMediaProgress(media, handler, { interval: 0 })();
+
+        media.notify("timestamp");
+        await new Promise((resolve) => setTimeout(resolve, 10));
+
+        expect(handler).not.toHaveBeenCalled();
+    });
+});
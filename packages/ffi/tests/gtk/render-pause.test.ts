@@ -0,0 +1,52 @@
+import { describe, expect, it } from "vitest";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import {
+    isRenderingPaused,
+    pauseRendering,
+    pauseRenderingWhenInactive,
+    resumeRendering,
+} from "../../src/gtk/render-pause.js";
+
+const createRealizedWindow = (): Gtk.Window => {
+    const window = new Gtk.Window();
+    window.realize();
+    return window;
+};
+
+describe("pauseRendering", () => {
+    it("pauses and resumes a realized window", () => {
+        const window = createRealizedWindow();
+
+        expect(pauseRendering(window)).toBe(true);
+        expect(pauseRendering(window)).toBe(true);
+        expect(isRenderingPaused(window)).toBe(true);
+
+        resumeRendering(window);
+
+        expect(isRenderingPaused(window)).toBe(false);
+        window.destroy();
+    });
+
+    it("returns false for a window that is not realized", () => {
+        const window = new Gtk.Window();
+
+        expect(pauseRendering(window)).toBe(false);
+        expect(isRenderingPaused(window)).toBe(false);
+        window.destroy();
+    });
+});
+
+describe("pauseRenderingWhenInactive", () => {
+    it("pauses an inactive window until throttling stops", () => {
+        const window = createRealizedWindow();
+
+        const stop = pauseRenderingWhenInactive(window);
+
+        expect(isRenderingPaused(window)).toBe(!window.isActive());
+
+        stop();
+
+        expect(isRenderingPaused(window)).toBe(false);
+        window.destroy();
+    });
+});
@@ -0,0 +1,114 @@
+import { describe, expect, it, vi } from "vitest";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import { getScrollState, setScrollState, watchScrollEdges } from "../../src/gtk/scrolled-window.js";
+
+const createScrolledWindow = (): Gtk.ScrolledWindow => {
+    const scrolledWindow = new Gtk.ScrolledWindow();
+    scrolledWindow.setHadjustment(new Gtk.Adjustment(0, 0, 1000, 10, 100, 100));
+    scrolledWindow.setVadjustment(new Gtk.Adjustment(0, 0, 1000, 10, 100, 100));
+    return scrolledWindow;
+};
+
+const settle = () => new Promise((resolve) => setTimeout(resolve, 20));
+
+describe("getScrollState / setScrollState", () => {
+    it("restores both axes", () => {
+        const scrolledWindow = createScrolledWindow();
+
+        setScrollState(scrolledWindow, { x: 120, y: 340 });
+
+        expect(getScrollState(scrolledWindow)).toEqual({ x: 120, y: 340 });
+    });
+
+    it("leaves omitted axes unchanged", () => {
+        const scrolledWindow = createScrolledWindow();
+        setScrollState(scrolledWindow, { x: 50, y: 60 });
+
+        setScrollState(scrolledWindow, { y: 200 });
+
+        expect(getScrollState(scrolledWindow)).toEqual({ x: 50, y: 200 });
+    });
+
+    it("keeps kinetic scrolling enabled", () => {
+        const scrolledWindow = createScrolledWindow();
+        scrolledWindow.setKineticScrolling(true);
+
+        setScrollState(scrolledWindow, { y: 100 });
+
+        expect(scrolledWindow.getKineticScrolling()).toBe(true);
+    });
+});
+
+describe("watchScrollEdges", () => {
+    it("reports an edge once until it is left", async () => {
+        const scrolledWindow = createScrolledWindow();
+        setScrollState(scrolledWindow, { y: 400 });
+        const handler = vi.fn();
+        const stop = watchScrollEdges(scrolledWindow, handler);
+
+        setScrollState(scrolledWindow, { y: 900 });
+        await settle();
+        setScrollState(scrolledWindow, { y: 899 });
+        setScrollState(scrolledWindow, { y: 900 });
+        await settle();
+        stop();
+
+        expect(handler).toHaveBeenCalledTimes(1);
+        expect(handler).toHaveBeenCalledWith("bottom");
+    });
+
+    it("reports edges within the threshold", async () => {
+        const scrolledWindow = createScrolledWindow();
+        setScrollState(scrolledWindow, { y: 400 });
+        const handler = vi.fn();
+        const stop = watchScrollEdges(scrolledWindow, handler, { threshold: 200 });
+
+        setScrollState(scrolledWindow, { y: 150 });
+        await settle();
+        stop();
+
+        expect(handler).toHaveBeenCalledWith("top");
+    });
+
+    it("rechecks an edge after loading content that is still too short", async () => {
+        const scrolledWindow = createScrolledWindow();
+        const vadjustment = new Gtk.Adjustment(0, 0, 50, 10, 100, 100);
+        scrolledWindow.setVadjustment(vadjustment);
+        const handler = vi.fn(async (edge: string) => {
+            if (edge === "bottom") vadjustment.setUpper(vadjustment.getUpper() + 30);
+        });
+        const stop = watchScrollEdges(scrolledWindow, handler);
+
+        vadjustment.setUpper(60);
+        await settle();
+        stop();
+
+        expect(handler.mock.calls.filter(([edge]) => edge === "bottom")).toHaveLength(2);
+        expect(vadjustment.getUpper()).toBe(120);
+    });
+
+    it("does not repeat an edge when loading added nothing", async () => {
+        const scrolledWindow = createScrolledWindow();
+        setScrollState(scrolledWindow, { x: 400 });
+        const handler = vi.fn(() => Promise.resolve());
+        const stop = watchScrollEdges(scrolledWindow, handler);
+
+        setScrollState(scrolledWindow, { x: 900 });
+        await settle();
+        stop();
+
+        expect(handler).toHaveBeenCalledTimes(1);
+    });
+
+    it("stops reporting once stopped", async () => {
+        const scrolledWindow = createScrolledWindow();
+        setScrollState(scrolledWindow, { y: 400 });
+        const handler = vi.fn();
+        watchScrollEdges(scrolledWindow, handler)();
+
+        setScrollState(scrolledWindow, { y: 900 });
+        await settle();
+
+        expect(handler).not.toHaveBeenCalled();
+    });
+});
@@ -0,0 +1,19 @@
+import { describe, expect, it, vi } from "vitest";
+import * as Gio from "../../src/generated/gio/index.js";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import { watchSessionEvents } from "../../src/gtk/session-events.js";
+
+describe("watchSessionEvents", () => {
+    it("reports session end from the application's query-end signal", () => {
+        const application = new Gtk.Application(Gio.ApplicationFlags.NON_UNIQUE, "org.gtkx.Test.SessionEvents");
+        const handler = vi.fn();
+        const stop = watchSessionEvents(handler, { application });
+
+        application.emit("query-end");
+        stop();
+        application.emit("query-end");
+
+        expect(handler).toHaveBeenCalledTimes(1);
+        expect(handler).toHaveBeenCalledWith("session-end");
+    });
+});
@@ -0,0 +1,76 @@
+import { describe, expect, it, vi } from "vitest";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import {
+    connectVisibleChildChanged,
+    popStackPage,
+    pushStackPage,
+    replaceStackPage,
+    showStackPage,
+} from "../../src/gtk/stack.js";
+
+const createStack = (): Gtk.Stack => {
+    const stack = new Gtk.Stack();
+    pushStackPage(stack, new Gtk.Label("Home"), "home", { title: "Home" });
+    return stack;
+};
+
+describe("GtkStack page management", () => {
+    it("pushes a named page and shows it", () => {
+        const stack = createStack();
+
+        pushStackPage(stack, new Gtk.Label("Details"), "details");
+
+        expect(stack.getVisibleChildName()).toBe("details");
+    });
+
+    it("restores the stack's transition settings after a change", () => {
+        const stack = createStack();
+        stack.setTransitionDuration(200);
+        pushStackPage(stack, new Gtk.Label("Details"), "details");
+
+        showStackPage(stack, "home", { type: Gtk.StackTransitionType.CROSSFADE, duration: 50 });
+
+        expect(stack.getVisibleChildName()).toBe("home");
+        expect(stack.getTransitionDuration()).toBe(200);
+        expect(stack.getTransitionType()).toBe(Gtk.StackTransitionType.NONE);
+    });
+
+    it("throws when showing an unknown page", () => {
+        const stack = createStack();
+        expect(() => showStackPage(stack, "missing")).toThrow("Stack has no child named 'missing'");
+    });
+
+    it("replaces a page under the same name and title", () => {
+        const stack = createStack();
+        const replacement = new Gtk.Label("New home");
+
+        const page = replaceStackPage(stack, "home", replacement);
+
+        expect(page.getName()).toBe("home");
+        expect(page.getTitle()).toBe("Home");
+        expect(stack.getChildByName("home")?.handle.id).toBe(replacement.handle.id);
+    });
+
+    it("pops a visible page back to a fallback", () => {
+        const stack = createStack();
+        pushStackPage(stack, new Gtk.Label("Details"), "details");
+
+        expect(popStackPage(stack, "details", "home")).toBe(true);
+        expect(stack.getVisibleChildName()).toBe("home");
+        expect(stack.getChildByName("details")).toBeNull();
+        expect(popStackPage(stack, "details")).toBe(false);
+    });
+});
+
+describe("connectVisibleChildChanged", () => {
+    it("reports the new and previous page names", () => {
+        const stack = createStack();
+        const handler = vi.fn();
+        connectVisibleChildChanged(stack, handler);
+
+        pushStackPage(stack, new Gtk.Label("Details"), "details");
+
+        expect(handler).toHaveBeenCalledTimes(1);
+        expect(handler.mock.calls[0]?.[0]).toMatchObject({ name: "details", previousName: "home" });
+    });
+});
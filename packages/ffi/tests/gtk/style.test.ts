@@ -0,0 +1,42 @@
+import { describe, expect, it } from "vitest";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import { getComputedStyle, updateCssClasses } from "../../src/gtk/style.js";
+
+describe("updateCssClasses", () => {
+    it("adds and removes classes in one update", () => {
+        const label = new Gtk.Label("Status");
+        label.setCssClasses(["warning", "dim-label"]);
+
+        updateCssClasses(label, ["error", "heading"], ["warning"]);
+
+        expect(label.getCssClasses()).toEqual(["dim-label", "error", "heading"]);
+    });
+
+    it("removes classes listed in both lists", () => {
+        const label = new Gtk.Label("Status");
+
+        updateCssClasses(label, ["error"], ["error"]);
+
+        expect(label.hasCssClass("error")).toBe(false);
+    });
+});
+
+describe("getComputedStyle", () => {
+    it("reads the foreground color and font", () => {
+        const label = new Gtk.Label("Text");
+
+        const style = getComputedStyle(label);
+
+        expect(style.color.alpha).toBeGreaterThan(0);
+        expect(style.font.size).toBeGreaterThan(0);
+        expect(style.colors).toEqual({});
+    });
+
+    it("returns null for undefined named colors", () => {
+        const label = new Gtk.Label("Text");
+
+        const style = getComputedStyle(label, { colors: ["no_such_color"] });
+
+        expect(style.colors).toEqual({ no_such_color: null });
+    });
+});
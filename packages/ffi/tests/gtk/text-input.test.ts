@@ -0,0 +1,43 @@
+import { describe, expect, it, vi } from "vitest";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import { configureTextInput } from "../../src/gtk/text-input.js";
+
+describe("configureTextInput", () => {
+    it("combines input hints from options", () => {
+        const view = new Gtk.TextView();
+
+        configureTextInput(view, {
+            purpose: Gtk.InputPurpose.EMAIL,
+            hints: Gtk.InputHints.LOWERCASE,
+            spellCheck: false,
+            wordCompletion: true,
+        });
+
+        expect(view.getInputPurpose()).toBe(Gtk.InputPurpose.EMAIL);
+        expect(view.getInputHints()).toBe(
+            Gtk.InputHints.LOWERCASE | Gtk.InputHints.NO_SPELLCHECK | Gtk.InputHints.WORD_COMPLETION,
+        );
+    });
+
+    it("enables emoji completion on text widgets", () => {
+        const text = new Gtk.Text();
+
+        configureTextInput(text, { emoji: true });
+
+        expect(text.getEnableEmojiCompletion()).toBe(true);
+        expect(text.getInputHints() & Gtk.InputHints.EMOJI).toBe(Gtk.InputHints.EMOJI);
+    });
+
+    it("reports preedit changes until disconnected", () => {
+        const view = new Gtk.TextView();
+        const onPreeditChanged = vi.fn();
+
+        const disconnect = configureTextInput(view, { onPreeditChanged });
+        view.emit("preedit-changed", "ni");
+        disconnect();
+        view.emit("preedit-changed", "nih");
+
+        expect(onPreeditChanged).toHaveBeenCalledTimes(1);
+        expect(onPreeditChanged).toHaveBeenCalledWith("ni");
+    });
+});
@@ -0,0 +1,37 @@
+import { describe, expect, it, vi } from "vitest";
+import * as Gtk from "../../src/generated/gtk/index.js";
+
+const wait = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+describe("Widget.addTickCallback", () => {
+    it("runs the callback once when it returns nothing", async () => {
+        const window = new Gtk.Window();
+        window.present();
+        const callback = vi.fn();
+
+        window.addTickCallback(callback);
+        await vi.waitFor(() => expect(callback).toHaveBeenCalled());
+        await wait(100);
+
+        expect(callback).toHaveBeenCalledTimes(1);
+        window.destroy();
+    });
+
+    it("keeps calling the callback while it returns true", async () => {
+        const window = new Gtk.Window();
+        window.present();
+        let ticks = 0;
+
+        const id = window.addTickCallback(() => {
+            ticks++;
+            return true;
+        });
+        await vi.waitFor(() => expect(ticks).toBeGreaterThan(2));
+        window.removeTickCallback(id);
+        const stopped = ticks;
+        await wait(100);
+
+        expect(ticks).toBe(stopped);
+        window.destroy();
+    });
+});
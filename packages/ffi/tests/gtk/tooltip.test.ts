@@ -0,0 +1,22 @@
+import { describe, expect, it } from "vitest";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import { setTooltipContent } from "../../src/gtk/tooltip.js";
+
+describe("setTooltipContent", () => {
+    it("enables tooltips on the widget", () => {
+        const label = new Gtk.Label("Hover me");
+
+        setTooltipContent(label, () => "Details");
+
+        expect(label.getHasTooltip()).toBe(true);
+    });
+
+    it("restores has-tooltip when removed", () => {
+        const label = new Gtk.Label("Hover me");
+
+        const remove = setTooltipContent(label, () => null);
+        remove();
+
+        expect(label.getHasTooltip()).toBe(false);
+    });
+});
@@ -0,0 +1,73 @@
+import { describe, expect, it } from "vitest";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import { Type } from "../../src/gobject/types.js";
+import {
+    appendListStoreRow,
+    forEachTreeModelRow,
+    getTreeModelRow,
+    getTreeModelValue,
+    setListStoreValue,
+    treeModelRows,
+} from "../../src/gtk/tree-model.js";
+import "../../src/gobject/value.js";
+
+const createStore = (): Gtk.ListStore => {
+    const store = new Gtk.ListStore([Type.STRING, Type.INT]);
+    appendListStoreRow(store, ["first", 1]);
+    appendListStoreRow(store, ["second", 2]);
+    appendListStoreRow(store, ["third", 3]);
+    return store;
+};
+
+describe("GtkListStore value helpers", () => {
+    it("round-trips cell values", () => {
+        const store = new Gtk.ListStore([Type.STRING, Type.INT]);
+        const iter = appendListStoreRow(store, []);
+
+        setListStoreValue(store, iter, 0, "hello");
+        setListStoreValue(store, iter, 1, 42);
+
+        expect(getTreeModelValue(store, iter, 0)).toBe("hello");
+        expect(getTreeModelValue(store, iter, 1)).toBe(42);
+    });
+
+    it("reads whole rows", () => {
+        const store = createStore();
+        const [first] = treeModelRows(store);
+
+        expect(first).toBeDefined();
+        expect(getTreeModelRow(store, first as Gtk.TreeIter)).toEqual(["first", 1]);
+    });
+});
+
+describe("tree model iteration", () => {
+    it("yields independent iterators for each top-level row", () => {
+        const store = createStore();
+        const iters = [...treeModelRows(store)];
+
+        expect(iters.map((iter) => getTreeModelValue(store, iter, 0))).toEqual(["first", "second", "third"]);
+    });
+
+    it("keeps foreach iterators valid after the walk", () => {
+        const store = createStore();
+        const iters: Gtk.TreeIter[] = [];
+
+        forEachTreeModelRow(store, (_path, iter) => {
+            iters.push(iter);
+        });
+
+        expect(iters.map((iter) => getTreeModelValue(store, iter, 1))).toEqual([1, 2, 3]);
+    });
+
+    it("stops when the callback returns true", () => {
+        const store = createStore();
+        let visited = 0;
+
+        forEachTreeModelRow(store, () => {
+            visited++;
+            return visited === 2;
+        });
+
+        expect(visited).toBe(2);
+    });
+});
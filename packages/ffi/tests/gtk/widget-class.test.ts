@@ -0,0 +1,107 @@
+import { describe, expect, it, vi } from "vitest";
+import * as GLib from "../../src/generated/glib/index.js";
+import { typeFromName } from "../../src/generated/gobject/functions.js";
+import * as Gtk from "../../src/generated/gtk/index.js";
+import { addWidgetBindingAction, installWidgetAction } from "../../src/gtk/widget-class.js";
+import { alloc, call, read, t } from "../../src/native.js";
+import { getNativeObject, registerNativeClass } from "../../src/registry.js";
+
+const LIB_GOBJECT = "libgobject-2.0.so.0";
+
+const registerSubclass = (name: string, parentTypeName: string): number => {
+    const query = alloc(24, "GTypeQuery", LIB_GOBJECT);
+    call(
+        LIB_GOBJECT,
+        "g_type_query",
+        [
+            { type: t.uint64, value: typeFromName(parentTypeName) },
+            { type: t.boxed("GTypeQuery", "borrowed", LIB_GOBJECT), value: query },
+        ],
+        t.void,
+    );
+    return call(
+        LIB_GOBJECT,
+        "g_type_register_static_simple",
+        [
+            { type: t.uint64, value: typeFromName(parentTypeName) },
+            { type: t.string("borrowed"), value: name },
+            { type: t.uint32, value: read(query, t.uint32, 16) },
+            { type: t.uint64, value: 0 },
+            { type: t.uint32, value: read(query, t.uint32, 20) },
+            { type: t.uint64, value: 0 },
+            { type: t.uint32, value: 0 },
+        ],
+        t.uint64,
+    ) as number;
+};
+
+class ActionBox extends Gtk.Box {
+    static override readonly glibTypeName = "GtkxTestActionBox";
+}
+
+const actionBoxType = registerSubclass(ActionBox.glibTypeName, "GtkBox");
+registerNativeClass(ActionBox);
+
+const createActionBox = (): ActionBox =>
+    getNativeObject(
+        call(
+            LIB_GOBJECT,
+            "g_object_new_with_properties",
+            [
+                { type: t.uint64, value: actionBoxType },
+                { type: t.uint32, value: 0 },
+                { type: t.uint64, value: 0 },
+                { type: t.uint64, value: 0 },
+            ],
+            t.object("full"),
+        ) as never,
+    ) as ActionBox;
+
+describe("installWidgetAction", () => {
+    it("activates the handler with the widget the action ran on", () => {
+        const activate = vi.fn();
+        installWidgetAction(ActionBox, "box.poke", null, activate);
+        const box = createActionBox();
+
+        expect(box.activateActionVariant("box.poke", null)).toBe(true);
+
+        expect(activate).toHaveBeenCalledTimes(1);
+        expect(activate).toHaveBeenCalledWith(box, "box.poke", null);
+    });
+
+    it("passes the activation parameter", () => {
+        let received: GLib.Variant | null = null;
+        installWidgetAction(ActionBox, "box.say", "s", (_widget, _name, parameter) => {
+            received = parameter;
+        });
+        const box = createActionBox();
+
+        box.activateActionVariant("box.say", GLib.Variant.newString("hello"));
+
+        expect(received).toBeInstanceOf(GLib.Variant);
+    });
+
+    it("throws for a type that is not registered with GLib", () => {
+        class Unregistered extends Gtk.Box {
+            static override readonly glibTypeName = "GtkxTestUnregistered";
+        }
+
+        expect(() => installWidgetAction(Unregistered, "box.none", null, () => {})).toThrow(
+            "Unknown GLib type 'GtkxTestUnregistered'",
+        );
+    });
+});
+
+describe("addWidgetBindingAction", () => {
+    it("binds a shortcut on a widget subclass", () => {
+        installWidgetAction(ActionBox, "box.close", null, () => {});
+
+        expect(() => addWidgetBindingAction(ActionBox, 0x077, 0, "box.close")).not.toThrow();
+    });
+
+    it("rejects classes that are not widgets", () => {
+        expect(() => addWidgetBindingAction(Gtk.Adjustment as never, 0x077, 0, "box.close")).toThrow(
+            "'GtkAdjustment' is not a GtkWidget subclass",
+        );
+    });
+});
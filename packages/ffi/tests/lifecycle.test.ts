@@ -1,5 +1,5 @@
 import { describe, expect, it, vi } from "vitest";
-import { events, isStarted, stop } from "../src/index.js";
+import { events, getDisplayBackend, initRuntime, isStarted, stop } from "../src/index.js";
 
 describe("events", () => {
     it("is an EventEmitter", () => {
@@ -23,6 +23,23 @@ describe("events", () => {
     });
 });
 
+describe("initRuntime", () => {
+    it("rejects an empty backend list", () => {
+        expect(() => initRuntime({ backend: [] })).toThrow("Expected at least one GDK backend");
+    });
+
+    it("ignores options once the runtime has started", () => {
+        expect(() => initRuntime({ backend: "broadway" })).not.toThrow();
+        expect(getDisplayBackend()).not.toBe("broadway");
+    });
+});
+
+describe("getDisplayBackend", () => {
+    it("reports the backend of the default display", () => {
+        expect(["wayland", "x11", "broadway"]).toContain(getDisplayBackend());
+    });
+});
+
 describe("stop and isStarted", () => {
     it("isStarted reports true while the runtime is active", () => {
         expect(isStarted()).toBe(true);
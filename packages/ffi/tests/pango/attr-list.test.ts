@@ -0,0 +1,31 @@
+import { describe, expect, it } from "vitest";
+import { AttrList } from "../../src/generated/pango/attr-list.js";
+import { Style, Weight } from "../../src/generated/pango/enums.js";
+import { attrStyleNew, attrWeightNew } from "../../src/generated/pango/functions.js";
+
+const createList = (): AttrList => {
+    const list = new AttrList();
+    list.insert(attrWeightNew(Weight.BOLD));
+    list.insert(attrStyleNew(Style.ITALIC));
+    return list;
+};
+
+describe("AttrList.filter", () => {
+    it("moves the attributes the predicate selects into a new list", () => {
+        const list = createList();
+
+        const removed = list.filter(() => true);
+
+        expect(removed?.getAttributes()).toHaveLength(2);
+        expect(list.getAttributes()).toHaveLength(0);
+    });
+
+    it("returns null and keeps the list when nothing is selected", () => {
+        const list = createList();
+
+        const removed = list.filter(() => false);
+
+        expect(removed).toBeNull();
+        expect(list.getAttributes()).toHaveLength(2);
+    });
+});
@@ -0,0 +1,35 @@
+import { describe, expect, it } from "vitest";
+import { NativeError } from "../../src/native.js";
+import { escapeMarkup, markup, validateMarkup } from "../../src/pango/markup.js";
+
+describe("validateMarkup", () => {
+    it("returns null for valid markup", () => {
+        expect(validateMarkup("<b>bold</b> and <span foreground='red'>red</span>")).toBeNull();
+    });
+
+    it("returns the parse error for invalid markup", () => {
+        const error = validateMarkup("<b>unclosed");
+
+        expect(error).toBeInstanceOf(NativeError);
+        expect(error?.message).not.toBe("");
+    });
+});
+
+describe("escapeMarkup", () => {
+    it("escapes markup delimiters and control characters", () => {
+        expect(escapeMarkup(`<a href="x">Tom & Jerry's</a>`)).toBe(
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&apos;s&lt;/a&gt;",
+        );
+        expect(escapeMarkup("a\u0001b\tc\n")).toBe("a&#x1;b\tc\n");
+    });
+
+    it("produces markup that parses", () => {
+        expect(validateMarkup(escapeMarkup("<b>not bold & fine</b>"))).toBeNull();
+    });
+});
+
+describe("markup", () => {
+    it("escapes interpolated values only", () => {
+        expect(markup`<b>${"<i>Eve</i>"}</b> has ${3} items`).toBe("<b>&lt;i&gt;Eve&lt;/i&gt;</b> has 3 items");
+    });
+});
@@ -8,6 +8,7 @@ export default mergeConfig(
         plugins: [gtkx()],
         test: {
             setupFiles: ["packages/ffi/tests/setup.ts"],
+            execArgv: ["--expose-gc"],
         },
     }),
 );
@@ -479,6 +479,7 @@ export class GirNormalizer {
             writable: raw.writable ?? false,
             readable: raw.readable ?? true,
             private: raw.private ?? false,
+            bits: raw.bits,
             doc: raw.doc,
         });
     }
@@ -425,6 +425,7 @@ export class GirParser {
                 writable: field["@_writable"] === "1",
                 readable: field["@_readable"] !== "0",
                 private: field["@_private"] === "1",
+                bits: field["@_bits"] === undefined ? undefined : Number(field["@_bits"]),
                 doc: extractDoc(field),
             }));
     }
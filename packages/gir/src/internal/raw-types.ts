@@ -213,5 +213,6 @@ export type RawField = {
     writable?: boolean;
     readable?: boolean;
     private?: boolean;
+    bits?: number;
     doc?: string;
 };
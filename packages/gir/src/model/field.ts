@@ -9,6 +9,8 @@ export class GirField {
     readonly writable: boolean;
     readonly readable: boolean;
     readonly private: boolean;
+    /** Width in bits, for fields declared as C bitfields. */
+    readonly bits?: number;
     readonly doc?: string;
 
     constructor(data: {
@@ -17,6 +19,7 @@ export class GirField {
         writable: boolean;
         readable: boolean;
         private: boolean;
+        bits?: number;
         doc?: string;
     }) {
         this.name = data.name;
@@ -24,6 +27,7 @@ export class GirField {
         this.writable = data.writable;
         this.readable = data.readable;
         this.private = data.private;
+        this.bits = data.bits;
         this.doc = data.doc;
     }
 }
@@ -132,5 +132,19 @@ describe("GirParser", () => {
             expect(result.classes[0]?.methods.length).toBe(1);
             expect(result.classes[0]?.methods[0]?.name).toBe("visible");
         });
+
+        it("parses bitfield widths of record fields", () => {
+            const parser = new GirParser();
+            const gir = createMinimalGir(`
+                <record name="Attr" c:type="TestAttr">
+                    <field name="start" writable="1"><type name="guint" c:type="guint"/></field>
+                    <field name="underline" writable="1" bits="1"><type name="guint" c:type="guint"/></field>
+                </record>
+            `);
+            const result = parser.parseNamespace(gir);
+            const fields = result.records[0]?.fields;
+            expect(fields?.[0]?.bits).toBeUndefined();
+            expect(fields?.[1]?.bits).toBe(1);
+        });
     });
 });
@@ -2,25 +2,76 @@ import * as nativeBinding from "./native-binding.cjs";
 import type {
     Arg,
     ArrayType,
+    CallbackPriority,
     CallbackType,
     FfiValue,
+    FlagsType,
     HashTableType,
     Ref,
     RefType,
+    StructField,
     TrampolineType,
     Type,
+    TypeRegistration,
 } from "./types.js";
 
 const native = nativeBinding as unknown as {
     alloc: (size: number, typeName?: string, lib?: string) => unknown;
+    blockHandler: (external: unknown, handlerId: number) => boolean;
     call: (library: string, symbol: string, args: unknown[], returnType: unknown) => unknown;
+    callHint: (library: string, symbol: string, external: unknown) => void;
+    cancelIdle: (id: number) => void;
+    callAsync: (
+        library: string,
+        symbol: string,
+        args: unknown[],
+        returnType: unknown,
+        cancelToken?: unknown,
+    ) => Promise<unknown>;
+    cancelCall: (cancelToken: unknown) => void;
+    configureBundle: (options: BundleOptions) => void;
+    configureLibrary: (name: string, options: LibraryOptions) => void;
+    connect: (external: unknown, signal: string, callback: unknown, flags?: ConnectFlags) => number;
+    copy: (external: unknown, typeName?: string, size?: number) => unknown;
+    createCancelToken: () => unknown;
+    decodeEvent: (external: unknown) => Omit<DecodedEvent, "device"> & { device: unknown };
+    disconnect: (external: unknown, handlerId: number) => boolean;
+    exportPointer: (external: unknown, addRef?: boolean) => bigint;
+    flagsFromNicks: (type: FlagsType, nicks: string[]) => number;
+    flagsToNicks: (type: FlagsType, mask: number) => string[];
     freeze: () => void;
     getNativeId: (external: unknown) => number;
-    init: () => unknown;
-    read: (external: unknown, type: unknown, offset: number) => unknown;
+    getLoopMetrics: (reset?: boolean) => LoopMetrics;
+    getQueueDepths: (resetPeaks?: boolean) => QueueDepths;
+    importForeignObject: (pointer: bigint, takeRef?: boolean) => unknown;
+    indexHandle: (external: unknown, index: number, stride: number, length?: number) => unknown;
+    init: (mode?: InitMode) => unknown;
+    isExternalPointer: (external: unknown) => boolean;
+    isObjectHandle: (external: unknown) => boolean;
+    listEnumValues: (typeName: string) => EnumValue[];
+    listSymbols: (library: string, prefix?: string) => string[];
+    mapFile: (path: string, writable?: boolean) => unknown;
+    mappedFileBuffer: (external: unknown) => Buffer;
+    onFatalError: (callback: ((domain: string, level: FatalLogLevel, message: string) => void) | null) => void;
+    onIdle: (callback: () => void, timeoutMs: number) => number;
+    pumpLoop: () => void;
+    read: (external: unknown, type: unknown, offset: number | string, byteOrder?: ByteOrder) => unknown;
+    registerTypes: (entries: TypeRegistration[]) => void;
+    setBackpressureThreshold: (threshold: number) => void;
+    setDispatchBudgets: (budgets: DispatchBudgets) => void;
+    setLoopPacing: (pacing: LoopPacing) => void;
     stop: (mainLoop: unknown) => void;
+    unblockHandler: (external: unknown, handlerId: number) => boolean;
     unfreeze: () => void;
-    write: (external: unknown, type: unknown, offset: number, value: unknown) => unknown;
+    unloadLibrary: (name: string, close?: boolean) => boolean;
+    wrapExternalPointer: (pointer: bigint, typeName?: string) => unknown;
+    write: (
+        external: unknown,
+        type: unknown,
+        offset: number | string,
+        value: unknown,
+        byteOrder?: ByteOrder,
+    ) => unknown;
 };
 
 /**
@@ -128,27 +179,145 @@ function unwrapRefArg(ref: Ref<unknown>, type: RefType): Ref<unknown> {
     return ref;
 }
 
+type CallbackWrapper = ((...args: unknown[]) => unknown) & { invalidated?: boolean };
+
+/** Most recent wrapper of each user function passed as a watched closure. */
+const watchedCallbackWrappers = new WeakMap<object, CallbackWrapper>();
+
+const typeRegistrations = new Map<string, TypeRegistration>();
+
+function callbackSignature(type: CallbackType | TrampolineType): { argTypes: Type[]; returnType: Type } {
+    if (type.argTypes && type.returnType) return { argTypes: type.argTypes, returnType: type.returnType };
+    const registered = type.typeName === undefined ? undefined : typeRegistrations.get(type.typeName);
+    if (registered?.kind === "callback") return registered;
+    return { argTypes: type.argTypes ?? [], returnType: type.returnType ?? { type: "void" } };
+}
+
 function wrapUserCallback(value: unknown, type: CallbackType | TrampolineType): unknown {
     if (typeof value !== "function") return value;
     const userCb = value as (...args: unknown[]) => unknown;
-    const { argTypes, returnType } = type;
-    return (...args: unknown[]) => {
+    const { argTypes: emittedTypes, returnType } = callbackSignature(type);
+    // Swapped closures receive the emitting instance last, as the native side rotates the arguments.
+    const argTypes =
+        type.type === "callback" && type.swapped && emittedTypes.length > 0
+            ? [...emittedTypes.slice(1), emittedTypes[0] as Type]
+            : emittedTypes;
+    const wrapper: CallbackWrapper = (...args: unknown[]) => {
         const wrappedArgs = args.map((arg, i) => wrapValue(arg, argTypes[i] ?? { type: "void" }));
         const result = userCb(...wrappedArgs);
         return unwrapValue(result, returnType);
     };
+    if (type.type === "callback" && type.watchArg !== undefined) {
+        watchedCallbackWrappers.set(userCb, wrapper);
+    }
+    return wrapper;
+}
+
+/**
+ * Registers metadata for named types, typically digested from GIR.
+ *
+ * Descriptors can then name a registered type and omit what it holds: the
+ * `library` and `getTypeFn` of boxed, enum and flags types (by `innerType`
+ * or `typeName`), the `library`, `refFn` and `unrefFn` of fundamental
+ * types, and the `argTypes` and `returnType` of callbacks (by `typeName`).
+ * Registering a name again replaces its metadata.
+ *
+ * @param entries - Type metadata to register
+ * @throws If an entry is malformed, in which case none are registered
+ *
+ * @example
+ * ```tsx
+ * registerTypes([{ kind: "boxed", typeName: "GdkRGBA", library: "libgtk-4.so.1", getTypeFn: "gdk_rgba_get_type" }]);
+ *
+ * const rgbaType: Type = { type: "boxed", ownership: "full", innerType: "GdkRGBA" };
+ * ```
+ */
+export function registerTypes(entries: readonly TypeRegistration[]): void {
+    native.registerTypes([...entries]);
+    for (const entry of entries) {
+        typeRegistrations.set(entry.typeName, entry);
+    }
+}
+
+/**
+ * Returns the metadata registered for a type with {@link registerTypes}.
+ *
+ * @param typeName - `GType` name, or C type name for callbacks
+ * @returns The registered metadata, or `undefined` if none
+ */
+export function getTypeRegistration(typeName: string): TypeRegistration | undefined {
+    return typeRegistrations.get(typeName);
+}
+
+/**
+ * Reports whether the closure most recently created from `callback` with a
+ * `watchArg` has been invalidated, either because the watched object was
+ * finalized or because the closure was released (e.g. its signal handler was
+ * disconnected). An invalidated closure never runs again.
+ *
+ * The flag is set asynchronously on the JS thread shortly after
+ * invalidation happens on the `GLib` thread.
+ *
+ * @param callback - The function passed as the closure argument
+ * @returns `true` once invalidated, `false` otherwise or if never watched
+ */
+export function isClosureInvalidated(callback: (...args: never[]) => unknown): boolean {
+    return watchedCallbackWrappers.get(callback)?.invalidated === true;
+}
+
+/**
+ * Live GObject handles keyed by pointer. Every owning handle holds a strong
+ * reference on its object, so a pointer cannot be reused while its entry is
+ * alive; entries are weak so the cache never extends a handle's lifetime.
+ */
+const gobjectHandles = new Map<number, WeakRef<NativeHandle>>();
+
+const gobjectHandleCleanup = new FinalizationRegistry<number>((id) => {
+    const ref = gobjectHandles.get(id);
+    if (ref && !ref.deref()) gobjectHandles.delete(id);
+});
+
+/**
+ * Returns the existing handle for a GObject pointer if one is alive, so that
+ * repeated returns of the same object share one handle. The duplicate
+ * external is left to the garbage collector, which releases its reference.
+ */
+function internGObjectHandle(external: unknown): NativeHandle {
+    const id = native.getNativeId(external);
+    const cached = gobjectHandles.get(id)?.deref();
+    if (cached) return cached;
+
+    const handle = new NativeHandle(external);
+    gobjectHandles.set(id, new WeakRef(handle));
+    gobjectHandleCleanup.register(handle, id);
+    return handle;
+}
+
+/**
+ * Wraps values converted by their runtime type, such as the elements of a
+ * `gvaluearray` without a declared item type. The only objects native code
+ * returns besides arrays and buffers are handles; those owning a GObject are
+ * interned like declared `gobject` values.
+ */
+function wrapUntypedValue(value: unknown): unknown {
+    if (Array.isArray(value)) return value.map(wrapUntypedValue);
+    if (typeof value !== "object" || value === null || value instanceof Uint8Array) return value;
+    return native.isObjectHandle(value) ? internGObjectHandle(value) : new NativeHandle(value);
 }
 
 function wrapValue(value: unknown, type: Type): unknown {
     if (value === null || value === undefined) return value;
 
     if (isHandleType(type)) {
-        return value instanceof NativeHandle ? value : new NativeHandle(value);
+        if (value instanceof NativeHandle) return value;
+        return type.type === "gobject" ? internGObjectHandle(value) : new NativeHandle(value);
     }
 
     switch (type.type) {
         case "array":
-            return Array.isArray(value) ? value.map((item) => wrapValue(item, type.itemType)) : value;
+            if (!Array.isArray(value)) return value;
+            if (type.itemType.type === "void") return value.map(wrapUntypedValue);
+            return value.map((item) => wrapValue(item, type.itemType));
         case "hashtable":
             if (!Array.isArray(value)) return value;
             return value.map((entry) => {
@@ -164,6 +333,34 @@ function rewrapRefArg(ref: Ref<unknown>, type: RefType): void {
     ref.value = wrapValue(ref.value, type.innerType);
 }
 
+let scopedPriority: CallbackPriority | undefined;
+
+function applyScopedPriority(type: Type): Type {
+    if (scopedPriority === undefined || type.type !== "callback" || type.priority !== undefined) return type;
+    return { ...type, priority: scopedPriority };
+}
+
+/**
+ * Runs `fn` with a default priority for every closure it creates.
+ *
+ * Closures whose descriptor sets `priority` keep their own. Lets code that
+ * connects through generated bindings, which build descriptors internally,
+ * choose the lane its handlers are delivered in.
+ *
+ * @param priority - Priority for closures created by `fn`
+ * @param fn - Function that creates closures, e.g. by connecting signals
+ * @returns The result of `fn`
+ */
+export function withCallbackPriority<T>(priority: CallbackPriority, fn: () => T): T {
+    const previous = scopedPriority;
+    scopedPriority = priority;
+    try {
+        return fn();
+    } finally {
+        scopedPriority = previous;
+    }
+}
+
 /**
  * Makes a low-level FFI call to a native library.
  *
@@ -175,10 +372,13 @@ function rewrapRefArg(ref: Ref<unknown>, type: RefType): void {
  * @param args - Function arguments with type information
  * @param returnType - Expected return type
  * @returns The function return value
+ * @throws An `Error` with `domain` and `code` when the function reports a
+ * `GError` through a `gerror` argument
  */
 export function call(library: string, symbol: string, args: Arg[], returnType: Type): FfiValue {
     const unwrapped = args.map((arg) => ({
         ...arg,
+        type: applyScopedPriority(arg.type),
         value: unwrapValue(arg.value, arg.type),
     }));
 
@@ -194,16 +394,281 @@ export function call(library: string, symbol: string, args: Arg[], returnType: T
 }
 
 /**
- * Spawns the dedicated `GLib` thread and starts a `glib::MainLoop` on it.
+ * How {@link init} obtains a `GLib` main loop.
+ *
+ * - `"spawn"`: start a dedicated `GLib` thread running its own loop
+ * - `"attach"`: reuse a loop another library is already running on the
+ *   default main context
+ * - `"auto"`: attach if such a loop exists, spawn otherwise
+ */
+export type InitMode = "spawn" | "attach" | "auto";
+
+/**
+ * Queues a call to a `void fn(gpointer)` function on the `GLib` thread.
+ *
+ * Intended for render hints such as `gtk_widget_queue_draw` that are issued
+ * at high frequency: no type descriptors are marshaled and the caller does
+ * not wait for completion. Calls run in submission order relative to
+ * {@link call}. A hint that fails, for example because the symbol is
+ * missing, does not throw: the error is passed to the native error
+ * reporter, which emits it on `process` as an `unhandledRejection` event.
+ *
+ * @param library - Shared library name (e.g., "libgtk-4.so.1")
+ * @param symbol - Function symbol name
+ * @param handle - The single pointer argument
+ */
+export function callHint(library: string, symbol: string, handle: NativeHandle): void {
+    native.callHint(library, symbol, handle.external);
+}
+
+/** Options for {@link connect}. */
+export type ConnectFlags = {
+    /** Run the handler after the signal's default handler (`G_CONNECT_AFTER`). */
+    after?: boolean;
+};
+
+/**
+ * Connects a callback to a signal of a `GObject`.
+ *
+ * The signal name and its detail are resolved for the object's type on the
+ * `GLib` thread, so an unknown signal throws instead of logging a critical
+ * warning. The callback's arguments are converted as for a closure passed
+ * to {@link call}.
+ *
+ * @param handle - The object to connect to
+ * @param signal - Signal name, optionally with a detail, as in `"notify::label"`
+ * @param callback - The handler, with a closure `callback` type describing the signal's arguments
+ * @param flags - Connection flags
+ * @returns The handler ID, for {@link disconnect}, {@link blockHandler} and {@link unblockHandler}
+ * @throws If the handle is not a `GObject` or its type has no such signal
+ *
+ * @example
+ * ```tsx
+ * const clicked: CallbackType = {
+ *     type: "callback",
+ *     kind: "closure",
+ *     argTypes: [{ type: "gobject", ownership: "borrowed" }],
+ *     returnType: { type: "void" },
+ * };
+ * const handlerId = connect(button.handle, "clicked", { type: clicked, value: () => console.log("clicked") });
+ * ```
+ */
+export function connect(
+    handle: NativeHandle,
+    signal: string,
+    callback: { type: CallbackType; value: (...args: never[]) => unknown },
+    flags: ConnectFlags = {},
+): number {
+    const type = applyScopedPriority(callback.type);
+    return native.connect(handle.external, signal, { type, value: unwrapValue(callback.value, type) }, flags);
+}
+
+/**
+ * Disconnects a signal handler.
+ *
+ * @param handle - The object the handler is connected to
+ * @param handlerId - ID returned by {@link connect}
+ * @returns False if the handler was not connected, e.g. already disconnected
+ */
+export function disconnect(handle: NativeHandle, handlerId: number): boolean {
+    return native.disconnect(handle.external, handlerId);
+}
+
+/**
+ * Blocks a signal handler, so emissions skip it until {@link unblockHandler}.
+ * Blocks nest: a handler blocked twice needs two unblocks.
+ *
+ * @param handle - The object the handler is connected to
+ * @param handlerId - ID returned by {@link connect}
+ * @returns False if the handler was not connected
+ */
+export function blockHandler(handle: NativeHandle, handlerId: number): boolean {
+    return native.blockHandler(handle.external, handlerId);
+}
+
+/**
+ * Undoes one {@link blockHandler}.
+ *
+ * @param handle - The object the handler is connected to
+ * @param handlerId - ID returned by {@link connect}
+ * @returns False if the handler was not connected
+ */
+export function unblockHandler(handle: NativeHandle, handlerId: number): boolean {
+    return native.unblockHandler(handle.external, handlerId);
+}
+
+/**
+ * Lists the nicks of the values set in a flags mask.
+ *
+ * Values are resolved from the flags `GType` registered for `type`. Values
+ * spanning several bits are listed only when all of their bits are set; the
+ * zero value is never listed. With `strict: true` on `type`, a mask with
+ * bits that no value defines is rejected.
+ *
+ * @param type - A `flags` type descriptor
+ * @param mask - The mask to decode
+ * @returns The nicks of the set values
+ */
+export function flagsToNicks(type: FlagsType, mask: number): string[] {
+    return native.flagsToNicks(type, mask);
+}
+
+/**
+ * Builds a flags mask from value nicks.
+ *
+ * @param type - A `flags` type descriptor
+ * @param nicks - Nicks of the values to set (e.g. `["expand", "fill"]`)
+ * @returns The combined mask
+ * @throws if a nick is not a member of the flags type
+ */
+export function flagsFromNicks(type: FlagsType, nicks: readonly string[]): number {
+    return native.flagsFromNicks(type, [...nicks]);
+}
+
+/** A member of an enum or flags type. */
+export type EnumValue = {
+    /** Short name, e.g. `"center"`. */
+    nick: string;
+    /** C identifier, e.g. `"GTK_ALIGN_CENTER"`. */
+    name: string;
+    value: number;
+};
+
+/**
+ * Lists the members of an enum or flags type, in declaration order.
+ *
+ * @param typeName - A `GType` name (e.g. `"GtkAlign"`), a name registered
+ * with {@link registerTypes}, or the `get_type` function of a loaded library
+ * (e.g. `"gtk_align_get_type"`)
+ * @returns The members registered for the type
+ * @throws if the type cannot be resolved or is neither an enum nor flags
+ */
+export function listEnumValues(typeName: string): EnumValue[] {
+    return native.listEnumValues(typeName);
+}
+
+/** Options for {@link callAsync}. */
+export type CallAsyncOptions = {
+    /** Cancels the call when aborted. */
+    signal?: AbortSignal;
+    /**
+     * Index of a `GCancellable*` argument passed as null, which is then
+     * given a new cancellable that `signal` cancels.
+     */
+    cancellableArg?: number;
+};
+
+/**
+ * Invokes a native function without blocking the JavaScript thread.
+ *
+ * Behaves like {@link call}, but returns a promise that settles once the
+ * `GLib` thread has executed the call. Argument values are copied into
+ * native storage, and handles among them are referenced, until the call
+ * completes, so callers may drop or mutate their arguments immediately.
+ * `Ref` out-parameters are updated before the promise resolves.
+ *
+ * Aborting `options.signal` rejects the promise with the signal's reason.
+ * A call still waiting for the `GLib` thread is not run. A running call is
+ * interrupted only if it takes a `GCancellable`, which is cancelled
+ * automatically when one is passed, or created for the argument at
+ * `options.cancellableArg`. Otherwise the call runs to completion and its
+ * result is discarded.
+ *
+ * @param library - Shared library name (e.g., "libgtk-4.so.1")
+ * @param symbol - Function symbol name
+ * @param args - Function arguments with type information
+ * @param returnType - Expected return type
+ * @param options - Abort signal and `GCancellable` argument
+ * @returns Promise for the function return value
+ *
+ * @example
+ * ```ts
+ * const controller = new AbortController();
+ * const exists = callAsync(
+ *     "libgio-2.0.so.0",
+ *     "g_file_query_exists",
+ *     [
+ *         { type: { type: "gobject", ownership: "borrowed" }, value: file },
+ *         { type: { type: "gobject", ownership: "borrowed" }, value: null, optional: true },
+ *     ],
+ *     { type: "boolean" },
+ *     { signal: controller.signal, cancellableArg: 1 },
+ * );
+ * controller.abort();
+ * ```
+ */
+export async function callAsync(
+    library: string,
+    symbol: string,
+    args: Arg[],
+    returnType: Type,
+    options: CallAsyncOptions = {},
+): Promise<FfiValue> {
+    const { signal, cancellableArg } = options;
+    signal?.throwIfAborted();
+
+    const unwrapped = args.map((arg) => ({
+        ...arg,
+        type: applyScopedPriority(arg.type),
+        value: unwrapValue(arg.value, arg.type),
+    }));
+
+    let result: unknown;
+    if (signal) {
+        const slot = cancellableArg === undefined ? undefined : unwrapped[cancellableArg];
+        if (slot && (slot.value === null || slot.value === undefined)) {
+            slot.value = native.call("libgio-2.0.so.0", "g_cancellable_new", [], {
+                type: "gobject",
+                ownership: "full",
+            });
+        }
+
+        const token = native.createCancelToken();
+        const pending = native.callAsync(library, symbol, unwrapped, returnType, token);
+        let onAbort = (): void => {};
+        const aborted = new Promise<never>((_, reject) => {
+            onAbort = () => {
+                native.cancelCall(token);
+                reject(signal.reason);
+            };
+            signal.addEventListener("abort", onAbort, { once: true });
+        });
+
+        try {
+            result = await Promise.race([pending, aborted]);
+        } finally {
+            signal.removeEventListener("abort", onAbort);
+            pending.catch(() => {});
+        }
+    } else {
+        result = await native.callAsync(library, symbol, unwrapped, returnType);
+    }
+
+    for (const arg of args) {
+        if (arg.type.type === "ref") {
+            rewrapRefArg(arg.value as Ref<unknown>, arg.type);
+        }
+    }
+
+    return wrapValue(result, returnType) as FfiValue;
+}
+
+/**
+ * Spawns the dedicated `GLib` thread and starts a `glib::MainLoop` on it,
+ * or attaches to a main loop already running in the process.
  *
  * Returns an opaque handle to the loop, which must be passed to {@link stop}
- * to terminate it. Most code should rely on `@gtkx/ffi`'s lifecycle wrapper
- * instead of calling this directly.
+ * to terminate it. In attach mode, {@link stop} drains pending work but
+ * leaves the host's loop running. Most code should rely on `@gtkx/ffi`'s
+ * lifecycle wrapper instead of calling this directly.
  *
- * @returns Native handle wrapping the spawned `GMainLoop`.
+ * @param mode - Main loop strategy, defaults to `"auto"`
+ * @returns Native handle wrapping the `GMainLoop`.
+ * @throws If `"spawn"` is requested while a host loop is running, or
+ *   `"attach"` is requested while none is.
  */
-export function init(): NativeHandle {
-    return new NativeHandle(native.init());
+export function init(mode: InitMode = "auto"): NativeHandle {
+    return new NativeHandle(native.init(mode));
 }
 
 /**
@@ -219,29 +684,185 @@ export function stop(mainLoop: NativeHandle): void {
     native.stop(mainLoop.external);
 }
 
+/** Byte order of an integer or float in memory. */
+export type ByteOrder = "little" | "big";
+
+/** Options for {@link read} and {@link write}. */
+export type MemoryAccessOptions = {
+    /**
+     * Byte order of the value, for data whose layout does not depend on the
+     * host, such as serialized GVariants or image headers. Only integer and
+     * float types accept it. Defaults to the host byte order.
+     */
+    byteOrder?: ByteOrder;
+};
+
+/** Returns the type of the field `offset` names in a struct layout, or `type` itself for byte offsets. */
+function resolveFieldType(type: Type, offset: number | string): Type {
+    if (typeof offset === "number") return type;
+    let resolved = type;
+    for (const name of offset.split(".")) {
+        const field: StructField | undefined =
+            resolved.type === "struct" ? resolved.fields?.find((candidate) => candidate.name === name) : undefined;
+        if (!field) return resolved;
+        resolved = field.type;
+    }
+    return resolved;
+}
+
 /**
  * Reads a value from native memory.
  *
+ * With a struct type that has `fields`, `offset` may name a field instead,
+ * or a dotted path through embedded structs. The field's offset is computed
+ * natively from the layout, and an embedded struct field is returned as a
+ * handle borrowed from `handle`.
+ *
  * @param handle - Native handle pointing to the memory
- * @param type - Type of value to read
- * @param offset - Byte offset from the handle pointer
+ * @param type - Type of value to read, or the struct type holding a named field
+ * @param offset - Byte offset from the handle pointer, or a field name
+ * @param options - Byte order of the value
  * @returns The read value
+ * @throws If `offset` names a field the struct layout does not have
+ *
+ * @example
+ * ```tsx
+ * const width = read(header, { type: "uint32" }, 16, { byteOrder: "big" });
+ * const x = read(rect, RECTANGLE, "origin.x");
+ * ```
  */
-export function read(handle: NativeHandle, type: Type, offset: number): FfiValue {
-    const result = native.read(handle.external, type, offset);
-    return wrapValue(result, type) as FfiValue;
+export function read(
+    handle: NativeHandle,
+    type: Type,
+    offset: number | string,
+    options: MemoryAccessOptions = {},
+): FfiValue {
+    const result = native.read(handle.external, type, offset, options.byteOrder);
+    return wrapValue(result, resolveFieldType(type, offset)) as FfiValue;
 }
 
 /**
  * Writes a value to native memory.
  *
+ * Accepts a field name as {@link read} does. Writing an embedded struct
+ * field copies the struct the value points to.
+ *
  * @param handle - Native handle pointing to the memory
- * @param type - Type of value to write
- * @param offset - Byte offset from the handle pointer
+ * @param type - Type of value to write, or the struct type holding a named field
+ * @param offset - Byte offset from the handle pointer, or a field name
+ * @param value - Value to write
+ * @param options - Byte order of the value
+ */
+export function write(
+    handle: NativeHandle,
+    type: Type,
+    offset: number | string,
+    value: unknown,
+    options: MemoryAccessOptions = {},
+): void {
+    native.write(handle.external, type, offset, unwrapValue(value, resolveFieldType(type, offset)), options.byteOrder);
+}
+
+/** Element layout of a native array, for {@link indexHandle}. */
+export type ArrayLayout = {
+    /** Size of each element in bytes, including padding. */
+    stride: number;
+    /** Number of elements, when known. Enables bounds checks. */
+    length?: number;
+};
+
+/**
+ * Returns a handle for one element of a native array of structs, such as
+ * the `PangoLogAttr` array of a layout.
+ *
+ * The element is borrowed from the array: it stays valid only as long as
+ * `handle` does, and must be copied with {@link copy} to outlive it.
+ *
+ * @param handle - Handle pointing to the first element
+ * @param index - Zero-based element index
+ * @param layout - Element size in bytes, or the element size and array length
+ * @returns Borrowed handle for the element
+ * @throws If the index is negative, or out of bounds when the length is known
+ *
+ * @example
+ * ```tsx
+ * const attr = indexHandle(attrs, offset, { stride: LOG_ATTR_SIZE, length: count });
+ * const isCursorPosition = readBitfield(attr, CURSOR_POSITION) !== 0;
+ * ```
+ */
+export function indexHandle(handle: NativeHandle, index: number, layout: number | ArrayLayout): NativeHandle {
+    const { stride, length } = typeof layout === "number" ? { stride: layout, length: undefined } : layout;
+    return new NativeHandle(native.indexHandle(handle.external, index, stride, length));
+}
+
+/**
+ * Location of a C bitfield within a struct.
+ *
+ * Bits are numbered from the least significant bit of each byte, matching
+ * how C compilers lay out bitfields on the little-endian platforms gtkx
+ * supports.
+ */
+export type Bitfield = {
+    /** Byte offset of the first byte holding the field. */
+    offset: number;
+    /** Position of the field's lowest bit within that byte, from 0 to 7. */
+    bitOffset: number;
+    /** Width in bits, from 1 to 32. */
+    width: number;
+    /** Whether the field is sign-extended when read. Defaults to `false`. */
+    signed?: boolean;
+};
+
+const BYTE: Type = Object.freeze({ type: "uint8" });
+
+function readBitfieldBytes(handle: NativeHandle, field: Bitfield): number {
+    if (!Number.isInteger(field.width) || field.width < 1 || field.width > 32) {
+        throw new RangeError(`Bitfield width must be between 1 and 32, got ${field.width}`);
+    }
+    if (!Number.isInteger(field.bitOffset) || field.bitOffset < 0 || field.bitOffset > 7) {
+        throw new RangeError(`Bitfield bit offset must be between 0 and 7, got ${field.bitOffset}`);
+    }
+
+    let raw = 0;
+    for (let i = Math.ceil((field.bitOffset + field.width) / 8) - 1; i >= 0; i--) {
+        raw = raw * 256 + (native.read(handle.external, BYTE, field.offset + i) as number);
+    }
+    return raw;
+}
+
+/**
+ * Reads a bitfield from native memory.
+ *
+ * @param handle - Native handle pointing to the struct
+ * @param field - Location of the bitfield
+ * @returns The field value
+ */
+export function readBitfield(handle: NativeHandle, field: Bitfield): number {
+    const span = 2 ** field.width;
+    const value = Math.floor(readBitfieldBytes(handle, field) / 2 ** field.bitOffset) % span;
+    return field.signed && value >= span / 2 ? value - span : value;
+}
+
+/**
+ * Writes a bitfield to native memory, leaving the surrounding bits intact.
+ *
+ * Values are truncated to the field width, as in C.
+ *
+ * @param handle - Native handle pointing to the struct
+ * @param field - Location of the bitfield
  * @param value - Value to write
  */
-export function write(handle: NativeHandle, type: Type, offset: number, value: unknown): void {
-    native.write(handle.external, type, offset, unwrapValue(value, type));
+export function writeBitfield(handle: NativeHandle, field: Bitfield, value: number): void {
+    const raw = readBitfieldBytes(handle, field);
+    const scale = 2 ** field.bitOffset;
+    const span = 2 ** field.width;
+    const bits = ((Math.trunc(value) % span) + span) % span;
+    let updated = raw + (bits - (Math.floor(raw / scale) % span)) * scale;
+
+    for (let i = 0; i < Math.ceil((field.bitOffset + field.width) / 8); i++) {
+        native.write(handle.external, BYTE, field.offset + i, updated % 256);
+        updated = Math.floor(updated / 256);
+    }
 }
 
 /**
@@ -256,6 +877,28 @@ export function alloc(size: number, glibTypeName?: string, lib?: string): Native
     return new NativeHandle(native.alloc(size, glibTypeName, lib));
 }
 
+/**
+ * Returns an independently owned copy of a handle.
+ *
+ * Boxed values are duplicated with `g_boxed_copy`, objects and fundamental
+ * instances gain a new reference. Use this to keep a borrowed struct (for
+ * example one passed to a signal handler) alive after the callback returns:
+ * borrowed structs handed to callbacks are invalidated on return, and any
+ * later use throws.
+ *
+ * `glibTypeName` and `size` are only consulted when the handle carries no
+ * `GType`: the type name is resolved for `g_boxed_copy`, and otherwise
+ * `size` bytes are copied for plain structs.
+ *
+ * @param handle - Handle to copy
+ * @param glibTypeName - GLib type name of a boxed value
+ * @param size - Size in bytes of a plain struct
+ * @returns A new handle owning the copy
+ */
+export function copy(handle: NativeHandle, glibTypeName?: string, size?: number): NativeHandle {
+    return new NativeHandle(native.copy(handle.external, glibTypeName, size));
+}
+
 /**
  * Suspends GTK frame-clock dispatch while a batch of mutations is applied.
  *
@@ -277,4 +920,472 @@ export function unfreeze(): void {
     native.unfreeze();
 }
 
-export type { Arg, CallbackType, FfiValue, Ref, Type } from "./types.js";
+/** Options for {@link importForeignObject}. */
+export type ImportForeignObjectOptions = {
+    /**
+     * Transfer the caller's reference to the returned handle instead of
+     * acquiring a new one. Defaults to `false`.
+     */
+    takeRef?: boolean;
+};
+
+/**
+ * Wraps a `GObject*` created by another native addon sharing this process.
+ *
+ * The pointer is validated as a live `GObject` instance before it is adopted.
+ *
+ * @param pointer - Raw pointer value, as produced by e.g. {@link exportPointer}
+ * @param options - Reference ownership options
+ * @returns Native handle to the object
+ * @throws If the pointer is null, not a valid address or not a `GObject` instance
+ */
+export function importForeignObject(pointer: bigint, options: ImportForeignObjectOptions = {}): NativeHandle {
+    return internGObjectHandle(native.importForeignObject(pointer, options.takeRef ?? false));
+}
+
+/** Options for {@link exportPointer}. */
+export type ExportPointerOptions = {
+    /**
+     * Take a new `GObject` reference on behalf of the receiver, who must
+     * release it with `g_object_unref`. Defaults to `false`.
+     */
+    addRef?: boolean;
+};
+
+/**
+ * Returns the raw pointer behind a handle for use by another native addon.
+ *
+ * Without `addRef` the pointer is only valid while `handle` is alive.
+ *
+ * @param handle - Handle to export
+ * @param options - Reference ownership options
+ * @returns Raw pointer value
+ * @throws If `addRef` is set and the handle does not own a `GObject`
+ */
+export function exportPointer(handle: NativeHandle, options: ExportPointerOptions = {}): bigint {
+    return native.exportPointer(handle.external, options.addRef ?? false);
+}
+
+/** Options for {@link wrapExternalPointer}. */
+export type WrapExternalPointerOptions = {
+    /**
+     * GLib type name of the value behind the pointer (e.g. `"GdkRGBA"`).
+     * Used in error messages and as the default type for {@link copy}.
+     */
+    typeName?: string;
+};
+
+/**
+ * Wraps a raw pointer obtained outside gtkx, such as from another native
+ * library, so it can be passed as an argument.
+ *
+ * The handle never owns the value: nothing is freed or unreferenced when it
+ * is garbage collected, so the caller must keep the value alive while the
+ * handle is in use. Only null is rejected, since nothing else about the
+ * pointer can be checked safely. Errors involving the handle identify it as
+ * an external pointer. Use {@link importForeignObject} instead for `GObject`
+ * instances, which validates the pointer and takes a reference.
+ *
+ * @param pointer - Raw pointer value
+ * @param options - Type of the value behind the pointer
+ * @returns Non-owning native handle
+ * @throws If the pointer is null or not a valid address
+ */
+export function wrapExternalPointer(pointer: bigint, options: WrapExternalPointerOptions = {}): NativeHandle {
+    return new NativeHandle(native.wrapExternalPointer(pointer, options.typeName));
+}
+
+/**
+ * Checks whether a handle was created by {@link wrapExternalPointer}.
+ *
+ * @param handle - Handle to check
+ * @returns `true` for external pointers, `false` for handles gtkx manages
+ */
+export function isExternalPointer(handle: NativeHandle): boolean {
+    return native.isExternalPointer(handle.external);
+}
+
+/** Options for {@link mapFile}. */
+export type MapFileOptions = {
+    /**
+     * Allow the mapping to be modified. Changes stay private to the process
+     * and are never written back to the file. Defaults to `false`.
+     */
+    writable?: boolean;
+};
+
+/**
+ * Maps a file into memory with `GMappedFile`.
+ *
+ * The file is unmapped once the handle and every buffer from
+ * {@link mappedFileBuffer} have been garbage collected.
+ *
+ * @param path - Path of the file to map
+ * @param options - Whether the mapping can be modified
+ * @returns Handle to the `GMappedFile`
+ * @throws If the file cannot be opened or mapped
+ */
+export function mapFile(path: string, options: MapFileOptions = {}): NativeHandle {
+    return new NativeHandle(native.mapFile(path, options.writable));
+}
+
+/**
+ * Returns a `Buffer` over the contents of a mapping from {@link mapFile},
+ * without copying them.
+ *
+ * The buffer keeps the mapping alive on its own. A mapping backs only one
+ * such buffer at a time, so call this once and share the result.
+ *
+ * @param handle - Handle returned by {@link mapFile}
+ * @returns Buffer backed by the mapped memory
+ * @throws If the handle is not a `GMappedFile`, or an earlier buffer over the
+ *   same mapping has not been garbage collected
+ */
+export function mappedFileBuffer(handle: NativeHandle): Buffer {
+    return native.mappedFileBuffer(handle.external);
+}
+
+/**
+ * The fields of a `GdkEvent`, from {@link decodeEvent}. Fields the event's
+ * type does not have are null.
+ */
+export type DecodedEvent = {
+    /** `GdkEventType` value, such as `GDK_KEY_PRESS`. */
+    type: number;
+    /** Timestamp in milliseconds. */
+    time: number;
+    /** `GdkModifierType` mask of the modifier keys and buttons held. */
+    modifiers: number;
+    /** Horizontal position in surface coordinates, if the event has one. */
+    x: number | null;
+    /** Vertical position in surface coordinates, if the event has one. */
+    y: number | null;
+    /** The `GdkDevice` that caused the event. */
+    device: NativeHandle | null;
+    /** Button number of a button press or release. */
+    button: number | null;
+    /** Keyval of a key press or release. */
+    keyval: number | null;
+    /** Hardware keycode of a key press or release. */
+    keycode: number | null;
+    /** Horizontal delta of a smooth scroll event; 0 for a discrete one. */
+    deltaX: number | null;
+    /** Vertical delta of a smooth scroll event; 0 for a discrete one. */
+    deltaY: number | null;
+};
+
+/**
+ * Reads the common fields of a `GdkEvent` in a single round trip to the
+ * `GLib` thread, instead of one call per getter.
+ *
+ * @param event - Handle to a `GdkEvent`, such as from an event controller
+ * @returns The event's fields
+ * @throws If the handle is not a `GdkEvent`
+ */
+export function decodeEvent(event: NativeHandle): DecodedEvent {
+    const decoded = native.decodeEvent(event.external);
+    return { ...decoded, device: decoded.device === null ? null : internGObjectHandle(decoded.device) };
+}
+
+/**
+ * Lists the functions a shared library exports.
+ *
+ * Reads the dynamic symbol table of the file the library loads from, so
+ * tooling can discover what is available at runtime or check generated
+ * bindings against the installed library version.
+ *
+ * @param library - Library name, resolved as for {@link call}
+ * @param prefix - Only list functions whose names start with this
+ * @returns Function names, sorted
+ * @throws If the library cannot be loaded or its symbols cannot be read
+ *
+ * @example
+ * ```tsx
+ * const missing = boundSymbols.filter((name) => !listSymbols("libgtk-4.so.1", "gtk_").includes(name));
+ * ```
+ */
+export function listSymbols(library: string, prefix?: string): string[] {
+    return native.listSymbols(library, prefix);
+}
+
+/** How a library is located and loaded. */
+export type LibraryOptions = {
+    /** Directories tried, in order, before the dynamic loader's search path. */
+    searchPaths?: string[];
+    /** File names tried instead of the library name, such as versioned sonames. */
+    sonames?: string[];
+    /** `dlopen` flags; must include `"lazy"` or `"now"`. Defaults to `["now", "global"]`. */
+    flags?: ("lazy" | "now" | "global" | "local")[];
+};
+
+/**
+ * Sets how a library is located and loaded.
+ *
+ * Options apply to the library name exactly as descriptors use it and
+ * replace any options set before. They take effect the next time the
+ * library is loaded, so set them before the first call into it, or unload
+ * it with {@link unloadLibrary} first.
+ *
+ * @param name - Library name, as passed to {@link call}
+ * @param options - Search paths, file names and flags
+ * @throws If a flag is unknown or neither `"lazy"` nor `"now"` is given
+ *
+ * @example
+ * ```tsx
+ * configureLibrary("libadwaita-1.so.0", { sonames: ["libadwaita-1.so.0", "libadwaita-1.so"] });
+ * ```
+ */
+export function configureLibrary(name: string, options: LibraryOptions): void {
+    native.configureLibrary(name, options);
+}
+
+/**
+ * Drops a loaded library's handle, so its next use loads it again with its
+ * current options.
+ *
+ * By default the handle is released without closing it and the library
+ * stays mapped. With `close`, it is closed, which unmaps the library if
+ * nothing else holds it; that is unsafe while its types are registered or
+ * its threads are running.
+ *
+ * @param name - Library name, as passed to {@link call}
+ * @param options - Whether to close the handle
+ * @returns Whether the library was loaded
+ */
+export function unloadLibrary(name: string, options: { close?: boolean } = {}): boolean {
+    return native.unloadLibrary(name, options.close ?? false);
+}
+
+/** Layout of a relocatable application bundle, such as a Flatpak, AppImage or packaged Electron app. */
+export type BundleOptions = {
+    /** Absolute path of the bundle. */
+    root: string;
+    /** Directories under `root` holding libraries. Defaults to `["lib"]`. */
+    libDirs?: string[];
+    /** Directories under `root` prepended to `XDG_DATA_DIRS`. Defaults to `["share"]`. */
+    dataDirs?: string[];
+    /** Value for `GSK_RENDERER`, for bundles that need a specific renderer. */
+    renderer?: string;
+    /** Path under `root` of the `gdk-pixbuf` loaders cache. Defaults to `"lib/gdk-pixbuf-2.0/2.10.0/loaders.cache"`. */
+    pixbufLoaders?: string;
+};
+
+/**
+ * Loads libraries and GTK data from a relocatable application bundle.
+ *
+ * Every library is searched for in the bundle's library directories after
+ * its own search paths, and relative {@link LibraryOptions.searchPaths}
+ * resolve against the bundle root. On the `GLib` thread, `XDG_DATA_DIRS`
+ * is prefixed with the bundle's data directories, `GSK_RENDERER` is set
+ * when a renderer is given, and `GDK_PIXBUF_MODULE_FILE` and
+ * `GDK_PIXBUF_MODULEDIR` point at the bundled loaders when their cache
+ * exists.
+ *
+ * Call it before initializing GTK and before the first call into a bundled
+ * library: GTK reads the environment once, and libraries already loaded
+ * keep their handles.
+ *
+ * @param options - Bundle layout
+ * @throws If `root` is not absolute or the environment cannot be set
+ *
+ * @example
+ * ```tsx
+ * configureBundle({ root: path.join(path.dirname(process.execPath), "gtk"), renderer: "gl" });
+ * ```
+ */
+export function configureBundle(options: BundleOptions): void {
+    native.configureBundle(options);
+}
+
+/**
+ * Per-drain task limits for the cross-thread queues.
+ *
+ * Omitted or zero means unlimited. A drain that reaches its budget yields
+ * before running more tasks, so a flood on one queue cannot starve the
+ * other thread's pending results, GTK input handling, or Node's event loop.
+ */
+export type DispatchBudgets = {
+    /** Tasks queued for the GLib thread. */
+    glib?: number;
+    /** Callbacks queued for the JS thread. */
+    js?: number;
+};
+
+/** Current and peak lengths of the cross-thread queues. */
+export type QueueDepths = {
+    glib: number;
+    js: number;
+    glibPeak: number;
+    jsPeak: number;
+    /** Emissions discarded by `overflow` closures under backpressure. */
+    dropped: number;
+};
+
+/**
+ * Sets how many queued tasks each drain of the cross-thread queues may run
+ * before yielding.
+ *
+ * @param budgets - Limits for each queue
+ */
+export function setDispatchBudgets(budgets: DispatchBudgets): void {
+    native.setDispatchBudgets(budgets);
+}
+
+/**
+ * Sets the JS queue length at which closures with an `overflow` policy
+ * start shedding emissions. Zero disables backpressure.
+ *
+ * @param threshold - Queue length that counts as saturated
+ */
+export function setBackpressureThreshold(threshold: number): void {
+    native.setBackpressureThreshold(threshold);
+}
+
+/**
+ * Returns the current and peak lengths of the cross-thread queues.
+ *
+ * @param options - `resetPeaks` restarts peak and drop tracking after reading
+ * @returns Queue depths
+ */
+export function getQueueDepths(options: { resetPeaks?: boolean } = {}): QueueDepths {
+    return native.getQueueDepths(options.resetPeaks ?? false);
+}
+
+/**
+ * Calls `callback` once, after the GTK main loop has gone `timeoutMs`
+ * without input, display events, or queued work.
+ *
+ * Useful for deferring expensive work such as persisting state until the
+ * user pauses. Implemented with GLib sources on the GTK thread, so checking
+ * for idleness costs nothing on the JS thread.
+ *
+ * @param callback - Function to call when the main loop is idle
+ * @param timeoutMs - How long the main loop must stay idle
+ * @returns A function that cancels the watch if it has not fired yet
+ */
+export function onIdle(callback: () => void, timeoutMs: number): () => void {
+    const id = native.onIdle(callback, timeoutMs);
+    return () => native.cancelIdle(id);
+}
+
+/** Level of a fatal `GLib` log message. */
+export type FatalLogLevel = "ERROR" | "CRITICAL";
+
+/** A fatal `GLib` log message, passed to the {@link onFatalError} callback. */
+export type FatalError = {
+    /** Log domain, such as `"Gtk"`. */
+    domain: string;
+    /** `"CRITICAL"` for criticals made fatal by `G_DEBUG=fatal-criticals`. */
+    level: FatalLogLevel;
+    message: string;
+};
+
+/**
+ * Calls `callback` when `g_error` or another fatal log message is about to
+ * abort the process.
+ *
+ * The callback runs once, synchronously, while the GTK thread waits for it,
+ * so it can still make FFI calls, persist state and {@link stop} the main
+ * loop. The process aborts when it returns. Only applies when {@link init}
+ * spawned the `GLib` thread; in attach mode the host's log handler is used.
+ *
+ * @param callback - Function to call with the fatal message, or null to
+ *   remove the current one
+ */
+export function onFatalError(callback: ((error: FatalError) => void) | null): void {
+    native.onFatalError(callback && ((domain, level, message) => callback({ domain, level, message })));
+}
+
+/**
+ * How the GTK main loop is held back between iterations.
+ *
+ * Omitting both fields lets it run freely, which is the default.
+ */
+export type LoopPacing = {
+    /**
+     * Minimum time between the starts of two iterations, such as `1000 / 60`
+     * to iterate at most once per frame of a 60 Hz display.
+     */
+    intervalMs?: number;
+    /**
+     * Wait for {@link pumpLoop} before each iteration. Combined with
+     * `intervalMs`, the interval bounds how long to wait for a tick.
+     */
+    external?: boolean;
+};
+
+/** GTK main-loop timing, accumulated since the last reset. */
+export type LoopMetrics = {
+    iterations: number;
+    /** Total time spent dispatching events, sources and frames. */
+    busyMs: number;
+    /** Longest time a single iteration spent dispatching. */
+    maxBusyMs: number;
+    /** Time the latest iteration spent dispatching. */
+    lastBusyMs: number;
+    /** Total time blocked waiting for events. */
+    pollMs: number;
+    /** Total time iterations were held back by pacing. */
+    pacedMs: number;
+};
+
+/**
+ * Paces the GTK main loop for embedding next to another message pump.
+ *
+ * In hosts such as Electron, GTK waking for every ready event competes with
+ * the host's own pump and shows up as input lag. Pacing holds each iteration
+ * back until the interval has passed or an external tick arrives, so GTK
+ * iterates in step with the host's frames. Calls into GTK from JS are never
+ * held back.
+ *
+ * @param pacing - Interval and tick settings; `{}` restores free running
+ * @throws If `intervalMs` is negative or not finite
+ *
+ * @example
+ * ```ts
+ * setLoopPacing({ external: true, intervalMs: 50 });
+ * const tick = () => {
+ *     pumpLoop();
+ *     requestAnimationFrame(tick);
+ * };
+ * requestAnimationFrame(tick);
+ * ```
+ */
+export function setLoopPacing(pacing: LoopPacing): void {
+    native.setLoopPacing(pacing);
+}
+
+/**
+ * Lets one GTK main-loop iteration held by external pacing run. Ticks that
+ * arrive while no iteration is waiting collapse into one.
+ */
+export function pumpLoop(): void {
+    native.pumpLoop();
+}
+
+/**
+ * Returns how the GTK main loop spent its time, for spotting long
+ * iterations that delay input.
+ *
+ * Collection starts with the first call to this function or to
+ * {@link setLoopPacing}, and reading never waits on the GTK thread.
+ *
+ * @param options - `reset` restarts the counters after reading
+ * @returns Loop metrics
+ */
+export function getLoopMetrics(options: { reset?: boolean } = {}): LoopMetrics {
+    return native.getLoopMetrics(options.reset ?? false);
+}
+
+export type {
+    Arg,
+    CallbackOverflow,
+    CallbackPriority,
+    CallbackType,
+    FfiValue,
+    FlagsType,
+    Ref,
+    StructField,
+    Type,
+    TypeRegistration,
+} from "./types.js";
@@ -16,7 +16,10 @@
 use napi::bindgen_prelude::*;
 use napi::{Env, JsObject};
 
-use crate::{types::Type, value::Value};
+use crate::{
+    types::Type,
+    value::{HandleRef, Value},
+};
 
 #[derive(Debug, Clone)]
 pub struct Arg {
@@ -52,6 +55,21 @@ impl Arg {
         Ok(args)
     }
 
+    /// Takes a [`HandleRef`] on every handle among the values of the
+    /// arguments in `value`, for calls that run after the JS frame that
+    /// passed them has returned.
+    pub fn retain_handles(env: &Env, value: &Array) -> napi::Result<Vec<HandleRef>> {
+        let mut handles = Vec::new();
+        for i in 0..value.len() {
+            let Some(item) = value.get::<Unknown<'_>>(i)? else {
+                continue;
+            };
+            let obj: JsObject = unsafe { JsObject::from_napi_value(env.raw(), item.raw())? };
+            HandleRef::collect(env, obj.get_named_property("value")?, &mut handles)?;
+        }
+        Ok(handles)
+    }
+
     pub fn from_js_value(env: &Env, value: Unknown<'_>) -> napi::Result<Self> {
         let obj: JsObject = unsafe { JsObject::from_napi_value(env.raw(), value.raw())? };
         let type_prop: Unknown<'_> = obj.get_named_property("type")?;
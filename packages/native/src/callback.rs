@@ -1,7 +1,11 @@
 use std::ptr::NonNull;
+use std::sync::{Arc, Mutex};
 
 use gtk4::glib::gobject_ffi;
 
+use crate::dispatch::{Mailbox, Priority};
+use crate::value::{JsCallbackRef, Value};
+
 #[derive(Debug)]
 pub struct ClosureGuard {
     closure: NonNull<gobject_ffi::GClosure>,
@@ -24,3 +28,102 @@ impl Drop for ClosureGuard {
         unsafe { gobject_ffi::g_closure_unref(self.closure.as_ptr()) };
     }
 }
+
+/// Latest-value slot for a closure connected with `coalesce: true`.
+///
+/// Every emission overwrites the pending arguments, but only the first
+/// emission after a delivery posts a task to the JS thread. A flood of
+/// emissions between two JS poll cycles therefore collapses into a single
+/// call carrying the most recent arguments, and the `GLib` thread never
+/// blocks waiting for JS.
+#[derive(Debug)]
+pub struct CoalescedCallback {
+    js_func: Arc<JsCallbackRef>,
+    priority: Priority,
+    pending: Mutex<Option<Vec<Value>>>,
+}
+
+impl CoalescedCallback {
+    #[must_use]
+    pub fn new(js_func: Arc<JsCallbackRef>, priority: Priority) -> Arc<Self> {
+        Arc::new(Self {
+            js_func,
+            priority,
+            pending: Mutex::new(None),
+        })
+    }
+
+    /// Records `args` as the latest emission, posting a delivery to the JS
+    /// thread if none is outstanding. Returns true if an undelivered
+    /// emission was replaced.
+    pub fn submit(self: &Arc<Self>, args: Vec<Value>) -> bool {
+        let was_idle = self
+            .pending
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .replace(args)
+            .is_none();
+
+        if was_idle {
+            Mailbox::global().post_coalesced(Arc::clone(self));
+        }
+        !was_idle
+    }
+
+    /// Takes the latest pending arguments, re-arming the slot so the next
+    /// emission posts a fresh delivery.
+    pub fn take_pending(&self) -> Option<Vec<Value>> {
+        self.pending
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .take()
+    }
+
+    #[must_use]
+    pub fn js_func(&self) -> &Arc<JsCallbackRef> {
+        &self.js_func
+    }
+
+    #[must_use]
+    pub fn priority(&self) -> Priority {
+        self.priority
+    }
+}
+
+/// Recycled argument vectors for a closure.
+///
+/// The `GLib` thread fills a vector per emission and the JS thread hands it
+/// back, emptied, once the handler has run, so a steady stream of factory
+/// `bind` or `notify::` emissions stops allocating argument storage. A
+/// nested emission that finds the pool empty simply allocates a fresh
+/// vector.
+#[derive(Debug, Default)]
+pub struct ArgPool {
+    free: Mutex<Vec<Vec<Value>>>,
+}
+
+impl ArgPool {
+    const MAX_POOLED: usize = 8;
+
+    /// Takes an empty vector from the pool, or allocates one.
+    #[must_use]
+    pub fn acquire(&self, capacity: usize) -> Vec<Value> {
+        self.free
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .pop()
+            .unwrap_or_else(|| Vec::with_capacity(capacity))
+    }
+
+    /// Clears `args` and returns it to the pool.
+    pub fn release(&self, mut args: Vec<Value>) {
+        args.clear();
+        let mut free = self
+            .free
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if free.len() < Self::MAX_POOLED {
+            free.push(args);
+        }
+    }
+}
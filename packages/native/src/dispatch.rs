@@ -6,6 +6,13 @@
 //! - `glib_inbox`: tasks pushed by the JS thread for execution on the `GLib` thread.
 //! - `node_inbox`: callbacks pushed by the `GLib` thread for execution in the JS context.
 //!
+//! Most node tasks block the `GLib` thread until JS returns a result. Closures
+//! connected with `coalesce: true` instead post a [`CoalescedCallback`] via
+//! [`Mailbox::post_coalesced`]: the `GLib` thread keeps running, and repeated
+//! emissions before JS drains the inbox collapse into one delivery carrying
+//! the latest arguments. [`Mailbox::post_node`] queues an arbitrary
+//! fire-and-forget task for the JS thread in the same way.
+//!
 //! Each thread parks on its own wake signal while waiting for a response.
 //! Re-entrance falls out of the call stack: while a thread is parked waiting for
 //! a response from the other side, the wait loop also services any incoming
@@ -21,6 +28,40 @@
 //! main loop, ensuring the frame clock cannot fire mid-commit. Nested freeze
 //! pairs are no-ops; only the outermost pair starts and stops the loop.
 //!
+//! ## Priority lanes
+//!
+//! The JS-bound inbox is split into [`Priority`] lanes, and each drain takes
+//! from the highest non-empty lane first. Closures declare their lane with
+//! `priority` on the callback descriptor, so an input handler queued behind a
+//! slow low-priority callback (logging, analytics) is delivered first.
+//! Tasks within a lane keep FIFO order.
+//!
+//! ## Fairness
+//!
+//! Each drain services at most a configurable number of tasks before it
+//! yields (see [`Mailbox::set_budgets`]). A wait loop that hits its budget
+//! checks for its own result before draining the next batch, so a flood of
+//! incoming work cannot starve the response it is waiting for. A drain run
+//! from the `GLib` main loop re-arms its idle source and returns, letting GTK
+//! process input and frames between batches; the JS-side drain re-posts its
+//! wake function so Node's event loop gets a turn. Budgets default to
+//! unlimited. [`Mailbox::queue_depths`] reports current and peak inbox sizes.
+//!
+//! ## Backpressure
+//!
+//! Once the JS-bound inbox holds at least the backpressure threshold of
+//! tasks ([`Mailbox::set_backpressure_threshold`]), the inbox is saturated.
+//! Closures whose descriptor sets `overflow` stop queueing new calls while
+//! saturated: they drop the emission or fold it into a single pending
+//! delivery, and each discarded emission is counted in
+//! [`QueueDepths::dropped`]. Closures without `overflow` are never dropped.
+//!
+//! ## Deferred tasks
+//!
+//! [`Mailbox::schedule_glib_delayed`] runs a task on the `GLib` thread after
+//! a delay and returns a [`ScheduledTask`] that can cancel it from either
+//! thread, for native-side debouncing and deferred cleanup.
+//!
 //! ## Lifecycle
 //!
 //! [`Mailbox::mark_stopped`] is set during the orchestrated shutdown task,
@@ -31,27 +72,40 @@
 use std::collections::VecDeque;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex, OnceLock, mpsc};
+use std::time::Duration;
 
 use gtk4::glib;
 use napi::bindgen_prelude::{FromNapiValue, Unknown};
 use napi::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
 use napi::{Env, Status};
 
+use crate::callback::{ArgPool, CoalescedCallback};
 use crate::error_reporter::NativeErrorReporter;
+use crate::state::JsThread;
 use crate::value::{JsCallbackRef, Value};
 use crate::wait_signal::WaitSignal;
 
 type GlibTask = Box<dyn FnOnce() + Send + 'static>;
 
+type DetachedNodeTask = Box<dyn FnOnce(Env) + Send + 'static>;
+
 pub type WakeJsTsfn = ThreadsafeFunction<(), (), (), Status, false, true>;
 
 struct NodeCallback {
     callback: Arc<JsCallbackRef>,
     args: Vec<Value>,
     capture_result: bool,
+    /// Pool that receives `args` back once they have been converted.
+    pool: Option<Arc<ArgPool>>,
     result_tx: mpsc::Sender<anyhow::Result<Value>>,
 }
 
+enum NodeTask {
+    Invoke(NodeCallback),
+    Coalesced(Arc<CoalescedCallback>),
+    Detached(DetachedNodeTask),
+}
+
 /// Bidirectional message queues coordinating the JS and `GLib` threads.
 ///
 /// Holds two inboxes — one for tasks bound for the `GLib` thread, one for
@@ -59,7 +113,7 @@ struct NodeCallback {
 /// each thread when its inbox is empty.
 pub struct Mailbox {
     glib_inbox: Mutex<VecDeque<GlibTask>>,
-    node_inbox: Mutex<VecDeque<NodeCallback>>,
+    node_inbox: Mutex<NodeLanes>,
 
     wake_js: WaitSignal,
     wake_glib: WaitSignal,
@@ -71,6 +125,127 @@ pub struct Mailbox {
     freeze_depth: AtomicUsize,
     freeze_loop_active: AtomicBool,
     freeze_wake: WaitSignal,
+
+    glib_budget: AtomicUsize,
+    node_budget: AtomicUsize,
+    glib_peak: AtomicUsize,
+    node_peak: AtomicUsize,
+    backpressure_threshold: AtomicUsize,
+    dropped: AtomicUsize,
+}
+
+/// Delivery lane of a JS-bound task. Higher lanes drain first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Priority {
+    Low,
+    #[default]
+    Default,
+    High,
+}
+
+impl std::str::FromStr for Priority {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "low" => Ok(Self::Low),
+            "default" => Ok(Self::Default),
+            "high" => Ok(Self::High),
+            _ => Err(format!("Invalid priority '{s}', expected 'low', 'default' or 'high'")),
+        }
+    }
+}
+
+/// JS-bound tasks, one FIFO queue per [`Priority`].
+#[derive(Default)]
+struct NodeLanes {
+    lanes: [VecDeque<NodeTask>; 3],
+}
+
+impl NodeLanes {
+    fn push(&mut self, priority: Priority, task: NodeTask) {
+        self.lanes[priority as usize].push_back(task);
+    }
+
+    fn pop(&mut self) -> Option<NodeTask> {
+        self.lanes.iter_mut().rev().find_map(VecDeque::pop_front)
+    }
+
+    fn len(&self) -> usize {
+        self.lanes.iter().map(VecDeque::len).sum()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.lanes.iter().all(VecDeque::is_empty)
+    }
+}
+
+/// Handle to a task queued with [`Mailbox::schedule_glib_delayed`].
+///
+/// Clones share the task. Dropping every handle does not cancel it.
+#[derive(Debug, Clone, Default)]
+pub struct ScheduledTask {
+    state: Arc<ScheduledState>,
+}
+
+#[derive(Debug, Default)]
+struct ScheduledState {
+    cancelled: AtomicBool,
+    source: Mutex<Option<glib::SourceId>>,
+}
+
+impl ScheduledState {
+    fn take_source(&self) -> Option<glib::SourceId> {
+        self.source
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .take()
+    }
+}
+
+impl ScheduledTask {
+    /// Prevents the task from running if it has not started yet. Callable
+    /// from any thread; later calls are no-ops.
+    pub fn cancel(&self) {
+        if self.state.cancelled.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        let state = Arc::clone(&self.state);
+        Mailbox::global().schedule_glib(move || {
+            if let Some(source) = state.take_source() {
+                source.remove();
+            }
+        });
+    }
+
+    /// Returns whether [`Self::cancel`] has been called.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.state.cancelled.load(Ordering::Acquire)
+    }
+}
+
+/// Current and peak lengths of the two inboxes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct QueueDepths {
+    pub glib: usize,
+    pub node: usize,
+    pub glib_peak: usize,
+    pub node_peak: usize,
+    /// Emissions discarded under backpressure.
+    pub dropped: usize,
+}
+
+/// JS-bound inbox length at which `overflow` closures start shedding calls.
+pub const DEFAULT_BACKPRESSURE_THRESHOLD: usize = 256;
+
+/// Converts a stored budget, where zero means unlimited, into a task limit.
+fn budget_limit(budget: &AtomicUsize) -> usize {
+    match budget.load(Ordering::Relaxed) {
+        0 => usize::MAX,
+        n => n,
+    }
 }
 
 impl std::fmt::Debug for Mailbox {
@@ -93,7 +268,7 @@ impl Mailbox {
     fn new() -> Self {
         Self {
             glib_inbox: Mutex::new(VecDeque::new()),
-            node_inbox: Mutex::new(VecDeque::new()),
+            node_inbox: Mutex::new(NodeLanes::default()),
             wake_js: WaitSignal::new(),
             wake_glib: WaitSignal::new(),
             wake_js_tsfn: OnceLock::new(),
@@ -101,6 +276,12 @@ impl Mailbox {
             freeze_depth: AtomicUsize::new(0),
             freeze_loop_active: AtomicBool::new(false),
             freeze_wake: WaitSignal::new(),
+            glib_budget: AtomicUsize::new(0),
+            node_budget: AtomicUsize::new(0),
+            glib_peak: AtomicUsize::new(0),
+            node_peak: AtomicUsize::new(0),
+            backpressure_threshold: AtomicUsize::new(DEFAULT_BACKPRESSURE_THRESHOLD),
+            dropped: AtomicUsize::new(0),
         }
     }
 
@@ -130,6 +311,92 @@ impl Mailbox {
         self.stopped.load(Ordering::Acquire)
     }
 
+    /// Sets how many tasks each drain of the `GLib` and JS inboxes may run
+    /// before yielding. `None` or zero means unlimited.
+    pub fn set_budgets(&self, glib: Option<usize>, node: Option<usize>) {
+        self.glib_budget.store(glib.unwrap_or(0), Ordering::Relaxed);
+        self.node_budget.store(node.unwrap_or(0), Ordering::Relaxed);
+    }
+
+    /// Sets the JS-bound inbox length at which the inbox counts as
+    /// saturated. Zero disables backpressure.
+    pub fn set_backpressure_threshold(&self, threshold: usize) {
+        self.backpressure_threshold.store(threshold, Ordering::Relaxed);
+    }
+
+    /// Returns whether the JS-bound inbox has reached the backpressure
+    /// threshold.
+    pub fn is_saturated(&self) -> bool {
+        match self.backpressure_threshold.load(Ordering::Relaxed) {
+            0 => false,
+            threshold => {
+                self.node_inbox
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner)
+                    .len()
+                    >= threshold
+            }
+        }
+    }
+
+    /// Counts one emission discarded under backpressure.
+    pub fn record_drop(&self) {
+        self.dropped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the current inbox lengths, and the peaks and drop count
+    /// accumulated since the last call with `reset_peaks`.
+    pub fn queue_depths(&self, reset_peaks: bool) -> QueueDepths {
+        let glib = self
+            .glib_inbox
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .len();
+        let node = self
+            .node_inbox
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .len();
+        let (glib_peak, node_peak, dropped) = if reset_peaks {
+            (
+                self.glib_peak.swap(glib, Ordering::Relaxed),
+                self.node_peak.swap(node, Ordering::Relaxed),
+                self.dropped.swap(0, Ordering::Relaxed),
+            )
+        } else {
+            (
+                self.glib_peak.load(Ordering::Relaxed),
+                self.node_peak.load(Ordering::Relaxed),
+                self.dropped.load(Ordering::Relaxed),
+            )
+        };
+
+        QueueDepths {
+            glib,
+            node,
+            glib_peak: glib_peak.max(glib),
+            node_peak: node_peak.max(node),
+            dropped,
+        }
+    }
+
+    /// Returns whether tasks are waiting in the `GLib` inbox.
+    pub fn has_glib_pending(&self) -> bool {
+        !self
+            .glib_inbox
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .is_empty()
+    }
+
+    fn has_node_pending(&self) -> bool {
+        !self
+            .node_inbox
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .is_empty()
+    }
+
     /// Increments the freeze depth. Returns true if this was the outermost call.
     pub fn freeze(&self) -> bool {
         self.freeze_depth.fetch_add(1, Ordering::AcqRel) == 0
@@ -149,21 +416,26 @@ impl Mailbox {
     pub fn run_freeze_loop(&self) {
         self.freeze_loop_active.store(true, Ordering::Release);
         loop {
-            self.dispatch_pending();
+            self.drain_glib(usize::MAX);
             if self.freeze_depth.load(Ordering::Acquire) == 0 {
                 break;
             }
             self.freeze_wake.wait();
         }
         self.freeze_loop_active.store(false, Ordering::Release);
-        self.dispatch_pending();
+        self.drain_glib(usize::MAX);
     }
 
     fn push_glib_task(&self, task: GlibTask) {
-        self.glib_inbox
-            .lock()
-            .unwrap_or_else(std::sync::PoisonError::into_inner)
-            .push_back(task);
+        let depth = {
+            let mut inbox = self
+                .glib_inbox
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            inbox.push_back(task);
+            inbox.len()
+        };
+        self.glib_peak.fetch_max(depth, Ordering::Relaxed);
         if self.freeze_loop_active.load(Ordering::Acquire) {
             self.freeze_wake.notify();
         }
@@ -177,19 +449,28 @@ impl Mailbox {
             .pop_front()
     }
 
-    fn push_node_callback(&self, callback: NodeCallback) {
-        self.node_inbox
-            .lock()
-            .unwrap_or_else(std::sync::PoisonError::into_inner)
-            .push_back(callback);
+    fn push_node_task(&self, priority: Priority, task: NodeTask) {
+        let depth = {
+            let mut inbox = self
+                .node_inbox
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            inbox.push(priority, task);
+            inbox.len()
+        };
+        self.node_peak.fetch_max(depth, Ordering::Relaxed);
         self.wake_js.notify();
+
+        if let Some(tsfn) = self.wake_js_tsfn.get() {
+            tsfn.call((), ThreadsafeFunctionCallMode::NonBlocking);
+        }
     }
 
-    fn pop_node_callback(&self) -> Option<NodeCallback> {
+    fn pop_node_task(&self) -> Option<NodeTask> {
         self.node_inbox
             .lock()
             .unwrap_or_else(std::sync::PoisonError::into_inner)
-            .pop_front()
+            .pop()
     }
 
     /// Pushes a fire-and-forget task onto the `GLib` inbox. The task runs on the
@@ -210,6 +491,42 @@ impl Mailbox {
             return;
         }
 
+        Self::arm_glib_idle();
+    }
+
+    /// Schedules `task` to run on the `GLib` thread after `delay`. The
+    /// returned handle cancels the task if it has not started yet.
+    ///
+    /// The timer is armed from the `GLib` inbox, so `delay` counts from
+    /// when that inbox is next drained.
+    pub fn schedule_glib_delayed<F>(&self, delay: Duration, task: F) -> ScheduledTask
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let handle = ScheduledTask::default();
+        let state = Arc::clone(&handle.state);
+
+        self.schedule_glib(move || {
+            if state.cancelled.load(Ordering::Acquire) {
+                return;
+            }
+            let timer_state = Arc::clone(&state);
+            let source = glib::timeout_add_once(delay, move || {
+                timer_state.take_source();
+                if !timer_state.cancelled.load(Ordering::Acquire) {
+                    task();
+                }
+            });
+            *state
+                .source
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(source);
+        });
+
+        handle
+    }
+
+    fn arm_glib_idle() {
         glib::idle_add_full(glib::Priority::HIGH_IDLE, || {
             Self::global().dispatch_pending();
             glib::ControlFlow::Break
@@ -227,21 +544,55 @@ impl Mailbox {
         self.wake_js.notify();
     }
 
-    /// Drains all queued `GLib` tasks. Returns whether any were executed.
-    /// Intended to run on the `GLib` thread.
+    /// Wakes the `GLib` thread if it is parked in [`Self::park_glib`].
+    pub fn notify_glib(&self) {
+        self.wake_glib.notify();
+    }
+
+    /// Parks the `GLib` thread until a task is queued for it,
+    /// [`Self::notify_glib`] is called, or `timeout` elapses. Returns whether
+    /// it was woken before the timeout.
+    ///
+    /// Shares the wake signal with the wait loop in
+    /// [`Self::invoke_node_and_wait`], which is safe because both only ever
+    /// run on the `GLib` thread.
+    pub fn park_glib(&self, timeout: Duration) -> bool {
+        self.wake_glib.wait_timeout(timeout)
+    }
+
+    /// Drains queued `GLib` tasks, up to the `GLib` budget. Returns whether
+    /// any were executed. Intended to run on the `GLib` thread.
+    ///
+    /// If the budget leaves tasks behind, an idle source is re-armed so the
+    /// main loop picks them up after its next iteration.
     pub fn dispatch_pending(&self) -> bool {
-        let mut dispatched = false;
+        let (dispatched, more) = self.drain_glib(budget_limit(&self.glib_budget));
+
+        if more && !self.freeze_loop_active.load(Ordering::Acquire) {
+            Self::arm_glib_idle();
+        }
 
-        while let Some(task) = self.pop_glib_task() {
+        dispatched
+    }
+
+    /// Runs up to `limit` `GLib` tasks. Returns whether any ran and whether
+    /// the limit left tasks queued.
+    fn drain_glib(&self, limit: usize) -> (bool, bool) {
+        let mut count = 0;
+
+        while count < limit {
+            let Some(task) = self.pop_glib_task() else {
+                break;
+            };
             task();
-            dispatched = true;
+            count += 1;
         }
 
-        if dispatched {
+        if count > 0 {
             self.wake_js.notify();
         }
 
-        dispatched
+        (count > 0, count == limit && self.has_glib_pending())
     }
 
     /// Schedules a task on the `GLib` thread and blocks the JS thread until the
@@ -271,17 +622,26 @@ impl Mailbox {
     /// tasks via [`Self::schedule_glib`] and want fine-grained control over
     /// what value the `GLib` task signals back through (for example, the
     /// freeze loop signals readiness mid-execution).
+    ///
+    /// Only the JS thread that owns the runtime services the node inbox.
+    /// Other JS threads (Node `worker_threads`) block on `rx` alone, since
+    /// node callbacks must run against the owner's `Env`.
     pub fn wait_for_glib_result<R>(
         &self,
         env: Env,
         rx: &mpsc::Receiver<R>,
     ) -> Result<R, GlibDisconnectedError> {
+        if !JsThread::global().is_current() {
+            return rx.recv().map_err(|_| GlibDisconnectedError);
+        }
+
         loop {
-            self.process_node_pending(env);
+            let more = self.drain_node(env, budget_limit(&self.node_budget));
 
             match rx.try_recv() {
                 Ok(result) => return Ok(result),
                 Err(mpsc::TryRecvError::Disconnected) => return Err(GlibDisconnectedError),
+                Err(mpsc::TryRecvError::Empty) if more => {}
                 Err(mpsc::TryRecvError::Empty) => self.wake_js.wait(),
             }
         }
@@ -296,71 +656,170 @@ impl Mailbox {
         callback: &Arc<JsCallbackRef>,
         args: Vec<Value>,
         capture_result: bool,
+    ) -> anyhow::Result<Value> {
+        self.invoke_node_with_priority(callback, args, capture_result, Priority::Default)
+    }
+
+    /// Like [`Self::invoke_node_and_wait`], queueing the call in the
+    /// `priority` lane.
+    pub fn invoke_node_with_priority(
+        &self,
+        callback: &Arc<JsCallbackRef>,
+        args: Vec<Value>,
+        capture_result: bool,
+        priority: Priority,
     ) -> anyhow::Result<Value> {
         let (tx, rx) = mpsc::channel();
 
-        self.push_node_callback(NodeCallback {
+        let task = NodeTask::Invoke(NodeCallback {
             callback: callback.clone(),
             args,
             capture_result,
+            pool: None,
             result_tx: tx,
         });
+        self.push_node_task(priority, task);
 
-        if let Some(tsfn) = self.wake_js_tsfn.get() {
-            tsfn.call((), ThreadsafeFunctionCallMode::NonBlocking);
-        }
+        self.wait_for_node_result(&rx)
+    }
+
+    /// Like [`Self::invoke_node_with_priority`], but returns the argument
+    /// vector to `pool` after the call instead of freeing it.
+    pub fn invoke_node_pooled(
+        &self,
+        callback: &Arc<JsCallbackRef>,
+        args: Vec<Value>,
+        capture_result: bool,
+        pool: &Arc<ArgPool>,
+        priority: Priority,
+    ) -> anyhow::Result<Value> {
+        let (tx, rx) = mpsc::channel();
+
+        let task = NodeTask::Invoke(NodeCallback {
+            callback: callback.clone(),
+            args,
+            capture_result,
+            pool: Some(Arc::clone(pool)),
+            result_tx: tx,
+        });
+        self.push_node_task(priority, task);
 
         self.wait_for_node_result(&rx)
     }
 
+    /// Queues a task for the JS thread without blocking the caller. Dropped
+    /// silently once the mailbox is stopped.
+    pub fn post_node<F>(&self, task: F)
+    where
+        F: FnOnce(Env) + Send + 'static,
+    {
+        if self.stopped.load(Ordering::Acquire) {
+            return;
+        }
+
+        self.push_node_task(Priority::Default, NodeTask::Detached(Box::new(task)));
+    }
+
+    /// Queues a coalesced callback for delivery on the JS thread without
+    /// blocking the caller. Called by [`CoalescedCallback::submit`] only when
+    /// its slot transitions from idle to pending, so each connection has at
+    /// most one task in the inbox at a time.
+    pub fn post_coalesced(&self, callback: Arc<CoalescedCallback>) {
+        if self.stopped.load(Ordering::Acquire) {
+            return;
+        }
+
+        self.push_node_task(callback.priority(), NodeTask::Coalesced(callback));
+    }
+
     fn wait_for_node_result(
         &self,
         rx: &mpsc::Receiver<anyhow::Result<Value>>,
     ) -> anyhow::Result<Value> {
         loop {
-            self.dispatch_pending();
+            let (_, more) = self.drain_glib(budget_limit(&self.glib_budget));
 
             match rx.try_recv() {
                 Ok(result) => return result,
                 Err(mpsc::TryRecvError::Disconnected) => {
                     return Err(anyhow::anyhow!("JS callback channel disconnected"));
                 }
+                Err(mpsc::TryRecvError::Empty) if more => {}
                 Err(mpsc::TryRecvError::Empty) => self.wake_glib.wait(),
             }
         }
     }
 
-    /// Drains all currently-queued node callbacks and invokes them in JS.
-    /// Intended to run on the JS thread, either from the wake TSFN scheduled by
-    /// [`Self::invoke_node_and_wait`] or from the wait loop in
-    /// [`Self::wait_for_glib_result`].
+    /// Drains queued node callbacks, up to the JS budget, and invokes them
+    /// in JS. Runs on the JS thread from the wake TSFN scheduled by
+    /// [`Self::invoke_node_and_wait`]. If the budget leaves callbacks queued,
+    /// the TSFN is posted again so other Node work runs in between.
     pub fn process_node_pending(&self, env: Env) {
-        while let Some(pending) = self.pop_node_callback() {
+        if self.drain_node(env, budget_limit(&self.node_budget))
+            && let Some(tsfn) = self.wake_js_tsfn.get()
+        {
+            tsfn.call((), ThreadsafeFunctionCallMode::NonBlocking);
+        }
+    }
+
+    /// Runs up to `limit` node callbacks. Returns whether the limit left
+    /// callbacks queued.
+    fn drain_node(&self, env: Env, limit: usize) -> bool {
+        let mut count = 0;
+
+        while count < limit {
+            let Some(task) = self.pop_node_task() else {
+                break;
+            };
+            count += 1;
+            let pending = match task {
+                NodeTask::Invoke(pending) => pending,
+                NodeTask::Coalesced(coalesced) => {
+                    if let Some(mut args) = coalesced.take_pending()
+                        && let Err(e) =
+                            Self::execute_callback(env, coalesced.js_func(), &mut args, false)
+                    {
+                        NativeErrorReporter::global()
+                            .report(&e.context("coalesced callback: JS callback error"));
+                    }
+                    continue;
+                }
+                NodeTask::Detached(task) => {
+                    task(env);
+                    continue;
+                }
+            };
             let NodeCallback {
                 callback,
-                args,
+                mut args,
                 capture_result,
+                pool,
                 result_tx,
             } = pending;
-            let result = Self::execute_callback(env, &callback, args, capture_result);
+            let result = Self::execute_callback(env, &callback, &mut args, capture_result);
+            if let Some(pool) = pool {
+                pool.release(args);
+            }
             if result_tx.send(result).is_err() {
                 NativeErrorReporter::global()
                     .report_str("Node callback completed but result channel was closed");
             }
             self.wake_glib.notify();
         }
+
+        count == limit && self.has_node_pending()
     }
 
     fn execute_callback(
         env: Env,
         callback: &Arc<JsCallbackRef>,
-        args: Vec<Value>,
+        args: &mut Vec<Value>,
         capture_result: bool,
     ) -> anyhow::Result<Value> {
         use napi::sys;
 
         let js_args: Vec<Unknown<'_>> = args
-            .into_iter()
+            .drain(..)
             .map(|v| {
                 v.to_js_value(&env)
                     .map_err(|e| anyhow::anyhow!("converting callback arg: {e}"))
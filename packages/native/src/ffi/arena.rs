@@ -0,0 +1,82 @@
+//! Per-call ownership of encoded FFI arguments.
+
+use anyhow::Context as _;
+use libffi::middle as libffi;
+
+use super::FfiValue;
+use crate::arg::Arg;
+use crate::types::{FfiEncoder as _, Type};
+
+/// Owns every encoded argument of one in-flight call.
+///
+/// Encoding produces [`FfiValue`]s whose [`super::FfiStorage`] backs the raw
+/// pointers handed to libffi: `CString`s, element `Vec`s, `GList`s, buffers and
+/// so on. The arena keeps all of them alive together until it is dropped,
+/// which callers must only do once the native function has returned and any
+/// out-parameters have been decoded. This holds for both blocking calls and
+/// `callAsync`, where the JS frame that issued the call has long since
+/// returned by the time the `GLib` thread runs it.
+pub struct CallArena {
+    values: Vec<FfiValue>,
+}
+
+impl std::fmt::Debug for CallArena {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CallArena")
+            .field("len", &self.values.len())
+            .finish()
+    }
+}
+
+impl CallArena {
+    /// Encodes `args` in order. `symbol` is only used for error context.
+    pub fn encode(args: &[Arg], symbol: &str) -> anyhow::Result<Self> {
+        let values = args
+            .iter()
+            .enumerate()
+            .map(|(i, arg)| {
+                arg.ty
+                    .encode(&arg.value, arg.optional)
+                    .with_context(|| format!("encoding arg {i} of {symbol}"))
+            })
+            .collect::<anyhow::Result<Vec<FfiValue>>>()?;
+
+        for (arg, value) in args.iter().zip(&values) {
+            if let Type::Callback(callback_type) = &arg.ty
+                && let Some(watch_arg) = callback_type.watch_arg
+            {
+                callback_type
+                    .watch_object(value, values.get(watch_arg))
+                    .with_context(|| format!("watching closure arg of {symbol}"))?;
+            }
+        }
+
+        Ok(Self { values })
+    }
+
+    #[must_use]
+    pub fn values(&self) -> &[FfiValue] {
+        &self.values
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Builds the libffi argument list. The returned arguments borrow from the
+    /// arena, so the borrow checker ties their lifetime to it.
+    #[must_use]
+    pub fn libffi_args(&self) -> Vec<libffi::Arg<'_>> {
+        let mut ffi_args = Vec::with_capacity(self.values.len() + 1);
+        for value in &self.values {
+            value.append_libffi_args(&mut ffi_args);
+        }
+        ffi_args
+    }
+}
@@ -8,7 +8,9 @@
 //!
 //! - [`FfiValue`]: Raw FFI-compatible value representation
 //! - [`FfiStorage`]: Temporary storage for FFI call arguments
+//! - [`CallArena`]: Keeps a call's encoded arguments alive until it completes
 
+mod arena;
 mod storage;
 mod value;
 
@@ -16,6 +18,7 @@ pub use storage::{
     FfiStorage, FfiStorageKind, GArrayData, GByteArrayData, GListData, GSListData, HashTableData,
     StringGListData, StringGSListData,
 };
+pub use arena::CallArena;
 pub use value::{FfiValue, TrampolineValue};
 
 use crate::arg::Arg;
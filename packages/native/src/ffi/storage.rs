@@ -1,6 +1,6 @@
 use std::ffi::c_void;
 
-use gtk4::glib;
+use gtk4::glib::{self, translate::FromGlibPtrFull as _};
 
 use crate::types::IntegerKind;
 
@@ -81,10 +81,19 @@ pub enum FfiStorageKind {
     CString(std::ffi::CString),
     GArray(GArrayData),
     GByteArray(GByteArrayData),
+    /// A `GString` created for a call, freed with its contents on drop.
+    GString(*mut glib::ffi::GString),
     Buffer(Vec<u8>),
     BoxedValue(Box<super::FfiValue>),
     PtrStorage(Box<*mut c_void>),
     HashTable(HashTableData),
+    /// The slot of a `GError**` argument. An error still in it is freed on
+    /// drop.
+    GError(Box<*mut glib::ffi::GError>),
+    /// A `GBytes` created for a call, released on drop.
+    GBytes(glib::Bytes),
+    /// A `GValue` created for a call, unset on drop.
+    GValue(Box<glib::Value>),
 }
 
 impl FfiStorage {
@@ -165,6 +174,16 @@ impl FfiStorage {
         }
     }
 
+    /// Takes the error a call left in a `GError**` slot, if any.
+    pub fn take_error(&self) -> Option<glib::Error> {
+        if !matches!(self.kind, FfiStorageKind::GError(_)) {
+            return None;
+        }
+        let slot = self.ptr.cast::<*mut glib::ffi::GError>();
+        let error = unsafe { std::ptr::replace(slot, std::ptr::null_mut()) };
+        (!error.is_null()).then(|| unsafe { glib::Error::from_glib_full(error) })
+    }
+
     pub fn closure(closure_ptr: *mut glib::gobject_ffi::GClosure) -> Self {
         Self {
             ptr: closure_ptr as *mut c_void,
@@ -248,8 +267,16 @@ impl Drop for FfiStorage {
             FfiStorageKind::GSList(data) => drop_gslist(data),
             FfiStorageKind::GArray(data) => drop_garray(data),
             FfiStorageKind::GByteArray(data) => drop_gbyte_array(data),
+            FfiStorageKind::GString(gstring) => unsafe {
+                glib::ffi::g_string_free(*gstring, glib::ffi::GTRUE);
+            },
             FfiStorageKind::StringGList(data) => Self::drop_string_glist(data),
             FfiStorageKind::StringGSList(data) => Self::drop_string_gslist(data),
+            FfiStorageKind::GError(slot) => {
+                if !slot.is_null() {
+                    unsafe { glib::ffi::g_error_free(**slot) };
+                }
+            }
             FfiStorageKind::Unit
             | FfiStorageKind::U8Vec(_)
             | FfiStorageKind::I8Vec(_)
@@ -266,7 +293,9 @@ impl Drop for FfiStorage {
             | FfiStorageKind::CString(_)
             | FfiStorageKind::Buffer(_)
             | FfiStorageKind::BoxedValue(_)
-            | FfiStorageKind::PtrStorage(_) => {}
+            | FfiStorageKind::PtrStorage(_)
+            | FfiStorageKind::GBytes(_)
+            | FfiStorageKind::GValue(_) => {}
         }
     }
 }
@@ -1,8 +1,14 @@
 use std::ffi::CStr;
+use std::sync::{Arc, Mutex};
 
 use gtk4::glib;
 
+use crate::dispatch::{Mailbox, Priority};
 use crate::error_reporter::NativeErrorReporter;
+use crate::value::{JsCallbackRef, Value};
+
+/// JS callback told about a fatal message before `GLib` aborts the process.
+static FATAL_CALLBACK: Mutex<Option<Arc<JsCallbackRef>>> = Mutex::new(None);
 
 #[derive(Debug)]
 pub struct GlibLogHandler;
@@ -13,6 +19,48 @@ impl GlibLogHandler {
             glib::ffi::g_log_set_default_handler(Some(log_handler), std::ptr::null_mut());
         }
     }
+
+    /// Sets the callback invoked with the domain, level and message of a
+    /// fatal log message, or clears it with `None`.
+    ///
+    /// Messages are fatal at `G_LOG_LEVEL_ERROR`, and at lower levels made
+    /// fatal by `G_DEBUG=fatal-criticals` and the like. The callback runs
+    /// once, on the JS thread, while the `GLib` thread waits for it, so it
+    /// can still make FFI calls and stop the main loop. The process aborts
+    /// when it returns.
+    pub fn set_fatal_callback(callback: Option<Arc<JsCallbackRef>>) {
+        *FATAL_CALLBACK
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = callback;
+    }
+}
+
+/// Hands a fatal message to the JS callback, if one is set and the message
+/// was logged on the `GLib` thread, whose wait loop keeps serving the FFI
+/// calls the callback makes.
+fn notify_fatal(domain: &str, level: &str, message: &str) {
+    if Mailbox::global().is_stopped() || !glib::MainContext::default().is_owner() {
+        return;
+    }
+
+    let callback = FATAL_CALLBACK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+    let Some(callback) = callback else {
+        return;
+    };
+
+    let args = vec![
+        Value::String(domain.to_owned()),
+        Value::String(level.to_owned()),
+        Value::String(message.to_owned()),
+    ];
+    if let Err(err) = Mailbox::global()
+        .invoke_node_with_priority(&callback, args, false, Priority::High)
+    {
+        eprintln!("[gtkx] Fatal error callback failed: {err:#}");
+    }
 }
 
 unsafe extern "C" fn log_handler(
@@ -57,5 +105,14 @@ unsafe extern "C" fn log_handler(
         "CRITICAL"
     };
 
+    // GLib aborts once a fatal message's handler returns, before a queued
+    // report could reach JS, so it is printed and handed to the fatal
+    // callback directly.
+    if (level & glib::ffi::G_LOG_FLAG_FATAL) != 0 {
+        eprintln!("{domain_str}-{level_str}: {message_str}");
+        notify_fatal(domain_str, level_str, message_str);
+        return;
+    }
+
     NativeErrorReporter::global().report_str(&format!("{domain_str}-{level_str}: {message_str}"));
 }
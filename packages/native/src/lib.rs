@@ -7,15 +7,49 @@
 //!
 //! | Function | Purpose |
 //! |----------|---------|
-//! | `start` | Spawn the `GLib` thread, run a `MainLoop`, and return its handle |
+//! | `start` | Spawn the `GLib` thread (or attach to a host loop), run a `MainLoop`, and return its handle |
 //! | `stop` | Quit the `GLib` main loop and drain pending finalizers |
 //! | `call` | Execute FFI function call to native library |
+//! | `callAsync` | Execute FFI function call without blocking the JS thread, optionally cancellable |
+//! | `createCancelToken` | Create a token that cancels an asynchronous call |
+//! | `cancelCall` | Cancel an asynchronous call and the `GCancellable` it was given |
+//! | `callHint` | Queue a `void fn(gpointer)` call without descriptors or waiting |
+//! | `connect` | Connect a callback to a signal, with its detail resolved natively, and return the handler ID |
+//! | `disconnect` | Disconnect a signal handler by ID |
+//! | `blockHandler` | Block a signal handler by ID |
+//! | `unblockHandler` | Unblock a signal handler by ID |
+//! | `listSymbols` | List the functions a library exports, optionally by name prefix |
+//! | `configureLibrary` | Set the search paths, file names and `dlopen` flags of a library |
+//! | `unloadLibrary` | Drop a library's handle so it is loaded again with its current options |
+//! | `configureBundle` | Load libraries and GTK data from a relocatable application bundle |
 //! | `alloc` | Allocate memory for boxed types |
-//! | `read` | Read field from boxed/struct memory |
-//! | `write` | Write primitive field to boxed memory (constructor initialization) |
+//! | `copy` | Duplicate a boxed value or take a new reference on an object |
+//! | `read` | Read field from boxed/struct memory by offset or layout field name, optionally in an explicit byte order |
+//! | `write` | Write field to boxed/struct memory by offset or layout field name, optionally in an explicit byte order |
+//! | `indexHandle` | Borrow a handle to one element of an array of structs, with bounds checks |
+//! | `flagsToNicks` | Convert a flags mask to the nicks of its set values |
+//! | `flagsFromNicks` | Build a flags mask from value nicks |
+//! | `decodeEvent` | Read the type, position, device, key or button and modifiers of a `GdkEvent` at once |
+//! | `listEnumValues` | List the nick, name and value of each member of an enum or flags type |
+//! | `registerTypes` | Register type metadata, e.g. from GIR, that descriptors can then omit |
 //! | `getNativeId` | Get internal handle ID for managed object |
+//! | `importForeignObject` | Adopt a `GObject` pointer created by another addon |
+//! | `exportPointer` | Expose a handle's raw pointer to another addon |
+//! | `wrapExternalPointer` | Wrap a raw pointer in a non-owning handle that is never freed |
+//! | `isExternalPointer` | Tell handles from `wrapExternalPointer` apart from managed ones |
+//! | `mapFile` | Map a file into memory with `GMappedFile` |
+//! | `mappedFileBuffer` | View a mapped file's contents as a `Buffer` without copying |
 //! | `freeze` | Freeze tick callbacks during React commit (prevents intermediate repaints) |
 //! | `unfreeze` | Unfreeze tick callbacks and allow a single repaint |
+//! | `setDispatchBudgets` | Limit how many queued tasks each drain runs before yielding |
+//! | `getQueueDepths` | Report current and peak lengths of the cross-thread queues |
+//! | `setBackpressureThreshold` | Set the JS queue length at which `overflow` closures shed emissions |
+//! | `onIdle` | Call back once the `GLib` main loop has been idle for a given time |
+//! | `cancelIdle` | Cancel a pending `onIdle` watch |
+//! | `onFatalError` | Call back before a fatal `GLib` log message aborts the process |
+//! | `setLoopPacing` | Hold `GLib` main-loop iterations to an interval or an external tick |
+//! | `pumpLoop` | Release one iteration held by external pacing |
+//! | `getLoopMetrics` | Report per-iteration dispatch and poll timing |
 //!
 //! ## Architecture
 //!
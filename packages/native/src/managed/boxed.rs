@@ -131,6 +131,7 @@ impl Clone for Boxed {
 impl Drop for Boxed {
     fn drop(&mut self) {
         if self.owned && !self.ptr.is_null() {
+            super::dependents::release(self.ptr as usize);
             unsafe {
                 match self.gtype {
                     Some(gtype) => {
@@ -0,0 +1,137 @@
+//! Parent-child borrow tracking for handles.
+//!
+//! A borrowed struct pointer returned by a method usually points into the
+//! instance the method was called on, such as a `GdkRectangle` inside an
+//! event. The child handle does not own that memory, so it must not be used
+//! once the parent is gone. Each borrowed child gets a [`Lifeline`]
+//! registered under its parent's pointer, and native code refuses handles
+//! whose lifeline is cut instead of reading freed memory.
+//!
+//! Lifelines are cut when the parent's memory is actually freed, not when
+//! some handle to it is dropped: a weak reference reports the finalization
+//! of a `GObject` parent, and an owned boxed parent releases its children
+//! when it frees its copy. Dropping one of several handles to the same
+//! parent, or a temporary copy of it, leaves the children valid.
+//!
+//! Callback arguments that point at callback-local memory use a scoped
+//! lifeline instead ([`Lifeline::scope`]), which the trampoline cuts as soon
+//! as the JS callback returns.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock, Weak};
+
+use gtk4::glib::{self, gobject_ffi};
+
+/// What a borrowed child's parent pointer refers to, which decides how its
+/// release is detected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParentKind {
+    /// A `GObject`, released when it is finalized.
+    Object,
+    /// Boxed or plain struct memory, released when its owned copy is freed.
+    Boxed,
+}
+
+/// Liveness flag shared between a borrowed handle and the registry.
+#[derive(Debug)]
+pub struct Lifeline {
+    alive: AtomicBool,
+}
+
+impl Lifeline {
+    fn new() -> Arc<Self> {
+        Arc::new(Self {
+            alive: AtomicBool::new(true),
+        })
+    }
+
+    /// Creates an unregistered lifeline that only [`Self::cut`] ends.
+    #[must_use]
+    pub fn scope() -> Arc<Self> {
+        Self::new()
+    }
+
+    #[must_use]
+    pub fn is_alive(&self) -> bool {
+        self.alive.load(Ordering::Acquire)
+    }
+
+    /// Invalidates every handle holding this lifeline.
+    pub fn cut(&self) {
+        self.alive.store(false, Ordering::Release);
+    }
+}
+
+type Registry = Mutex<HashMap<usize, Vec<Weak<Lifeline>>>>;
+
+static REGISTRY: OnceLock<Registry> = OnceLock::new();
+
+/// Number of parents with registered children, checked before locking so
+/// handle drops stay cheap while nothing is borrowed.
+static PARENTS: AtomicUsize = AtomicUsize::new(0);
+
+fn registry() -> &'static Registry {
+    REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+unsafe extern "C" fn parent_finalized(
+    _data: glib::ffi::gpointer,
+    object: *mut gobject_ffi::GObject,
+) {
+    release(object as usize);
+}
+
+/// Registers a child borrowed from `parent` and returns its lifeline.
+///
+/// The first child of a `GObject` parent adds a weak reference that
+/// releases the children once the parent is finalized.
+pub(super) fn register(parent: usize, kind: ParentKind) -> Arc<Lifeline> {
+    let lifeline = Lifeline::new();
+    let mut first_child = false;
+
+    {
+        let mut registry = registry()
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let children = registry.entry(parent).or_insert_with(|| {
+            PARENTS.fetch_add(1, Ordering::Relaxed);
+            first_child = true;
+            Vec::new()
+        });
+        children.retain(|child| child.strong_count() > 0);
+        children.push(Arc::downgrade(&lifeline));
+    }
+
+    if first_child && kind == ParentKind::Object {
+        unsafe {
+            gobject_ffi::g_object_weak_ref(
+                parent as *mut gobject_ffi::GObject,
+                Some(parent_finalized),
+                std::ptr::null_mut(),
+            );
+        }
+    }
+
+    lifeline
+}
+
+/// Cuts the lifelines of every child borrowed from `parent`, whose memory is
+/// being freed.
+pub(super) fn release(parent: usize) {
+    if PARENTS.load(Ordering::Relaxed) == 0 {
+        return;
+    }
+
+    let children = registry()
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .remove(&parent);
+
+    if let Some(children) = children {
+        PARENTS.fetch_sub(1, Ordering::Relaxed);
+        for child in children.iter().filter_map(Weak::upgrade) {
+            child.cut();
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! Batched release of handles dropped off the `GLib` thread.
+//!
+//! A large JS garbage collection can drop thousands of handles at once.
+//! Rather than scheduling one `GLib` task per handle, each drop pushes its
+//! value onto a lock-free stack, and only the push that finds the stack
+//! unarmed schedules an idle source. That source releases the whole batch on
+//! the next main-loop iteration, so a GC costs one wakeup instead of one per
+//! handle. Release order within a batch is unspecified.
+
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+
+use gtk4::glib;
+use send_wrapper::SendWrapper;
+
+use super::NativeValue;
+
+struct Node {
+    value: SendWrapper<NativeValue>,
+    next: *mut Node,
+}
+
+pub(super) struct FinalizeQueue {
+    head: AtomicPtr<Node>,
+    armed: AtomicBool,
+}
+
+static QUEUE: FinalizeQueue = FinalizeQueue {
+    head: AtomicPtr::new(ptr::null_mut()),
+    armed: AtomicBool::new(false),
+};
+
+impl FinalizeQueue {
+    pub(super) fn global() -> &'static Self {
+        &QUEUE
+    }
+
+    /// Queues `value` for release on the `GLib` thread. Callable from any
+    /// thread.
+    pub(super) fn push(&self, value: SendWrapper<NativeValue>) {
+        let node = Box::into_raw(Box::new(Node {
+            value,
+            next: ptr::null_mut(),
+        }));
+
+        let mut head = self.head.load(Ordering::Relaxed);
+        loop {
+            unsafe { (*node).next = head };
+            match self
+                .head
+                .compare_exchange_weak(head, node, Ordering::Release, Ordering::Relaxed)
+            {
+                Ok(_) => break,
+                Err(current) => head = current,
+            }
+        }
+
+        if !self.armed.swap(true, Ordering::AcqRel) {
+            glib::idle_add_once(|| {
+                Self::global().drain();
+            });
+        }
+    }
+
+    /// Releases every queued value and returns how many there were. Must
+    /// run on the `GLib` thread.
+    pub(super) fn drain(&self) -> usize {
+        self.armed.store(false, Ordering::Release);
+
+        let mut node = self.head.swap(ptr::null_mut(), Ordering::Acquire);
+        let mut released = 0;
+        while !node.is_null() {
+            let boxed = unsafe { Box::from_raw(node) };
+            node = boxed.next;
+            drop(boxed);
+            released += 1;
+        }
+        released
+    }
+}
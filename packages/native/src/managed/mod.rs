@@ -20,27 +20,51 @@
 //!    `GLib` thread.
 //! 3. [`NativeHandle`] is wrapped in `napi::bindgen_prelude::External` and returned to JavaScript.
 //! 4. When JS garbage collects the external value, napi-rs calls the
-//!    [`NativeHandle`]'s [`Drop`] impl, which routes the drop back to the
-//!    `GLib` thread via `glib::idle_add_once`.
+//!    [`NativeHandle`]'s [`Drop`] impl, which pushes the value onto a
+//!    finalize queue released in batches on the `GLib` thread (see
+//!    [`drain_finalize_queue`]).
 //! 5. On the `GLib` thread, the underlying `GObject` ref / boxed copy /
 //!    fundamental unref is released.
 //!
+//! Handles for borrowed struct pointers can be tied to the object or boxed
+//! value they were borrowed from, and are invalidated once its memory is
+//! freed; see [`dependents`].
+//!
+//! Handles created with [`NativeHandle::external`] wrap a pointer supplied by
+//! the caller. They never own the value, so dropping one releases nothing,
+//! and errors about them say so.
+//!
 //! At shutdown ([`Mailbox::is_stopped`]) the handle's value is intentionally
 //! leaked via [`std::mem::forget`] to avoid post-shutdown teardown crashes.
 
 mod boxed;
+pub mod dependents;
+mod finalize;
 mod fundamental;
 
 pub use boxed::Boxed;
 pub use fundamental::{Fundamental, RefFn, UnrefFn};
 
+use dependents::{Lifeline, ParentKind};
+use finalize::FinalizeQueue;
+
 use std::ffi::c_void;
+use std::sync::Arc;
 
 use gtk4::glib::{self, prelude::ObjectType as _};
 use send_wrapper::SendWrapper;
 
 use crate::dispatch::Mailbox;
 
+/// Releases every handle value queued for finalization, returning how many
+/// were released. Must run on the `GLib` thread.
+///
+/// Batches are normally drained by an idle source armed on the first drop;
+/// shutdown calls this directly so nothing is left behind.
+pub fn drain_finalize_queue() -> usize {
+    FinalizeQueue::global().drain()
+}
+
 /// Owned handle for a managed native value.
 ///
 /// Wraps either an owned [`NativeValue`] (constructed via `From<NativeValue>`)
@@ -51,6 +75,19 @@ use crate::dispatch::Mailbox;
 pub struct NativeHandle {
     ptr: *mut c_void,
     inner: Option<SendWrapper<NativeValue>>,
+    /// How children borrowed from an owned value are released, readable
+    /// from any thread. `None` for borrowed handles and fundamentals, whose
+    /// memory outlives any one reference.
+    kind: Option<ParentKind>,
+    lifeline: Option<Arc<Lifeline>>,
+    external: Option<Arc<ExternalOrigin>>,
+}
+
+/// Provenance of a handle created with [`NativeHandle::external`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExternalOrigin {
+    /// Type name the caller declared for the pointer, used in errors.
+    pub type_name: Option<String>,
 }
 
 // SAFETY: `ptr` is treated as an opaque integer for cross-thread identity
@@ -67,20 +104,24 @@ impl std::fmt::Debug for NativeHandle {
         f.debug_struct("NativeHandle")
             .field("ptr", &self.ptr)
             .field("owned", &self.inner.is_some())
+            .field("external", &self.external)
             .finish_non_exhaustive()
     }
 }
 
 impl From<NativeValue> for NativeHandle {
     fn from(value: NativeValue) -> Self {
-        let ptr = match &value {
-            NativeValue::GObject(obj) => obj.as_ptr() as *mut c_void,
-            NativeValue::Boxed(boxed) => boxed.as_ptr(),
-            NativeValue::Fundamental(fundamental) => fundamental.as_ptr(),
+        let (ptr, kind) = match &value {
+            NativeValue::GObject(obj) => (obj.as_ptr() as *mut c_void, Some(ParentKind::Object)),
+            NativeValue::Boxed(boxed) => (boxed.as_ptr(), Some(ParentKind::Boxed)),
+            NativeValue::Fundamental(fundamental) => (fundamental.as_ptr(), None),
         };
         Self {
             ptr,
             inner: Some(SendWrapper::new(value)),
+            kind,
+            lifeline: None,
+            external: None,
         }
     }
 }
@@ -98,6 +139,9 @@ impl Clone for NativeHandle {
         Self {
             ptr: self.ptr,
             inner: self.inner.clone(),
+            kind: self.kind,
+            lifeline: self.lifeline.clone(),
+            external: self.external.clone(),
         }
     }
 }
@@ -111,7 +155,73 @@ impl NativeHandle {
     /// [`SendWrapper`] and is therefore safe to clone or drop on any thread.
     #[must_use]
     pub fn borrowed(ptr: *mut c_void) -> Self {
-        Self { ptr, inner: None }
+        Self {
+            ptr,
+            inner: None,
+            kind: None,
+            lifeline: None,
+            external: None,
+        }
+    }
+
+    /// Constructs a non-owning handle for a pointer obtained outside gtkx,
+    /// such as from another native library.
+    ///
+    /// The pointer must be non-null. Nothing is released when the handle is
+    /// dropped, so the caller must keep the value alive while it is in use.
+    pub fn external(ptr: *mut c_void, type_name: Option<String>) -> anyhow::Result<Self> {
+        if ptr.is_null() {
+            anyhow::bail!("External pointer is null");
+        }
+        Ok(Self {
+            ptr,
+            inner: None,
+            kind: None,
+            lifeline: None,
+            external: Some(Arc::new(ExternalOrigin { type_name })),
+        })
+    }
+
+    /// Constructs a borrowed handle for `ptr`, which points into memory that
+    /// `parent` refers to, such as an element of an array.
+    ///
+    /// The handle stays valid as long as `parent` would: until the memory
+    /// of an owning `parent` is freed, or for as long as a borrowed `parent`
+    /// is alive. Elements of an external pointer are external too.
+    #[must_use]
+    pub fn element_of(parent: &Self, ptr: *mut c_void) -> Self {
+        let lifeline = match (&parent.lifeline, parent.kind) {
+            (Some(lifeline), _) => Some(Arc::clone(lifeline)),
+            (None, Some(kind)) => Some(dependents::register(parent.ptr as usize, kind)),
+            (None, None) => None,
+        };
+        Self {
+            ptr,
+            inner: None,
+            kind: None,
+            lifeline,
+            external: parent.external.as_ref().map(|_| Arc::default()),
+        }
+    }
+
+    /// Returns the provenance of a handle created with [`Self::external`],
+    /// or `None` for handles gtkx created itself.
+    #[must_use]
+    pub fn external_origin(&self) -> Option<&ExternalOrigin> {
+        self.external.as_deref()
+    }
+
+    /// Describes the handle for error messages, distinguishing external
+    /// pointers from handles gtkx manages.
+    #[must_use]
+    pub fn describe(&self) -> String {
+        match self.external_origin() {
+            Some(ExternalOrigin {
+                type_name: Some(type_name),
+            }) => format!("external pointer {:p} ({type_name})", self.ptr),
+            Some(_) => format!("external pointer {:p}", self.ptr),
+            None => format!("handle {:p}", self.ptr),
+        }
     }
 
     /// Returns the raw native pointer.
@@ -124,6 +234,17 @@ impl NativeHandle {
         self.ptr
     }
 
+    /// Returns the owned value, or `None` for a borrowed handle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle is owned and accessed from a thread other than
+    /// the one that constructed it (normally the `GLib` thread).
+    #[must_use]
+    pub fn value(&self) -> Option<&NativeValue> {
+        self.inner.as_deref()
+    }
+
     /// Returns the raw native pointer reinterpreted as a [`usize`].
     ///
     /// Used by the JS-facing `getNativeId` to expose the pointer value as an
@@ -132,6 +253,65 @@ impl NativeHandle {
     pub fn ptr_as_usize(&self) -> usize {
         self.ptr as usize
     }
+
+    /// Returns whether the handle owns a `GObject`, without engaging the
+    /// [`SendWrapper`] thread check.
+    #[must_use]
+    pub fn is_object(&self) -> bool {
+        matches!(self.kind, Some(ParentKind::Object))
+    }
+
+    /// Ties a borrowed struct handle to `parent`, so it is invalidated once
+    /// the parent's memory is freed. Returns whether the handle was bound;
+    /// handles that own their value are left untouched.
+    ///
+    /// Must run on the thread that constructed the handle.
+    pub fn bind_to_parent(&mut self, parent: *mut c_void, kind: ParentKind) -> bool {
+        if !self.is_view() || parent.is_null() || parent == self.ptr {
+            return false;
+        }
+        self.lifeline = Some(dependents::register(parent as usize, kind));
+        true
+    }
+
+    /// Limits a borrowed struct handle to `scope`, typically the duration
+    /// of a callback. Returns whether the handle was scoped; handles that
+    /// own their value are left untouched.
+    ///
+    /// Must run on the thread that constructed the handle.
+    pub fn scope_to(&mut self, scope: &Arc<Lifeline>) -> bool {
+        if !self.is_view() {
+            return false;
+        }
+        self.lifeline = Some(Arc::clone(scope));
+        true
+    }
+
+    fn is_view(&self) -> bool {
+        matches!(
+            self.value(),
+            Some(NativeValue::Boxed(boxed)) if !boxed.is_owned() && !boxed.as_ptr().is_null()
+        )
+    }
+
+    /// Returns false once the memory a borrowed handle points into has been
+    /// released: its parent was freed or its callback returned.
+    #[must_use]
+    pub fn is_alive(&self) -> bool {
+        self.lifeline.as_deref().is_none_or(Lifeline::is_alive)
+    }
+
+    /// Fails once [`Self::is_alive`] turns false.
+    pub fn ensure_alive(&self) -> anyhow::Result<()> {
+        if !self.is_alive() {
+            anyhow::bail!(
+                "Handle {:p} is no longer valid: the memory it borrows has been released. \
+                 Use copy() to keep a borrowed value beyond its owner or callback",
+                self.ptr
+            );
+        }
+        Ok(())
+    }
 }
 
 impl Drop for NativeHandle {
@@ -144,7 +324,7 @@ impl Drop for NativeHandle {
         } else if Mailbox::global().is_stopped() {
             std::mem::forget(wrapper);
         } else {
-            glib::idle_add_once(move || drop(wrapper));
+            FinalizeQueue::global().push(wrapper);
         }
     }
 }
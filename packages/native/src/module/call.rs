@@ -14,40 +14,150 @@
 //! 6. Convert the result back to a [`Value`] for JavaScript
 //! 7. Update any `Ref` type out-parameters with modified values
 //!
+//! ## Errors
+//!
+//! A `gerror` argument passes an empty `GError**` slot. If the function
+//! reports an error in it, the call throws a JS `Error` with the error's
+//! `message`, its `domain` quark and its `code` instead of returning, and
+//! out-parameters are left untouched.
+//!
+//! ## Asynchronous Calls
+//!
+//! [`call_async`] runs the same request without blocking the JS thread and
+//! returns a promise that settles once the `GLib` thread has executed it.
+//! Arguments are parsed into owned [`Value`]s before scheduling and encoded
+//! into a [`ffi::CallArena`] on the `GLib` thread, so every string, array
+//! and buffer passed to native code stays alive until the call and its
+//! out-parameter decoding have finished. Object, boxed and other handle
+//! arguments are kept alive the same way by a [`HandleRef`] on each.
+//!
+//! ## Cancellation
+//!
+//! An asynchronous call may carry a [`CancelToken`]. Cancelling it through
+//! [`cancel_call`] keeps a call that is still queued from running, and
+//! cancels the `GCancellable` among the call's arguments, if any, so a
+//! running call that honours it returns early. `g_cancellable_cancel` is
+//! thread-safe, so it is called right away on the JS thread instead of
+//! waiting behind the busy `GLib` thread. A cancelled call's promise
+//! rejects instead of delivering a result.
+//!
 //! ## Callbacks
 //!
 //! Special handling is required for callback arguments (`AsyncReady`, Destroy,
 //! `DrawFunc`). These expand to multiple FFI arguments: the callback function
 //! pointer, user data, and optionally a destroy notify.
 
-use std::{ffi::c_void, sync::Arc};
+use std::{
+    ffi::c_void,
+    sync::{Arc, Mutex},
+};
 
 use anyhow::Context as _;
+use gtk4::gio;
+use gtk4::glib::{
+    self,
+    translate::{FromGlibPtrNone as _, IntoGlib as _},
+};
 use libffi::middle as libffi;
-use napi::Env;
+use napi::{Env, JsValue as _};
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 
-use super::handler::{ModuleRequest, RefUpdate, dispatch_request};
+use super::handler::{ModuleRequest, ModuleResponse, RefUpdate, dispatch_request};
 use crate::{
     arg::Arg,
+    dispatch::{GlibDisconnectedError, Mailbox},
     ffi,
+    managed::dependents::ParentKind,
     state::GtkThreadState,
     types::{FfiEncoder as _, Type},
-    value::Value,
+    value::{HandleRef, Value},
 };
 
+/// Ties a borrowed boxed or struct value returned by a method to the
+/// method's instance, which by convention owns the memory the value points
+/// into.
+///
+/// Only calls whose first argument is a `GObject`, boxed or struct instance
+/// are methods in this sense; other borrowed results are left unbound.
+fn bind_borrowed_to_instance(value: &mut Value, ty: &Type, args: &[Arg]) {
+    let borrowed_struct = match ty {
+        Type::Ref(ref_type) => return bind_borrowed_to_instance(value, &ref_type.inner_type, args),
+        Type::Boxed(boxed) => boxed.ownership.is_borrowed(),
+        Type::Struct(struct_type) => struct_type.ownership.is_borrowed(),
+        _ => false,
+    };
+    let Some(instance) = args.first() else {
+        return;
+    };
+    let kind = match instance.ty {
+        Type::GObject(_) => ParentKind::Object,
+        Type::Boxed(_) | Type::Struct(_) => ParentKind::Boxed,
+        _ => return,
+    };
+
+    if borrowed_struct
+        && let Value::Object(handle) = value
+        && let Value::Object(parent) = &instance.value
+    {
+        handle.bind_to_parent(parent.ptr(), kind);
+    }
+}
+
+/// A `GError` reported by a call through a `gerror` argument.
+struct CallError {
+    domain: u32,
+    code: i32,
+    message: String,
+}
+
+impl From<glib::Error> for CallError {
+    fn from(error: glib::Error) -> Self {
+        Self {
+            domain: error.domain().into_glib(),
+            code: error.code(),
+            message: error.message().to_owned(),
+        }
+    }
+}
+
+/// Result of a call: its return value and out-parameter updates, or the
+/// error it reported.
+enum CallOutcome {
+    Returned(Value, Vec<RefUpdate>),
+    Failed(CallError),
+}
+
+impl ModuleResponse for CallOutcome {
+    fn to_js_response(self, env: &Env) -> napi::Result<Unknown<'_>> {
+        match self {
+            Self::Returned(value, ref_updates) => (value, ref_updates).to_js_response(env),
+            Self::Failed(error) => {
+                let reason = napi::Error::new(napi::Status::GenericFailure, error.message);
+                let mut js_error = env.create_error(reason)?;
+                js_error.set_named_property("domain", error.domain)?;
+                js_error.set_named_property("code", error.code)?;
+                Err(napi::Error::from(js_error.to_unknown()))
+            }
+        }
+    }
+}
+
 struct CallRequest {
     library_name: String,
     symbol_name: String,
     args: Vec<Arg>,
     result_type: Type,
+    /// References on the handles among `args` for an asynchronous call,
+    /// whose JS frame no longer keeps them alive. Released with the request
+    /// once the call has completed.
+    handles: Vec<HandleRef>,
 }
 
 impl ModuleRequest for CallRequest {
-    type Output = (Value, Vec<RefUpdate>);
+    type Output = CallOutcome;
 
-    fn execute(self) -> anyhow::Result<(Value, Vec<RefUpdate>)> {
+    fn execute(self) -> anyhow::Result<CallOutcome> {
         let mut arg_types: Vec<libffi::Type> = Vec::with_capacity(self.args.len() + 1);
         for arg in &self.args {
             arg.ty.append_ffi_arg_types(&mut arg_types);
@@ -58,21 +168,9 @@ impl ModuleRequest for CallRequest {
             .args(arg_types)
             .into_cif();
 
-        let ffi_values = self
-            .args
-            .iter()
-            .enumerate()
-            .map(|(i, arg)| {
-                arg.ty
-                    .encode(&arg.value, arg.optional)
-                    .with_context(|| format!("encoding arg {} of {}", i, self.symbol_name))
-            })
-            .collect::<anyhow::Result<Vec<ffi::FfiValue>>>()?;
-
-        let mut ffi_args: Vec<libffi::Arg> = Vec::with_capacity(ffi_values.len() + 1);
-        for ffi_value in &ffi_values {
-            ffi_value.append_libffi_args(&mut ffi_args);
-        }
+        let arena = ffi::CallArena::encode(&self.args, &self.symbol_name)?;
+        let ffi_values = arena.values();
+        let ffi_args = arena.libffi_args();
 
         let symbol_ptr = unsafe {
             GtkThreadState::with::<_, anyhow::Result<libffi::CodePtr>>(|state| {
@@ -90,24 +188,36 @@ impl ModuleRequest for CallRequest {
             .call_cif(&cif, symbol_ptr, &ffi_args)
             .with_context(|| format!("calling {}", self.symbol_name))?;
 
+        let error = self.args.iter().zip(ffi_values).find_map(|(arg, value)| match value {
+            ffi::FfiValue::Storage(storage) if matches!(arg.ty, Type::GError(_)) => {
+                storage.take_error()
+            }
+            _ => None,
+        });
+        if let Some(error) = error {
+            return Ok(CallOutcome::Failed(error.into()));
+        }
+
         let mut ref_updates = Vec::new();
 
         for (i, arg) in self.args.iter().enumerate() {
             if let Value::Ref(ref_val) = &arg.value {
-                let new_value = Value::from_ffi_value_with_args(
+                let mut new_value = Value::from_ffi_value_with_args(
                     &ffi_values[i],
                     &arg.ty,
-                    &ffi_values,
+                    ffi_values,
                     &self.args,
                 )?;
+                bind_borrowed_to_instance(&mut new_value, &arg.ty, &self.args);
                 ref_updates.push((Arc::clone(&ref_val.js_obj), new_value));
             }
         }
 
-        let return_value =
-            Value::from_ffi_value_with_args(&result, &self.result_type, &ffi_values, &self.args)
+        let mut return_value =
+            Value::from_ffi_value_with_args(&result, &self.result_type, ffi_values, &self.args)
                 .with_context(|| format!("decoding return value of {}", self.symbol_name))?;
-        Ok((return_value, ref_updates))
+        bind_borrowed_to_instance(&mut return_value, &self.result_type, &self.args);
+        Ok(CallOutcome::Returned(return_value, ref_updates))
     }
 
     fn error_context() -> &'static str {
@@ -130,6 +240,153 @@ pub fn call<'env>(
         symbol_name: symbol,
         args: parsed_args,
         result_type,
+        handles: Vec::new(),
     };
     dispatch_request(env, request)
 }
+
+#[derive(Debug, Default)]
+struct CancelState {
+    cancelled: bool,
+    cancellable: Option<gio::Cancellable>,
+}
+
+/// Cancellation state shared between an asynchronous call on the `GLib`
+/// thread and [`cancel_call`] on the JS thread.
+#[derive(Debug, Default)]
+pub struct CancelToken {
+    state: Mutex<CancelState>,
+}
+
+impl CancelToken {
+    fn state(&self) -> std::sync::MutexGuard<'_, CancelState> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Records the call's `GCancellable` argument, if it has one, so a later
+    /// [`cancel_call`] can cancel it. Returns `false` if the token is
+    /// already cancelled and the call must not run.
+    fn arm(&self, args: &[Arg]) -> bool {
+        let mut state = self.state();
+        if state.cancelled {
+            return false;
+        }
+
+        state.cancellable = args.iter().find_map(|arg| match (&arg.ty, &arg.value) {
+            (Type::GObject(_), Value::Object(handle)) if !handle.ptr().is_null() => {
+                let object = unsafe {
+                    glib::Object::from_glib_none(handle.ptr() as *mut glib::gobject_ffi::GObject)
+                };
+                object.downcast::<gio::Cancellable>().ok()
+            }
+            _ => None,
+        });
+        true
+    }
+
+    /// Forgets the call's `GCancellable` once the call has returned, and
+    /// reports whether the token was cancelled in the meantime.
+    fn disarm(&self) -> bool {
+        let mut state = self.state();
+        state.cancellable = None;
+        state.cancelled
+    }
+}
+
+/// Creates a token for cancelling an asynchronous call.
+#[napi]
+pub fn create_cancel_token() -> External<Arc<CancelToken>> {
+    External::new(Arc::new(CancelToken::default()))
+}
+
+/// Cancels the call that `token` was passed to. Cancelling twice, or after
+/// the call has finished, does nothing.
+#[napi]
+pub fn cancel_call(token: &External<Arc<CancelToken>>) {
+    let cancellable = {
+        let mut state = token.state();
+        state.cancelled = true;
+        state.cancellable.take()
+    };
+    if let Some(cancellable) = cancellable {
+        cancellable.cancel();
+    }
+}
+
+/// Result of an asynchronous call, converted to JS when its promise settles.
+struct AsyncCallOutput(CallOutcome);
+
+impl ToNapiValue for AsyncCallOutput {
+    unsafe fn to_napi_value(
+        env: napi::sys::napi_env,
+        val: Self,
+    ) -> napi::Result<napi::sys::napi_value> {
+        let env = Env::from_raw(env);
+        let result = val.0.to_js_response(&env)?;
+        Ok(napi::JsValue::raw(&result))
+    }
+}
+
+#[napi]
+pub fn call_async<'env>(
+    env: &'env Env,
+    library: String,
+    symbol: String,
+    args: Array,
+    return_type: Unknown<'_>,
+    cancel_token: Option<Unknown<'_>>,
+) -> napi::Result<Object<'env>> {
+    let parsed_args = Arg::from_js_array(env, &args)?;
+    let handles = Arg::retain_handles(env, &args)?;
+    let result_type = Type::from_js_value(env, return_type)?;
+    let cancel_token = match cancel_token {
+        Some(token) => {
+            let token = unsafe {
+                <&External<Arc<CancelToken>>>::from_napi_value(env.raw(), token.raw())?
+            };
+            Some(Arc::clone(&**token))
+        }
+        None => None,
+    };
+    let request = CallRequest {
+        library_name: library,
+        symbol_name: symbol,
+        args: parsed_args,
+        result_type,
+        handles,
+    };
+
+    let mailbox = Mailbox::global();
+    if mailbox.is_stopped() {
+        return Err(napi::Error::new(
+            napi::Status::GenericFailure,
+            GlibDisconnectedError.to_string(),
+        ));
+    }
+
+    let (deferred, promise) = env.create_deferred::<AsyncCallOutput, _>()?;
+    mailbox.schedule_glib(move || {
+        let symbol = request.symbol_name.clone();
+        let result = match &cancel_token {
+            Some(token) if !token.arm(&request.args) => None,
+            _ => Some(request.execute()),
+        };
+        let cancelled = cancel_token.is_some_and(|token| token.disarm());
+
+        match (result, cancelled) {
+            (Some(Ok(outcome)), false) => deferred.resolve(move |_| Ok(AsyncCallOutput(outcome))),
+            (Some(Err(e)), false) => deferred.reject(napi::Error::new(
+                napi::Status::GenericFailure,
+                format!("Error during {}: {e}", CallRequest::error_context()),
+            )),
+            _ => deferred.reject(napi::Error::new(
+                napi::Status::Cancelled,
+                format!("Call to {symbol} was cancelled"),
+            )),
+        }
+    });
+
+    Ok(promise)
+}
@@ -0,0 +1,107 @@
+//! Independent copies of managed handles.
+//!
+//! The [`copy`] function returns a new handle that owns its value
+//! independently of the source. Boxed values are duplicated with
+//! `g_boxed_copy` (or a `memcpy` of `size` bytes for plain structs), while
+//! `GObject` and fundamental instances gain a new reference. This is how JS
+//! retains a snapshot of a borrowed struct, such as an event rectangle handed
+//! to a signal handler, beyond the lifetime of the callback. Borrowed struct
+//! handles passed to callbacks are scoped to the callback, so the copy must
+//! be taken before it returns.
+
+use gtk4::glib::{self, gobject_ffi, prelude::StaticType as _, translate::FromGlibPtrNone as _};
+use napi::Env;
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+use super::handler::{ModuleRequest, dispatch_request};
+use crate::managed::{Boxed, ExternalOrigin, NativeHandle, NativeValue};
+
+struct CopyRequest {
+    /// Address of the source [`NativeHandle`]. The JS thread is blocked in
+    /// `dispatch_request` for the whole task and the `External` is held by
+    /// the call's arguments, so the handle outlives the dereference.
+    handle_addr: usize,
+    type_name: Option<String>,
+    size: Option<usize>,
+}
+
+impl CopyRequest {
+    fn copy_unknown_boxed(&self, ptr: *mut std::ffi::c_void) -> anyhow::Result<Boxed> {
+        let gtype = self.type_name.as_deref().and_then(glib::Type::from_name);
+        Boxed::from_glib_none_with_size(gtype, ptr, self.size, self.type_name.as_deref())
+    }
+
+    /// Copies the value behind an external pointer, typed by the request's
+    /// type name or else the one declared when the pointer was wrapped.
+    fn copy_external(
+        self,
+        ptr: *mut std::ffi::c_void,
+        origin: &ExternalOrigin,
+    ) -> anyhow::Result<NativeValue> {
+        let request = Self {
+            type_name: self.type_name.or_else(|| origin.type_name.clone()),
+            ..self
+        };
+        let gtype = request.type_name.as_deref().and_then(glib::Type::from_name);
+        if gtype.is_some_and(|gtype| gtype.is_a(glib::Object::static_type())) {
+            let object = ptr.cast::<gobject_ffi::GObject>();
+            return Ok(NativeValue::GObject(unsafe { glib::Object::from_glib_none(object) }));
+        }
+        Ok(NativeValue::Boxed(request.copy_unknown_boxed(ptr)?))
+    }
+}
+
+impl ModuleRequest for CopyRequest {
+    type Output = NativeHandle;
+
+    fn execute(self) -> anyhow::Result<NativeHandle> {
+        let handle = unsafe { &*(self.handle_addr as *const NativeHandle) };
+
+        if handle.ptr().is_null() {
+            anyhow::bail!("Cannot copy a null handle");
+        }
+        handle.ensure_alive()?;
+
+        let value = match handle.value() {
+            Some(NativeValue::GObject(object)) => NativeValue::GObject(object.clone()),
+            Some(NativeValue::Fundamental(fundamental)) => {
+                NativeValue::Fundamental(fundamental.clone())
+            }
+            Some(NativeValue::Boxed(boxed)) if boxed.gtype().is_some() => {
+                NativeValue::Boxed(boxed.clone())
+            }
+            Some(NativeValue::Boxed(boxed)) => {
+                NativeValue::Boxed(self.copy_unknown_boxed(boxed.as_ptr())?)
+            }
+            None => match handle.external_origin() {
+                Some(origin) => self
+                    .copy_external(handle.ptr(), origin)
+                    .map_err(|err| err.context(format!("Cannot copy {}", handle.describe())))?,
+                None => NativeValue::Boxed(self.copy_unknown_boxed(handle.ptr())?),
+            },
+        };
+
+        Ok(value.into())
+    }
+
+    fn error_context() -> &'static str {
+        "copy"
+    }
+}
+
+#[napi]
+pub fn copy<'env>(
+    env: &'env Env,
+    handle: &External<NativeHandle>,
+    type_name: Option<String>,
+    size: Option<f64>,
+) -> napi::Result<Unknown<'env>> {
+    let handle: &NativeHandle = handle;
+    let request = CopyRequest {
+        handle_addr: std::ptr::from_ref(handle) as usize,
+        type_name,
+        size: size.map(|s| s as usize),
+    };
+    dispatch_request(env, request)
+}
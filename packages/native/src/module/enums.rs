@@ -0,0 +1,109 @@
+//! Introspection of the values registered for enum and flags types.
+//!
+//! [`list_enum_values`] reads the values from the type's class, so tooling
+//! and strict descriptors agree on what a type defines without generated
+//! tables. The type is resolved on the `GLib` thread, where libraries are
+//! loaded.
+
+use anyhow::{Context as _, bail};
+use gtk4::glib::{self, translate::FromGlib as _};
+use libloading::os::unix::Library;
+use napi::Env;
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+use super::handler::{ModuleRequest, ModuleResponse, dispatch_request};
+use crate::state::GtkThreadState;
+use crate::types::registry;
+
+/// A value of an enum or flags type.
+#[napi(object)]
+#[derive(Debug)]
+pub struct EnumValue {
+    pub nick: String,
+    pub name: String,
+    pub value: i64,
+}
+
+/// Resolves `name` as a registered `GType` name, a name given to
+/// `registerTypes`, or a `get_type` function of a loaded library.
+fn resolve_gtype(name: &str) -> anyhow::Result<glib::Type> {
+    if let Some(gtype) = glib::Type::from_name(name) {
+        return Ok(gtype);
+    }
+
+    if let Some((library, get_type_fn)) = registry::lookup_typed(name) {
+        return GtkThreadState::with(|state| state.gtype_from_lib(&library, &get_type_fn));
+    }
+
+    if name.ends_with("_get_type") {
+        let this = Library::this();
+        let get_type =
+            unsafe { this.get::<unsafe extern "C" fn() -> glib::ffi::GType>(name.as_bytes()) }
+                .with_context(|| format!("Cannot find '{name}' in the loaded libraries"))?;
+        return Ok(unsafe { glib::Type::from_glib(get_type()) });
+    }
+
+    bail!("Unknown type '{name}'; register it with registerTypes or pass its get_type function")
+}
+
+struct ListEnumValuesRequest {
+    type_name: String,
+}
+
+impl ModuleRequest for ListEnumValuesRequest {
+    type Output = Vec<EnumValue>;
+
+    fn execute(self) -> anyhow::Result<Vec<EnumValue>> {
+        let gtype = resolve_gtype(&self.type_name)?;
+
+        if let Some(enum_class) = glib::EnumClass::with_type(gtype) {
+            return Ok(enum_class
+                .values()
+                .iter()
+                .map(|v| EnumValue {
+                    nick: v.nick().to_owned(),
+                    name: v.name().to_owned(),
+                    value: v.value().into(),
+                })
+                .collect());
+        }
+
+        if let Some(flags_class) = glib::FlagsClass::with_type(gtype) {
+            return Ok(flags_class
+                .values()
+                .iter()
+                .map(|v| EnumValue {
+                    nick: v.nick().to_owned(),
+                    name: v.name().to_owned(),
+                    value: v.value().into(),
+                })
+                .collect());
+        }
+
+        bail!("{gtype} is neither an enum nor a flags type")
+    }
+
+    fn error_context() -> &'static str {
+        "listEnumValues"
+    }
+}
+
+impl ModuleResponse for Vec<EnumValue> {
+    fn to_js_response(self, env: &Env) -> napi::Result<Unknown<'_>> {
+        unsafe {
+            let raw = Vec::<EnumValue>::to_napi_value(env.raw(), self)?;
+            Ok(Unknown::from_raw_unchecked(env.raw(), raw))
+        }
+    }
+}
+
+/// Lists the values registered for an enum or flags type, in declaration
+/// order.
+///
+/// `type_name` is a `GType` name, a name registered with `registerTypes`, or
+/// the `get_type` function of a loaded library.
+#[napi]
+pub fn list_enum_values<'env>(env: &'env Env, type_name: String) -> napi::Result<Unknown<'env>> {
+    dispatch_request(env, ListEnumValuesRequest { type_name })
+}
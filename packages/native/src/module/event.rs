@@ -0,0 +1,181 @@
+//! Decoding of `GdkEvent`s.
+//!
+//! Event controllers hand JavaScript a `GdkEvent` handle, and reading its
+//! type, position, key and modifiers through generated bindings takes one
+//! round trip to the `GLib` thread per getter. [`decode_event`] reads all of
+//! them in a single request and returns a plain object.
+//!
+//! The getters are resolved from the GTK library at call time, like any
+//! other symbol, so the native module keeps no link-time dependency on GTK.
+
+use std::ffi::{c_int, c_void};
+
+use gtk4::glib::{
+    self, gobject_ffi,
+    translate::{FromGlibPtrNone as _, IntoGlib as _},
+};
+use napi::{Env, JsValue as _};
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+use super::handler::{ModuleRequest, ModuleResponse, dispatch_request};
+use crate::managed::{NativeHandle, NativeValue};
+use crate::state::GtkThreadState;
+use crate::value::Value;
+
+const GTK_LIB: &str = "libgtk-4.so.1";
+
+const GDK_BUTTON_PRESS: c_int = 2;
+const GDK_BUTTON_RELEASE: c_int = 3;
+const GDK_KEY_PRESS: c_int = 4;
+const GDK_KEY_RELEASE: c_int = 5;
+const GDK_SCROLL: c_int = 15;
+
+type EventGetter<T> = unsafe extern "C" fn(*mut c_void) -> T;
+type EventPairGetter = unsafe extern "C" fn(*mut c_void, *mut f64, *mut f64) -> c_int;
+type ScrollDeltasGetter = unsafe extern "C" fn(*mut c_void, *mut f64, *mut f64);
+
+/// The `GdkEvent` getters [`DecodeEventRequest`] calls.
+struct EventFns {
+    event_type: EventGetter<c_int>,
+    time: EventGetter<u32>,
+    modifier_state: EventGetter<u32>,
+    position: EventPairGetter,
+    device: EventGetter<*mut c_void>,
+    button: EventGetter<u32>,
+    keyval: EventGetter<u32>,
+    keycode: EventGetter<u32>,
+    scroll_deltas: ScrollDeltasGetter,
+}
+
+impl EventFns {
+    fn load(state: &mut GtkThreadState) -> anyhow::Result<Self> {
+        let library = state.library(GTK_LIB)?;
+        unsafe {
+            Ok(Self {
+                event_type: *library.get(b"gdk_event_get_event_type")?,
+                time: *library.get(b"gdk_event_get_time")?,
+                modifier_state: *library.get(b"gdk_event_get_modifier_state")?,
+                position: *library.get(b"gdk_event_get_position")?,
+                device: *library.get(b"gdk_event_get_device")?,
+                button: *library.get(b"gdk_button_event_get_button")?,
+                keyval: *library.get(b"gdk_key_event_get_keyval")?,
+                keycode: *library.get(b"gdk_key_event_get_keycode")?,
+                scroll_deltas: *library.get(b"gdk_scroll_event_get_deltas")?,
+            })
+        }
+    }
+}
+
+/// The fields of a `GdkEvent`, with those its type lacks left out.
+struct DecodedEvent {
+    event_type: i32,
+    time: u32,
+    modifiers: u32,
+    position: Option<(f64, f64)>,
+    device: Option<NativeHandle>,
+    button: Option<u32>,
+    keyval: Option<u32>,
+    keycode: Option<u32>,
+    scroll_deltas: Option<(f64, f64)>,
+}
+
+impl ModuleResponse for DecodedEvent {
+    fn to_js_response(self, env: &Env) -> napi::Result<Unknown<'_>> {
+        let optional = |value: Option<u32>| value.map_or(Value::Null, |v| Value::Number(v.into()));
+        let (x, y) = self.position.unzip();
+        let (delta_x, delta_y) = self.scroll_deltas.unzip();
+        let fields = [
+            ("type", Value::Number(self.event_type.into())),
+            ("time", Value::Number(self.time.into())),
+            ("modifiers", Value::Number(self.modifiers.into())),
+            ("x", x.map_or(Value::Null, Value::Number)),
+            ("y", y.map_or(Value::Null, Value::Number)),
+            ("device", self.device.map_or(Value::Null, Value::Object)),
+            ("button", optional(self.button)),
+            ("keyval", optional(self.keyval)),
+            ("keycode", optional(self.keycode)),
+            ("deltaX", delta_x.map_or(Value::Null, Value::Number)),
+            ("deltaY", delta_y.map_or(Value::Null, Value::Number)),
+        ];
+
+        let mut object = Object::new(env)?;
+        for (name, value) in fields {
+            object.set_named_property(name, value.to_js_value(env)?)?;
+        }
+        Ok(object.to_unknown())
+    }
+}
+
+struct DecodeEventRequest {
+    ptr: *mut c_void,
+}
+
+unsafe impl Send for DecodeEventRequest {}
+
+impl ModuleRequest for DecodeEventRequest {
+    type Output = DecodedEvent;
+
+    fn execute(self) -> anyhow::Result<DecodedEvent> {
+        let (fns, gtype) = GtkThreadState::with::<_, anyhow::Result<_>>(|state| {
+            let gtype = state.gtype_from_lib(GTK_LIB, "gdk_event_get_type")?;
+            Ok((EventFns::load(state)?, gtype))
+        })?;
+
+        let instance = self.ptr.cast::<gobject_ffi::GTypeInstance>();
+        if self.ptr.is_null()
+            || unsafe { gobject_ffi::g_type_check_instance_is_a(instance, gtype.into_glib()) } == 0
+        {
+            anyhow::bail!("Handle is not a GdkEvent");
+        }
+
+        let event = self.ptr;
+        let event_type = unsafe { (fns.event_type)(event) };
+
+        let (mut x, mut y) = (0.0, 0.0);
+        let position = (unsafe { (fns.position)(event, &mut x, &mut y) } != 0).then_some((x, y));
+
+        let device = unsafe { (fns.device)(event) };
+        let device = (!device.is_null()).then(|| {
+            let object = unsafe { glib::Object::from_glib_none(device.cast()) };
+            NativeHandle::from(NativeValue::GObject(object))
+        });
+
+        let is_button = matches!(event_type, GDK_BUTTON_PRESS | GDK_BUTTON_RELEASE);
+        let is_key = matches!(event_type, GDK_KEY_PRESS | GDK_KEY_RELEASE);
+        let scroll_deltas = (event_type == GDK_SCROLL).then(|| {
+            let (mut dx, mut dy) = (0.0, 0.0);
+            unsafe { (fns.scroll_deltas)(event, &mut dx, &mut dy) };
+            (dx, dy)
+        });
+
+        Ok(DecodedEvent {
+            event_type,
+            time: unsafe { (fns.time)(event) },
+            modifiers: unsafe { (fns.modifier_state)(event) },
+            position,
+            device,
+            button: is_button.then(|| unsafe { (fns.button)(event) }),
+            keyval: is_key.then(|| unsafe { (fns.keyval)(event) }),
+            keycode: is_key.then(|| unsafe { (fns.keycode)(event) }),
+            scroll_deltas,
+        })
+    }
+
+    fn error_context() -> &'static str {
+        "decodeEvent"
+    }
+}
+
+/// Reads the type, timestamp, modifiers, position, device and, depending on
+/// the type, button, key or scroll deltas of a `GdkEvent` in one request.
+#[napi]
+pub fn decode_event<'env>(
+    env: &'env Env,
+    handle: &External<NativeHandle>,
+) -> napi::Result<Unknown<'env>> {
+    handle
+        .ensure_alive()
+        .map_err(|e| napi::Error::new(napi::Status::InvalidArg, e.to_string()))?;
+    dispatch_request(env, DecodeEventRequest { ptr: handle.ptr() })
+}
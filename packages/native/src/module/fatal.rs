@@ -0,0 +1,29 @@
+//! Fatal log message handling.
+//!
+//! `GLib` aborts the process after logging a fatal message, such as one from
+//! `g_error` or a failed assertion under `G_DEBUG=fatal-criticals`.
+//! [`on_fatal_error`] registers a JS callback that the log handler runs
+//! first, giving the app a chance to save state and stop the main loop in
+//! order.
+//!
+//! The handler is the one installed by [`super::init`] when it spawns the
+//! `GLib` thread. In attach mode the host's log handler stays in place and
+//! the callback is never called.
+
+use napi::Env;
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+use crate::glib_log_handler::GlibLogHandler;
+use crate::value::Callback;
+
+/// Calls `callback` with the domain, level and message of the first fatal
+/// log message before the process aborts. `null` removes the callback.
+#[napi]
+pub fn on_fatal_error(env: Env, callback: Option<Unknown<'_>>) -> napi::Result<()> {
+    let callback = callback
+        .map(|callback| Callback::from_js_value(&env, callback))
+        .transpose()?;
+    GlibLogHandler::set_fatal_callback(callback.map(|callback| callback.js_func));
+    Ok(())
+}
@@ -22,6 +22,28 @@
 //! - `Boolean`
 //! - `String` (copies via `g_strdup`)
 //! - `GObject` / `Boxed` / `Struct` / `Fundamental` (writes pointer value)
+//!
+//! ## Named Fields
+//!
+//! When the type is a struct with a field layout, a field name, or a dotted
+//! path through embedded structs, can be given instead of an offset. The
+//! offset and type of the field come from the layout. A struct with a
+//! layout is embedded in the memory it is read from: reading one returns a
+//! borrowed handle to it in place, and writing one copies its bytes.
+//!
+//! ## Arrays
+//!
+//! [`index_handle`] steps through an array of structs, returning a borrowed
+//! handle for one element. The address is computed and bounds-checked here
+//! rather than in JavaScript, and the element stays tied to the lifetime of
+//! the array's handle.
+//!
+//! ## Byte Order
+//!
+//! Values are accessed in host byte order unless a byte order is given.
+//! Integers and floats can be read and written as little- or big-endian,
+//! for binary formats whose layout does not depend on the host, such as
+//! serialized `GVariant` data or image headers.
 
 use std::ffi::c_void;
 
@@ -31,7 +53,7 @@ use napi_derive::napi;
 
 use super::handler::{ModuleRequest, dispatch_request};
 use crate::managed::NativeHandle;
-use crate::types::{RawPtrCodec as _, Type};
+use crate::types::{RawPtrCodec as _, StructType, Type};
 use crate::value::Value;
 
 fn require_non_null(ptr: *mut c_void) -> anyhow::Result<*mut c_void> {
@@ -41,10 +63,74 @@ fn require_non_null(ptr: *mut c_void) -> anyhow::Result<*mut c_void> {
     Ok(ptr)
 }
 
+fn ensure_alive(handle: &NativeHandle) -> napi::Result<()> {
+    handle
+        .ensure_alive()
+        .map_err(|e| napi::Error::new(napi::Status::InvalidArg, e.to_string()))
+}
+
+/// Returns how many bytes of a `field_type` value must be reversed to access
+/// it in `byte_order`, or `None` when no byte order is given or it matches
+/// the host's.
+fn swap_size(field_type: &Type, byte_order: Option<&str>) -> napi::Result<Option<usize>> {
+    let Some(byte_order) = byte_order else {
+        return Ok(None);
+    };
+    let big_endian = match byte_order {
+        "little" => false,
+        "big" => true,
+        other => {
+            return Err(napi::Error::new(
+                napi::Status::InvalidArg,
+                format!("Unknown byte order: {other}, expected \"little\" or \"big\""),
+            ));
+        }
+    };
+    let size = match field_type {
+        Type::Integer(kind) => kind.byte_size(),
+        Type::Float(kind) => kind.byte_size(),
+        other => {
+            return Err(napi::Error::new(
+                napi::Status::InvalidArg,
+                format!("Byte order only applies to integers and floats, not {other}"),
+            ));
+        }
+    };
+    Ok((big_endian != cfg!(target_endian = "big")).then_some(size))
+}
+
+/// Resolves `offset` to the type stored there and its byte offset. A field
+/// name is looked up in the layout of `field_type`, which must then be a
+/// struct type with fields.
+fn resolve_field(field_type: Type, offset: Either<f64, String>) -> napi::Result<(Type, usize)> {
+    let name = match offset {
+        Either::A(offset) => return Ok((field_type, offset as usize)),
+        Either::B(name) => name,
+    };
+    let Type::Struct(struct_type) = &field_type else {
+        return Err(napi::Error::new(
+            napi::Status::InvalidArg,
+            format!("Field names require a struct type, not {field_type}"),
+        ));
+    };
+    let (offset, resolved) = struct_type
+        .field(&name)
+        .map_err(|e| napi::Error::new(napi::Status::InvalidArg, e.to_string()))?;
+    Ok((resolved.clone(), offset))
+}
+
+fn embedded_struct(field_type: &Type) -> Option<&StructType> {
+    match field_type {
+        Type::Struct(struct_type) if struct_type.is_embedded() => Some(struct_type),
+        _ => None,
+    }
+}
+
 struct ReadRequest {
     base_ptr: *mut c_void,
     field_type: Type,
     offset: usize,
+    swap_size: Option<usize>,
 }
 
 unsafe impl Send for ReadRequest {}
@@ -54,8 +140,18 @@ impl ModuleRequest for ReadRequest {
 
     fn execute(self) -> anyhow::Result<Value> {
         let base_ptr = require_non_null(self.base_ptr)?;
-        let field_ptr = unsafe { (base_ptr as *const u8).add(self.offset) as *const c_void };
-        self.field_type.read_from_raw_ptr(field_ptr, "field read")
+        let field_ptr = unsafe { (base_ptr as *const u8).add(self.offset) };
+        let Some(size) = self.swap_size else {
+            return self
+                .field_type
+                .read_from_raw_ptr(field_ptr as *const c_void, "field read");
+        };
+
+        let mut bytes = [0u8; 8];
+        unsafe { std::ptr::copy_nonoverlapping(field_ptr, bytes.as_mut_ptr(), size) };
+        bytes[..size].reverse();
+        self.field_type
+            .read_from_raw_ptr(bytes.as_ptr() as *const c_void, "field read")
     }
 
     fn error_context() -> &'static str {
@@ -68,14 +164,27 @@ pub fn read<'env>(
     env: &'env Env,
     handle: &External<NativeHandle>,
     js_type: Unknown<'_>,
-    offset: f64,
+    offset: Either<f64, String>,
+    byte_order: Option<String>,
 ) -> napi::Result<Unknown<'env>> {
-    let field_type = Type::from_js_value(env, js_type)?;
+    let (field_type, offset) = resolve_field(Type::from_js_value(env, js_type)?, offset)?;
+    let swap_size = swap_size(&field_type, byte_order.as_deref())?;
+    ensure_alive(handle)?;
     let base_ptr = handle.ptr();
+
+    if embedded_struct(&field_type).is_some() {
+        let base_ptr = require_non_null(base_ptr)
+            .map_err(|e| napi::Error::new(napi::Status::InvalidArg, e.to_string()))?;
+        let field_ptr = unsafe { (base_ptr as *mut u8).add(offset) };
+        let element = NativeHandle::element_of(handle, field_ptr.cast());
+        return Value::Object(element).to_js_value(env);
+    }
+
     let request = ReadRequest {
         base_ptr,
         field_type,
-        offset: offset as usize,
+        offset,
+        swap_size,
     };
     dispatch_request(env, request)
 }
@@ -85,6 +194,7 @@ struct WriteRequest {
     field_type: Type,
     offset: usize,
     value: Value,
+    swap_size: Option<usize>,
 }
 
 unsafe impl Send for WriteRequest {}
@@ -94,9 +204,25 @@ impl ModuleRequest for WriteRequest {
 
     fn execute(self) -> anyhow::Result<()> {
         let base_ptr = require_non_null(self.base_ptr)?;
-        let field_ptr = unsafe { (base_ptr as *mut u8).add(self.offset) as *mut c_void };
+        let field_ptr = unsafe { (base_ptr as *mut u8).add(self.offset) };
+        if let Some(struct_type) = embedded_struct(&self.field_type) {
+            let source = require_non_null(self.value.object_ptr("embedded struct write")?)?;
+            let size = struct_type.size.unwrap_or(0);
+            unsafe { std::ptr::copy(source as *const u8, field_ptr, size) };
+            return Ok(());
+        }
+        let Some(size) = self.swap_size else {
+            return self
+                .field_type
+                .write_value_to_raw_ptr(field_ptr as *mut c_void, &self.value);
+        };
+
+        let mut bytes = [0u8; 8];
         self.field_type
-            .write_value_to_raw_ptr(field_ptr, &self.value)
+            .write_value_to_raw_ptr(bytes.as_mut_ptr() as *mut c_void, &self.value)?;
+        bytes[..size].reverse();
+        unsafe { std::ptr::copy_nonoverlapping(bytes.as_ptr(), field_ptr, size) };
+        Ok(())
     }
 
     fn error_context() -> &'static str {
@@ -109,17 +235,74 @@ pub fn write<'env>(
     env: &'env Env,
     handle: &External<NativeHandle>,
     js_type: Unknown<'_>,
-    offset: f64,
+    offset: Either<f64, String>,
     value: Unknown<'_>,
+    byte_order: Option<String>,
 ) -> napi::Result<Unknown<'env>> {
-    let field_type = Type::from_js_value(env, js_type)?;
+    let (field_type, offset) = resolve_field(Type::from_js_value(env, js_type)?, offset)?;
+    let swap_size = swap_size(&field_type, byte_order.as_deref())?;
     let parsed_value = Value::from_js_value(env, value)?;
+    ensure_alive(handle)?;
     let base_ptr = handle.ptr();
     let request = WriteRequest {
         base_ptr,
         field_type,
-        offset: offset as usize,
+        offset,
         value: parsed_value,
+        swap_size,
     };
     dispatch_request(env, request)
 }
+
+fn require_usize(name: &str, value: f64) -> napi::Result<usize> {
+    if !value.is_finite() || value < 0.0 || value.fract() != 0.0 || value > usize::MAX as f64 {
+        return Err(napi::Error::new(
+            napi::Status::InvalidArg,
+            format!("Invalid {name} {value}, expected a non-negative integer"),
+        ));
+    }
+    Ok(value as usize)
+}
+
+/// Returns a borrowed handle for element `index` of an array of `stride`-byte
+/// elements starting at `handle`. With `length`, indices past the end of the
+/// array are rejected.
+#[napi]
+pub fn index_handle(
+    handle: &External<NativeHandle>,
+    index: f64,
+    stride: f64,
+    length: Option<f64>,
+) -> napi::Result<External<NativeHandle>> {
+    let index = require_usize("index", index)?;
+    let stride = require_usize("stride", stride)?;
+    if stride == 0 {
+        return Err(napi::Error::new(
+            napi::Status::InvalidArg,
+            "Invalid stride 0, expected a positive integer",
+        ));
+    }
+    if let Some(length) = length.map(|length| require_usize("length", length)).transpose()?
+        && index >= length
+    {
+        return Err(napi::Error::new(
+            napi::Status::InvalidArg,
+            format!("Index {index} is out of bounds for an array of length {length}"),
+        ));
+    }
+
+    ensure_alive(handle)?;
+    let base_ptr = require_non_null(handle.ptr())
+        .map_err(|e| napi::Error::new(napi::Status::InvalidArg, e.to_string()))?;
+    let element_ptr = index
+        .checked_mul(stride)
+        .and_then(|offset| (base_ptr as usize).checked_add(offset))
+        .ok_or_else(|| {
+            napi::Error::new(
+                napi::Status::InvalidArg,
+                format!("Index {index} with stride {stride} overflows the address space"),
+            )
+        })?;
+
+    Ok(External::new(NativeHandle::element_of(handle, element_ptr as *mut c_void)))
+}
@@ -0,0 +1,96 @@
+//! Conversions between flags masks and nick arrays.
+//!
+//! Both directions resolve the flags `GType` named by a `flags` type
+//! descriptor and use the values registered for it, so they work for any
+//! flags type without generated tables. Lookups run on the `GLib` thread,
+//! where the type's library is loaded.
+
+use napi::Env;
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+use super::handler::{ModuleRequest, dispatch_request};
+use crate::types::{FlagsType, Type};
+use crate::value::Value;
+
+fn parse_flags_type(env: &Env, js_type: Unknown<'_>) -> napi::Result<FlagsType> {
+    match Type::from_js_value(env, js_type)? {
+        Type::Flags(flags_type) => Ok(flags_type),
+        other => Err(napi::Error::new(
+            napi::Status::InvalidArg,
+            format!("Expected a flags type, got {other:?}"),
+        )),
+    }
+}
+
+struct FlagsToNicksRequest {
+    flags_type: FlagsType,
+    mask: f64,
+}
+
+impl ModuleRequest for FlagsToNicksRequest {
+    type Output = Value;
+
+    fn execute(self) -> anyhow::Result<Value> {
+        let mask = self.flags_type.mask_from_number(self.mask)?;
+        if self.flags_type.strict {
+            self.flags_type.validate_mask(mask)?;
+        }
+        let nicks = self.flags_type.nicks_from_mask(mask)?;
+        Ok(Value::Array(nicks.into_iter().map(Value::String).collect()))
+    }
+
+    fn error_context() -> &'static str {
+        "flagsToNicks"
+    }
+}
+
+struct FlagsFromNicksRequest {
+    flags_type: FlagsType,
+    nicks: Vec<String>,
+}
+
+impl ModuleRequest for FlagsFromNicksRequest {
+    type Output = Value;
+
+    fn execute(self) -> anyhow::Result<Value> {
+        let mask = self
+            .flags_type
+            .mask_from_nicks(self.nicks.iter().map(String::as_str))?;
+        Ok(Value::Number(self.flags_type.mask_to_number(mask)))
+    }
+
+    fn error_context() -> &'static str {
+        "flagsFromNicks"
+    }
+}
+
+/// Lists the nicks of the values set in `mask`.
+///
+/// With a `strict` descriptor, a mask carrying undefined bits is rejected.
+#[napi]
+pub fn flags_to_nicks<'env>(
+    env: &'env Env,
+    js_type: Unknown<'_>,
+    mask: f64,
+) -> napi::Result<Unknown<'env>> {
+    let request = FlagsToNicksRequest {
+        flags_type: parse_flags_type(env, js_type)?,
+        mask,
+    };
+    dispatch_request(env, request)
+}
+
+/// Builds a mask from value nicks, failing on any unknown nick.
+#[napi]
+pub fn flags_from_nicks<'env>(
+    env: &'env Env,
+    js_type: Unknown<'_>,
+    nicks: Vec<String>,
+) -> napi::Result<Unknown<'env>> {
+    let request = FlagsFromNicksRequest {
+        flags_type: parse_flags_type(env, js_type)?,
+        nicks,
+    };
+    dispatch_request(env, request)
+}
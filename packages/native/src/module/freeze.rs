@@ -5,9 +5,12 @@ use napi_derive::napi;
 
 use crate::dispatch::Mailbox;
 use crate::error_reporter::NativeErrorReporter;
+use crate::state::JsThread;
 
 #[napi]
 pub fn freeze(env: Env) -> napi::Result<()> {
+    JsThread::global().ensure_current("freeze")?;
+
     let mailbox = Mailbox::global();
     let is_outermost = mailbox.freeze();
 
@@ -32,6 +35,8 @@ pub fn freeze(env: Env) -> napi::Result<()> {
 }
 
 #[napi]
-pub fn unfreeze() {
+pub fn unfreeze() -> napi::Result<()> {
+    JsThread::global().ensure_current("unfreeze")?;
     Mailbox::global().unfreeze();
+    Ok(())
 }
@@ -0,0 +1,47 @@
+//! Descriptor-free calls for high-frequency render hints.
+//!
+//! Reconciler-style JS layers call functions such as `gtk_widget_queue_draw`
+//! or `gdk_display_flush` constantly. They all share the `void fn(gpointer)`
+//! signature, so [`call_hint`] skips type descriptors, argument parsing and
+//! CIF construction, and invokes the symbol directly on the `GLib` thread.
+//!
+//! Hints are fire-and-forget: the JS thread does not wait for the call. The
+//! task is queued on the `GLib` inbox ahead of any drop the handle's own
+//! garbage collection could schedule (inbox tasks run from a `HIGH_IDLE`
+//! source, handle drops from a default-priority idle), so the pointer stays
+//! valid until the hint has run. Failures are reported through
+//! [`NativeErrorReporter`].
+
+use std::ffi::c_void;
+
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+use crate::dispatch::Mailbox;
+use crate::error_reporter::NativeErrorReporter;
+use crate::managed::NativeHandle;
+use crate::state::GtkThreadState;
+
+type HintFn = unsafe extern "C" fn(*mut c_void);
+
+fn run_hint(library: &str, symbol: &str, ptr: *mut c_void) -> anyhow::Result<()> {
+    let hint = GtkThreadState::with::<_, anyhow::Result<HintFn>>(|state| {
+        let library = state.library(library)?;
+        let symbol = unsafe { library.get::<HintFn>(symbol.as_bytes())? };
+        Ok(*symbol)
+    })?;
+
+    unsafe { hint(ptr) };
+    Ok(())
+}
+
+#[napi]
+pub fn call_hint(library: String, symbol: String, handle: &External<NativeHandle>) {
+    let ptr = handle.ptr_as_usize();
+
+    Mailbox::global().schedule_glib(move || {
+        if let Err(e) = run_hint(&library, &symbol, ptr as *mut c_void) {
+            NativeErrorReporter::global().report(&e.context(format!("hint {symbol}")));
+        }
+    });
+}
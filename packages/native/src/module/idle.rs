@@ -0,0 +1,138 @@
+//! Main-loop idle detection.
+//!
+//! An idle watch calls its callback once the `GLib` main loop has gone
+//! `timeout_ms` without activity. Activity is any main-loop wakeup caused by
+//! a ready file descriptor — input and display events, or work queued from
+//! JS — observed by wrapping the main context's poll function. Timer
+//! wakeups, including the watch's own, do not count.
+//!
+//! Each watch is a low-priority `GLib` timeout that re-arms itself for the
+//! remainder of the quiet period until the period elapses, then posts the
+//! callback to the JS thread in the low [`Priority`] lane. Watches are
+//! one-shot.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, AtomicU32, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use gtk4::glib::{self, translate::ToGlibPtr as _};
+use napi::Env;
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+use crate::callback::CoalescedCallback;
+use crate::dispatch::{Mailbox, Priority};
+use crate::value::Callback;
+
+/// Monotonic time, in microseconds, of the last poll that returned ready
+/// file descriptors.
+static LAST_ACTIVITY_US: AtomicI64 = AtomicI64::new(0);
+static NEXT_WATCH_ID: AtomicU32 = AtomicU32::new(1);
+static ORIGINAL_POLL: OnceLock<glib::ffi::GPollFunc> = OnceLock::new();
+
+struct IdleWatch {
+    callback: Arc<CoalescedCallback>,
+    timeout_us: i64,
+    since_us: i64,
+    source: Option<glib::SourceId>,
+}
+
+thread_local! {
+    static WATCHES: RefCell<HashMap<u32, IdleWatch>> = RefCell::new(HashMap::new());
+}
+
+unsafe extern "C" fn tracking_poll(
+    fds: *mut glib::ffi::GPollFD,
+    nfds: std::ffi::c_uint,
+    timeout: std::ffi::c_int,
+) -> std::ffi::c_int {
+    let ready = match ORIGINAL_POLL.get().copied().flatten() {
+        Some(poll) => unsafe { poll(fds, nfds, timeout) },
+        None => unsafe { glib::ffi::g_poll(fds, nfds, timeout) },
+    };
+    if ready > 0 {
+        LAST_ACTIVITY_US.store(glib::monotonic_time(), Ordering::Relaxed);
+    }
+    ready
+}
+
+/// Wraps the thread-default main context's poll function to record
+/// activity. Must run on the `GLib` thread; later calls are no-ops.
+fn install_activity_tracking() {
+    ORIGINAL_POLL.get_or_init(|| {
+        let context = glib::MainContext::ref_thread_default();
+        let context_ptr: *mut glib::ffi::GMainContext = context.to_glib_none().0;
+        LAST_ACTIVITY_US.store(glib::monotonic_time(), Ordering::Relaxed);
+        unsafe {
+            let original = glib::ffi::g_main_context_get_poll_func(context_ptr);
+            glib::ffi::g_main_context_set_poll_func(context_ptr, Some(tracking_poll));
+            original
+        }
+    });
+}
+
+/// Fires watch `id` if its quiet period has elapsed, otherwise schedules
+/// another check for when it would.
+fn arm(id: u32) {
+    let now = glib::monotonic_time();
+    let expired = WATCHES.with_borrow_mut(|watches| {
+        let watch = watches.get_mut(&id)?;
+        let quiet_since = watch.since_us.max(LAST_ACTIVITY_US.load(Ordering::Relaxed));
+        let remaining = quiet_since + watch.timeout_us - now;
+
+        if remaining <= 0 {
+            return watches.remove(&id);
+        }
+
+        watch.source = Some(glib::timeout_add_local_full(
+            Duration::from_micros(remaining as u64),
+            glib::Priority::LOW,
+            move || {
+                arm(id);
+                glib::ControlFlow::Break
+            },
+        ));
+        None
+    });
+
+    if let Some(watch) = expired {
+        watch.callback.submit(Vec::new());
+    }
+}
+
+/// Calls `callback` once the main loop has been idle for `timeout_ms`.
+///
+/// Returns a watch ID for [`cancel_idle`].
+#[napi]
+pub fn on_idle(env: Env, callback: Unknown<'_>, timeout_ms: u32) -> napi::Result<u32> {
+    let callback = Callback::from_js_value(&env, callback)?;
+    let slot = CoalescedCallback::new(callback.js_func, Priority::Low);
+    let id = NEXT_WATCH_ID.fetch_add(1, Ordering::Relaxed);
+
+    Mailbox::global().schedule_glib(move || {
+        install_activity_tracking();
+        let watch = IdleWatch {
+            callback: slot,
+            timeout_us: i64::from(timeout_ms) * 1000,
+            since_us: glib::monotonic_time(),
+            source: None,
+        };
+        WATCHES.with_borrow_mut(|watches| watches.insert(id, watch));
+        arm(id);
+    });
+
+    Ok(id)
+}
+
+/// Cancels an idle watch. Unknown or already-fired IDs are ignored.
+#[napi]
+pub fn cancel_idle(id: u32) {
+    Mailbox::global().schedule_glib(move || {
+        let watch = WATCHES.with_borrow_mut(|watches| watches.remove(&id));
+        if let Some(source) = watch.and_then(|watch| watch.source) {
+            source.remove();
+        }
+    });
+}
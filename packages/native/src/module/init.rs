@@ -14,10 +14,32 @@
 //! 4. Block the JS thread on the barrier; once unblocked, return the handle
 //! 5. The loop runs until JS calls `stop`, which dispatches a final task to
 //!    drain pending finalizers and quit the loop
+//!
+//! ## Attach Mode
+//!
+//! When GTK is embedded in a process where another library already iterates
+//! the default `GMainContext` on its own thread, spawning a second loop would
+//! fight over context ownership. In `attach` mode no thread is spawned: the
+//! startup barrier is posted to the default context and the thread that
+//! dispatches it becomes the `GLib` side of the mailbox. The returned handle
+//! wraps a loop that never runs, so `stop` drains pending finalizers and then
+//! quits a no-op loop, leaving the host's own loop untouched. The host's log
+//! handler is also left in place.
+//!
+//! ## Worker Threads
+//!
+//! The first JS thread to call [`init`] owns the runtime (see
+//! [`JsThread`]). Later calls from Node `worker_threads` do not start
+//! anything; they return a borrowed handle to the same loop so workers can
+//! issue FFI calls through the shared mailbox.
+//!
+//! `auto` (the default) attaches when the default context is already owned by
+//! another thread and spawns otherwise.
 
 use std::ffi::c_void;
 use std::sync::Arc;
 use std::sync::mpsc;
+use std::time::Duration;
 
 use gtk4::glib::{
     self,
@@ -32,9 +54,76 @@ use crate::dispatch::{Mailbox, WakeJsTsfn};
 use crate::error_reporter::{ErrorReporterTsfn, NativeErrorReporter};
 use crate::glib_log_handler::GlibLogHandler;
 use crate::managed::{Boxed, NativeHandle, NativeValue};
+use crate::state::JsThread;
+
+/// How long `attach` mode waits for the host loop to dispatch the startup barrier.
+const ATTACH_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InitMode {
+    Spawn,
+    Attach,
+    Auto,
+}
+
+impl InitMode {
+    fn parse(mode: Option<&str>) -> napi::Result<Self> {
+        match mode {
+            None | Some("auto") => Ok(Self::Auto),
+            Some("spawn") => Ok(Self::Spawn),
+            Some("attach") => Ok(Self::Attach),
+            Some(other) => Err(napi::Error::new(
+                napi::Status::InvalidArg,
+                format!("Unknown init mode '{other}' (expected 'spawn', 'attach' or 'auto')"),
+            )),
+        }
+    }
+}
+
+/// Returns whether a thread other than the caller currently owns the default
+/// main context, i.e. a host main loop is already running.
+pub fn default_context_is_owned_elsewhere() -> bool {
+    glib::MainContext::default().acquire().is_err()
+}
 
 #[napi]
-pub fn init(env: Env) -> napi::Result<External<NativeHandle>> {
+pub fn init(env: Env, mode: Option<String>) -> napi::Result<External<NativeHandle>> {
+    let mode = InitMode::parse(mode.as_deref())?;
+
+    let js_thread = JsThread::global();
+    if !js_thread.claim() {
+        let main_loop = js_thread.main_loop();
+        if main_loop == 0 {
+            return Err(napi::Error::new(
+                napi::Status::GenericFailure,
+                "gtkx is still starting on its owner thread".to_owned(),
+            ));
+        }
+        return Ok(External::new(NativeHandle::borrowed(main_loop as *mut c_void)));
+    }
+
+    let host_loop_running = default_context_is_owned_elsewhere();
+
+    let attach = match mode {
+        InitMode::Spawn if host_loop_running => {
+            return Err(napi::Error::new(
+                napi::Status::GenericFailure,
+                "A GLib main loop is already running on the default main context; use 'attach' mode"
+                    .to_owned(),
+            ));
+        }
+        InitMode::Attach if !host_loop_running => {
+            return Err(napi::Error::new(
+                napi::Status::GenericFailure,
+                "Cannot attach: no GLib main loop is running on the default main context"
+                    .to_owned(),
+            ));
+        }
+        InitMode::Spawn => false,
+        InitMode::Attach => true,
+        InitMode::Auto => host_loop_running,
+    };
+
     let wake_js_fn = env.create_function_from_closure::<(), _, _>("gtkx_wake_js", |ctx| {
         Mailbox::global().process_node_pending(*ctx.env);
         Ok(())
@@ -63,38 +152,65 @@ pub fn init(env: Env) -> napi::Result<External<NativeHandle>> {
 
     NativeErrorReporter::global().initialize(Arc::new(error_tsfn));
 
+    let main_loop_handle = if attach {
+        attach_to_host_loop()?
+    } else {
+        spawn_glib_thread()?
+    };
+    js_thread.set_main_loop(main_loop_handle.ptr_as_usize());
+
+    Ok(External::new(main_loop_handle))
+}
+
+/// Posts the startup barrier to the default context and sends back a handle
+/// wrapping `main_loop` once it fires.
+fn post_startup_barrier(main_loop: glib::MainLoop, tx: mpsc::Sender<NativeHandle>) {
+    glib::idle_add_once(move || {
+        let gtype = unsafe { glib::Type::from_glib(glib::ffi::g_main_loop_get_type()) };
+        let raw_ptr =
+            IntoGlibPtr::<*mut glib::ffi::GMainLoop>::into_glib_ptr(main_loop) as *mut c_void;
+        let boxed = Boxed::from_glib_full(Some(gtype), raw_ptr);
+        let handle: NativeHandle = NativeValue::Boxed(boxed).into();
+
+        if tx.send(handle).is_err() {
+            NativeErrorReporter::global()
+                .report_str("GLib main loop ready but startup channel was closed");
+        }
+    });
+}
+
+fn spawn_glib_thread() -> napi::Result<NativeHandle> {
     let (tx, rx) = mpsc::channel::<NativeHandle>();
 
     std::thread::spawn(move || {
         GlibLogHandler::install();
 
         let main_loop = glib::MainLoop::new(None, false);
-        let main_loop_for_js = main_loop.clone();
-
-        glib::idle_add_once(move || {
-            let gtype = unsafe { glib::Type::from_glib(glib::ffi::g_main_loop_get_type()) };
-            let raw_ptr = IntoGlibPtr::<*mut glib::ffi::GMainLoop>::into_glib_ptr(main_loop_for_js)
-                as *mut c_void;
-            let boxed = Boxed::from_glib_full(Some(gtype), raw_ptr);
-            let handle: NativeHandle = NativeValue::Boxed(boxed).into();
-
-            if tx.send(handle).is_err() {
-                NativeErrorReporter::global()
-                    .report_str("GLib main loop ready but startup channel was closed");
-            }
-        });
-
+        post_startup_barrier(main_loop.clone(), tx);
         main_loop.run();
     });
 
-    let main_loop_handle = rx.recv().map_err(|err| {
+    rx.recv().map_err(|err| {
         napi::Error::new(
             napi::Status::GenericFailure,
             format!("Error starting GLib thread: {err}"),
         )
-    })?;
+    })
+}
 
-    Ok(External::new(main_loop_handle))
+/// Makes the thread running the host's loop on the default context the
+/// `GLib` side of the mailbox, and returns a handle to a loop that never runs.
+pub fn attach_to_host_loop() -> napi::Result<NativeHandle> {
+    let (tx, rx) = mpsc::channel::<NativeHandle>();
+
+    post_startup_barrier(glib::MainLoop::new(None, false), tx);
+
+    rx.recv_timeout(ATTACH_TIMEOUT).map_err(|err| {
+        napi::Error::new(
+            napi::Status::GenericFailure,
+            format!("Error attaching to host GLib main loop: {err}"),
+        )
+    })
 }
 
 /// Emits an `unhandledRejection` event on the Node.js process with a synthesized
@@ -0,0 +1,169 @@
+//! Pointer exchange with other native addons.
+//!
+//! [`import_foreign_object`] wraps a raw `GObject*` obtained elsewhere in the
+//! process (for example from node-gtk or a custom Rust addon linked against
+//! the same GTK) in a managed [`NativeHandle`]. The pointer is validated with
+//! `g_type_check_instance` on the `GLib` thread before it is adopted.
+//!
+//! [`export_pointer`] is the reverse: it hands out the raw pointer of a
+//! managed handle, optionally taking an extra reference on behalf of the
+//! receiver so the object outlives the gtkx handle.
+//!
+//! [`wrap_external_pointer`] is the escape hatch for anything else, such as
+//! a struct pointer from a library gtkx has no bindings for. The pointer is
+//! only checked to be non-null, and the handle never owns or frees it; see
+//! [`NativeHandle::external`].
+
+use std::ffi::c_void;
+
+use gtk4::glib::{
+    self, gobject_ffi,
+    translate::{FromGlibPtrFull as _, FromGlibPtrNone as _},
+};
+use napi::Env;
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+use super::handler::{ModuleRequest, dispatch_request};
+use crate::managed::{NativeHandle, NativeValue};
+use crate::value::Value;
+
+struct ImportForeignObjectRequest {
+    ptr: usize,
+    take_ref: bool,
+}
+
+impl ModuleRequest for ImportForeignObjectRequest {
+    type Output = NativeHandle;
+
+    fn execute(self) -> anyhow::Result<NativeHandle> {
+        let ptr = self.ptr as *mut c_void;
+        if ptr.is_null() {
+            anyhow::bail!("Pointer is null");
+        }
+
+        let instance = ptr.cast::<gobject_ffi::GTypeInstance>();
+        if unsafe { gobject_ffi::g_type_check_instance(instance) } == 0 {
+            anyhow::bail!("Pointer {ptr:p} is not a valid GTypeInstance");
+        }
+        if unsafe {
+            gobject_ffi::g_type_check_instance_is_fundamentally_a(instance, gobject_ffi::G_TYPE_OBJECT)
+        } == 0
+        {
+            anyhow::bail!("Pointer {ptr:p} is not a GObject instance");
+        }
+
+        let gobject_ptr = ptr.cast::<gobject_ffi::GObject>();
+        let object = if self.take_ref {
+            unsafe { glib::Object::from_glib_full(gobject_ptr) }
+        } else {
+            unsafe { glib::Object::from_glib_none(gobject_ptr) }
+        };
+
+        Ok(NativeValue::GObject(object).into())
+    }
+
+    fn error_context() -> &'static str {
+        "importForeignObject"
+    }
+}
+
+/// Takes a reference on a `GObject` for the receiver of an exported pointer.
+/// Only built for handles that own a `GObject`, so the memory is never probed.
+struct ExportPointerRequest {
+    ptr: usize,
+}
+
+impl ModuleRequest for ExportPointerRequest {
+    type Output = Value;
+
+    fn execute(self) -> anyhow::Result<Value> {
+        let ptr = self.ptr as *mut c_void;
+        unsafe { gobject_ffi::g_object_ref(ptr.cast()) };
+        Ok(Value::BigInt(self.ptr as i128))
+    }
+
+    fn error_context() -> &'static str {
+        "exportPointer"
+    }
+}
+
+/// Reads a pointer address passed from JS as a `BigInt`.
+fn address_from_bigint(pointer: &BigInt, what: &str) -> napi::Result<usize> {
+    let (negative, address, lossless) = pointer.get_u64();
+    match usize::try_from(address) {
+        Ok(address) if !negative && lossless => Ok(address),
+        _ => Err(napi::Error::new(
+            napi::Status::InvalidArg,
+            format!("{what} {address:#x} is not a valid address"),
+        )),
+    }
+}
+
+/// Adopts a `GObject*` created outside gtkx.
+///
+/// With `take_ref` the caller transfers one of its references to the returned
+/// handle; otherwise the handle acquires its own reference.
+#[napi]
+pub fn import_foreign_object(
+    env: &Env,
+    pointer: BigInt,
+    take_ref: Option<bool>,
+) -> napi::Result<Unknown<'_>> {
+    let request = ImportForeignObjectRequest {
+        ptr: address_from_bigint(&pointer, "Pointer")?,
+        take_ref: take_ref.unwrap_or(false),
+    };
+    dispatch_request(env, request)
+}
+
+/// Returns the raw pointer behind `handle`.
+///
+/// With `add_ref` a new `GObject` reference is taken for the receiver, which
+/// becomes responsible for releasing it with `g_object_unref`.
+#[napi]
+pub fn export_pointer<'env>(
+    env: &'env Env,
+    handle: &External<NativeHandle>,
+    add_ref: Option<bool>,
+) -> napi::Result<Unknown<'env>> {
+    if !add_ref.unwrap_or(false) {
+        return Value::BigInt(handle.ptr_as_usize() as i128).to_js_value(env);
+    }
+
+    if !handle.is_object() {
+        return Err(napi::Error::new(
+            napi::Status::InvalidArg,
+            format!(
+                "Only GObject handles can be exported with a reference, got {}",
+                handle.describe()
+            ),
+        ));
+    }
+
+    let request = ExportPointerRequest {
+        ptr: handle.ptr_as_usize(),
+    };
+    dispatch_request(env, request)
+}
+
+/// Wraps a raw pointer from outside gtkx in a non-owning handle.
+///
+/// `type_name` is recorded for error messages and as the default type for
+/// `copy`.
+#[napi]
+pub fn wrap_external_pointer(
+    pointer: BigInt,
+    type_name: Option<String>,
+) -> napi::Result<External<NativeHandle>> {
+    let address = address_from_bigint(&pointer, "External pointer")?;
+    NativeHandle::external(address as *mut c_void, type_name)
+        .map(External::new)
+        .map_err(|err| napi::Error::new(napi::Status::InvalidArg, err.to_string()))
+}
+
+/// Returns whether `handle` was created by [`wrap_external_pointer`].
+#[napi]
+pub fn is_external_pointer(handle: &External<NativeHandle>) -> bool {
+    handle.external_origin().is_some()
+}
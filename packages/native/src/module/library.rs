@@ -0,0 +1,229 @@
+//! Configuration of how native libraries are located and loaded.
+//!
+//! [`configure_library`] sets, per library name as descriptors use it, the
+//! directories to search, the file names to try and the `dlopen` flags, for
+//! distributions with unusual library names or applications bundling
+//! private copies. Options are recorded globally and take effect the next
+//! time the library is loaded, so they are best set before the first call
+//! into it. [`unload_library`] drops a loaded library's handle so it can be
+//! loaded again under new options.
+//!
+//! [`configure_bundle`] prepares a relocatable application bundle: its
+//! library directories are searched for every library, and the environment
+//! GTK reads at startup is pointed at the bundle's data. The environment is
+//! set on the `GLib` thread, where `gtk_init` later reads it.
+
+use std::ffi::c_int;
+use std::path::PathBuf;
+
+use gtk4::glib;
+use libloading::os::unix::{RTLD_GLOBAL, RTLD_LAZY, RTLD_LOCAL, RTLD_NOW};
+use napi::Env;
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+use super::handler::{ModuleRequest, dispatch_request};
+use crate::state::{GtkThreadState, LibraryBundle, LibraryOptions};
+use crate::value::Value;
+
+/// What `XDG_DATA_DIRS` means when unset, per the XDG base directory
+/// specification.
+const DEFAULT_XDG_DATA_DIRS: &str = "/usr/local/share:/usr/share";
+
+/// Options for locating and loading a library.
+#[napi(object)]
+#[derive(Debug)]
+pub struct LibraryLoadOptions {
+    /// Directories tried, in order, before the loader's search path.
+    pub search_paths: Option<Vec<String>>,
+    /// File names tried instead of the comma-separated name.
+    pub sonames: Option<Vec<String>>,
+    /// `dlopen` flags among `lazy`, `now`, `global` and `local`.
+    pub flags: Option<Vec<String>>,
+}
+
+fn parse_flags(flags: &[String]) -> napi::Result<c_int> {
+    flags.iter().try_fold(0, |acc, flag| {
+        let bit = match flag.as_str() {
+            "lazy" => RTLD_LAZY,
+            "now" => RTLD_NOW,
+            "global" => RTLD_GLOBAL,
+            "local" => RTLD_LOCAL,
+            other => {
+                return Err(napi::Error::new(
+                    napi::Status::InvalidArg,
+                    format!("Unknown library flag '{other}'; expected lazy, now, global or local"),
+                ));
+            }
+        };
+        Ok(acc | bit)
+    })
+}
+
+/// Sets how the library named `name` is located and loaded, replacing any
+/// options set before.
+#[napi]
+pub fn configure_library(name: String, options: LibraryLoadOptions) -> napi::Result<()> {
+    let flags = options.flags.as_deref().map(parse_flags).transpose()?;
+    if flags.is_some_and(|flags| flags & (RTLD_LAZY | RTLD_NOW) == 0) {
+        return Err(napi::Error::new(
+            napi::Status::InvalidArg,
+            "Library flags must include 'lazy' or 'now'".to_owned(),
+        ));
+    }
+
+    LibraryOptions::set(
+        name,
+        LibraryOptions {
+            search_paths: options.search_paths.unwrap_or_default(),
+            sonames: options.sonames.unwrap_or_default(),
+            flags,
+        },
+    );
+    Ok(())
+}
+
+struct UnloadLibraryRequest {
+    name: String,
+    close: bool,
+}
+
+impl ModuleRequest for UnloadLibraryRequest {
+    type Output = Value;
+
+    fn execute(self) -> anyhow::Result<Value> {
+        let unloaded = GtkThreadState::with(|state| state.unload_library(&self.name, self.close));
+        Ok(Value::Boolean(unloaded))
+    }
+
+    fn error_context() -> &'static str {
+        "unloadLibrary"
+    }
+}
+
+/// Drops the handle of the library named `name`, so its next use loads it
+/// again. Returns whether it was loaded.
+///
+/// With `close`, the handle is closed, which unmaps the library if nothing
+/// else holds it. Closing a library whose types are registered or whose
+/// threads are still running is unsafe.
+#[napi]
+pub fn unload_library(env: &Env, name: String, close: Option<bool>) -> napi::Result<Unknown<'_>> {
+    let request = UnloadLibraryRequest {
+        name,
+        close: close.unwrap_or(false),
+    };
+    dispatch_request(env, request)
+}
+
+/// Layout of a relocatable application bundle.
+#[napi(object)]
+#[derive(Debug)]
+pub struct BundleOptions {
+    /// Absolute path of the bundle.
+    pub root: String,
+    /// Directories under the root holding libraries. Defaults to `lib`.
+    pub lib_dirs: Option<Vec<String>>,
+    /// Directories under the root prepended to `XDG_DATA_DIRS`. Defaults to
+    /// `share`.
+    pub data_dirs: Option<Vec<String>>,
+    /// Value for `GSK_RENDERER`, if the bundle needs a specific renderer.
+    pub renderer: Option<String>,
+    /// Path under the root of the `gdk-pixbuf` loaders cache. Defaults to
+    /// `lib/gdk-pixbuf-2.0/2.10.0/loaders.cache`.
+    pub pixbuf_loaders: Option<String>,
+}
+
+struct ConfigureBundleRequest {
+    bundle: LibraryBundle,
+    data_dirs: Vec<String>,
+    renderer: Option<String>,
+    pixbuf_loaders: PathBuf,
+}
+
+impl ConfigureBundleRequest {
+    fn set_env(name: &str, value: impl AsRef<std::ffi::OsStr>) -> anyhow::Result<()> {
+        // SAFETY: Runs on the GLib thread, which is the one reading the
+        // environment GTK depends on
+        unsafe { glib::setenv(name, value, true) }
+            .map_err(|err| anyhow::anyhow!("Failed to set {name}: {err}"))
+    }
+
+    fn data_dirs(&self) -> String {
+        let inherited = glib::getenv("XDG_DATA_DIRS")
+            .filter(|dirs| !dirs.is_empty())
+            .map_or_else(
+                || DEFAULT_XDG_DATA_DIRS.to_owned(),
+                |dirs| dirs.to_string_lossy().into_owned(),
+            );
+
+        self.data_dirs
+            .iter()
+            .map(|dir| self.bundle.root.join(dir).to_string_lossy().into_owned())
+            .chain(std::iter::once(inherited))
+            .collect::<Vec<_>>()
+            .join(":")
+    }
+}
+
+impl ModuleRequest for ConfigureBundleRequest {
+    type Output = ();
+
+    fn execute(self) -> anyhow::Result<()> {
+        Self::set_env("XDG_DATA_DIRS", self.data_dirs())?;
+
+        if let Some(renderer) = &self.renderer {
+            Self::set_env("GSK_RENDERER", renderer)?;
+        }
+
+        if self.pixbuf_loaders.is_file() {
+            Self::set_env("GDK_PIXBUF_MODULE_FILE", &self.pixbuf_loaders)?;
+            if let Some(dir) = self.pixbuf_loaders.parent() {
+                Self::set_env("GDK_PIXBUF_MODULEDIR", dir.join("loaders"))?;
+            }
+        }
+
+        LibraryBundle::set(self.bundle);
+        Ok(())
+    }
+
+    fn error_context() -> &'static str {
+        "configureBundle"
+    }
+}
+
+/// Loads libraries from a relocatable application bundle and points the
+/// environment GTK reads at startup at the bundle's data.
+///
+/// Sets `XDG_DATA_DIRS`, `GSK_RENDERER` when a renderer is given, and the
+/// `gdk-pixbuf` loader variables when the loaders cache exists. Call it
+/// before initializing GTK and before the first call into a bundled
+/// library: GTK reads the environment once, and libraries loaded before
+/// keep their handles.
+#[napi]
+pub fn configure_bundle(env: &Env, options: BundleOptions) -> napi::Result<Unknown<'_>> {
+    let root = PathBuf::from(options.root);
+    if !root.is_absolute() {
+        return Err(napi::Error::new(
+            napi::Status::InvalidArg,
+            format!("Bundle root '{}' must be an absolute path", root.display()),
+        ));
+    }
+
+    let pixbuf_loaders = root.join(
+        options
+            .pixbuf_loaders
+            .as_deref()
+            .unwrap_or("lib/gdk-pixbuf-2.0/2.10.0/loaders.cache"),
+    );
+    let request = ConfigureBundleRequest {
+        bundle: LibraryBundle {
+            root,
+            lib_dirs: options.lib_dirs.unwrap_or_else(|| vec!["lib".to_owned()]),
+        },
+        data_dirs: options.data_dirs.unwrap_or_else(|| vec!["share".to_owned()]),
+        renderer: options.renderer,
+        pixbuf_loaders,
+    };
+    dispatch_request(env, request)
+}
@@ -0,0 +1,169 @@
+//! Memory-mapped files.
+//!
+//! [`map_file`] maps a file with `GMappedFile` and returns a managed handle
+//! that unmaps it once released. [`mapped_file_buffer`] exposes the mapping
+//! to JavaScript as a `Buffer` without copying it.
+//!
+//! The buffer takes its own reference on the mapping, released when the
+//! buffer is garbage collected, so it stays valid after the handle is gone.
+//! V8 aborts the process when two external buffers share a memory region, so
+//! a mapping backs at most one buffer at a time: asking for another while the
+//! first is alive is an error.
+
+use std::collections::HashSet;
+use std::ffi::CString;
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+use gtk4::glib::{
+    self,
+    translate::{FromGlibPtrFull as _, IntoGlib as _, from_glib},
+};
+use napi::bindgen_prelude::*;
+use napi::{Env, JsValue as _};
+use napi_derive::napi;
+
+use super::handler::{ModuleRequest, ModuleResponse, dispatch_request};
+use crate::managed::{Boxed, NativeHandle, NativeValue};
+
+struct MapFileRequest {
+    path: String,
+    writable: bool,
+}
+
+impl ModuleRequest for MapFileRequest {
+    type Output = NativeHandle;
+
+    fn execute(self) -> anyhow::Result<NativeHandle> {
+        let path = CString::new(self.path.as_str())?;
+        let mut error = std::ptr::null_mut();
+        let file = unsafe {
+            glib::ffi::g_mapped_file_new(path.as_ptr(), self.writable.into_glib(), &mut error)
+        };
+        if file.is_null() {
+            let error = unsafe { glib::Error::from_glib_full(error) };
+            anyhow::bail!("Cannot map '{}': {error}", self.path);
+        }
+
+        let gtype: glib::Type = unsafe { from_glib(glib::ffi::g_mapped_file_get_type()) };
+        let boxed = Boxed::from_glib_full(Some(gtype), file.cast());
+        Ok(NativeValue::Boxed(boxed).into())
+    }
+
+    fn error_context() -> &'static str {
+        "mapFile"
+    }
+}
+
+static BUFFERED: OnceLock<Mutex<HashSet<usize>>> = OnceLock::new();
+
+/// Addresses of the mappings that currently back a buffer.
+fn buffered() -> &'static Mutex<HashSet<usize>> {
+    BUFFERED.get_or_init(|| Mutex::new(HashSet::new()))
+}
+
+/// Records that `file` backs a buffer. Returns false if it already does.
+fn claim_buffer(file: *mut glib::ffi::GMappedFile) -> bool {
+    buffered()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(file as usize)
+}
+
+/// Releases the buffer's reference on `file`, once the buffer is collected.
+fn release_buffer(file: *mut glib::ffi::GMappedFile) {
+    buffered()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .remove(&(file as usize));
+    unsafe { glib::ffi::g_mapped_file_unref(file) };
+}
+
+/// A reference on a mapping, with the location of its contents.
+struct MappedContents {
+    file: *mut glib::ffi::GMappedFile,
+    data: *mut u8,
+    len: usize,
+}
+
+unsafe impl Send for MappedContents {}
+
+impl ModuleResponse for MappedContents {
+    fn to_js_response(self, env: &Env) -> napi::Result<Unknown<'_>> {
+        if self.len == 0 {
+            unsafe { glib::ffi::g_mapped_file_unref(self.file) };
+            return Ok(BufferSlice::from_data(env, Vec::new())?.to_unknown());
+        }
+
+        let release = |_: Env, file: usize| release_buffer(file as *mut glib::ffi::GMappedFile);
+        let buffer = unsafe {
+            BufferSlice::from_external(env, self.data, self.len, self.file as usize, release)?
+        };
+        Ok(buffer.to_unknown())
+    }
+}
+
+struct MappedFileBufferRequest {
+    /// Address of the mapping's [`NativeHandle`]. The JS thread is blocked in
+    /// `dispatch_request` for the whole task and the `External` is held by
+    /// the call's arguments, so the handle outlives the dereference.
+    handle_addr: usize,
+}
+
+impl ModuleRequest for MappedFileBufferRequest {
+    type Output = MappedContents;
+
+    fn execute(self) -> anyhow::Result<MappedContents> {
+        let handle = unsafe { &*(self.handle_addr as *const NativeHandle) };
+        let gtype: glib::Type = unsafe { from_glib(glib::ffi::g_mapped_file_get_type()) };
+        let file = match handle.value() {
+            Some(NativeValue::Boxed(boxed)) if boxed.gtype() == Some(gtype) => boxed.as_ptr(),
+            _ => anyhow::bail!("Expected a GMappedFile, got {}", handle.describe()),
+        };
+        let file: *mut glib::ffi::GMappedFile = file.cast();
+
+        let len = unsafe { glib::ffi::g_mapped_file_get_length(file) };
+        if len > 0 && !claim_buffer(file) {
+            anyhow::bail!("The mapping already backs a buffer; share the one returned first");
+        }
+        Ok(MappedContents {
+            file: unsafe { glib::ffi::g_mapped_file_ref(file) },
+            data: unsafe { glib::ffi::g_mapped_file_get_contents(file) }.cast(),
+            len,
+        })
+    }
+
+    fn error_context() -> &'static str {
+        "mappedFileBuffer"
+    }
+}
+
+/// Maps the file at `path` into memory.
+///
+/// With `writable` the mapping can be modified, but changes are private to
+/// the process and never written back to the file.
+#[napi]
+pub fn map_file(env: &Env, path: String, writable: Option<bool>) -> napi::Result<Unknown<'_>> {
+    let request = MapFileRequest {
+        path,
+        writable: writable.unwrap_or(false),
+    };
+    dispatch_request(env, request)
+}
+
+/// Returns a `Buffer` over the contents of a mapping from [`map_file`],
+/// without copying them. Fails while an earlier buffer over the same mapping
+/// is still alive.
+#[napi]
+pub fn mapped_file_buffer<'env>(
+    env: &'env Env,
+    handle: &External<NativeHandle>,
+) -> napi::Result<Unknown<'env>> {
+    handle
+        .ensure_alive()
+        .map_err(|e| napi::Error::new(napi::Status::InvalidArg, e.to_string()))?;
+    let handle: &NativeHandle = handle;
+    let request = MappedFileBufferRequest {
+        handle_addr: std::ptr::from_ref(handle) as usize,
+    };
+    dispatch_request(env, request)
+}
@@ -4,9 +4,24 @@
 
 mod alloc;
 mod call;
+mod copy;
+mod enums;
+mod event;
+mod fatal;
 mod field;
+mod flags;
 mod freeze;
 pub(crate) mod handler;
-mod init;
+mod hint;
+mod idle;
+pub mod init;
+mod interop;
+mod library;
+mod mapped_file;
 mod object;
+mod pacing;
+mod queue;
+mod registry;
+mod signal;
 mod stop;
+mod symbols;
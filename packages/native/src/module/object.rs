@@ -3,7 +3,8 @@
 //! The [`get_native_id`] function returns the raw pointer value for a managed
 //! object. This is primarily used for object-identity comparisons in JavaScript.
 //! With pointer-bearing handles, the read is purely synchronous and never
-//! crosses the `GLib` thread boundary.
+//! crosses the `GLib` thread boundary. [`is_object_handle`] tells `GObject`
+//! handles apart from the others in the same way.
 
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
@@ -14,3 +15,8 @@ use crate::managed::NativeHandle;
 pub fn get_native_id(handle: &External<NativeHandle>) -> f64 {
     handle.ptr_as_usize() as f64
 }
+
+#[napi]
+pub fn is_object_handle(handle: &External<NativeHandle>) -> bool {
+    handle.is_object()
+}
@@ -0,0 +1,230 @@
+//! Main-loop pacing and per-iteration timing.
+//!
+//! Both features wrap the main context's poll function, the point where an
+//! iteration of the `GLib` main loop ends its dispatch and blocks for the
+//! next event. The wrapper is installed on the `GLib` thread the first time
+//! either feature is used.
+//!
+//! ## Metrics
+//!
+//! The time between a poll returning and the next poll starting is the busy
+//! part of an iteration: dispatching sources, handling input, and drawing
+//! frames. Totals, the longest and the latest busy time, time blocked in
+//! poll, and time held back by pacing are kept in atomics, so reading them
+//! never waits on the `GLib` thread.
+//!
+//! ## Pacing
+//!
+//! When an embedder runs its own message pump next to GTK's, as Electron
+//! does with Chromium's, GTK waking for every ready event competes with the
+//! host for the CPU and for the display connection. Pacing holds each
+//! iteration back before it polls:
+//!
+//! - with an interval, until that long after the previous iteration began,
+//!   so GTK iterates at most once per display frame
+//! - in external mode, until [`pump_loop`] is called, typically from the
+//!   host's vsync-aligned tick
+//!
+//! Both can be combined, in which case the interval bounds how long GTK waits
+//! for a tick. Work queued from JS always releases a held iteration, so calls
+//! into GTK never wait for the next tick or frame.
+
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU32, AtomicU64, Ordering};
+use std::time::Duration;
+
+use gtk4::glib::{self, translate::ToGlibPtr as _};
+use napi_derive::napi;
+
+use crate::dispatch::Mailbox;
+
+/// Longest single park while waiting for an external tick, after which the
+/// wait re-checks whether pacing was switched off.
+const MAX_PARK: Duration = Duration::from_millis(100);
+
+static ORIGINAL_POLL: OnceLock<glib::ffi::GPollFunc> = OnceLock::new();
+
+static INTERVAL_US: AtomicI64 = AtomicI64::new(0);
+static EXTERNAL: AtomicBool = AtomicBool::new(false);
+static TICKS: AtomicU32 = AtomicU32::new(0);
+
+/// Monotonic time, in microseconds, at which the last poll returned.
+static LAST_RETURN_US: AtomicI64 = AtomicI64::new(0);
+static ITERATIONS: AtomicU64 = AtomicU64::new(0);
+static BUSY_US: AtomicU64 = AtomicU64::new(0);
+static MAX_BUSY_US: AtomicU64 = AtomicU64::new(0);
+static LAST_BUSY_US: AtomicU64 = AtomicU64::new(0);
+static POLL_US: AtomicU64 = AtomicU64::new(0);
+static PACED_US: AtomicU64 = AtomicU64::new(0);
+
+/// How the main loop is held back between iterations. Omitting both fields
+/// lets it run freely.
+#[napi(object)]
+#[derive(Debug)]
+pub struct LoopPacing {
+    /// Minimum time between the starts of two iterations.
+    pub interval_ms: Option<f64>,
+    /// Wait for [`pump_loop`] before each iteration.
+    pub external: Option<bool>,
+}
+
+/// Main-loop timing accumulated since the last reset.
+#[napi(object)]
+#[derive(Debug)]
+pub struct LoopMetrics {
+    pub iterations: u32,
+    /// Total time spent dispatching.
+    pub busy_ms: f64,
+    /// Longest time a single iteration spent dispatching.
+    pub max_busy_ms: f64,
+    /// Time the latest iteration spent dispatching.
+    pub last_busy_ms: f64,
+    /// Total time blocked waiting for events.
+    pub poll_ms: f64,
+    /// Total time iterations were held back by pacing.
+    pub paced_ms: f64,
+}
+
+fn elapsed_since(start_us: i64) -> u64 {
+    (glib::monotonic_time() - start_us).max(0) as u64
+}
+
+/// Blocks until the iteration following the one that ended at `last_return`
+/// may poll. Returns how long it waited, in microseconds.
+fn hold_iteration(last_return: i64) -> u64 {
+    let interval = INTERVAL_US.load(Ordering::Relaxed);
+    let external = EXTERNAL.load(Ordering::Relaxed);
+    if interval == 0 && !external {
+        return 0;
+    }
+
+    let mailbox = Mailbox::global();
+    let start = glib::monotonic_time();
+    let due = (interval > 0).then_some(last_return + interval);
+
+    loop {
+        if external && TICKS.swap(0, Ordering::AcqRel) > 0 {
+            break;
+        }
+        if mailbox.has_glib_pending() || mailbox.is_stopped() {
+            break;
+        }
+        let now = glib::monotonic_time();
+        let park = match due {
+            Some(due) if now >= due => break,
+            Some(due) => Duration::from_micros((due - now) as u64),
+            None if EXTERNAL.load(Ordering::Relaxed) => MAX_PARK,
+            None => break,
+        };
+        mailbox.park_glib(park.min(MAX_PARK));
+    }
+
+    elapsed_since(start)
+}
+
+unsafe extern "C" fn paced_poll(
+    fds: *mut glib::ffi::GPollFD,
+    nfds: std::ffi::c_uint,
+    timeout: std::ffi::c_int,
+) -> std::ffi::c_int {
+    let last_return = LAST_RETURN_US.load(Ordering::Relaxed);
+    if last_return > 0 {
+        let busy = elapsed_since(last_return);
+        ITERATIONS.fetch_add(1, Ordering::Relaxed);
+        BUSY_US.fetch_add(busy, Ordering::Relaxed);
+        MAX_BUSY_US.fetch_max(busy, Ordering::Relaxed);
+        LAST_BUSY_US.store(busy, Ordering::Relaxed);
+    }
+
+    let paced = hold_iteration(last_return);
+    PACED_US.fetch_add(paced, Ordering::Relaxed);
+    let timeout = if timeout > 0 {
+        (i64::from(timeout) - (paced / 1000) as i64).max(0) as std::ffi::c_int
+    } else {
+        timeout
+    };
+
+    let poll_start = glib::monotonic_time();
+    let ready = match ORIGINAL_POLL.get().copied().flatten() {
+        Some(poll) => unsafe { poll(fds, nfds, timeout) },
+        None => unsafe { glib::ffi::g_poll(fds, nfds, timeout) },
+    };
+    POLL_US.fetch_add(elapsed_since(poll_start), Ordering::Relaxed);
+    LAST_RETURN_US.store(glib::monotonic_time(), Ordering::Relaxed);
+    ready
+}
+
+/// Wraps the thread-default main context's poll function. Must run on the
+/// `GLib` thread; later calls are no-ops.
+fn install_paced_poll() {
+    ORIGINAL_POLL.get_or_init(|| {
+        let context = glib::MainContext::ref_thread_default();
+        let context_ptr: *mut glib::ffi::GMainContext = context.to_glib_none().0;
+        unsafe {
+            let original = glib::ffi::g_main_context_get_poll_func(context_ptr);
+            glib::ffi::g_main_context_set_poll_func(context_ptr, Some(paced_poll));
+            original
+        }
+    });
+}
+
+fn ensure_installed() {
+    if ORIGINAL_POLL.get().is_none() {
+        Mailbox::global().schedule_glib(install_paced_poll);
+    }
+}
+
+fn to_ms(us: u64) -> f64 {
+    us as f64 / 1000.0
+}
+
+/// Sets how the main loop is paced between iterations.
+#[napi]
+pub fn set_loop_pacing(pacing: LoopPacing) -> napi::Result<()> {
+    let interval_ms = pacing.interval_ms.unwrap_or(0.0);
+    if !interval_ms.is_finite() || interval_ms < 0.0 {
+        return Err(napi::Error::new(
+            napi::Status::InvalidArg,
+            format!("Invalid pacing interval {interval_ms}, expected a non-negative number"),
+        ));
+    }
+
+    INTERVAL_US.store((interval_ms * 1000.0).round() as i64, Ordering::Relaxed);
+    EXTERNAL.store(pacing.external.unwrap_or(false), Ordering::Relaxed);
+    ensure_installed();
+    Mailbox::global().notify_glib();
+    Ok(())
+}
+
+/// Lets one held iteration run. Ticks that arrive while no iteration is
+/// held collapse into one.
+#[napi]
+pub fn pump_loop() {
+    TICKS.fetch_add(1, Ordering::AcqRel);
+    Mailbox::global().notify_glib();
+}
+
+/// Returns the main-loop timing. With `reset`, the counters restart from
+/// zero after being read. The first call starts collecting.
+#[napi]
+pub fn get_loop_metrics(reset: Option<bool>) -> LoopMetrics {
+    ensure_installed();
+
+    let reset = reset.unwrap_or(false);
+    let read = |counter: &AtomicU64| {
+        if reset {
+            counter.swap(0, Ordering::Relaxed)
+        } else {
+            counter.load(Ordering::Relaxed)
+        }
+    };
+
+    LoopMetrics {
+        iterations: read(&ITERATIONS) as u32,
+        busy_ms: to_ms(read(&BUSY_US)),
+        max_busy_ms: to_ms(read(&MAX_BUSY_US)),
+        last_busy_ms: to_ms(LAST_BUSY_US.load(Ordering::Relaxed)),
+        poll_ms: to_ms(read(&POLL_US)),
+        paced_ms: to_ms(read(&PACED_US)),
+    }
+}
@@ -0,0 +1,63 @@
+//! Dispatch fairness controls and inbox monitoring.
+//!
+//! Budgets bound how many tasks one drain of each inbox runs before it
+//! yields; see the fairness notes on [`crate::dispatch`]. The backpressure
+//! threshold decides when closures with an `overflow` policy start shedding
+//! emissions. Depths are read without waiting on the `GLib` thread, so
+//! monitoring never blocks.
+
+use napi_derive::napi;
+
+use crate::dispatch::Mailbox;
+
+/// Per-drain task limits. Omitted or zero means unlimited.
+#[napi(object)]
+#[derive(Debug)]
+pub struct DispatchBudgets {
+    /// Tasks bound for the `GLib` thread.
+    pub glib: Option<u32>,
+    /// Callbacks bound for the JS thread.
+    pub js: Option<u32>,
+}
+
+/// Current and peak inbox lengths.
+#[napi(object)]
+#[derive(Debug)]
+pub struct QueueDepths {
+    pub glib: u32,
+    pub js: u32,
+    pub glib_peak: u32,
+    pub js_peak: u32,
+    /// Emissions discarded under backpressure.
+    pub dropped: u32,
+}
+
+/// Sets the per-drain task budgets for both inboxes.
+#[napi]
+pub fn set_dispatch_budgets(budgets: DispatchBudgets) {
+    Mailbox::global().set_budgets(
+        budgets.glib.map(|n| n as usize),
+        budgets.js.map(|n| n as usize),
+    );
+}
+
+/// Sets the JS inbox length at which `overflow` closures start shedding
+/// emissions. Zero disables backpressure.
+#[napi]
+pub fn set_backpressure_threshold(threshold: u32) {
+    Mailbox::global().set_backpressure_threshold(threshold as usize);
+}
+
+/// Returns the inbox depths. With `reset_peaks`, peaks restart from the
+/// current depths and the drop count from zero after being read.
+#[napi]
+pub fn get_queue_depths(reset_peaks: Option<bool>) -> QueueDepths {
+    let depths = Mailbox::global().queue_depths(reset_peaks.unwrap_or(false));
+    QueueDepths {
+        glib: depths.glib as u32,
+        js: depths.node as u32,
+        glib_peak: depths.glib_peak as u32,
+        js_peak: depths.node_peak as u32,
+        dropped: depths.dropped as u32,
+    }
+}
@@ -0,0 +1,72 @@
+//! Registration of named type metadata.
+//!
+//! [`register_types`] fills the type registry (see
+//! [`crate::types::registry`]) from plain descriptions, typically digested
+//! from GIR, so descriptors can name boxed, fundamental, enum, flags and
+//! callback types without repeating their library and function names.
+//!
+//! Registration runs on the JS thread, where descriptors are parsed, and
+//! takes effect for every descriptor parsed afterwards.
+
+use napi::bindgen_prelude::*;
+use napi::{Env, JsObject};
+use napi_derive::napi;
+
+use crate::types::{self, RegisteredType, registry};
+
+fn parse_registration(env: &Env, entry: Unknown<'_>) -> napi::Result<(String, RegisteredType)> {
+    let obj: JsObject = unsafe { JsObject::from_napi_value(env.raw(), entry.raw())? };
+    let kind: String = obj.get_named_property("kind")?;
+    let type_name: String = obj.get_named_property("typeName")?;
+
+    let registered = match kind.as_str() {
+        "boxed" | "enum" | "flags" => RegisteredType::Typed {
+            library: obj.get_named_property("library")?,
+            get_type_fn: obj.get_named_property("getTypeFn")?,
+        },
+        "fundamental" => RegisteredType::Fundamental {
+            library: obj.get_named_property("library")?,
+            ref_fn: obj.get_named_property("refFn")?,
+            unref_fn: obj.get_named_property("unrefFn")?,
+        },
+        "callback" => {
+            let (arg_types, return_type) =
+                types::parse_callback_arg_and_return_types(env, &obj, "callback")?;
+            RegisteredType::Callback {
+                arg_types,
+                return_type: *return_type,
+            }
+        }
+        other => {
+            return Err(napi::Error::new(
+                napi::Status::InvalidArg,
+                format!("Unknown type registration kind '{other}' for '{type_name}'"),
+            ));
+        }
+    };
+
+    Ok((type_name, registered))
+}
+
+/// Registers the metadata of named types for descriptors that omit it.
+///
+/// Every entry is validated before any is registered, so a malformed entry
+/// leaves the registry unchanged.
+#[napi]
+pub fn register_types(env: &Env, entries: Array) -> napi::Result<()> {
+    let mut registrations = Vec::with_capacity(entries.len() as usize);
+    for i in 0..entries.len() {
+        let entry: Unknown<'_> = entries.get(i)?.ok_or_else(|| {
+            napi::Error::new(
+                napi::Status::GenericFailure,
+                format!("Type registration {i} missing"),
+            )
+        })?;
+        registrations.push(parse_registration(env, entry)?);
+    }
+
+    for (type_name, registered) in registrations {
+        registry::register(type_name, registered);
+    }
+    Ok(())
+}
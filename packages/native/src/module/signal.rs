@@ -0,0 +1,229 @@
+//! Signal handler management.
+//!
+//! Connecting a handler through [`call`](super::call) takes a descriptor for
+//! `g_signal_connect_closure` and leaves a misspelled signal or detail to a
+//! `GLib` critical warning. [`connect`] resolves the signal name and its
+//! `::detail` for the instance's type, builds the callback's closure and
+//! connects it in one request, failing with an error for an unknown signal.
+//!
+//! The returned handler ID is what [`disconnect`], [`block_handler`] and
+//! [`unblock_handler`] take. They check that the handler is still connected
+//! first, so stale IDs report `false` instead of a warning.
+
+use std::ffi::CString;
+
+use gtk4::glib::{
+    self, gobject_ffi,
+    translate::{FromGlib as _, IntoGlib as _},
+};
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+use super::handler::{ModuleRequest, dispatch_request};
+use crate::{
+    arg::Arg,
+    ffi,
+    managed::NativeHandle,
+    types::{CallbackType, Type},
+    value::{Callback, Value},
+};
+
+/// Options for [`connect`].
+#[napi(object)]
+#[derive(Debug)]
+pub struct ConnectFlags {
+    /// Run the handler after the signal's default handler
+    /// (`G_CONNECT_AFTER`).
+    pub after: Option<bool>,
+}
+
+/// Returns the address of the `GObject` `handle` owns, or an error naming
+/// the handle if it owns something else. Decided by the handle's kind, so
+/// the memory of a boxed or struct handle is never read as an instance.
+fn object_address(handle: &NativeHandle) -> napi::Result<usize> {
+    if handle.is_object() && !handle.ptr().is_null() {
+        return Ok(handle.ptr_as_usize());
+    }
+    Err(napi::Error::new(
+        napi::Status::InvalidArg,
+        format!("Signal handlers need a GObject, got {}", handle.describe()),
+    ))
+}
+
+struct ConnectRequest {
+    ptr: usize,
+    signal: String,
+    callback_type: CallbackType,
+    callback: Callback,
+    after: bool,
+}
+
+impl ModuleRequest for ConnectRequest {
+    type Output = Value;
+
+    fn execute(self) -> anyhow::Result<Value> {
+        let object = self.ptr as *mut gobject_ffi::GObject;
+        let gtype = unsafe { (*(*object).g_type_instance.g_class).g_type };
+        let name = CString::new(self.signal.as_str())?;
+
+        let (mut signal_id, mut detail) = (0, 0);
+        let parsed = unsafe {
+            gobject_ffi::g_signal_parse_name(
+                name.as_ptr(),
+                gtype,
+                &mut signal_id,
+                &mut detail,
+                glib::ffi::GTRUE,
+            )
+        };
+        if parsed == 0 {
+            let type_name = unsafe { glib::Type::from_glib(gtype) };
+            anyhow::bail!("Type {type_name} has no signal '{}'", self.signal);
+        }
+
+        let ffi::FfiValue::Storage(closure) = self.callback_type.build_ffi_value(&self.callback)
+        else {
+            anyhow::bail!("Callback did not produce a closure");
+        };
+        let handler_id = unsafe {
+            gobject_ffi::g_signal_connect_closure_by_id(
+                object.cast(),
+                signal_id,
+                detail,
+                closure.ptr().cast(),
+                self.after.into_glib(),
+            )
+        };
+        if handler_id == 0 {
+            anyhow::bail!("Could not connect to signal '{}'", self.signal);
+        }
+
+        Ok(Value::Number(handler_id as f64))
+    }
+
+    fn error_context() -> &'static str {
+        "connect"
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum HandlerAction {
+    Disconnect,
+    Block,
+    Unblock,
+}
+
+struct HandlerRequest {
+    ptr: usize,
+    handler_id: glib::ffi::gulong,
+    action: HandlerAction,
+}
+
+impl ModuleRequest for HandlerRequest {
+    type Output = Value;
+
+    fn execute(self) -> anyhow::Result<Value> {
+        let object = self.ptr as glib::ffi::gpointer;
+        let connected = self.handler_id != 0
+            && unsafe { gobject_ffi::g_signal_handler_is_connected(object, self.handler_id) } != 0;
+        if !connected {
+            return Ok(Value::Boolean(false));
+        }
+
+        unsafe {
+            match self.action {
+                HandlerAction::Disconnect => {
+                    gobject_ffi::g_signal_handler_disconnect(object, self.handler_id);
+                }
+                HandlerAction::Block => {
+                    gobject_ffi::g_signal_handler_block(object, self.handler_id);
+                }
+                HandlerAction::Unblock => {
+                    gobject_ffi::g_signal_handler_unblock(object, self.handler_id);
+                }
+            }
+        }
+        Ok(Value::Boolean(true))
+    }
+
+    fn error_context() -> &'static str {
+        "signal handler"
+    }
+}
+
+fn dispatch_handler_action<'env>(
+    env: &'env Env,
+    handle: &External<NativeHandle>,
+    handler_id: i64,
+    action: HandlerAction,
+) -> napi::Result<Unknown<'env>> {
+    let request = HandlerRequest {
+        ptr: object_address(handle)?,
+        handler_id: glib::ffi::gulong::try_from(handler_id).unwrap_or(0),
+        action,
+    };
+    dispatch_request(env, request)
+}
+
+/// Connects a closure callback to a signal of a `GObject`, and returns the
+/// handler ID.
+///
+/// `signal` may carry a detail, as in `notify::label`. `callback` is an
+/// argument descriptor whose type is a closure callback type.
+#[napi]
+pub fn connect<'env>(
+    env: &'env Env,
+    handle: &External<NativeHandle>,
+    signal: String,
+    callback: Unknown<'_>,
+    flags: Option<ConnectFlags>,
+) -> napi::Result<Unknown<'env>> {
+    let arg = Arg::from_js_value(env, callback)?;
+    let (Type::Callback(callback_type), Value::Callback(callback)) = (arg.ty, arg.value) else {
+        return Err(napi::Error::new(
+            napi::Status::InvalidArg,
+            "connect expects a function with a closure callback type".to_owned(),
+        ));
+    };
+
+    let request = ConnectRequest {
+        ptr: object_address(handle)?,
+        signal,
+        callback_type,
+        callback,
+        after: flags.and_then(|f| f.after).unwrap_or(false),
+    };
+    dispatch_request(env, request)
+}
+
+/// Disconnects a signal handler. Returns false if it was not connected.
+#[napi]
+pub fn disconnect<'env>(
+    env: &'env Env,
+    handle: &External<NativeHandle>,
+    handler_id: i64,
+) -> napi::Result<Unknown<'env>> {
+    dispatch_handler_action(env, handle, handler_id, HandlerAction::Disconnect)
+}
+
+/// Blocks a signal handler. Blocks nest, each one undone by an
+/// [`unblock_handler`]. Returns false if the handler was not connected.
+#[napi]
+pub fn block_handler<'env>(
+    env: &'env Env,
+    handle: &External<NativeHandle>,
+    handler_id: i64,
+) -> napi::Result<Unknown<'env>> {
+    dispatch_handler_action(env, handle, handler_id, HandlerAction::Block)
+}
+
+/// Undoes one [`block_handler`]. Returns false if the handler was not
+/// connected.
+#[napi]
+pub fn unblock_handler<'env>(
+    env: &'env Env,
+    handle: &External<NativeHandle>,
+    handler_id: i64,
+) -> napi::Result<Unknown<'env>> {
+    dispatch_handler_action(env, handle, handler_id, HandlerAction::Unblock)
+}
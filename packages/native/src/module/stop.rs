@@ -10,8 +10,9 @@
 //! 1. Mark the mailbox stopped, fencing further JS-side cleanup schedules.
 //!    Subsequent JS-thread drops of [`crate::managed::NativeHandle`] hit the
 //!    [`std::mem::forget`] branch instead of queuing onto a dying main loop.
-//! 2. Drain all pending sources on the default main context, running queued
-//!    cleanup callbacks while the `GLib` main loop is still alive.
+//! 2. Release the batched handle finalize queue, then drain all pending
+//!    sources on the default main context, running queued cleanup callbacks
+//!    while the `GLib` main loop is still alive.
 //! 3. Quit the main loop, allowing `main_loop.run()` on the spawned thread to
 //!    return.
 //!
@@ -26,15 +27,19 @@ use napi::bindgen_prelude::*;
 use napi_derive::napi;
 
 use crate::dispatch::Mailbox;
-use crate::managed::NativeHandle;
+use crate::managed::{self, NativeHandle};
+use crate::state::JsThread;
 
 #[napi]
 pub fn stop(env: Env, main_loop: &External<NativeHandle>) -> napi::Result<()> {
+    JsThread::global().ensure_current("stop")?;
+
     let main_loop_addr = main_loop.ptr() as usize;
 
     Mailbox::global()
         .dispatch_to_glib_and_wait(env, move || {
             Mailbox::global().mark_stopped();
+            managed::drain_finalize_queue();
             drain_pending_sources();
             unsafe { glib::ffi::g_main_loop_quit(main_loop_addr as *mut glib::ffi::GMainLoop) };
         })
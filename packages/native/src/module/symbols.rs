@@ -0,0 +1,173 @@
+//! Introspection of the functions a library exports.
+//!
+//! [`list_symbols`] lets tooling discover which functions are available at
+//! runtime, for example to check generated bindings against the library
+//! versions actually installed.
+//!
+//! The library is opened with the platform dynamic loader, which resolves
+//! the name exactly as calls do, and `dlinfo` reports the file it loaded.
+//! The functions are then read from that file's ELF dynamic symbol table,
+//! so only defined, globally visible functions are listed.
+
+use std::ffi::{CStr, c_char, c_int, c_void};
+
+use anyhow::{Context as _, bail};
+use libloading::os::unix::{Library, RTLD_LAZY};
+use napi_derive::napi;
+
+use crate::state::{LibraryBundle, LibraryOptions};
+
+const RTLD_DI_LINKMAP: c_int = 2;
+
+const SHT_DYNSYM: usize = 11;
+const STT_FUNC: u8 = 2;
+const STB_GLOBAL: u8 = 1;
+const STB_WEAK: u8 = 2;
+const SYMBOL_SIZE: usize = 24;
+
+unsafe extern "C" {
+    fn dlinfo(handle: *mut c_void, request: c_int, info: *mut c_void) -> c_int;
+}
+
+/// Opens the first candidate for the library `names` the loader can find
+/// and returns the path of the file it loaded.
+fn library_path(names: &str) -> anyhow::Result<String> {
+    let mut last_error = None;
+    let bundle = LibraryBundle::get();
+    for name in LibraryOptions::get(names).candidates(names, bundle.as_ref()) {
+        let library = match unsafe { Library::open(Some(&name), RTLD_LAZY) } {
+            Ok(library) => library,
+            Err(err) => {
+                last_error = Some(err);
+                continue;
+            }
+        };
+
+        let handle = library.into_raw();
+        let mut link_map: *const *const c_char = std::ptr::null();
+        let status = unsafe { dlinfo(handle, RTLD_DI_LINKMAP, (&raw mut link_map).cast()) };
+        // `l_name` follows `l_addr` at the start of `struct link_map`.
+        let path = (status == 0 && !link_map.is_null()).then(|| {
+            let name = unsafe { CStr::from_ptr(*link_map.add(1)) };
+            name.to_string_lossy().into_owned()
+        });
+        drop(unsafe { Library::from_raw(handle) });
+
+        return path.with_context(|| format!("Cannot locate the file of library '{name}'"));
+    }
+
+    match last_error {
+        Some(err) => bail!("Failed to load library '{names}': {err}"),
+        None => bail!("Failed to load library '{names}': no libraries specified"),
+    }
+}
+
+struct Section {
+    kind: usize,
+    offset: usize,
+    size: usize,
+    link: usize,
+}
+
+/// Contents of a little-endian ELF64 file.
+struct Elf<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Elf<'a> {
+    fn parse(bytes: &'a [u8]) -> anyhow::Result<Self> {
+        if bytes.get(..4) != Some(b"\x7fELF".as_slice()) {
+            bail!("Not an ELF file");
+        }
+        if bytes.get(4..6) != Some([2, 1].as_slice()) {
+            bail!("Only little-endian 64-bit ELF files are supported");
+        }
+        Ok(Self { bytes })
+    }
+
+    fn read<const N: usize>(&self, offset: usize) -> anyhow::Result<[u8; N]> {
+        offset
+            .checked_add(N)
+            .and_then(|end| self.bytes.get(offset..end))
+            .and_then(|bytes| bytes.try_into().ok())
+            .context("Truncated ELF file")
+    }
+
+    fn read_u16(&self, offset: usize) -> anyhow::Result<usize> {
+        Ok(u16::from_le_bytes(self.read(offset)?).into())
+    }
+
+    fn read_u32(&self, offset: usize) -> anyhow::Result<usize> {
+        Ok(u32::from_le_bytes(self.read(offset)?) as usize)
+    }
+
+    fn read_u64(&self, offset: usize) -> anyhow::Result<usize> {
+        Ok(u64::from_le_bytes(self.read(offset)?) as usize)
+    }
+
+    fn section(&self, index: usize) -> anyhow::Result<Section> {
+        let header = self.read_u64(0x28)? + index * self.read_u16(0x3a)?;
+        Ok(Section {
+            kind: self.read_u32(header + 0x04)?,
+            offset: self.read_u64(header + 0x18)?,
+            size: self.read_u64(header + 0x20)?,
+            link: self.read_u32(header + 0x28)?,
+        })
+    }
+
+    fn string(&self, offset: usize) -> anyhow::Result<&'a str> {
+        let bytes = self.bytes.get(offset..).context("Truncated ELF file")?;
+        Ok(CStr::from_bytes_until_nul(bytes)?.to_str()?)
+    }
+
+    /// Names of the defined global and weak functions in the dynamic
+    /// symbol table.
+    fn exported_functions(&self) -> anyhow::Result<Vec<&'a str>> {
+        let mut names = Vec::new();
+        for index in 0..self.read_u16(0x3c)? {
+            let section = self.section(index)?;
+            if section.kind != SHT_DYNSYM {
+                continue;
+            }
+            let strings = self.section(section.link)?.offset;
+            let end = section.offset + section.size;
+            for symbol in (section.offset..end).step_by(SYMBOL_SIZE) {
+                let [info] = self.read(symbol + 4)?;
+                let defined = self.read_u16(symbol + 6)? != 0;
+                let visible = matches!(info >> 4, STB_GLOBAL | STB_WEAK);
+                if defined && visible && info & 0xf == STT_FUNC {
+                    names.push(self.string(strings + self.read_u32(symbol)?)?);
+                }
+            }
+        }
+        Ok(names)
+    }
+}
+
+fn exported_functions(library: &str, prefix: &str) -> anyhow::Result<Vec<String>> {
+    let path = library_path(library)?;
+    let bytes = std::fs::read(&path).map_err(|e| anyhow::anyhow!("Cannot read '{path}': {e}"))?;
+    let functions = Elf::parse(&bytes)
+        .and_then(|elf| elf.exported_functions())
+        .map_err(|e| anyhow::anyhow!("Cannot read the symbols of '{path}': {e}"))?;
+
+    let mut names: Vec<String> = functions
+        .into_iter()
+        .filter(|name| name.starts_with(prefix))
+        .map(str::to_owned)
+        .collect();
+    names.sort_unstable();
+    names.dedup();
+    Ok(names)
+}
+
+/// Lists the functions exported by `library` whose names start with
+/// `prefix`, sorted by name.
+///
+/// `library` is resolved like the library of a call, including
+/// comma-separated fallbacks and the options set by `configureLibrary`.
+#[napi]
+pub fn list_symbols(library: String, prefix: Option<String>) -> napi::Result<Vec<String>> {
+    exported_functions(&library, prefix.as_deref().unwrap_or(""))
+        .map_err(|e| napi::Error::new(napi::Status::GenericFailure, e.to_string()))
+}
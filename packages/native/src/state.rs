@@ -4,15 +4,23 @@
 //! focused single-responsibility types:
 //!
 //! - [`LibraryCache`]: Caches dynamically loaded native libraries
+//! - [`LibraryOptions`]: How a library name is located and opened, set per
+//!   name from any thread
+//! - [`LibraryBundle`]: Library directories of a relocatable application bundle
 //! - [`FundamentalFnCache`]: Caches ref/unref function pointers for fundamental types
 //! - [`GtkThreadState`]: Thin coordinator composing the above, accessed via [`GtkThreadState::with`]
 //! - [`GtkThread`]: Singleton for GTK thread lifecycle management
+//! - [`JsThread`]: Records which JS thread owns the runtime, so calls from
+//!   Node `worker_threads` can be routed safely
 
 use std::cell::RefCell;
 use std::collections::{HashMap, hash_map::Entry};
+use std::ffi::c_int;
 use std::mem::ManuallyDrop;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Mutex, OnceLock};
-use std::thread::JoinHandle;
+use std::thread::{JoinHandle, ThreadId};
 
 use libloading::os::unix::{Library, RTLD_GLOBAL, RTLD_NOW};
 
@@ -64,6 +72,207 @@ impl GtkThread {
     }
 }
 
+/// The JS thread that called `init` first and owns the JS side of the mailbox.
+///
+/// The native module is loaded once per process, so every `worker_thread`
+/// shares the same `GLib` thread and mailbox. Only the owner thread services
+/// the node inbox; other JS threads may issue calls, which block on their own
+/// result channel, but they cannot pass callbacks, freeze, or stop the runtime
+/// because those require the owner's `napi::Env`.
+#[derive(Debug)]
+pub struct JsThread {
+    owner: OnceLock<ThreadId>,
+    main_loop: AtomicUsize,
+}
+
+static JS_THREAD: OnceLock<JsThread> = OnceLock::new();
+
+impl JsThread {
+    pub fn global() -> &'static Self {
+        JS_THREAD.get_or_init(|| Self {
+            owner: OnceLock::new(),
+            main_loop: AtomicUsize::new(0),
+        })
+    }
+
+    /// Claims ownership for the current thread if no owner exists yet.
+    /// Returns whether the current thread is the owner.
+    pub fn claim(&self) -> bool {
+        let current = std::thread::current().id();
+        *self.owner.get_or_init(|| current) == current
+    }
+
+    /// Returns whether the current thread is the owner. Before any thread has
+    /// claimed ownership every thread is treated as the owner.
+    #[must_use]
+    pub fn is_current(&self) -> bool {
+        self.owner
+            .get()
+            .is_none_or(|owner| *owner == std::thread::current().id())
+    }
+
+    /// Fails with [`ForeignJsThreadError`] unless called from the owner thread.
+    pub fn ensure_current(&self, operation: &'static str) -> Result<(), ForeignJsThreadError> {
+        if self.is_current() {
+            Ok(())
+        } else {
+            Err(ForeignJsThreadError { operation })
+        }
+    }
+
+    pub fn set_main_loop(&self, ptr: usize) {
+        self.main_loop.store(ptr, Ordering::Release);
+    }
+
+    #[must_use]
+    pub fn main_loop(&self) -> usize {
+        self.main_loop.load(Ordering::Acquire)
+    }
+}
+
+/// Returned when an operation that needs the owner thread's `napi::Env` is
+/// attempted from another JS thread, such as a Node `worker_thread`.
+#[derive(Debug, Clone, Copy)]
+pub struct ForeignJsThreadError {
+    operation: &'static str,
+}
+
+impl std::fmt::Display for ForeignJsThreadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} is only available on the JS thread that initialized gtkx, not from a worker thread",
+            self.operation
+        )
+    }
+}
+
+impl std::error::Error for ForeignJsThreadError {}
+
+impl From<ForeignJsThreadError> for napi::Error {
+    fn from(err: ForeignJsThreadError) -> Self {
+        Self::new(napi::Status::GenericFailure, err.to_string())
+    }
+}
+
+/// How the library that descriptors refer to by a given name is located and
+/// opened.
+///
+/// By default the name is a comma-separated list of file names tried in
+/// order, each resolved by the dynamic loader and opened with
+/// `RTLD_NOW | RTLD_GLOBAL`.
+#[derive(Debug, Clone, Default)]
+pub struct LibraryOptions {
+    /// Directories tried, in order, before the loader's own search path.
+    pub search_paths: Vec<String>,
+    /// File names tried instead of those in the name, e.g. other versions.
+    pub sonames: Vec<String>,
+    /// `dlopen` flags, if not the default ones.
+    pub flags: Option<c_int>,
+}
+
+static LIBRARY_OPTIONS: OnceLock<Mutex<HashMap<String, LibraryOptions>>> = OnceLock::new();
+
+fn library_options_map() -> &'static Mutex<HashMap<String, LibraryOptions>> {
+    LIBRARY_OPTIONS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+impl LibraryOptions {
+    /// Sets the options of the library named `name`. A library already
+    /// loaded under that name keeps its handle until unloaded.
+    pub fn set(name: String, options: Self) {
+        library_options_map()
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .insert(name, options);
+    }
+
+    /// Returns the options of the library named `name`.
+    #[must_use]
+    pub fn get(name: &str) -> Self {
+        library_options_map()
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get(name)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Returns the paths to open, in order, for the library named `name`:
+    /// every file name in each search path, then in each library directory
+    /// of `bundle`, then every file name on its own.
+    ///
+    /// Relative search paths resolve against the bundle root.
+    #[must_use]
+    pub fn candidates(&self, name: &str, bundle: Option<&LibraryBundle>) -> Vec<String> {
+        let sonames: Vec<&str> = if self.sonames.is_empty() {
+            name.split(',').collect()
+        } else {
+            self.sonames.iter().map(String::as_str).collect()
+        };
+
+        let search_paths = self.search_paths.iter().map(|dir| match bundle {
+            Some(bundle) => bundle.root.join(dir),
+            None => PathBuf::from(dir),
+        });
+        let bundle_dirs = bundle
+            .into_iter()
+            .flat_map(|bundle| bundle.lib_dirs.iter().map(|dir| bundle.root.join(dir)));
+
+        let mut candidates = Vec::new();
+        for dir in search_paths.chain(bundle_dirs) {
+            for soname in sonames.iter().filter(|soname| !soname.contains('/')) {
+                candidates.push(dir.join(soname).to_string_lossy().into_owned());
+            }
+        }
+        candidates.extend(sonames.into_iter().map(str::to_owned));
+        candidates
+    }
+
+    #[must_use]
+    pub fn flags(&self) -> c_int {
+        self.flags.unwrap_or(RTLD_NOW | RTLD_GLOBAL)
+    }
+}
+
+/// A relocatable application bundle carrying its own copies of libraries,
+/// as packaged by Flatpak, AppImage or Electron builders.
+///
+/// Its library directories are searched for every library after the
+/// library's own search paths, which resolve against `root` when relative.
+#[derive(Debug, Clone, Default)]
+pub struct LibraryBundle {
+    /// Absolute path of the bundle.
+    pub root: PathBuf,
+    /// Directories under `root` holding libraries.
+    pub lib_dirs: Vec<String>,
+}
+
+static LIBRARY_BUNDLE: OnceLock<Mutex<Option<LibraryBundle>>> = OnceLock::new();
+
+fn library_bundle() -> &'static Mutex<Option<LibraryBundle>> {
+    LIBRARY_BUNDLE.get_or_init(|| Mutex::new(None))
+}
+
+impl LibraryBundle {
+    /// Sets the bundle libraries are loaded from. Libraries already loaded
+    /// keep their handles until unloaded.
+    pub fn set(bundle: Self) {
+        *library_bundle()
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(bundle);
+    }
+
+    /// Returns the bundle libraries are loaded from, if one is set.
+    #[must_use]
+    pub fn get() -> Option<Self> {
+        library_bundle()
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    }
+}
+
 pub struct LibraryCache {
     /// Wrapped in `ManuallyDrop` because libraries like `WebKit` spawn threads with
     /// TLS destructors — calling `dlclose()` while those threads exist causes
@@ -90,13 +299,13 @@ impl LibraryCache {
         match self.libraries.entry(name.to_string()) {
             Entry::Occupied(entry) => Ok(entry.into_mut()),
             Entry::Vacant(entry) => {
-                let lib_names: Vec<&str> = name.split(',').collect();
+                let options = LibraryOptions::get(name);
                 let mut last_error = None;
 
-                for lib_name in &lib_names {
-                    // SAFETY: Loading a shared library with RTLD_NOW | RTLD_GLOBAL
-                    // is safe as long as the library path is valid
-                    match unsafe { Library::open(Some(*lib_name), RTLD_NOW | RTLD_GLOBAL) } {
+                for candidate in options.candidates(name, LibraryBundle::get().as_ref()) {
+                    // SAFETY: Loading a shared library is safe as long as the
+                    // library path is valid
+                    match unsafe { Library::open(Some(&candidate), options.flags()) } {
                         Ok(lib) => {
                             return Ok(entry.insert(lib));
                         }
@@ -116,6 +325,27 @@ impl LibraryCache {
         }
     }
 
+    /// Drops the cached handle of the library named `name`, so its next use
+    /// loads it again with its current options. Returns whether it was
+    /// loaded.
+    ///
+    /// With `close`, the handle is closed; otherwise the library stays
+    /// mapped, which is the only safe choice for libraries whose threads or
+    /// registered types outlive the handle.
+    pub fn unload(&mut self, name: &str, close: bool) -> bool {
+        match self.libraries.remove(name) {
+            Some(library) if close => {
+                drop(library);
+                true
+            }
+            Some(library) => {
+                std::mem::forget(library);
+                true
+            }
+            None => false,
+        }
+    }
+
     pub fn resolve_gtype(
         &mut self,
         lib_name: &str,
@@ -204,6 +434,10 @@ impl FundamentalFnCache {
         self.cache.insert(key, result);
         Ok(result)
     }
+
+    fn clear(&mut self) {
+        self.cache.clear();
+    }
 }
 
 pub struct GtkThreadState {
@@ -257,4 +491,12 @@ impl GtkThreadState {
     pub fn library(&mut self, name: &str) -> anyhow::Result<&Library> {
         self.libs.get_or_load(name)
     }
+
+    /// Unloads the library named `name` (see [`LibraryCache::unload`]) and
+    /// forgets the fundamental type functions resolved so far, which may
+    /// come from it.
+    pub fn unload_library(&mut self, name: &str, close: bool) -> bool {
+        self.fundamental_fns.clear();
+        self.libs.unload(name, close)
+    }
 }
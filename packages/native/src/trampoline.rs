@@ -8,14 +8,82 @@ use ::libffi::middle as libffi;
 
 use crate::dispatch::Mailbox;
 use crate::error_reporter::NativeErrorReporter;
-use crate::types::{FfiEncoder as _, RawPtrCodec as _, Type};
+use crate::managed::dependents::Lifeline;
+use crate::managed::{Boxed, NativeValue};
+use crate::types::{FfiEncoder as _, RawPtrCodec as _, TrampolineKind, Type};
 use crate::value::{JsCallbackRef, Value};
 
+/// Reads a borrowed struct argument that has no known size, and so cannot be
+/// copied, as a view onto the caller's memory. Returns `None` for every
+/// other type.
+///
+/// Such pointers (a `PangoAttrShape`, a `GskPathPoint`) are only valid for
+/// the duration of the callback; the caller scopes the view to it.
+fn read_struct_view(ty: &Type, arg_ptr: *const c_void) -> Option<Value> {
+    let Type::Struct(struct_type) = ty else {
+        return None;
+    };
+    if struct_type.ownership.is_full() || struct_type.size.is_some() {
+        return None;
+    }
+
+    let ptr = unsafe { *(arg_ptr as *const *mut c_void) };
+    if ptr.is_null() {
+        return Some(Value::Null);
+    }
+    Some(Value::Object(NativeValue::Boxed(Boxed::from_ptr_unowned(ptr)).into()))
+}
+
+/// Reduces a comparator's result to -1, 0 or 1, so fractional differences
+/// are not truncated to "equal". Anything but a number or `BigInt` compares
+/// equal.
+fn compare_result(value: Value) -> Value {
+    let sign = match value {
+        Value::Number(n) if n > 0.0 => 1.0,
+        Value::Number(n) if n < 0.0 => -1.0,
+        Value::BigInt(n) => n.signum() as f64,
+        _ => 0.0,
+    };
+    Value::Number(sign)
+}
+
+/// Converts a predicate's result to a boolean with JS truthiness, so
+/// `(item) => item.name` keeps items with a non-empty name.
+fn filter_result(value: Value) -> Value {
+    let keep = match value {
+        Value::Boolean(b) => b,
+        Value::Number(n) => n != 0.0 && !n.is_nan(),
+        Value::BigInt(n) => n != 0,
+        Value::String(s) => !s.is_empty(),
+        Value::Null | Value::Undefined => false,
+        Value::Bytes(_)
+        | Value::SharedBytes(_)
+        | Value::Object(_)
+        | Value::Array(_)
+        | Value::Callback(_)
+        | Value::Ref(_) => true,
+    };
+    Value::Boolean(keep)
+}
+
+/// Converts a `GSourceFunc` result to `G_SOURCE_CONTINUE` or
+/// `G_SOURCE_REMOVE`. Only `true` and non-zero numbers keep the source, so
+/// a callback that returns nothing runs once rather than forever.
+fn source_result(value: Value) -> Value {
+    let keep = match value {
+        Value::Boolean(b) => b,
+        Value::Number(n) => n != 0.0 && !n.is_nan(),
+        _ => false,
+    };
+    Value::Boolean(keep)
+}
+
 pub struct TrampolineData {
     pub js_func: Arc<JsCallbackRef>,
     pub arg_types: Vec<Type>,
     pub return_type: Type,
     pub user_data_index: Option<usize>,
+    pub kind: TrampolineKind,
     pub is_oneshot: bool,
     pub oneshot_state_ptr: AtomicPtr<TrampolineState>,
 }
@@ -26,6 +94,7 @@ impl std::fmt::Debug for TrampolineData {
             .field("arg_types", &self.arg_types)
             .field("return_type", &self.return_type)
             .field("user_data_index", &self.user_data_index)
+            .field("kind", &self.kind)
             .field("is_oneshot", &self.is_oneshot)
             .finish_non_exhaustive()
     }
@@ -103,6 +172,7 @@ impl TrampolineData {
         result: *mut c_void,
     ) -> Option<*mut TrampolineState> {
         let mut values = Vec::with_capacity(self.arg_types.len());
+        let scope = Lifeline::scope();
 
         for (i, ty) in self.arg_types.iter().enumerate() {
             if self.user_data_index == Some(i) {
@@ -110,6 +180,11 @@ impl TrampolineData {
             }
 
             let arg_ptr = unsafe { *args.add(i) };
+            if let Some(view) = read_struct_view(ty, arg_ptr) {
+                values.push(view);
+                continue;
+            }
+
             match ty.read_from_raw_ptr(arg_ptr, "trampoline arg") {
                 Ok(val) => values.push(val),
                 Err(e) => {
@@ -131,8 +206,10 @@ impl TrampolineData {
             None
         };
 
+        Value::scope_views(&mut values, &scope);
         let js_result =
             Mailbox::global().invoke_node_and_wait(&self.js_func, values, capture_result);
+        scope.cut();
 
         if let Err(ref e) = js_result {
             NativeErrorReporter::global().report(&anyhow::anyhow!(
@@ -141,7 +218,12 @@ impl TrampolineData {
             ));
         }
 
-        let write_result = js_result.map_err(|_| ());
+        let write_result = match self.kind {
+            TrampolineKind::Compare => js_result.map(compare_result).map_err(|_| ()),
+            TrampolineKind::Filter => js_result.map(filter_result).map_err(|_| ()),
+            TrampolineKind::Source => js_result.map(source_result).map_err(|_| ()),
+            TrampolineKind::Default => js_result.map_err(|_| ()),
+        };
         self.return_type
             .write_return_to_raw_ptr(result, &write_result);
 
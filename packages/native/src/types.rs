@@ -9,6 +9,7 @@
 //! ```text
 //! Type
 //! ├── Integer(IntegerKind)    - Sized integers (i8..i64, u8..u64)
+//! ├── PointerSized(PointerSizedType) - gintptr / guintptr, as JS BigInt
 //! ├── Float(FloatKind)        - Floating point (f32, f64)
 //! ├── String(StringType)      - UTF-8 strings (owned or borrowed)
 //! ├── Boolean                 - Boolean values
@@ -17,8 +18,11 @@
 //! ├── Boxed(BoxedType)        - GObject boxed types (e.g., GdkRGBA)
 //! ├── Fundamental(FundamentalType) - Fundamental types (GVariant, GParamSpec, etc.)
 //! ├── Array(ArrayType)        - Arrays, GLists, GSLists
+//! ├── GBytes(GBytesType)      - `GBytes` buffers, as JS `Buffer`s
+//! ├── GValue(GValueType)      - `GValue` containers, built from JS values
 //! ├── Callback(CallbackType)  - JavaScript callback functions
-//! └── Ref(RefType)            - Pointers to values (out parameters)
+//! ├── Ref(RefType)            - Pointers to values (out parameters)
+//! └── GError(GErrorType)      - Trailing `GError**` error slots
 //! ```
 //!
 //! ## Ownership
@@ -45,6 +49,9 @@ use crate::{ffi, value};
 /// Shared parser for the `argTypes` and `returnType` properties used by both
 /// `CallbackType` and `TrampolineType`. Returns the parsed argument types and
 /// return type or returns a JS type error referencing `kind` (e.g. `"callback"`).
+///
+/// When `argTypes` is omitted, the signature registered for `typeName` is
+/// used instead.
 pub(crate) fn parse_callback_arg_and_return_types(
     env: &Env,
     obj: &JsObject,
@@ -52,6 +59,17 @@ pub(crate) fn parse_callback_arg_and_return_types(
 ) -> napi::Result<(Vec<Type>, Box<Type>)> {
     let arg_types_prop: Unknown<'_> = obj.get_named_property("argTypes")?;
     if !arg_types_prop.is_array()? {
+        let type_name = obj
+            .get_named_property::<Option<String>>("typeName")
+            .ok()
+            .flatten();
+        if let Some(RegisteredType::Callback {
+            arg_types,
+            return_type,
+        }) = type_name.as_deref().and_then(registry::lookup)
+        {
+            return Ok((arg_types, Box::new(return_type)));
+        }
         return Err(napi::Error::new(
             napi::Status::InvalidArg,
             format!("'argTypes' property is required for {kind} types"),
@@ -86,10 +104,15 @@ mod boolean;
 mod boxed;
 mod callback;
 mod fundamental;
+mod gbytes;
+mod gerror;
 mod gobject;
+mod gvalue;
 mod hashtable;
 mod numeric;
+mod pointer_sized;
 mod ref_type;
+pub mod registry;
 mod string;
 mod trampoline;
 mod unichar;
@@ -99,14 +122,19 @@ pub use array::ArrayKind;
 pub use array::ArrayType;
 pub use boolean::BooleanType;
 pub use boxed::{BoxedType, StructType};
-pub use callback::CallbackType;
+pub use callback::{CallbackType, ClosurePlan, OverflowPolicy, StaticArg};
 pub use fundamental::FundamentalType;
+pub use gbytes::GBytesType;
+pub use gerror::GErrorType;
 pub use gobject::GObjectType;
+pub use gvalue::GValueType;
 pub use hashtable::{HashTableEntryEncoder, HashTableType};
 pub use numeric::{EnumType, FlagsType, FloatKind, IntegerKind, TaggedType};
+pub use pointer_sized::PointerSizedType;
 pub use ref_type::RefType;
-pub use string::StringType;
-pub use trampoline::TrampolineType;
+pub use registry::RegisteredType;
+pub use string::{StringType, Utf8Policy};
+pub use trampoline::{TrampolineKind, TrampolineType};
 pub use unichar::UnicharType;
 pub use void::VoidType;
 
@@ -273,6 +301,7 @@ impl<T: FfiEncoder + FfiDecoder + RawPtrCodec + GlibValueCodec> FfiCodec for T {
 #[non_exhaustive]
 pub enum Type {
     Integer(IntegerKind),
+    PointerSized(PointerSizedType),
     Float(FloatKind),
     Enum(EnumType),
     Flags(FlagsType),
@@ -284,10 +313,13 @@ pub enum Type {
     Struct(StructType),
     Fundamental(FundamentalType),
     Array(ArrayType),
+    GBytes(GBytesType),
+    GValue(GValueType),
     HashTable(HashTableType),
     Callback(CallbackType),
     Trampoline(TrampolineType),
     Ref(RefType),
+    GError(GErrorType),
     Unichar(UnicharType),
 }
 
@@ -295,6 +327,7 @@ impl std::fmt::Display for Type {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Integer(kind) => write!(f, "Integer({kind:?})"),
+            Self::PointerSized(t) => write!(f, "PointerSized(signed: {})", t.signed),
             Self::Float(kind) => write!(f, "Float({kind:?})"),
             Self::Enum(t) => write!(f, "Enum({})", t.tagged.get_type_fn),
             Self::Flags(t) => write!(f, "Flags({})", t.tagged.get_type_fn),
@@ -306,10 +339,13 @@ impl std::fmt::Display for Type {
             Self::Struct(t) => write!(f, "Struct({})", t.type_name),
             Self::Fundamental(t) => write!(f, "Fundamental({})", t.unref_func),
             Self::Array(_) => write!(f, "Array"),
+            Self::GBytes(_) => write!(f, "GBytes"),
+            Self::GValue(_) => write!(f, "GValue"),
             Self::HashTable(_) => write!(f, "HashTable"),
             Self::Callback(_) => write!(f, "Callback"),
             Self::Trampoline(_) => write!(f, "Trampoline"),
             Self::Ref(t) => write!(f, "Ref({})", t.inner_type),
+            Self::GError(_) => write!(f, "GError"),
             Self::Unichar(_) => write!(f, "Unichar"),
         }
     }
@@ -329,6 +365,8 @@ impl Type {
             "uint32" => Ok(Self::Integer(IntegerKind::U32)),
             "int64" => Ok(Self::Integer(IntegerKind::I64)),
             "uint64" => Ok(Self::Integer(IntegerKind::U64)),
+            "intptr" => Ok(Self::PointerSized(PointerSizedType { signed: true })),
+            "uintptr" => Ok(Self::PointerSized(PointerSizedType { signed: false })),
             "float32" => Ok(Self::Float(FloatKind::F32)),
             "float64" => Ok(Self::Float(FloatKind::F64)),
             "enum" => Ok(Self::Enum(EnumType::from_js_value(env, &obj)?)),
@@ -340,10 +378,13 @@ impl Type {
             "boxed" => Ok(Self::Boxed(BoxedType::from_js_value(env, &obj)?)),
             "struct" => Ok(Self::Struct(StructType::from_js_value(env, &obj)?)),
             "array" => Ok(Self::Array(ArrayType::from_js_value(env, &obj)?)),
+            "gbytes" => Ok(Self::GBytes(GBytesType::from_js_value(env, &obj)?)),
+            "gvalue" => Ok(Self::GValue(GValueType::from_js_value(env, &obj)?)),
             "hashtable" => Ok(Self::HashTable(HashTableType::from_js_value(env, &obj)?)),
             "callback" => Ok(Self::Callback(CallbackType::from_js_value(env, &obj)?)),
             "trampoline" => Ok(Self::Trampoline(TrampolineType::from_js_value(env, &obj)?)),
             "ref" => Ok(Self::Ref(RefType::from_js_value(env, &obj)?)),
+            "gerror" => Ok(Self::GError(GErrorType)),
             "unichar" => Ok(Self::Unichar(UnicharType)),
             "fundamental" => Ok(Self::Fundamental(FundamentalType::from_js_value(
                 env, &obj,
@@ -1,14 +1,14 @@
 use std::ffi::{CStr, CString, c_char, c_void};
 
 use anyhow::bail;
-use gtk4::glib;
+use gtk4::glib::{self, translate::ToGlibPtr as _};
 use napi::bindgen_prelude::*;
 use napi::{Env, JsObject};
 
 use super::{FfiDecoder, FfiEncoder, GlibValueCodec, Ownership, RawPtrCodec};
 use crate::arg::Arg;
 use crate::ffi::{FfiStorage, FfiStorageKind};
-use crate::types::{FloatKind, IntegerKind, Type};
+use crate::types::{FloatKind, IntegerKind, Type, Utf8Policy};
 use crate::{ffi, value};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -20,6 +20,7 @@ pub enum ArrayKind {
     GPtrArray,
     GArray,
     GByteArray,
+    GValueArray,
     Sized { size_index: usize },
     Fixed { size: usize },
 }
@@ -35,10 +36,11 @@ impl std::str::FromStr for ArrayKind {
             "gptrarray" => Ok(Self::GPtrArray),
             "garray" => Ok(Self::GArray),
             "gbytearray" => Ok(Self::GByteArray),
+            "gvaluearray" => Ok(Self::GValueArray),
             "sized" => Ok(Self::Sized { size_index: 0 }),
             "fixed" => Ok(Self::Fixed { size: 0 }),
             _ => Err(format!(
-                "'kind' must be 'array', 'glist', 'gslist', 'gptrarray', 'garray', 'gbytearray', 'sized', or 'fixed'; got '{s}'"
+                "'kind' must be 'array', 'glist', 'gslist', 'gptrarray', 'garray', 'gbytearray', 'gvaluearray', 'sized', or 'fixed'; got '{s}'"
             )),
         }
     }
@@ -46,6 +48,7 @@ impl std::str::FromStr for ArrayKind {
 
 #[derive(Debug, Clone)]
 pub struct ArrayType {
+    /// For `GValueArray`s, `Void` converts each element by the type it holds.
     pub item_type: Box<Type>,
     pub kind: ArrayKind,
     pub ownership: Ownership,
@@ -151,7 +154,18 @@ impl RawPtrCodec for ArrayType {
     }
 }
 
-impl GlibValueCodec for ArrayType {}
+impl GlibValueCodec for ArrayType {
+    fn from_glib_value(&self, gvalue: &glib::Value) -> anyhow::Result<value::Value> {
+        if self.kind != ArrayKind::GValueArray {
+            bail!("Only GValueArray arrays support GLib value conversion");
+        }
+        let ptr = unsafe { glib::gobject_ffi::g_value_get_boxed(gvalue.to_glib_none().0) };
+        if ptr.is_null() {
+            return Ok(value::Value::Null);
+        }
+        self.gvalue_array_items(ptr.cast())
+    }
+}
 
 trait ArrayKindEncoder {
     fn encode_integers(
@@ -547,13 +561,38 @@ impl ArrayType {
             | Type::Callback(_)
             | Type::Trampoline(_)
             | Type::Ref(_)
+            | Type::GBytes(_)
+            | Type::GValue(_)
+            | Type::GError(_)
             | Type::Unichar(_) => None,
         }
     }
 
+    /// Whether elements are single bytes that a `Buffer` or `Uint8Array` can
+    /// provide in one block.
+    fn holds_bytes(&self) -> bool {
+        match self.kind {
+            ArrayKind::GByteArray => true,
+            ArrayKind::Array | ArrayKind::Sized { .. } | ArrayKind::Fixed { .. } => {
+                matches!(*self.item_type, Type::Integer(IntegerKind::U8))
+            }
+            ArrayKind::GList
+            | ArrayKind::GSList
+            | ArrayKind::GPtrArray
+            | ArrayKind::GArray
+            | ArrayKind::GValueArray => false,
+        }
+    }
+
     pub fn encode(&self, val: &value::Value, optional: bool) -> anyhow::Result<ffi::FfiValue> {
         let array = match val {
             value::Value::Array(arr) => arr,
+            value::Value::Bytes(bytes) if self.holds_bytes() => {
+                if self.kind == ArrayKind::GByteArray {
+                    return Ok(self.gbytearray_from_bytes(bytes));
+                }
+                return Ok(ffi::FfiValue::Storage(bytes.clone().into()));
+            }
             value::Value::Null | value::Value::Undefined if optional => {
                 return Ok(ffi::FfiValue::Ptr(std::ptr::null_mut()));
             }
@@ -568,6 +607,10 @@ impl ArrayType {
             return self.encode_garray(array);
         }
 
+        if self.kind == ArrayKind::GValueArray {
+            bail!("GValueArray arrays can only be decoded");
+        }
+
         let encoder: &dyn ArrayKindEncoder = match &self.kind {
             ArrayKind::GList => &GListEncoder,
             ArrayKind::GSList => &GSListEncoder,
@@ -575,7 +618,7 @@ impl ArrayType {
             | ArrayKind::GPtrArray
             | ArrayKind::Sized { .. }
             | ArrayKind::Fixed { .. } => &NullTerminatedArrayEncoder,
-            ArrayKind::GArray | ArrayKind::GByteArray => unreachable!(),
+            ArrayKind::GArray | ArrayKind::GByteArray | ArrayKind::GValueArray => unreachable!(),
         };
 
         match &*self.item_type {
@@ -635,6 +678,9 @@ impl ArrayType {
             | Type::Callback(_)
             | Type::Trampoline(_)
             | Type::Ref(_)
+            | Type::GBytes(_)
+            | Type::GValue(_)
+            | Type::GError(_)
             | Type::Unichar(_) => bail!("Unsupported array item type: {:?}", self.item_type),
         }
     }
@@ -654,6 +700,10 @@ impl ArrayType {
             })
             .collect::<anyhow::Result<Vec<u8>>>()?;
 
+        Ok(self.gbytearray_from_bytes(&bytes))
+    }
+
+    fn gbytearray_from_bytes(&self, bytes: &[u8]) -> ffi::FfiValue {
         let byte_array = unsafe {
             let ba = glib::ffi::g_byte_array_sized_new(bytes.len() as u32);
             glib::ffi::g_byte_array_append(ba, bytes.as_ptr(), bytes.len() as u32);
@@ -661,13 +711,13 @@ impl ArrayType {
         };
 
         let should_free = self.ownership.is_borrowed();
-        Ok(ffi::FfiValue::Storage(FfiStorage::new(
+        ffi::FfiValue::Storage(FfiStorage::new(
             byte_array as *mut c_void,
             FfiStorageKind::GByteArray(ffi::GByteArrayData {
                 array_ptr: byte_array,
                 should_free,
             }),
-        )))
+        ))
     }
 
     fn append_integer_values_to_garray(
@@ -795,6 +845,9 @@ impl ArrayType {
             | Type::Callback(_)
             | Type::Trampoline(_)
             | Type::Ref(_)
+            | Type::GBytes(_)
+            | Type::GValue(_)
+            | Type::GError(_)
             | Type::Unichar(_) => {
                 unsafe { glib::ffi::g_array_unref(g_array) };
                 bail!("Unsupported GArray item type: {:?}", self.item_type);
@@ -834,6 +887,7 @@ impl ArrayType {
             ArrayKind::GArray => return self.decode_garray(ffi_value),
             ArrayKind::GPtrArray => return self.decode_gptrarray(ffi_value),
             ArrayKind::GByteArray => return self.decode_gbytearray(ffi_value),
+            ArrayKind::GValueArray => return self.decode_gvaluearray(ffi_value),
             ArrayKind::Array | ArrayKind::Sized { .. } | ArrayKind::Fixed { .. } => {}
         }
 
@@ -842,8 +896,8 @@ impl ArrayType {
                 return Ok(value::Value::Array(vec![]));
             }
 
-            if matches!(&*self.item_type, Type::String(_)) {
-                return Ok(self.decode_null_terminated_string_array(*ptr));
+            if let Type::String(string_type) = &*self.item_type {
+                return self.decode_null_terminated_string_array(*ptr, string_type.utf8);
             }
 
             return self.decode_null_terminated_ptr_array(*ptr);
@@ -888,7 +942,8 @@ impl ArrayType {
             | ArrayKind::GSList
             | ArrayKind::GPtrArray
             | ArrayKind::GArray
-            | ArrayKind::GByteArray => {}
+            | ArrayKind::GByteArray
+            | ArrayKind::GValueArray => {}
         }
 
         self.decode(ffi_value)
@@ -966,7 +1021,7 @@ impl ArrayType {
                 }
                 values
             }
-            Type::String(_) => {
+            Type::String(string_type) => {
                 let ptrs = unsafe { std::slice::from_raw_parts(data as *const *const c_char, len) };
                 let mut values = Vec::with_capacity(len);
                 for &str_ptr in ptrs {
@@ -974,7 +1029,7 @@ impl ArrayType {
                         values.push(value::Value::Null);
                     } else {
                         let c_str = unsafe { CStr::from_ptr(str_ptr) };
-                        values.push(value::Value::String(c_str.to_string_lossy().into_owned()));
+                        values.push(string_type.utf8.decode(c_str)?);
                     }
                 }
                 values
@@ -993,6 +1048,9 @@ impl ArrayType {
             | Type::Callback(_)
             | Type::Trampoline(_)
             | Type::Ref(_)
+            | Type::GBytes(_)
+            | Type::GValue(_)
+            | Type::GError(_)
             | Type::Unichar(_) => bail!("Unsupported GArray item type: {:?}", self.item_type),
         };
 
@@ -1055,6 +1113,41 @@ impl ArrayType {
         Ok(value::Value::Array(values))
     }
 
+    fn decode_gvaluearray(&self, ffi_value: &ffi::FfiValue) -> anyhow::Result<value::Value> {
+        let Some(ptr) = ffi_value.as_non_null_ptr("GValueArray")? else {
+            return Ok(value::Value::Array(vec![]));
+        };
+
+        let value_array = ptr as *mut glib::gobject_ffi::GValueArray;
+        let values = self.gvalue_array_items(value_array);
+
+        if self.ownership.is_full() {
+            unsafe { glib::gobject_ffi::g_value_array_free(value_array) };
+        }
+
+        values
+    }
+
+    /// Converts the elements of a `GValueArray` with the declared item type,
+    /// or by the type each one holds when the item type is `Void`.
+    fn gvalue_array_items(
+        &self,
+        value_array: *mut glib::gobject_ffi::GValueArray,
+    ) -> anyhow::Result<value::Value> {
+        let len = unsafe { (*value_array).n_values as usize };
+        let data = unsafe { (*value_array).values as *const glib::Value };
+        let mut values = Vec::with_capacity(len);
+        for i in 0..len {
+            let gvalue = unsafe { &*data.add(i) };
+            let item_value = match &*self.item_type {
+                Type::Void(_) => value::Value::try_from(gvalue)?,
+                item_type => item_type.from_glib_value(gvalue)?,
+            };
+            values.push(item_value);
+        }
+        Ok(value::Value::Array(values))
+    }
+
     fn decode_null_terminated_ptr_array(&self, ptr: *mut c_void) -> anyhow::Result<value::Value> {
         let ptr_array = ptr as *const *mut c_void;
         let mut values = Vec::new();
@@ -1076,7 +1169,11 @@ impl ArrayType {
         Ok(value::Value::Array(values))
     }
 
-    fn decode_null_terminated_string_array(&self, ptr: *mut c_void) -> value::Value {
+    fn decode_null_terminated_string_array(
+        &self,
+        ptr: *mut c_void,
+        utf8: Utf8Policy,
+    ) -> anyhow::Result<value::Value> {
         let mut values = Vec::new();
         let str_array = ptr as *const *const c_char;
         let mut i = 0;
@@ -1086,7 +1183,7 @@ impl ArrayType {
                 break;
             }
             let c_str = unsafe { CStr::from_ptr(str_ptr) };
-            values.push(value::Value::String(c_str.to_string_lossy().into_owned()));
+            values.push(utf8.decode(c_str));
             i += 1;
         }
 
@@ -1094,7 +1191,10 @@ impl ArrayType {
             unsafe { glib::ffi::g_strfreev(ptr as *mut *mut c_char) };
         }
 
-        value::Value::Array(values)
+        values
+            .into_iter()
+            .collect::<anyhow::Result<Vec<_>>>()
+            .map(value::Value::Array)
     }
 
     fn decode_storage(&self, storage: &FfiStorage) -> anyhow::Result<value::Value> {
@@ -1116,11 +1216,11 @@ impl ArrayType {
                     f64_vec.iter().map(|v| value::Value::Number(*v)).collect()
                 }
             },
-            Type::String(_) => {
+            Type::String(string_type) => {
                 let cstrings = storage.as_cstring_array()?;
                 cstrings
                     .iter()
-                    .map(|cstr| Ok(value::Value::String(cstr.to_str()?.to_string())))
+                    .map(|cstr| string_type.utf8.decode(cstr))
                     .collect::<anyhow::Result<Vec<value::Value>>>()?
             }
             Type::Boolean(_) => {
@@ -1151,6 +1251,9 @@ impl ArrayType {
             | Type::Callback(_)
             | Type::Trampoline(_)
             | Type::Ref(_)
+            | Type::GBytes(_)
+            | Type::GValue(_)
+            | Type::GError(_)
             | Type::Unichar(_) => bail!(
                 "Unsupported array item type for ffi value conversion: {:?}",
                 self.item_type
@@ -1162,6 +1265,7 @@ impl ArrayType {
 
     fn decode_sized_array(&self, ptr: *mut c_void, length: usize) -> anyhow::Result<value::Value> {
         match &*self.item_type {
+            Type::Integer(IntegerKind::U8) => Ok(Self::decode_sized_bytes(ptr, length)),
             Type::Integer(int_type) => Ok(Self::decode_sized_byte_array(ptr, length, int_type)),
             Type::Float(float_kind) => Ok(Self::decode_sized_float_array(ptr, length, *float_kind)),
             Type::Boolean(_) => Ok(Self::decode_sized_bool_array(ptr, length)),
@@ -1178,6 +1282,9 @@ impl ArrayType {
             | Type::Callback(_)
             | Type::Trampoline(_)
             | Type::Ref(_)
+            | Type::GBytes(_)
+            | Type::GValue(_)
+            | Type::GError(_)
             | Type::Unichar(_) => bail!(
                 "Unsupported item type for sized array: {:?}",
                 self.item_type
@@ -1242,6 +1349,15 @@ impl ArrayType {
         value::Value::Array(values)
     }
 
+    fn decode_sized_bytes(ptr: *mut c_void, length: usize) -> value::Value {
+        if ptr.is_null() {
+            return value::Value::Bytes(vec![]);
+        }
+
+        let bytes = unsafe { std::slice::from_raw_parts(ptr as *const u8, length) };
+        value::Value::Bytes(bytes.to_vec())
+    }
+
     fn decode_sized_byte_array(
         ptr: *mut c_void,
         length: usize,
@@ -1284,6 +1400,7 @@ impl ArrayType {
                 let ffi_value = ffi::FfiValue::Ptr(ptr);
                 self.decode_garray(&ffi_value)
             }
+            ArrayKind::GValueArray => self.gvalue_array_items(ptr.cast()),
             ArrayKind::GList | ArrayKind::GSList => {
                 let ffi_value = ffi::FfiValue::Ptr(ptr);
                 self.decode_glist(&ffi_value)
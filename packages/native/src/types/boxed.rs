@@ -4,6 +4,11 @@
 //! managed by `GLib`. Struct types are similar but may be stack-allocated
 //! or have fixed sizes. This module provides [`BoxedType`] and [`StructType`]
 //! descriptors that handle encoding/decoding these types for FFI calls.
+//!
+//! A [`StructType`] may carry a field layout, with the offset of each
+//! [`StructField`] computed from C alignment rules unless given. Fields
+//! can then be read and written by name, and a field whose type has its own
+//! layout is a struct embedded in its parent rather than a pointer.
 
 use std::ffi::c_void;
 
@@ -12,9 +17,10 @@ use gtk4::glib::{
     self,
     translate::{FromGlib as _, IntoGlib as _, ToGlibPtr as _, ToGlibPtrMut as _},
 };
-use napi::{Env, JsObject};
+use napi::bindgen_prelude::{Array, FromNapiValue as _, Unknown};
+use napi::{Env, JsObject, JsValue as _};
 
-use super::{FfiDecoder, FfiEncoder, GlibValueCodec, Ownership, RawPtrCodec};
+use super::{FfiDecoder, FfiEncoder, GlibValueCodec, Ownership, RawPtrCodec, Type};
 use crate::error_reporter::NativeErrorReporter;
 use crate::managed::{Boxed, NativeValue};
 use crate::state::GtkThreadState;
@@ -44,6 +50,14 @@ impl BoxedType {
             .ok()
             .flatten();
 
+        let (library, get_type_fn) = match (library, get_type_fn) {
+            (Some(library), Some(get_type_fn)) => (Some(library), Some(get_type_fn)),
+            (library, get_type_fn) => match super::registry::lookup_typed(&type_name) {
+                Some((library, get_type_fn)) => (Some(library), Some(get_type_fn)),
+                None => (library, get_type_fn),
+            },
+        };
+
         Ok(Self {
             ownership,
             type_name,
@@ -222,15 +236,53 @@ impl GlibValueCodec for BoxedType {
     }
 }
 
+/// A named field of a [`StructType`], at a byte offset from the start of
+/// the struct.
+#[derive(Debug, Clone)]
+pub struct StructField {
+    pub name: String,
+    pub field_type: Type,
+    pub offset: usize,
+}
+
 #[derive(Debug, Clone)]
 pub struct StructType {
     pub ownership: Ownership,
     pub type_name: String,
     pub size: Option<usize>,
+    /// Field layout, when known. Enables access to fields by name.
+    pub fields: Option<Vec<StructField>>,
+}
+
+fn align_up(offset: usize, align: usize) -> usize {
+    offset.div_ceil(align) * align
+}
+
+/// Returns the size and alignment of a `field_type` value stored in a struct.
+fn field_layout(field_type: &Type) -> anyhow::Result<(usize, usize)> {
+    let pointer = size_of::<*mut c_void>();
+    let layout = match field_type {
+        Type::Integer(kind) => (kind.byte_size(), kind.byte_size()),
+        Type::Float(kind) => (kind.byte_size(), kind.byte_size()),
+        Type::Enum(e) => (e.storage.byte_size(), e.storage.byte_size()),
+        Type::Flags(f) => (f.storage.byte_size(), f.storage.byte_size()),
+        Type::Boolean(_) | Type::Unichar(_) => (4, 4),
+        Type::Struct(inner) if inner.is_embedded() => {
+            (inner.size.unwrap_or(0), inner.alignment()?)
+        }
+        Type::PointerSized(_)
+        | Type::String(_)
+        | Type::GObject(_)
+        | Type::Boxed(_)
+        | Type::Struct(_)
+        | Type::Fundamental(_) => (pointer, pointer),
+        other => bail!("{other} cannot be a struct field"),
+    };
+    Ok(layout)
 }
 
 impl StructType {
-    pub fn from_js_value(_env: &Env, obj: &JsObject) -> napi::Result<Self> {
+    pub fn from_js_value(env: &Env, obj: &JsObject) -> napi::Result<Self> {
         let ownership = Ownership::from_js_value(obj, "struct")?;
 
         let type_name: String = obj.get_named_property("innerType")?;
@@ -241,12 +293,117 @@ impl StructType {
             .flatten()
             .map(|n| n as usize);
 
+        let fields_prop: Option<Unknown<'_>> = obj.get_named_property("fields")?;
+        let Some(fields_prop) = fields_prop.filter(|prop| prop.is_array().unwrap_or(false)) else {
+            return Ok(Self {
+                ownership,
+                type_name,
+                size,
+                fields: None,
+            });
+        };
+
+        let fields_arr: Array = unsafe { Array::from_napi_value(env.raw(), fields_prop.raw())? };
+        let mut fields = Vec::with_capacity(fields_arr.len() as usize);
+        for i in 0..fields_arr.len() {
+            let field: JsObject = fields_arr.get(i)?.ok_or_else(|| {
+                napi::Error::new(napi::Status::InvalidArg, format!("'fields[{i}]' missing"))
+            })?;
+            let name: String = field.get_named_property("name")?;
+            let field_type = Type::from_js_value(env, field.get_named_property("type")?)?;
+            let offset = field
+                .get_named_property::<Option<f64>>("offset")
+                .ok()
+                .flatten()
+                .map(|n| n as usize);
+            fields.push((name, field_type, offset));
+        }
+
+        let mut struct_type = Self::with_fields(ownership, type_name, fields)
+            .map_err(|e| napi::Error::new(napi::Status::InvalidArg, e.to_string()))?;
+        if size.is_some() {
+            struct_type.size = size;
+        }
+        Ok(struct_type)
+    }
+
+    /// Creates a struct type laid out from `fields`, given as name, type and
+    /// optional byte offset. Fields without an offset follow the previous
+    /// field, aligned as a C compiler would, and the struct is sized to fit
+    /// them, padded to its alignment.
+    pub fn with_fields(
+        ownership: Ownership,
+        type_name: String,
+        fields: Vec<(String, Type, Option<usize>)>,
+    ) -> anyhow::Result<Self> {
+        let mut laid_out = Vec::with_capacity(fields.len());
+        let mut end = 0;
+        let mut max_align = 1;
+        for (name, field_type, offset) in fields {
+            let (size, align) = field_layout(&field_type)?;
+            let offset = offset.unwrap_or_else(|| align_up(end, align));
+            end = end.max(offset + size);
+            max_align = max_align.max(align);
+            laid_out.push(StructField {
+                name,
+                field_type,
+                offset,
+            });
+        }
+
         Ok(Self {
             ownership,
             type_name,
-            size,
+            size: Some(align_up(end, max_align)),
+            fields: Some(laid_out),
         })
     }
+
+    /// Returns the alignment of the struct: that of its most aligned field.
+    fn alignment(&self) -> anyhow::Result<usize> {
+        let mut align = 1;
+        for field in self.fields.iter().flatten() {
+            align = align.max(field_layout(&field.field_type)?.1);
+        }
+        Ok(align)
+    }
+
+    /// Returns whether values of this type are stored inline in a parent
+    /// struct, rather than as pointers.
+    pub fn is_embedded(&self) -> bool {
+        self.fields.is_some()
+    }
+
+    /// Resolves a field by name, or a dotted path through embedded structs
+    /// such as `"origin.x"`, to its byte offset and type.
+    pub fn field(&self, path: &str) -> anyhow::Result<(usize, &Type)> {
+        let mut struct_type = self;
+        let mut offset = 0;
+        let mut parts = path.split('.').peekable();
+        while let Some(part) = parts.next() {
+            let Some(fields) = &struct_type.fields else {
+                bail!(
+                    "Struct {} has no field layout; pass a byte offset instead",
+                    struct_type.type_name
+                )
+            };
+            let Some(field) = fields.iter().find(|field| field.name == part) else {
+                bail!("Struct {} has no field '{part}'", struct_type.type_name)
+            };
+            offset += field.offset;
+            if parts.peek().is_none() {
+                return Ok((offset, &field.field_type));
+            }
+            match &field.field_type {
+                Type::Struct(inner) if inner.is_embedded() => struct_type = inner,
+                _ => bail!(
+                    "Field '{part}' of {} is not an embedded struct",
+                    struct_type.type_name
+                ),
+            }
+        }
+        bail!("Empty field name for struct {}", self.type_name)
+    }
 }
 
 impl FfiEncoder for StructType {
@@ -4,43 +4,219 @@ use std::sync::atomic::{AtomicPtr, Ordering};
 
 use gtk4::glib::{
     self, gobject_ffi,
-    translate::{FromGlibPtrFull as _, ToGlibPtr as _},
+    translate::{FromGlibPtrFull as _, FromGlibPtrNone as _, ToGlibPtr as _},
 };
 use libffi::middle as libffi;
 use napi::{Env, JsObject};
 
-use crate::callback::ClosureGuard;
-use crate::dispatch::Mailbox;
+use crate::callback::{ArgPool, ClosureGuard, CoalescedCallback};
+use crate::dispatch::{Mailbox, Priority};
 use crate::error_reporter::NativeErrorReporter;
 use crate::ffi::{self, FfiStorage};
+use crate::managed::dependents::Lifeline;
 use crate::managed::{Boxed, NativeValue};
-use crate::types::{FfiDecoder, FfiEncoder, GlibValueCodec, RawPtrCodec, Type};
+use crate::types::{
+    BoxedType, FfiDecoder, FfiEncoder, FloatKind, GlibValueCodec, IntegerKind, RawPtrCodec, Type,
+};
 use crate::value;
 use crate::value::{Callback, JsCallbackRef};
 
+/// How [`ClosurePlan`] converts one emission argument for JS.
+#[derive(Debug, Clone, Copy)]
+enum ArgStep {
+    /// Read straight from the `GValue` when it holds the expected type.
+    Static(StaticArg),
+    /// Wrapped as a boxed handle, copied when owned or detached.
+    Boxed,
+    /// Converted through the type's [`GlibValueCodec`].
+    Generic,
+}
+
+/// The argument and return conversions of a closure signature, worked out
+/// once when its [`CallbackType`] is parsed.
+///
+/// The plan takes the parsed types without cloning them and is shared by
+/// every closure built from that type, so an emission only follows the
+/// precomputed steps.
+#[derive(Debug)]
+pub struct ClosurePlan {
+    arg_types: Box<[Type]>,
+    steps: Box<[ArgStep]>,
+    /// Indices of [`Type::Ref`] arguments, whose targets are written from
+    /// the extra elements of an array returned by JS.
+    ref_args: Box<[usize]>,
+    return_type: Type,
+}
+
+impl ClosurePlan {
+    #[must_use]
+    pub fn new(arg_types: Vec<Type>, return_type: Type) -> Self {
+        let steps = arg_types
+            .iter()
+            .map(|ty| match ty {
+                Type::Boxed(_) => ArgStep::Boxed,
+                Type::GObject(_) => ArgStep::Generic,
+                _ => StaticArg::from_type(ty).map_or(ArgStep::Generic, ArgStep::Static),
+            })
+            .collect();
+        let ref_args = arg_types
+            .iter()
+            .enumerate()
+            .filter_map(|(i, ty)| matches!(ty, Type::Ref(_)).then_some(i))
+            .collect();
+
+        Self {
+            arg_types: arg_types.into_boxed_slice(),
+            steps,
+            ref_args,
+            return_type,
+        }
+    }
+
+    #[must_use]
+    pub fn arg_types(&self) -> &[Type] {
+        &self.arg_types
+    }
+
+    #[must_use]
+    pub fn return_type(&self) -> &Type {
+        &self.return_type
+    }
+
+    /// Converts emission arguments for JS, appending them to `values`. With
+    /// `detach`, borrowed boxed arguments are copied so they outlive the
+    /// emission.
+    fn convert(
+        &self,
+        args: &[glib::Value],
+        detach: bool,
+        values: &mut Vec<value::Value>,
+    ) -> anyhow::Result<()> {
+        for (gval, (step, ty)) in args.iter().zip(self.steps.iter().zip(self.arg_types.iter())) {
+            let value = match (step, ty) {
+                (ArgStep::Static(arg), _) => arg.read_or_convert(gval, ty)?,
+                (ArgStep::Boxed, Type::Boxed(boxed_type)) => {
+                    Self::convert_boxed(gval, boxed_type, detach)?
+                }
+                _ => value::Value::from_glib_value(gval, ty)?,
+            };
+            values.push(value);
+        }
+        Ok(())
+    }
+
+    fn convert_boxed(
+        gval: &glib::Value,
+        boxed_type: &BoxedType,
+        detach: bool,
+    ) -> anyhow::Result<value::Value> {
+        let boxed_ptr =
+            unsafe { glib::gobject_ffi::g_value_get_boxed(gval.to_glib_none().0 as *const _) };
+        if boxed_ptr.is_null() {
+            return Ok(value::Value::Null);
+        }
+        let boxed = if boxed_type.ownership.is_full() {
+            let gtype = boxed_type.gtype();
+            let owned_ptr =
+                unsafe { glib::gobject_ffi::g_value_dup_boxed(gval.to_glib_none().0 as *const _) };
+            Boxed::from_glib_full(gtype, owned_ptr)
+        } else if detach {
+            Boxed::from_glib_none(boxed_type.gtype(), boxed_ptr)?
+        } else {
+            Boxed::from_ptr_unowned(boxed_ptr)
+        };
+        Ok(value::Value::Object(NativeValue::Boxed(boxed).into()))
+    }
+
+    /// Returns the target pointers of the emission's `Ref` arguments with
+    /// their inner types, or an empty list without allocating when the
+    /// signature has none.
+    fn ref_targets(&self, args: &[glib::Value]) -> Vec<(*mut c_void, &Type)> {
+        self.ref_args
+            .iter()
+            .filter_map(|&i| {
+                let Type::Ref(ref_type) = &self.arg_types[i] else {
+                    return None;
+                };
+                let gval = args.get(i)?;
+                let ptr = unsafe {
+                    glib::gobject_ffi::g_value_get_pointer(gval.to_glib_none().0 as *const _)
+                };
+                Some((ptr, &*ref_type.inner_type))
+            })
+            .collect()
+    }
+}
+
 struct ClosureContext {
     js_func: Arc<JsCallbackRef>,
-    arg_types: Vec<Type>,
+    plan: Arc<ClosurePlan>,
+    swapped: bool,
+    priority: Priority,
+    overflow: Option<OverflowPolicy>,
 }
 
 impl ClosureContext {
     fn from_callback(callback: &Callback, callback_type: &CallbackType) -> Self {
         Self {
             js_func: callback.js_func.clone(),
-            arg_types: callback_type.arg_types.clone(),
+            plan: Arc::clone(&callback_type.plan),
+            swapped: callback_type.swapped,
+            priority: callback_type.priority,
+            overflow: callback_type.overflow,
         }
     }
 
-    fn build_closure_with_guard(self, return_type: Box<Type>) -> glib::Closure {
+    /// Returns the slot that folds emissions together under backpressure,
+    /// for closures with [`OverflowPolicy::Coalesce`].
+    fn overflow_slot(&self) -> Option<Arc<CoalescedCallback>> {
+        (self.overflow == Some(OverflowPolicy::Coalesce))
+            .then(|| CoalescedCallback::new(self.js_func.clone(), self.priority))
+    }
+
+    /// Sheds the emission if the closure has an overflow policy and the JS
+    /// inbox is saturated. Returns true if the emission was handled here
+    /// and must not be queued as a regular call.
+    fn shed_overflow(&self, args: &[glib::Value], slot: Option<&Arc<CoalescedCallback>>) -> bool {
+        if self.overflow.is_none() || !Mailbox::global().is_saturated() {
+            return false;
+        }
+
+        match slot {
+            Some(slot) => match self.convert_closure_args(args, true, Vec::new()) {
+                Ok(values) => {
+                    if slot.submit(values) {
+                        Mailbox::global().record_drop();
+                    }
+                }
+                Err(e) => NativeErrorReporter::global()
+                    .report(&e.context("closure: failed to convert callback arguments")),
+            },
+            None => Mailbox::global().record_drop(),
+        }
+        true
+    }
+
+    /// Builds a closure that waits for the JS call and returns its result.
+    /// Arguments are converted into a vector borrowed from an [`ArgPool`]
+    /// that the JS thread returns after the call.
+    fn build_closure_with_guard(self) -> glib::Closure {
         let closure_holder: Arc<AtomicPtr<gobject_ffi::GClosure>> =
             Arc::new(AtomicPtr::new(std::ptr::null_mut()));
         let closure_holder_for_callback = closure_holder.clone();
+        let pool = Arc::new(ArgPool::default());
+        let overflow_slot = self.overflow_slot();
 
         let closure = glib::Closure::new(move |args: &[glib::Value]| {
             let _guard =
                 ClosureGuard::from_ptr(closure_holder_for_callback.load(Ordering::Acquire));
 
-            let args_values = match Self::convert_closure_args(args, &self.arg_types) {
+            if self.shed_overflow(args, overflow_slot.as_ref()) {
+                return None;
+            }
+
+            let buffer = pool.acquire(args.len());
+            let mut args_values = match self.convert_closure_args(args, false, buffer) {
                 Ok(v) => v,
                 Err(e) => {
                     NativeErrorReporter::global()
@@ -49,26 +225,19 @@ impl ClosureContext {
                 }
             };
 
-            let return_type_ref: Option<&Type> = Some(&return_type);
-
-            let ref_pointers: Vec<(*mut c_void, &Type)> = args
-                .iter()
-                .zip(self.arg_types.iter())
-                .filter_map(|(gval, ty)| {
-                    if let Type::Ref(ref_type) = ty {
-                        let ptr = unsafe {
-                            glib::gobject_ffi::g_value_get_pointer(
-                                gval.to_glib_none().0 as *const _,
-                            )
-                        };
-                        Some((ptr, &*ref_type.inner_type))
-                    } else {
-                        None
-                    }
-                })
-                .collect();
+            let return_type_ref: Option<&Type> = Some(self.plan.return_type());
+            let ref_pointers = self.plan.ref_targets(args);
 
-            let result = Mailbox::global().invoke_node_and_wait(&self.js_func, args_values, true);
+            let scope = Lifeline::scope();
+            value::Value::scope_views(&mut args_values, &scope);
+            let result = Mailbox::global().invoke_node_pooled(
+                &self.js_func,
+                args_values,
+                true,
+                &pool,
+                self.priority,
+            );
+            scope.cut();
 
             match result {
                 Ok(value::Value::Array(arr)) if !ref_pointers.is_empty() => {
@@ -104,62 +273,364 @@ impl ClosureContext {
         unsafe { glib::Closure::from_glib_full(closure_ptr) }
     }
 
+    /// Builds a closure that records each emission in a [`CoalescedCallback`]
+    /// and returns immediately. Borrowed boxed arguments are copied because
+    /// the JS call happens after the emission has returned.
+    fn build_coalesced_closure(self) -> glib::Closure {
+        let slot = CoalescedCallback::new(self.js_func.clone(), self.priority);
+
+        glib::Closure::new(move |args: &[glib::Value]| {
+            match self.convert_closure_args(args, true, Vec::new()) {
+                Ok(values) => {
+                    slot.submit(values);
+                }
+                Err(e) => NativeErrorReporter::global()
+                    .report(&e.context("coalesced closure: failed to convert callback arguments")),
+            }
+            None
+        })
+    }
+
+    /// Builds a closure for a `fastArgs` callback. Arguments are read
+    /// straight from the emission's `GValue`s following `plan`, into a vector
+    /// borrowed from an [`ArgPool`] that the JS thread returns after the call.
+    /// A `GValue` of another type than its step expects is converted the
+    /// generic way instead.
+    fn build_fast_closure(self, plan: Arc<[StaticArg]>) -> glib::Closure {
+        let closure_holder: Arc<AtomicPtr<gobject_ffi::GClosure>> =
+            Arc::new(AtomicPtr::new(std::ptr::null_mut()));
+        let closure_holder_for_callback = closure_holder.clone();
+        let pool = Arc::new(ArgPool::default());
+        let overflow_slot = self.overflow_slot();
+
+        let closure = glib::Closure::new(move |args: &[glib::Value]| {
+            let _guard =
+                ClosureGuard::from_ptr(closure_holder_for_callback.load(Ordering::Acquire));
+
+            if self.shed_overflow(args, overflow_slot.as_ref()) {
+                return None;
+            }
+
+            let mut values = pool.acquire(plan.len());
+            let arg_types = self.plan.arg_types();
+            for (gval, (arg, ty)) in args.iter().zip(plan.iter().zip(arg_types)) {
+                match arg.read_or_convert(gval, ty) {
+                    Ok(value) => values.push(value),
+                    Err(e) => {
+                        pool.release(values);
+                        NativeErrorReporter::global().report(
+                            &e.context("fast closure: failed to convert callback arguments"),
+                        );
+                        return None;
+                    }
+                }
+            }
+            if self.swapped && !values.is_empty() {
+                values.rotate_left(1);
+            }
+
+            if let Err(e) = Mailbox::global().invoke_node_pooled(
+                &self.js_func,
+                values,
+                false,
+                &pool,
+                self.priority,
+            ) {
+                NativeErrorReporter::global().report(&anyhow::anyhow!(
+                    "fast closure callback: JS callback error: {e:#}"
+                ));
+            }
+            None
+        });
+
+        let closure_ptr: *mut gobject_ffi::GClosure = closure.to_glib_full();
+        closure_holder.store(closure_ptr, Ordering::Release);
+
+        unsafe { glib::Closure::from_glib_full(closure_ptr) }
+    }
+
+    /// Converts emission arguments for JS into `values`, an empty vector
+    /// that is returned filled. For swapped closures the first argument
+    /// (the emitting instance) is moved to the end, mirroring
+    /// `g_cclosure_new_swap`.
     fn convert_closure_args(
+        &self,
         args: &[glib::Value],
-        arg_types: &[Type],
+        detach: bool,
+        mut values: Vec<value::Value>,
     ) -> anyhow::Result<Vec<value::Value>> {
-        args.iter()
-            .zip(arg_types.iter())
-            .map(|(gval, ty)| {
-                if let Type::Boxed(boxed_type) = ty {
-                    let boxed_ptr = unsafe {
-                        glib::gobject_ffi::g_value_get_boxed(gval.to_glib_none().0 as *const _)
-                    };
-                    if boxed_ptr.is_null() {
-                        return Ok(value::Value::Null);
-                    }
-                    let boxed = if boxed_type.ownership.is_full() {
-                        let gtype = boxed_type.gtype();
-                        let owned_ptr = unsafe {
-                            glib::gobject_ffi::g_value_dup_boxed(gval.to_glib_none().0 as *const _)
-                        };
-                        Boxed::from_glib_full(gtype, owned_ptr)
+        self.plan.convert(args, detach, &mut values)?;
+        if self.swapped && !values.is_empty() {
+            values.rotate_left(1);
+        }
+        Ok(values)
+    }
+}
+
+/// Argument kinds a `fastArgs` closure reads directly from the emission's
+/// `GValue`s, without going through [`GlibValueCodec`].
+#[derive(Debug, Clone, Copy)]
+pub enum StaticArg {
+    Object,
+    Boolean,
+    Int,
+    UInt,
+    Double,
+}
+
+impl StaticArg {
+    fn from_type(ty: &Type) -> Option<Self> {
+        match ty {
+            Type::GObject(_) => Some(Self::Object),
+            Type::Boolean(_) => Some(Self::Boolean),
+            Type::Integer(IntegerKind::I32) => Some(Self::Int),
+            Type::Integer(IntegerKind::U32) => Some(Self::UInt),
+            Type::Float(FloatKind::F64) => Some(Self::Double),
+            _ => None,
+        }
+    }
+
+    /// The `GType` a `GValue` must hold, or derive from, to be read as this
+    /// kind.
+    fn gtype(self) -> glib::Type {
+        match self {
+            Self::Object => glib::Type::OBJECT,
+            Self::Boolean => glib::Type::BOOL,
+            Self::Int => glib::Type::I32,
+            Self::UInt => glib::Type::U32,
+            Self::Double => glib::Type::F64,
+        }
+    }
+
+    /// Reads `gval` directly when it holds this kind, and converts it as
+    /// `ty` through [`GlibValueCodec`] otherwise, such as for an enum
+    /// emitted where an `int32` was declared.
+    fn read_or_convert(self, gval: &glib::Value, ty: &Type) -> anyhow::Result<value::Value> {
+        if gval.type_().is_a(self.gtype()) {
+            Ok(self.read(gval))
+        } else {
+            value::Value::from_glib_value(gval, ty)
+        }
+    }
+
+    fn read(self, gval: &glib::Value) -> value::Value {
+        let raw = gval.to_glib_none().0;
+        unsafe {
+            match self {
+                Self::Object => {
+                    let obj_ptr = gobject_ffi::g_value_get_object(raw);
+                    if obj_ptr.is_null() {
+                        value::Value::Null
                     } else {
-                        Boxed::from_ptr_unowned(boxed_ptr)
-                    };
-                    Ok(value::Value::Object(NativeValue::Boxed(boxed).into()))
-                } else {
-                    value::Value::from_glib_value(gval, ty)
+                        let obj = glib::Object::from_glib_none(obj_ptr);
+                        value::Value::Object(NativeValue::GObject(obj).into())
+                    }
                 }
+                Self::Boolean => value::Value::Boolean(gobject_ffi::g_value_get_boolean(raw) != 0),
+                Self::Int => value::Value::Number(f64::from(gobject_ffi::g_value_get_int(raw))),
+                Self::UInt => value::Value::Number(f64::from(gobject_ffi::g_value_get_uint(raw))),
+                Self::Double => value::Value::Number(gobject_ffi::g_value_get_double(raw)),
+            }
+        }
+    }
+
+    fn plan(arg_types: &[Type]) -> napi::Result<Arc<[Self]>> {
+        arg_types
+            .iter()
+            .map(|ty| {
+                Self::from_type(ty).ok_or_else(|| {
+                    napi::Error::new(
+                        napi::Status::InvalidArg,
+                        format!(
+                            "'fastArgs' supports gobject, boolean, int32, uint32 and float64 arguments, got {ty:?}"
+                        ),
+                    )
+                })
             })
             .collect()
     }
 }
 
+/// How a closure sheds emissions while the JS inbox is saturated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Discard the emission.
+    Drop,
+    /// Keep only the latest emission and deliver it once, asynchronously.
+    Coalesce,
+}
+
+impl std::str::FromStr for OverflowPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "drop" => Ok(Self::Drop),
+            "coalesce" => Ok(Self::Coalesce),
+            _ => Err(format!("Invalid overflow policy '{s}', expected 'drop' or 'coalesce'")),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CallbackType {
-    pub arg_types: Vec<Type>,
-    pub return_type: Box<Type>,
+    /// Argument and return conversions, shared by every closure built from
+    /// this type.
+    pub plan: Arc<ClosurePlan>,
+    pub coalesce: bool,
+    pub swapped: bool,
+    /// Index of the call argument whose `GObject` lifetime bounds the
+    /// closure (`g_cclosure_new_object` semantics).
+    pub watch_arg: Option<usize>,
+    /// Precomputed argument readers for `fastArgs` closures.
+    pub fast_args: Option<Arc<[StaticArg]>>,
+    /// Lane the closure's calls are queued in on the JS thread.
+    pub priority: Priority,
+    /// What to do with emissions while the JS inbox is saturated.
+    pub overflow: Option<OverflowPolicy>,
 }
 
 impl CallbackType {
     pub fn from_js_value(env: &Env, obj: &JsObject) -> napi::Result<Self> {
         let (arg_types, return_type) =
             super::parse_callback_arg_and_return_types(env, obj, "callback")?;
+
+        let coalesce = obj
+            .get_named_property::<Option<bool>>("coalesce")
+            .ok()
+            .flatten()
+            .unwrap_or(false);
+
+        if coalesce && !matches!(*return_type, Type::Void(_)) {
+            return Err(napi::Error::new(
+                napi::Status::InvalidArg,
+                "'coalesce' requires a void return type".to_owned(),
+            ));
+        }
+
+        let swapped = obj
+            .get_named_property::<Option<bool>>("swapped")
+            .ok()
+            .flatten()
+            .unwrap_or(false);
+
+        let watch_arg = obj
+            .get_named_property::<Option<f64>>("watchArg")
+            .ok()
+            .flatten()
+            .map(|i| i as usize);
+
+        let fast_args = obj
+            .get_named_property::<Option<bool>>("fastArgs")
+            .ok()
+            .flatten()
+            .unwrap_or(false);
+
+        let fast_args = if fast_args {
+            if coalesce || !matches!(*return_type, Type::Void(_)) {
+                return Err(napi::Error::new(
+                    napi::Status::InvalidArg,
+                    "'fastArgs' requires a void return type and cannot be combined with 'coalesce'"
+                        .to_owned(),
+                ));
+            }
+            Some(StaticArg::plan(&arg_types)?)
+        } else {
+            None
+        };
+
+        let priority = obj
+            .get_named_property::<Option<String>>("priority")
+            .ok()
+            .flatten()
+            .map(|p| p.parse::<Priority>())
+            .transpose()
+            .map_err(|e| napi::Error::new(napi::Status::InvalidArg, e))?
+            .unwrap_or_default();
+
+        let overflow = obj
+            .get_named_property::<Option<String>>("overflow")
+            .ok()
+            .flatten()
+            .map(|p| p.parse::<OverflowPolicy>())
+            .transpose()
+            .map_err(|e| napi::Error::new(napi::Status::InvalidArg, e))?;
+
+        if overflow.is_some() && (coalesce || !matches!(*return_type, Type::Void(_))) {
+            return Err(napi::Error::new(
+                napi::Status::InvalidArg,
+                "'overflow' requires a void return type and cannot be combined with 'coalesce'"
+                    .to_owned(),
+            ));
+        }
+
         Ok(Self {
-            arg_types,
-            return_type,
+            plan: Arc::new(ClosurePlan::new(arg_types, *return_type)),
+            coalesce,
+            swapped,
+            watch_arg,
+            fast_args,
+            priority,
+            overflow,
         })
     }
 
     #[must_use]
     pub fn build_ffi_value(&self, callback: &Callback) -> ffi::FfiValue {
         let ctx = ClosureContext::from_callback(callback, self);
-        let closure = ctx.build_closure_with_guard(self.return_type.clone());
+        let closure = if let Some(plan) = &self.fast_args {
+            ctx.build_fast_closure(Arc::clone(plan))
+        } else if self.coalesce {
+            ctx.build_coalesced_closure()
+        } else {
+            ctx.build_closure_with_guard()
+        };
         let closure_ptr: *mut gobject_ffi::GClosure = closure.to_glib_full();
+
+        if self.watch_arg.is_some() {
+            let data = Box::into_raw(Box::new(callback.js_func.clone()));
+            unsafe {
+                gobject_ffi::g_closure_add_invalidate_notifier(
+                    closure_ptr,
+                    data.cast(),
+                    Some(on_watched_closure_invalidated),
+                );
+            }
+        }
+
         ffi::FfiValue::Storage(FfiStorage::closure(closure_ptr))
     }
 
+    /// Ties an encoded closure to the `GObject` passed at [`Self::watch_arg`]
+    /// with `g_object_watch_closure`, so finalizing the object invalidates
+    /// the closure. A null closure or object is left unwatched.
+    pub fn watch_object(
+        &self,
+        closure: &ffi::FfiValue,
+        object: Option<&ffi::FfiValue>,
+    ) -> anyhow::Result<()> {
+        let ffi::FfiValue::Storage(storage) = closure else {
+            return Ok(());
+        };
+        if storage.ptr().is_null() {
+            return Ok(());
+        }
+
+        let object_ptr = match object {
+            Some(ffi::FfiValue::Ptr(ptr)) => *ptr,
+            Some(other) => anyhow::bail!("watchArg must refer to a GObject argument, got {other:?}"),
+            None => anyhow::bail!("watchArg {:?} is out of range", self.watch_arg),
+        };
+        if object_ptr.is_null() {
+            return Ok(());
+        }
+
+        unsafe {
+            gobject_ffi::g_object_watch_closure(object_ptr.cast(), storage.ptr().cast());
+        }
+        Ok(())
+    }
+
     fn build_null_ffi_value() -> ffi::FfiValue {
         ffi::FfiValue::Storage(FfiStorage::new(
             std::ptr::null_mut(),
@@ -198,3 +669,39 @@ impl FfiDecoder for CallbackType {}
 impl RawPtrCodec for CallbackType {}
 
 impl GlibValueCodec for CallbackType {}
+
+/// Runs once when a watched closure is invalidated, whether by its object
+/// being finalized or by the closure itself being released. Marks the JS
+/// function with `invalidated = true` so JS can tell that it will not run again.
+unsafe extern "C" fn on_watched_closure_invalidated(
+    data: glib::ffi::gpointer,
+    _closure: *mut gobject_ffi::GClosure,
+) {
+    let js_func = unsafe { Box::from_raw(data.cast::<Arc<JsCallbackRef>>()) };
+    Mailbox::global().post_node(move |env| {
+        if let Err(e) = mark_invalidated(env, &js_func) {
+            NativeErrorReporter::global()
+                .report(&anyhow::anyhow!("closure: failed to mark invalidated: {e}"));
+        }
+    });
+}
+
+fn mark_invalidated(env: Env, js_func: &JsCallbackRef) -> napi::Result<()> {
+    use napi::sys;
+
+    let func = js_func.get_value(&env)?;
+    let func_raw = unsafe { napi::NapiRaw::raw(&func) };
+
+    let mut flag = std::ptr::null_mut();
+    let status = unsafe {
+        sys::napi_get_boolean(env.raw(), true, &mut flag);
+        sys::napi_set_named_property(env.raw(), func_raw, c"invalidated".as_ptr(), flag)
+    };
+    if status != sys::Status::napi_ok {
+        return Err(napi::Error::new(
+            napi::Status::GenericFailure,
+            format!("napi_set_named_property failed: {status:?}"),
+        ));
+    }
+    Ok(())
+}
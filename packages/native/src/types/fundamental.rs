@@ -10,6 +10,7 @@ use anyhow::bail;
 use gtk4::glib::{self, translate::ToGlibPtr as _, translate::ToGlibPtrMut as _};
 use napi::{Env, JsObject};
 
+use super::registry::{self, RegisteredType};
 use super::{FfiDecoder, FfiEncoder, GlibValueCodec, Ownership, RawPtrCodec};
 use crate::managed::{Fundamental, NativeValue, RefFn, UnrefFn};
 use crate::state::GtkThreadState;
@@ -28,14 +29,33 @@ impl FundamentalType {
     pub fn from_js_value(_env: &Env, obj: &JsObject) -> napi::Result<Self> {
         let ownership = Ownership::from_js_value(obj, "fundamental")?;
 
-        let library: String = obj.get_named_property("library")?;
-        let ref_func: String = obj.get_named_property("refFn")?;
-        let unref_func: String = obj.get_named_property("unrefFn")?;
         let type_name: Option<String> = obj
             .get_named_property::<Option<String>>("typeName")
             .ok()
             .flatten();
 
+        let library: Option<String> = obj
+            .get_named_property::<Option<String>>("library")
+            .ok()
+            .flatten();
+
+        let registered = type_name.as_deref().and_then(registry::lookup);
+        let (library, ref_func, unref_func) = match (library, registered) {
+            (
+                None,
+                Some(RegisteredType::Fundamental {
+                    library,
+                    ref_fn,
+                    unref_fn,
+                }),
+            ) => (library, ref_fn, unref_fn),
+            _ => (
+                obj.get_named_property("library")?,
+                obj.get_named_property("refFn")?,
+                obj.get_named_property("unrefFn")?,
+            ),
+        };
+
         Ok(Self {
             ownership,
             library,
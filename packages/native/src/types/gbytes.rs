@@ -0,0 +1,139 @@
+//! `GBytes` byte buffers.
+//!
+//! A [`GBytesType`] argument takes a `Buffer`, `Uint8Array` or string and
+//! passes a new `GBytes` holding a copy of its contents. A returned
+//! `GBytes*` becomes a `Buffer`: by default a copy of its contents, or with
+//! `view` a `Buffer` over them that holds a reference on the `GBytes` until
+//! it is garbage collected.
+//!
+//! Node allows only one external buffer per memory region, so views suit
+//! `GBytes` that are read once, such as freshly loaded file contents. A
+//! `GBytes` whose view may be requested again should be copied.
+
+use std::ffi::c_void;
+
+use anyhow::bail;
+use gtk4::glib::{
+    self,
+    translate::{FromGlibPtrFull as _, FromGlibPtrNone as _, IntoGlibPtr as _, ToGlibPtr as _},
+    value::ToValue as _,
+};
+use napi::{Env, JsObject};
+
+use super::{FfiDecoder, FfiEncoder, GlibValueCodec, Ownership, RawPtrCodec};
+use crate::ffi::{self, FfiStorage, FfiStorageKind};
+use crate::value;
+
+#[derive(Debug, Clone, Copy)]
+pub struct GBytesType {
+    pub ownership: Ownership,
+    /// Decode to a `Buffer` over the contents instead of a copy.
+    pub view: bool,
+}
+
+impl GBytesType {
+    pub fn from_js_value(_env: &Env, obj: &JsObject) -> napi::Result<Self> {
+        let ownership = Ownership::from_js_value(obj, "gbytes")?;
+        let view = obj
+            .get_named_property::<Option<bool>>("view")
+            .ok()
+            .flatten()
+            .unwrap_or(false);
+        Ok(Self { ownership, view })
+    }
+
+    fn bytes_from_value(value: &value::Value) -> anyhow::Result<Option<glib::Bytes>> {
+        match value {
+            value::Value::Bytes(bytes) => Ok(Some(glib::Bytes::from(bytes.as_slice()))),
+            value::Value::String(s) => Ok(Some(glib::Bytes::from(s.as_bytes()))),
+            value::Value::Null | value::Value::Undefined => Ok(None),
+            _ => bail!("Expected a Buffer, Uint8Array or string for gbytes type, got {value:?}"),
+        }
+    }
+
+    fn bytes_to_value(&self, bytes: glib::Bytes) -> value::Value {
+        if self.view {
+            value::Value::SharedBytes(bytes)
+        } else {
+            value::Value::Bytes(bytes.to_vec())
+        }
+    }
+}
+
+impl FfiEncoder for GBytesType {
+    fn encode(&self, value: &value::Value, _optional: bool) -> anyhow::Result<ffi::FfiValue> {
+        if let value::Value::Object(handle) = value {
+            let ptr = handle.ptr();
+            if self.ownership.is_full() && !ptr.is_null() {
+                unsafe { glib::ffi::g_bytes_ref(ptr.cast()) };
+            }
+            return Ok(ffi::FfiValue::Ptr(ptr));
+        }
+
+        let Some(bytes) = Self::bytes_from_value(value)? else {
+            return Ok(ffi::FfiValue::Ptr(std::ptr::null_mut()));
+        };
+
+        if self.ownership.is_full() {
+            return Ok(ffi::FfiValue::Ptr(unsafe { bytes.into_glib_ptr() }.cast()));
+        }
+
+        let ptr: *const glib::ffi::GBytes = bytes.to_glib_none().0;
+        Ok(ffi::FfiValue::Storage(FfiStorage::new(
+            ptr as *mut c_void,
+            FfiStorageKind::GBytes(bytes),
+        )))
+    }
+}
+
+impl FfiDecoder for GBytesType {
+    fn decode(&self, ffi_value: &ffi::FfiValue) -> anyhow::Result<value::Value> {
+        let Some(ptr) = ffi_value.as_non_null_ptr("GBytes")? else {
+            return Ok(value::Value::Null);
+        };
+
+        let bytes = if self.ownership.is_full() {
+            unsafe { glib::Bytes::from_glib_full(ptr as *const glib::ffi::GBytes) }
+        } else {
+            unsafe { glib::Bytes::from_glib_none(ptr as *const glib::ffi::GBytes) }
+        };
+        Ok(self.bytes_to_value(bytes))
+    }
+}
+
+impl RawPtrCodec for GBytesType {
+    fn ptr_to_value(&self, ptr: *mut c_void, _context: &str) -> anyhow::Result<value::Value> {
+        if ptr.is_null() {
+            return Ok(value::Value::Null);
+        }
+        let bytes = unsafe { glib::Bytes::from_glib_none(ptr as *const glib::ffi::GBytes) };
+        Ok(self.bytes_to_value(bytes))
+    }
+
+    fn write_return_to_raw_ptr(&self, ret: *mut c_void, value: &Result<value::Value, ()>) {
+        let ptr = match value {
+            Ok(value) => Self::bytes_from_value(value)
+                .ok()
+                .flatten()
+                .map_or(std::ptr::null_mut(), |bytes| unsafe { bytes.into_glib_ptr() }),
+            Err(()) => std::ptr::null_mut(),
+        };
+        unsafe { *(ret as *mut *mut glib::ffi::GBytes) = ptr };
+    }
+}
+
+impl GlibValueCodec for GBytesType {
+    fn to_glib_value(&self, val: &value::Value) -> anyhow::Result<Option<glib::Value>> {
+        Ok(Self::bytes_from_value(val)?.map(|bytes| bytes.to_value()))
+    }
+
+    fn from_glib_value(&self, gvalue: &glib::Value) -> anyhow::Result<value::Value> {
+        let ptr =
+            unsafe { glib::gobject_ffi::g_value_get_boxed(gvalue.to_glib_none().0 as *const _) };
+        if ptr.is_null() {
+            return Ok(value::Value::Null);
+        }
+        let bytes = unsafe { glib::Bytes::from_glib_none(ptr as *const glib::ffi::GBytes) };
+        Ok(self.bytes_to_value(bytes))
+    }
+}
@@ -0,0 +1,46 @@
+//! `GError**` error slots.
+//!
+//! A [`GErrorType`] argument passes a pointer to an empty error slot owned
+//! by the call's storage. After the call, `module::call` takes any error the
+//! function reported from the slot and throws it to JavaScript instead of
+//! returning, so callers need no `Ref` plumbing for the common trailing
+//! `GError **error` parameter.
+
+use std::ffi::c_void;
+
+use anyhow::bail;
+use gtk4::glib;
+use libffi::middle as libffi;
+
+use super::{FfiDecoder, FfiEncoder, GlibValueCodec, RawPtrCodec};
+use crate::ffi::{self, FfiStorage, FfiStorageKind};
+use crate::value;
+
+#[derive(Debug, Clone, Copy)]
+pub struct GErrorType;
+
+impl FfiEncoder for GErrorType {
+    fn encode(&self, value: &value::Value, _optional: bool) -> anyhow::Result<ffi::FfiValue> {
+        if !matches!(value, value::Value::Null | value::Value::Undefined) {
+            bail!("Expected null or undefined for gerror type, got {value:?}");
+        }
+        let slot: Box<*mut glib::ffi::GError> = Box::new(std::ptr::null_mut());
+        let ptr = slot.as_ref() as *const *mut glib::ffi::GError as *mut c_void;
+        Ok(ffi::FfiValue::Storage(FfiStorage::new(ptr, FfiStorageKind::GError(slot))))
+    }
+
+    fn call_cif(
+        &self,
+        _cif: &libffi::Cif,
+        _ptr: libffi::CodePtr,
+        _args: &[libffi::Arg],
+    ) -> anyhow::Result<ffi::FfiValue> {
+        bail!("GError types cannot be return types")
+    }
+}
+
+impl FfiDecoder for GErrorType {}
+
+impl RawPtrCodec for GErrorType {}
+
+impl GlibValueCodec for GErrorType {}
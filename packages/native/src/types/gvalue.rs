@@ -0,0 +1,180 @@
+//! `GValue` containers.
+//!
+//! A [`GValueType`] argument takes a native handle to an existing `GValue`,
+//! or any other JS value, which is converted to a new `GValue` holding it.
+//! Object handles are put in a new `GValue` rather than taken as one. The
+//! value's type comes from `valueType` when given, or is inferred from the
+//! JS value, and is then converted to `typeName` when that names another
+//! type. A returned `GValue*` becomes a native handle owning a copy.
+
+use std::ffi::c_void;
+
+use anyhow::bail;
+use gtk4::glib::{
+    self,
+    translate::{FromGlib as _, IntoGlib as _, ToGlibPtr as _, ToGlibPtrMut as _},
+};
+use napi::bindgen_prelude::*;
+use napi::{Env, JsObject};
+
+use super::{FfiDecoder, FfiEncoder, GlibValueCodec, Ownership, RawPtrCodec, Type, registry};
+use crate::ffi::{self, FfiStorage, FfiStorageKind};
+use crate::managed::{Boxed, NativeHandle, NativeValue};
+use crate::state::GtkThreadState;
+use crate::value;
+
+#[derive(Debug, Clone)]
+pub struct GValueType {
+    pub ownership: Ownership,
+    /// Type converting a JS value to the `GValue` contents.
+    pub value_type: Option<Box<Type>>,
+    /// `GType` name the contents are converted to.
+    pub type_name: Option<String>,
+}
+
+fn gvalue_gtype() -> glib::Type {
+    unsafe { glib::Type::from_glib(glib::gobject_ffi::g_value_get_type()) }
+}
+
+/// Returns whether `handle` is a `GValue` itself rather than an object to
+/// put in one. Borrowed handles carry no type and are taken as `GValue`s.
+fn is_gvalue(handle: &NativeHandle) -> bool {
+    match handle.value() {
+        Some(NativeValue::Boxed(boxed)) => {
+            boxed.gtype().is_none_or(|gtype| gtype == gvalue_gtype())
+        }
+        Some(NativeValue::GObject(_) | NativeValue::Fundamental(_)) => false,
+        None => true,
+    }
+}
+
+impl GValueType {
+    pub fn from_js_value(env: &Env, obj: &JsObject) -> napi::Result<Self> {
+        let ownership = Ownership::from_js_value(obj, "gvalue")?;
+        let value_type = match obj.get_named_property::<Option<Unknown<'_>>>("valueType")? {
+            Some(value_type) => Some(Box::new(Type::from_js_value(env, value_type)?)),
+            None => None,
+        };
+        let type_name = obj
+            .get_named_property::<Option<String>>("typeName")
+            .ok()
+            .flatten();
+        Ok(Self {
+            ownership,
+            value_type,
+            type_name,
+        })
+    }
+
+    fn target_gtype(name: &str) -> anyhow::Result<glib::Type> {
+        if let Some(gtype) = glib::Type::from_name(name) {
+            return Ok(gtype);
+        }
+        let Some((library, get_type_fn)) = registry::lookup_typed(name) else {
+            bail!("Unknown GType '{name}' for gvalue type; register it with registerTypes")
+        };
+        GtkThreadState::with(|state| state.gtype_from_lib(&library, &get_type_fn))
+    }
+
+    /// Converts `gvalue` to hold `target`: copied when compatible, stored as
+    /// the raw value for numbers given to enums and flags, and transformed
+    /// otherwise.
+    fn convert(gvalue: glib::Value, target: glib::Type) -> anyhow::Result<glib::Value> {
+        let source = gvalue.type_();
+        if source == target {
+            return Ok(gvalue);
+        }
+
+        let mut converted = glib::Value::from_type(target);
+        let src_ptr = gvalue.to_glib_none().0;
+        let dest_ptr = converted.to_glib_none_mut().0;
+        unsafe {
+            if glib::gobject_ffi::g_value_type_compatible(source.into_glib(), target.into_glib())
+                != glib::ffi::GFALSE
+            {
+                glib::gobject_ffi::g_value_copy(src_ptr, dest_ptr);
+            } else if target.is_a(glib::Type::ENUM) && source == glib::Type::F64 {
+                glib::gobject_ffi::g_value_set_enum(dest_ptr, gvalue.get::<f64>()? as i32);
+            } else if target.is_a(glib::Type::FLAGS) && source == glib::Type::F64 {
+                glib::gobject_ffi::g_value_set_flags(dest_ptr, gvalue.get::<f64>()? as u32);
+            } else if glib::gobject_ffi::g_value_transform(src_ptr, dest_ptr) == glib::ffi::GFALSE {
+                bail!("Cannot convert a {source} value to {target} for gvalue type");
+            }
+        }
+        Ok(converted)
+    }
+
+    fn to_gvalue(&self, value: &value::Value) -> anyhow::Result<glib::Value> {
+        let gvalue = value.clone().to_glib_value_typed(self.value_type.as_deref())?;
+        match &self.type_name {
+            Some(name) => Self::convert(gvalue, Self::target_gtype(name)?),
+            None => Ok(gvalue),
+        }
+    }
+
+    fn ptr_to_handle(&self, ptr: *mut c_void, owned: bool) -> anyhow::Result<value::Value> {
+        let boxed = if owned {
+            Boxed::from_glib_full(Some(gvalue_gtype()), ptr)
+        } else {
+            Boxed::from_glib_none(Some(gvalue_gtype()), ptr)?
+        };
+        Ok(value::Value::Object(NativeValue::Boxed(boxed).into()))
+    }
+}
+
+impl FfiEncoder for GValueType {
+    fn encode(&self, value: &value::Value, _optional: bool) -> anyhow::Result<ffi::FfiValue> {
+        match value {
+            value::Value::Null | value::Value::Undefined => {
+                return Ok(ffi::FfiValue::Ptr(std::ptr::null_mut()));
+            }
+            value::Value::Object(handle) if self.value_type.is_none() && is_gvalue(handle) => {
+                let ptr = handle.ptr();
+                if self.ownership.is_full() && !ptr.is_null() {
+                    let copied = unsafe {
+                        glib::gobject_ffi::g_boxed_copy(gvalue_gtype().into_glib(), ptr)
+                    };
+                    return Ok(ffi::FfiValue::Ptr(copied));
+                }
+                return Ok(ffi::FfiValue::Ptr(ptr));
+            }
+            _ => {}
+        }
+
+        let gvalue = self.to_gvalue(value)?;
+
+        if self.ownership.is_full() {
+            let ptr: *const glib::gobject_ffi::GValue = gvalue.to_glib_none().0;
+            let copied =
+                unsafe { glib::gobject_ffi::g_boxed_copy(gvalue_gtype().into_glib(), ptr.cast()) };
+            return Ok(ffi::FfiValue::Ptr(copied));
+        }
+
+        let gvalue = Box::new(gvalue);
+        let ptr: *const glib::gobject_ffi::GValue = gvalue.to_glib_none().0;
+        Ok(ffi::FfiValue::Storage(FfiStorage::new(
+            ptr as *mut c_void,
+            FfiStorageKind::GValue(gvalue),
+        )))
+    }
+}
+
+impl FfiDecoder for GValueType {
+    fn decode(&self, ffi_value: &ffi::FfiValue) -> anyhow::Result<value::Value> {
+        let Some(ptr) = ffi_value.as_non_null_ptr("GValue")? else {
+            return Ok(value::Value::Null);
+        };
+        self.ptr_to_handle(ptr, self.ownership.is_full())
+    }
+}
+
+impl RawPtrCodec for GValueType {
+    fn ptr_to_value(&self, ptr: *mut c_void, _context: &str) -> anyhow::Result<value::Value> {
+        if ptr.is_null() {
+            return Ok(value::Value::Null);
+        }
+        self.ptr_to_handle(ptr, false)
+    }
+}
+
+impl GlibValueCodec for GValueType {}
@@ -132,6 +132,12 @@ unsafe extern "C" fn g_ptr_array_unref_wrapper(ptr: *mut c_void) {
     }
 }
 
+unsafe extern "C" fn g_object_unref_wrapper(ptr: *mut c_void) {
+    if !ptr.is_null() {
+        unsafe { glib::gobject_ffi::g_object_unref(ptr as *mut glib::gobject_ffi::GObject) };
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct HashTableType {
     pub key_type: Box<Type>,
@@ -163,31 +169,75 @@ impl HashTableType {
         }
     }
 
+    /// Returns the function the table calls on an entry of type `ty` when
+    /// removing it: the one releasing the reference `ref_for_transfer` took
+    /// for a transfer-full object, or the one freeing what the encoder
+    /// allocated.
+    fn destroy_func(
+        ty: &Type,
+        encoder: &HashTableEntryEncoder,
+    ) -> anyhow::Result<glib::ffi::GDestroyNotify> {
+        match ty {
+            Type::GObject(gobject_type) if gobject_type.ownership.is_full() => {
+                Ok(Some(g_object_unref_wrapper))
+            }
+            Type::Fundamental(fundamental_type) if fundamental_type.ownership.is_full() => {
+                Ok(fundamental_type.lookup_fns()?.1)
+            }
+            _ => Ok(encoder.free_func()),
+        }
+    }
+
+    fn encode_entry(
+        ty: &Type,
+        encoder: &HashTableEntryEncoder,
+        val: &value::Value,
+    ) -> anyhow::Result<*mut c_void> {
+        ty.ref_for_transfer(encoder.encode(val)?)
+    }
+
     fn encode_hashtable(
         &self,
         tuples: &[value::Value],
         key_encoder: &HashTableEntryEncoder,
         value_encoder: &HashTableEntryEncoder,
     ) -> anyhow::Result<ffi::FfiValue> {
+        let key_destroy = Self::destroy_func(&self.key_type, key_encoder)?;
+        let value_destroy = Self::destroy_func(&self.value_type, value_encoder)?;
+
         let hash_table = unsafe {
             glib::ffi::g_hash_table_new_full(
                 key_encoder.hash_func(),
                 key_encoder.equal_func(),
-                key_encoder.free_func(),
-                value_encoder.free_func(),
+                key_destroy,
+                value_destroy,
             )
         };
 
         for tuple in tuples {
-            let (key, val) = Self::tuple(tuple)?;
-            let key_ptr = key_encoder.encode(key)?;
-            let val_ptr = value_encoder.encode(val)?;
-
-            let key_ptr = self.key_type.ref_for_transfer(key_ptr)?;
-            let val_ptr = self.value_type.ref_for_transfer(val_ptr)?;
-
-            unsafe {
-                glib::ffi::g_hash_table_insert(hash_table, key_ptr, val_ptr);
+            let entry = Self::tuple(tuple).and_then(|(key, val)| {
+                let key_ptr = Self::encode_entry(&self.key_type, key_encoder, key)?;
+                match Self::encode_entry(&self.value_type, value_encoder, val) {
+                    Ok(val_ptr) => Ok((key_ptr, val_ptr)),
+                    Err(err) => {
+                        if let Some(destroy) = key_destroy
+                            && !key_ptr.is_null()
+                        {
+                            unsafe { destroy(key_ptr) };
+                        }
+                        Err(err)
+                    }
+                }
+            });
+
+            match entry {
+                Ok((key_ptr, val_ptr)) => unsafe {
+                    glib::ffi::g_hash_table_insert(hash_table, key_ptr, val_ptr);
+                },
+                Err(err) => {
+                    unsafe { glib::ffi::g_hash_table_unref(hash_table) };
+                    return Err(err);
+                }
             }
         }
 
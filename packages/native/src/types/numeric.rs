@@ -1,8 +1,6 @@
 use std::ffi::c_void;
 
 use anyhow::bail;
-#[cfg(debug_assertions)]
-use gtk4::glib::translate::IntoGlib as _;
 use gtk4::glib::{
     self,
     translate::{ToGlibPtr as _, ToGlibPtrMut as _},
@@ -57,6 +55,28 @@ macro_rules! impl_integer_kind_dispatch {
                 }
             }
 
+            pub fn wide_to_ffi_value(self, value: i128) -> ffi::FfiValue {
+                match self {
+                    $(Self::$variant => ffi::FfiValue::$variant(value as $ty)),+
+                }
+            }
+
+            pub fn read_wide_ptr(self, ptr: *const u8) -> i128 {
+                unsafe {
+                    match self {
+                        $(Self::$variant => ptr.cast::<$ty>().read_unaligned() as i128),+
+                    }
+                }
+            }
+
+            pub fn write_wide_ptr(self, ptr: *mut u8, value: i128) {
+                unsafe {
+                    match self {
+                        $(Self::$variant => ptr.cast::<$ty>().write_unaligned(value as $ty)),+
+                    }
+                }
+            }
+
             pub fn read_slice(self, ptr: *const u8, length: usize) -> Vec<f64> {
                 unsafe {
                     match self {
@@ -131,11 +151,58 @@ impl IntegerKind {
             Self::U64 => (0.0, MAX_SAFE_INTEGER, "u64"),
         };
         if !value.is_finite() || value.fract() != 0.0 || value < min || value > max {
+            if matches!(self, Self::I64 | Self::U64) && value.fract() == 0.0 {
+                bail!("Number {value} cannot be passed losslessly as {name}; use a BigInt");
+            }
+            bail!("Value {value} is out of range for {name} [{min}, {max}]");
+        }
+        Ok(())
+    }
+
+    fn check_wide_range(self, value: i128) -> anyhow::Result<()> {
+        let (min, max, name) = match self {
+            Self::I8 => (i128::from(i8::MIN), i128::from(i8::MAX), "i8"),
+            Self::U8 => (0, i128::from(u8::MAX), "u8"),
+            Self::I16 => (i128::from(i16::MIN), i128::from(i16::MAX), "i16"),
+            Self::U16 => (0, i128::from(u16::MAX), "u16"),
+            Self::I32 => (i128::from(i32::MIN), i128::from(i32::MAX), "i32"),
+            Self::U32 => (0, i128::from(u32::MAX), "u32"),
+            Self::I64 => (i128::from(i64::MIN), i128::from(i64::MAX), "i64"),
+            Self::U64 => (0, i128::from(u64::MAX), "u64"),
+        };
+        if !(min..=max).contains(&value) {
             bail!("Value {value} is out of range for {name} [{min}, {max}]");
         }
         Ok(())
     }
 
+    /// Converts a `Number` or `BigInt` to the integer it holds. `BigInt`s
+    /// keep the precision that 64-bit values lose as `f64`.
+    fn checked_wide(self, value: &value::Value) -> anyhow::Result<i128> {
+        match value {
+            value::Value::BigInt(wide) => {
+                self.check_wide_range(*wide)?;
+                Ok(*wide)
+            }
+            value::Value::Number(n) => {
+                self.check_range(*n)?;
+                Ok(*n as i128)
+            }
+            _ => bail!("Expected a Number or BigInt for integer type, got {value:?}"),
+        }
+    }
+
+    /// Decodes an integer as a `Number` when `f64` represents it exactly,
+    /// and as a `BigInt` otherwise.
+    #[must_use]
+    pub fn wide_to_value(value: i128) -> value::Value {
+        if value.unsigned_abs() <= MAX_SAFE_INTEGER as u128 {
+            value::Value::Number(value as f64)
+        } else {
+            value::Value::BigInt(value)
+        }
+    }
+
     pub fn checked_to_ffi_value(self, value: f64) -> anyhow::Result<ffi::FfiValue> {
         self.check_range(value)?;
         Ok(self.to_ffi_value(value))
@@ -160,8 +227,8 @@ impl IntegerKind {
             Self::U8 | Self::U16 => ptr as usize as f64,
             Self::I32 => ptr as i32 as f64,
             Self::U32 => ptr as u32 as f64,
-            Self::I64 => ptr as i64 as f64,
-            Self::U64 => ptr as u64 as f64,
+            Self::I64 => return Self::wide_to_value(ptr as i64 as i128),
+            Self::U64 => return Self::wide_to_value(ptr as u64 as i128),
         };
         value::Value::Number(number)
     }
@@ -171,6 +238,7 @@ impl FfiEncoder for IntegerKind {
     fn encode(&self, value: &value::Value, optional: bool) -> anyhow::Result<ffi::FfiValue> {
         let number = match value {
             value::Value::Number(n) => *n,
+            value::Value::BigInt(_) => return Ok(self.wide_to_ffi_value(self.checked_wide(value)?)),
             value::Value::Object(handle) => handle.ptr_as_usize() as f64,
             value::Value::Null | value::Value::Undefined if optional => 0.0,
             _ => bail!("Expected a Number for integer type, got {value:?}"),
@@ -194,7 +262,11 @@ impl FfiEncoder for IntegerKind {
 
 impl FfiDecoder for IntegerKind {
     fn decode(&self, ffi_value: &ffi::FfiValue) -> anyhow::Result<value::Value> {
-        Ok(value::Value::Number(ffi_value.to_number()?))
+        match ffi_value {
+            ffi::FfiValue::I64(v) => Ok(Self::wide_to_value(i128::from(*v))),
+            ffi::FfiValue::U64(v) => Ok(Self::wide_to_value(i128::from(*v))),
+            _ => Ok(value::Value::Number(ffi_value.to_number()?)),
+        }
     }
 }
 
@@ -208,40 +280,58 @@ impl RawPtrCodec for IntegerKind {
         ptr: *const c_void,
         _context: &str,
     ) -> anyhow::Result<value::Value> {
+        if matches!(self, Self::I64 | Self::U64) {
+            return Ok(Self::wide_to_value(self.read_wide_ptr(ptr as *const u8)));
+        }
         Ok(value::Value::Number(self.read_ptr(ptr as *const u8)))
     }
 
     fn write_return_to_raw_ptr(&self, ret: *mut c_void, value: &Result<value::Value, ()>) {
-        let n = match value {
-            Ok(value::Value::Number(n)) => *n,
-            _ => 0.0,
-        };
-        self.write_ptr(ret as *mut u8, n);
+        match value {
+            Ok(value::Value::Number(n)) => self.write_ptr(ret as *mut u8, *n),
+            Ok(value @ value::Value::BigInt(_)) => {
+                let wide = self.checked_wide(value).unwrap_or(0);
+                self.write_wide_ptr(ret as *mut u8, wide);
+            }
+            _ => self.write_ptr(ret as *mut u8, 0.0),
+        }
     }
 
     fn write_value_to_raw_ptr(&self, ptr: *mut c_void, value: &value::Value) -> anyhow::Result<()> {
-        let value::Value::Number(n) = value else {
-            bail!("Expected a Number for integer field write, got {value:?}");
-        };
-        self.write_ptr(ptr as *mut u8, *n);
+        match value {
+            value::Value::Number(n) => self.write_ptr(ptr as *mut u8, *n),
+            value::Value::BigInt(_) => {
+                self.write_wide_ptr(ptr as *mut u8, self.checked_wide(value)?);
+            }
+            _ => bail!("Expected a Number or BigInt for integer field write, got {value:?}"),
+        }
         Ok(())
     }
 }
 
 impl GlibValueCodec for IntegerKind {
     fn to_glib_value(&self, val: &value::Value) -> anyhow::Result<Option<glib::Value>> {
-        let value::Value::Number(n) = val else {
-            return Ok(None);
+        let n = match val {
+            value::Value::Number(n) => *n,
+            value::Value::BigInt(_) => {
+                let wide = self.checked_wide(val)?;
+                match self {
+                    Self::I64 => return Ok(Some((wide as i64).into())),
+                    Self::U64 => return Ok(Some((wide as u64).into())),
+                    _ => wide as f64,
+                }
+            }
+            _ => return Ok(None),
         };
         let gvalue = match self {
-            Self::I8 => (*n as i8).into(),
-            Self::U8 => (*n as u8).into(),
-            Self::I16 => (*n as i16 as i32).into(),
-            Self::U16 => (*n as u16 as u32).into(),
-            Self::I32 => (*n as i32).into(),
-            Self::U32 => (*n as u32).into(),
-            Self::I64 => (*n as i64).into(),
-            Self::U64 => (*n as u64).into(),
+            Self::I8 => (n as i8).into(),
+            Self::U8 => (n as u8).into(),
+            Self::I16 => (n as i16 as i32).into(),
+            Self::U16 => (n as u16 as u32).into(),
+            Self::I32 => (n as i32).into(),
+            Self::U32 => (n as u32).into(),
+            Self::I64 => (n as i64).into(),
+            Self::U64 => (n as u64).into(),
         };
         Ok(Some(gvalue))
     }
@@ -272,14 +362,18 @@ impl GlibValueCodec for IntegerKind {
                 .get::<u32>()
                 .map_err(|e| anyhow::anyhow!("Failed to get u32 from GValue: {e}"))?
                 as f64,
-            Self::I64 => gvalue
-                .get::<i64>()
-                .map_err(|e| anyhow::anyhow!("Failed to get i64 from GValue: {e}"))?
-                as f64,
-            Self::U64 => gvalue
-                .get::<u64>()
-                .map_err(|e| anyhow::anyhow!("Failed to get u64 from GValue: {e}"))?
-                as f64,
+            Self::I64 => {
+                let wide = gvalue
+                    .get::<i64>()
+                    .map_err(|e| anyhow::anyhow!("Failed to get i64 from GValue: {e}"))?;
+                return Ok(Self::wide_to_value(i128::from(wide)));
+            }
+            Self::U64 => {
+                let wide = gvalue
+                    .get::<u64>()
+                    .map_err(|e| anyhow::anyhow!("Failed to get u64 from GValue: {e}"))?;
+                return Ok(Self::wide_to_value(i128::from(wide)));
+            }
         };
         Ok(value::Value::Number(number))
     }
@@ -298,9 +392,23 @@ pub struct TaggedType {
 }
 
 impl TaggedType {
+    /// Parses `library` and `getTypeFn`, or looks both up by `typeName` in
+    /// the type registry when they are omitted.
     pub fn from_js_value(_env: &Env, obj: &JsObject) -> napi::Result<Self> {
-        let library: String = obj.get_named_property("library")?;
-        let get_type_fn: String = obj.get_named_property("getTypeFn")?;
+        let property = |name: &str| obj.get_named_property::<Option<String>>(name).ok().flatten();
+
+        let (library, get_type_fn) = match (property("library"), property("getTypeFn")) {
+            (Some(library), Some(get_type_fn)) => (library, get_type_fn),
+            _ => property("typeName")
+                .and_then(|name| super::registry::lookup_typed(&name))
+                .ok_or_else(|| {
+                    napi::Error::new(
+                        napi::Status::InvalidArg,
+                        "'library' and 'getTypeFn' properties are required for enum and flags \
+                         types without a registered 'typeName'",
+                    )
+                })?,
+        };
 
         Ok(Self {
             library,
@@ -325,6 +433,14 @@ impl FloatKind {
         }
     }
 
+    #[must_use]
+    pub fn byte_size(self) -> usize {
+        match self {
+            Self::F32 => 4,
+            Self::F64 => 8,
+        }
+    }
+
     #[must_use]
     pub fn read_ptr(self, ptr: *const u8) -> f64 {
         unsafe {
@@ -489,6 +605,8 @@ impl From<FloatKind> for libffi::Type {
 pub struct EnumType {
     pub tagged: TaggedType,
     pub storage: IntegerKind,
+    /// Reject values not registered for the enum type.
+    pub strict: bool,
 }
 
 impl EnumType {
@@ -500,30 +618,51 @@ impl EnumType {
         } else {
             IntegerKind::U32
         };
-        Ok(Self { tagged, storage })
+        let strict = obj
+            .get_named_property::<Option<bool>>("strict")
+            .ok()
+            .flatten()
+            .unwrap_or(false);
+        Ok(Self {
+            tagged,
+            storage,
+            strict,
+        })
     }
 }
 
 impl EnumType {
-    #[cfg(debug_assertions)]
-    fn validate_enum_value(&self, value: i32) {
-        if let Ok(gtype) = crate::state::GtkThreadState::with(|state| {
+    fn enum_class(&self) -> anyhow::Result<glib::EnumClass> {
+        let gtype = crate::state::GtkThreadState::with(|state| {
             state.gtype_from_lib(&self.tagged.library, &self.tagged.get_type_fn)
-        }) {
-            unsafe {
-                let enum_class = glib::gobject_ffi::g_type_class_ref(gtype.into_glib())
-                    as *mut glib::gobject_ffi::GEnumClass;
-                if !enum_class.is_null() {
-                    let enum_value = glib::gobject_ffi::g_enum_get_value(enum_class, value);
-                    if enum_value.is_null() {
-                        crate::error_reporter::NativeErrorReporter::global().report_str(&format!(
-                            "Enum value {} is not a valid member of {} (GType {})",
-                            value, self.tagged.get_type_fn, gtype
-                        ));
-                    }
-                    glib::gobject_ffi::g_type_class_unref(enum_class as *mut _);
-                }
-            }
+        })?;
+        glib::EnumClass::with_type(gtype)
+            .ok_or_else(|| anyhow::anyhow!("{} is not an enum type", self.tagged.get_type_fn))
+    }
+
+    /// Fails if `value` is not one of the values registered for the enum
+    /// type.
+    pub fn validate_value(&self, value: i32) -> anyhow::Result<()> {
+        if self.enum_class()?.value(value).is_none() {
+            bail!(
+                "Enum value {value} is not defined by {}",
+                self.tagged.get_type_fn
+            );
+        }
+        Ok(())
+    }
+
+    #[cfg(debug_assertions)]
+    fn report_invalid_value(&self, value: i32) {
+        if let Ok(enum_class) = self.enum_class()
+            && enum_class.value(value).is_none()
+        {
+            crate::error_reporter::NativeErrorReporter::global().report_str(&format!(
+                "Enum value {} is not a valid member of {} (GType {})",
+                value,
+                self.tagged.get_type_fn,
+                enum_class.type_()
+            ));
         }
     }
 }
@@ -531,9 +670,15 @@ impl EnumType {
 impl FfiEncoder for EnumType {
     fn encode(&self, value: &value::Value, optional: bool) -> anyhow::Result<ffi::FfiValue> {
         let result = FfiEncoder::encode(&self.storage, value, optional)?;
-        #[cfg(debug_assertions)]
-        if let value::Value::Number(n) = value {
-            self.validate_enum_value(*n as i32);
+        if let value::Value::Number(_) | value::Value::BigInt(_) = value {
+            let resolved = self.storage.checked_wide(value)? as i32;
+            if self.strict {
+                self.validate_value(resolved)?;
+            }
+            #[cfg(debug_assertions)]
+            if !self.strict {
+                self.report_invalid_value(resolved);
+            }
         }
         Ok(result)
     }
@@ -607,6 +752,8 @@ impl GlibValueCodec for EnumType {
 pub struct FlagsType {
     pub tagged: TaggedType,
     pub storage: IntegerKind,
+    /// Reject masks with bits not defined by the registered flags type.
+    pub strict: bool,
 }
 
 impl FlagsType {
@@ -618,13 +765,128 @@ impl FlagsType {
         } else {
             IntegerKind::U32
         };
-        Ok(Self { tagged, storage })
+        let strict = obj
+            .get_named_property::<Option<bool>>("strict")
+            .ok()
+            .flatten()
+            .unwrap_or(false);
+        Ok(Self {
+            tagged,
+            storage,
+            strict,
+        })
+    }
+}
+
+impl FlagsType {
+    fn flags_class(&self) -> anyhow::Result<glib::FlagsClass> {
+        let gtype = crate::state::GtkThreadState::with(|state| {
+            state.gtype_from_lib(&self.tagged.library, &self.tagged.get_type_fn)
+        })?;
+        glib::FlagsClass::with_type(gtype)
+            .ok_or_else(|| anyhow::anyhow!("{} is not a flags type", self.tagged.get_type_fn))
+    }
+
+    /// Fails if `mask` sets any bit not covered by a value of the flags type.
+    pub fn validate_mask(&self, mask: u32) -> anyhow::Result<()> {
+        let known = self
+            .flags_class()?
+            .values()
+            .iter()
+            .fold(0, |acc, v| acc | v.value());
+        let unknown = mask & !known;
+        if unknown != 0 {
+            bail!(
+                "Flags value {mask:#x} sets bits {unknown:#x} not defined by {}",
+                self.tagged.get_type_fn
+            );
+        }
+        Ok(())
+    }
+
+    /// ORs together the values named by `nicks`.
+    pub fn mask_from_nicks<'a>(
+        &self,
+        nicks: impl IntoIterator<Item = &'a str>,
+    ) -> anyhow::Result<u32> {
+        let class = self.flags_class()?;
+        nicks.into_iter().try_fold(0, |acc, nick| {
+            class
+                .value_by_nick(nick)
+                .map(|v| acc | v.value())
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "'{nick}' is not a member of {}",
+                        self.tagged.get_type_fn
+                    )
+                })
+        })
+    }
+
+    /// Lists the nicks of the non-zero values fully contained in `mask`.
+    pub fn nicks_from_mask(&self, mask: u32) -> anyhow::Result<Vec<String>> {
+        Ok(self
+            .flags_class()?
+            .values()
+            .iter()
+            .filter(|v| v.value() != 0 && mask & v.value() == v.value())
+            .map(|v| v.nick().to_owned())
+            .collect())
+    }
+
+    /// Converts a number to a mask. The number must be an integer in the
+    /// range of the storage, so signed flags may be negative.
+    pub fn mask_from_number(&self, n: f64) -> anyhow::Result<u32> {
+        self.storage.check_range(n)?;
+        Ok(match self.storage {
+            IntegerKind::I32 => n as i32 as u32,
+            _ => n as u32,
+        })
+    }
+
+    /// Converts a mask to the number its storage holds, which is negative
+    /// for signed flags with the top bit set.
+    pub fn mask_to_number(&self, mask: u32) -> f64 {
+        match self.storage {
+            IntegerKind::I32 => f64::from(mask as i32),
+            _ => f64::from(mask),
+        }
+    }
+
+    /// Converts an encodable value to a mask, accepting either a number or
+    /// an array of nicks, and applies strict validation.
+    fn to_mask(&self, value: &value::Value) -> anyhow::Result<Option<u32>> {
+        let mask = match value {
+            value::Value::Number(n) => self.mask_from_number(*n)?,
+            value::Value::Array(items) => {
+                let nicks = items
+                    .iter()
+                    .map(|item| match item {
+                        value::Value::String(nick) => Ok(nick.as_str()),
+                        other => Err(anyhow::anyhow!("Expected a flags nick, got {other:?}")),
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                self.mask_from_nicks(nicks)?
+            }
+            _ => return Ok(None),
+        };
+        if self.strict {
+            self.validate_mask(mask)?;
+        }
+        Ok(Some(mask))
     }
 }
 
 impl FfiEncoder for FlagsType {
     fn encode(&self, value: &value::Value, optional: bool) -> anyhow::Result<ffi::FfiValue> {
-        FfiEncoder::encode(&self.storage, value, optional)
+        match self.to_mask(value)? {
+            Some(mask) => FfiEncoder::encode(
+                &self.storage,
+                &value::Value::Number(self.mask_to_number(mask)),
+                optional,
+            ),
+            None => FfiEncoder::encode(&self.storage, value, optional),
+        }
     }
 
     fn libffi_type(&self) -> libffi::Type {
@@ -673,7 +935,7 @@ impl RawPtrCodec for FlagsType {
 
 impl GlibValueCodec for FlagsType {
     fn to_glib_value(&self, val: &value::Value) -> anyhow::Result<Option<glib::Value>> {
-        let value::Value::Number(n) = val else {
+        let Some(mask) = self.to_mask(val)? else {
             return Ok(None);
         };
         let gtype = crate::state::GtkThreadState::with(|state| {
@@ -681,7 +943,7 @@ impl GlibValueCodec for FlagsType {
         })?;
         let mut gvalue = glib::Value::from_type(gtype);
         unsafe {
-            glib::gobject_ffi::g_value_set_flags(gvalue.to_glib_none_mut().0, *n as u32);
+            glib::gobject_ffi::g_value_set_flags(gvalue.to_glib_none_mut().0, mask);
         }
         Ok(Some(gvalue))
     }
@@ -689,6 +951,6 @@ impl GlibValueCodec for FlagsType {
     fn from_glib_value(&self, gvalue: &glib::Value) -> anyhow::Result<value::Value> {
         let v =
             unsafe { glib::gobject_ffi::g_value_get_flags(gvalue.to_glib_none().0 as *const _) };
-        Ok(value::Value::Number(v as f64))
+        Ok(value::Value::Number(self.mask_to_number(v)))
     }
 }
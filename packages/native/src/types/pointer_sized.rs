@@ -0,0 +1,211 @@
+//! Pointer-sized integers (`gintptr` / `guintptr`) marshaled as JS `BigInt`.
+//!
+//! Values such as X11 window IDs or GL texture names travel through APIs as
+//! `guintptr`. Converting them to `f64` silently loses precision above 2^53,
+//! so this type decodes to a `BigInt` and accepts either a `BigInt` or a
+//! safe-integer `Number` on encode.
+//!
+//! The width follows the target's pointers: 64-bit on 64-bit targets and
+//! 32-bit on 32-bit ones.
+
+use std::ffi::c_void;
+
+use anyhow::bail;
+use gtk4::glib;
+use libffi::middle as libffi;
+
+use super::numeric::IntegerKind;
+use super::{FfiDecoder, FfiEncoder, GlibValueCodec, RawPtrCodec};
+use crate::{ffi, value};
+
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+#[derive(Debug, Clone, Copy)]
+pub struct PointerSizedType {
+    pub signed: bool,
+}
+
+impl PointerSizedType {
+    #[cfg(target_pointer_width = "64")]
+    fn kind(self) -> IntegerKind {
+        if self.signed {
+            IntegerKind::I64
+        } else {
+            IntegerKind::U64
+        }
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    fn kind(self) -> IntegerKind {
+        if self.signed {
+            IntegerKind::I32
+        } else {
+            IntegerKind::U32
+        }
+    }
+
+    fn name(self) -> &'static str {
+        if self.signed { "intptr" } else { "uintptr" }
+    }
+
+    fn to_bits(self, value: &value::Value, optional: bool) -> anyhow::Result<i128> {
+        let wide = match value {
+            value::Value::BigInt(v) => *v,
+            value::Value::Number(n) => {
+                if !n.is_finite() || n.fract() != 0.0 || n.abs() > MAX_SAFE_INTEGER {
+                    bail!(
+                        "Number {n} cannot be passed losslessly as {}; use a BigInt",
+                        self.name()
+                    );
+                }
+                *n as i128
+            }
+            value::Value::Null | value::Value::Undefined if optional => 0,
+            _ => bail!("Expected a BigInt for {} type, got {value:?}", self.name()),
+        };
+
+        let (min, max) = if self.signed {
+            (isize::MIN as i128, isize::MAX as i128)
+        } else {
+            (0, usize::MAX as i128)
+        };
+        if !(min..=max).contains(&wide) {
+            bail!("Value {wide} is out of range for {}", self.name());
+        }
+        Ok(wide)
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    fn to_ffi_value(self, wide: i128) -> ffi::FfiValue {
+        if self.signed {
+            ffi::FfiValue::I64(wide as i64)
+        } else {
+            ffi::FfiValue::U64(wide as u64)
+        }
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    fn to_ffi_value(self, wide: i128) -> ffi::FfiValue {
+        if self.signed {
+            ffi::FfiValue::I32(wide as i32)
+        } else {
+            ffi::FfiValue::U32(wide as u32)
+        }
+    }
+
+    fn read(self, ptr: *const c_void) -> value::Value {
+        let wide = unsafe {
+            if self.signed {
+                ptr.cast::<isize>().read_unaligned() as i128
+            } else {
+                ptr.cast::<usize>().read_unaligned() as i128
+            }
+        };
+        value::Value::BigInt(wide)
+    }
+
+    fn write(self, ptr: *mut c_void, wide: i128) {
+        unsafe {
+            if self.signed {
+                ptr.cast::<isize>().write_unaligned(wide as isize);
+            } else {
+                ptr.cast::<usize>().write_unaligned(wide as usize);
+            }
+        }
+    }
+}
+
+impl FfiEncoder for PointerSizedType {
+    fn encode(&self, value: &value::Value, optional: bool) -> anyhow::Result<ffi::FfiValue> {
+        Ok(self.to_ffi_value(self.to_bits(value, optional)?))
+    }
+
+    fn libffi_type(&self) -> libffi::Type {
+        self.kind().ffi_type()
+    }
+
+    fn call_cif(
+        &self,
+        cif: &libffi::Cif,
+        ptr: libffi::CodePtr,
+        args: &[libffi::Arg],
+    ) -> anyhow::Result<ffi::FfiValue> {
+        self.kind().call_cif(cif, ptr, args)
+    }
+}
+
+impl FfiDecoder for PointerSizedType {
+    fn decode(&self, ffi_value: &ffi::FfiValue) -> anyhow::Result<value::Value> {
+        let wide = match ffi_value {
+            ffi::FfiValue::I64(v) => i128::from(*v),
+            ffi::FfiValue::U64(v) => i128::from(*v),
+            ffi::FfiValue::I32(v) => i128::from(*v),
+            ffi::FfiValue::U32(v) => i128::from(*v),
+            _ => bail!("Expected an integer FfiValue for {}, got {ffi_value:?}", self.name()),
+        };
+        Ok(value::Value::BigInt(wide))
+    }
+}
+
+impl RawPtrCodec for PointerSizedType {
+    fn ptr_to_value(&self, ptr: *mut c_void, _context: &str) -> anyhow::Result<value::Value> {
+        let wide = if self.signed {
+            ptr as isize as i128
+        } else {
+            ptr as usize as i128
+        };
+        Ok(value::Value::BigInt(wide))
+    }
+
+    fn read_from_raw_ptr(
+        &self,
+        ptr: *const c_void,
+        _context: &str,
+    ) -> anyhow::Result<value::Value> {
+        Ok(self.read(ptr))
+    }
+
+    fn write_return_to_raw_ptr(&self, ret: *mut c_void, value: &Result<value::Value, ()>) {
+        let wide = value
+            .as_ref()
+            .ok()
+            .and_then(|v| self.to_bits(v, true).ok())
+            .unwrap_or(0);
+        self.write(ret, wide);
+    }
+
+    fn write_value_to_raw_ptr(&self, ptr: *mut c_void, value: &value::Value) -> anyhow::Result<()> {
+        self.write(ptr, self.to_bits(value, false)?);
+        Ok(())
+    }
+}
+
+impl GlibValueCodec for PointerSizedType {
+    fn to_glib_value(&self, val: &value::Value) -> anyhow::Result<Option<glib::Value>> {
+        if !matches!(val, value::Value::BigInt(_) | value::Value::Number(_)) {
+            return Ok(None);
+        }
+        let wide = self.to_bits(val, false)?;
+        let gvalue = if self.signed {
+            (wide as i64).into()
+        } else {
+            (wide as u64).into()
+        };
+        Ok(Some(gvalue))
+    }
+
+    fn from_glib_value(&self, gvalue: &glib::Value) -> anyhow::Result<value::Value> {
+        let wide = if self.signed {
+            gvalue
+                .get::<i64>()
+                .map(i128::from)
+                .map_err(|e| anyhow::anyhow!("Failed to get i64 from GValue: {e}"))?
+        } else {
+            gvalue
+                .get::<u64>()
+                .map(i128::from)
+                .map_err(|e| anyhow::anyhow!("Failed to get u64 from GValue: {e}"))?
+        };
+        Ok(value::Value::BigInt(wide))
+    }
+}
@@ -50,7 +50,11 @@ impl FfiEncoder for RefType {
         };
 
         match &*self.inner_type {
-            Type::Boxed(_) | Type::Struct(_) | Type::GObject(_) | Type::Fundamental(_) => {
+            Type::Boxed(_)
+            | Type::Struct(_)
+            | Type::GObject(_)
+            | Type::Fundamental(_)
+            | Type::GBytes(_) => {
                 match &*ref_val.value {
                     value::Value::Null | value::Value::Undefined => {
                         let ptr_storage: Box<*mut c_void> = Box::new(std::ptr::null_mut());
@@ -61,7 +65,7 @@ impl FfiEncoder for RefType {
                         )))
                     }
                     _ => bail!(
-                        "Expected Null for Ref<Boxed/Struct/GObject/Fundamental>, got {:?}",
+                        "Expected Null for Ref<Boxed/Struct/GObject/Fundamental/GBytes>, got {:?}",
                         ref_val.value
                     ),
                 }
@@ -235,6 +239,10 @@ impl FfiDecoder for RefType {
                 Self::decode_fundamental_inner(fundamental_type, storage)
             }
             Type::Struct(struct_type) => Self::decode_struct_inner(struct_type, storage),
+            Type::GBytes(gbytes_type) => {
+                let actual_ptr = unsafe { *(storage.ptr() as *const *mut c_void) };
+                gbytes_type.decode(&ffi::FfiValue::Ptr(actual_ptr))
+            }
             Type::Integer(int_type) => {
                 let number = int_type.read_ptr(storage.ptr() as *const u8);
                 Ok(value::Value::Number(number))
@@ -251,7 +259,7 @@ impl FfiDecoder for RefType {
                 let number = float_kind.read_ptr(storage.ptr() as *const u8);
                 Ok(value::Value::Number(number))
             }
-            Type::String(string_type) => Ok(Self::decode_ref_string(storage, string_type)),
+            Type::String(string_type) => Self::decode_ref_string(storage, string_type),
             Type::Array(_) => {
                 bail!("Ref<Array> requires decode_with_context to get size from another parameter")
             }
@@ -367,28 +375,30 @@ impl RefType {
         self.decode(ffi_value)
     }
 
-    fn decode_ref_string(storage: &FfiStorage, string_type: &super::StringType) -> value::Value {
+    fn decode_ref_string(
+        storage: &FfiStorage,
+        string_type: &super::StringType,
+    ) -> anyhow::Result<value::Value> {
         if storage.ptr().is_null() {
-            return value::Value::Null;
+            return Ok(value::Value::Null);
         }
 
         if let FfiStorageKind::Buffer(_) = storage.kind() {
             let c_str = unsafe { CStr::from_ptr(storage.ptr() as *const c_char) };
-            let string = c_str.to_string_lossy().into_owned();
-            value::Value::String(string)
+            string_type.utf8.decode(c_str)
         } else {
             let str_ptr = unsafe { *(storage.ptr() as *const *const c_char) };
             if str_ptr.is_null() {
-                return value::Value::Null;
+                return Ok(value::Value::Null);
             }
             let c_str = unsafe { CStr::from_ptr(str_ptr) };
-            let string = c_str.to_string_lossy().into_owned();
+            let value = string_type.utf8.decode(c_str);
 
             if string_type.ownership.is_full() {
                 unsafe { glib::ffi::g_free(str_ptr as *mut c_void) };
             }
 
-            value::Value::String(string)
+            value
         }
     }
 }
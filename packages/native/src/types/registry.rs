@@ -0,0 +1,71 @@
+//! Runtime registry of named type metadata.
+//!
+//! Descriptors normally carry everything the native side needs to resolve a
+//! type: the library and `get_type` function of a boxed, enum or flags type,
+//! the ref and unref functions of a fundamental type, the signature of a
+//! callback. Registering that metadata once per type, typically digested
+//! from GIR, lets descriptors name the type and leave the rest out; the
+//! descriptor parsers complete them from the registry.
+//!
+//! Types are keyed by their `GType` name, which for callbacks is their C
+//! type name. Registering a name again replaces its metadata.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use super::Type;
+
+/// Metadata registered for a named type.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum RegisteredType {
+    /// A boxed, enum or flags type, resolved through its `get_type` function.
+    Typed { library: String, get_type_fn: String },
+    /// A fundamental type with its own reference counting functions.
+    Fundamental {
+        library: String,
+        ref_fn: String,
+        unref_fn: String,
+    },
+    /// The signature of a callback type.
+    Callback {
+        arg_types: Vec<Type>,
+        return_type: Type,
+    },
+}
+
+static REGISTRY: OnceLock<Mutex<HashMap<String, RegisteredType>>> = OnceLock::new();
+
+fn registry() -> &'static Mutex<HashMap<String, RegisteredType>> {
+    REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Records the metadata of the type named `name`.
+pub fn register(name: String, registered: RegisteredType) {
+    registry()
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .insert(name, registered);
+}
+
+/// Returns the metadata registered for the type named `name`.
+#[must_use]
+pub fn lookup(name: &str) -> Option<RegisteredType> {
+    registry()
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .get(name)
+        .cloned()
+}
+
+/// Returns the library and `get_type` function registered for `name`.
+#[must_use]
+pub fn lookup_typed(name: &str) -> Option<(String, String)> {
+    match lookup(name)? {
+        RegisteredType::Typed {
+            library,
+            get_type_fn,
+        } => Some((library, get_type_fn)),
+        _ => None,
+    }
+}
@@ -7,10 +7,76 @@ use napi::{Env, JsObject};
 use super::{FfiDecoder, FfiEncoder, GlibValueCodec, Ownership, RawPtrCodec};
 use crate::{ffi, value};
 
+/// How strings returned from C are converted when they are not valid UTF-8.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Utf8Policy {
+    /// Replace invalid sequences with U+FFFD.
+    #[default]
+    Lossy,
+    /// Fail the call.
+    Strict,
+    /// Return the raw bytes as a `Buffer`, valid or not.
+    Buffer,
+}
+
+impl Utf8Policy {
+    fn from_js_value(obj: &JsObject) -> napi::Result<Self> {
+        let policy: Option<String> = obj.get_named_property("utf8").ok().flatten();
+        match policy.as_deref() {
+            None | Some("lossy") => Ok(Self::Lossy),
+            Some("strict") => Ok(Self::Strict),
+            Some("buffer") => Ok(Self::Buffer),
+            Some(other) => Err(napi::Error::new(
+                napi::Status::InvalidArg,
+                format!("Invalid utf8 policy '{other}', expected 'lossy', 'strict' or 'buffer'"),
+            )),
+        }
+    }
+
+    /// Converts a C string according to this policy.
+    pub fn decode(self, c_str: &CStr) -> anyhow::Result<value::Value> {
+        self.decode_bytes(c_str.to_bytes())
+    }
+
+    /// Converts string bytes according to this policy.
+    pub fn decode_bytes(self, bytes: &[u8]) -> anyhow::Result<value::Value> {
+        match self {
+            Self::Lossy => Ok(value::Value::String(String::from_utf8_lossy(bytes).into_owned())),
+            Self::Strict => match std::str::from_utf8(bytes) {
+                Ok(s) => Ok(value::Value::String(s.to_owned())),
+                Err(e) => bail!("String returned from C is not valid UTF-8: {e}"),
+            },
+            Self::Buffer => Ok(value::Value::Bytes(bytes.to_vec())),
+        }
+    }
+}
+
+/// Returns the contents of a `GString`, which are counted rather than
+/// NUL-terminated and may contain NUL bytes.
+///
+/// # Safety
+///
+/// `gstring` must point to a valid `GString` that outlives the slice.
+unsafe fn gstring_bytes<'a>(gstring: *const glib::ffi::GString) -> &'a [u8] {
+    let gstring = unsafe { &*gstring };
+    if gstring.str.is_null() {
+        return &[];
+    }
+    unsafe { std::slice::from_raw_parts(gstring.str.cast::<u8>(), gstring.len) }
+}
+
+fn new_gstring(bytes: &[u8]) -> *mut glib::ffi::GString {
+    unsafe { glib::ffi::g_string_new_len(bytes.as_ptr().cast(), bytes.len() as isize) }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct StringType {
     pub ownership: Ownership,
     pub length: Option<usize>,
+    pub utf8: Utf8Policy,
+    /// The value is a `GString*` rather than a `char*`. Owned values are
+    /// released with `g_string_free`.
+    pub gstring: bool,
 }
 
 impl StringType {
@@ -23,30 +89,113 @@ impl StringType {
             .flatten()
             .map(|n| n as usize);
 
-        Ok(Self { ownership, length })
+        let utf8 = Utf8Policy::from_js_value(obj)?;
+
+        let gstring = obj
+            .get_named_property::<Option<bool>>("gstring")
+            .ok()
+            .flatten()
+            .unwrap_or(false);
+
+        Ok(Self {
+            ownership,
+            length,
+            utf8,
+            gstring,
+        })
+    }
+}
+
+impl StringType {
+    /// Fails if `bytes` do not fit in `length`, rather than passing a
+    /// silently truncated value.
+    fn check_length(&self, bytes: &[u8]) -> anyhow::Result<()> {
+        if let Some(length) = self.length
+            && bytes.len() > length
+        {
+            bail!("String of {} bytes does not fit in length {length}", bytes.len());
+        }
+        Ok(())
+    }
+
+    /// Encodes raw bytes for APIs that take an explicit length.
+    ///
+    /// The bytes are passed as-is, embedded NULs included. A NUL is still
+    /// appended past the end so callees that ignore the length never read
+    /// out of bounds.
+    fn encode_bytes(&self, bytes: &[u8]) -> anyhow::Result<ffi::FfiValue> {
+        self.check_length(bytes)?;
+        let len = bytes.len();
+
+        if self.ownership.is_full() {
+            let ptr = unsafe {
+                let ptr = glib::ffi::g_malloc(len + 1).cast::<u8>();
+                std::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, len);
+                ptr.add(len).write(0);
+                ptr
+            };
+            return Ok(ffi::FfiValue::Ptr(ptr.cast::<c_void>()));
+        }
+
+        let mut buffer = Vec::with_capacity(len + 1);
+        buffer.extend_from_slice(bytes);
+        buffer.push(0);
+        let ptr = buffer.as_mut_ptr().cast::<c_void>();
+        Ok(ffi::FfiValue::Storage(ffi::FfiStorage::new(
+            ptr,
+            ffi::FfiStorageKind::Buffer(buffer),
+        )))
+    }
+
+    /// Encodes bytes as a new `GString`, handed to the callee for full
+    /// ownership and freed after the call otherwise.
+    fn encode_gstring(&self, bytes: &[u8]) -> anyhow::Result<ffi::FfiValue> {
+        self.check_length(bytes)?;
+        let gstring = new_gstring(bytes);
+
+        if self.ownership.is_full() {
+            return Ok(ffi::FfiValue::Ptr(gstring.cast::<c_void>()));
+        }
+        Ok(ffi::FfiValue::Storage(ffi::FfiStorage::new(
+            gstring.cast::<c_void>(),
+            ffi::FfiStorageKind::GString(gstring),
+        )))
+    }
+
+    fn encode_cstring(&self, bytes: &[u8]) -> anyhow::Result<ffi::FfiValue> {
+        let Ok(cstring) = CString::new(bytes) else {
+            bail!("String contains an interior NUL byte; set 'length' to pass it as-is");
+        };
+        if self.ownership.is_full() {
+            let glib_ptr = unsafe { glib::ffi::g_strdup(cstring.as_ptr()) };
+            Ok(ffi::FfiValue::Ptr(glib_ptr as *mut c_void))
+        } else {
+            let ptr = cstring.as_ptr() as *mut c_void;
+            Ok(ffi::FfiValue::Storage(ffi::FfiStorage::new(
+                ptr,
+                ffi::FfiStorageKind::CString(cstring),
+            )))
+        }
     }
 }
 
 impl FfiEncoder for StringType {
     fn encode(&self, value: &value::Value, _optional: bool) -> anyhow::Result<ffi::FfiValue> {
-        match value {
-            value::Value::String(s) => {
-                let cstring = CString::new(s.as_bytes())?;
-                if self.ownership.is_full() {
-                    let glib_ptr = unsafe { glib::ffi::g_strdup(cstring.as_ptr()) };
-                    Ok(ffi::FfiValue::Ptr(glib_ptr as *mut c_void))
-                } else {
-                    let ptr = cstring.as_ptr() as *mut c_void;
-                    Ok(ffi::FfiValue::Storage(ffi::FfiStorage::new(
-                        ptr,
-                        ffi::FfiStorageKind::CString(cstring),
-                    )))
-                }
-            }
+        let bytes = match value {
+            value::Value::String(s) => s.as_bytes(),
+            value::Value::Bytes(b) => b.as_slice(),
             value::Value::Null | value::Value::Undefined => {
-                Ok(ffi::FfiValue::Ptr(std::ptr::null_mut()))
+                return Ok(ffi::FfiValue::Ptr(std::ptr::null_mut()));
             }
-            _ => bail!("Expected a String for string type, got {value:?}"),
+            _ => bail!("Expected a String or Buffer for string type, got {value:?}"),
+        };
+
+        if self.gstring {
+            self.encode_gstring(bytes)
+        } else if self.length.is_some() {
+            self.encode_bytes(bytes)
+        } else {
+            self.encode_cstring(bytes)
         }
     }
 }
@@ -57,14 +206,23 @@ impl FfiDecoder for StringType {
             return Ok(value::Value::Null);
         };
 
+        if self.gstring {
+            let gstring = str_ptr.cast::<glib::ffi::GString>();
+            let value = self.utf8.decode_bytes(unsafe { gstring_bytes(gstring) });
+            if self.ownership.is_full() {
+                unsafe { glib::ffi::g_string_free(gstring, glib::ffi::GTRUE) };
+            }
+            return value;
+        }
+
         let c_str = unsafe { CStr::from_ptr(str_ptr as *const c_char) };
-        let string = c_str.to_string_lossy().into_owned();
+        let value = self.utf8.decode(c_str);
 
         if self.ownership.is_full() {
             unsafe { glib::ffi::g_free(str_ptr) };
         }
 
-        Ok(value::Value::String(string))
+        value
     }
 }
 
@@ -73,12 +231,16 @@ impl RawPtrCodec for StringType {
         if ptr.is_null() {
             return Ok(value::Value::Null);
         }
+        if self.gstring {
+            return self.utf8.decode_bytes(unsafe { gstring_bytes(ptr.cast()) });
+        }
         let c_str = unsafe { CStr::from_ptr(ptr as *const c_char) };
-        Ok(value::Value::String(c_str.to_string_lossy().into_owned()))
+        self.utf8.decode(c_str)
     }
 
     fn write_return_to_raw_ptr(&self, ret: *mut c_void, value: &Result<value::Value, ()>) {
         let ptr = match value {
+            Ok(value::Value::String(s)) if self.gstring => new_gstring(s.as_bytes()).cast(),
             Ok(value::Value::String(s)) => CString::new(s.as_bytes())
                 .ok()
                 .map_or(std::ptr::null_mut(), |cs| unsafe {
@@ -91,6 +253,9 @@ impl RawPtrCodec for StringType {
 
     fn write_value_to_raw_ptr(&self, ptr: *mut c_void, value: &value::Value) -> anyhow::Result<()> {
         match value {
+            value::Value::String(s) if self.gstring => unsafe {
+                (ptr as *mut *mut glib::ffi::GString).write_unaligned(new_gstring(s.as_bytes()));
+            },
             value::Value::String(s) => {
                 let c_string = CString::new(s.as_str())?;
                 let duped = unsafe { glib::ffi::g_strdup(c_string.as_ptr()) };
@@ -35,6 +35,41 @@ impl std::str::FromStr for TrampolineScope {
     }
 }
 
+/// How a trampoline turns the JS callback's result into its return value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TrampolineKind {
+    /// The result is written as the declared return type.
+    #[default]
+    Default,
+    /// A `GCompareFunc`-style comparator: any JS number, including
+    /// fractions such as `a.size - b.size`, is reduced to its sign.
+    Compare,
+    /// A predicate such as a `GtkCustomFilterFunc`: the JS result is
+    /// converted to a `gboolean` by its truthiness.
+    Filter,
+    /// A `GSourceFunc`: the source continues only if the JS callback returns
+    /// `true` or a non-zero number, and is removed if it returns anything
+    /// else or throws.
+    Source,
+}
+
+impl std::str::FromStr for TrampolineKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(Self::Default),
+            "compare" => Ok(Self::Compare),
+            "filter" => Ok(Self::Filter),
+            "source" => Ok(Self::Source),
+            other => Err(format!(
+                "'kind' must be 'default', 'compare', 'filter', or 'source'; got '{other}'"
+            )),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TrampolineType {
     pub arg_types: Vec<Type>,
@@ -42,6 +77,7 @@ pub struct TrampolineType {
     pub has_destroy: bool,
     pub user_data_index: Option<usize>,
     pub scope: TrampolineScope,
+    pub kind: TrampolineKind,
 }
 
 impl TrampolineType {
@@ -79,12 +115,46 @@ impl TrampolineType {
             }
         };
 
+        let kind_prop: Option<String> = obj
+            .get_named_property::<Option<String>>("kind")
+            .ok()
+            .flatten();
+
+        let kind = match kind_prop {
+            Some(s) => s
+                .parse()
+                .map_err(|e: String| napi::Error::new(napi::Status::InvalidArg, e))?,
+            None => TrampolineKind::Default,
+        };
+
+        if kind == TrampolineKind::Compare && !matches!(*return_type, Type::Integer(_)) {
+            return Err(napi::Error::new(
+                napi::Status::InvalidArg,
+                format!("Compare trampolines must return an integer, not {return_type}"),
+            ));
+        }
+
+        if matches!(kind, TrampolineKind::Filter | TrampolineKind::Source)
+            && !matches!(*return_type, Type::Boolean(_))
+        {
+            let name = if kind == TrampolineKind::Filter {
+                "Filter"
+            } else {
+                "Source"
+            };
+            return Err(napi::Error::new(
+                napi::Status::InvalidArg,
+                format!("{name} trampolines must return a boolean, not {return_type}"),
+            ));
+        }
+
         Ok(Self {
             arg_types,
             return_type,
             has_destroy,
             user_data_index,
             scope,
+            kind,
         })
     }
 }
@@ -125,6 +195,7 @@ impl FfiEncoder for TrampolineType {
             arg_types: self.arg_types.clone(),
             return_type: (*self.return_type).clone(),
             user_data_index: self.user_data_index,
+            kind: self.kind,
             is_oneshot,
             oneshot_state_ptr: AtomicPtr::new(std::ptr::null_mut()),
         };
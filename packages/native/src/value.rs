@@ -11,30 +11,39 @@
 //! - Callbacks: JavaScript functions invocable from native code
 //! - Arrays and references
 
-use std::ffi::c_void;
+use std::ffi::{CStr, c_void};
 use std::sync::Arc;
 
 use anyhow::bail;
 use gtk4::glib::{
     self,
     prelude::{ObjectExt as _, ObjectType as _},
-    translate::{FromGlibPtrNone as _, ToGlibPtrMut as _},
+    translate::{FromGlibPtrNone as _, ToGlibPtr as _, ToGlibPtrMut as _},
     value::ToValue as _,
 };
 use napi::bindgen_prelude::*;
 use napi::sys;
-use napi::{Env, JsFunction, JsObject, NapiRaw as _, ValueType};
+use napi::{Env, JsFunction, JsObject, JsValue as _, NapiRaw as _, ValueType};
 
+use crate::dispatch::Mailbox;
 use crate::error_reporter::NativeErrorReporter;
 use crate::managed::NativeHandle;
-use crate::types::{FfiDecoder, GlibValueCodec, Type};
+use crate::managed::dependents::Lifeline;
+use crate::state::JsThread;
+use crate::types::{
+    ArrayKind, ArrayType, BoxedType, FfiDecoder, FundamentalType, GObjectType, GlibValueCodec,
+    IntegerKind, Ownership, Type, VoidType,
+};
 use crate::{arg::Arg, ffi};
 
 /// Send-safe napi reference to a JavaScript function.
 ///
 /// Wraps a raw `napi_ref` paired with its `napi_env`. Sending the ref across
 /// threads is safe because the contained pointer is opaque; only the JS thread
-/// dereferences it via `get_value`. The reference is released on `Drop`.
+/// dereferences it via `get_value`. The reference is released on `Drop`;
+/// a ref dropped on another thread (typically the `GLib` thread, when a
+/// signal handler is disconnected or its instance is finalized) is handed
+/// back to the JS thread and released there.
 pub struct JsCallbackRef {
     raw: sys::napi_ref,
     env: sys::napi_env,
@@ -51,6 +60,22 @@ impl std::fmt::Debug for JsCallbackRef {
 
 impl Drop for JsCallbackRef {
     fn drop(&mut self) {
+        if self.raw.is_null() {
+            return;
+        }
+
+        if !JsThread::global().is_current() {
+            // Leaked rather than released here if the mailbox has stopped
+            // and the task is discarded unrun.
+            let deferred = std::mem::ManuallyDrop::new(Self {
+                raw: std::mem::replace(&mut self.raw, std::ptr::null_mut()),
+                env: self.env,
+            });
+            Mailbox::global()
+                .post_node(move |_env| drop(std::mem::ManuallyDrop::into_inner(deferred)));
+            return;
+        }
+
         let status = unsafe { sys::napi_delete_reference(self.env, self.raw) };
         debug_assert_eq!(status, sys::Status::napi_ok);
     }
@@ -152,6 +177,89 @@ impl JsObjectRefValue {
     }
 }
 
+/// Send-safe napi reference to the `External` of a [`NativeHandle`].
+///
+/// Keeps the handle, and the value it owns, alive while a call that runs
+/// after its JS frame has returned still uses the pointer. Like
+/// [`JsCallbackRef`], a reference dropped off the JS thread is handed back
+/// to the JS thread and released there.
+pub struct HandleRef {
+    raw: sys::napi_ref,
+    env: sys::napi_env,
+}
+
+unsafe impl Send for HandleRef {}
+unsafe impl Sync for HandleRef {}
+
+impl std::fmt::Debug for HandleRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HandleRef").finish_non_exhaustive()
+    }
+}
+
+impl Drop for HandleRef {
+    fn drop(&mut self) {
+        if self.raw.is_null() {
+            return;
+        }
+
+        if !JsThread::global().is_current() {
+            let deferred = std::mem::ManuallyDrop::new(Self {
+                raw: std::mem::replace(&mut self.raw, std::ptr::null_mut()),
+                env: self.env,
+            });
+            Mailbox::global()
+                .post_node(move |_env| drop(std::mem::ManuallyDrop::into_inner(deferred)));
+            return;
+        }
+
+        let status = unsafe { sys::napi_delete_reference(self.env, self.raw) };
+        debug_assert_eq!(status, sys::Status::napi_ok);
+    }
+}
+
+impl HandleRef {
+    /// Takes a reference on every handle in `value`, looking into arrays
+    /// and `Ref` objects the way [`Value::from_js_value`] does.
+    pub fn collect(env: &Env, value: Unknown<'_>, handles: &mut Vec<Self>) -> napi::Result<()> {
+        match value.get_type()? {
+            ValueType::External => {
+                let mut raw_ref = std::ptr::null_mut();
+                let status =
+                    unsafe { sys::napi_create_reference(env.raw(), value.raw(), 1, &mut raw_ref) };
+                if status != sys::Status::napi_ok {
+                    return Err(napi::Error::new(
+                        napi::Status::GenericFailure,
+                        "Failed to create handle reference",
+                    ));
+                }
+                handles.push(Self {
+                    raw: raw_ref,
+                    env: env.raw(),
+                });
+            }
+            ValueType::Object if value.is_array()? => {
+                let arr: Array = unsafe { Array::from_napi_value(env.raw(), value.raw())? };
+                for i in 0..arr.len() {
+                    if let Some(item) = arr.get::<Unknown<'_>>(i)? {
+                        Self::collect(env, item, handles)?;
+                    }
+                }
+            }
+            ValueType::Object
+                if !value.is_buffer()? && !value.is_typedarray()? && !value.is_arraybuffer()? =>
+            {
+                use napi::NapiValue as _;
+                let obj: JsObject = unsafe { JsObject::from_raw_unchecked(env.raw(), value.raw()) };
+                let value_prop: Unknown<'_> = obj.get_named_property("value")?;
+                Self::collect(env, value_prop, handles)?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub struct Callback {
     pub js_func: Arc<JsCallbackRef>,
@@ -165,6 +273,7 @@ impl Callback {
 
     pub fn from_js_value(env: &Env, value: Unknown<'_>) -> napi::Result<Self> {
         use napi::NapiValue as _;
+        crate::state::JsThread::global().ensure_current("Passing callbacks")?;
         let func: JsFunction = unsafe { JsFunction::from_raw_unchecked(env.raw(), value.raw()) };
         let func_ref = JsCallbackRef::from_js_function(env, &func)?;
         Ok(Self::new(Arc::new(func_ref)))
@@ -223,7 +332,16 @@ impl Ref {
 #[non_exhaustive]
 pub enum Value {
     Number(f64),
+    /// Integer received from or destined for a JS `BigInt`. Wide enough to
+    /// hold both `i64` and `u64` exactly.
+    BigInt(i128),
     String(String),
+    /// Bytes received from a JS `Buffer`, `Uint8Array` or `ArrayBuffer`,
+    /// copied in one block rather than element by element.
+    Bytes(Vec<u8>),
+    /// Contents of a `GBytes`, handed to JS as a `Buffer` over them that
+    /// keeps a reference on the `GBytes` instead of a copy.
+    SharedBytes(glib::Bytes),
     Boolean(bool),
     Object(NativeHandle),
     Null,
@@ -242,12 +360,25 @@ impl Value {
         }
     }
 
+    /// Limits the borrowed struct handles among `values` to `scope`, so
+    /// they stop working once a callback that received them returns.
+    pub fn scope_views(values: &mut [Self], scope: &Arc<Lifeline>) {
+        for value in values {
+            if let Self::Object(handle) = value {
+                handle.scope_to(scope);
+            }
+        }
+    }
+
     pub fn object_ptr(&self, type_name: &str) -> anyhow::Result<*mut c_void> {
         match self {
             Self::Object(handle) => Ok(handle.ptr()),
             Self::Null | Self::Undefined => Ok(std::ptr::null_mut()),
             Self::Number(_)
+            | Self::BigInt(_)
             | Self::String(_)
+            | Self::Bytes(_)
+            | Self::SharedBytes(_)
             | Self::Boolean(_)
             | Self::Array(_)
             | Self::Callback(_)
@@ -270,46 +401,91 @@ impl Value {
         ty.decode_with_context(ffi_value, ffi_args, args)
     }
 
+    /// Converts a JS callback result to the value a closure returns for
+    /// `return_type`. Numbers, booleans and `BigInt`s are coerced to the
+    /// declared number or boolean type, since callbacks often return `a > b`
+    /// where an integer is expected. `null`, `undefined` and results that
+    /// fail to convert give the type's zero value, such as `0` or a `NULL`
+    /// boxed or `GVariant`, so the caller always receives the type it expects.
     #[must_use]
     pub fn into_glib_value_with_default(self, return_type: Option<&Type>) -> Option<glib::Value> {
-        match &self {
-            Self::Undefined => {
-                let ty = return_type?;
-                let default = match ty {
-                    Type::Boolean(_) => Self::Boolean(false),
-                    Type::Integer(_) | Type::Enum(_) | Type::Flags(_) | Type::Float(_) => {
-                        Self::Number(0.0)
-                    }
-                    Type::String(_) | Type::GObject(_) => Self::Null,
-                    _ => return None,
-                };
-                match ty.to_glib_value(&default) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        NativeErrorReporter::global()
-                            .report(&e.context("failed to compute default glib value"));
-                        None
-                    }
-                }
+        let Some(ty) = return_type else {
+            if matches!(self, Self::Undefined) {
+                return None;
             }
-            Self::Number(_)
-            | Self::String(_)
-            | Self::Boolean(_)
-            | Self::Object(_)
-            | Self::Null
-            | Self::Array(_)
-            | Self::Callback(_)
-            | Self::Ref(_) => match self.to_glib_value_typed(return_type) {
-                Ok(v) => Some(v),
-                Err(e) => {
+            return self
+                .to_glib_value()
+                .map_err(|e| {
                     NativeErrorReporter::global()
                         .report(&e.context("failed to convert value to glib::Value"));
-                    None
+                })
+                .ok();
+        };
+        if matches!(ty, Type::Void(_)) {
+            return None;
+        }
+
+        let default = Self::default_glib_value(ty).unwrap_or_else(|e| {
+            NativeErrorReporter::global()
+                .report(&e.context("failed to compute default glib value"));
+            None
+        });
+        if matches!(self, Self::Null | Self::Undefined) {
+            return default;
+        }
+
+        match self.coerce_to(ty).to_glib_value_typed(Some(ty)) {
+            Ok(v) => match &default {
+                Some(expected) if !v.type_().is_a(expected.type_()) => {
+                    NativeErrorReporter::global().report(&anyhow::anyhow!(
+                        "Expected a {} return value, got {}",
+                        expected.type_(),
+                        v.type_()
+                    ));
                 }
+                _ => return Some(v),
             },
+            Err(e) => NativeErrorReporter::global()
+                .report(&e.context("failed to convert value to glib::Value")),
+        }
+        default
+    }
+
+    /// Coerces numbers, booleans and `BigInt`s to the kind of value `ty`
+    /// converts from. Other values are returned unchanged.
+    fn coerce_to(self, ty: &Type) -> Self {
+        match (ty, self) {
+            (Type::Boolean(_), Self::Number(n)) => Self::Boolean(n != 0.0),
+            (Type::Boolean(_), Self::BigInt(n)) => Self::Boolean(n != 0),
+            (
+                Type::Integer(_) | Type::Enum(_) | Type::Flags(_) | Type::Float(_),
+                Self::Boolean(b),
+            ) => Self::Number(f64::from(u8::from(b))),
+            (Type::Enum(_) | Type::Float(_), Self::BigInt(n)) => Self::Number(n as f64),
+            (_, value) => value,
         }
     }
 
+    /// Returns the zero value of `ty` as a `glib::Value`, or `None` for types
+    /// without one.
+    fn default_glib_value(ty: &Type) -> anyhow::Result<Option<glib::Value>> {
+        let default = match ty {
+            Type::Boolean(_) => Self::Boolean(false),
+            Type::Integer(_) | Type::Enum(_) | Type::Flags(_) | Type::Float(_) => {
+                Self::Number(0.0)
+            }
+            Type::PointerSized(_) => Self::BigInt(0),
+            Type::String(_) | Type::GObject(_) => Self::Null,
+            Type::Boxed(boxed) => return Ok(boxed.gtype().map(glib::Value::from_type)),
+            Type::Fundamental(fundamental) => {
+                let gtype = fundamental.type_name.as_deref().and_then(glib::Type::from_name);
+                return Ok(gtype.map(glib::Value::from_type));
+            }
+            _ => return Ok(None),
+        };
+        ty.to_glib_value(&default)
+    }
+
     pub fn to_glib_value(self) -> anyhow::Result<glib::Value> {
         self.to_glib_value_typed(None)
     }
@@ -322,6 +498,10 @@ impl Value {
         }
         match self {
             Self::Number(n) => Ok(n.into()),
+            Self::BigInt(n) => match i64::try_from(n) {
+                Ok(v) => Ok(v.into()),
+                Err(_) => Ok(u64::try_from(n)?.into()),
+            },
             Self::String(s) => Ok(s.into()),
             Self::Boolean(b) => Ok(b.into()),
             Self::Object(handle) => {
@@ -345,7 +525,8 @@ impl Value {
             Self::Null | Self::Undefined => {
                 bail!("Cannot convert Null/Undefined to glib::Value without a type hint")
             }
-            Self::Array(_) | Self::Callback(_) | Self::Ref(_) => {
+            Self::SharedBytes(bytes) => Ok(bytes.to_value()),
+            Self::Bytes(_) | Self::Array(_) | Self::Callback(_) | Self::Ref(_) => {
                 bail!("Unsupported Value type for glib::Value conversion: {self:?}")
             }
         }
@@ -359,6 +540,17 @@ impl Value {
                 let n = unsafe { f64::from_napi_value(env.raw(), value.raw())? };
                 Ok(Self::Number(n))
             }
+            ValueType::BigInt => {
+                let big = unsafe { BigInt::from_napi_value(env.raw(), value.raw())? };
+                let (n, lossless) = big.get_i128();
+                if !lossless {
+                    return Err(napi::Error::new(
+                        napi::Status::InvalidArg,
+                        "BigInt does not fit in 128 bits".to_owned(),
+                    ));
+                }
+                Ok(Self::BigInt(n))
+            }
             ValueType::String => {
                 let s = unsafe { String::from_napi_value(env.raw(), value.raw())? };
                 Ok(Self::String(s))
@@ -372,6 +564,9 @@ impl Value {
             ValueType::External => {
                 let external_ref =
                     unsafe { <&External<NativeHandle>>::from_napi_value(env.raw(), value.raw())? };
+                external_ref
+                    .ensure_alive()
+                    .map_err(|e| napi::Error::new(napi::Status::InvalidArg, e.to_string()))?;
                 Ok(Self::Object(NativeHandle::borrowed(external_ref.ptr())))
             }
             ValueType::Function => {
@@ -379,6 +574,19 @@ impl Value {
                 Ok(Self::Callback(cb))
             }
             ValueType::Object => {
+                if value.is_buffer()? {
+                    let buffer = unsafe { Buffer::from_napi_value(env.raw(), value.raw())? };
+                    return Ok(Self::Bytes(buffer.to_vec()));
+                }
+                if is_uint8_array(env, &value)? {
+                    let bytes =
+                        unsafe { Uint8ArraySlice::from_napi_value(env.raw(), value.raw())? };
+                    return Ok(Self::Bytes(bytes.to_vec()));
+                }
+                if value.is_arraybuffer()? {
+                    let bytes = unsafe { ArrayBuffer::from_napi_value(env.raw(), value.raw())? };
+                    return Ok(Self::Bytes(bytes.to_vec()));
+                }
                 if value.is_array()? {
                     let arr: Array = unsafe { Array::from_napi_value(env.raw(), value.raw())? };
                     let len = arr.len();
@@ -411,10 +619,28 @@ impl Value {
                 let raw = f64::to_napi_value(env.raw(), n)?;
                 Ok(Unknown::from_raw_unchecked(env.raw(), raw))
             },
+            Self::BigInt(n) => unsafe {
+                let raw = BigInt::to_napi_value(env.raw(), BigInt::from(n))?;
+                Ok(Unknown::from_raw_unchecked(env.raw(), raw))
+            },
             Self::String(s) => unsafe {
                 let raw = String::to_napi_value(env.raw(), s)?;
                 Ok(Unknown::from_raw_unchecked(env.raw(), raw))
             },
+            Self::Bytes(bytes) => unsafe {
+                let raw = Buffer::to_napi_value(env.raw(), Buffer::from(bytes))?;
+                Ok(Unknown::from_raw_unchecked(env.raw(), raw))
+            },
+            Self::SharedBytes(bytes) => {
+                if bytes.is_empty() {
+                    return Ok(BufferSlice::from_data(env, Vec::new())?.to_unknown());
+                }
+                let data = bytes.as_ptr().cast_mut();
+                let len = bytes.len();
+                let release = |_: Env, bytes: glib::Bytes| drop(bytes);
+                let buffer = unsafe { BufferSlice::from_external(env, data, len, bytes, release)? };
+                Ok(buffer.to_unknown())
+            }
             Self::Boolean(b) => unsafe {
                 let raw = bool::to_napi_value(env.raw(), b)?;
                 Ok(Unknown::from_raw_unchecked(env.raw(), raw))
@@ -461,3 +687,122 @@ impl Value {
             .collect()
     }
 }
+
+/// Returns whether `value` is a `Uint8Array`. Other typed arrays hold
+/// wider elements, so their bytes are not a byte array's elements.
+fn is_uint8_array(env: &Env, value: &Unknown<'_>) -> napi::Result<bool> {
+    if !value.is_typedarray()? {
+        return Ok(false);
+    }
+    let mut kind = sys::TypedarrayType::int8_array;
+    let status = unsafe {
+        sys::napi_get_typedarray_info(
+            env.raw(),
+            value.raw(),
+            &mut kind,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        )
+    };
+    if status != sys::Status::napi_ok {
+        return Err(napi::Error::new(
+            napi::Status::GenericFailure,
+            "Failed to read typed array info",
+        ));
+    }
+    Ok(kind == sys::TypedarrayType::uint8_array)
+}
+
+/// Converts a `GValue` by the type it holds, for values whose type was not
+/// declared. Enums and flags become numbers; objects, boxed values,
+/// variants and param specs are borrowed as handles.
+impl TryFrom<&glib::Value> for Value {
+    type Error = anyhow::Error;
+
+    fn try_from(gvalue: &glib::Value) -> anyhow::Result<Self> {
+        use glib::gobject_ffi as gobject;
+
+        let gtype = gvalue.type_();
+        let raw = gvalue.to_glib_none().0;
+        let value = match gtype.fundamental() {
+            glib::Type::BOOL => Self::Boolean(unsafe { gobject::g_value_get_boolean(raw) } != 0),
+            glib::Type::I8 => Self::Number(unsafe { gobject::g_value_get_schar(raw) }.into()),
+            glib::Type::U8 => Self::Number(unsafe { gobject::g_value_get_uchar(raw) }.into()),
+            glib::Type::I32 => Self::Number(unsafe { gobject::g_value_get_int(raw) }.into()),
+            glib::Type::U32 => Self::Number(unsafe { gobject::g_value_get_uint(raw) }.into()),
+            glib::Type::I_LONG => {
+                IntegerKind::wide_to_value(unsafe { gobject::g_value_get_long(raw) }.into())
+            }
+            glib::Type::U_LONG => {
+                IntegerKind::wide_to_value(unsafe { gobject::g_value_get_ulong(raw) }.into())
+            }
+            glib::Type::I64 => {
+                IntegerKind::wide_to_value(unsafe { gobject::g_value_get_int64(raw) }.into())
+            }
+            glib::Type::U64 => {
+                IntegerKind::wide_to_value(unsafe { gobject::g_value_get_uint64(raw) }.into())
+            }
+            glib::Type::F32 => Self::Number(unsafe { gobject::g_value_get_float(raw) }.into()),
+            glib::Type::F64 => Self::Number(unsafe { gobject::g_value_get_double(raw) }),
+            glib::Type::ENUM => Self::Number(unsafe { gobject::g_value_get_enum(raw) }.into()),
+            glib::Type::FLAGS => Self::Number(unsafe { gobject::g_value_get_flags(raw) }.into()),
+            glib::Type::STRING => {
+                let ptr = unsafe { gobject::g_value_get_string(raw) };
+                if ptr.is_null() {
+                    Self::Null
+                } else {
+                    let c_str = unsafe { CStr::from_ptr(ptr) };
+                    Self::String(c_str.to_string_lossy().into_owned())
+                }
+            }
+            glib::Type::OBJECT | glib::Type::INTERFACE => {
+                let object_type = GObjectType {
+                    ownership: Ownership::Borrowed,
+                };
+                object_type.from_glib_value(gvalue)?
+            }
+            glib::Type::BOXED if gtype.name() == "GValueArray" => {
+                let array_type = ArrayType {
+                    item_type: Box::new(Type::Void(VoidType)),
+                    kind: ArrayKind::GValueArray,
+                    ownership: Ownership::Borrowed,
+                    element_size: None,
+                };
+                array_type.from_glib_value(gvalue)?
+            }
+            glib::Type::BOXED => {
+                let boxed_type = BoxedType {
+                    ownership: Ownership::Borrowed,
+                    type_name: gtype.name().to_owned(),
+                    library: None,
+                    get_type_fn: None,
+                };
+                boxed_type.from_glib_value(gvalue)?
+            }
+            glib::Type::VARIANT => {
+                let variant_type = FundamentalType {
+                    ownership: Ownership::Borrowed,
+                    library: "libglib-2.0.so.0".to_owned(),
+                    ref_func: "g_variant_ref".to_owned(),
+                    unref_func: "g_variant_unref".to_owned(),
+                    type_name: Some("GVariant".to_owned()),
+                };
+                variant_type.from_glib_value(gvalue)?
+            }
+            glib::Type::PARAM_SPEC => {
+                let param_type = FundamentalType {
+                    ownership: Ownership::Borrowed,
+                    library: "libgobject-2.0.so.0".to_owned(),
+                    ref_func: "g_param_spec_ref".to_owned(),
+                    unref_func: "g_param_spec_unref".to_owned(),
+                    type_name: Some("GParam".to_owned()),
+                };
+                param_type.from_glib_value(gvalue)?
+            }
+            _ => bail!("Cannot convert a GValue of type '{}'", gtype.name()),
+        };
+        Ok(value)
+    }
+}
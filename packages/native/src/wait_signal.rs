@@ -1,4 +1,5 @@
 use std::sync::{Condvar, Mutex};
+use std::time::Duration;
 
 #[derive(Debug)]
 pub struct WaitSignal {
@@ -45,4 +46,18 @@ impl WaitSignal {
         }
         *notified = false;
     }
+
+    /// Like [`Self::wait`], but gives up after `timeout`. Returns whether the
+    /// signal was notified.
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        let notified = self
+            .state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let (mut notified, _) = self
+            .condvar
+            .wait_timeout_while(notified, timeout, |notified| !*notified)
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        std::mem::take(&mut *notified)
+    }
 }
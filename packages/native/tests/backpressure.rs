@@ -0,0 +1,21 @@
+use native::dispatch::Mailbox;
+
+#[test]
+fn saturation_follows_threshold_and_drops_are_counted() {
+    let mailbox = Mailbox::global();
+    mailbox.set_backpressure_threshold(3);
+    assert!(!mailbox.is_saturated());
+
+    for _ in 0..3 {
+        mailbox.post_node(|_env| {});
+    }
+    assert!(mailbox.is_saturated());
+
+    mailbox.set_backpressure_threshold(0);
+    assert!(!mailbox.is_saturated());
+
+    mailbox.record_drop();
+    mailbox.record_drop();
+    assert_eq!(mailbox.queue_depths(true).dropped, 2);
+    assert_eq!(mailbox.queue_depths(false).dropped, 0);
+}
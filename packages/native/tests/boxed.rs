@@ -6,6 +6,7 @@ use gtk4::glib::translate::IntoGlib as _;
 use gtk4::prelude::StaticType as _;
 
 use native::Boxed;
+use native::types::{FloatKind, IntegerKind, Ownership, StructType, Type};
 
 #[test]
 fn from_glib_full_sets_owned_flag() {
@@ -293,3 +294,33 @@ fn clone_without_gtype_returns_non_owned_shallow_copy() {
     assert_eq!(cloned.as_ptr(), boxed.as_ptr());
     assert!(!cloned.is_owned());
 }
+
+#[test]
+fn struct_fields_are_laid_out_with_c_alignment() {
+    let point = StructType::with_fields(
+        Ownership::Borrowed,
+        "TestPoint".to_string(),
+        vec![
+            ("tag".to_string(), Type::Integer(IntegerKind::U8), None),
+            ("x".to_string(), Type::Float(FloatKind::F64), None),
+        ],
+    )
+    .unwrap();
+    let rect = StructType::with_fields(
+        Ownership::Borrowed,
+        "TestRect".to_string(),
+        vec![
+            ("id".to_string(), Type::Integer(IntegerKind::I32), None),
+            ("origin".to_string(), Type::Struct(point.clone()), None),
+            ("count".to_string(), Type::Integer(IntegerKind::U16), None),
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(point.size, Some(16));
+    assert_eq!(rect.size, Some(32));
+    assert_eq!(rect.field("origin").unwrap().0, 8);
+    assert_eq!(rect.field("origin.x").unwrap().0, 16);
+    assert_eq!(rect.field("count").unwrap().0, 24);
+    assert!(rect.field("origin.y").is_err());
+}
@@ -8,6 +8,7 @@ use std::sync::{
 use glib::translate::ToGlibPtr as _;
 use gtk4::glib;
 use native::callback::ClosureGuard;
+use native::types::{BooleanType, ClosurePlan, FloatKind, IntegerKind, Type};
 
 fn create_test_closure_with_flag(
     flag: Arc<AtomicBool>,
@@ -89,3 +90,14 @@ fn closure_guard_from_ptr_with_null_returns_none() {
     let guard = ClosureGuard::from_ptr(std::ptr::null_mut());
     assert!(guard.is_none());
 }
+
+#[test]
+fn closure_plan_keeps_its_signature() {
+    let arg_types = vec![Type::Float(FloatKind::F64), Type::Integer(IntegerKind::U32)];
+
+    let plan = ClosurePlan::new(arg_types, Type::Boolean(BooleanType));
+
+    assert_eq!(plan.arg_types().len(), 2);
+    assert!(matches!(plan.arg_types()[1], Type::Integer(IntegerKind::U32)));
+    assert!(matches!(plan.return_type(), Type::Boolean(_)));
+}
@@ -6,7 +6,7 @@ use native::arg::Arg;
 use native::ffi::{FfiStorage, FfiStorageKind, FfiValue};
 use native::types::{
     ArrayKind, ArrayType, BooleanType, FloatKind, IntegerKind, Ownership, StringType, Type,
-    VoidType,
+    Utf8Policy, VoidType,
 };
 use native::value;
 
@@ -178,6 +178,8 @@ fn try_from_string_full() {
         Type::String(StringType {
             ownership: Ownership::Full,
             length: None,
+            utf8: Utf8Policy::Lossy,
+            gstring: false,
         }),
         value::Value::String("hello world".to_string()),
     );
@@ -201,6 +203,8 @@ fn try_from_string_borrowed() {
         Type::String(StringType {
             ownership: Ownership::Borrowed,
             length: None,
+            utf8: Utf8Policy::Lossy,
+            gstring: false,
         }),
         value::Value::String("hello world".to_string()),
     );
@@ -223,6 +227,8 @@ fn try_from_string_null() {
         Type::String(StringType {
             ownership: Ownership::Full,
             length: None,
+            utf8: Utf8Policy::Lossy,
+            gstring: false,
         }),
         value::Value::Null,
     );
@@ -316,6 +322,44 @@ fn try_from_array_u8() {
     }
 }
 
+#[test]
+fn try_from_array_u8_bytes() {
+    let arg = Arg::new(
+        Type::Array(ArrayType {
+            item_type: Box::new(Type::Integer(IntegerKind::U8)),
+            kind: ArrayKind::Sized { size_index: 1 },
+            ownership: Ownership::Borrowed,
+            element_size: None,
+        }),
+        value::Value::Bytes(vec![0, 128, 255]),
+    );
+
+    let result = FfiValue::try_from(arg);
+    if let FfiValue::Storage(owned) = result.unwrap() {
+        unsafe {
+            let slice = std::slice::from_raw_parts(owned.ptr() as *const u8, 3);
+            assert_eq!(slice, &[0, 128, 255]);
+        }
+    } else {
+        panic!("Expected FfiValue::Storage");
+    }
+}
+
+#[test]
+fn try_from_array_i32_rejects_bytes() {
+    let arg = Arg::new(
+        Type::Array(ArrayType {
+            item_type: Box::new(Type::Integer(IntegerKind::I32)),
+            kind: ArrayKind::Array,
+            ownership: Ownership::Full,
+            element_size: None,
+        }),
+        value::Value::Bytes(vec![1, 2, 3]),
+    );
+
+    assert!(FfiValue::try_from(arg).is_err());
+}
+
 #[test]
 fn try_from_array_i32() {
     let arg = Arg::new(
@@ -376,6 +420,8 @@ fn try_from_array_string() {
             item_type: Box::new(Type::String(StringType {
                 ownership: Ownership::Full,
                 length: None,
+                utf8: Utf8Policy::Lossy,
+                gstring: false,
             })),
             kind: ArrayKind::Array,
             ownership: Ownership::Full,
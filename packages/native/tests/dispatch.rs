@@ -4,6 +4,7 @@ use std::sync::{
     Arc,
     atomic::{AtomicUsize, Ordering},
 };
+use std::time::{Duration, Instant};
 
 use native::dispatch::Mailbox;
 
@@ -97,3 +98,51 @@ fn dispatch_pending_drains_multiple_tasks_in_fifo_order() {
     let collected = order.lock().unwrap().clone();
     assert_eq!(collected, vec![0, 1, 2, 3, 4]);
 }
+
+fn iterate_until(deadline: Duration, mut done: impl FnMut() -> bool) {
+    let context = gtk4::glib::MainContext::default();
+    let start = Instant::now();
+    while !done() && start.elapsed() < deadline {
+        drain_pending();
+        context.iteration(false);
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
+
+#[test]
+fn schedule_glib_delayed_runs_task_after_delay() {
+    common::ensure_gtk_init();
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    let counter_clone = counter.clone();
+    let start = Instant::now();
+
+    let task = Mailbox::global().schedule_glib_delayed(Duration::from_millis(20), move || {
+        counter_clone.fetch_add(1, Ordering::SeqCst);
+    });
+
+    iterate_until(Duration::from_secs(2), || counter.load(Ordering::SeqCst) == 1);
+
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+    assert!(start.elapsed() >= Duration::from_millis(20));
+    assert!(!task.is_cancelled());
+}
+
+#[test]
+fn schedule_glib_delayed_skips_cancelled_task() {
+    common::ensure_gtk_init();
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    let counter_clone = counter.clone();
+
+    let task = Mailbox::global().schedule_glib_delayed(Duration::from_millis(10), move || {
+        counter_clone.fetch_add(1, Ordering::SeqCst);
+    });
+    drain_pending();
+    task.cancel();
+
+    iterate_until(Duration::from_millis(60), || false);
+
+    assert_eq!(counter.load(Ordering::SeqCst), 0);
+    assert!(task.is_cancelled());
+}
@@ -3,11 +3,13 @@ mod common;
 use std::ffi::c_void;
 
 use gtk4::glib;
+use gtk4::prelude::ObjectType as _;
 
 use native::ffi::FfiValue;
+use native::managed::NativeValue;
 use native::types::{
-    BooleanType, FloatKind, HashTableEntryEncoder, HashTableType, IntegerKind, Ownership,
-    StringType, StructType, Type,
+    BooleanType, FloatKind, GObjectType, HashTableEntryEncoder, HashTableType, IntegerKind,
+    Ownership, StringType, StructType, Type, Utf8Policy,
 };
 use native::types::{FfiDecoder, FfiEncoder, RawPtrCodec};
 use native::value::Value;
@@ -38,6 +40,8 @@ fn encoder_from_type_string() {
     let ty = Type::String(StringType {
         ownership: Ownership::Borrowed,
         length: None,
+        utf8: Utf8Policy::Lossy,
+        gstring: false,
     });
     let encoder = HashTableEntryEncoder::from_type(&ty);
     assert_eq!(encoder, Some(HashTableEntryEncoder::String));
@@ -328,6 +332,8 @@ fn hashtable_encode_decode_string_to_boolean() {
     let key_type = Type::String(StringType {
         ownership: Ownership::Borrowed,
         length: None,
+        utf8: Utf8Policy::Lossy,
+        gstring: false,
     });
     let value_type = Type::Boolean(BooleanType);
     let ht_type = HashTableType {
@@ -561,3 +567,62 @@ fn boolean_roundtrip_preserves_values() {
 
     assert!(found_true && found_false);
 }
+
+#[test]
+fn hashtable_transfer_full_releases_object_values() {
+    common::ensure_gtk_init();
+
+    let obj = glib::Object::new::<glib::Object>();
+    let obj_ptr = obj.as_ptr();
+
+    let ht_type = HashTableType {
+        key_type: Box::new(Type::Integer(IntegerKind::I32)),
+        value_type: Box::new(Type::GObject(GObjectType {
+            ownership: Ownership::Full,
+        })),
+        ownership: Ownership::Full,
+    };
+    let input = Value::Array(vec![Value::Array(vec![
+        Value::Number(1.0),
+        Value::Object(NativeValue::GObject(obj).into()),
+    ])]);
+    let initial_ref = common::get_gobject_refcount(obj_ptr);
+
+    let encoded = ht_type
+        .encode(&input, false)
+        .expect("encoding should succeed");
+    assert_eq!(common::get_gobject_refcount(obj_ptr), initial_ref + 1);
+
+    let FfiValue::Storage(storage) = &encoded else {
+        panic!("Expected storage");
+    };
+    unsafe { glib::ffi::g_hash_table_unref(storage.ptr() as *mut glib::ffi::GHashTable) };
+
+    assert_eq!(common::get_gobject_refcount(obj_ptr), initial_ref);
+}
+
+#[test]
+fn hashtable_encode_rejects_mismatched_entries() {
+    common::ensure_gtk_init();
+
+    let ht_type = HashTableType {
+        key_type: Box::new(Type::String(StringType {
+            ownership: Ownership::Full,
+            length: None,
+            utf8: Utf8Policy::Strict,
+            gstring: false,
+        })),
+        value_type: Box::new(Type::Boolean(BooleanType)),
+        ownership: Ownership::Borrowed,
+    };
+    let input = Value::Array(vec![
+        Value::Array(vec![Value::String("a".to_owned()), Value::Boolean(true)]),
+        Value::Array(vec![Value::String("b".to_owned()), Value::Number(1.0)]),
+    ]);
+
+    let err = ht_type
+        .encode(&input, false)
+        .expect_err("encoding should fail");
+
+    assert!(err.to_string().contains("Expected boolean in GHashTable"));
+}
@@ -0,0 +1,43 @@
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use gtk4::glib;
+use native::dispatch::Mailbox;
+use native::module::init::{attach_to_host_loop, default_context_is_owned_elsewhere};
+
+#[test]
+fn attach_serves_the_mailbox_from_the_host_loop() {
+    assert!(!default_context_is_owned_elsewhere());
+
+    let (ready_tx, ready_rx) = mpsc::channel();
+    let host = thread::spawn(move || {
+        let main_loop = glib::MainLoop::new(None, false);
+        let host_loop = main_loop.clone();
+        glib::idle_add_once(move || {
+            ready_tx
+                .send((thread::current().id(), host_loop))
+                .expect("test thread stopped waiting");
+        });
+        main_loop.run();
+    });
+    let (host_thread, host_loop) = ready_rx
+        .recv_timeout(Duration::from_secs(5))
+        .expect("host loop did not start");
+
+    assert!(default_context_is_owned_elsewhere());
+    let handle = attach_to_host_loop().expect("attach failed");
+    assert_ne!(handle.ptr_as_usize(), 0);
+
+    let (tx, rx) = mpsc::channel();
+    Mailbox::global().schedule_glib(move || {
+        let _ = tx.send(thread::current().id());
+    });
+    let task_thread = rx
+        .recv_timeout(Duration::from_secs(5))
+        .expect("mailbox task did not run");
+    assert_eq!(task_thread, host_thread);
+
+    host_loop.quit();
+    host.join().expect("host thread panicked");
+}
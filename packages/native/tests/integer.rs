@@ -1,5 +1,6 @@
 use native::ffi;
 use native::types::IntegerKind;
+use native::value::Value;
 
 #[test]
 fn read_u8() {
@@ -220,3 +221,31 @@ fn vec_to_f64_wrong_type_fails() {
     let result = IntegerKind::U8.vec_to_f64(&storage);
     assert!(result.is_err());
 }
+
+#[test]
+fn read_wide_u64_is_exact() {
+    let value: u64 = u64::MAX;
+    let ptr = &value as *const u64 as *const u8;
+    let result = IntegerKind::U64.read_wide_ptr(ptr);
+    assert_eq!(result, i128::from(u64::MAX));
+}
+
+#[test]
+fn write_wide_i64_is_exact() {
+    let mut value: i64 = 0;
+    let ptr = &mut value as *mut i64 as *mut u8;
+    IntegerKind::I64.write_wide_ptr(ptr, i128::from(i64::MIN) + 1);
+    assert_eq!(value, i64::MIN + 1);
+}
+
+#[test]
+fn wide_to_value_keeps_safe_integers_as_numbers() {
+    let result = IntegerKind::wide_to_value(-(1 << 53));
+    assert!(matches!(result, Value::Number(n) if n == -9_007_199_254_740_992.0));
+}
+
+#[test]
+fn wide_to_value_uses_bigint_beyond_safe_integers() {
+    let result = IntegerKind::wide_to_value((1 << 53) + 1);
+    assert!(matches!(result, Value::BigInt(n) if n == (1 << 53) + 1));
+}
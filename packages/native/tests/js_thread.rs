@@ -0,0 +1,24 @@
+//! Claiming the JS thread is permanent for the process, so this lives in its
+//! own test binary rather than alongside the other state tests.
+
+use native::state::JsThread;
+
+#[test]
+fn js_thread_rejects_foreign_threads_after_claim() {
+    let js_thread = JsThread::global();
+    assert!(js_thread.claim());
+    assert!(js_thread.is_current());
+
+    let from_worker = std::thread::spawn(move || {
+        let js_thread = JsThread::global();
+        (
+            js_thread.claim(),
+            js_thread.is_current(),
+            js_thread.ensure_current("stop").is_err(),
+        )
+    })
+    .join()
+    .expect("worker thread panicked");
+
+    assert_eq!(from_worker, (false, false, true));
+}
@@ -1,17 +1,43 @@
 import { describe, expect, it } from "vitest";
-import { call } from "../../../index.js";
+import { call, type Type } from "../../../index.js";
 import {
     createLabel,
+    createRef,
     forceGC,
     GOBJECT_BORROWED,
+    GOBJECT_LIB,
     GTK_LIB,
     getRefCount,
     STRING,
     STRING_ARRAY,
     startMemoryMeasurement,
+    UINT8,
+    UINT32,
+    UINT64,
     VOID,
 } from "../utils.js";
 
+const GLIB_LIB = "libglib-2.0.so.0";
+
+const sizedBytes = (ownership: "full" | "borrowed"): Type => ({
+    type: "array",
+    itemType: UINT8,
+    kind: "sized",
+    sizeParamIndex: 1,
+    ownership,
+});
+
+const base64Encode = (data: unknown, length: number): string =>
+    call(
+        GLIB_LIB,
+        "g_base64_encode",
+        [
+            { type: sizedBytes("borrowed"), value: data },
+            { type: UINT64, value: length },
+        ],
+        STRING,
+    ) as string;
+
 describe("call - array types", () => {
     describe("string arrays", () => {
         it("passes string array argument", () => {
@@ -216,6 +242,95 @@ describe("call - array types", () => {
         });
     });
 
+    describe("byte arrays", () => {
+        it("passes a Buffer as a sized byte array", () => {
+            expect(base64Encode(Buffer.from("hello"), 5)).toBe("aGVsbG8=");
+        });
+
+        it("passes a Uint8Array as a sized byte array", () => {
+            expect(base64Encode(new Uint8Array([0, 128, 255]), 3)).toBe("AID/");
+        });
+
+        it("passes an ArrayBuffer as a sized byte array", () => {
+            expect(base64Encode(new Uint8Array([104, 105]).buffer, 2)).toBe("aGk=");
+        });
+
+        it("does not pass other typed arrays as bytes", () => {
+            expect(() => base64Encode(new Float32Array([1, 2]), 8)).toThrow("Expected an Array");
+            expect(() => base64Encode(new Int32Array([1, 2]), 8)).toThrow("Expected an Array");
+        });
+
+        it("still accepts an array of numbers", () => {
+            expect(base64Encode([104, 105], 2)).toBe("aGk=");
+        });
+
+        it("returns a sized byte array as a Buffer", () => {
+            const length = createRef(0);
+
+            const result = call(
+                GLIB_LIB,
+                "g_base64_decode",
+                [
+                    { type: STRING, value: "AID/aGVsbG8=" },
+                    { type: { type: "ref", innerType: UINT64 }, value: length },
+                ],
+                sizedBytes("full"),
+            );
+
+            expect(Buffer.isBuffer(result)).toBe(true);
+            expect([...(result as Buffer)]).toEqual([0, 128, 255, 104, 101, 108, 108, 111]);
+            expect(length.value).toBe(8);
+        });
+
+        it("passes large buffers intact", () => {
+            const data = Buffer.alloc(1 << 20, 0xab);
+
+            const encoded = base64Encode(data, data.length);
+
+            expect(Buffer.from(encoded, "base64").equals(data)).toBe(true);
+        });
+    });
+
+    describe("value arrays", () => {
+        const valueArray = (ownership: "full" | "borrowed"): Type => ({
+            type: "boxed",
+            ownership,
+            innerType: "GValueArray",
+            library: GOBJECT_LIB,
+            getTypeFn: "g_value_array_get_type",
+        });
+
+        const appendValue = (array: unknown, value: unknown): unknown[] =>
+            call(
+                GOBJECT_LIB,
+                "g_value_array_append",
+                [
+                    { type: valueArray("borrowed"), value: array },
+                    { type: { type: "gvalue", ownership: "borrowed" }, value },
+                ],
+                { type: "array", itemType: VOID, kind: "gvaluearray", ownership: "borrowed" },
+            ) as unknown[];
+
+        it("shares the handle of objects converted by their runtime type", () => {
+            const label = createLabel("Test");
+            const array = call(GOBJECT_LIB, "g_value_array_new", [{ type: UINT32, value: 1 }], valueArray("full"));
+
+            const items = appendValue(array, label);
+
+            expect(items).toHaveLength(1);
+            expect(items[0]).toBe(label);
+        });
+
+        it("decodes 64-bit integers beyond the safe range as BigInts", () => {
+            const array = call(GOBJECT_LIB, "g_value_array_new", [{ type: UINT32, value: 2 }], valueArray("full"));
+
+            appendValue(array, 2n ** 60n + 1n);
+            const items = appendValue(array, 5n);
+
+            expect(items).toEqual([2n ** 60n + 1n, 5]);
+        });
+    });
+
     describe("ownership", () => {
         it("handles owned arrays (caller frees)", () => {
             const label = createLabel("Test");
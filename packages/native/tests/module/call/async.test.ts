@@ -0,0 +1,234 @@
+import { describe, expect, it } from "vitest";
+import { alloc, call, callAsync, createRef, write } from "../../../index.js";
+import {
+    BOOLEAN,
+    createLabel,
+    FLOAT32,
+    forceGC,
+    GDK_LIB,
+    GIO_LIB,
+    GOBJECT,
+    GOBJECT_BORROWED,
+    GTK_LIB,
+    INT32,
+    STRING,
+    STRING_ARRAY,
+    STRING_BORROWED,
+    UINT64,
+    VOID,
+} from "../utils.js";
+
+const GLIB_LIB = "libglib-2.0.so.0";
+
+const RGBA_BOXED_NONE = { type: "boxed" as const, innerType: "GdkRGBA", lib: GDK_LIB, ownership: "borrowed" as const };
+
+const sleepOnGlibThread = (microseconds: number) =>
+    callAsync(GLIB_LIB, "g_usleep", [{ type: UINT64, value: microseconds }], VOID);
+
+const createWhite = () => {
+    const rgba = alloc(16, "GdkRGBA", GDK_LIB);
+    for (const offset of [0, 4, 8, 12]) write(rgba, FLOAT32, offset, 1);
+    return rgba;
+};
+
+describe("callAsync", () => {
+    it("resolves with the return value", async () => {
+        const label = await callAsync(GTK_LIB, "gtk_label_new", [{ type: STRING, value: "Async" }], GOBJECT);
+
+        const text = call(GTK_LIB, "gtk_label_get_text", [{ type: GOBJECT_BORROWED, value: label }], STRING_BORROWED);
+        expect(text).toBe("Async");
+    });
+
+    it("keeps string arguments alive until the call completes", async () => {
+        const label = createLabel("Before");
+
+        const pending = callAsync(
+            GTK_LIB,
+            "gtk_label_set_text",
+            [
+                { type: GOBJECT_BORROWED, value: label },
+                { type: STRING, value: `After ${"x".repeat(4096)}` },
+            ],
+            VOID,
+        );
+        await pending;
+
+        const text = call(GTK_LIB, "gtk_label_get_text", [{ type: GOBJECT_BORROWED, value: label }], STRING_BORROWED);
+        expect(text).toBe(`After ${"x".repeat(4096)}`);
+    });
+
+    it("keeps array arguments alive until the call completes", async () => {
+        const label = createLabel("Test");
+        const classes = Array.from({ length: 64 }, (_, i) => `class-${i}`);
+
+        const pending = callAsync(
+            GTK_LIB,
+            "gtk_widget_set_css_classes",
+            [
+                { type: GOBJECT_BORROWED, value: label },
+                { type: STRING_ARRAY, value: [...classes] },
+            ],
+            VOID,
+        );
+        await pending;
+
+        const result = call(GTK_LIB, "gtk_widget_get_css_classes", [{ type: GOBJECT_BORROWED, value: label }], STRING_ARRAY);
+        expect(result).toEqual(classes);
+    });
+
+    it("keeps object arguments alive after the caller drops them", async () => {
+        const sleeping = sleepOnGlibThread(100_000);
+        const pending = callAsync(
+            GTK_LIB,
+            "gtk_label_get_text",
+            [{ type: GOBJECT_BORROWED, value: createLabel("Dropped") }],
+            STRING_BORROWED,
+        );
+        forceGC();
+        await sleeping;
+
+        await expect(pending).resolves.toBe("Dropped");
+    });
+
+    it("keeps boxed arguments alive after the caller drops them", async () => {
+        const sleeping = sleepOnGlibThread(100_000);
+        const pending = callAsync(
+            GDK_LIB,
+            "gdk_rgba_to_string",
+            [{ type: RGBA_BOXED_NONE, value: createWhite() }],
+            STRING,
+        );
+        forceGC();
+        await sleeping;
+
+        await expect(pending).resolves.toBe("rgb(255,255,255)");
+    });
+
+    it("runs concurrent calls in submission order", async () => {
+        const label = createLabel("Start");
+
+        await Promise.all(
+            ["one", "two", "three"].map((text) =>
+                callAsync(
+                    GTK_LIB,
+                    "gtk_label_set_text",
+                    [
+                        { type: GOBJECT_BORROWED, value: label },
+                        { type: STRING, value: text },
+                    ],
+                    VOID,
+                ),
+            ),
+        );
+
+        const text = call(GTK_LIB, "gtk_label_get_text", [{ type: GOBJECT_BORROWED, value: label }], STRING_BORROWED);
+        expect(text).toBe("three");
+    });
+
+    it("updates ref out-parameters before resolving", async () => {
+        const label = createLabel("Test");
+        const width = createRef(0);
+        const height = createRef(0);
+
+        await callAsync(
+            GTK_LIB,
+            "gtk_widget_get_size_request",
+            [
+                { type: GOBJECT_BORROWED, value: label },
+                { type: { type: "ref", innerType: INT32 }, value: width },
+                { type: { type: "ref", innerType: INT32 }, value: height },
+            ],
+            VOID,
+        );
+
+        expect(width.value).toBe(-1);
+        expect(height.value).toBe(-1);
+    });
+
+    it("rejects when the symbol does not exist", async () => {
+        await expect(callAsync(GTK_LIB, "gtk_does_not_exist", [], VOID)).rejects.toThrow();
+    });
+
+    describe("cancellation", () => {
+        const GERROR = { type: "gerror" as const };
+        const STRING_ARRAY_BORROWED = { ...STRING_ARRAY, itemType: STRING_BORROWED, ownership: "borrowed" as const };
+
+        const getText = (label: unknown) =>
+            call(GTK_LIB, "gtk_label_get_text", [{ type: GOBJECT_BORROWED, value: label }], STRING_BORROWED);
+
+        it("rejects without calling when already aborted", async () => {
+            const label = createLabel("Before");
+            const controller = new AbortController();
+            controller.abort(new Error("aborted"));
+
+            const pending = callAsync(
+                GTK_LIB,
+                "gtk_label_set_text",
+                [
+                    { type: GOBJECT_BORROWED, value: label },
+                    { type: STRING, value: "After" },
+                ],
+                VOID,
+                { signal: controller.signal },
+            );
+
+            await expect(pending).rejects.toThrow("aborted");
+            expect(getText(label)).toBe("Before");
+        });
+
+        it("skips a queued call when aborted", async () => {
+            const label = createLabel("Before");
+            const controller = new AbortController();
+
+            const sleeping = sleepOnGlibThread(100_000);
+            const pending = callAsync(
+                GTK_LIB,
+                "gtk_label_set_text",
+                [
+                    { type: GOBJECT_BORROWED, value: label },
+                    { type: STRING, value: "After" },
+                ],
+                VOID,
+                { signal: controller.signal },
+            );
+            controller.abort(new Error("aborted"));
+            await sleeping;
+
+            await expect(pending).rejects.toThrow("aborted");
+            await sleepOnGlibThread(0);
+            expect(getText(label)).toBe("Before");
+        });
+
+        it("cancels the GCancellable of a running call", async () => {
+            const subprocess = call(
+                GIO_LIB,
+                "g_subprocess_newv",
+                [
+                    { type: STRING_ARRAY_BORROWED, value: ["sleep", "10"] },
+                    { type: INT32, value: 0 },
+                    { type: GERROR, value: null },
+                ],
+                GOBJECT,
+            );
+            const controller = new AbortController();
+
+            const pending = callAsync(
+                GIO_LIB,
+                "g_subprocess_wait",
+                [
+                    { type: GOBJECT_BORROWED, value: subprocess },
+                    { type: GOBJECT_BORROWED, value: null, optional: true },
+                    { type: GERROR, value: null },
+                ],
+                BOOLEAN,
+                { signal: controller.signal, cancellableArg: 1 },
+            );
+            await new Promise((resolve) => setTimeout(resolve, 50));
+            controller.abort(new Error("aborted"));
+
+            await expect(pending).rejects.toThrow("aborted");
+            await expect(sleepOnGlibThread(0)).resolves.toBeUndefined();
+            call(GIO_LIB, "g_subprocess_force_exit", [{ type: GOBJECT_BORROWED, value: subprocess }], VOID);
+        });
+    });
+});
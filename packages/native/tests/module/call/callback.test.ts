@@ -1,21 +1,35 @@
-import { describe, expect, it } from "vitest";
-import { call } from "../../../index.js";
+import { describe, expect, it, vi } from "vitest";
+import {
+    call,
+    callAsync,
+    copy,
+    isClosureInvalidated,
+    type NativeHandle,
+    read,
+    withCallbackPriority,
+} from "../../../index.js";
 import { suppressUnhandledRejections } from "../lifecycle.js";
 import {
     BOOLEAN,
     connectSignal,
     createButton,
     createCancellable,
+    createLabel,
     disconnectSignal,
     forceGC,
     GIO_LIB,
+    FLOAT64,
+    GOBJECT,
     GOBJECT_BORROWED,
     GOBJECT_LIB,
+    GTK_LIB,
     getRefCount,
     INT32,
     POINTER,
     STRING,
+    STRING_BORROWED,
     startMemoryMeasurement,
+    UINT32,
     UINT64,
     VOID,
 } from "../utils.js";
@@ -197,6 +211,112 @@ describe("call - callback types", () => {
             expect(count1).toBe(1);
             expect(count2).toBe(1);
         });
+
+        it("marks a watched closure invalidated when its handler is disconnected", async () => {
+            const cancellable = createCancellable();
+            const callback = () => {};
+
+            const handlerId = call(
+                GOBJECT_LIB,
+                "g_signal_connect_closure",
+                [
+                    { type: GOBJECT_BORROWED, value: cancellable },
+                    { type: STRING, value: "cancelled" },
+                    {
+                        type: {
+                            type: "callback",
+                            kind: "closure",
+                            argTypes: [{ type: "gobject", ownership: "borrowed" }],
+                            returnType: { type: "void" },
+                            watchArg: 0,
+                        },
+                        value: callback,
+                    },
+                    { type: BOOLEAN, value: false },
+                ],
+                UINT64,
+            ) as number;
+
+            expect(isClosureInvalidated(callback)).toBe(false);
+
+            disconnectSignal(cancellable, handlerId);
+
+            await vi.waitFor(() => expect(isClosureInvalidated(callback)).toBe(true));
+        });
+
+        it("marks a watched closure invalidated when the watched object is finalized", async () => {
+            let cancellable: unknown = createCancellable();
+            const callback = () => {};
+
+            call(
+                GOBJECT_LIB,
+                "g_signal_connect_closure",
+                [
+                    { type: GOBJECT_BORROWED, value: cancellable },
+                    { type: STRING, value: "cancelled" },
+                    {
+                        type: {
+                            type: "callback",
+                            kind: "closure",
+                            argTypes: [{ type: "gobject", ownership: "borrowed" }],
+                            returnType: { type: "void" },
+                            watchArg: 0,
+                        },
+                        value: callback,
+                    },
+                    { type: BOOLEAN, value: false },
+                ],
+                UINT64,
+            );
+            cancellable = null;
+
+            await vi.waitFor(() => {
+                forceGC();
+                expect(isClosureInvalidated(callback)).toBe(true);
+            });
+        });
+
+        it("passes the instance last and converts the other arguments by their own types when swapped", () => {
+            const itemType = call(GTK_LIB, "gtk_string_object_get_type", [], UINT64);
+            const store = call(GIO_LIB, "g_list_store_new", [{ type: UINT64, value: itemType }], GOBJECT);
+            const item = call(GTK_LIB, "gtk_string_object_new", [{ type: STRING_BORROWED, value: "a" }], GOBJECT);
+            let received: unknown[] = [];
+
+            call(
+                GOBJECT_LIB,
+                "g_signal_connect_closure",
+                [
+                    { type: GOBJECT_BORROWED, value: store },
+                    { type: STRING, value: "items-changed" },
+                    {
+                        type: {
+                            type: "callback",
+                            kind: "closure",
+                            argTypes: [GOBJECT_BORROWED, UINT32, UINT32, UINT32],
+                            returnType: VOID,
+                            swapped: true,
+                        },
+                        value: (...args: unknown[]) => {
+                            received = args;
+                        },
+                    },
+                    { type: BOOLEAN, value: false },
+                ],
+                UINT64,
+            );
+            call(
+                GIO_LIB,
+                "g_list_store_append",
+                [
+                    { type: GOBJECT_BORROWED, value: store },
+                    { type: GOBJECT_BORROWED, value: item },
+                ],
+                VOID,
+            );
+
+            expect(received.slice(0, 3)).toEqual([0, 0, 1]);
+            expect((received[3] as NativeHandle).id).toBe((store as NativeHandle).id);
+        });
     });
 
     describe("destroy trampoline", () => {
@@ -269,6 +389,552 @@ describe("call - callback types", () => {
         });
     });
 
+    describe("fast argument path", () => {
+        const FLOAT64 = { type: "float64" as const };
+
+        const createAdjustment = (): unknown =>
+            call(
+                GTK_LIB,
+                "gtk_adjustment_new",
+                [0, 0, 100, 1, 10, 0].map((value) => ({ type: FLOAT64, value })),
+                { type: "gobject", ownership: "full" },
+            );
+
+        const connectValueChanged = (adjustment: unknown, callbackType: object, value: unknown) =>
+            call(
+                GOBJECT_LIB,
+                "g_signal_connect_closure",
+                [
+                    { type: GOBJECT_BORROWED, value: adjustment },
+                    { type: STRING, value: "value-changed" },
+                    { type: callbackType as never, value },
+                    { type: BOOLEAN, value: false },
+                ],
+                UINT64,
+            );
+
+        const setValue = (adjustment: unknown, value: number) =>
+            call(
+                GTK_LIB,
+                "gtk_adjustment_set_value",
+                [
+                    { type: GOBJECT_BORROWED, value: adjustment },
+                    { type: FLOAT64, value },
+                ],
+                VOID,
+            );
+
+        it("delivers every emission with its arguments", () => {
+            const adjustment = createAdjustment();
+            const handler = vi.fn();
+
+            connectValueChanged(
+                adjustment,
+                {
+                    type: "callback",
+                    kind: "closure",
+                    argTypes: [GOBJECT_BORROWED],
+                    returnType: VOID,
+                    fastArgs: true,
+                },
+                handler,
+            );
+
+            for (let i = 1; i <= 50; i++) setValue(adjustment, i);
+
+            expect(handler).toHaveBeenCalledTimes(50);
+            expect(handler.mock.calls.every(([arg]) => arg !== null && arg !== undefined)).toBe(true);
+        });
+
+        it("does not read a value of another type as the declared type", async () => {
+            const label = createLabel("Test");
+            const handler = vi.fn();
+            call(
+                GOBJECT_LIB,
+                "g_signal_connect_closure",
+                [
+                    { type: GOBJECT_BORROWED, value: label },
+                    { type: STRING, value: "direction-changed" },
+                    {
+                        type: {
+                            type: "callback",
+                            kind: "closure",
+                            argTypes: [GOBJECT_BORROWED, INT32],
+                            returnType: VOID,
+                            fastArgs: true,
+                        } as never,
+                        value: handler,
+                    },
+                    { type: BOOLEAN, value: false },
+                ],
+                UINT64,
+            );
+
+            await suppressUnhandledRejections(() => {
+                call(
+                    GTK_LIB,
+                    "gtk_widget_set_direction",
+                    [
+                        { type: GOBJECT_BORROWED, value: label },
+                        { type: INT32, value: 2 },
+                    ],
+                    VOID,
+                );
+            });
+
+            expect(handler).not.toHaveBeenCalled();
+        });
+
+        it("rejects argument types it cannot read directly", () => {
+            const adjustment = createAdjustment();
+
+            expect(() =>
+                connectValueChanged(
+                    adjustment,
+                    {
+                        type: "callback",
+                        kind: "closure",
+                        argTypes: [STRING],
+                        returnType: VOID,
+                        fastArgs: true,
+                    },
+                    () => {},
+                ),
+            ).toThrow("'fastArgs' supports");
+        });
+
+        it("rejects non-void return types", () => {
+            const adjustment = createAdjustment();
+
+            expect(() =>
+                connectValueChanged(
+                    adjustment,
+                    {
+                        type: "callback",
+                        kind: "closure",
+                        argTypes: [GOBJECT_BORROWED],
+                        returnType: BOOLEAN,
+                        fastArgs: true,
+                    },
+                    () => true,
+                ),
+            ).toThrow("'fastArgs' requires a void return type");
+        });
+    });
+
+    describe("priority lanes", () => {
+        const connectClicked = (button: unknown, callbackType: object, value: unknown) =>
+            call(
+                GOBJECT_LIB,
+                "g_signal_connect_closure",
+                [
+                    { type: GOBJECT_BORROWED, value: button },
+                    { type: STRING, value: "clicked" },
+                    { type: callbackType as never, value },
+                    { type: BOOLEAN, value: false },
+                ],
+                UINT64,
+            );
+
+        const click = (button: unknown) =>
+            call(GTK_LIB, "gtk_button_clicked", [{ type: GOBJECT_BORROWED, value: button }], VOID);
+
+        it("delivers callbacks queued in the high lane", () => {
+            const button = createButton();
+            const handler = vi.fn();
+
+            connectClicked(
+                button,
+                { type: "callback", kind: "closure", argTypes: [GOBJECT_BORROWED], returnType: VOID, priority: "high" },
+                handler,
+            );
+            click(button);
+
+            expect(handler).toHaveBeenCalledOnce();
+        });
+
+        it("applies a scoped priority to callbacks without one", () => {
+            const button = createButton();
+            const handler = vi.fn();
+
+            withCallbackPriority("low", () =>
+                connectClicked(
+                    button,
+                    { type: "callback", kind: "closure", argTypes: [GOBJECT_BORROWED], returnType: VOID },
+                    handler,
+                ),
+            );
+            click(button);
+
+            expect(handler).toHaveBeenCalledOnce();
+        });
+
+        it("drains the high lane before lower ones once the JS thread is free", async () => {
+            const delivered: string[] = [];
+            const buttons = (["low", "default", "high"] as const).map((priority) => {
+                const button = createButton();
+                connectClicked(
+                    button,
+                    {
+                        type: "callback",
+                        kind: "closure",
+                        argTypes: [GOBJECT_BORROWED],
+                        returnType: VOID,
+                        coalesce: true,
+                        priority,
+                    },
+                    () => delivered.push(priority),
+                );
+                return button;
+            });
+
+            const clicks = buttons.map((button) =>
+                callAsync(GTK_LIB, "gtk_button_clicked", [{ type: GOBJECT_BORROWED, value: button }], VOID),
+            );
+            Atomics.wait(new Int32Array(new SharedArrayBuffer(4)), 0, 0, 200);
+            expect(delivered).toEqual([]);
+            await Promise.all(clicks);
+            await vi.waitFor(() => expect(delivered).toHaveLength(3));
+
+            expect(delivered).toEqual(["high", "default", "low"]);
+        });
+
+        it("rejects unknown priorities", () => {
+            const button = createButton();
+
+            expect(() =>
+                connectClicked(
+                    button,
+                    {
+                        type: "callback",
+                        kind: "closure",
+                        argTypes: [GOBJECT_BORROWED],
+                        returnType: VOID,
+                        priority: "urgent",
+                    },
+                    () => {},
+                ),
+            ).toThrow("Invalid priority 'urgent'");
+        });
+    });
+
+    describe("scoped borrowed arguments", () => {
+        const TEXT_ITER = {
+            type: "boxed" as const,
+            innerType: "GtkTextIter",
+            lib: GTK_LIB,
+            ownership: "borrowed" as const,
+        };
+
+        const insertWithHandler = (handler: (iter: NativeHandle) => void) => {
+            const buffer = call(GTK_LIB, "gtk_text_buffer_new", [{ type: POINTER, value: 0 }], {
+                type: "gobject",
+                ownership: "full",
+            });
+            call(
+                GOBJECT_LIB,
+                "g_signal_connect_closure",
+                [
+                    { type: GOBJECT_BORROWED, value: buffer },
+                    { type: STRING, value: "insert-text" },
+                    {
+                        type: {
+                            type: "callback",
+                            kind: "closure",
+                            argTypes: [GOBJECT_BORROWED, TEXT_ITER, STRING_BORROWED, INT32],
+                            returnType: VOID,
+                        },
+                        value: (_buffer: unknown, iter: NativeHandle) => handler(iter),
+                    },
+                    { type: BOOLEAN, value: false },
+                ],
+                UINT64,
+            );
+            call(
+                GTK_LIB,
+                "gtk_text_buffer_insert_at_cursor",
+                [
+                    { type: GOBJECT_BORROWED, value: buffer },
+                    { type: STRING, value: "hi" },
+                    { type: INT32, value: -1 },
+                ],
+                VOID,
+            );
+        };
+
+        it("invalidates borrowed arguments once the callback returns", () => {
+            let retained: NativeHandle | undefined;
+            let readInside: unknown;
+
+            insertWithHandler((iter) => {
+                retained = iter;
+                readInside = read(iter, INT32, 0);
+            });
+
+            expect(readInside).toBeTypeOf("number");
+            expect(() => read(retained as NativeHandle, INT32, 0)).toThrow("no longer valid");
+        });
+
+        it("keeps copies taken inside the callback usable", () => {
+            let copied: NativeHandle | undefined;
+
+            insertWithHandler((iter) => {
+                copied = copy(iter, "GtkTextIter");
+            });
+
+            expect(() => read(copied as NativeHandle, INT32, 0)).not.toThrow();
+        });
+    });
+
+    const stringOf = (item: unknown): string =>
+        call(
+            GTK_LIB,
+            "gtk_string_object_get_string",
+            [{ type: GOBJECT_BORROWED, value: item }],
+            STRING_BORROWED,
+        ) as string;
+
+    describe("compare trampoline", () => {
+        const COMPARE = {
+            type: "trampoline" as const,
+            argTypes: [GOBJECT_BORROWED, GOBJECT_BORROWED, UINT64],
+            returnType: INT32,
+            userDataIndex: 2,
+            kind: "compare" as const,
+        };
+
+        const createStore = (values: string[]): unknown => {
+            const itemType = call(GTK_LIB, "gtk_string_object_get_type", [], UINT64);
+            const store = call(GIO_LIB, "g_list_store_new", [{ type: UINT64, value: itemType }], GOBJECT);
+            for (const value of values) {
+                const item = call(GTK_LIB, "gtk_string_object_new", [{ type: STRING_BORROWED, value }], GOBJECT);
+                call(
+                    GIO_LIB,
+                    "g_list_store_append",
+                    [
+                        { type: GOBJECT_BORROWED, value: store },
+                        { type: GOBJECT_BORROWED, value: item },
+                    ],
+                    VOID,
+                );
+            }
+            return store;
+        };
+
+        const valuesOf = (store: unknown): string[] => {
+            const count = call(GIO_LIB, "g_list_model_get_n_items", [{ type: GOBJECT_BORROWED, value: store }], UINT32);
+            return Array.from({ length: count as number }, (_, i) =>
+                stringOf(
+                    call(
+                        GIO_LIB,
+                        "g_list_model_get_item",
+                        [
+                            { type: GOBJECT_BORROWED, value: store },
+                            { type: UINT32, value: i },
+                        ],
+                        GOBJECT,
+                    ),
+                ),
+            );
+        };
+
+        it("sorts by the sign of fractional comparator results", () => {
+            const store = createStore(["0.5", "0.25", "0.75"]);
+
+            call(
+                GIO_LIB,
+                "g_list_store_sort",
+                [
+                    { type: GOBJECT_BORROWED, value: store },
+                    {
+                        type: COMPARE,
+                        value: (a: unknown, b: unknown) => Number(stringOf(a)) - Number(stringOf(b)),
+                    },
+                ],
+                VOID,
+            );
+
+            expect(valuesOf(store)).toEqual(["0.25", "0.5", "0.75"]);
+        });
+
+        it("rejects a non-integer return type", () => {
+            const store = createStore(["a"]);
+
+            expect(() =>
+                call(
+                    GIO_LIB,
+                    "g_list_store_sort",
+                    [
+                        { type: GOBJECT_BORROWED, value: store },
+                        { type: { ...COMPARE, returnType: FLOAT64 }, value: () => 0 },
+                    ],
+                    VOID,
+                ),
+            ).toThrow("Compare trampolines must return an integer");
+        });
+    });
+
+    describe("filter trampoline", () => {
+        const FILTER = {
+            type: "trampoline" as const,
+            argTypes: [GOBJECT_BORROWED, UINT64],
+            returnType: BOOLEAN,
+            hasDestroy: true,
+            userDataIndex: 1,
+            kind: "filter" as const,
+        };
+
+        const createFilter = (predicate: (item: unknown) => unknown): unknown =>
+            call(GTK_LIB, "gtk_custom_filter_new", [{ type: FILTER, value: predicate }], GOBJECT);
+
+        const matches = (filter: unknown, value: string): boolean => {
+            const item = call(GTK_LIB, "gtk_string_object_new", [{ type: STRING_BORROWED, value }], GOBJECT);
+            return call(
+                GTK_LIB,
+                "gtk_filter_match",
+                [
+                    { type: GOBJECT_BORROWED, value: filter },
+                    { type: GOBJECT_BORROWED, value: item },
+                ],
+                BOOLEAN,
+            ) as boolean;
+        };
+
+        it("matches items by the truthiness of the predicate result", () => {
+            const filter = createFilter((item) => stringOf(item));
+
+            expect(matches(filter, "kept")).toBe(true);
+            expect(matches(filter, "")).toBe(false);
+        });
+
+        it("treats numbers and null like JS conditions do", () => {
+            const filter = createFilter((item) => (stringOf(item) === "null" ? null : Number(stringOf(item))));
+
+            expect(matches(filter, "2")).toBe(true);
+            expect(matches(filter, "0")).toBe(false);
+            expect(matches(filter, "NaN")).toBe(false);
+            expect(matches(filter, "null")).toBe(false);
+        });
+
+        it("rejects a non-boolean return type", () => {
+            const filterType = { ...FILTER, returnType: INT32 };
+
+            expect(() =>
+                call(GTK_LIB, "gtk_custom_filter_new", [{ type: filterType, value: () => true }], GOBJECT),
+            ).toThrow("Filter trampolines must return a boolean");
+        });
+    });
+
+    describe("widget-returning trampoline", () => {
+        const stringOfLabel = (label: unknown): string =>
+            call(GTK_LIB, "gtk_label_get_label", [{ type: GOBJECT_BORROWED, value: label }], STRING_BORROWED) as string;
+
+        it("hands a new reference to the widget it returns to C", () => {
+            const box = call(GTK_LIB, "gtk_list_box_new", [], GOBJECT);
+            const model = call(GTK_LIB, "gtk_string_list_new", [{ type: POINTER, value: 0 }], GOBJECT);
+            call(
+                GTK_LIB,
+                "gtk_string_list_append",
+                [
+                    { type: GOBJECT_BORROWED, value: model },
+                    { type: STRING_BORROWED, value: "row" },
+                ],
+                VOID,
+            );
+            const createWidget = {
+                type: "trampoline" as const,
+                argTypes: [GOBJECT_BORROWED, UINT64],
+                returnType: GOBJECT,
+                hasDestroy: true,
+                userDataIndex: 1,
+            };
+
+            call(
+                GTK_LIB,
+                "gtk_list_box_bind_model",
+                [
+                    { type: GOBJECT_BORROWED, value: box },
+                    { type: GOBJECT_BORROWED, value: model },
+                    { type: createWidget, value: (item: unknown) => createLabel(stringOf(item)) },
+                ],
+                VOID,
+            );
+            forceGC();
+
+            const row = call(
+                GTK_LIB,
+                "gtk_list_box_get_row_at_index",
+                [
+                    { type: GOBJECT_BORROWED, value: box },
+                    { type: INT32, value: 0 },
+                ],
+                GOBJECT_BORROWED,
+            );
+            const label = call(
+                GTK_LIB,
+                "gtk_list_box_row_get_child",
+                [{ type: GOBJECT_BORROWED, value: row }],
+                GOBJECT_BORROWED,
+            );
+            expect(stringOfLabel(label)).toBe("row");
+        });
+    });
+
+    describe("source trampoline", () => {
+        const GLIB_LIB = "libglib-2.0.so.0";
+        const PRIORITY_DEFAULT_IDLE = 200;
+        const SOURCE = {
+            type: "trampoline" as const,
+            argTypes: [UINT64],
+            returnType: BOOLEAN,
+            hasDestroy: true,
+            userDataIndex: 0,
+            kind: "source" as const,
+        };
+
+        const addIdle = (callback: () => unknown): number =>
+            call(
+                GLIB_LIB,
+                "g_idle_add_full",
+                [
+                    { type: INT32, value: PRIORITY_DEFAULT_IDLE },
+                    { type: SOURCE, value: callback },
+                ],
+                UINT32,
+            ) as number;
+
+        const settle = () => new Promise((resolve) => setTimeout(resolve, 50));
+
+        it("runs the callback again while it returns true", async () => {
+            let runs = 0;
+            addIdle(() => ++runs < 3);
+
+            await settle();
+
+            expect(runs).toBe(3);
+        });
+
+        it("removes the source when the callback returns nothing", async () => {
+            const callback = vi.fn();
+            addIdle(callback);
+
+            await settle();
+
+            expect(callback).toHaveBeenCalledTimes(1);
+        });
+
+        it("rejects a non-boolean return type", () => {
+            const args = [
+                { type: INT32, value: PRIORITY_DEFAULT_IDLE },
+                { type: { ...SOURCE, returnType: INT32 }, value: () => true },
+            ];
+
+            expect(() => call(GLIB_LIB, "g_idle_add_full", args, UINT32)).toThrow(
+                "Source trampolines must return a boolean",
+            );
+        });
+    });
+
     describe("memory leaks", () => {
         it("does not leak closure when signal handler disconnects", () => {
             const button = createButton("Test");
@@ -294,6 +960,23 @@ describe("call - callback types", () => {
             expect(mem.measure()).toBeLessThan(5 * 1024 * 1024);
         });
 
+        it("releases handler functions when widgets are finalized", async () => {
+            const mem = startMemoryMeasurement();
+
+            for (let i = 0; i < 10_000; i++) {
+                const label = createLabel(`Label ${i}`);
+                const captured = new Array(128).fill(i);
+                connectSignal(label, "destroy", () => captured.length);
+            }
+
+            await vi.waitFor(
+                () => {
+                    expect(mem.measure()).toBeLessThan(5 * 1024 * 1024);
+                },
+                { timeout: 5000, interval: 50 },
+            );
+        });
+
         it("does not leak trampoline memory on disconnect", () => {
             const mem = startMemoryMeasurement();
 
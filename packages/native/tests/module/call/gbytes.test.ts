@@ -0,0 +1,81 @@
+import { describe, expect, it } from "vitest";
+import { call, createRef, type Type } from "../../../index.js";
+import { UINT64 } from "../utils.js";
+
+const GLIB_LIB = "libglib-2.0.so.0";
+const GBYTES_BORROWED: Type = { type: "gbytes", ownership: "borrowed" };
+const GBYTES_FULL: Type = { type: "gbytes", ownership: "full" };
+const GBYTES_FULL_VIEW: Type = { type: "gbytes", ownership: "full", view: true };
+
+const bytesSize = (data: unknown): number =>
+    call(GLIB_LIB, "g_bytes_get_size", [{ type: GBYTES_BORROWED, value: data }], UINT64) as number;
+
+const slice = (data: unknown, offset: number, length: number, returnType: Type): unknown =>
+    call(
+        GLIB_LIB,
+        "g_bytes_new_from_bytes",
+        [
+            { type: GBYTES_BORROWED, value: data },
+            { type: UINT64, value: offset },
+            { type: UINT64, value: length },
+        ],
+        returnType,
+    );
+
+describe("call - gbytes type", () => {
+    it("passes a Buffer", () => {
+        expect(bytesSize(Buffer.from("hello"))).toBe(5);
+    });
+
+    it("passes a Uint8Array", () => {
+        expect(bytesSize(new Uint8Array([1, 2, 3]))).toBe(3);
+    });
+
+    it("passes a string as UTF-8", () => {
+        expect(bytesSize("héllo")).toBe(6);
+    });
+
+    it("passes an empty Buffer", () => {
+        expect(bytesSize(Buffer.alloc(0))).toBe(0);
+    });
+
+    it("returns a copy as a Buffer", () => {
+        const result = slice(Buffer.from("hello world"), 6, 5, GBYTES_FULL);
+
+        expect(Buffer.isBuffer(result)).toBe(true);
+        expect((result as Buffer).toString()).toBe("world");
+    });
+
+    it("returns a view as a Buffer", () => {
+        const result = slice(Buffer.from("hello world"), 0, 5, GBYTES_FULL_VIEW);
+
+        expect(Buffer.isBuffer(result)).toBe(true);
+        expect((result as Buffer).toString()).toBe("hello");
+    });
+
+    it("returns an empty view as an empty Buffer", () => {
+        const result = slice(Buffer.from("hello"), 5, 0, GBYTES_FULL_VIEW);
+
+        expect((result as Buffer).length).toBe(0);
+    });
+
+    it("transfers ownership of a passed GBytes", () => {
+        const length = createRef(0);
+
+        const data = call(
+            GLIB_LIB,
+            "g_bytes_unref_to_data",
+            [
+                { type: GBYTES_FULL, value: Buffer.from([7, 8, 9]) },
+                { type: { type: "ref", innerType: UINT64 }, value: length },
+            ],
+            { type: "array", itemType: { type: "uint8" }, kind: "sized", sizeParamIndex: 1, ownership: "full" },
+        );
+
+        expect([...(data as Buffer)]).toEqual([7, 8, 9]);
+    });
+
+    it("rejects values that are not bytes", () => {
+        expect(() => bytesSize(42)).toThrow("Expected a Buffer, Uint8Array or string for gbytes type");
+    });
+});
@@ -0,0 +1,68 @@
+import { describe, expect, it } from "vitest";
+import { call, callAsync } from "../../../index.js";
+import { STRING, STRING_BORROWED, UINT32 } from "../utils.js";
+
+const GLIB_LIB = "libglib-2.0.so.0";
+const GERROR = { type: "gerror" as const };
+const CONVERT_ERROR_NOT_ABSOLUTE_PATH = 5;
+
+const filenameToUri = (filename: string) => [
+    { type: STRING_BORROWED, value: filename },
+    { type: STRING_BORROWED, value: null, optional: true },
+    { type: GERROR, value: null },
+];
+
+const convertErrorQuark = () => call(GLIB_LIB, "g_convert_error_quark", [], UINT32);
+
+describe("call - gerror type", () => {
+    it("returns normally when no error is set", () => {
+        const uri = call(GLIB_LIB, "g_filename_to_uri", filenameToUri("/tmp"), STRING);
+
+        expect(uri).toBe("file:///tmp");
+    });
+
+    it("throws the reported error with its domain, code and message", () => {
+        let thrown: unknown;
+        try {
+            call(GLIB_LIB, "g_filename_to_uri", filenameToUri("relative"), STRING);
+        } catch (error) {
+            thrown = error;
+        }
+
+        expect(thrown).toBeInstanceOf(Error);
+        expect(thrown).toMatchObject({
+            domain: convertErrorQuark(),
+            code: CONVERT_ERROR_NOT_ABSOLUTE_PATH,
+            message: expect.stringContaining("relative"),
+        });
+    });
+
+    it("accepts an undefined value", () => {
+        const args = [
+            { type: STRING_BORROWED, value: "/tmp" },
+            { type: STRING_BORROWED, value: null, optional: true },
+            { type: GERROR, value: undefined },
+        ];
+
+        expect(call(GLIB_LIB, "g_filename_to_uri", args, STRING)).toBe("file:///tmp");
+    });
+
+    it("rejects a value other than null or undefined", () => {
+        const args = [
+            { type: STRING_BORROWED, value: "/tmp" },
+            { type: STRING_BORROWED, value: null, optional: true },
+            { type: GERROR, value: 1 },
+        ];
+
+        expect(() => call(GLIB_LIB, "g_filename_to_uri", args, STRING)).toThrow("gerror");
+    });
+
+    it("rejects an async call with the reported error", async () => {
+        const result = callAsync(GLIB_LIB, "g_filename_to_uri", filenameToUri("relative"), STRING);
+
+        await expect(result).rejects.toMatchObject({
+            domain: convertErrorQuark(),
+            code: CONVERT_ERROR_NOT_ABSOLUTE_PATH,
+        });
+    });
+});
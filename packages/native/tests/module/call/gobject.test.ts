@@ -133,6 +133,29 @@ describe("call - gobject types", () => {
         });
     });
 
+    describe("handle identity", () => {
+        it("returns the same handle for repeated returns of one object", () => {
+            const box = createBox();
+            const label = createLabel("Child");
+
+            call(
+                GTK_LIB,
+                "gtk_box_append",
+                [
+                    { type: GOBJECT_BORROWED, value: box },
+                    { type: GOBJECT_BORROWED, value: label },
+                ],
+                VOID,
+            );
+
+            const parents = Array.from({ length: 5 }, () =>
+                call(GTK_LIB, "gtk_widget_get_parent", [{ type: GOBJECT_BORROWED, value: label }], GOBJECT_BORROWED),
+            );
+
+            expect(parents.every((parent) => parent === box)).toBe(true);
+        });
+    });
+
     describe("widget hierarchy", () => {
         it("creates parent-child relationships", () => {
             const box = createBox();
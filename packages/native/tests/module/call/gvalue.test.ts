@@ -0,0 +1,97 @@
+import { describe, expect, it } from "vitest";
+import { call, type Type } from "../../../index.js";
+import {
+    createLabel,
+    GOBJECT_BORROWED,
+    GOBJECT_LIB,
+    GTK_LIB,
+    INT32,
+    STRING,
+    STRING_BORROWED,
+    UINT64,
+    VOID,
+} from "../utils.js";
+
+const GVALUE: Type = { type: "gvalue", ownership: "borrowed" };
+
+const gvalue = (options: { valueType?: Type; typeName?: string } = {}): Type => ({ ...GVALUE, ...options });
+
+const contents = (type: Type, value: unknown): string =>
+    call(GOBJECT_LIB, "g_strdup_value_contents", [{ type, value }], STRING) as string;
+
+const getInt = (type: Type, value: unknown): number =>
+    call(GOBJECT_LIB, "g_value_get_int", [{ type, value }], INT32) as number;
+
+const labelText = (label: unknown): string =>
+    call(GTK_LIB, "gtk_label_get_text", [{ type: GOBJECT_BORROWED, value: label }], STRING_BORROWED) as string;
+
+describe("call - gvalue type", () => {
+    it("infers the type of a string", () => {
+        expect(contents(GVALUE, "hello")).toBe('"hello"');
+    });
+
+    it("builds the contents with a value type", () => {
+        expect(getInt(gvalue({ valueType: INT32 }), 42)).toBe(42);
+    });
+
+    it("converts the contents to a named type", () => {
+        expect(getInt(gvalue({ typeName: "gint" }), 7)).toBe(7);
+    });
+
+    it("stores numbers as enum values", () => {
+        call(GTK_LIB, "gtk_align_get_type", [], UINT64);
+
+        expect(contents(gvalue({ typeName: "GtkAlign" }), 3)).toBe("GTK_ALIGN_CENTER");
+    });
+
+    it("puts an object handle in a new GValue", () => {
+        const label = createLabel();
+
+        const object = call(GOBJECT_LIB, "g_value_get_object", [{ type: GVALUE, value: label }], GOBJECT_BORROWED);
+
+        expect(labelText(object)).toBe("Test");
+    });
+
+    it("sets an object property", () => {
+        const label = createLabel("before");
+
+        call(
+            GOBJECT_LIB,
+            "g_object_set_property",
+            [
+                { type: GOBJECT_BORROWED, value: label },
+                { type: STRING_BORROWED, value: "label" },
+                { type: GVALUE, value: "after" },
+            ],
+            VOID,
+        );
+
+        expect(labelText(label)).toBe("after");
+    });
+
+    it("returns a GValue as a handle that can be passed back", () => {
+        const gvalueType = call(GOBJECT_LIB, "g_value_get_type", [], UINT64) as number;
+
+        const copy = call(
+            GOBJECT_LIB,
+            "g_boxed_copy",
+            [
+                { type: UINT64, value: gvalueType },
+                { type: gvalue({ valueType: INT32 }), value: 99 },
+            ],
+            { type: "gvalue", ownership: "full" },
+        );
+
+        expect(getInt(GVALUE, copy)).toBe(99);
+    });
+
+    it("throws for contents that cannot be converted", () => {
+        expect(() => contents(gvalue({ typeName: "GtkWidget" }), "text")).toThrow(
+            "Cannot convert a gchararray value to GtkWidget for gvalue type",
+        );
+    });
+
+    it("throws for an unknown type name", () => {
+        expect(() => contents(gvalue({ typeName: "NoSuchType" }), 1)).toThrow("Unknown GType 'NoSuchType'");
+    });
+});
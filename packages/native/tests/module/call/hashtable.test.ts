@@ -0,0 +1,92 @@
+import { describe, expect, it } from "vitest";
+import { call, type Type } from "../../../index.js";
+import {
+    createLabel,
+    GOBJECT,
+    GOBJECT_BORROWED,
+    getRefCount,
+    INT32,
+    STRING,
+    STRING_BORROWED,
+    UINT32,
+    UINT64,
+    VOID,
+} from "../utils.js";
+
+const GLIB_LIB = "libglib-2.0.so.0";
+
+const hashTable = (keyType: Type, valueType: Type, ownership: "full" | "borrowed" = "borrowed"): Type => ({
+    type: "hashtable",
+    keyType,
+    valueType,
+    ownership,
+});
+
+const STRING_TO_INT = hashTable(STRING, INT32);
+const OBJECT_TO_INT = hashTable(GOBJECT_BORROWED, INT32);
+
+const size = (type: Type, entries: unknown): number =>
+    call(GLIB_LIB, "g_hash_table_size", [{ type, value: entries }], UINT32) as number;
+
+const lookup = (type: Type, entries: unknown, keyType: Type, key: unknown): number =>
+    call(
+        GLIB_LIB,
+        "g_hash_table_lookup",
+        [
+            { type, value: entries },
+            { type: keyType, value: key },
+        ],
+        UINT64,
+    ) as number;
+
+describe("call - hashtable arguments", () => {
+    it("passes string keys", () => {
+        const entries = [
+            ["one", 1],
+            ["two", 2],
+        ];
+
+        expect(size(STRING_TO_INT, entries)).toBe(2);
+        expect(lookup(STRING_TO_INT, entries, STRING_BORROWED, "two")).toBe(2);
+    });
+
+    it("keeps the last value of a repeated key", () => {
+        const entries = [
+            ["key", 1],
+            ["key", 3],
+        ];
+
+        expect(size(STRING_TO_INT, entries)).toBe(1);
+        expect(lookup(STRING_TO_INT, entries, STRING_BORROWED, "key")).toBe(3);
+    });
+
+    it("passes object keys", () => {
+        const first = createLabel("first");
+        const second = createLabel("second");
+
+        expect(lookup(OBJECT_TO_INT, [[first, 10], [second, 20]], GOBJECT_BORROWED, second)).toBe(20);
+    });
+
+    it("passes an empty table", () => {
+        expect(size(STRING_TO_INT, [])).toBe(0);
+    });
+
+    it("releases transferred object values with the table", () => {
+        const label = createLabel();
+        const before = getRefCount(label);
+
+        const type = hashTable(INT32, GOBJECT, "full");
+
+        call(GLIB_LIB, "g_hash_table_unref", [{ type, value: [[1, label]] }], VOID);
+
+        expect(getRefCount(label)).toBe(before);
+    });
+
+    it("rejects entries that are not pairs", () => {
+        expect(() => size(STRING_TO_INT, [["one"]])).toThrow("Expected [key, value] tuple in GHashTable");
+    });
+
+    it("rejects values of the wrong type", () => {
+        expect(() => size(STRING_TO_INT, [["one", "1"]])).toThrow("Expected number in GHashTable");
+    });
+});
@@ -425,6 +425,110 @@ describe("call - integer types", () => {
         });
     });
 
+    describe("64-bit bigint", () => {
+        const GLIB_LIB = "libglib-2.0.so.0";
+
+        it("returns values above 2^53 as bigint", () => {
+            const result = call(
+                GLIB_LIB,
+                "g_ascii_strtoull",
+                [
+                    { type: STRING, value: "18446744073709551615" },
+                    { type: POINTER, value: 0 },
+                    { type: UINT32, value: 10 },
+                ],
+                UINT64,
+            );
+
+            expect(result).toBe(18446744073709551615n);
+        });
+
+        it("keeps returning safe integers as numbers", () => {
+            const result = call(
+                GLIB_LIB,
+                "g_ascii_strtoll",
+                [
+                    { type: STRING, value: "-9007199254740992" },
+                    { type: POINTER, value: 0 },
+                    { type: UINT32, value: 10 },
+                ],
+                INT64,
+            );
+
+            expect(result).toBe(-9007199254740992);
+        });
+
+        it("round-trips a bigint argument exactly", () => {
+            const variant = call(GLIB_LIB, "g_variant_new_int64", [{ type: INT64, value: -(2n ** 60n) - 1n }], POINTER);
+            const value = call(GLIB_LIB, "g_variant_get_int64", [{ type: POINTER, value: variant }], INT64);
+            call(GLIB_LIB, "g_variant_unref", [{ type: POINTER, value: variant }], VOID);
+
+            expect(value).toBe(-(2n ** 60n) - 1n);
+        });
+
+        it("rejects numbers outside the safe integer range", () => {
+            expect(() =>
+                call(GLIB_LIB, "g_variant_new_uint64", [{ type: UINT64, value: 2 ** 60 }], POINTER),
+            ).toThrow("use a BigInt");
+        });
+
+        it("rejects bigints outside the integer range", () => {
+            expect(() =>
+                call(GLIB_LIB, "g_variant_new_uint64", [{ type: UINT64, value: -1n }], POINTER),
+            ).toThrow("out of range for u64");
+        });
+    });
+
+    describe("pointer-sized", () => {
+        const GLIB_LIB = "libglib-2.0.so.0";
+        const INTPTR = { type: "intptr" as const };
+        const UINTPTR = { type: "uintptr" as const };
+
+        it("returns guint64 values above 2^53 exactly as bigint", () => {
+            const result = call(
+                GLIB_LIB,
+                "g_ascii_strtoull",
+                [
+                    { type: STRING, value: "18446744073709551615" },
+                    { type: POINTER, value: 0 },
+                    { type: UINT32, value: 10 },
+                ],
+                UINTPTR,
+            );
+
+            expect(result).toBe(18446744073709551615n);
+        });
+
+        it("returns negative values as bigint", () => {
+            const result = call(
+                GLIB_LIB,
+                "g_ascii_strtoll",
+                [
+                    { type: STRING, value: "-9007199254740993" },
+                    { type: POINTER, value: 0 },
+                    { type: UINT32, value: 10 },
+                ],
+                INTPTR,
+            );
+
+            expect(result).toBe(-9007199254740993n);
+        });
+
+        it("round-trips a bigint argument exactly", () => {
+            const variant = call(GLIB_LIB, "g_variant_new_uint64", [{ type: UINTPTR, value: 2n ** 60n + 1n }], UINTPTR);
+            const value = call(GLIB_LIB, "g_variant_get_uint64", [{ type: UINTPTR, value: variant }], UINTPTR);
+            call(GLIB_LIB, "g_variant_unref", [{ type: UINTPTR, value: variant }], VOID);
+
+            expect(value).toBe(2n ** 60n + 1n);
+        });
+
+        it("rejects numbers outside the safe integer range", () => {
+            expect(() =>
+                call(GLIB_LIB, "g_variant_new_uint64", [{ type: UINTPTR, value: 2 ** 60 }], UINTPTR),
+            ).toThrow("use a BigInt");
+        });
+    });
+
     describe("edge cases", () => {
         it("handles integer as argument and return type simultaneously", () => {
             const label = createLabel("Test");
@@ -191,6 +191,106 @@ describe("call - string types", () => {
         });
     });
 
+    describe("length-aware strings", () => {
+        const GLIB_LIB = "libglib-2.0.so.0";
+        const G_CHECKSUM_MD5 = 0;
+
+        const md5 = (data: unknown, length: number): unknown =>
+            call(
+                GLIB_LIB,
+                "g_compute_checksum_for_data",
+                [
+                    { type: { type: "int32" }, value: G_CHECKSUM_MD5 },
+                    { type: { type: "string", ownership: "borrowed", length }, value: data },
+                    { type: { type: "uint64" }, value: length },
+                ],
+                STRING,
+            );
+
+        it("passes a Buffer with embedded NULs", () => {
+            expect(md5(Buffer.from([0x61, 0x00, 0x62]), 3)).toBe("70350f6027bce3713f6b76473084309b");
+        });
+
+        it("passes a string with embedded NULs", () => {
+            expect(md5("a\0b", 3)).toBe("70350f6027bce3713f6b76473084309b");
+        });
+
+        it("rejects values longer than length", () => {
+            const label = createLabel("Initial");
+
+            expect(() =>
+                call(
+                    GTK_LIB,
+                    "gtk_label_set_text",
+                    [
+                        { type: GOBJECT_BORROWED, value: label },
+                        { type: { type: "string", ownership: "borrowed", length: 5 }, value: "Hello world" },
+                    ],
+                    VOID,
+                ),
+            ).toThrow("does not fit in length 5");
+            expect(() => md5(Buffer.from("Hello world"), 5)).toThrow("does not fit in length 5");
+        });
+
+        it("rejects embedded NULs without a length", () => {
+            const label = createLabel("Initial");
+
+            expect(() =>
+                call(
+                    GTK_LIB,
+                    "gtk_label_set_text",
+                    [
+                        { type: GOBJECT_BORROWED, value: label },
+                        { type: STRING_BORROWED, value: "a\0b" },
+                    ],
+                    VOID,
+                ),
+            ).toThrow("interior NUL");
+        });
+    });
+
+    describe("GString", () => {
+        const GLIB_LIB = "libglib-2.0.so.0";
+        const GSTRING = { type: "string" as const, ownership: "full" as const, gstring: true };
+        const GSTRING_BORROWED = { type: "string" as const, ownership: "borrowed" as const, gstring: true };
+
+        it("reads a returned GString", () => {
+            const result = call(GLIB_LIB, "g_string_new", [{ type: STRING_BORROWED, value: "Hello" }], GSTRING);
+
+            expect(result).toBe("Hello");
+        });
+
+        it("keeps embedded NULs in a returned GString", () => {
+            const result = call(
+                GLIB_LIB,
+                "g_string_new_len",
+                [
+                    { type: { type: "string", ownership: "borrowed", length: 3 }, value: "a\0b" },
+                    { type: { type: "int64" }, value: 3 },
+                ],
+                { ...GSTRING, utf8: "buffer" },
+            );
+
+            expect([...(result as Buffer)]).toEqual([0x61, 0x00, 0x62]);
+        });
+
+        it("passes GString arguments", () => {
+            const equal = (a: string, b: string) =>
+                call(
+                    GLIB_LIB,
+                    "g_string_equal",
+                    [
+                        { type: GSTRING_BORROWED, value: a },
+                        { type: GSTRING_BORROWED, value: b },
+                    ],
+                    { type: "boolean" },
+                );
+
+            expect(equal("a\0b", "a\0b")).toBe(true);
+            expect(equal("a\0b", "a\0c")).toBe(false);
+        });
+    });
+
     describe("memory leaks", () => {
         it("does not leak owned strings passed as arguments", () => {
             const label = createLabel("Test");
@@ -0,0 +1,45 @@
+import { describe, expect, it } from "vitest";
+import { alloc, copy, type NativeHandle, read, write } from "../../index.js";
+import { createLabel, FLOAT32, GDK_LIB, getRefCount, INT32 } from "./utils.js";
+
+describe("copy", () => {
+    it("duplicates a boxed value into new memory", () => {
+        const rgba = alloc(16, "GdkRGBA", GDK_LIB);
+        write(rgba, FLOAT32, 0, 0.5);
+
+        const snapshot = copy(rgba);
+
+        expect(snapshot.id).not.toBe(rgba.id);
+        expect(read(snapshot, FLOAT32, 0)).toBeCloseTo(0.5);
+    });
+
+    it("is independent of later writes to the source", () => {
+        const rgba = alloc(16, "GdkRGBA", GDK_LIB);
+        write(rgba, FLOAT32, 0, 0.25);
+
+        const snapshot = copy(rgba);
+        write(rgba, FLOAT32, 0, 1.0);
+
+        expect(read(snapshot, FLOAT32, 0)).toBeCloseTo(0.25);
+    });
+
+    it("copies plain structs by size", () => {
+        const struct = alloc(8);
+        write(struct, INT32, 4, 42);
+
+        const snapshot = copy(struct, undefined, 8);
+
+        expect(snapshot.id).not.toBe(struct.id);
+        expect(read(snapshot, INT32, 4)).toBe(42);
+    });
+
+    it("takes a new reference on a GObject", () => {
+        const label = createLabel("Copy") as NativeHandle;
+        const before = getRefCount(label);
+
+        const copied = copy(label);
+
+        expect(copied.id).toBe(label.id);
+        expect(getRefCount(label)).toBe(before + 1);
+    });
+});
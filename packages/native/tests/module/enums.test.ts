@@ -0,0 +1,93 @@
+import { describe, expect, it } from "vitest";
+import { call, listEnumValues, registerTypes, type Type } from "../../index.js";
+import { createLabel, GOBJECT_BORROWED, GTK_LIB, INT32, VOID } from "./utils.js";
+
+const ALIGN: Type = { type: "enum", library: GTK_LIB, getTypeFn: "gtk_align_get_type", signed: true };
+const STRICT_ALIGN: Type = { ...ALIGN, strict: true };
+
+const GTK_ALIGN_CENTER = 3;
+
+const setHalign = (widget: unknown, alignType: Type, value: number | bigint): void => {
+    call(
+        GTK_LIB,
+        "gtk_widget_set_halign",
+        [
+            { type: GOBJECT_BORROWED, value: widget },
+            { type: alignType, value },
+        ],
+        VOID,
+    );
+};
+
+const getHalign = (widget: unknown): number =>
+    call(GTK_LIB, "gtk_widget_get_halign", [{ type: GOBJECT_BORROWED, value: widget }], INT32) as number;
+
+describe("enums", () => {
+    describe("listEnumValues", () => {
+        it("lists enum members by get_type function", () => {
+            expect(listEnumValues("gtk_align_get_type")).toEqual([
+                { nick: "fill", name: "GTK_ALIGN_FILL", value: 0 },
+                { nick: "start", name: "GTK_ALIGN_START", value: 1 },
+                { nick: "end", name: "GTK_ALIGN_END", value: 2 },
+                { nick: "center", name: "GTK_ALIGN_CENTER", value: 3 },
+                { nick: "baseline-fill", name: "GTK_ALIGN_BASELINE_FILL", value: 4 },
+                { nick: "baseline-center", name: "GTK_ALIGN_BASELINE_CENTER", value: 5 },
+            ]);
+        });
+
+        it("lists enum members by GType name", () => {
+            listEnumValues("gtk_align_get_type");
+
+            expect(listEnumValues("GtkAlign").map((v) => v.nick)).toContain("center");
+        });
+
+        it("lists flags members by registered type name", () => {
+            registerTypes([
+                { kind: "flags", typeName: "GtkStateFlags", library: GTK_LIB, getTypeFn: "gtk_state_flags_get_type" },
+            ]);
+
+            expect(listEnumValues("GtkStateFlags")).toContainEqual({
+                nick: "active",
+                name: "GTK_STATE_FLAG_ACTIVE",
+                value: 1,
+            });
+        });
+
+        it("throws for an unknown type", () => {
+            expect(() => listEnumValues("NoSuchEnum")).toThrow("Unknown type 'NoSuchEnum'");
+        });
+
+        it("throws for a type that is not an enum", () => {
+            expect(() => listEnumValues("GObject")).toThrow("neither an enum nor a flags type");
+        });
+    });
+
+    describe("strict call arguments", () => {
+        it("accepts defined values", () => {
+            const label = createLabel();
+
+            setHalign(label, STRICT_ALIGN, GTK_ALIGN_CENTER);
+
+            expect(getHalign(label)).toBe(GTK_ALIGN_CENTER);
+        });
+
+        it("rejects undefined values", () => {
+            const label = createLabel();
+
+            expect(() => setHalign(label, STRICT_ALIGN, 99)).toThrow(
+                "Enum value 99 is not defined by gtk_align_get_type",
+            );
+        });
+
+        it("validates values passed as BigInts", () => {
+            const label = createLabel();
+
+            setHalign(label, STRICT_ALIGN, 3n);
+
+            expect(getHalign(label)).toBe(GTK_ALIGN_CENTER);
+            expect(() => setHalign(label, STRICT_ALIGN, 99n)).toThrow(
+                "Enum value 99 is not defined by gtk_align_get_type",
+            );
+        });
+    });
+});
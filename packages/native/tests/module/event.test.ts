@@ -0,0 +1,11 @@
+import { describe, expect, it } from "vitest";
+import { decodeEvent, type NativeHandle } from "../../index.js";
+import { createLabel } from "./utils.js";
+
+describe("decodeEvent", () => {
+    it("rejects a handle that is not a GdkEvent", () => {
+        const label = createLabel("Not an event") as NativeHandle;
+
+        expect(() => decodeEvent(label)).toThrow("Handle is not a GdkEvent");
+    });
+});
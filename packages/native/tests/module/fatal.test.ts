@@ -0,0 +1,41 @@
+import { spawnSync } from "node:child_process";
+import { fileURLToPath } from "node:url";
+import { describe, expect, it, vi } from "vitest";
+import { call, onFatalError } from "../../index.js";
+import { suppressUnhandledRejections } from "./lifecycle.js";
+import { INT32 } from "./utils.js";
+
+const FIXTURE = fileURLToPath(new URL("./fixtures/fatal-error.cjs", import.meta.url));
+
+describe("onFatalError", () => {
+    it("runs the callback and stops the main loop before aborting", () => {
+        const result = spawnSync(process.execPath, [FIXTURE], { encoding: "utf8" });
+
+        expect(result.signal).toBe("SIGABRT");
+        expect(result.stdout.trim().split("\n")).toEqual([
+            JSON.stringify({ domain: "Gtkx", level: "ERROR", message: "something went wrong" }),
+            "stopped",
+        ]);
+    });
+
+    it("ignores criticals that are not fatal", async () => {
+        const callback = vi.fn();
+        onFatalError(callback);
+
+        await suppressUnhandledRejections(() => {
+            call(
+                "libglib-2.0.so.0",
+                "g_log",
+                [
+                    { type: { type: "string", ownership: "borrowed" }, value: "Gtkx" },
+                    { type: INT32, value: 1 << 3 },
+                    { type: { type: "string", ownership: "borrowed" }, value: "not fatal" },
+                ],
+                { type: "void" },
+            );
+        });
+        onFatalError(null);
+
+        expect(callback).not.toHaveBeenCalled();
+    });
+});
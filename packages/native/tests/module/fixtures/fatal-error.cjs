@@ -0,0 +1,23 @@
+// Logs a fatal GLib error with an onFatalError callback that stops the
+// main loop, printing what happens to stdout before the process aborts.
+const { writeSync } = require("node:fs");
+const native = require("../../../native-binding.cjs");
+
+const mainLoop = native.init("spawn");
+
+native.onFatalError((domain, level, message) => {
+    writeSync(1, `${JSON.stringify({ domain, level, message })}\n`);
+    native.stop(mainLoop);
+    writeSync(1, "stopped\n");
+});
+
+native.call(
+    "libglib-2.0.so.0",
+    "g_log",
+    [
+        { type: { type: "string", ownership: "borrowed" }, value: "Gtkx" },
+        { type: { type: "int32" }, value: 1 << 2 },
+        { type: { type: "string", ownership: "borrowed" }, value: "something went wrong" },
+    ],
+    { type: "void" },
+);
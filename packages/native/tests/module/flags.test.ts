@@ -0,0 +1,114 @@
+import { describe, expect, it } from "vitest";
+import { call, type FlagsType, flagsFromNicks, flagsToNicks } from "../../index.js";
+import { BOOLEAN, createLabel, GOBJECT_BORROWED, GOBJECT_LIB, GTK_LIB, STRING_BORROWED, VOID } from "./utils.js";
+
+const STATE_FLAGS: FlagsType = {
+    type: "flags",
+    library: GTK_LIB,
+    getTypeFn: "gtk_state_flags_get_type",
+    signed: false,
+};
+
+const STRICT_STATE_FLAGS: FlagsType = { ...STATE_FLAGS, strict: true };
+
+const LOG_LEVEL_FLAGS: FlagsType = {
+    type: "flags",
+    library: GOBJECT_LIB,
+    getTypeFn: "g_log_level_flags_get_type",
+    signed: true,
+};
+
+const LOG_LEVEL_MASK = ~0b11;
+
+const ACTIVE = 1 << 0;
+const SELECTED = 1 << 2;
+const UNKNOWN_BIT = 1 << 30;
+
+const setStateFlags = (widget: unknown, flagsType: FlagsType, flags: unknown): void => {
+    call(
+        GTK_LIB,
+        "gtk_widget_set_state_flags",
+        [
+            { type: GOBJECT_BORROWED, value: widget },
+            { type: flagsType, value: flags },
+            { type: BOOLEAN, value: true },
+        ],
+        VOID,
+    );
+};
+
+const getStateFlags = (widget: unknown): number =>
+    call(GTK_LIB, "gtk_widget_get_state_flags", [{ type: GOBJECT_BORROWED, value: widget }], STATE_FLAGS) as number;
+
+describe("flags", () => {
+    describe("flagsToNicks / flagsFromNicks", () => {
+        it("converts a mask to the nicks of its set values", () => {
+            expect(flagsToNicks(STATE_FLAGS, ACTIVE | SELECTED)).toEqual(["active", "selected"]);
+        });
+
+        it("never lists the zero value", () => {
+            expect(flagsToNicks(STATE_FLAGS, 0)).toEqual([]);
+        });
+
+        it("builds a mask from nicks", () => {
+            expect(flagsFromNicks(STATE_FLAGS, ["selected", "active"])).toBe(ACTIVE | SELECTED);
+        });
+
+        it("rejects unknown nicks", () => {
+            expect(() => flagsFromNicks(STATE_FLAGS, ["bogus"])).toThrow("'bogus' is not a member");
+        });
+
+        it("rejects undefined bits in strict mode", () => {
+            expect(() => flagsToNicks(STRICT_STATE_FLAGS, ACTIVE | UNKNOWN_BIT)).toThrow("not defined by");
+            expect(flagsToNicks(STATE_FLAGS, ACTIVE | UNKNOWN_BIT)).toEqual(["active"]);
+        });
+    });
+
+    describe("call arguments", () => {
+        it("accepts an array of nicks", () => {
+            const label = createLabel();
+
+            setStateFlags(label, STATE_FLAGS, ["active", "selected"]);
+
+            expect(getStateFlags(label) & (ACTIVE | SELECTED)).toBe(ACTIVE | SELECTED);
+        });
+
+        it("accepts known bits in strict mode", () => {
+            const label = createLabel();
+
+            setStateFlags(label, STRICT_STATE_FLAGS, SELECTED);
+
+            expect(getStateFlags(label) & SELECTED).toBe(SELECTED);
+        });
+
+        it("rejects undefined bits in strict mode", () => {
+            const label = createLabel();
+
+            expect(() => setStateFlags(label, STRICT_STATE_FLAGS, UNKNOWN_BIT)).toThrow("not defined by");
+        });
+
+        it("passes negative masks of signed flags", () => {
+            const setFatalMask = (mask: number) =>
+                call(
+                    "libglib-2.0.so.0",
+                    "g_log_set_fatal_mask",
+                    [
+                        { type: STRING_BORROWED, value: "GtkxFlagsTest" },
+                        { type: LOG_LEVEL_FLAGS, value: mask },
+                    ],
+                    LOG_LEVEL_FLAGS,
+                );
+
+            setFatalMask(LOG_LEVEL_MASK);
+
+            expect(setFatalMask(0)).toBe(LOG_LEVEL_MASK);
+        });
+
+        it("rejects fractional masks", () => {
+            const label = createLabel();
+
+            expect(() => setStateFlags(label, STATE_FLAGS, ACTIVE + 0.5)).toThrow("out of range");
+            expect(() => flagsToNicks(STATE_FLAGS, ACTIVE + 0.5)).toThrow("out of range");
+        });
+    });
+});
@@ -0,0 +1,49 @@
+import { describe, expect, it } from "vitest";
+import { call, callHint, type NativeHandle } from "../../index.js";
+import { BOOLEAN, createCancellable, GIO_LIB, GOBJECT_BORROWED } from "./utils.js";
+
+const captureRejections = async (fn: () => void): Promise<string[]> => {
+    const messages: string[] = [];
+    const savedListeners = process.rawListeners("unhandledRejection").slice();
+    process.removeAllListeners("unhandledRejection");
+    process.on("unhandledRejection", (reason) => messages.push((reason as Error).message));
+
+    try {
+        fn();
+        await new Promise((resolve) => setTimeout(resolve, 100));
+    } finally {
+        process.removeAllListeners("unhandledRejection");
+        for (const listener of savedListeners) {
+            process.on("unhandledRejection", listener as (...args: unknown[]) => void);
+        }
+    }
+
+    return messages;
+};
+
+describe("callHint", () => {
+    it("runs before calls made after it", () => {
+        const cancellable = createCancellable();
+
+        callHint(GIO_LIB, "g_cancellable_cancel", cancellable as NativeHandle);
+        const cancelled = call(
+            GIO_LIB,
+            "g_cancellable_is_cancelled",
+            [{ type: GOBJECT_BORROWED, value: cancellable }],
+            BOOLEAN,
+        );
+
+        expect(cancelled).toBe(true);
+    });
+
+    it("reports a failed hint as an unhandled rejection instead of throwing", async () => {
+        const cancellable = createCancellable();
+
+        const messages = await captureRejections(() => {
+            callHint(GIO_LIB, "g_no_such_hint", cancellable as NativeHandle);
+        });
+
+        expect(messages).toHaveLength(1);
+        expect(messages[0]).toContain("hint g_no_such_hint");
+    });
+});
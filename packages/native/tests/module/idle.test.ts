@@ -0,0 +1,45 @@
+import { describe, expect, it } from "vitest";
+import { onIdle } from "../../index.js";
+import { createLabel } from "./utils.js";
+
+const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+describe("onIdle", () => {
+    it("fires once the main loop has been idle for the timeout", async () => {
+        const start = performance.now();
+
+        const firedAt = await new Promise<number>((resolve) => {
+            onIdle(() => resolve(performance.now()), 20);
+        });
+
+        expect(firedAt - start).toBeGreaterThanOrEqual(15);
+    });
+
+    it("postpones firing while work keeps arriving", async () => {
+        let burstEnd = 0;
+        const fired = new Promise<number>((resolve) => {
+            onIdle(() => resolve(performance.now()), 40);
+        });
+
+        const burstStart = performance.now();
+        while (performance.now() - burstStart < 80) {
+            createLabel();
+            burstEnd = performance.now();
+            await sleep(5);
+        }
+
+        expect((await fired) - burstEnd).toBeGreaterThanOrEqual(30);
+    });
+
+    it("does not fire after being cancelled", async () => {
+        let calls = 0;
+        const cancel = onIdle(() => {
+            calls++;
+        }, 10);
+
+        cancel();
+        await sleep(50);
+
+        expect(calls).toBe(0);
+    });
+});
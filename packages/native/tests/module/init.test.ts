@@ -1,5 +1,5 @@
 import { describe, expect, it } from "vitest";
-import { call } from "../../index.js";
+import { call, type InitMode, init } from "../../index.js";
 import { GTK_LIB } from "./utils.js";
 
 describe("init", () => {
@@ -16,4 +16,8 @@ describe("init", () => {
 
         expect(label).toBeDefined();
     });
+
+    it("rejects an unknown mode", () => {
+        expect(() => init("detached" as InitMode)).toThrow(/Unknown init mode/);
+    });
 });
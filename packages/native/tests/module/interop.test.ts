@@ -0,0 +1,146 @@
+import { describe, expect, it } from "vitest";
+import {
+    alloc,
+    call,
+    copy,
+    exportPointer,
+    importForeignObject,
+    isExternalPointer,
+    type NativeHandle,
+    type Type,
+    wrapExternalPointer,
+    write,
+} from "../../index.js";
+import {
+    BOOLEAN,
+    createLabel,
+    FLOAT32,
+    GDK_LIB,
+    GOBJECT_BORROWED,
+    GOBJECT_LIB,
+    INT32,
+    STRING_BORROWED,
+} from "./utils.js";
+
+const PARAM_SPEC: Type = {
+    type: "fundamental",
+    ownership: "full",
+    library: GOBJECT_LIB,
+    refFn: "g_param_spec_ref_sink",
+    unrefFn: "g_param_spec_unref",
+};
+
+describe("exportPointer / importForeignObject", () => {
+    it("round-trips a GObject pointer to the same instance", () => {
+        const label = createLabel("Interop") as NativeHandle;
+
+        const pointer = exportPointer(label);
+        const imported = importForeignObject(pointer);
+
+        expect(imported.id).toBe(label.id);
+        expect(imported).toBe(label);
+    });
+
+    it("adopts a reference taken by exportPointer with addRef", () => {
+        const label = createLabel("Owned") as NativeHandle;
+
+        const pointer = exportPointer(label, { addRef: true });
+        const imported = importForeignObject(pointer, { takeRef: true });
+
+        const name = call(
+            GOBJECT_LIB,
+            "g_type_name_from_instance",
+            [{ type: GOBJECT_BORROWED, value: imported }],
+            STRING_BORROWED,
+        );
+        expect(name).toBe("GtkLabel");
+    });
+
+    it("refuses to take a reference on a boxed value", () => {
+        const rgba = alloc(16, "GdkRGBA", GDK_LIB);
+        for (const offset of [0, 4, 8, 12]) write(rgba, FLOAT32, offset, 1);
+
+        expect(() => exportPointer(rgba, { addRef: true })).toThrow("Only GObject handles");
+    });
+
+    it("returns pointers as BigInts", () => {
+        const label = createLabel("Pointer") as NativeHandle;
+
+        expect(exportPointer(label)).toBe(BigInt(label.id));
+    });
+
+    it("rejects a null pointer", () => {
+        expect(() => importForeignObject(0n)).toThrow("Pointer is null");
+    });
+
+    it("rejects a negative pointer", () => {
+        expect(() => importForeignObject(-1n)).toThrow("not a valid address");
+    });
+
+    it("rejects a type instance that is not a GObject", () => {
+        const paramSpec = call(
+            GOBJECT_LIB,
+            "g_param_spec_boolean",
+            [
+                { type: STRING_BORROWED, value: "flag" },
+                { type: STRING_BORROWED, value: null, optional: true },
+                { type: STRING_BORROWED, value: null, optional: true },
+                { type: BOOLEAN, value: false },
+                { type: INT32, value: 0 },
+            ],
+            PARAM_SPEC,
+        ) as NativeHandle;
+
+        expect(() => importForeignObject(exportPointer(paramSpec))).toThrow("is not a GObject instance");
+    });
+});
+
+describe("wrapExternalPointer", () => {
+    it("wraps a pointer in a handle flagged as external", () => {
+        const rgba = alloc(16, "GdkRGBA", GDK_LIB);
+
+        const external = wrapExternalPointer(BigInt(rgba.id), { typeName: "GdkRGBA" });
+
+        expect(external.id).toBe(rgba.id);
+        expect(isExternalPointer(external)).toBe(true);
+        expect(isExternalPointer(rgba)).toBe(false);
+    });
+
+    it("copies the value using the declared type", () => {
+        const rgba = alloc(16, "GdkRGBA", GDK_LIB);
+        const external = wrapExternalPointer(BigInt(rgba.id), { typeName: "GdkRGBA" });
+
+        const copied = copy(external);
+
+        expect(copied.id).not.toBe(rgba.id);
+        expect(isExternalPointer(copied)).toBe(false);
+    });
+
+    it("passes the pointer through as an argument", () => {
+        const label = createLabel("External") as NativeHandle;
+        const external = wrapExternalPointer(exportPointer(label));
+
+        const name = call(
+            GOBJECT_LIB,
+            "g_type_name_from_instance",
+            [{ type: GOBJECT_BORROWED, value: external }],
+            STRING_BORROWED,
+        );
+        expect(name).toBe("GtkLabel");
+    });
+
+    it("rejects a null pointer", () => {
+        expect(() => wrapExternalPointer(0n)).toThrow("External pointer is null");
+    });
+
+    it("rejects a negative pointer", () => {
+        expect(() => wrapExternalPointer(-1n)).toThrow("not a valid address");
+    });
+
+    it("names the pointer in errors", () => {
+        const rgba = alloc(16, "GdkRGBA", GDK_LIB);
+        const external = wrapExternalPointer(BigInt(rgba.id), { typeName: "GdkRGBA" });
+
+        expect(() => exportPointer(external, { addRef: true })).toThrow("external pointer");
+    });
+});
@@ -0,0 +1,99 @@
+import { mkdirSync, mkdtempSync, readFileSync, symlinkSync, writeFileSync } from "node:fs";
+import { tmpdir } from "node:os";
+import { dirname, join } from "node:path";
+import { describe, expect, it } from "vitest";
+import { call, configureBundle, configureLibrary, unloadLibrary } from "../../index.js";
+import { STRING, STRING_BORROWED } from "./utils.js";
+
+const GLIB_LIB = "libglib-2.0.so.0";
+
+const glibDir = (): string => {
+    const line = readFileSync("/proc/self/maps", "utf8")
+        .split("\n")
+        .find((entry) => entry.includes(`/${GLIB_LIB}`));
+    if (!line) throw new Error(`${GLIB_LIB} is not mapped`);
+    return dirname(line.slice(line.indexOf("/")));
+};
+
+const getenv = (name: string): unknown =>
+    call(GLIB_LIB, "g_getenv", [{ type: STRING_BORROWED, value: name }], STRING_BORROWED);
+
+const strdup = (library: string, value: string): unknown =>
+    call(library, "g_strdup", [{ type: STRING_BORROWED, value }], STRING);
+
+describe("configureLibrary", () => {
+    it("loads a library under another file name", () => {
+        configureLibrary("libtest-alias.so", { sonames: ["libnonexistent.so.9", GLIB_LIB] });
+
+        expect(strdup("libtest-alias.so", "aliased")).toBe("aliased");
+    });
+
+    it("loads a library from a search path", () => {
+        configureLibrary("libtest-search.so", { searchPaths: [glibDir()], sonames: [GLIB_LIB], flags: ["lazy"] });
+
+        expect(strdup("libtest-search.so", "found")).toBe("found");
+    });
+
+    it("rejects unknown flags", () => {
+        expect(() => configureLibrary("libtest-flags.so", { flags: ["now", "deep" as "now"] })).toThrow(
+            "Unknown library flag 'deep'",
+        );
+    });
+
+    it("requires a binding mode flag", () => {
+        expect(() => configureLibrary("libtest-flags.so", { flags: ["global"] })).toThrow(
+            "Library flags must include 'lazy' or 'now'",
+        );
+    });
+});
+
+describe("unloadLibrary", () => {
+    it("reports whether the library was loaded", () => {
+        configureLibrary("libtest-unload.so", { sonames: [GLIB_LIB] });
+        strdup("libtest-unload.so", "loaded");
+
+        expect(unloadLibrary("libtest-unload.so")).toBe(true);
+        expect(unloadLibrary("libtest-unload.so")).toBe(false);
+    });
+
+    it("loads the library again on next use", () => {
+        configureLibrary("libtest-reload.so", { sonames: [GLIB_LIB] });
+        strdup("libtest-reload.so", "first");
+        unloadLibrary("libtest-reload.so");
+
+        expect(strdup("libtest-reload.so", "second")).toBe("second");
+    });
+});
+
+describe("configureBundle", () => {
+    const root = mkdtempSync(join(tmpdir(), "gtkx-bundle-"));
+    mkdirSync(join(root, "lib", "gdk-pixbuf-2.0", "2.10.0"), { recursive: true });
+    writeFileSync(join(root, "lib", "gdk-pixbuf-2.0", "2.10.0", "loaders.cache"), "");
+    symlinkSync(join(glibDir(), GLIB_LIB), join(root, "lib", "libtest-bundled.so"));
+
+    configureBundle({ root, renderer: "cairo" });
+
+    it("loads libraries from the bundle", () => {
+        expect(strdup("libtest-bundled.so", "bundled")).toBe("bundled");
+    });
+
+    it("resolves relative search paths against the bundle root", () => {
+        configureLibrary("libtest-relative.so", { searchPaths: ["lib"], sonames: ["libtest-bundled.so"] });
+
+        expect(strdup("libtest-relative.so", "relative")).toBe("relative");
+    });
+
+    it("prepends the bundle data directory to XDG_DATA_DIRS", () => {
+        expect(getenv("XDG_DATA_DIRS")).toMatch(new RegExp(`^${join(root, "share")}:.+`));
+    });
+
+    it("sets the renderer and pixbuf loaders", () => {
+        expect(getenv("GSK_RENDERER")).toBe("cairo");
+        expect(getenv("GDK_PIXBUF_MODULE_FILE")).toBe(join(root, "lib", "gdk-pixbuf-2.0", "2.10.0", "loaders.cache"));
+        expect(getenv("GDK_PIXBUF_MODULEDIR")).toBe(join(root, "lib", "gdk-pixbuf-2.0", "2.10.0", "loaders"));
+    });
+
+    it("rejects a relative root", () => {
+        expect(() => configureBundle({ root: "bundle" })).toThrow("Bundle root 'bundle' must be an absolute path");
+    });
+});
@@ -0,0 +1,81 @@
+import { mkdtempSync, rmSync, writeFileSync } from "node:fs";
+import { tmpdir } from "node:os";
+import { join } from "node:path";
+import { afterAll, describe, expect, it, vi } from "vitest";
+import { mapFile, mappedFileBuffer, type NativeHandle } from "../../index.js";
+import { createCancellable, forceGC } from "./utils.js";
+
+const dir = mkdtempSync(join(tmpdir(), "gtkx-mapped-"));
+
+const createFile = (name: string, contents: string | Buffer): string => {
+    const path = join(dir, name);
+    writeFileSync(path, contents);
+    return path;
+};
+
+describe("mapFile", () => {
+    afterAll(() => {
+        rmSync(dir, { recursive: true, force: true });
+    });
+
+    it("exposes the file contents as a buffer", () => {
+        const path = createFile("hello.txt", "Hello, mapping");
+
+        const buffer = mappedFileBuffer(mapFile(path));
+
+        expect(buffer.toString()).toBe("Hello, mapping");
+    });
+
+    it("maps binary contents without conversion", () => {
+        const path = createFile("data.bin", Buffer.from([0, 1, 254, 255]));
+
+        const buffer = mappedFileBuffer(mapFile(path));
+
+        expect([...buffer]).toEqual([0, 1, 254, 255]);
+    });
+
+    it("returns an empty buffer for an empty file", () => {
+        const path = createFile("empty", "");
+
+        expect(mappedFileBuffer(mapFile(path))).toHaveLength(0);
+    });
+
+    it("keeps writes to a writable mapping private", () => {
+        const path = createFile("private.txt", "abc");
+
+        const buffer = mappedFileBuffer(mapFile(path, { writable: true }));
+        buffer[0] = "x".charCodeAt(0);
+
+        expect(buffer.toString()).toBe("xbc");
+        expect(mappedFileBuffer(mapFile(path)).toString()).toBe("abc");
+    });
+
+    it("rejects a second buffer over a mapping while the first is alive", () => {
+        const path = createFile("shared.txt", "shared");
+        const file = mapFile(path);
+
+        const buffer = mappedFileBuffer(file);
+
+        expect(() => mappedFileBuffer(file)).toThrow("already backs a buffer");
+        expect(buffer.toString()).toBe("shared");
+    });
+
+    it("creates a new buffer once the first has been collected", async () => {
+        const path = createFile("reused.txt", "reused");
+        const file = mapFile(path);
+        mappedFileBuffer(file);
+
+        await vi.waitFor(() => {
+            forceGC();
+            expect(mappedFileBuffer(file).toString()).toBe("reused");
+        });
+    });
+
+    it("rejects handles that are not a mapped file", () => {
+        expect(() => mappedFileBuffer(createCancellable() as NativeHandle)).toThrow("Expected a GMappedFile");
+    });
+
+    it("throws for a missing file", () => {
+        expect(() => mapFile(join(dir, "missing"))).toThrow("Cannot map");
+    });
+});
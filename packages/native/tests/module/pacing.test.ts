@@ -0,0 +1,74 @@
+import { afterEach, describe, expect, it } from "vitest";
+import { getLoopMetrics, pumpLoop, setLoopPacing } from "../../index.js";
+import { createLabel } from "./utils.js";
+
+const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+describe("loop pacing", () => {
+    afterEach(() => {
+        setLoopPacing({});
+    });
+
+    it("counts main-loop iterations", async () => {
+        getLoopMetrics({ reset: true });
+
+        for (let i = 0; i < 5; i++) {
+            createLabel();
+            await sleep(5);
+        }
+
+        const metrics = getLoopMetrics();
+        expect(metrics.iterations).toBeGreaterThan(0);
+        expect(metrics.busyMs).toBeGreaterThanOrEqual(0);
+        expect(metrics.maxBusyMs).toBeGreaterThanOrEqual(metrics.lastBusyMs);
+    });
+
+    it("resets counters on request", async () => {
+        createLabel();
+        await sleep(5);
+
+        getLoopMetrics({ reset: true });
+
+        expect(getLoopMetrics().pacedMs).toBe(0);
+    });
+
+    it("does not hold back calls from JS", () => {
+        setLoopPacing({ external: true });
+
+        const start = performance.now();
+        for (let i = 0; i < 10; i++) {
+            createLabel();
+        }
+
+        expect(performance.now() - start).toBeLessThan(500);
+    });
+
+    it("holds iterations back by the interval", async () => {
+        setLoopPacing({ intervalMs: 20 });
+        getLoopMetrics({ reset: true });
+
+        for (let i = 0; i < 5; i++) {
+            createLabel();
+            await sleep(5);
+        }
+
+        expect(getLoopMetrics().pacedMs).toBeGreaterThan(0);
+    });
+
+    it("keeps running on external ticks", async () => {
+        setLoopPacing({ external: true, intervalMs: 50 });
+        getLoopMetrics({ reset: true });
+
+        for (let i = 0; i < 5; i++) {
+            pumpLoop();
+            createLabel();
+            await sleep(5);
+        }
+
+        expect(getLoopMetrics().iterations).toBeGreaterThan(0);
+    });
+
+    it("rejects a negative interval", () => {
+        expect(() => setLoopPacing({ intervalMs: -1 })).toThrow("Invalid pacing interval");
+    });
+});
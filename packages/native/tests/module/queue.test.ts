@@ -0,0 +1,116 @@
+import { afterEach, describe, expect, it } from "vitest";
+import { call, getQueueDepths, setBackpressureThreshold, setDispatchBudgets } from "../../index.js";
+import {
+    BOOLEAN,
+    connectSignal,
+    createButton,
+    GOBJECT_BORROWED,
+    GOBJECT_LIB,
+    GTK_LIB,
+    STRING,
+    UINT64,
+    VOID,
+} from "./utils.js";
+
+describe("dispatch budgets", () => {
+    afterEach(() => {
+        setDispatchBudgets({});
+    });
+
+    it("completes re-entrant callbacks with a budget of one", () => {
+        setDispatchBudgets({ glib: 1, js: 1 });
+        const button = createButton("Test");
+        let clicks = 0;
+        connectSignal(button, "clicked", () => {
+            clicks++;
+        });
+
+        for (let i = 0; i < 20; i++) {
+            call(GTK_LIB, "gtk_widget_activate", [{ type: GOBJECT_BORROWED, value: button }], BOOLEAN);
+        }
+
+        expect(clicks).toBe(20);
+    });
+});
+
+describe("getQueueDepths", () => {
+    it("reports empty queues while idle", () => {
+        const depths = getQueueDepths();
+
+        expect(depths.glib).toBe(0);
+        expect(depths.js).toBe(0);
+    });
+
+    it("tracks peaks and resets them on request", () => {
+        createButton("Test");
+
+        const depths = getQueueDepths({ resetPeaks: true });
+        expect(depths.glibPeak).toBeGreaterThanOrEqual(1);
+
+        expect(getQueueDepths().glibPeak).toBe(0);
+    });
+});
+
+describe("backpressure", () => {
+    const connectClicked = (button: unknown, callbackType: object, value: unknown) =>
+        call(
+            GOBJECT_LIB,
+            "g_signal_connect_closure",
+            [
+                { type: GOBJECT_BORROWED, value: button },
+                { type: STRING, value: "clicked" },
+                { type: callbackType as never, value },
+                { type: BOOLEAN, value: false },
+            ],
+            UINT64,
+        );
+
+    afterEach(() => {
+        setBackpressureThreshold(256);
+    });
+
+    it("delivers overflow closures normally below the threshold", () => {
+        setBackpressureThreshold(64);
+        getQueueDepths({ resetPeaks: true });
+        const button = createButton("Test");
+        let clicks = 0;
+        connectClicked(
+            button,
+            { type: "callback", kind: "closure", argTypes: [], returnType: VOID, overflow: "drop" },
+            () => {
+                clicks++;
+            },
+        );
+
+        for (let i = 0; i < 10; i++) {
+            call(GTK_LIB, "gtk_widget_activate", [{ type: GOBJECT_BORROWED, value: button }], BOOLEAN);
+        }
+
+        expect(clicks).toBe(10);
+        expect(getQueueDepths().dropped).toBe(0);
+    });
+
+    it("rejects unknown overflow policies", () => {
+        const button = createButton("Test");
+
+        expect(() =>
+            connectClicked(
+                button,
+                { type: "callback", kind: "closure", argTypes: [], returnType: VOID, overflow: "queue" },
+                () => {},
+            ),
+        ).toThrow("Invalid overflow policy 'queue'");
+    });
+
+    it("rejects overflow on closures with a return value", () => {
+        const button = createButton("Test");
+
+        expect(() =>
+            connectClicked(
+                button,
+                { type: "callback", kind: "closure", argTypes: [], returnType: BOOLEAN, overflow: "drop" },
+                () => true,
+            ),
+        ).toThrow("'overflow' requires a void return type");
+    });
+});
@@ -1,7 +1,36 @@
 import { describe, expect, it } from "vitest";
-import { alloc, read, write } from "../../index.js";
+import {
+    alloc,
+    indexHandle,
+    NativeHandle,
+    read,
+    readBitfield,
+    type Type,
+    write,
+    writeBitfield,
+} from "../../index.js";
 import { GDK_LIB } from "./utils.js";
 
+const POINT: Type = {
+    type: "struct",
+    ownership: "borrowed",
+    innerType: "TestPoint",
+    fields: [
+        { name: "x", type: { type: "float32" } },
+        { name: "y", type: { type: "float32" } },
+    ],
+};
+
+const RECT: Type = {
+    type: "struct",
+    ownership: "borrowed",
+    innerType: "TestRect",
+    fields: [
+        { name: "origin", type: POINT },
+        { name: "size", type: POINT },
+    ],
+};
+
 describe("read and write", () => {
     describe("float fields", () => {
         it("writes and reads float values at offset 0", () => {
@@ -99,4 +128,190 @@ describe("read and write", () => {
             expect(result).toBe(0.0);
         });
     });
+
+    describe("byte order", () => {
+        it("writes integers in the requested byte order", () => {
+            const rect = alloc(16, "GdkRectangle", GDK_LIB);
+
+            write(rect, { type: "uint32" }, 0, 0x01020304, { byteOrder: "big" });
+            write(rect, { type: "uint16" }, 4, 0x0102, { byteOrder: "little" });
+
+            expect([0, 1, 2, 3, 4, 5].map((i) => read(rect, { type: "uint8" }, i))).toEqual([1, 2, 3, 4, 2, 1]);
+        });
+
+        it("reads integers in the requested byte order", () => {
+            const rect = alloc(16, "GdkRectangle", GDK_LIB);
+
+            write(rect, { type: "int32" }, 0, -2, { byteOrder: "big" });
+
+            expect(read(rect, { type: "int32" }, 0, { byteOrder: "big" })).toBe(-2);
+            expect(read(rect, { type: "uint32" }, 0, { byteOrder: "little" })).toBe(0xfeffffff);
+        });
+
+        it("round-trips floats in either byte order", () => {
+            const rgba = alloc(16, "GdkRGBA", GDK_LIB);
+
+            write(rgba, { type: "float32" }, 0, 0.75, { byteOrder: "big" });
+            write(rgba, { type: "float64" }, 8, 1.5, { byteOrder: "little" });
+
+            expect(read(rgba, { type: "float32" }, 0, { byteOrder: "big" })).toBe(0.75);
+            expect(read(rgba, { type: "float64" }, 8, { byteOrder: "little" })).toBe(1.5);
+        });
+
+        it("rejects a byte order for non-numeric types", () => {
+            const rect = alloc(16, "GdkRectangle", GDK_LIB);
+
+            expect(() => read(rect, { type: "boolean" }, 0, { byteOrder: "big" })).toThrow(
+                "Byte order only applies to integers and floats",
+            );
+        });
+    });
+
+    describe("named fields", () => {
+        it("reads and writes fields of embedded structs by path", () => {
+            const rect = alloc(16);
+
+            write(rect, RECT, "origin.y", 2.5);
+            write(rect, RECT, "size.x", 640);
+
+            expect(read(rect, RECT, "origin.y")).toBe(2.5);
+            expect(read(rect, { type: "float32" }, 4)).toBe(2.5);
+            expect(read(rect, { type: "float32" }, 8)).toBe(640);
+        });
+
+        it("aligns fields without an offset as C does", () => {
+            const layout: Type = {
+                type: "struct",
+                ownership: "borrowed",
+                innerType: "TestPadded",
+                fields: [
+                    { name: "tag", type: { type: "uint8" } },
+                    { name: "value", type: { type: "int64" } },
+                    { name: "flag", type: { type: "boolean" }, offset: 20 },
+                ],
+            };
+            const block = alloc(24);
+
+            write(block, layout, "value", 7);
+            write(block, layout, "flag", true);
+
+            expect(read(block, { type: "int64" }, 8)).toBe(7);
+            expect(read(block, { type: "int32" }, 20)).toBe(1);
+        });
+
+        it("reads an embedded struct as a handle into its parent", () => {
+            const rect = alloc(16);
+            write(rect, RECT, "size.y", 480);
+
+            const size = read(rect, RECT, "size");
+
+            expect(size).toBeInstanceOf(NativeHandle);
+            expect(read(size as NativeHandle, POINT, "y")).toBe(480);
+        });
+
+        it("copies a struct into an embedded field", () => {
+            const point = alloc(8);
+            write(point, POINT, "x", 3);
+            write(point, POINT, "y", 4);
+            const rect = alloc(16);
+
+            write(rect, RECT, "size", point);
+
+            expect(read(rect, RECT, "size.x")).toBe(3);
+            expect(read(rect, RECT, "size.y")).toBe(4);
+        });
+
+        it("rejects unknown fields and field names without a layout", () => {
+            const rect = alloc(16);
+
+            expect(() => read(rect, RECT, "origin.z")).toThrow("Struct TestPoint has no field 'z'");
+            expect(() => read(rect, RECT, "origin.x.y")).toThrow("Field 'x' of TestPoint is not an embedded struct");
+            expect(() => read(rect, { type: "int32" }, "x")).toThrow("Field names require a struct type");
+        });
+    });
+
+    describe("array elements", () => {
+        it("addresses elements by stride", () => {
+            const rect = alloc(16, "GdkRectangle", GDK_LIB);
+
+            for (let i = 0; i < 4; i++) {
+                write(indexHandle(rect, i, 4), { type: "int32" }, 0, (i + 1) * 10);
+            }
+
+            expect([0, 4, 8, 12].map((offset) => read(rect, { type: "int32" }, offset))).toEqual([10, 20, 30, 40]);
+        });
+
+        it("reads elements with a known length", () => {
+            const rect = alloc(16, "GdkRectangle", GDK_LIB);
+            write(rect, { type: "int32" }, 8, 7);
+
+            const element = indexHandle(rect, 1, { stride: 8, length: 2 });
+
+            expect(read(element, { type: "int32" }, 0)).toBe(7);
+        });
+
+        it("rejects indices past a known length", () => {
+            const rect = alloc(16, "GdkRectangle", GDK_LIB);
+
+            expect(() => indexHandle(rect, 2, { stride: 8, length: 2 })).toThrow(
+                "Index 2 is out of bounds for an array of length 2",
+            );
+        });
+
+        it("rejects negative indices and zero strides", () => {
+            const rect = alloc(16, "GdkRectangle", GDK_LIB);
+
+            expect(() => indexHandle(rect, -1, 4)).toThrow("Invalid index");
+            expect(() => indexHandle(rect, 0, 0)).toThrow("Invalid stride");
+        });
+    });
+
+    describe("bitfields", () => {
+        it("reads bits packed into a byte", () => {
+            const block = alloc(8);
+            write(block, { type: "uint8" }, 0, 0b1011_0100);
+
+            expect(readBitfield(block, { offset: 0, bitOffset: 2, width: 1 })).toBe(1);
+            expect(readBitfield(block, { offset: 0, bitOffset: 3, width: 1 })).toBe(0);
+            expect(readBitfield(block, { offset: 0, bitOffset: 4, width: 4 })).toBe(0b1011);
+        });
+
+        it("reads fields spanning several bytes", () => {
+            const block = alloc(8);
+            write(block, { type: "uint32" }, 0, 0x0003_fc00);
+
+            expect(readBitfield(block, { offset: 1, bitOffset: 2, width: 8 })).toBe(0xff);
+        });
+
+        it("sign-extends signed fields", () => {
+            const block = alloc(8);
+            write(block, { type: "uint8" }, 0, 0b0000_0110);
+
+            expect(readBitfield(block, { offset: 0, bitOffset: 1, width: 2, signed: true })).toBe(-1);
+            expect(readBitfield(block, { offset: 0, bitOffset: 1, width: 2 })).toBe(3);
+        });
+
+        it("writes a field without touching neighbouring bits", () => {
+            const block = alloc(8);
+            write(block, { type: "uint16" }, 0, 0xffff);
+
+            writeBitfield(block, { offset: 0, bitOffset: 6, width: 4 }, 0b0101);
+
+            expect(read(block, { type: "uint16" }, 0)).toBe(0b1111_1101_0111_1111);
+        });
+
+        it("truncates written values to the field width", () => {
+            const block = alloc(8);
+
+            writeBitfield(block, { offset: 0, bitOffset: 0, width: 3, signed: true }, -1);
+
+            expect(read(block, { type: "uint8" }, 0)).toBe(0b111);
+        });
+
+        it("rejects widths over 32 bits", () => {
+            const block = alloc(8);
+
+            expect(() => readBitfield(block, { offset: 0, bitOffset: 0, width: 33 })).toThrow(RangeError);
+        });
+    });
 });
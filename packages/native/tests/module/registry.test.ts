@@ -0,0 +1,116 @@
+import { describe, expect, it } from "vitest";
+import { call, type FlagsType, flagsToNicks, getTypeRegistration, NativeHandle, registerTypes } from "../../index.js";
+import {
+    BOOLEAN,
+    createCancellable,
+    GIO_LIB,
+    GOBJECT_BORROWED,
+    GOBJECT_LIB,
+    GTK_LIB,
+    INT32,
+    STRING,
+    UINT64,
+    VOID,
+} from "./utils.js";
+
+const GLIB_LIB = "libglib-2.0.so.0";
+
+const ACTIVE = 1 << 0;
+const SELECTED = 1 << 2;
+
+describe("registerTypes", () => {
+    it("completes flags descriptors that only name the type", () => {
+        const stateFlags: FlagsType = { type: "flags", typeName: "GtkStateFlags", signed: false };
+
+        expect(() => flagsToNicks(stateFlags, ACTIVE)).toThrow("'getTypeFn'");
+
+        registerTypes([
+            { kind: "flags", typeName: "GtkStateFlags", library: GTK_LIB, getTypeFn: "gtk_state_flags_get_type" },
+        ]);
+
+        expect(flagsToNicks(stateFlags, ACTIVE | SELECTED)).toEqual(["active", "selected"]);
+    });
+
+    it("completes fundamental descriptors from their type name", () => {
+        registerTypes([
+            {
+                kind: "fundamental",
+                typeName: "GVariant",
+                library: GLIB_LIB,
+                refFn: "g_variant_ref_sink",
+                unrefFn: "g_variant_unref",
+            },
+        ]);
+
+        const variant = call(GLIB_LIB, "g_variant_new_int32", [{ type: INT32, value: 42 }], {
+            type: "fundamental",
+            ownership: "full",
+            typeName: "GVariant",
+        });
+        const value = call(
+            GLIB_LIB,
+            "g_variant_get_int32",
+            [{ type: { type: "fundamental", ownership: "borrowed", typeName: "GVariant" }, value: variant }],
+            INT32,
+        );
+
+        expect(variant).toBeInstanceOf(NativeHandle);
+        expect(value).toBe(42);
+    });
+
+    it("uses registered callback signatures for native and JS arguments", () => {
+        registerTypes([
+            {
+                kind: "callback",
+                typeName: "TestCancelledHandler",
+                argTypes: [GOBJECT_BORROWED],
+                returnType: VOID,
+            },
+        ]);
+        const cancellable = createCancellable();
+        let received: unknown = null;
+
+        call(
+            GOBJECT_LIB,
+            "g_signal_connect_closure",
+            [
+                { type: GOBJECT_BORROWED, value: cancellable },
+                { type: STRING, value: "cancelled" },
+                {
+                    type: { type: "callback", kind: "closure", typeName: "TestCancelledHandler" },
+                    value: (instance: unknown) => {
+                        received = instance;
+                    },
+                },
+                { type: BOOLEAN, value: false },
+            ],
+            UINT64,
+        );
+        call(GIO_LIB, "g_cancellable_cancel", [{ type: GOBJECT_BORROWED, value: cancellable }], VOID);
+
+        expect(received).toBeInstanceOf(NativeHandle);
+        expect((received as NativeHandle).id).toBe((cancellable as NativeHandle).id);
+    });
+
+    it("exposes registered metadata", () => {
+        registerTypes([{ kind: "boxed", typeName: "GdkRGBA", library: GTK_LIB, getTypeFn: "gdk_rgba_get_type" }]);
+
+        expect(getTypeRegistration("GdkRGBA")).toEqual({
+            kind: "boxed",
+            typeName: "GdkRGBA",
+            library: GTK_LIB,
+            getTypeFn: "gdk_rgba_get_type",
+        });
+        expect(getTypeRegistration("GdkUnregistered")).toBeUndefined();
+    });
+
+    it("registers nothing when an entry is malformed", () => {
+        const entries = [
+            { kind: "boxed", typeName: "GtkBorder", library: GTK_LIB, getTypeFn: "gtk_border_get_type" },
+            { kind: "bogus", typeName: "GtkBogus" },
+        ] as unknown as Parameters<typeof registerTypes>[0];
+
+        expect(() => registerTypes(entries)).toThrow("Unknown type registration kind 'bogus'");
+        expect(getTypeRegistration("GtkBorder")).toBeUndefined();
+    });
+});
@@ -0,0 +1,155 @@
+import { describe, expect, it } from "vitest";
+import {
+    alloc,
+    blockHandler,
+    type CallbackType,
+    call,
+    connect,
+    disconnect,
+    type NativeHandle,
+    unblockHandler,
+    write,
+} from "../../index.js";
+import {
+    createCancellable,
+    createLabel,
+    FLOAT32,
+    GDK_LIB,
+    GIO_LIB,
+    GOBJECT_BORROWED,
+    GTK_LIB,
+    STRING_BORROWED,
+    VOID,
+} from "./utils.js";
+
+const NOTIFY: CallbackType = {
+    type: "callback",
+    kind: "closure",
+    argTypes: [GOBJECT_BORROWED],
+    returnType: VOID,
+};
+
+const setLabel = (label: unknown, text: string) => {
+    call(
+        GTK_LIB,
+        "gtk_label_set_label",
+        [
+            { type: GOBJECT_BORROWED, value: label },
+            { type: STRING_BORROWED, value: text },
+        ],
+        VOID,
+    );
+};
+
+const getLabel = (label: unknown): string =>
+    call(GTK_LIB, "gtk_label_get_label", [{ type: GOBJECT_BORROWED, value: label }], STRING_BORROWED) as string;
+
+const watchLabel = (label: unknown): { handlerId: number; labels: string[] } => {
+    const labels: string[] = [];
+    const handlerId = connect(label as NativeHandle, "notify::label", {
+        type: NOTIFY,
+        value: () => labels.push(getLabel(label)),
+    });
+    return { handlerId, labels };
+};
+
+describe("connect", () => {
+    it("returns a handler ID and invokes the callback on emission", () => {
+        const cancellable = createCancellable();
+        let received: unknown = null;
+
+        const handlerId = connect(cancellable as NativeHandle, "cancelled", {
+            type: { type: "callback", kind: "closure", argTypes: [GOBJECT_BORROWED], returnType: VOID },
+            value: (instance: unknown) => {
+                received = instance;
+            },
+        });
+        call(GIO_LIB, "g_cancellable_cancel", [{ type: GOBJECT_BORROWED, value: cancellable }], VOID);
+
+        expect(handlerId).toBeGreaterThan(0);
+        expect((received as NativeHandle).id).toBe((cancellable as NativeHandle).id);
+    });
+
+    it("only invokes the callback for the detail it was connected with", () => {
+        const label = createLabel("Initial");
+        const { labels } = watchLabel(label);
+
+        call(
+            GTK_LIB,
+            "gtk_label_set_selectable",
+            [
+                { type: GOBJECT_BORROWED, value: label },
+                { type: { type: "boolean" }, value: true },
+            ],
+            VOID,
+        );
+        setLabel(label, "Changed");
+
+        expect(labels).toEqual(["Changed"]);
+    });
+
+    it("throws for a signal the type does not have", () => {
+        const label = createLabel() as NativeHandle;
+
+        expect(() => connect(label, "no-such-signal", { type: NOTIFY, value: () => {} })).toThrow(
+            "has no signal 'no-such-signal'",
+        );
+    });
+
+    it("throws for a callback type that is not a closure", () => {
+        const label = createLabel() as NativeHandle;
+
+        expect(() =>
+            connect(label, "notify", { type: { type: "int32" } as unknown as CallbackType, value: () => {} }),
+        ).toThrow("closure callback type");
+    });
+
+    it("throws for a handle that is not a GObject", () => {
+        const rgba = alloc(16, "GdkRGBA", GDK_LIB);
+        for (const offset of [0, 4, 8, 12]) write(rgba, FLOAT32, offset, 1);
+
+        expect(() => connect(rgba, "notify", { type: NOTIFY, value: () => {} })).toThrow(
+            "Signal handlers need a GObject",
+        );
+        expect(() => disconnect(rgba, 1)).toThrow("Signal handlers need a GObject");
+        expect(() => blockHandler(rgba, 1)).toThrow("Signal handlers need a GObject");
+        expect(() => unblockHandler(rgba, 1)).toThrow("Signal handlers need a GObject");
+    });
+});
+
+describe("disconnect", () => {
+    it("stops the callback and reports whether the handler was connected", () => {
+        const label = createLabel("Initial");
+        const { handlerId, labels } = watchLabel(label);
+
+        expect(disconnect(label as NativeHandle, handlerId)).toBe(true);
+        setLabel(label, "Changed");
+
+        expect(labels).toEqual([]);
+        expect(disconnect(label as NativeHandle, handlerId)).toBe(false);
+    });
+});
+
+describe("blockHandler", () => {
+    it("skips the handler until it is unblocked as many times as it was blocked", () => {
+        const label = createLabel("Initial");
+        const { handlerId, labels } = watchLabel(label);
+
+        expect(blockHandler(label as NativeHandle, handlerId)).toBe(true);
+        blockHandler(label as NativeHandle, handlerId);
+        setLabel(label, "Blocked");
+        unblockHandler(label as NativeHandle, handlerId);
+        setLabel(label, "Still blocked");
+        expect(unblockHandler(label as NativeHandle, handlerId)).toBe(true);
+        setLabel(label, "Unblocked");
+
+        expect(labels).toEqual(["Unblocked"]);
+    });
+
+    it("returns false for a handler that is not connected", () => {
+        const label = createLabel() as NativeHandle;
+
+        expect(blockHandler(label, 0)).toBe(false);
+        expect(unblockHandler(label, 0)).toBe(false);
+    });
+});
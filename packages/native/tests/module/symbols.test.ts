@@ -0,0 +1,31 @@
+import { describe, expect, it } from "vitest";
+import { listSymbols } from "../../index.js";
+import { GTK_LIB } from "./utils.js";
+
+describe("listSymbols", () => {
+    it("lists exported functions with the prefix", () => {
+        const symbols = listSymbols(GTK_LIB, "gtk_label_");
+
+        expect(symbols).toContain("gtk_label_new");
+        expect(symbols).toContain("gtk_label_set_text");
+        expect(symbols.every((name) => name.startsWith("gtk_label_"))).toBe(true);
+    });
+
+    it("returns names sorted and without duplicates", () => {
+        const symbols = listSymbols("libglib-2.0.so.0", "g_strdup");
+
+        expect(symbols).toEqual([...new Set(symbols)].sort());
+    });
+
+    it("lists every function without a prefix", () => {
+        expect(listSymbols("libglib-2.0.so.0").length).toBeGreaterThan(1000);
+    });
+
+    it("uses the first library of a fallback list that loads", () => {
+        expect(listSymbols(`libmissing.so.0,${GTK_LIB}`, "gtk_init")).toContain("gtk_init");
+    });
+
+    it("throws for a library that cannot be loaded", () => {
+        expect(() => listSymbols("libmissing.so.0")).toThrow("Failed to load library");
+    });
+});
@@ -1,5 +1,5 @@
 use native::ffi;
-use native::types::{FloatKind, IntegerKind};
+use native::types::{FlagsType, FloatKind, IntegerKind, TaggedType};
 
 #[test]
 fn integer_dispatch_ffi_type_u8() {
@@ -138,3 +138,37 @@ fn float_dispatch_to_ffi_value_f64() {
         panic!("Expected FfiValue::F64");
     }
 }
+
+fn flags_type(storage: IntegerKind) -> FlagsType {
+    FlagsType {
+        tagged: TaggedType {
+            library: "libglib-2.0.so.0".to_owned(),
+            get_type_fn: "g_log_level_flags_get_type".to_owned(),
+        },
+        storage,
+        strict: false,
+    }
+}
+
+#[test]
+fn flags_mask_from_number_accepts_negative_signed_masks() {
+    let flags = flags_type(IntegerKind::I32);
+
+    assert_eq!(flags.mask_from_number(-4.0).unwrap(), 0xFFFF_FFFC);
+    assert_eq!(flags.mask_to_number(0xFFFF_FFFC), -4.0);
+}
+
+#[test]
+fn flags_mask_from_number_rejects_values_outside_the_storage() {
+    let signed = flags_type(IntegerKind::I32);
+    let unsigned = flags_type(IntegerKind::U32);
+
+    assert!(signed.mask_from_number(f64::from(u32::MAX)).is_err());
+    assert!(unsigned.mask_from_number(-4.0).is_err());
+    assert_eq!(unsigned.mask_from_number(f64::from(u32::MAX)).unwrap(), u32::MAX);
+}
+
+#[test]
+fn flags_mask_from_number_rejects_fractions() {
+    assert!(flags_type(IntegerKind::U32).mask_from_number(1.5).is_err());
+}
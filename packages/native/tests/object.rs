@@ -7,7 +7,10 @@ use gtk4::glib;
 use gtk4::prelude::{ObjectType as _, StaticType as _};
 
 use native::Boxed;
-use native::managed::{NativeHandle, NativeValue};
+use native::ffi::FfiValue;
+use native::managed::{self, NativeHandle, NativeValue, dependents::ParentKind};
+use native::types::{FfiDecoder as _, Ownership, StructType};
+use native::value::Value;
 
 fn create_test_gobject() -> glib::Object {
     common::ensure_gtk_init();
@@ -139,3 +142,100 @@ fn gobject_refcount_preserved_when_handle_owns() {
 
     assert!(after_ref >= initial_ref);
 }
+
+#[test]
+fn handles_dropped_off_thread_are_released_in_one_batch() {
+    let objects: Vec<glib::Object> = (0..16).map(|_| create_test_gobject()).collect();
+    let ref_count = |obj: &glib::Object| unsafe { (*obj.as_ptr()).ref_count };
+    let handles: Vec<NativeHandle> = objects
+        .iter()
+        .map(|obj| NativeValue::GObject(obj.clone()).into())
+        .collect();
+
+    std::thread::spawn(move || drop(handles))
+        .join()
+        .expect("drop thread panicked");
+
+    assert!(objects.iter().all(|obj| ref_count(obj) == 2));
+    assert_eq!(managed::drain_finalize_queue(), 16);
+    assert!(objects.iter().all(|obj| ref_count(obj) == 1));
+}
+
+fn borrowed_struct(backing: &mut [u8; 16]) -> NativeHandle {
+    let struct_type = StructType {
+        ownership: Ownership::Borrowed,
+        type_name: "TestRect".to_string(),
+        size: None,
+        fields: None,
+    };
+
+    let Ok(Value::Object(child)) =
+        struct_type.decode(&FfiValue::Ptr(backing.as_mut_ptr().cast()))
+    else {
+        panic!("Expected an object for a borrowed struct");
+    };
+    child
+}
+
+#[test]
+fn borrowed_struct_handle_is_invalidated_when_parent_is_finalized() {
+    let parent: NativeHandle = NativeValue::GObject(create_test_gobject()).into();
+    let mut backing = [0u8; 16];
+    let mut child = borrowed_struct(&mut backing);
+
+    assert!(child.bind_to_parent(parent.ptr(), ParentKind::Object));
+    assert!(child.ensure_alive().is_ok());
+
+    drop(parent);
+
+    assert!(!child.is_alive());
+    assert!(child.ensure_alive().is_err());
+}
+
+#[test]
+fn borrowed_struct_handle_outlives_handles_to_a_live_parent() {
+    let obj = create_test_gobject();
+    let parent: NativeHandle = NativeValue::GObject(obj.clone()).into();
+    let duplicate: NativeHandle = NativeValue::GObject(obj.clone()).into();
+    let mut backing = [0u8; 16];
+    let mut child = borrowed_struct(&mut backing);
+
+    assert!(child.bind_to_parent(parent.ptr(), ParentKind::Object));
+    drop(duplicate);
+    drop(parent);
+
+    assert!(child.is_alive());
+
+    drop(obj);
+
+    assert!(!child.is_alive());
+}
+
+#[test]
+fn borrowed_struct_handle_is_invalidated_when_boxed_parent_is_freed() {
+    let gtype = gdk::RGBA::static_type();
+    let ptr = common::allocate_test_boxed(gtype);
+    let parent: NativeHandle = NativeValue::Boxed(Boxed::from_glib_full(Some(gtype), ptr)).into();
+    let mut backing = [0u8; 16];
+    let mut child = borrowed_struct(&mut backing);
+
+    assert!(child.bind_to_parent(parent.ptr(), ParentKind::Boxed));
+    drop(parent.clone());
+
+    assert!(child.is_alive());
+
+    drop(parent);
+
+    assert!(!child.is_alive());
+}
+
+#[test]
+fn owned_handles_are_not_bound_to_parents() {
+    let parent: NativeHandle = NativeValue::GObject(create_test_gobject()).into();
+    let mut child: NativeHandle = NativeValue::GObject(create_test_gobject()).into();
+
+    assert!(!child.bind_to_parent(parent.ptr(), ParentKind::Object));
+    drop(parent);
+
+    assert!(child.is_alive());
+}
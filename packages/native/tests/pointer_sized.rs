@@ -0,0 +1,76 @@
+use native::ffi;
+use native::types::{FfiDecoder, FfiEncoder, PointerSizedType, RawPtrCodec};
+use native::value::Value;
+
+const UINTPTR: PointerSizedType = PointerSizedType { signed: false };
+const INTPTR: PointerSizedType = PointerSizedType { signed: true };
+
+#[test]
+fn encode_uintptr_above_safe_integer_range() {
+    let value = Value::BigInt(i128::from(u64::MAX));
+    let encoded = UINTPTR.encode(&value, false).unwrap();
+    assert!(matches!(encoded, ffi::FfiValue::U64(u64::MAX)));
+}
+
+#[test]
+fn encode_intptr_negative() {
+    let value = Value::BigInt(-42);
+    let encoded = INTPTR.encode(&value, false).unwrap();
+    assert!(matches!(encoded, ffi::FfiValue::I64(-42)));
+}
+
+#[test]
+fn encode_accepts_safe_integer_number() {
+    let encoded = UINTPTR.encode(&Value::Number(1234.0), false).unwrap();
+    assert!(matches!(encoded, ffi::FfiValue::U64(1234)));
+}
+
+#[test]
+fn encode_rejects_unsafe_number() {
+    let result = UINTPTR.encode(&Value::Number(2f64.powi(60)), false);
+    assert!(result.is_err());
+}
+
+#[test]
+fn encode_rejects_out_of_range() {
+    assert!(UINTPTR.encode(&Value::BigInt(-1), false).is_err());
+    assert!(
+        INTPTR
+            .encode(&Value::BigInt(i128::from(u64::MAX)), false)
+            .is_err()
+    );
+}
+
+#[test]
+fn decode_uintptr_is_exact() {
+    let decoded = UINTPTR
+        .decode(&ffi::FfiValue::U64((1 << 60) + 1))
+        .unwrap();
+    assert!(matches!(decoded, Value::BigInt(v) if v == (1 << 60) + 1));
+}
+
+#[test]
+fn raw_ptr_round_trip() {
+    let mut slot: u64 = 0;
+    let ptr = (&raw mut slot).cast::<std::ffi::c_void>();
+
+    UINTPTR
+        .write_value_to_raw_ptr(ptr, &Value::BigInt(i128::from(u64::MAX - 1)))
+        .unwrap();
+    assert_eq!(slot, u64::MAX - 1);
+
+    let read = UINTPTR.read_from_raw_ptr(ptr, "test").unwrap();
+    assert!(matches!(read, Value::BigInt(v) if v == i128::from(u64::MAX - 1)));
+}
+
+#[test]
+fn range_follows_pointer_width() {
+    assert!(UINTPTR.encode(&Value::BigInt(usize::MAX as i128), false).is_ok());
+    assert!(UINTPTR.encode(&Value::BigInt(usize::MAX as i128 + 1), false).is_err());
+    assert!(INTPTR.encode(&Value::BigInt(isize::MIN as i128), false).is_ok());
+    assert!(INTPTR.encode(&Value::BigInt(isize::MIN as i128 - 1), false).is_err());
+    assert_eq!(
+        UINTPTR.libffi_type().as_raw_ptr(),
+        libffi::middle::Type::usize().as_raw_ptr()
+    );
+}
@@ -1,6 +1,6 @@
 mod common;
 
-use native::state::GtkThreadState;
+use native::state::{GtkThreadState, LibraryBundle, LibraryOptions};
 
 #[test]
 fn gtk_thread_state_default_initializes_correctly() {
@@ -60,3 +60,55 @@ fn get_library_tries_comma_separated_names() {
 
     assert!(success);
 }
+
+#[test]
+fn library_options_try_search_paths_before_sonames() {
+    let options = LibraryOptions {
+        search_paths: vec!["/opt/app/lib".to_owned()],
+        sonames: vec!["libfoo.so.2".to_owned(), "/usr/lib/libfoo.so".to_owned()],
+        flags: None,
+    };
+
+    assert_eq!(
+        options.candidates("libfoo.so", None),
+        ["/opt/app/lib/libfoo.so.2", "libfoo.so.2", "/usr/lib/libfoo.so"]
+    );
+}
+
+#[test]
+fn library_options_default_to_comma_separated_names() {
+    assert_eq!(
+        LibraryOptions::default().candidates("liba.so.1,libb.so.1", None),
+        ["liba.so.1", "libb.so.1"]
+    );
+}
+
+#[test]
+fn library_options_search_bundle_after_own_paths() {
+    let bundle = LibraryBundle {
+        root: "/opt/app".into(),
+        lib_dirs: vec!["lib".to_owned()],
+    };
+    let options = LibraryOptions {
+        search_paths: vec!["plugins".to_owned()],
+        ..LibraryOptions::default()
+    };
+
+    assert_eq!(
+        options.candidates("libfoo.so.1", Some(&bundle)),
+        ["/opt/app/plugins/libfoo.so.1", "/opt/app/lib/libfoo.so.1", "libfoo.so.1"]
+    );
+}
+
+#[test]
+fn unload_library_forgets_the_handle() {
+    common::ensure_gtk_init();
+
+    GtkThreadState::with(|state| {
+        let _ = state.library("libgobject-2.0.so.0");
+
+        assert!(state.unload_library("libgobject-2.0.so.0", false));
+        assert!(!state.unload_library("libgobject-2.0.so.0", false));
+        assert!(state.library("libgobject-2.0.so.0").is_ok());
+    });
+}
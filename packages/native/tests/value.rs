@@ -4,14 +4,15 @@ use std::ffi::c_void;
 
 use gtk4::gdk;
 use gtk4::glib;
-use gtk4::glib::translate::IntoGlib as _;
+use gtk4::glib::translate::{IntoGlib as _, ToGlibPtr as _, ToGlibPtrMut as _};
+use gtk4::glib::value::ToValue as _;
 use gtk4::prelude::ObjectType as _;
 use gtk4::prelude::StaticType as _;
 
 use native::ffi;
 use native::types::{
     ArrayKind, ArrayType, BooleanType, BoxedType, GObjectType, Ownership, StringType, Type,
-    VoidType,
+    Utf8Policy, VoidType,
 };
 use native::value::Value;
 
@@ -125,6 +126,8 @@ fn string_transfer_none_does_not_free() {
     let string_type = StringType {
         ownership: Ownership::Borrowed,
         length: None,
+        utf8: Utf8Policy::Lossy,
+        gstring: false,
     };
     let type_ = Type::String(string_type);
 
@@ -153,6 +156,8 @@ fn string_full_transfer_frees_memory() {
     let string_type = StringType {
         ownership: Ownership::Full,
         length: None,
+        utf8: Utf8Policy::Lossy,
+        gstring: false,
     };
     let type_ = Type::String(string_type);
 
@@ -174,6 +179,8 @@ fn string_null_returns_null_value() {
     let string_type = StringType {
         ownership: Ownership::Full,
         length: None,
+        utf8: Utf8Policy::Lossy,
+        gstring: false,
     };
     let type_ = Type::String(string_type);
 
@@ -184,6 +191,46 @@ fn string_null_returns_null_value() {
     assert!(matches!(result.unwrap(), Value::Null));
 }
 
+fn invalid_utf8_string_type(utf8: Utf8Policy) -> (Type, std::ffi::CString) {
+    let string_type = StringType {
+        ownership: Ownership::Borrowed,
+        length: None,
+        utf8,
+    };
+    let c_string = std::ffi::CString::new(vec![b'a', 0xff, b'b']).unwrap();
+    (Type::String(string_type), c_string)
+}
+
+#[test]
+fn string_lossy_policy_replaces_invalid_utf8() {
+    let (type_, c_string) = invalid_utf8_string_type(Utf8Policy::Lossy);
+
+    let cif_value = ffi::FfiValue::Ptr(c_string.as_ptr() as *mut c_void);
+    let result = Value::from_ffi_value(&cif_value, &type_).unwrap();
+
+    assert!(matches!(result, Value::String(s) if s == "a\u{fffd}b"));
+}
+
+#[test]
+fn string_strict_policy_rejects_invalid_utf8() {
+    let (type_, c_string) = invalid_utf8_string_type(Utf8Policy::Strict);
+
+    let cif_value = ffi::FfiValue::Ptr(c_string.as_ptr() as *mut c_void);
+    let result = Value::from_ffi_value(&cif_value, &type_);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn string_buffer_policy_returns_raw_bytes() {
+    let (type_, c_string) = invalid_utf8_string_type(Utf8Policy::Buffer);
+
+    let cif_value = ffi::FfiValue::Ptr(c_string.as_ptr() as *mut c_void);
+    let result = Value::from_ffi_value(&cif_value, &type_).unwrap();
+
+    assert!(matches!(result, Value::Bytes(b) if b == [b'a', 0xff, b'b']));
+}
+
 #[test]
 fn boxed_transfer_none_creates_copy() {
     common::ensure_gtk_init();
@@ -381,6 +428,8 @@ fn strv_transfer_none_does_not_free() {
     let string_type = StringType {
         ownership: Ownership::Borrowed,
         length: None,
+        utf8: Utf8Policy::Lossy,
+        gstring: false,
     };
     let array_type = ArrayType {
         item_type: Box::new(Type::String(string_type)),
@@ -432,6 +481,8 @@ fn strv_full_transfer_frees_strings() {
     let string_type = StringType {
         ownership: Ownership::Full,
         length: None,
+        utf8: Utf8Policy::Lossy,
+        gstring: false,
     };
     let array_type = ArrayType {
         item_type: Box::new(Type::String(string_type)),
@@ -485,6 +536,8 @@ fn from_glib_value_string() {
     let string_type = StringType {
         ownership: Ownership::Borrowed,
         length: None,
+        utf8: Utf8Policy::Lossy,
+        gstring: false,
     };
     let type_ = Type::String(string_type);
 
@@ -843,6 +896,53 @@ fn into_glib_value_with_default_regular_value() {
     assert!(result.is_some());
 }
 
+#[test]
+fn into_glib_value_with_default_coerces_booleans_and_numbers() {
+    common::ensure_gtk_init();
+
+    let int_type = Type::Integer(native::types::IntegerKind::I32);
+    let from_boolean = Value::Boolean(true).into_glib_value_with_default(Some(&int_type));
+    let from_number =
+        Value::Number(2.0).into_glib_value_with_default(Some(&Type::Boolean(BooleanType)));
+
+    assert_eq!(from_boolean.unwrap().get::<i32>().unwrap(), 1);
+    assert!(from_number.unwrap().get::<bool>().unwrap());
+}
+
+#[test]
+fn into_glib_value_with_default_falls_back_to_zero_on_mismatch() {
+    common::ensure_gtk_init();
+
+    let int_type = Type::Integer(native::types::IntegerKind::I32);
+    let result = Value::String("five".to_string()).into_glib_value_with_default(Some(&int_type));
+
+    assert_eq!(result.unwrap().get::<i32>().unwrap(), 0);
+}
+
+#[test]
+fn into_glib_value_with_default_null_boxed_is_typed() {
+    common::ensure_gtk_init();
+
+    let rgba_type = gdk::RGBA::static_type();
+    let boxed_type = Type::Boxed(BoxedType {
+        ownership: Ownership::Borrowed,
+        type_name: "GdkRGBA".to_string(),
+        library: None,
+        get_type_fn: None,
+    });
+    let result = Value::Null.into_glib_value_with_default(Some(&boxed_type)).unwrap();
+
+    assert_eq!(result.type_(), rgba_type);
+    assert!(result.get::<Option<gdk::RGBA>>().unwrap().is_none());
+}
+
+#[test]
+fn into_glib_value_with_default_void_returns_nothing() {
+    let result = Value::Number(1.0).into_glib_value_with_default(Some(&Type::Void(VoidType)));
+
+    assert!(result.is_none());
+}
+
 #[test]
 fn glist_with_string_items() {
     common::ensure_gtk_init();
@@ -857,6 +957,8 @@ fn glist_with_string_items() {
     let string_type = StringType {
         ownership: Ownership::Borrowed,
         length: None,
+        utf8: Utf8Policy::Lossy,
+        gstring: false,
     };
     let array_type = ArrayType {
         item_type: Box::new(Type::String(string_type)),
@@ -897,6 +999,7 @@ fn from_cif_value_struct_transfer_none_logs_warning() {
         ownership: Ownership::Borrowed,
         type_name: "TestRect".to_string(),
         size: Some(16),
+        fields: None,
     };
     let type_ = Type::Struct(struct_type);
 
@@ -924,6 +1027,7 @@ fn from_cif_value_struct_full_transfer() {
         ownership: Ownership::Full,
         type_name: "CustomStruct".to_string(),
         size: Some(32),
+        fields: None,
     };
     let type_ = Type::Struct(struct_type);
 
@@ -945,6 +1049,7 @@ fn from_cif_value_struct_null_returns_null_value() {
         ownership: Ownership::Borrowed,
         type_name: "TestStruct".to_string(),
         size: Some(16),
+        fields: None,
     };
     let type_ = Type::Struct(struct_type);
 
@@ -965,6 +1070,7 @@ fn from_glib_value_struct_fails() {
         ownership: Ownership::Borrowed,
         type_name: "PlainStruct".to_string(),
         size: Some(16),
+        fields: None,
     };
     let type_ = Type::Struct(struct_type);
 
@@ -983,6 +1089,7 @@ fn from_cif_value_struct_transfer_none_without_size_creates_unowned() {
         ownership: Ownership::Borrowed,
         type_name: "UnknownSizeStruct".to_string(),
         size: None,
+        fields: None,
     };
     let type_ = Type::Struct(struct_type);
 
@@ -1010,6 +1117,7 @@ fn from_cif_value_struct_owned_without_size() {
         ownership: Ownership::Full,
         type_name: "UnknownSizeStruct".to_string(),
         size: None,
+        fields: None,
     };
     let type_ = Type::Struct(struct_type);
 
@@ -1022,3 +1130,110 @@ fn from_cif_value_struct_owned_without_size() {
         panic!("Expected Value::Object for struct");
     }
 }
+
+fn new_gvalue_array(values: &[glib::Value]) -> *mut glib::gobject_ffi::GValueArray {
+    let array = unsafe { glib::gobject_ffi::g_value_array_new(values.len() as u32) };
+    for value in values {
+        unsafe { glib::gobject_ffi::g_value_array_append(array, value.to_glib_none().0) };
+    }
+    array
+}
+
+fn gvalue_array_type(item_type: Type, ownership: Ownership) -> Type {
+    Type::Array(ArrayType {
+        item_type: Box::new(item_type),
+        kind: ArrayKind::GValueArray,
+        ownership,
+        element_size: None,
+    })
+}
+
+#[test]
+fn gvaluearray_converts_elements_by_their_type() {
+    common::ensure_gtk_init();
+
+    let array = new_gvalue_array(&[42i32.to_value(), "text".to_value(), true.to_value()]);
+    let type_ = gvalue_array_type(Type::Void(VoidType), Ownership::Full);
+
+    let result = Value::from_ffi_value(&ffi::FfiValue::Ptr(array.cast()), &type_).unwrap();
+
+    let Value::Array(items) = result else {
+        panic!("Expected Value::Array");
+    };
+    assert!(matches!(items[0], Value::Number(n) if n == 42.0));
+    assert!(matches!(&items[1], Value::String(s) if s == "text"));
+    assert!(matches!(items[2], Value::Boolean(true)));
+}
+
+#[test]
+fn gvaluearray_uses_declared_item_type() {
+    common::ensure_gtk_init();
+
+    let array = new_gvalue_array(&[1u64.to_value(), 2u64.to_value()]);
+    let type_ = gvalue_array_type(
+        Type::Integer(native::types::IntegerKind::U64),
+        Ownership::Borrowed,
+    );
+
+    let result = Value::from_ffi_value(&ffi::FfiValue::Ptr(array.cast()), &type_).unwrap();
+
+    let Value::Array(items) = result else {
+        panic!("Expected Value::Array");
+    };
+    assert_eq!(items.len(), 2);
+    assert!(matches!(items[1], Value::Number(n) if n == 2.0));
+
+    unsafe { glib::gobject_ffi::g_value_array_free(array) };
+}
+
+#[test]
+fn gvaluearray_rejects_mismatched_item_type() {
+    common::ensure_gtk_init();
+
+    let array = new_gvalue_array(&["text".to_value()]);
+    let type_ = gvalue_array_type(Type::Integer(native::types::IntegerKind::I32), Ownership::Full);
+
+    let result = Value::from_ffi_value(&ffi::FfiValue::Ptr(array.cast()), &type_);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn gvaluearray_from_glib_value_converts_nested_arrays() {
+    common::ensure_gtk_init();
+
+    let inner = new_gvalue_array(&[1i32.to_value()]);
+    let mut inner_gvalue = glib::Value::from_type(glib::Type::from_name("GValueArray").unwrap());
+    unsafe {
+        glib::gobject_ffi::g_value_take_boxed(inner_gvalue.to_glib_none_mut().0, inner.cast());
+    }
+    let outer = new_gvalue_array(&["outer".to_value(), inner_gvalue]);
+    let mut gvalue = glib::Value::from_type(glib::Type::from_name("GValueArray").unwrap());
+    unsafe {
+        glib::gobject_ffi::g_value_take_boxed(gvalue.to_glib_none_mut().0, outer.cast());
+    }
+
+    let type_ = gvalue_array_type(Type::Void(VoidType), Ownership::Borrowed);
+
+    let result = Value::from_glib_value(&gvalue, &type_).unwrap();
+
+    let Value::Array(items) = result else {
+        panic!("Expected Value::Array");
+    };
+    assert!(matches!(&items[0], Value::String(s) if s == "outer"));
+    let Value::Array(nested) = &items[1] else {
+        panic!("Expected a nested Value::Array");
+    };
+    assert!(matches!(nested[0], Value::Number(n) if n == 1.0));
+}
+
+#[test]
+fn value_try_from_gvalue_rejects_pointers() {
+    common::ensure_gtk_init();
+
+    let gvalue = glib::Value::from_type(glib::types::Type::POINTER);
+
+    let result = Value::try_from(&gvalue);
+
+    assert!(result.is_err());
+}
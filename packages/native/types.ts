@@ -6,7 +6,7 @@ import type { NativeHandle } from "./index.js";
  * Returned by `call()` and `read()` where the concrete type
  * depends on the type descriptor passed to the function.
  */
-export type FfiValue = NativeHandle | number | string | boolean | FfiValue[] | null | undefined;
+export type FfiValue = NativeHandle | number | bigint | string | boolean | FfiValue[] | null | undefined;
 
 type Int8Type = { type: "int8" };
 type Uint8Type = { type: "uint8" };
@@ -14,40 +14,148 @@ type Int16Type = { type: "int16" };
 type Uint16Type = { type: "uint16" };
 type Int32Type = { type: "int32" };
 type Uint32Type = { type: "uint32" };
+/**
+ * `gint64`; decoded as a `number`, or as a `bigint` beyond 2^53 where a
+ * `number` would lose precision. Encoded from a `bigint` or safe-integer `number`.
+ */
 type Int64Type = { type: "int64" };
+/**
+ * `guint64`; decoded as a `number`, or as a `bigint` beyond 2^53 where a
+ * `number` would lose precision. Encoded from a `bigint` or safe-integer `number`.
+ */
 type Uint64Type = { type: "uint64" };
+/** `gintptr`; decoded as a `bigint`, encoded from a `bigint` or safe-integer `number`. */
+type IntPtrType = { type: "intptr" };
+/** `guintptr`; decoded as a `bigint`, encoded from a `bigint` or safe-integer `number`. */
+type UintPtrType = { type: "uintptr" };
 
 type Float32Type = { type: "float32" };
 type Float64Type = { type: "float64" };
 
-type EnumType = { type: "enum"; library: string; getTypeFn: string; signed: boolean };
-type FlagsType = { type: "flags"; library: string; getTypeFn: string; signed: boolean };
+/**
+ * `library` and `getTypeFn` locate the type's `GType`. Both may be omitted
+ * when `typeName` names a type registered with `registerTypes`.
+ */
+type EnumType = {
+    type: "enum";
+    library?: string;
+    getTypeFn?: string;
+    typeName?: string;
+    signed: boolean;
+    /** Reject values not defined by the registered enum type. */
+    strict?: boolean;
+};
+/** See {@link EnumType} for how the flags type is located. */
+export type FlagsType = {
+    type: "flags";
+    library?: string;
+    getTypeFn?: string;
+    typeName?: string;
+    signed: boolean;
+    /** Reject masks with bits not defined by the registered flags type. */
+    strict?: boolean;
+};
 
 type BooleanType = { type: "boolean" };
 
 type Ownership = "full" | "borrowed";
 
-type StringType = { type: "string"; ownership: Ownership; length?: number };
+type StringType = {
+    type: "string";
+    ownership: Ownership;
+    /**
+     * Byte length of the buffer. On input, the value (a string or `Buffer`)
+     * is passed as raw bytes, so embedded NULs are allowed; a value longer
+     * than this length is rejected. For `Ref<string>` out-parameters, the
+     * size to allocate.
+     */
+    length?: number;
+    /**
+     * Handling of returned strings that are not valid UTF-8: replace invalid
+     * sequences (`"lossy"`, the default), throw (`"strict"`), or return the
+     * raw bytes as a `Buffer` (`"buffer"`).
+     */
+    utf8?: "lossy" | "strict" | "buffer";
+    /**
+     * The value is a `GString*` rather than a `char*`. Its counted contents
+     * are read, embedded NULs included, and owned values are released with
+     * `g_string_free`.
+     */
+    gstring?: boolean;
+};
 
 type GObjectType = { type: "gobject"; ownership: Ownership };
 
+/**
+ * A `GBytes*`, passed from a `Buffer`, `Uint8Array` or string (as UTF-8) and
+ * returned as a `Buffer`.
+ */
+type GBytesType = {
+    type: "gbytes";
+    ownership: Ownership;
+    /**
+     * Return a `Buffer` over the `GBytes` contents, holding a reference on
+     * it until garbage collected, instead of a copy. Node allows only one
+     * such buffer per memory region, so use it only for `GBytes` read once.
+     */
+    view?: boolean;
+};
+
+/**
+ * A `GValue*`. Takes a native handle to an existing `GValue`, or any other
+ * value, which is put in a new `GValue`. Returned as a native handle owning
+ * a copy.
+ */
+type GValueType = {
+    type: "gvalue";
+    ownership: Ownership;
+    /** Type converting a value to the `GValue` contents; inferred from the value when omitted. */
+    valueType?: Type;
+    /** `GType` name to convert the contents to, such as an enum or object type. */
+    typeName?: string;
+};
+
 type BoxedType = { type: "boxed"; ownership: Ownership; innerType: string; library?: string; getTypeFn?: string };
 
-type StructType = { type: "struct"; ownership: Ownership; innerType: string; size?: number };
+/** A named field of a struct layout. */
+export type StructField = {
+    name: string;
+    type: Type;
+    /** Byte offset from the start of the struct. Defaults to just past the previous field, aligned as in C. */
+    offset?: number;
+};
+
+/**
+ * A pointer to a plain struct. With `fields`, its layout: fields can be read
+ * and written by name, and `size` defaults to the laid out size. A struct
+ * with `fields` used as a field type is embedded in its parent.
+ */
+type StructType = { type: "struct"; ownership: Ownership; innerType: string; size?: number; fields?: StructField[] };
 
+/** `library`, `refFn` and `unrefFn` may be omitted when `typeName` is registered with `registerTypes`. */
 type FundamentalType = {
     type: "fundamental";
     ownership: Ownership;
-    library: string;
-    refFn: string;
-    unrefFn: string;
+    library?: string;
+    refFn?: string;
+    unrefFn?: string;
     typeName?: string;
 };
 
+/**
+ * Arrays of `uint8` items (`"array"`, `"sized"` or `"fixed"`) and
+ * `"gbytearray"`s also accept a `Buffer`, `Uint8Array` or `ArrayBuffer`,
+ * copied in one block instead of element by element. Sized and fixed
+ * `uint8` arrays are returned as a `Buffer`.
+ */
 export type ArrayType = {
     type: "array";
+    /**
+     * Type of each element. For `"gvaluearray"`, `{ type: "void" }` converts
+     * each element by the type its `GValue` holds.
+     */
     itemType: Type;
-    kind: "array" | "glist" | "gslist" | "gptrarray" | "garray" | "gbytearray" | "sized" | "fixed";
+    kind: "array" | "glist" | "gslist" | "gptrarray" | "garray" | "gbytearray" | "gvaluearray" | "sized" | "fixed";
     ownership: Ownership;
     elementSize?: number;
     sizeParamIndex?: number;
@@ -65,22 +173,90 @@ export type RefType = { type: "ref"; innerType: Type };
 
 type UnicharType = { type: "unichar" };
 
+/**
+ * A trailing `GError **error` argument, passed with a `null` value. If the
+ * function reports an error, the call throws an `Error` with the error's
+ * `message`, its `domain` quark and its `code` instead of returning.
+ */
+type GErrorType = { type: "gerror" };
+
 type VoidType = { type: "void" };
 
+/** Delivery lane for closure calls on the JS thread. Higher lanes are delivered first. */
+export type CallbackPriority = "high" | "default" | "low";
+
+/**
+ * How a closure sheds emissions while the JS thread is behind: `"drop"`
+ * discards them, `"coalesce"` keeps only the latest and delivers it once.
+ */
+export type CallbackOverflow = "drop" | "coalesce";
+
 export type CallbackType = {
     type: "callback";
     kind: "closure";
-    argTypes: Type[];
-    returnType: Type;
+    /** Omitted, with `returnType`, when `typeName` names a callback registered with `registerTypes`. */
+    argTypes?: Type[];
+    returnType?: Type;
+    typeName?: string;
+    /**
+     * Deliver emissions without blocking the emitting thread, collapsing
+     * bursts into one call with the latest arguments. Requires a void return type.
+     */
+    coalesce?: boolean;
+    /** Pass the emitting instance as the last argument instead of the first (`g_cclosure_new_swap`). */
+    swapped?: boolean;
+    /**
+     * Index of the call argument holding a `GObject` that bounds the
+     * closure's lifetime (`g_cclosure_new_object`). Finalizing the object
+     * invalidates the closure; see `isClosureInvalidated`.
+     */
+    watchArg?: number;
+    /**
+     * Read arguments straight from the emission and reuse argument storage
+     * across calls. For hot void signals such as list item factory `bind`.
+     * Arguments must be `gobject`, `boolean`, `int32`, `uint32` or `float64`;
+     * cannot be combined with `coalesce`.
+     */
+    fastArgs?: boolean;
+    /**
+     * Lane the closure's calls are queued in on the JS thread. Use `"high"`
+     * for input handlers and `"low"` for work such as logging. Defaults to
+     * `"default"`.
+     */
+    priority?: CallbackPriority;
+    /**
+     * Shed emissions once the JS queue reaches the backpressure threshold
+     * (see `setBackpressureThreshold`). For low-importance floods such as
+     * motion or `value-changed`. Requires a void return type; cannot be
+     * combined with `coalesce`.
+     */
+    overflow?: CallbackOverflow;
 };
 
 export type TrampolineType = {
     type: "trampoline";
-    argTypes: Type[];
-    returnType: Type;
+    /** Omitted, with `returnType`, when `typeName` names a callback registered with `registerTypes`. */
+    argTypes?: Type[];
+    returnType?: Type;
+    typeName?: string;
     hasDestroy?: boolean;
     userDataIndex?: number;
     scope?: "call" | "notified" | "async" | "forever";
+    /**
+     * `"compare"` for `GCompareFunc`-style comparators such as list box sort
+     * functions: the callback's number result is reduced to -1, 0 or 1, so
+     * fractional differences still order. Requires an integer return type.
+     *
+     * `"filter"` for predicates such as custom filter functions: the
+     * callback's result is converted to a boolean by its truthiness.
+     * Requires a boolean return type.
+     *
+     * `"source"` for `GSourceFunc`s such as timeout and idle callbacks: the
+     * source continues only when the callback returns `true` or a non-zero
+     * number, and is removed when it returns anything else or throws.
+     * Requires a boolean return type.
+     */
+    kind?: "default" | "compare" | "filter" | "source";
 };
 
 /**
@@ -97,6 +273,8 @@ export type Type =
     | Uint32Type
     | Int64Type
     | Uint64Type
+    | IntPtrType
+    | UintPtrType
     | Float32Type
     | Float64Type
     | EnumType
@@ -104,6 +282,8 @@ export type Type =
     | BooleanType
     | StringType
     | GObjectType
+    | GBytesType
+    | GValueType
     | BoxedType
     | StructType
     | FundamentalType
@@ -113,6 +293,7 @@ export type Type =
     | CallbackType
     | TrampolineType
     | UnicharType
+    | GErrorType
     | VoidType;
 
 /**
@@ -138,3 +319,14 @@ export type Ref<T> = {
     readonly __brand: "Ref";
     value: T;
 };
+
+/**
+ * Metadata for a named type, registered with `registerTypes` so
+ * descriptors can refer to the type by name alone.
+ *
+ * `typeName` is the type's `GType` name, or its C type name for callbacks.
+ */
+export type TypeRegistration =
+    | { kind: "boxed" | "enum" | "flags"; typeName: string; library: string; getTypeFn: string }
+    | { kind: "fundamental"; typeName: string; library: string; refFn: string; unrefFn: string }
+    | { kind: "callback"; typeName: string; argTypes: Type[]; returnType: Type };
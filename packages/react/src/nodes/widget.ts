@@ -1,8 +1,10 @@
 import { getNativeObject, type NativeObject } from "@gtkx/ffi";
 import type * as GObject from "@gtkx/ffi/gobject";
 import {
+    freezeNotify,
     ObjectClass,
     ParamSpecString,
+    thawNotify,
     Type,
     TypeInstance,
     typeClassRef,
@@ -207,13 +209,19 @@ export class WidgetNode<
     private applyPendingProperties(
         pendingProperties: Array<{ name: string; oldValue: unknown; newValue: unknown }>,
     ): void {
-        for (const { name, oldValue, newValue } of pendingProperties) {
-            if (name === "text" && oldValue !== undefined && isEditable(this.container)) {
-                if (oldValue !== this.container.getText()) {
-                    continue;
+        const batched = pendingProperties.length > 1;
+        if (batched) freezeNotify(this.container.handle);
+        try {
+            for (const { name, oldValue, newValue } of pendingProperties) {
+                if (name === "text" && oldValue !== undefined && isEditable(this.container)) {
+                    if (oldValue !== this.container.getText()) {
+                        continue;
+                    }
                 }
+                this.setProperty(name, newValue);
             }
-            this.setProperty(name, newValue);
+        } finally {
+            if (batched) thawNotify(this.container.handle);
         }
     }
 
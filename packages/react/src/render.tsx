@@ -1,4 +1,5 @@
 import { initRuntime, stop } from "@gtkx/ffi";
+import { forwardActivation, watchActivations } from "@gtkx/ffi/gio";
 import type * as Gtk from "@gtkx/ffi/gtk";
 import { createContext, type ReactNode, useContext } from "react";
 import { toGtkxError } from "./errors.js";
@@ -59,6 +60,11 @@ let container: unknown = null;
  * role of `createRoot().render()` in `react-dom`: call once at module
  * top-level in your entry file.
  *
+ * Unless the application is `NON_UNIQUE`, a second launch forwards its
+ * arguments to the running instance and exits without rendering; the
+ * running instance receives them as an `"activated"` event on `events`
+ * from `@gtkx/ffi`.
+ *
  * In the dev server, the entry module runs once per process. Component-level
  * edits are applied via React Refresh; edits that propagate up to the entry
  * trigger a process restart so this function still runs at most once per
@@ -88,6 +94,11 @@ let container: unknown = null;
 export const render = (element: ReactNode, app: Gtk.Application): void => {
     initRuntime();
     app.register(null);
+    if (forwardActivation(app)) {
+        stop();
+        return;
+    }
+    watchActivations(app);
     app.activate();
 
     container = reconciler.createContainer(
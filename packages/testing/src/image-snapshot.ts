@@ -0,0 +1,221 @@
+import { existsSync, mkdirSync, writeFileSync } from "node:fs";
+import { dirname } from "node:path";
+import * as Gdk from "@gtkx/ffi/gdk";
+import * as GLib from "@gtkx/ffi/glib";
+import type * as Gtk from "@gtkx/ffi/gtk";
+import { captureTexture, type ScreenshotOptions } from "./screenshot.js";
+
+/**
+ * Options for comparing a widget against a reference image.
+ */
+export type ImageSnapshotOptions = ScreenshotOptions & {
+    /**
+     * Largest difference in any color channel (0–255) for two pixels to
+     * still count as equal. Absorbs antialiasing noise between renderers.
+     * @default 0
+     */
+    threshold?: number;
+    /**
+     * Fraction of pixels (0–1) allowed to differ before the comparison fails.
+     * @default 0
+     */
+    tolerance?: number;
+    /**
+     * Overwrite the reference image with the rendered widget instead of
+     * comparing. A missing reference image is always written.
+     * @default false
+     */
+    update?: boolean;
+};
+
+/**
+ * Result of comparing a widget against a reference image.
+ */
+export type ImageSnapshotResult = {
+    /** Whether the differing pixels stay within the tolerance */
+    pass: boolean;
+    /** Whether the reference image was written instead of compared */
+    written: boolean;
+    /** Number of pixels that differ beyond the threshold */
+    diffPixels: number;
+    /** Fraction of pixels that differ beyond the threshold */
+    diffRatio: number;
+    /**
+     * PNG image highlighting differing pixels in red over a faded copy of
+     * the reference. Null if the images were not compared or their sizes differ.
+     */
+    diff: Buffer | null;
+    /** Width of the rendered widget in pixels */
+    width: number;
+    /** Height of the rendered widget in pixels */
+    height: number;
+};
+
+type Pixels = {
+    data: number[];
+    stride: number;
+    width: number;
+    height: number;
+};
+
+const readPixels = (texture: Gdk.Texture): Pixels => {
+    const downloader = new Gdk.TextureDownloader(texture);
+    downloader.setFormat(Gdk.MemoryFormat.R8G8B8A8);
+    const [bytes, stride] = downloader.downloadBytes();
+
+    return {
+        data: bytes.getData() ?? [],
+        stride,
+        width: texture.getWidth(),
+        height: texture.getHeight(),
+    };
+};
+
+const encodePng = (data: number[], width: number, height: number): Buffer => {
+    const texture = new Gdk.MemoryTexture(width, height, Gdk.MemoryFormat.R8G8B8A8, new GLib.Bytes(data), width * 4);
+    return Buffer.from(texture.saveToPngBytes().getData() ?? []);
+};
+
+const writeReference = (texture: Gdk.Texture, path: string): void => {
+    mkdirSync(dirname(path), { recursive: true });
+    writeFileSync(path, Buffer.from(texture.saveToPngBytes().getData() ?? []));
+};
+
+const comparePixels = (actual: Pixels, expected: Pixels, threshold: number): { diffPixels: number; diff: number[] } => {
+    const { width, height } = actual;
+    const diff = new Array<number>(width * height * 4);
+    let diffPixels = 0;
+
+    for (let y = 0; y < height; y++) {
+        for (let x = 0; x < width; x++) {
+            const a = y * actual.stride + x * 4;
+            const e = y * expected.stride + x * 4;
+            const d = (y * width + x) * 4;
+            let delta = 0;
+
+            for (let channel = 0; channel < 4; channel++) {
+                delta = Math.max(delta, Math.abs((actual.data[a + channel] ?? 0) - (expected.data[e + channel] ?? 0)));
+            }
+
+            if (delta > threshold) {
+                diffPixels++;
+                diff[d] = 255;
+                diff[d + 1] = 0;
+                diff[d + 2] = 0;
+                diff[d + 3] = 255;
+            } else {
+                const red = expected.data[e] ?? 0;
+                const green = expected.data[e + 1] ?? 0;
+                const blue = expected.data[e + 2] ?? 0;
+                const gray = Math.round(0.299 * red + 0.587 * green + 0.114 * blue);
+                diff[d] = gray;
+                diff[d + 1] = gray;
+                diff[d + 2] = gray;
+                diff[d + 3] = 64;
+            }
+        }
+    }
+
+    return { diffPixels, diff };
+};
+
+/**
+ * Renders a widget and compares it pixel by pixel against a reference PNG.
+ *
+ * If the reference image does not exist yet, or `update` is set, the
+ * rendered widget is written to `referencePath` and the comparison passes.
+ * Images of different sizes never match.
+ *
+ * @param widget - The widget to render
+ * @param referencePath - Path of the reference PNG
+ * @param options - Matching tolerances and capture timing
+ * @returns The comparison result, including a diff image when compared
+ * @throws Error if the widget cannot be rendered or the reference cannot be loaded
+ *
+ * @example
+ * ```tsx
+ * import { compareImageSnapshot, render } from "@gtkx/testing";
+ *
+ * const { container } = await render(<Badge count={3} />);
+ * const result = await compareImageSnapshot(container, "tests/__images__/badge.png", { threshold: 8 });
+ * if (result.diff) writeFileSync("badge.diff.png", result.diff);
+ * expect(result.pass).toBe(true);
+ * ```
+ */
+export const compareImageSnapshot = async (
+    widget: Gtk.Widget,
+    referencePath: string,
+    options?: ImageSnapshotOptions,
+): Promise<ImageSnapshotResult> => {
+    const texture = await captureTexture(widget, options);
+    const width = texture.getWidth();
+    const height = texture.getHeight();
+
+    if (options?.update || !existsSync(referencePath)) {
+        writeReference(texture, referencePath);
+        return { pass: true, written: true, diffPixels: 0, diffRatio: 0, diff: null, width, height };
+    }
+
+    const actual = readPixels(texture);
+    const expected = readPixels(Gdk.Texture.newFromFilename(referencePath));
+    const totalPixels = width * height;
+
+    if (expected.width !== width || expected.height !== height) {
+        return { pass: false, written: false, diffPixels: totalPixels, diffRatio: 1, diff: null, width, height };
+    }
+
+    const { diffPixels, diff } = comparePixels(actual, expected, options?.threshold ?? 0);
+    const diffRatio = totalPixels === 0 ? 0 : diffPixels / totalPixels;
+
+    return {
+        pass: diffRatio <= (options?.tolerance ?? 0),
+        written: false,
+        diffPixels,
+        diffRatio,
+        diff: encodePng(diff, width, height),
+        width,
+        height,
+    };
+};
+
+/**
+ * Asserts that a widget renders like a reference PNG.
+ *
+ * Behaves like {@link compareImageSnapshot}, but throws when the images
+ * differ beyond the tolerance. The thrown error carries the comparison
+ * result in its `cause`, including the diff image.
+ *
+ * @param widget - The widget to render
+ * @param referencePath - Path of the reference PNG
+ * @param options - Matching tolerances and capture timing
+ * @returns The comparison result
+ * @throws Error if the images differ beyond the tolerance
+ *
+ * @example
+ * ```tsx
+ * import { expectImageSnapshot, render } from "@gtkx/testing";
+ *
+ * const { container } = await render(<Toolbar />);
+ * await expectImageSnapshot(container, "tests/__images__/toolbar.png", {
+ *     threshold: 8,
+ *     tolerance: 0.001,
+ *     update: process.env.UPDATE_SNAPSHOTS === "1",
+ * });
+ * ```
+ */
+export const expectImageSnapshot = async (
+    widget: Gtk.Widget,
+    referencePath: string,
+    options?: ImageSnapshotOptions,
+): Promise<ImageSnapshotResult> => {
+    const result = await compareImageSnapshot(widget, referencePath, options);
+
+    if (!result.pass) {
+        const reason = result.diff
+            ? `${result.diffPixels} pixels (${(result.diffRatio * 100).toFixed(2)}%) differ`
+            : `rendered size ${result.width}x${result.height} differs from the reference`;
+        throw new Error(`Widget does not match reference image ${referencePath}: ${reason}`, { cause: result });
+    }
+
+    return result;
+};
@@ -1,6 +1,10 @@
 export type { Config } from "./config.js";
 export { configure, getConfig } from "./config.js";
 export { fireEvent } from "./fire-event.js";
+export type { ImageSnapshotOptions, ImageSnapshotResult } from "./image-snapshot.js";
+export { compareImageSnapshot, expectImageSnapshot } from "./image-snapshot.js";
+export type { InjectedEvent } from "./inject-event.js";
+export { injectEvent } from "./inject-event.js";
 export type { PrettyWidgetOptions } from "./pretty-widget.js";
 export { prettyWidget } from "./pretty-widget.js";
 export {
@@ -28,7 +32,7 @@ export { getRoles, logRoles, prettyRoles } from "./role-helpers.js";
 export { screen } from "./screen.js";
 export type { ScreenshotOptions } from "./screenshot.js";
 export { screenshot } from "./screenshot.js";
-export { tick } from "./timing.js";
+export { tick, waitForDraw } from "./timing.js";
 export type { Container } from "./traversal.js";
 export type {
     BoundQueries,
@@ -45,7 +49,7 @@ export type {
     WaitForOptions,
     WrapperComponent,
 } from "./types.js";
-export type { PointerInput, TabOptions } from "./user-event.js";
+export type { PointerInput, PointerOptions, TabOptions } from "./user-event.js";
 export { userEvent } from "./user-event.js";
 export { waitFor, waitForElementToBeRemoved } from "./wait-for.js";
 export { within } from "./within.js";
@@ -0,0 +1,239 @@
+import type * as Gdk from "@gtkx/ffi/gdk";
+import { signalEmitv, signalLookup, typeFromName, Value } from "@gtkx/ffi/gobject";
+import * as Graphene from "@gtkx/ffi/graphene";
+import * as Gtk from "@gtkx/ffi/gtk";
+import { tick } from "./timing.js";
+
+/**
+ * A synthesized input event for {@link injectEvent}.
+ *
+ * Pointer coordinates are relative to the widget the event is injected into.
+ */
+export type InjectedEvent =
+    | {
+          type: "motion";
+          x: number;
+          y: number;
+      }
+    | {
+          type: "press" | "release";
+          x: number;
+          y: number;
+          /** Mouse button, 1 for primary. Defaults to 1. */
+          button?: number;
+          /** Click count of the press, 2 for a double click. Defaults to 1. */
+          nPress?: number;
+      }
+    | {
+          type: "key-press" | "key-release";
+          /** Key symbol, such as `Gdk.KEY_Return` */
+          keyval: number;
+          /** Hardware keycode. Defaults to 0. */
+          keycode?: number;
+          /** Modifier state during the event. Defaults to none. */
+          modifiers?: Gdk.ModifierType;
+      }
+    | {
+          type: "scroll";
+          dx: number;
+          dy: number;
+          /** Pointer position. Defaults to the origin of the target widget. */
+          x?: number;
+          y?: number;
+      };
+
+type Delivery<T extends Gtk.EventController> = { widget: Gtk.Widget; controller: T };
+
+const hoveredPaths = new WeakMap<Gtk.Widget, Gtk.Widget[]>();
+
+let gdkModifierType: number | null = null;
+
+const emit = (controller: Gtk.EventController, signalName: string, args: Value[], returnValue?: Value): void => {
+    const gtype = typeFromName(controller.constructor.glibTypeName);
+    signalEmitv([Value.newFromObject(controller), ...args], signalLookup(signalName, gtype), 0, returnValue);
+};
+
+const pathToRoot = (widget: Gtk.Widget): Gtk.Widget[] => {
+    const path: Gtk.Widget[] = [];
+    for (let current: Gtk.Widget | null = widget; current; current = current.getParent()) {
+        path.push(current);
+    }
+    return path;
+};
+
+/**
+ * Orders the controllers of a widget path the way GTK delivers events:
+ * capture-phase controllers from the root down, then target and
+ * bubble-phase controllers from the target up.
+ */
+const deliveryOrder = <T extends Gtk.EventController>(
+    path: Gtk.Widget[],
+    controllerType: new () => T,
+): Delivery<T>[] => {
+    const capture: Delivery<T>[] = [];
+    const bubble: Delivery<T>[] = [];
+
+    for (const widget of path) {
+        const controllers = widget.observeControllers();
+        for (let i = 0; i < controllers.getNItems(); i++) {
+            const controller = controllers.getObject(i);
+            if (!(controller instanceof controllerType)) continue;
+
+            const phase = controller.getPropagationPhase();
+            if (phase === Gtk.PropagationPhase.CAPTURE) {
+                capture.unshift({ widget, controller });
+            } else if (phase !== Gtk.PropagationPhase.NONE) {
+                bubble.push({ widget, controller });
+            }
+        }
+    }
+
+    return [...capture, ...bubble];
+};
+
+const localPoint = (target: Gtk.Widget, widget: Gtk.Widget, x: number, y: number): { x: number; y: number } => {
+    if (widget === target) return { x, y };
+    const [ok, point] = target.computePoint(widget, new Graphene.Point({ x, y }));
+    return ok ? { x: point.x, y: point.y } : { x, y };
+};
+
+const pickPath = (target: Gtk.Widget, x: number, y: number): Gtk.Widget[] =>
+    pathToRoot(target.pick(x, y, Gtk.PickFlags.DEFAULT) ?? target);
+
+const injectMotion = (target: Gtk.Widget, x: number, y: number): boolean => {
+    const path = pickPath(target, x, y);
+    const previous = hoveredPaths.get(target) ?? [];
+    hoveredPaths.set(target, path);
+    let handled = false;
+
+    const left = previous.filter((widget) => !path.includes(widget));
+    for (const { controller } of deliveryOrder(left, Gtk.EventControllerMotion)) {
+        emit(controller, "leave", []);
+        handled = true;
+    }
+
+    for (const { widget, controller } of deliveryOrder(path, Gtk.EventControllerMotion)) {
+        const point = localPoint(target, widget, x, y);
+        const args = [Value.newFromDouble(point.x), Value.newFromDouble(point.y)];
+        if (!previous.includes(widget)) emit(controller, "enter", args);
+        emit(controller, "motion", args);
+        handled = true;
+    }
+
+    return handled;
+};
+
+const injectButton = (target: Gtk.Widget, event: Extract<InjectedEvent, { type: "press" | "release" }>): boolean => {
+    const button = event.button ?? 1;
+    const signalName = event.type === "press" ? "pressed" : "released";
+    let handled = false;
+
+    for (const { widget, controller } of deliveryOrder(pickPath(target, event.x, event.y), Gtk.GestureClick)) {
+        const gestureButton = controller.getButton();
+        if (gestureButton !== 0 && gestureButton !== button) continue;
+
+        const point = localPoint(target, widget, event.x, event.y);
+        emit(controller, signalName, [
+            Value.newFromInt(event.nPress ?? 1),
+            Value.newFromDouble(point.x),
+            Value.newFromDouble(point.y),
+        ]);
+        handled = true;
+    }
+
+    return handled;
+};
+
+const injectScroll = (target: Gtk.Widget, event: Extract<InjectedEvent, { type: "scroll" }>): boolean => {
+    const path = pickPath(target, event.x ?? 0, event.y ?? 0);
+
+    for (const { controller } of deliveryOrder(path, Gtk.EventControllerScroll)) {
+        const returnValue = Value.newFromBoolean(false);
+        emit(controller, "scroll", [Value.newFromDouble(event.dx), Value.newFromDouble(event.dy)], returnValue);
+        if (returnValue.getBoolean()) return true;
+    }
+
+    return false;
+};
+
+const injectKey = (
+    target: Gtk.Widget,
+    event: Extract<InjectedEvent, { type: "key-press" | "key-release" }>,
+): boolean => {
+    gdkModifierType ??= typeFromName("GdkModifierType");
+    const focus = target instanceof Gtk.Window ? (target.getFocus() ?? target) : target;
+    const args = [
+        Value.newFromUint(event.keyval),
+        Value.newFromUint(event.keycode ?? 0),
+        Value.newFromFlags(gdkModifierType, event.modifiers ?? 0),
+    ];
+
+    const deliveries = deliveryOrder(pathToRoot(focus), Gtk.EventControllerKey);
+
+    if (event.type === "key-release") {
+        for (const { controller } of deliveries) emit(controller, "key-released", args);
+        return deliveries.length > 0;
+    }
+
+    for (const { controller } of deliveries) {
+        const returnValue = Value.newFromBoolean(false);
+        emit(controller, "key-pressed", args, returnValue);
+        if (returnValue.getBoolean()) return true;
+    }
+
+    return false;
+};
+
+/**
+ * Injects a synthesized input event into a widget, hit-testing and
+ * propagating it the way GTK would deliver a real one.
+ *
+ * Pointer events go to the widget under the given position, found with
+ * `Gtk.Widget.pick()`, and reach the event controllers of it and its
+ * ancestors in capture, then bubble order, with coordinates translated for
+ * each widget. Motion also emits enter and leave as the pointer crosses
+ * widgets. Key events go to the focus widget when injected into a window.
+ * Key presses and scrolls stop propagating once a handler returns true.
+ *
+ * Unlike {@link userEvent}, which acts on a known widget, this drives the
+ * UI by position, so end-to-end tests need no external tools.
+ *
+ * @param target - The window or widget receiving the event
+ * @param event - The event to inject
+ * @returns Whether any controller received the event, or for key presses and scrolls, handled it
+ *
+ * @example
+ * ```tsx
+ * import { injectEvent, render } from "@gtkx/testing";
+ * import * as Gdk from "@gtkx/ffi/gdk";
+ *
+ * const { container } = await render(<Canvas />);
+ * await injectEvent(container, { type: "motion", x: 120, y: 80 });
+ * await injectEvent(container, { type: "press", x: 120, y: 80 });
+ * await injectEvent(container, { type: "release", x: 120, y: 80 });
+ * await injectEvent(container, { type: "key-press", keyval: Gdk.KEY_z, modifiers: Gdk.ModifierType.CONTROL_MASK });
+ * ```
+ */
+export const injectEvent = async (target: Gtk.Widget, event: InjectedEvent): Promise<boolean> => {
+    let handled: boolean;
+
+    switch (event.type) {
+        case "motion":
+            handled = injectMotion(target, event.x, event.y);
+            break;
+        case "press":
+        case "release":
+            handled = injectButton(target, event);
+            break;
+        case "scroll":
+            handled = injectScroll(target, event);
+            break;
+        case "key-press":
+        case "key-release":
+            handled = injectKey(target, event);
+            break;
+    }
+
+    await tick();
+    return handled;
+};
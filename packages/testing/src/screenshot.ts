@@ -1,3 +1,4 @@
+import type * as Gdk from "@gtkx/ffi/gdk";
 import * as Gsk from "@gtkx/ffi/gsk";
 import * as Gtk from "@gtkx/ffi/gtk";
 import { tick } from "./timing.js";
@@ -11,7 +12,7 @@ const bytesToBase64 = (bytes: number[]): string => {
 const DEFAULT_SCREENSHOT_TIMEOUT = 100;
 const DEFAULT_SCREENSHOT_INTERVAL = 10;
 
-const captureSnapshot = (widget: Gtk.Widget): ScreenshotResult => {
+const renderTexture = (widget: Gtk.Widget): Gdk.Texture => {
     const paintable = new Gtk.WidgetPaintable(widget);
     const width = paintable.getIntrinsicWidth();
     const height = paintable.getIntrinsicHeight();
@@ -37,25 +38,43 @@ const captureSnapshot = (widget: Gtk.Widget): ScreenshotResult => {
     renderer.realizeForDisplay(display);
 
     try {
-        const texture = renderer.renderTexture(renderNode);
-        const pngBytes = texture.saveToPngBytes();
-        const data = pngBytes.getData();
-
-        if (!data) {
-            throw new Error("Failed to serialize screenshot to PNG");
-        }
-
-        return {
-            data: bytesToBase64(data),
-            mimeType: "image/png",
-            width,
-            height,
-        };
+        return renderer.renderTexture(renderNode);
     } finally {
         renderer.unrealize();
     }
 };
 
+const captureSnapshot = (widget: Gtk.Widget): ScreenshotResult => {
+    const texture = renderTexture(widget);
+    const data = texture.saveToPngBytes().getData();
+
+    if (!data) {
+        throw new Error("Failed to serialize screenshot to PNG");
+    }
+
+    return {
+        data: bytesToBase64(data),
+        mimeType: "image/png",
+        width: texture.getWidth(),
+        height: texture.getHeight(),
+    };
+};
+
+const waitOptions = (widget: Gtk.Widget, options?: ScreenshotOptions): WaitForOptions => ({
+    timeout: options?.timeout ?? DEFAULT_SCREENSHOT_TIMEOUT,
+    interval: options?.interval ?? DEFAULT_SCREENSHOT_INTERVAL,
+    onTimeout: (error) => {
+        const paintable = new Gtk.WidgetPaintable(widget);
+        const width = paintable.getIntrinsicWidth();
+        const height = paintable.getIntrinsicHeight();
+
+        if (width <= 0 || height <= 0) {
+            return new Error("Widget has no size: ensure it is realized and visible");
+        }
+        return new Error(`Widget produced no render content after waiting for paint cycle: ${error.message}`);
+    },
+});
+
 /**
  * Options for capturing widget screenshots.
  */
@@ -86,18 +105,16 @@ export type ScreenshotOptions = Pick<WaitForOptions, "timeout" | "interval">;
 export const screenshot = async (widget: Gtk.Widget, options?: ScreenshotOptions): Promise<ScreenshotResult> => {
     await tick();
 
-    return waitFor(() => captureSnapshot(widget), {
-        timeout: options?.timeout ?? DEFAULT_SCREENSHOT_TIMEOUT,
-        interval: options?.interval ?? DEFAULT_SCREENSHOT_INTERVAL,
-        onTimeout: (error) => {
-            const paintable = new Gtk.WidgetPaintable(widget);
-            const width = paintable.getIntrinsicWidth();
-            const height = paintable.getIntrinsicHeight();
-
-            if (width <= 0 || height <= 0) {
-                return new Error("Widget has no size: ensure it is realized and visible");
-            }
-            return new Error(`Widget produced no render content after waiting for paint cycle: ${error.message}`);
-        },
-    });
+    return waitFor(() => captureSnapshot(widget), waitOptions(widget, options));
+};
+
+/**
+ * Renders a widget to a texture, retrying until GTK has painted it.
+ *
+ * @internal
+ */
+export const captureTexture = async (widget: Gtk.Widget, options?: ScreenshotOptions): Promise<Gdk.Texture> => {
+    await tick();
+
+    return waitFor(() => renderTexture(widget), waitOptions(widget, options));
 };
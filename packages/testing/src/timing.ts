@@ -1,3 +1,5 @@
+import * as Gtk from "@gtkx/ffi/gtk";
+
 /**
  * Yields to the event loop, allowing pending GTK events to process.
  *
@@ -15,3 +17,27 @@
  * ```
  */
 export const tick = (): Promise<void> => new Promise((resolve) => setTimeout(resolve, 0));
+
+/**
+ * Waits until a widget has been drawn at least once.
+ *
+ * Wraps `gtk_test_widget_wait_for_draw()`, which runs the GTK main loop
+ * until the widget's surface has painted a frame. Use it before inspecting
+ * allocations or pixels of a freshly shown widget.
+ *
+ * @param widget - A widget that is mapped or about to be mapped
+ * @returns Promise that resolves once the widget has been drawn
+ *
+ * @example
+ * ```tsx
+ * import { waitForDraw } from "@gtkx/testing";
+ *
+ * window.present();
+ * await waitForDraw(window);
+ * expect(button.getWidth()).toBeGreaterThan(0);
+ * ```
+ */
+export const waitForDraw = async (widget: Gtk.Widget): Promise<void> => {
+    Gtk.testWidgetWaitForDraw(widget);
+    await tick();
+};
@@ -264,8 +264,8 @@ const parseKeyToken = (token: string): { keyval: number; press: boolean; release
         release = false;
     }
 
-    const keyval = KEY_MAP[keyName];
-    if (keyval === undefined) {
+    const keyval = KEY_MAP[keyName] ?? Gdk.keyvalFromName(keyName);
+    if (keyval === Gdk.KEY_VoidSymbol) {
         throw new Error(`Unknown key: {${keyName}}`);
     }
     return { keyval, press, release };
@@ -357,19 +357,31 @@ const keyboard = async (element: Gtk.Widget, input: string): Promise<void> => {
  */
 export type PointerInput = "click" | "down" | "up" | "[MouseLeft]" | "[MouseLeft>]" | "[/MouseLeft]";
 
-const pointer = async (element: Gtk.Widget, input: PointerInput): Promise<void> => {
+/**
+ * Options for pointer input.
+ */
+export type PointerOptions = {
+    /** Horizontal position of the pointer, relative to the widget. Defaults to 0. */
+    x?: number;
+    /** Vertical position of the pointer, relative to the widget. Defaults to 0. */
+    y?: number;
+};
+
+const pointer = async (element: Gtk.Widget, input: PointerInput, options?: PointerOptions): Promise<void> => {
     const controller = getOrCreateController(element, Gtk.GestureClick);
+    const x = options?.x ?? 0;
+    const y = options?.y ?? 0;
     const pressedArgs = [
         Value.newFromObject(controller),
         Value.newFromInt(1),
-        Value.newFromDouble(0),
-        Value.newFromDouble(0),
+        Value.newFromDouble(x),
+        Value.newFromDouble(y),
     ];
     const releasedArgs = [
         Value.newFromObject(controller),
         Value.newFromInt(1),
-        Value.newFromDouble(0),
-        Value.newFromDouble(0),
+        Value.newFromDouble(x),
+        Value.newFromDouble(y),
     ];
 
     if (input === "[MouseLeft]" || input === "click") {
@@ -482,6 +494,7 @@ export const userEvent = {
      * Simulates keyboard input.
      *
      * Supports special keys in braces: `{Enter}`, `{Tab}`, `{Escape}`, etc.
+     * Any other GDK key name, such as `{F5}` or `{KP_Add}`, is accepted too.
      * Use `{Key>}` to hold a key down, `{/Key}` to release.
      *
      * @example
@@ -495,12 +508,14 @@ export const userEvent = {
     /**
      * Simulates pointer (mouse) input.
      *
-     * Supports: `"click"`, `"[MouseLeft]"`, `"down"`, `"up"`.
+     * Supports: `"click"`, `"[MouseLeft]"`, `"down"`, `"up"`. Pass `x` and
+     * `y` to press at a position within the widget.
      *
      * @example
      * ```tsx
      * await userEvent.pointer(element, "click");
      * await userEvent.pointer(element, "[MouseLeft]");
+     * await userEvent.pointer(canvas, "click", { x: 40, y: 25 });
      * ```
      */
     pointer,
@@ -0,0 +1,76 @@
+import { existsSync, mkdtempSync } from "node:fs";
+import { tmpdir } from "node:os";
+import { join } from "node:path";
+import * as Gtk from "@gtkx/ffi/gtk";
+import { GtkButton } from "@gtkx/react";
+import { describe, expect, it } from "vitest";
+import { compareImageSnapshot, expectImageSnapshot, render, screen } from "../src/index.js";
+
+const referencePath = (name: string): string => join(mkdtempSync(join(tmpdir(), "gtkx-snapshot-")), name);
+
+describe("compareImageSnapshot", () => {
+    it("writes a missing reference image", async () => {
+        await render(<GtkButton label="Reference" />);
+        const button = await screen.findByRole(Gtk.AccessibleRole.BUTTON, { name: "Reference" });
+        const path = referencePath("button.png");
+
+        const result = await compareImageSnapshot(button, path);
+
+        expect(result.written).toBe(true);
+        expect(result.pass).toBe(true);
+        expect(existsSync(path)).toBe(true);
+    });
+
+    it("matches an unchanged widget and returns a diff image", async () => {
+        await render(<GtkButton label="Same" />);
+        const button = await screen.findByRole(Gtk.AccessibleRole.BUTTON, { name: "Same" });
+        const path = referencePath("button.png");
+        await compareImageSnapshot(button, path);
+
+        const result = await compareImageSnapshot(button, path);
+
+        expect(result.written).toBe(false);
+        expect(result.pass).toBe(true);
+        expect(result.diffPixels).toBe(0);
+        expect(result.diff?.subarray(1, 4).toString()).toBe("PNG");
+    });
+
+    it("reports differing pixels", async () => {
+        await render(<GtkButton label="Before" />);
+        const button = await screen.findByRole(Gtk.AccessibleRole.BUTTON, { name: "Before" });
+        const path = referencePath("button.png");
+        await compareImageSnapshot(button, path);
+
+        button.setSensitive(false);
+        const result = await compareImageSnapshot(button, path);
+
+        expect(result.pass).toBe(false);
+        expect(result.diffPixels).toBeGreaterThan(0);
+        expect(result.diffRatio).toBeGreaterThan(0);
+    });
+
+    it("passes differences within the tolerance", async () => {
+        await render(<GtkButton label="Tolerant" />);
+        const button = await screen.findByRole(Gtk.AccessibleRole.BUTTON, { name: "Tolerant" });
+        const path = referencePath("button.png");
+        await compareImageSnapshot(button, path);
+
+        button.setSensitive(false);
+        const result = await compareImageSnapshot(button, path, { tolerance: 1 });
+
+        expect(result.pass).toBe(true);
+    });
+});
+
+describe("expectImageSnapshot", () => {
+    it("throws when the widget differs from the reference", async () => {
+        await render(<GtkButton label="Changed" />);
+        const button = await screen.findByRole(Gtk.AccessibleRole.BUTTON, { name: "Changed" });
+        const path = referencePath("button.png");
+        await expectImageSnapshot(button, path);
+
+        button.setSensitive(false);
+
+        await expect(expectImageSnapshot(button, path)).rejects.toThrow(/does not match reference image/);
+    });
+});
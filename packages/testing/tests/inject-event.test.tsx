@@ -0,0 +1,94 @@
+import * as Gdk from "@gtkx/ffi/gdk";
+import * as Graphene from "@gtkx/ffi/graphene";
+import * as Gtk from "@gtkx/ffi/gtk";
+import { GtkBox, GtkButton, GtkEntry } from "@gtkx/react";
+import { describe, expect, it, vi } from "vitest";
+import { injectEvent, render, screen, waitForDraw } from "../src/index.js";
+
+const renderButton = async (label: string) => {
+    const { container } = await render(
+        <GtkBox>
+            <GtkButton label={label} />
+        </GtkBox>,
+    );
+    const button = await screen.findByRole(Gtk.AccessibleRole.BUTTON, { name: label });
+    await waitForDraw(button);
+    const [, point] = button.computePoint(container, new Graphene.Point({ x: 2, y: 2 }));
+    return { container, button, x: point.x, y: point.y };
+};
+
+describe("injectEvent", () => {
+    it("delivers presses to the widget under the pointer", async () => {
+        const { container, button, x, y } = await renderButton("Target");
+        const gesture = new Gtk.GestureClick();
+        const handlePressed = vi.fn();
+        gesture.connect("pressed", handlePressed);
+        button.addController(gesture);
+
+        const handled = await injectEvent(container, { type: "press", x, y });
+
+        expect(handled).toBe(true);
+        expect(handlePressed).toHaveBeenCalledWith(gesture, 1, 2, 2);
+    });
+
+    it("skips gestures listening to another button", async () => {
+        const { container, button, x, y } = await renderButton("Secondary");
+        const gesture = new Gtk.GestureClick();
+        gesture.setButton(Gdk.BUTTON_SECONDARY);
+        const handlePressed = vi.fn();
+        gesture.connect("pressed", handlePressed);
+        button.addController(gesture);
+
+        await injectEvent(container, { type: "press", x, y, button: Gdk.BUTTON_PRIMARY });
+
+        expect(handlePressed).not.toHaveBeenCalled();
+    });
+
+    it("emits enter before the first motion over a widget", async () => {
+        const { container, button, x, y } = await renderButton("Hover");
+        const motion = new Gtk.EventControllerMotion();
+        const calls: string[] = [];
+        motion.connect("enter", () => calls.push("enter"));
+        motion.connect("motion", () => calls.push("motion"));
+        button.addController(motion);
+
+        await injectEvent(container, { type: "motion", x, y });
+        await injectEvent(container, { type: "motion", x: x + 1, y });
+
+        expect(calls).toEqual(["enter", "motion", "motion"]);
+    });
+
+    it("stops scroll propagation once handled", async () => {
+        const { container, button, x, y } = await renderButton("Scroll");
+        const inner = new Gtk.EventControllerScroll(Gtk.EventControllerScrollFlags.VERTICAL);
+        const outer = new Gtk.EventControllerScroll(Gtk.EventControllerScrollFlags.VERTICAL);
+        const handleOuter = vi.fn(() => false);
+        inner.connect("scroll", () => true);
+        outer.connect("scroll", handleOuter);
+        button.addController(inner);
+        container.addController(outer);
+
+        const handled = await injectEvent(container, { type: "scroll", dx: 0, dy: 1, x, y });
+
+        expect(handled).toBe(true);
+        expect(handleOuter).not.toHaveBeenCalled();
+    });
+
+    it("delivers key presses to the focus widget", async () => {
+        const { container } = await render(<GtkEntry />);
+        const entry = await screen.findByRole(Gtk.AccessibleRole.TEXT_BOX);
+        entry.grabFocus();
+        const controller = new Gtk.EventControllerKey();
+        const handleKeyPressed = vi.fn(() => false);
+        controller.connect("key-pressed", handleKeyPressed);
+        entry.addController(controller);
+
+        await injectEvent(container, {
+            type: "key-press",
+            keyval: Gdk.KEY_a,
+            modifiers: Gdk.ModifierType.CONTROL_MASK,
+        });
+
+        expect(handleKeyPressed).toHaveBeenCalledWith(controller, Gdk.KEY_a, 0, Gdk.ModifierType.CONTROL_MASK);
+    });
+});
@@ -1,5 +1,7 @@
+import { initRuntime } from "@gtkx/ffi";
+import * as Gtk from "@gtkx/ffi/gtk";
 import { describe, expect, it } from "vitest";
-import { tick } from "../src/index.js";
+import { tick, waitForDraw } from "../src/index.js";
 
 describe("tick", () => {
     it("returns a promise", () => {
@@ -38,3 +40,18 @@ describe("tick", () => {
         expect(order).toEqual([1, 2, 3]);
     });
 });
+
+describe("waitForDraw", () => {
+    it("resolves once the widget has been drawn", async () => {
+        initRuntime();
+        const window = new Gtk.Window();
+        const label = new Gtk.Label("Drawn");
+        window.setChild(label);
+        window.present();
+
+        await waitForDraw(label);
+
+        expect(label.getWidth()).toBeGreaterThan(0);
+        window.destroy();
+    });
+});
@@ -1,3 +1,4 @@
+import * as Gdk from "@gtkx/ffi/gdk";
 import * as Gtk from "@gtkx/ffi/gtk";
 import {
     GtkBox,
@@ -262,3 +263,43 @@ describe("userEvent.deselectOptions", () => {
         });
     });
 });
+
+describe("userEvent.keyboard", () => {
+    it("accepts GDK key names", async () => {
+        await render(<GtkEntry />);
+
+        const entry = await screen.findByRole(Gtk.AccessibleRole.TEXT_BOX);
+        const controller = new Gtk.EventControllerKey();
+        const handleKeyPressed = vi.fn(() => false);
+        controller.connect("key-pressed", handleKeyPressed);
+        entry.addController(controller);
+
+        await userEvent.keyboard(entry, "{F5}");
+
+        expect(handleKeyPressed).toHaveBeenCalledWith(controller, Gdk.KEY_F5, 0, 0);
+    });
+
+    it("throws for unknown key names", async () => {
+        await render(<GtkEntry />);
+
+        const entry = await screen.findByRole(Gtk.AccessibleRole.TEXT_BOX);
+
+        await expect(userEvent.keyboard(entry, "{NotAKey}")).rejects.toThrow("Unknown key: {NotAKey}");
+    });
+});
+
+describe("userEvent.pointer", () => {
+    it("presses at the given position", async () => {
+        await render(<GtkButton label="Canvas" />);
+
+        const button = await screen.findByRole(Gtk.AccessibleRole.BUTTON, { name: "Canvas" });
+        const gesture = new Gtk.GestureClick();
+        const handlePressed = vi.fn();
+        gesture.connect("pressed", handlePressed);
+        button.addController(gesture);
+
+        await userEvent.pointer(button, "click", { x: 40, y: 25 });
+
+        expect(handlePressed).toHaveBeenCalledWith(gesture, 1, 40, 25);
+    });
+});
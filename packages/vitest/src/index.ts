@@ -1 +1,2 @@
+export type { GtkxPluginOptions } from "./plugin.js";
 export { default } from "./plugin.js";
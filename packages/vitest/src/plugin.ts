@@ -2,11 +2,27 @@ import { join } from "node:path";
 
 import type { Plugin } from "vitest/config";
 
+/**
+ * Options for the GTKX Vitest plugin.
+ */
+export type GtkxPluginOptions = {
+    /**
+     * Display server each worker starts for its widgets.
+     *
+     * - `"x11"` (default): an Xvfb instance
+     * - `"broadway"`: a `gtk4-broadwayd` instance, for CI environments
+     *   without Xvfb. Only GTK's own packages are needed.
+     */
+    backend?: "x11" | "broadway";
+};
+
 /**
  * Creates the GTKX Vitest plugin for running GTK tests.
  *
- * Each worker spawns its own Xvfb instance on a PID-based display number.
+ * Each worker spawns its own headless display server on a PID-based
+ * display number.
  *
+ * @param options - Display backend selection
  * @returns Vitest plugin configuration
  *
  * @example
@@ -18,9 +34,14 @@ import type { Plugin } from "vitest/config";
  * export default defineConfig({
  *   plugins: [gtkx()],
  * });
+ *
+ * // Without Xvfb
+ * export default defineConfig({
+ *   plugins: [gtkx({ backend: "broadway" })],
+ * });
  * ```
  */
-const gtkx = (): Plugin => {
+const gtkx = (options: GtkxPluginOptions = {}): Plugin => {
     const workerSetupPath = join(import.meta.dirname, "setup.js");
 
     return {
@@ -32,6 +53,7 @@ const gtkx = (): Plugin => {
                 test: {
                     setupFiles: [workerSetupPath, ...(Array.isArray(setupFiles) ? setupFiles : [setupFiles])],
                     pool: "forks",
+                    env: { GTKX_TEST_BACKEND: options.backend ?? "x11" },
                 },
             };
         },
@@ -1,17 +1,22 @@
 import { spawn } from "node:child_process";
 import { existsSync, mkdtempSync, writeFileSync } from "node:fs";
-import { tmpdir } from "node:os";
+import { homedir, tmpdir } from "node:os";
 import { join } from "node:path";
 import { beforeAll } from "vitest";
 
+const backend = process.env.GTKX_TEST_BACKEND === "broadway" ? "broadway" : "x11";
 const display = 100 + (process.pid % 5000);
-const socketPath = `/tmp/.X11-unix/X${display}`;
 
-const xvfb = spawn("Xvfb", [`:${display}`, "-screen", "0", "1024x768x24"], {
-    stdio: "ignore",
-});
+const runtimeDir = process.env.XDG_RUNTIME_DIR ?? join(homedir(), ".cache");
+const socketPath =
+    backend === "broadway" ? join(runtimeDir, `broadway${display + 1}.socket`) : `/tmp/.X11-unix/X${display}`;
+
+const displayServer =
+    backend === "broadway"
+        ? spawn("gtk4-broadwayd", [`:${display}`], { stdio: "ignore" })
+        : spawn("Xvfb", [`:${display}`, "-screen", "0", "1024x768x24"], { stdio: "ignore" });
 
-xvfb.unref();
+displayServer.unref();
 
 const busDir = mkdtempSync(join(tmpdir(), "gtkx-dbus-"));
 const busConfigPath = join(busDir, "session.conf");
@@ -39,17 +44,21 @@ const dbus = spawn("dbus-daemon", [`--config-file=${busConfigPath}`], {
 dbus.unref();
 
 process.env.DBUS_SESSION_BUS_ADDRESS = `unix:path=${busSocketPath}`;
-process.env.DISPLAY = `:${display}`;
-process.env.GDK_BACKEND = "x11";
+if (backend === "broadway") {
+    process.env.BROADWAY_DISPLAY = `:${display}`;
+} else {
+    process.env.DISPLAY = `:${display}`;
+}
+process.env.GDK_BACKEND = backend;
 process.env.GDK_DISABLE = "vulkan";
 process.env.GSK_RENDERER = "cairo";
 process.env.GTK_A11Y = "none";
 process.env.LIBGL_ALWAYS_SOFTWARE = "1";
 
 const killChildren = (): void => {
-    if (xvfb.pid !== undefined) {
+    if (displayServer.pid !== undefined) {
         try {
-            process.kill(xvfb.pid, "SIGTERM");
+            process.kill(displayServer.pid, "SIGTERM");
         } catch {}
     }
     if (dbus.pid !== undefined) {
@@ -84,7 +93,7 @@ const waitForFile = async (path: string, label: string, timeout = 15000): Promis
 
 beforeAll(async () => {
     await Promise.all([
-        waitForFile(socketPath, `Xvfb display :${display}`),
+        waitForFile(socketPath, `${backend === "broadway" ? "Broadway" : "Xvfb"} display :${display}`),
         waitForFile(busSocketPath, "D-Bus session bus"),
     ]);
 });